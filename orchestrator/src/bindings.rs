@@ -3,89 +3,6 @@
 //   * runtime_path: "wit_bindgen_rt"
 #[doc(hidden)]
 #[allow(non_snake_case)]
-pub unsafe fn _export_assert_text_cabi<T: Guest>(arg0: *mut u8, arg1: usize) -> *mut u8 {
-    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
-    let len0 = arg1;
-    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
-    let result1 = T::assert_text(_rt::string_lift(bytes0));
-    let ptr2 = (&raw mut _RET_AREA.0).cast::<u8>();
-    match result1 {
-        Ok(e) => {
-            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
-            *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(
-                e,
-            );
-        }
-        Err(e) => {
-            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
-            let vec3 = (e.into_bytes()).into_boxed_slice();
-            let ptr3 = vec3.as_ptr().cast::<u8>();
-            let len3 = vec3.len();
-            ::core::mem::forget(vec3);
-            *ptr2.add(2 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len3;
-            *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr3
-                .cast_mut();
-        }
-    };
-    ptr2
-}
-#[doc(hidden)]
-#[allow(non_snake_case)]
-pub unsafe fn __post_return_assert_text<T: Guest>(arg0: *mut u8) {
-    let l0 = i32::from(*arg0.add(0).cast::<u8>());
-    match l0 {
-        0 => {}
-        _ => {
-            let l1 = *arg0.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
-            let l2 = *arg0.add(2 * ::core::mem::size_of::<*const u8>()).cast::<usize>();
-            _rt::cabi_dealloc(l1, l2, 1);
-        }
-    }
-}
-#[doc(hidden)]
-#[allow(non_snake_case)]
-pub unsafe fn _export_query_text_cabi<T: Guest>(arg0: *mut u8, arg1: usize) -> *mut u8 {
-    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
-    let len0 = arg1;
-    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
-    let result1 = T::query_text(_rt::string_lift(bytes0));
-    let ptr2 = (&raw mut _RET_AREA.0).cast::<u8>();
-    match result1 {
-        Ok(e) => {
-            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
-            *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<u8>() = (match e {
-                true => 1,
-                false => 0,
-            }) as u8;
-        }
-        Err(e) => {
-            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
-            let vec3 = (e.into_bytes()).into_boxed_slice();
-            let ptr3 = vec3.as_ptr().cast::<u8>();
-            let len3 = vec3.len();
-            ::core::mem::forget(vec3);
-            *ptr2.add(2 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len3;
-            *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr3
-                .cast_mut();
-        }
-    };
-    ptr2
-}
-#[doc(hidden)]
-#[allow(non_snake_case)]
-pub unsafe fn __post_return_query_text<T: Guest>(arg0: *mut u8) {
-    let l0 = i32::from(*arg0.add(0).cast::<u8>());
-    match l0 {
-        0 => {}
-        _ => {
-            let l1 = *arg0.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
-            let l2 = *arg0.add(2 * ::core::mem::size_of::<*const u8>()).cast::<usize>();
-            _rt::cabi_dealloc(l1, l2, 1);
-        }
-    }
-}
-#[doc(hidden)]
-#[allow(non_snake_case)]
 pub unsafe fn _export_compile_debug_cabi<T: Guest>(
     arg0: *mut u8,
     arg1: usize,
@@ -137,34 +54,18 @@ pub unsafe fn __post_return_compile_debug<T: Guest>(arg0: *mut u8) {
     }
 }
 pub trait Guest {
-    /// Assert Lojban text as facts into the knowledge base.
-    /// Returns the number of root facts inserted.
-    fn assert_text(input: _rt::String) -> Result<u32, _rt::String>;
-    /// Query whether Lojban text is entailed by the knowledge base.
-    fn query_text(input: _rt::String) -> Result<bool, _rt::String>;
     /// Debug: compile text to logic s-expression without asserting.
     fn compile_debug(input: _rt::String) -> Result<_rt::String, _rt::String>;
 }
 #[doc(hidden)]
 macro_rules! __export_world_engine_pipeline_cabi {
     ($ty:ident with_types_in $($path_to_types:tt)*) => {
-        const _ : () = { #[unsafe (export_name = "assert-text")] unsafe extern "C" fn
-        export_assert_text(arg0 : * mut u8, arg1 : usize,) -> * mut u8 { unsafe {
-        $($path_to_types)*:: _export_assert_text_cabi::<$ty > (arg0, arg1) } } #[unsafe
-        (export_name = "cabi_post_assert-text")] unsafe extern "C" fn
-        _post_return_assert_text(arg0 : * mut u8,) { unsafe { $($path_to_types)*::
-        __post_return_assert_text::<$ty > (arg0) } } #[unsafe (export_name =
-        "query-text")] unsafe extern "C" fn export_query_text(arg0 : * mut u8, arg1 :
-        usize,) -> * mut u8 { unsafe { $($path_to_types)*:: _export_query_text_cabi::<$ty
-        > (arg0, arg1) } } #[unsafe (export_name = "cabi_post_query-text")] unsafe extern
-        "C" fn _post_return_query_text(arg0 : * mut u8,) { unsafe { $($path_to_types)*::
-        __post_return_query_text::<$ty > (arg0) } } #[unsafe (export_name =
-        "compile-debug")] unsafe extern "C" fn export_compile_debug(arg0 : * mut u8, arg1
-        : usize,) -> * mut u8 { unsafe { $($path_to_types)*::
-        _export_compile_debug_cabi::<$ty > (arg0, arg1) } } #[unsafe (export_name =
-        "cabi_post_compile-debug")] unsafe extern "C" fn _post_return_compile_debug(arg0
-        : * mut u8,) { unsafe { $($path_to_types)*:: __post_return_compile_debug::<$ty >
-        (arg0) } } };
+        const _ : () = { #[unsafe (export_name = "compile-debug")] unsafe extern "C" fn
+        export_compile_debug(arg0 : * mut u8, arg1 : usize,) -> * mut u8 { unsafe {
+        $($path_to_types)*:: _export_compile_debug_cabi::<$ty > (arg0, arg1) } } #[unsafe
+        (export_name = "cabi_post_compile-debug")] unsafe extern "C" fn
+        _post_return_compile_debug(arg0 : * mut u8,) { unsafe { $($path_to_types)*::
+        __post_return_compile_debug::<$ty > (arg0) } } };
     };
 }
 #[doc(hidden)]
@@ -304,8 +205,14 @@ pub mod lojban {
                 Lo,
                 Le,
                 La,
-                RoLo,
-                RoLe,
+                Loi,
+                Lei,
+                Lai,
+                Lohi,
+                Lehi,
+                Lahi,
+                Lohe,
+                Lehe,
             }
             impl ::core::fmt::Debug for Gadri {
                 fn fmt(
@@ -316,8 +223,14 @@ pub mod lojban {
                         Gadri::Lo => f.debug_tuple("Gadri::Lo").finish(),
                         Gadri::Le => f.debug_tuple("Gadri::Le").finish(),
                         Gadri::La => f.debug_tuple("Gadri::La").finish(),
-                        Gadri::RoLo => f.debug_tuple("Gadri::RoLo").finish(),
-                        Gadri::RoLe => f.debug_tuple("Gadri::RoLe").finish(),
+                        Gadri::Loi => f.debug_tuple("Gadri::Loi").finish(),
+                        Gadri::Lei => f.debug_tuple("Gadri::Lei").finish(),
+                        Gadri::Lai => f.debug_tuple("Gadri::Lai").finish(),
+                        Gadri::Lohi => f.debug_tuple("Gadri::Lohi").finish(),
+                        Gadri::Lehi => f.debug_tuple("Gadri::Lehi").finish(),
+                        Gadri::Lahi => f.debug_tuple("Gadri::Lahi").finish(),
+                        Gadri::Lohe => f.debug_tuple("Gadri::Lohe").finish(),
+                        Gadri::Lehe => f.debug_tuple("Gadri::Lehe").finish(),
                     }
                 }
             }
@@ -331,8 +244,14 @@ pub mod lojban {
                         0 => Gadri::Lo,
                         1 => Gadri::Le,
                         2 => Gadri::La,
-                        3 => Gadri::RoLo,
-                        4 => Gadri::RoLe,
+                        3 => Gadri::Loi,
+                        4 => Gadri::Lei,
+                        5 => Gadri::Lai,
+                        6 => Gadri::Lohi,
+                        7 => Gadri::Lehi,
+                        8 => Gadri::Lahi,
+                        9 => Gadri::Lohe,
+                        10 => Gadri::Lehe,
                         _ => panic!("invalid enum discriminant"),
                     }
                 }
@@ -342,6 +261,12 @@ pub mod lojban {
             pub enum RelClauseKind {
                 Poi,
                 Noi,
+                Voi,
+                Goi,
+                Pe,
+                Ne,
+                Po,
+                Pohe,
             }
             impl ::core::fmt::Debug for RelClauseKind {
                 fn fmt(
@@ -355,6 +280,18 @@ pub mod lojban {
                         RelClauseKind::Noi => {
                             f.debug_tuple("RelClauseKind::Noi").finish()
                         }
+                        RelClauseKind::Voi => {
+                            f.debug_tuple("RelClauseKind::Voi").finish()
+                        }
+                        RelClauseKind::Goi => {
+                            f.debug_tuple("RelClauseKind::Goi").finish()
+                        }
+                        RelClauseKind::Pe => f.debug_tuple("RelClauseKind::Pe").finish(),
+                        RelClauseKind::Ne => f.debug_tuple("RelClauseKind::Ne").finish(),
+                        RelClauseKind::Po => f.debug_tuple("RelClauseKind::Po").finish(),
+                        RelClauseKind::Pohe => {
+                            f.debug_tuple("RelClauseKind::Pohe").finish()
+                        }
                     }
                 }
             }
@@ -367,15 +304,330 @@ pub mod lojban {
                     match val {
                         0 => RelClauseKind::Poi,
                         1 => RelClauseKind::Noi,
+                        2 => RelClauseKind::Voi,
+                        3 => RelClauseKind::Goi,
+                        4 => RelClauseKind::Pe,
+                        5 => RelClauseKind::Ne,
+                        6 => RelClauseKind::Po,
+                        7 => RelClauseKind::Pohe,
+                        _ => panic!("invalid enum discriminant"),
+                    }
+                }
+            }
+            #[repr(u8)]
+            #[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+            pub enum AbstractionKind {
+                Nu,
+                Duhu,
+                Ka,
+                Ni,
+                Siho,
+            }
+            impl ::core::fmt::Debug for AbstractionKind {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    match self {
+                        AbstractionKind::Nu => {
+                            f.debug_tuple("AbstractionKind::Nu").finish()
+                        }
+                        AbstractionKind::Duhu => {
+                            f.debug_tuple("AbstractionKind::Duhu").finish()
+                        }
+                        AbstractionKind::Ka => {
+                            f.debug_tuple("AbstractionKind::Ka").finish()
+                        }
+                        AbstractionKind::Ni => {
+                            f.debug_tuple("AbstractionKind::Ni").finish()
+                        }
+                        AbstractionKind::Siho => {
+                            f.debug_tuple("AbstractionKind::Siho").finish()
+                        }
+                    }
+                }
+            }
+            impl AbstractionKind {
+                #[doc(hidden)]
+                pub unsafe fn _lift(val: u8) -> AbstractionKind {
+                    if !cfg!(debug_assertions) {
+                        return ::core::mem::transmute(val);
+                    }
+                    match val {
+                        0 => AbstractionKind::Nu,
+                        1 => AbstractionKind::Duhu,
+                        2 => AbstractionKind::Ka,
+                        3 => AbstractionKind::Ni,
+                        4 => AbstractionKind::Siho,
+                        _ => panic!("invalid enum discriminant"),
+                    }
+                }
+            }
+            #[repr(u8)]
+            #[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+            pub enum TimeDirection {
+                Pu,
+                Ca,
+                Ba,
+            }
+            impl ::core::fmt::Debug for TimeDirection {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    match self {
+                        TimeDirection::Pu => f.debug_tuple("TimeDirection::Pu").finish(),
+                        TimeDirection::Ca => f.debug_tuple("TimeDirection::Ca").finish(),
+                        TimeDirection::Ba => f.debug_tuple("TimeDirection::Ba").finish(),
+                    }
+                }
+            }
+            impl TimeDirection {
+                #[doc(hidden)]
+                pub unsafe fn _lift(val: u8) -> TimeDirection {
+                    if !cfg!(debug_assertions) {
+                        return ::core::mem::transmute(val);
+                    }
+                    match val {
+                        0 => TimeDirection::Pu,
+                        1 => TimeDirection::Ca,
+                        2 => TimeDirection::Ba,
+                        _ => panic!("invalid enum discriminant"),
+                    }
+                }
+            }
+            #[repr(u8)]
+            #[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+            pub enum TimeDistance {
+                Zi,
+                Za,
+                Zu,
+            }
+            impl ::core::fmt::Debug for TimeDistance {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    match self {
+                        TimeDistance::Zi => f.debug_tuple("TimeDistance::Zi").finish(),
+                        TimeDistance::Za => f.debug_tuple("TimeDistance::Za").finish(),
+                        TimeDistance::Zu => f.debug_tuple("TimeDistance::Zu").finish(),
+                    }
+                }
+            }
+            impl TimeDistance {
+                #[doc(hidden)]
+                pub unsafe fn _lift(val: u8) -> TimeDistance {
+                    if !cfg!(debug_assertions) {
+                        return ::core::mem::transmute(val);
+                    }
+                    match val {
+                        0 => TimeDistance::Zi,
+                        1 => TimeDistance::Za,
+                        2 => TimeDistance::Zu,
+                        _ => panic!("invalid enum discriminant"),
+                    }
+                }
+            }
+            #[repr(u8)]
+            #[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+            pub enum Aspect {
+                Coha,
+                Cohu,
+                Caho,
+            }
+            impl ::core::fmt::Debug for Aspect {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    match self {
+                        Aspect::Coha => f.debug_tuple("Aspect::Coha").finish(),
+                        Aspect::Cohu => f.debug_tuple("Aspect::Cohu").finish(),
+                        Aspect::Caho => f.debug_tuple("Aspect::Caho").finish(),
+                    }
+                }
+            }
+            impl Aspect {
+                #[doc(hidden)]
+                pub unsafe fn _lift(val: u8) -> Aspect {
+                    if !cfg!(debug_assertions) {
+                        return ::core::mem::transmute(val);
+                    }
+                    match val {
+                        0 => Aspect::Coha,
+                        1 => Aspect::Cohu,
+                        2 => Aspect::Caho,
+                        _ => panic!("invalid enum discriminant"),
+                    }
+                }
+            }
+            #[repr(u8)]
+            #[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+            pub enum QuantifierKind {
+                All,
+                AtLeast,
+                Exactly,
+            }
+            impl ::core::fmt::Debug for QuantifierKind {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    match self {
+                        QuantifierKind::All => {
+                            f.debug_tuple("QuantifierKind::All").finish()
+                        }
+                        QuantifierKind::AtLeast => {
+                            f.debug_tuple("QuantifierKind::AtLeast").finish()
+                        }
+                        QuantifierKind::Exactly => {
+                            f.debug_tuple("QuantifierKind::Exactly").finish()
+                        }
+                    }
+                }
+            }
+            impl QuantifierKind {
+                #[doc(hidden)]
+                pub unsafe fn _lift(val: u8) -> QuantifierKind {
+                    if !cfg!(debug_assertions) {
+                        return ::core::mem::transmute(val);
+                    }
+                    match val {
+                        0 => QuantifierKind::All,
+                        1 => QuantifierKind::AtLeast,
+                        2 => QuantifierKind::Exactly,
                         _ => panic!("invalid enum discriminant"),
                     }
                 }
             }
+            /// Byte range in the source text, end-exclusive.
+            #[repr(C)]
+            #[derive(Clone, Copy)]
+            pub struct Span {
+                pub start: u32,
+                pub end: u32,
+            }
+            impl ::core::fmt::Debug for Span {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    f.debug_struct("Span")
+                        .field("start", &self.start)
+                        .field("end", &self.end)
+                        .finish()
+                }
+            }
+            /// PU? ZI? ZAhO? — at least one part is present.
+            #[repr(C)]
+            #[derive(Clone, Copy)]
+            pub struct Tense {
+                pub direction: Option<TimeDirection>,
+                pub distance: Option<TimeDistance>,
+                pub aspect: Option<Aspect>,
+            }
+            impl ::core::fmt::Debug for Tense {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    f.debug_struct("Tense")
+                        .field("direction", &self.direction)
+                        .field("distance", &self.distance)
+                        .field("aspect", &self.aspect)
+                        .finish()
+                }
+            }
+            /// ro → all; su'o N → at-least N (bare su'o: 1); PA → exactly N
+            /// (no: exactly 0). `count` is unused for `all`.
+            #[repr(C)]
+            #[derive(Clone, Copy)]
+            pub struct Quantifier {
+                pub kind: QuantifierKind,
+                pub count: u32,
+            }
+            impl ::core::fmt::Debug for Quantifier {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    f.debug_struct("Quantifier")
+                        .field("kind", &self.kind)
+                        .field("count", &self.count)
+                        .finish()
+                }
+            }
+            /// BAI cmavo (e.g. "ri'a") or ad-hoc fi'o + selbri.
+            #[derive(Clone)]
+            pub enum ModalTag {
+                Bai(_rt::String),
+                Fiho(SelbriId),
+            }
+            impl ::core::fmt::Debug for ModalTag {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    match self {
+                        ModalTag::Bai(e) => {
+                            f.debug_tuple("ModalTag::Bai").field(e).finish()
+                        }
+                        ModalTag::Fiho(e) => {
+                            f.debug_tuple("ModalTag::Fiho").field(e).finish()
+                        }
+                    }
+                }
+            }
+            /// se? GA nai? ... gi nai? ... — a connective with conversion and negation.
+            #[repr(C)]
+            #[derive(Clone, Copy)]
+            pub struct LogicalConnective {
+                pub connective: Connective,
+                pub converted: bool,
+                pub negate_left: bool,
+                pub negate_right: bool,
+            }
+            impl ::core::fmt::Debug for LogicalConnective {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    f.debug_struct("LogicalConnective")
+                        .field("connective", &self.connective)
+                        .field("converted", &self.converted)
+                        .field("negate-left", &self.negate_left)
+                        .field("negate-right", &self.negate_right)
+                        .finish()
+                }
+            }
+            /// poi/noi/voi attach a sentence (an index into `sentences`); goi
+            /// and the relative phrases pe/ne/po/po'e attach a sumti.
+            #[derive(Clone, Copy)]
+            pub enum RelClauseBody {
+                Sentence(u32),
+                Sumti(SumtiId),
+            }
+            impl ::core::fmt::Debug for RelClauseBody {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    match self {
+                        RelClauseBody::Sentence(e) => {
+                            f.debug_tuple("RelClauseBody::Sentence").field(e).finish()
+                        }
+                        RelClauseBody::Sumti(e) => {
+                            f.debug_tuple("RelClauseBody::Sumti").field(e).finish()
+                        }
+                    }
+                }
+            }
             #[repr(C)]
             #[derive(Clone, Copy)]
             pub struct RelClause {
                 pub kind: RelClauseKind,
-                pub body_sentence: u32,
+                pub body: RelClauseBody,
             }
             impl ::core::fmt::Debug for RelClause {
                 fn fmt(
@@ -384,19 +636,27 @@ pub mod lojban {
                 ) -> ::core::fmt::Result {
                     f.debug_struct("RelClause")
                         .field("kind", &self.kind)
-                        .field("body-sentence", &self.body_sentence)
+                        .field("body", &self.body)
                         .finish()
                 }
             }
             #[derive(Clone)]
             pub enum Sumti {
                 ProSumti(_rt::String),
-                Description((Gadri, SelbriId)),
+                /// ke'a xi N; 1 is the innermost relative clause
+                Relativized(u32),
+                /// gadri, inner quantifier ("lo ci gerku"), selbri
+                Description((Gadri, Option<Quantifier>, SelbriId)),
+                /// Outer quantifier on a description or da/de/di ("re lo gerku")
+                Quantified((Quantifier, SumtiId)),
                 Name(_rt::String),
                 QuotedLiteral(_rt::String),
                 Unspecified,
                 Tagged((PlaceTag, SumtiId)),
+                TenseTagged((Tense, SumtiId)),
+                ModalTagged((ModalTag, SumtiId)),
                 Restricted((SumtiId, RelClause)),
+                Connected((SumtiId, LogicalConnective, SumtiId)),
             }
             impl ::core::fmt::Debug for Sumti {
                 fn fmt(
@@ -407,9 +667,15 @@ pub mod lojban {
                         Sumti::ProSumti(e) => {
                             f.debug_tuple("Sumti::ProSumti").field(e).finish()
                         }
+                        Sumti::Relativized(e) => {
+                            f.debug_tuple("Sumti::Relativized").field(e).finish()
+                        }
                         Sumti::Description(e) => {
                             f.debug_tuple("Sumti::Description").field(e).finish()
                         }
+                        Sumti::Quantified(e) => {
+                            f.debug_tuple("Sumti::Quantified").field(e).finish()
+                        }
                         Sumti::Name(e) => f.debug_tuple("Sumti::Name").field(e).finish(),
                         Sumti::QuotedLiteral(e) => {
                             f.debug_tuple("Sumti::QuotedLiteral").field(e).finish()
@@ -420,9 +686,18 @@ pub mod lojban {
                         Sumti::Tagged(e) => {
                             f.debug_tuple("Sumti::Tagged").field(e).finish()
                         }
+                        Sumti::TenseTagged(e) => {
+                            f.debug_tuple("Sumti::TenseTagged").field(e).finish()
+                        }
+                        Sumti::ModalTagged(e) => {
+                            f.debug_tuple("Sumti::ModalTagged").field(e).finish()
+                        }
                         Sumti::Restricted(e) => {
                             f.debug_tuple("Sumti::Restricted").field(e).finish()
                         }
+                        Sumti::Connected(e) => {
+                            f.debug_tuple("Sumti::Connected").field(e).finish()
+                        }
                     }
                 }
             }
@@ -436,6 +711,8 @@ pub mod lojban {
                 Grouped(SelbriId),
                 WithArgs((SelbriId, _rt::Vec<SumtiId>)),
                 Connected((SelbriId, Connective, SelbriId)),
+                /// NU-class abstraction; the u32 indexes the body in `sentences`.
+                Abstraction((AbstractionKind, u32)),
             }
             impl ::core::fmt::Debug for Selbri {
                 fn fmt(
@@ -467,6 +744,9 @@ pub mod lojban {
                         Selbri::Connected(e) => {
                             f.debug_tuple("Selbri::Connected").field(e).finish()
                         }
+                        Selbri::Abstraction(e) => {
+                            f.debug_tuple("Selbri::Abstraction").field(e).finish()
+                        }
                     }
                 }
             }
@@ -476,6 +756,10 @@ pub mod lojban {
                 pub head_terms: _rt::Vec<SumtiId>,
                 pub tail_terms: _rt::Vec<SumtiId>,
                 pub negated: bool,
+                pub tense: Option<Tense>,
+                /// Marked with xu: a yes/no question rather than a claim.
+                pub question: bool,
+                pub span: Span,
             }
             impl ::core::fmt::Debug for Bridi {
                 fn fmt(
@@ -487,17 +771,72 @@ pub mod lojban {
                         .field("head-terms", &self.head_terms)
                         .field("tail-terms", &self.tail_terms)
                         .field("negated", &self.negated)
+                        .field("tense", &self.tense)
+                        .field("question", &self.question)
+                        .field("span", &self.span)
                         .finish()
                 }
             }
-            #[derive(Clone)]
-            pub struct AstBuffer {
-                pub selbris: _rt::Vec<Selbri>,
-                pub sumtis: _rt::Vec<Sumti>,
-                pub sentences: _rt::Vec<Bridi>,
-                /// Indices into `sentences` for top-level sentences only.
-                /// Rel clause bodies live in `sentences` but are NOT roots.
-                pub roots: _rt::Vec<u32>,
+            #[derive(Clone, Copy)]
+            pub enum PrenexTerm {
+                /// quantifier? + da/de/di; a bare variable is existential.
+                Variable((Option<Quantifier>, SumtiId)),
+                Naku,
+            }
+            impl ::core::fmt::Debug for PrenexTerm {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    match self {
+                        PrenexTerm::Variable(e) => {
+                            f.debug_tuple("PrenexTerm::Variable").field(e).finish()
+                        }
+                        PrenexTerm::Naku => f.debug_tuple("PrenexTerm::Naku").finish(),
+                    }
+                }
+            }
+            #[derive(Clone)]
+            pub enum Statement {
+                /// A single top-level bridi; the u32 indexes it in `sentences`.
+                Simple(u32),
+                /// Connected statements (GA ... gi, .i JA); the u32s index `statements`.
+                Connected((LogicalConnective, u32, u32)),
+                /// terms zo'u statement; the u32 indexes the body in `statements`.
+                Prenex((_rt::Vec<PrenexTerm>, u32)),
+            }
+            impl ::core::fmt::Debug for Statement {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    match self {
+                        Statement::Simple(e) => {
+                            f.debug_tuple("Statement::Simple").field(e).finish()
+                        }
+                        Statement::Connected(e) => {
+                            f.debug_tuple("Statement::Connected").field(e).finish()
+                        }
+                        Statement::Prenex(e) => {
+                            f.debug_tuple("Statement::Prenex").field(e).finish()
+                        }
+                    }
+                }
+            }
+            #[derive(Clone)]
+            pub struct AstBuffer {
+                pub selbris: _rt::Vec<Selbri>,
+                pub sumtis: _rt::Vec<Sumti>,
+                /// Source spans parallel to `selbris` and `sumtis`. Nested nodes
+                /// (description selbri, be/rel clause arguments, ...) share the span
+                /// of the term or selbri they belong to.
+                pub selbri_spans: _rt::Vec<Span>,
+                pub sumti_spans: _rt::Vec<Span>,
+                pub sentences: _rt::Vec<Bridi>,
+                pub statements: _rt::Vec<Statement>,
+                /// Indices into `statements` for top-level statements only.
+                /// Rel clause and abstraction bodies live in `sentences` but are NOT roots.
+                pub roots: _rt::Vec<u32>,
             }
             impl ::core::fmt::Debug for AstBuffer {
                 fn fmt(
@@ -507,7 +846,10 @@ pub mod lojban {
                     f.debug_struct("AstBuffer")
                         .field("selbris", &self.selbris)
                         .field("sumtis", &self.sumtis)
+                        .field("selbri-spans", &self.selbri_spans)
+                        .field("sumti-spans", &self.sumti_spans)
                         .field("sentences", &self.sentences)
+                        .field("statements", &self.statements)
                         .field("roots", &self.roots)
                         .finish()
                 }
@@ -519,6 +861,8 @@ pub mod lojban {
                 Constant(_rt::String),
                 Description(_rt::String),
                 Unspecified,
+                /// Reified proposition; the u32 indexes its formula in `nodes`.
+                Proposition(u32),
             }
             impl ::core::fmt::Debug for LogicalTerm {
                 fn fmt(
@@ -538,6 +882,9 @@ pub mod lojban {
                         LogicalTerm::Unspecified => {
                             f.debug_tuple("LogicalTerm::Unspecified").finish()
                         }
+                        LogicalTerm::Proposition(e) => {
+                            f.debug_tuple("LogicalTerm::Proposition").field(e).finish()
+                        }
                     }
                 }
             }
@@ -546,9 +893,15 @@ pub mod lojban {
                 Predicate((_rt::String, _rt::Vec<LogicalTerm>)),
                 AndNode((u32, u32)),
                 OrNode((u32, u32)),
+                ImpliesNode((u32, u32)),
                 NotNode(u32),
                 ExistsNode((_rt::String, u32)),
                 ForAllNode((_rt::String, u32)),
+                /// Time-indexed formula: the body holds at the given interval.
+                AtNode((LogicalTerm, u32)),
+                /// Defeasible generic (lo'e): variable, restrictor, body. Each x
+                /// meeting the restrictor meets the body unless that is contradicted.
+                GenericNode((_rt::String, u32, u32)),
             }
             impl ::core::fmt::Debug for LogicNode {
                 fn fmt(
@@ -565,6 +918,9 @@ pub mod lojban {
                         LogicNode::OrNode(e) => {
                             f.debug_tuple("LogicNode::OrNode").field(e).finish()
                         }
+                        LogicNode::ImpliesNode(e) => {
+                            f.debug_tuple("LogicNode::ImpliesNode").field(e).finish()
+                        }
                         LogicNode::NotNode(e) => {
                             f.debug_tuple("LogicNode::NotNode").field(e).finish()
                         }
@@ -574,6 +930,12 @@ pub mod lojban {
                         LogicNode::ForAllNode(e) => {
                             f.debug_tuple("LogicNode::ForAllNode").field(e).finish()
                         }
+                        LogicNode::AtNode(e) => {
+                            f.debug_tuple("LogicNode::AtNode").field(e).finish()
+                        }
+                        LogicNode::GenericNode(e) => {
+                            f.debug_tuple("LogicNode::GenericNode").field(e).finish()
+                        }
                     }
                 }
             }
@@ -601,18 +963,91 @@ pub mod lojban {
             static __FORCE_SECTION_REF: fn() = super::super::super::__link_custom_section_describing_imports;
             use super::super::super::_rt;
             pub type AstBuffer = super::super::super::lojban::nesy::ast_types::AstBuffer;
+            pub type Span = super::super::super::lojban::nesy::ast_types::Span;
+            /// What to do with input that is not Lojban (unknown characters,
+            /// malformed words).
+            #[repr(u8)]
+            #[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+            pub enum LexMode {
+                /// Report the first invalid segment and parse nothing.
+                Strict,
+                /// Report and skip every invalid segment, parsing the rest.
+                Lenient,
+            }
+            impl ::core::fmt::Debug for LexMode {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    match self {
+                        LexMode::Strict => f.debug_tuple("LexMode::Strict").finish(),
+                        LexMode::Lenient => f.debug_tuple("LexMode::Lenient").finish(),
+                    }
+                }
+            }
+            impl LexMode {
+                #[doc(hidden)]
+                pub unsafe fn _lift(val: u8) -> LexMode {
+                    if !cfg!(debug_assertions) {
+                        return ::core::mem::transmute(val);
+                    }
+                    match val {
+                        0 => LexMode::Strict,
+                        1 => LexMode::Lenient,
+                        _ => panic!("invalid enum discriminant"),
+                    }
+                }
+            }
+            /// A lex or parse error; `message` is rendered with line, column and a
+            /// caret snippet of the offending source.
+            #[derive(Clone)]
+            pub struct Diagnostic {
+                pub message: _rt::String,
+                pub span: Span,
+            }
+            impl ::core::fmt::Debug for Diagnostic {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    f.debug_struct("Diagnostic")
+                        .field("message", &self.message)
+                        .field("span", &self.span)
+                        .finish()
+                }
+            }
+            /// Every statement that parsed, plus one diagnostic per invalid
+            /// segment the lexer reported and per region the parser skipped (up
+            /// to the next `.i` or `ni'o`), in source order. Empty diagnostics
+            /// means the whole input parsed.
+            #[derive(Clone)]
+            pub struct ParseResult {
+                pub ast: AstBuffer,
+                pub diagnostics: _rt::Vec<Diagnostic>,
+            }
+            impl ::core::fmt::Debug for ParseResult {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    f.debug_struct("ParseResult")
+                        .field("ast", &self.ast)
+                        .field("diagnostics", &self.diagnostics)
+                        .finish()
+                }
+            }
             #[allow(unused_unsafe, clippy::all)]
-            pub fn parse_text(input: &str) -> Result<AstBuffer, _rt::String> {
+            pub fn parse_text(input: &str, mode: LexMode) -> ParseResult {
                 unsafe {
                     #[cfg_attr(target_pointer_width = "64", repr(align(8)))]
                     #[cfg_attr(target_pointer_width = "32", repr(align(4)))]
                     struct RetArea(
                         [::core::mem::MaybeUninit<
                             u8,
-                        >; 9 * ::core::mem::size_of::<*const u8>()],
+                        >; 16 * ::core::mem::size_of::<*const u8>()],
                     );
                     let mut ret_area = RetArea(
-                        [::core::mem::MaybeUninit::uninit(); 9
+                        [::core::mem::MaybeUninit::uninit(); 16
                             * ::core::mem::size_of::<*const u8>()],
                     );
                     let vec0 = input;
@@ -623,422 +1058,972 @@ pub mod lojban {
                     #[link(wasm_import_module = "lojban:nesy/parser@0.1.0")]
                     unsafe extern "C" {
                         #[link_name = "parse-text"]
-                        fn wit_import2(_: *mut u8, _: usize, _: *mut u8);
+                        fn wit_import2(_: *mut u8, _: usize, _: i32, _: *mut u8);
                     }
                     #[cfg(not(target_arch = "wasm32"))]
-                    unsafe extern "C" fn wit_import2(_: *mut u8, _: usize, _: *mut u8) {
+                    unsafe extern "C" fn wit_import2(
+                        _: *mut u8,
+                        _: usize,
+                        _: i32,
+                        _: *mut u8,
+                    ) {
                         unreachable!()
                     }
-                    unsafe { wit_import2(ptr0.cast_mut(), len0, ptr1) };
-                    let l3 = i32::from(*ptr1.add(0).cast::<u8>());
-                    let result69 = match l3 {
-                        0 => {
-                            let e = {
-                                let l4 = *ptr1
-                                    .add(::core::mem::size_of::<*const u8>())
-                                    .cast::<*mut u8>();
-                                let l5 = *ptr1
-                                    .add(2 * ::core::mem::size_of::<*const u8>())
-                                    .cast::<usize>();
-                                let base30 = l4;
-                                let len30 = l5;
-                                let mut result30 = _rt::Vec::with_capacity(len30);
-                                for i in 0..len30 {
-                                    let base = base30
-                                        .add(i * (4 * ::core::mem::size_of::<*const u8>()));
+                    unsafe {
+                        wit_import2(ptr0.cast_mut(), len0, mode.clone() as i32, ptr1)
+                    };
+                    let l3 = *ptr1.add(0).cast::<*mut u8>();
+                    let l4 = *ptr1
+                        .add(::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base31 = l3;
+                    let len31 = l4;
+                    let mut result31 = _rt::Vec::with_capacity(len31);
+                    for i in 0..len31 {
+                        let base = base31
+                            .add(i * (4 * ::core::mem::size_of::<*const u8>()));
+                        let e31 = {
+                            let l5 = i32::from(*base.add(0).cast::<u8>());
+                            use super::super::super::lojban::nesy::ast_types::Selbri as V30;
+                            let v30 = match l5 {
+                                0 => {
+                                    let e30 = {
+                                        let l6 = *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<*mut u8>();
+                                        let l7 = *base
+                                            .add(2 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<usize>();
+                                        let len8 = l7;
+                                        let bytes8 = _rt::Vec::from_raw_parts(
+                                            l6.cast(),
+                                            len8,
+                                            len8,
+                                        );
+                                        _rt::string_lift(bytes8)
+                                    };
+                                    V30::Root(e30)
+                                }
+                                1 => {
+                                    let e30 = {
+                                        let l9 = *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<*mut u8>();
+                                        let l10 = *base
+                                            .add(2 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<usize>();
+                                        let base14 = l9;
+                                        let len14 = l10;
+                                        let mut result14 = _rt::Vec::with_capacity(len14);
+                                        for i in 0..len14 {
+                                            let base = base14
+                                                .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                                            let e14 = {
+                                                let l11 = *base.add(0).cast::<*mut u8>();
+                                                let l12 = *base
+                                                    .add(::core::mem::size_of::<*const u8>())
+                                                    .cast::<usize>();
+                                                let len13 = l12;
+                                                let bytes13 = _rt::Vec::from_raw_parts(
+                                                    l11.cast(),
+                                                    len13,
+                                                    len13,
+                                                );
+                                                _rt::string_lift(bytes13)
+                                            };
+                                            result14.push(e14);
+                                        }
+                                        _rt::cabi_dealloc(
+                                            base14,
+                                            len14 * (2 * ::core::mem::size_of::<*const u8>()),
+                                            ::core::mem::size_of::<*const u8>(),
+                                        );
+                                        result14
+                                    };
+                                    V30::Compound(e30)
+                                }
+                                2 => {
+                                    let e30 = {
+                                        let l15 = *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        let l16 = *base
+                                            .add(4 + 1 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        (l15 as u32, l16 as u32)
+                                    };
+                                    V30::Tanru(e30)
+                                }
+                                3 => {
+                                    let e30 = {
+                                        let l17 = i32::from(
+                                            *base.add(::core::mem::size_of::<*const u8>()).cast::<u8>(),
+                                        );
+                                        let l18 = *base
+                                            .add(4 + 1 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        (
+                                            super::super::super::lojban::nesy::ast_types::Conversion::_lift(
+                                                l17 as u8,
+                                            ),
+                                            l18 as u32,
+                                        )
+                                    };
+                                    V30::Converted(e30)
+                                }
+                                4 => {
+                                    let e30 = {
+                                        let l19 = *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        l19 as u32
+                                    };
+                                    V30::Negated(e30)
+                                }
+                                5 => {
+                                    let e30 = {
+                                        let l20 = *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        l20 as u32
+                                    };
+                                    V30::Grouped(e30)
+                                }
+                                6 => {
+                                    let e30 = {
+                                        let l21 = *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        let l22 = *base
+                                            .add(2 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<*mut u8>();
+                                        let l23 = *base
+                                            .add(3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<usize>();
+                                        let len24 = l23;
+                                        (
+                                            l21 as u32,
+                                            _rt::Vec::from_raw_parts(l22.cast(), len24, len24),
+                                        )
+                                    };
+                                    V30::WithArgs(e30)
+                                }
+                                7 => {
+                                    let e30 = {
+                                        let l25 = *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        let l26 = i32::from(
+                                            *base
+                                                .add(4 + 1 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>(),
+                                        );
+                                        let l27 = *base
+                                            .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        (
+                                            l25 as u32,
+                                            super::super::super::lojban::nesy::ast_types::Connective::_lift(
+                                                l26 as u8,
+                                            ),
+                                            l27 as u32,
+                                        )
+                                    };
+                                    V30::Connected(e30)
+                                }
+                                n => {
+                                    debug_assert_eq!(n, 8, "invalid enum discriminant");
                                     let e30 = {
-                                        let l6 = i32::from(*base.add(0).cast::<u8>());
-                                        use super::super::super::lojban::nesy::ast_types::Selbri as V29;
-                                        let v29 = match l6 {
+                                        let l28 = i32::from(
+                                            *base.add(::core::mem::size_of::<*const u8>()).cast::<u8>(),
+                                        );
+                                        let l29 = *base
+                                            .add(4 + 1 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        (
+                                            super::super::super::lojban::nesy::ast_types::AbstractionKind::_lift(
+                                                l28 as u8,
+                                            ),
+                                            l29 as u32,
+                                        )
+                                    };
+                                    V30::Abstraction(e30)
+                                }
+                            };
+                            v30
+                        };
+                        result31.push(e31);
+                    }
+                    _rt::cabi_dealloc(
+                        base31,
+                        len31 * (4 * ::core::mem::size_of::<*const u8>()),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let l32 = *ptr1
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l33 = *ptr1
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base82 = l32;
+                    let len82 = l33;
+                    let mut result82 = _rt::Vec::with_capacity(len82);
+                    for i in 0..len82 {
+                        let base = base82
+                            .add(i * (8 + 4 * ::core::mem::size_of::<*const u8>()));
+                        let e82 = {
+                            let l34 = i32::from(*base.add(0).cast::<u8>());
+                            use super::super::super::lojban::nesy::ast_types::Sumti as V81;
+                            let v81 = match l34 {
+                                0 => {
+                                    let e81 = {
+                                        let l35 = *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<*mut u8>();
+                                        let l36 = *base
+                                            .add(2 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<usize>();
+                                        let len37 = l36;
+                                        let bytes37 = _rt::Vec::from_raw_parts(
+                                            l35.cast(),
+                                            len37,
+                                            len37,
+                                        );
+                                        _rt::string_lift(bytes37)
+                                    };
+                                    V81::ProSumti(e81)
+                                }
+                                1 => {
+                                    let e81 = {
+                                        let l38 = *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        l38 as u32
+                                    };
+                                    V81::Relativized(e81)
+                                }
+                                2 => {
+                                    let e81 = {
+                                        let l39 = i32::from(
+                                            *base.add(::core::mem::size_of::<*const u8>()).cast::<u8>(),
+                                        );
+                                        let l40 = i32::from(
+                                            *base
+                                                .add(4 + 1 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>(),
+                                        );
+                                        let l43 = *base
+                                            .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        (
+                                            super::super::super::lojban::nesy::ast_types::Gadri::_lift(
+                                                l39 as u8,
+                                            ),
+                                            match l40 {
+                                                0 => None,
+                                                1 => {
+                                                    let e = {
+                                                        let l41 = i32::from(
+                                                            *base
+                                                                .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                                                .cast::<u8>(),
+                                                        );
+                                                        let l42 = *base
+                                                            .add(12 + 1 * ::core::mem::size_of::<*const u8>())
+                                                            .cast::<i32>();
+                                                        super::super::super::lojban::nesy::ast_types::Quantifier {
+                                                            kind: super::super::super::lojban::nesy::ast_types::QuantifierKind::_lift(
+                                                                l41 as u8,
+                                                            ),
+                                                            count: l42 as u32,
+                                                        }
+                                                    };
+                                                    Some(e)
+                                                }
+                                                _ => _rt::invalid_enum_discriminant(),
+                                            },
+                                            l43 as u32,
+                                        )
+                                    };
+                                    V81::Description(e81)
+                                }
+                                3 => {
+                                    let e81 = {
+                                        let l44 = i32::from(
+                                            *base.add(::core::mem::size_of::<*const u8>()).cast::<u8>(),
+                                        );
+                                        let l45 = *base
+                                            .add(4 + 1 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        let l46 = *base
+                                            .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        (
+                                            super::super::super::lojban::nesy::ast_types::Quantifier {
+                                                kind: super::super::super::lojban::nesy::ast_types::QuantifierKind::_lift(
+                                                    l44 as u8,
+                                                ),
+                                                count: l45 as u32,
+                                            },
+                                            l46 as u32,
+                                        )
+                                    };
+                                    V81::Quantified(e81)
+                                }
+                                4 => {
+                                    let e81 = {
+                                        let l47 = *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<*mut u8>();
+                                        let l48 = *base
+                                            .add(2 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<usize>();
+                                        let len49 = l48;
+                                        let bytes49 = _rt::Vec::from_raw_parts(
+                                            l47.cast(),
+                                            len49,
+                                            len49,
+                                        );
+                                        _rt::string_lift(bytes49)
+                                    };
+                                    V81::Name(e81)
+                                }
+                                5 => {
+                                    let e81 = {
+                                        let l50 = *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<*mut u8>();
+                                        let l51 = *base
+                                            .add(2 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<usize>();
+                                        let len52 = l51;
+                                        let bytes52 = _rt::Vec::from_raw_parts(
+                                            l50.cast(),
+                                            len52,
+                                            len52,
+                                        );
+                                        _rt::string_lift(bytes52)
+                                    };
+                                    V81::QuotedLiteral(e81)
+                                }
+                                6 => V81::Unspecified,
+                                7 => {
+                                    let e81 = {
+                                        let l53 = i32::from(
+                                            *base.add(::core::mem::size_of::<*const u8>()).cast::<u8>(),
+                                        );
+                                        let l54 = *base
+                                            .add(4 + 1 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        (
+                                            super::super::super::lojban::nesy::ast_types::PlaceTag::_lift(
+                                                l53 as u8,
+                                            ),
+                                            l54 as u32,
+                                        )
+                                    };
+                                    V81::Tagged(e81)
+                                }
+                                8 => {
+                                    let e81 = {
+                                        let l55 = i32::from(
+                                            *base.add(::core::mem::size_of::<*const u8>()).cast::<u8>(),
+                                        );
+                                        let l57 = i32::from(
+                                            *base
+                                                .add(2 + 1 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>(),
+                                        );
+                                        let l59 = i32::from(
+                                            *base
+                                                .add(4 + 1 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>(),
+                                        );
+                                        let l61 = *base
+                                            .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        (
+                                            super::super::super::lojban::nesy::ast_types::Tense {
+                                                direction: match l55 {
+                                                    0 => None,
+                                                    1 => {
+                                                        let e = {
+                                                            let l56 = i32::from(
+                                                                *base
+                                                                    .add(1 + 1 * ::core::mem::size_of::<*const u8>())
+                                                                    .cast::<u8>(),
+                                                            );
+                                                            super::super::super::lojban::nesy::ast_types::TimeDirection::_lift(
+                                                                l56 as u8,
+                                                            )
+                                                        };
+                                                        Some(e)
+                                                    }
+                                                    _ => _rt::invalid_enum_discriminant(),
+                                                },
+                                                distance: match l57 {
+                                                    0 => None,
+                                                    1 => {
+                                                        let e = {
+                                                            let l58 = i32::from(
+                                                                *base
+                                                                    .add(3 + 1 * ::core::mem::size_of::<*const u8>())
+                                                                    .cast::<u8>(),
+                                                            );
+                                                            super::super::super::lojban::nesy::ast_types::TimeDistance::_lift(
+                                                                l58 as u8,
+                                                            )
+                                                        };
+                                                        Some(e)
+                                                    }
+                                                    _ => _rt::invalid_enum_discriminant(),
+                                                },
+                                                aspect: match l59 {
+                                                    0 => None,
+                                                    1 => {
+                                                        let e = {
+                                                            let l60 = i32::from(
+                                                                *base
+                                                                    .add(5 + 1 * ::core::mem::size_of::<*const u8>())
+                                                                    .cast::<u8>(),
+                                                            );
+                                                            super::super::super::lojban::nesy::ast_types::Aspect::_lift(
+                                                                l60 as u8,
+                                                            )
+                                                        };
+                                                        Some(e)
+                                                    }
+                                                    _ => _rt::invalid_enum_discriminant(),
+                                                },
+                                            },
+                                            l61 as u32,
+                                        )
+                                    };
+                                    V81::TenseTagged(e81)
+                                }
+                                9 => {
+                                    let e81 = {
+                                        let l62 = i32::from(
+                                            *base.add(::core::mem::size_of::<*const u8>()).cast::<u8>(),
+                                        );
+                                        use super::super::super::lojban::nesy::ast_types::ModalTag as V67;
+                                        let v67 = match l62 {
                                             0 => {
-                                                let e29 = {
-                                                    let l7 = *base
-                                                        .add(::core::mem::size_of::<*const u8>())
-                                                        .cast::<*mut u8>();
-                                                    let l8 = *base
+                                                let e67 = {
+                                                    let l63 = *base
                                                         .add(2 * ::core::mem::size_of::<*const u8>())
-                                                        .cast::<usize>();
-                                                    let len9 = l8;
-                                                    let bytes9 = _rt::Vec::from_raw_parts(
-                                                        l7.cast(),
-                                                        len9,
-                                                        len9,
-                                                    );
-                                                    _rt::string_lift(bytes9)
-                                                };
-                                                V29::Root(e29)
-                                            }
-                                            1 => {
-                                                let e29 = {
-                                                    let l10 = *base
-                                                        .add(::core::mem::size_of::<*const u8>())
                                                         .cast::<*mut u8>();
-                                                    let l11 = *base
-                                                        .add(2 * ::core::mem::size_of::<*const u8>())
+                                                    let l64 = *base
+                                                        .add(3 * ::core::mem::size_of::<*const u8>())
                                                         .cast::<usize>();
-                                                    let base15 = l10;
-                                                    let len15 = l11;
-                                                    let mut result15 = _rt::Vec::with_capacity(len15);
-                                                    for i in 0..len15 {
-                                                        let base = base15
-                                                            .add(i * (2 * ::core::mem::size_of::<*const u8>()));
-                                                        let e15 = {
-                                                            let l12 = *base.add(0).cast::<*mut u8>();
-                                                            let l13 = *base
-                                                                .add(::core::mem::size_of::<*const u8>())
-                                                                .cast::<usize>();
-                                                            let len14 = l13;
-                                                            let bytes14 = _rt::Vec::from_raw_parts(
-                                                                l12.cast(),
-                                                                len14,
-                                                                len14,
-                                                            );
-                                                            _rt::string_lift(bytes14)
-                                                        };
-                                                        result15.push(e15);
-                                                    }
-                                                    _rt::cabi_dealloc(
-                                                        base15,
-                                                        len15 * (2 * ::core::mem::size_of::<*const u8>()),
-                                                        ::core::mem::size_of::<*const u8>(),
-                                                    );
-                                                    result15
-                                                };
-                                                V29::Compound(e29)
-                                            }
-                                            2 => {
-                                                let e29 = {
-                                                    let l16 = *base
-                                                        .add(::core::mem::size_of::<*const u8>())
-                                                        .cast::<i32>();
-                                                    let l17 = *base
-                                                        .add(4 + 1 * ::core::mem::size_of::<*const u8>())
-                                                        .cast::<i32>();
-                                                    (l16 as u32, l17 as u32)
-                                                };
-                                                V29::Tanru(e29)
-                                            }
-                                            3 => {
-                                                let e29 = {
-                                                    let l18 = i32::from(
-                                                        *base.add(::core::mem::size_of::<*const u8>()).cast::<u8>(),
+                                                    let len65 = l64;
+                                                    let bytes65 = _rt::Vec::from_raw_parts(
+                                                        l63.cast(),
+                                                        len65,
+                                                        len65,
                                                     );
-                                                    let l19 = *base
-                                                        .add(4 + 1 * ::core::mem::size_of::<*const u8>())
-                                                        .cast::<i32>();
-                                                    (
-                                                        super::super::super::lojban::nesy::ast_types::Conversion::_lift(
-                                                            l18 as u8,
-                                                        ),
-                                                        l19 as u32,
-                                                    )
-                                                };
-                                                V29::Converted(e29)
-                                            }
-                                            4 => {
-                                                let e29 = {
-                                                    let l20 = *base
-                                                        .add(::core::mem::size_of::<*const u8>())
-                                                        .cast::<i32>();
-                                                    l20 as u32
+                                                    _rt::string_lift(bytes65)
                                                 };
-                                                V29::Negated(e29)
+                                                V67::Bai(e67)
                                             }
-                                            5 => {
-                                                let e29 = {
-                                                    let l21 = *base
-                                                        .add(::core::mem::size_of::<*const u8>())
+                                            n => {
+                                                debug_assert_eq!(n, 1, "invalid enum discriminant");
+                                                let e67 = {
+                                                    let l66 = *base
+                                                        .add(2 * ::core::mem::size_of::<*const u8>())
                                                         .cast::<i32>();
-                                                    l21 as u32
+                                                    l66 as u32
                                                 };
-                                                V29::Grouped(e29)
+                                                V67::Fiho(e67)
                                             }
-                                            6 => {
-                                                let e29 = {
-                                                    let l22 = *base
-                                                        .add(::core::mem::size_of::<*const u8>())
+                                        };
+                                        let l68 = *base
+                                            .add(4 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        (v67, l68 as u32)
+                                    };
+                                    V81::ModalTagged(e81)
+                                }
+                                10 => {
+                                    let e81 = {
+                                        let l69 = *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        let l70 = i32::from(
+                                            *base
+                                                .add(4 + 1 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>(),
+                                        );
+                                        let l71 = i32::from(
+                                            *base
+                                                .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>(),
+                                        );
+                                        use super::super::super::lojban::nesy::ast_types::RelClauseBody as V74;
+                                        let v74 = match l71 {
+                                            0 => {
+                                                let e74 = {
+                                                    let l72 = *base
+                                                        .add(12 + 1 * ::core::mem::size_of::<*const u8>())
                                                         .cast::<i32>();
-                                                    let l23 = *base
-                                                        .add(2 * ::core::mem::size_of::<*const u8>())
-                                                        .cast::<*mut u8>();
-                                                    let l24 = *base
-                                                        .add(3 * ::core::mem::size_of::<*const u8>())
-                                                        .cast::<usize>();
-                                                    let len25 = l24;
-                                                    (
-                                                        l22 as u32,
-                                                        _rt::Vec::from_raw_parts(l23.cast(), len25, len25),
-                                                    )
+                                                    l72 as u32
                                                 };
-                                                V29::WithArgs(e29)
+                                                V74::Sentence(e74)
                                             }
                                             n => {
-                                                debug_assert_eq!(n, 7, "invalid enum discriminant");
-                                                let e29 = {
-                                                    let l26 = *base
-                                                        .add(::core::mem::size_of::<*const u8>())
-                                                        .cast::<i32>();
-                                                    let l27 = i32::from(
-                                                        *base
-                                                            .add(4 + 1 * ::core::mem::size_of::<*const u8>())
-                                                            .cast::<u8>(),
-                                                    );
-                                                    let l28 = *base
-                                                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                                debug_assert_eq!(n, 1, "invalid enum discriminant");
+                                                let e74 = {
+                                                    let l73 = *base
+                                                        .add(12 + 1 * ::core::mem::size_of::<*const u8>())
                                                         .cast::<i32>();
-                                                    (
-                                                        l26 as u32,
-                                                        super::super::super::lojban::nesy::ast_types::Connective::_lift(
-                                                            l27 as u8,
-                                                        ),
-                                                        l28 as u32,
-                                                    )
+                                                    l73 as u32
                                                 };
-                                                V29::Connected(e29)
+                                                V74::Sumti(e74)
                                             }
                                         };
-                                        v29
+                                        (
+                                            l69 as u32,
+                                            super::super::super::lojban::nesy::ast_types::RelClause {
+                                                kind: super::super::super::lojban::nesy::ast_types::RelClauseKind::_lift(
+                                                    l70 as u8,
+                                                ),
+                                                body: v74,
+                                            },
+                                        )
                                     };
-                                    result30.push(e30);
+                                    V81::Restricted(e81)
                                 }
-                                _rt::cabi_dealloc(
-                                    base30,
-                                    len30 * (4 * ::core::mem::size_of::<*const u8>()),
-                                    ::core::mem::size_of::<*const u8>(),
-                                );
-                                let l31 = *ptr1
-                                    .add(3 * ::core::mem::size_of::<*const u8>())
-                                    .cast::<*mut u8>();
-                                let l32 = *ptr1
-                                    .add(4 * ::core::mem::size_of::<*const u8>())
-                                    .cast::<usize>();
-                                let base51 = l31;
-                                let len51 = l32;
-                                let mut result51 = _rt::Vec::with_capacity(len51);
-                                for i in 0..len51 {
-                                    let base = base51
-                                        .add(i * (8 + 2 * ::core::mem::size_of::<*const u8>()));
-                                    let e51 = {
-                                        let l33 = i32::from(*base.add(0).cast::<u8>());
-                                        use super::super::super::lojban::nesy::ast_types::Sumti as V50;
-                                        let v50 = match l33 {
-                                            0 => {
-                                                let e50 = {
-                                                    let l34 = *base
-                                                        .add(::core::mem::size_of::<*const u8>())
-                                                        .cast::<*mut u8>();
-                                                    let l35 = *base
-                                                        .add(2 * ::core::mem::size_of::<*const u8>())
-                                                        .cast::<usize>();
-                                                    let len36 = l35;
-                                                    let bytes36 = _rt::Vec::from_raw_parts(
-                                                        l34.cast(),
-                                                        len36,
-                                                        len36,
-                                                    );
-                                                    _rt::string_lift(bytes36)
-                                                };
-                                                V50::ProSumti(e50)
-                                            }
-                                            1 => {
-                                                let e50 = {
-                                                    let l37 = i32::from(
-                                                        *base.add(::core::mem::size_of::<*const u8>()).cast::<u8>(),
-                                                    );
-                                                    let l38 = *base
-                                                        .add(4 + 1 * ::core::mem::size_of::<*const u8>())
-                                                        .cast::<i32>();
-                                                    (
-                                                        super::super::super::lojban::nesy::ast_types::Gadri::_lift(
-                                                            l37 as u8,
-                                                        ),
-                                                        l38 as u32,
-                                                    )
-                                                };
-                                                V50::Description(e50)
-                                            }
-                                            2 => {
-                                                let e50 = {
-                                                    let l39 = *base
-                                                        .add(::core::mem::size_of::<*const u8>())
-                                                        .cast::<*mut u8>();
-                                                    let l40 = *base
-                                                        .add(2 * ::core::mem::size_of::<*const u8>())
-                                                        .cast::<usize>();
-                                                    let len41 = l40;
-                                                    let bytes41 = _rt::Vec::from_raw_parts(
-                                                        l39.cast(),
-                                                        len41,
-                                                        len41,
-                                                    );
-                                                    _rt::string_lift(bytes41)
-                                                };
-                                                V50::Name(e50)
-                                            }
-                                            3 => {
-                                                let e50 = {
-                                                    let l42 = *base
-                                                        .add(::core::mem::size_of::<*const u8>())
-                                                        .cast::<*mut u8>();
-                                                    let l43 = *base
-                                                        .add(2 * ::core::mem::size_of::<*const u8>())
-                                                        .cast::<usize>();
-                                                    let len44 = l43;
-                                                    let bytes44 = _rt::Vec::from_raw_parts(
-                                                        l42.cast(),
-                                                        len44,
-                                                        len44,
-                                                    );
-                                                    _rt::string_lift(bytes44)
-                                                };
-                                                V50::QuotedLiteral(e50)
-                                            }
-                                            4 => V50::Unspecified,
-                                            5 => {
-                                                let e50 = {
-                                                    let l45 = i32::from(
-                                                        *base.add(::core::mem::size_of::<*const u8>()).cast::<u8>(),
-                                                    );
-                                                    let l46 = *base
-                                                        .add(4 + 1 * ::core::mem::size_of::<*const u8>())
-                                                        .cast::<i32>();
-                                                    (
-                                                        super::super::super::lojban::nesy::ast_types::PlaceTag::_lift(
-                                                            l45 as u8,
-                                                        ),
-                                                        l46 as u32,
-                                                    )
-                                                };
-                                                V50::Tagged(e50)
-                                            }
-                                            n => {
-                                                debug_assert_eq!(n, 6, "invalid enum discriminant");
-                                                let e50 = {
-                                                    let l47 = *base
-                                                        .add(::core::mem::size_of::<*const u8>())
-                                                        .cast::<i32>();
-                                                    let l48 = i32::from(
-                                                        *base
-                                                            .add(4 + 1 * ::core::mem::size_of::<*const u8>())
-                                                            .cast::<u8>(),
-                                                    );
-                                                    let l49 = *base
-                                                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
-                                                        .cast::<i32>();
-                                                    (
-                                                        l47 as u32,
-                                                        super::super::super::lojban::nesy::ast_types::RelClause {
-                                                            kind: super::super::super::lojban::nesy::ast_types::RelClauseKind::_lift(
-                                                                l48 as u8,
-                                                            ),
-                                                            body_sentence: l49 as u32,
-                                                        },
-                                                    )
-                                                };
-                                                V50::Restricted(e50)
+                                n => {
+                                    debug_assert_eq!(n, 11, "invalid enum discriminant");
+                                    let e81 = {
+                                        let l75 = *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        let l76 = i32::from(
+                                            *base
+                                                .add(4 + 1 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>(),
+                                        );
+                                        let l77 = i32::from(
+                                            *base
+                                                .add(5 + 1 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>(),
+                                        );
+                                        let l78 = i32::from(
+                                            *base
+                                                .add(6 + 1 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>(),
+                                        );
+                                        let l79 = i32::from(
+                                            *base
+                                                .add(7 + 1 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>(),
+                                        );
+                                        let l80 = *base
+                                            .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        (
+                                            l75 as u32,
+                                            super::super::super::lojban::nesy::ast_types::LogicalConnective {
+                                                connective: super::super::super::lojban::nesy::ast_types::Connective::_lift(
+                                                    l76 as u8,
+                                                ),
+                                                converted: _rt::bool_lift(l77 as u8),
+                                                negate_left: _rt::bool_lift(l78 as u8),
+                                                negate_right: _rt::bool_lift(l79 as u8),
+                                            },
+                                            l80 as u32,
+                                        )
+                                    };
+                                    V81::Connected(e81)
+                                }
+                            };
+                            v81
+                        };
+                        result82.push(e82);
+                    }
+                    _rt::cabi_dealloc(
+                        base82,
+                        len82 * (8 + 4 * ::core::mem::size_of::<*const u8>()),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let l83 = *ptr1
+                        .add(4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l84 = *ptr1
+                        .add(5 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let len85 = l84;
+                    let l86 = *ptr1
+                        .add(6 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l87 = *ptr1
+                        .add(7 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let len88 = l87;
+                    let l89 = *ptr1
+                        .add(8 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l90 = *ptr1
+                        .add(9 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base109 = l89;
+                    let len109 = l90;
+                    let mut result109 = _rt::Vec::with_capacity(len109);
+                    for i in 0..len109 {
+                        let base = base109
+                            .add(i * (16 + 6 * ::core::mem::size_of::<*const u8>()));
+                        let e109 = {
+                            let l91 = *base.add(0).cast::<i32>();
+                            let l92 = *base
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l93 = *base
+                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            let len94 = l93;
+                            let l95 = *base
+                                .add(3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l96 = *base
+                                .add(4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            let len97 = l96;
+                            let l98 = i32::from(
+                                *base
+                                    .add(5 * ::core::mem::size_of::<*const u8>())
+                                    .cast::<u8>(),
+                            );
+                            let l99 = i32::from(
+                                *base
+                                    .add(1 + 5 * ::core::mem::size_of::<*const u8>())
+                                    .cast::<u8>(),
+                            );
+                            let l106 = i32::from(
+                                *base
+                                    .add(8 + 5 * ::core::mem::size_of::<*const u8>())
+                                    .cast::<u8>(),
+                            );
+                            let l107 = *base
+                                .add(12 + 5 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>();
+                            let l108 = *base
+                                .add(16 + 5 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>();
+                            super::super::super::lojban::nesy::ast_types::Bridi {
+                                relation: l91 as u32,
+                                head_terms: _rt::Vec::from_raw_parts(
+                                    l92.cast(),
+                                    len94,
+                                    len94,
+                                ),
+                                tail_terms: _rt::Vec::from_raw_parts(
+                                    l95.cast(),
+                                    len97,
+                                    len97,
+                                ),
+                                negated: _rt::bool_lift(l98 as u8),
+                                tense: match l99 {
+                                    0 => None,
+                                    1 => {
+                                        let e = {
+                                            let l100 = i32::from(
+                                                *base
+                                                    .add(2 + 5 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<u8>(),
+                                            );
+                                            let l102 = i32::from(
+                                                *base
+                                                    .add(4 + 5 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<u8>(),
+                                            );
+                                            let l104 = i32::from(
+                                                *base
+                                                    .add(6 + 5 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<u8>(),
+                                            );
+                                            super::super::super::lojban::nesy::ast_types::Tense {
+                                                direction: match l100 {
+                                                    0 => None,
+                                                    1 => {
+                                                        let e = {
+                                                            let l101 = i32::from(
+                                                                *base
+                                                                    .add(3 + 5 * ::core::mem::size_of::<*const u8>())
+                                                                    .cast::<u8>(),
+                                                            );
+                                                            super::super::super::lojban::nesy::ast_types::TimeDirection::_lift(
+                                                                l101 as u8,
+                                                            )
+                                                        };
+                                                        Some(e)
+                                                    }
+                                                    _ => _rt::invalid_enum_discriminant(),
+                                                },
+                                                distance: match l102 {
+                                                    0 => None,
+                                                    1 => {
+                                                        let e = {
+                                                            let l103 = i32::from(
+                                                                *base
+                                                                    .add(5 + 5 * ::core::mem::size_of::<*const u8>())
+                                                                    .cast::<u8>(),
+                                                            );
+                                                            super::super::super::lojban::nesy::ast_types::TimeDistance::_lift(
+                                                                l103 as u8,
+                                                            )
+                                                        };
+                                                        Some(e)
+                                                    }
+                                                    _ => _rt::invalid_enum_discriminant(),
+                                                },
+                                                aspect: match l104 {
+                                                    0 => None,
+                                                    1 => {
+                                                        let e = {
+                                                            let l105 = i32::from(
+                                                                *base
+                                                                    .add(7 + 5 * ::core::mem::size_of::<*const u8>())
+                                                                    .cast::<u8>(),
+                                                            );
+                                                            super::super::super::lojban::nesy::ast_types::Aspect::_lift(
+                                                                l105 as u8,
+                                                            )
+                                                        };
+                                                        Some(e)
+                                                    }
+                                                    _ => _rt::invalid_enum_discriminant(),
+                                                },
                                             }
                                         };
-                                        v50
+                                        Some(e)
+                                    }
+                                    _ => _rt::invalid_enum_discriminant(),
+                                },
+                                question: _rt::bool_lift(l106 as u8),
+                                span: super::super::super::lojban::nesy::ast_types::Span {
+                                    start: l107 as u32,
+                                    end: l108 as u32,
+                                },
+                            }
+                        };
+                        result109.push(e109);
+                    }
+                    _rt::cabi_dealloc(
+                        base109,
+                        len109 * (16 + 6 * ::core::mem::size_of::<*const u8>()),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let l110 = *ptr1
+                        .add(10 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l111 = *ptr1
+                        .add(11 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base131 = l110;
+                    let len131 = l111;
+                    let mut result131 = _rt::Vec::with_capacity(len131);
+                    for i in 0..len131 {
+                        let base = base131
+                            .add(i * (4 * ::core::mem::size_of::<*const u8>()));
+                        let e131 = {
+                            let l112 = i32::from(*base.add(0).cast::<u8>());
+                            use super::super::super::lojban::nesy::ast_types::Statement as V130;
+                            let v130 = match l112 {
+                                0 => {
+                                    let e130 = {
+                                        let l113 = *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        l113 as u32
                                     };
-                                    result51.push(e51);
+                                    V130::Simple(e130)
                                 }
-                                _rt::cabi_dealloc(
-                                    base51,
-                                    len51 * (8 + 2 * ::core::mem::size_of::<*const u8>()),
-                                    ::core::mem::size_of::<*const u8>(),
-                                );
-                                let l52 = *ptr1
-                                    .add(5 * ::core::mem::size_of::<*const u8>())
-                                    .cast::<*mut u8>();
-                                let l53 = *ptr1
-                                    .add(6 * ::core::mem::size_of::<*const u8>())
-                                    .cast::<usize>();
-                                let base62 = l52;
-                                let len62 = l53;
-                                let mut result62 = _rt::Vec::with_capacity(len62);
-                                for i in 0..len62 {
-                                    let base = base62
-                                        .add(i * (6 * ::core::mem::size_of::<*const u8>()));
-                                    let e62 = {
-                                        let l54 = *base.add(0).cast::<i32>();
-                                        let l55 = *base
+                                1 => {
+                                    let e130 = {
+                                        let l114 = i32::from(
+                                            *base.add(::core::mem::size_of::<*const u8>()).cast::<u8>(),
+                                        );
+                                        let l115 = i32::from(
+                                            *base
+                                                .add(1 + 1 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>(),
+                                        );
+                                        let l116 = i32::from(
+                                            *base
+                                                .add(2 + 1 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>(),
+                                        );
+                                        let l117 = i32::from(
+                                            *base
+                                                .add(3 + 1 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>(),
+                                        );
+                                        let l118 = *base
+                                            .add(4 + 1 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        let l119 = *base
+                                            .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        (
+                                            super::super::super::lojban::nesy::ast_types::LogicalConnective {
+                                                connective: super::super::super::lojban::nesy::ast_types::Connective::_lift(
+                                                    l114 as u8,
+                                                ),
+                                                converted: _rt::bool_lift(l115 as u8),
+                                                negate_left: _rt::bool_lift(l116 as u8),
+                                                negate_right: _rt::bool_lift(l117 as u8),
+                                            },
+                                            l118 as u32,
+                                            l119 as u32,
+                                        )
+                                    };
+                                    V130::Connected(e130)
+                                }
+                                n => {
+                                    debug_assert_eq!(n, 2, "invalid enum discriminant");
+                                    let e130 = {
+                                        let l120 = *base
                                             .add(::core::mem::size_of::<*const u8>())
                                             .cast::<*mut u8>();
-                                        let l56 = *base
+                                        let l121 = *base
                                             .add(2 * ::core::mem::size_of::<*const u8>())
                                             .cast::<usize>();
-                                        let len57 = l56;
-                                        let l58 = *base
-                                            .add(3 * ::core::mem::size_of::<*const u8>())
-                                            .cast::<*mut u8>();
-                                        let l59 = *base
-                                            .add(4 * ::core::mem::size_of::<*const u8>())
-                                            .cast::<usize>();
-                                        let len60 = l59;
-                                        let l61 = i32::from(
-                                            *base
-                                                .add(5 * ::core::mem::size_of::<*const u8>())
-                                                .cast::<u8>(),
-                                        );
-                                        super::super::super::lojban::nesy::ast_types::Bridi {
-                                            relation: l54 as u32,
-                                            head_terms: _rt::Vec::from_raw_parts(
-                                                l55.cast(),
-                                                len57,
-                                                len57,
-                                            ),
-                                            tail_terms: _rt::Vec::from_raw_parts(
-                                                l58.cast(),
-                                                len60,
-                                                len60,
-                                            ),
-                                            negated: _rt::bool_lift(l61 as u8),
+                                        let base128 = l120;
+                                        let len128 = l121;
+                                        let mut result128 = _rt::Vec::with_capacity(len128);
+                                        for i in 0..len128 {
+                                            let base = base128.add(i * 20);
+                                            let e128 = {
+                                                let l122 = i32::from(*base.add(0).cast::<u8>());
+                                                use super::super::super::lojban::nesy::ast_types::PrenexTerm as V127;
+                                                let v127 = match l122 {
+                                                    0 => {
+                                                        let e127 = {
+                                                            let l123 = i32::from(*base.add(4).cast::<u8>());
+                                                            let l126 = *base.add(16).cast::<i32>();
+                                                            (
+                                                                match l123 {
+                                                                    0 => None,
+                                                                    1 => {
+                                                                        let e = {
+                                                                            let l124 = i32::from(*base.add(8).cast::<u8>());
+                                                                            let l125 = *base.add(12).cast::<i32>();
+                                                                            super::super::super::lojban::nesy::ast_types::Quantifier {
+                                                                                kind: super::super::super::lojban::nesy::ast_types::QuantifierKind::_lift(
+                                                                                    l124 as u8,
+                                                                                ),
+                                                                                count: l125 as u32,
+                                                                            }
+                                                                        };
+                                                                        Some(e)
+                                                                    }
+                                                                    _ => _rt::invalid_enum_discriminant(),
+                                                                },
+                                                                l126 as u32,
+                                                            )
+                                                        };
+                                                        V127::Variable(e127)
+                                                    }
+                                                    n => {
+                                                        debug_assert_eq!(n, 1, "invalid enum discriminant");
+                                                        V127::Naku
+                                                    }
+                                                };
+                                                v127
+                                            };
+                                            result128.push(e128);
                                         }
+                                        _rt::cabi_dealloc(base128, len128 * 20, 4);
+                                        let l129 = *base
+                                            .add(3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        (result128, l129 as u32)
                                     };
-                                    result62.push(e62);
-                                }
-                                _rt::cabi_dealloc(
-                                    base62,
-                                    len62 * (6 * ::core::mem::size_of::<*const u8>()),
-                                    ::core::mem::size_of::<*const u8>(),
-                                );
-                                let l63 = *ptr1
-                                    .add(7 * ::core::mem::size_of::<*const u8>())
-                                    .cast::<*mut u8>();
-                                let l64 = *ptr1
-                                    .add(8 * ::core::mem::size_of::<*const u8>())
-                                    .cast::<usize>();
-                                let len65 = l64;
-                                super::super::super::lojban::nesy::ast_types::AstBuffer {
-                                    selbris: result30,
-                                    sumtis: result51,
-                                    sentences: result62,
-                                    roots: _rt::Vec::from_raw_parts(l63.cast(), len65, len65),
+                                    V130::Prenex(e130)
                                 }
                             };
-                            Ok(e)
-                        }
-                        1 => {
-                            let e = {
-                                let l66 = *ptr1
-                                    .add(::core::mem::size_of::<*const u8>())
-                                    .cast::<*mut u8>();
-                                let l67 = *ptr1
-                                    .add(2 * ::core::mem::size_of::<*const u8>())
-                                    .cast::<usize>();
-                                let len68 = l67;
-                                let bytes68 = _rt::Vec::from_raw_parts(
-                                    l66.cast(),
-                                    len68,
-                                    len68,
-                                );
-                                _rt::string_lift(bytes68)
-                            };
-                            Err(e)
-                        }
-                        _ => _rt::invalid_enum_discriminant(),
+                            v130
+                        };
+                        result131.push(e131);
+                    }
+                    _rt::cabi_dealloc(
+                        base131,
+                        len131 * (4 * ::core::mem::size_of::<*const u8>()),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let l132 = *ptr1
+                        .add(12 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l133 = *ptr1
+                        .add(13 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let len134 = l133;
+                    let l135 = *ptr1
+                        .add(14 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l136 = *ptr1
+                        .add(15 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base142 = l135;
+                    let len142 = l136;
+                    let mut result142 = _rt::Vec::with_capacity(len142);
+                    for i in 0..len142 {
+                        let base = base142
+                            .add(i * (8 + 2 * ::core::mem::size_of::<*const u8>()));
+                        let e142 = {
+                            let l137 = *base.add(0).cast::<*mut u8>();
+                            let l138 = *base
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            let len139 = l138;
+                            let bytes139 = _rt::Vec::from_raw_parts(
+                                l137.cast(),
+                                len139,
+                                len139,
+                            );
+                            let l140 = *base
+                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>();
+                            let l141 = *base
+                                .add(4 + 2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>();
+                            Diagnostic {
+                                message: _rt::string_lift(bytes139),
+                                span: super::super::super::lojban::nesy::ast_types::Span {
+                                    start: l140 as u32,
+                                    end: l141 as u32,
+                                },
+                            }
+                        };
+                        result142.push(e142);
+                    }
+                    _rt::cabi_dealloc(
+                        base142,
+                        len142 * (8 + 2 * ::core::mem::size_of::<*const u8>()),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let result143 = ParseResult {
+                        ast: super::super::super::lojban::nesy::ast_types::AstBuffer {
+                            selbris: result31,
+                            sumtis: result82,
+                            selbri_spans: _rt::Vec::from_raw_parts(
+                                l83.cast(),
+                                len85,
+                                len85,
+                            ),
+                            sumti_spans: _rt::Vec::from_raw_parts(
+                                l86.cast(),
+                                len88,
+                                len88,
+                            ),
+                            sentences: result109,
+                            statements: result131,
+                            roots: _rt::Vec::from_raw_parts(l132.cast(), len134, len134),
+                        },
+                        diagnostics: result142,
                     };
-                    result69
+                    result143
                 }
             }
         }
@@ -1050,1132 +2035,4485 @@ pub mod lojban {
             use super::super::super::_rt;
             pub type AstBuffer = super::super::super::lojban::nesy::ast_types::AstBuffer;
             pub type LogicBuffer = super::super::super::lojban::nesy::ast_types::LogicBuffer;
-            #[allow(unused_unsafe, clippy::all)]
-            pub fn compile_buffer(ast: &AstBuffer) -> Result<LogicBuffer, _rt::String> {
-                unsafe {
-                    let mut cleanup_list = _rt::Vec::new();
-                    #[cfg_attr(target_pointer_width = "64", repr(align(8)))]
-                    #[cfg_attr(target_pointer_width = "32", repr(align(4)))]
-                    struct RetArea(
-                        [::core::mem::MaybeUninit<
-                            u8,
-                        >; 5 * ::core::mem::size_of::<*const u8>()],
-                    );
-                    let mut ret_area = RetArea(
-                        [::core::mem::MaybeUninit::uninit(); 5
-                            * ::core::mem::size_of::<*const u8>()],
-                    );
-                    let super::super::super::lojban::nesy::ast_types::AstBuffer {
-                        selbris: selbris0,
-                        sumtis: sumtis0,
-                        sentences: sentences0,
-                        roots: roots0,
-                    } = ast;
-                    let vec10 = selbris0;
-                    let len10 = vec10.len();
-                    let layout10 = _rt::alloc::Layout::from_size_align_unchecked(
-                        vec10.len() * (4 * ::core::mem::size_of::<*const u8>()),
-                        ::core::mem::size_of::<*const u8>(),
-                    );
-                    let result10 = if layout10.size() != 0 {
-                        let ptr = _rt::alloc::alloc(layout10).cast::<u8>();
-                        if ptr.is_null() {
-                            _rt::alloc::handle_alloc_error(layout10);
+            /// A compiler with a discourse of its own: what ri/ra/ru, go'i and
+            /// the ko'a series refer to carries over from one compile-buffer call
+            /// to the next, and to no other compiler.
+            #[derive(Debug)]
+            #[repr(transparent)]
+            pub struct Compiler {
+                handle: _rt::Resource<Compiler>,
+            }
+            impl Compiler {
+                #[doc(hidden)]
+                pub unsafe fn from_handle(handle: u32) -> Self {
+                    Self {
+                        handle: unsafe { _rt::Resource::from_handle(handle) },
+                    }
+                }
+                #[doc(hidden)]
+                pub fn take_handle(&self) -> u32 {
+                    _rt::Resource::take_handle(&self.handle)
+                }
+                #[doc(hidden)]
+                pub fn handle(&self) -> u32 {
+                    _rt::Resource::handle(&self.handle)
+                }
+            }
+            unsafe impl _rt::WasmResource for Compiler {
+                #[inline]
+                unsafe fn drop(_handle: u32) {
+                    #[cfg(not(target_arch = "wasm32"))]
+                    unreachable!();
+                    #[cfg(target_arch = "wasm32")]
+                    {
+                        #[link(wasm_import_module = "lojban:nesy/semantics@0.1.0")]
+                        unsafe extern "C" {
+                            #[link_name = "[resource-drop]compiler"]
+                            fn drop(_: u32);
                         }
-                        ptr
-                    } else {
-                        ::core::ptr::null_mut()
-                    };
-                    for (i, e) in vec10.into_iter().enumerate() {
-                        let base = result10
-                            .add(i * (4 * ::core::mem::size_of::<*const u8>()));
-                        {
-                            use super::super::super::lojban::nesy::ast_types::Selbri as V9;
-                            match e {
-                                V9::Root(e) => {
-                                    *base.add(0).cast::<u8>() = (0i32) as u8;
-                                    let vec1 = e;
-                                    let ptr1 = vec1.as_ptr().cast::<u8>();
-                                    let len1 = vec1.len();
-                                    *base
-                                        .add(2 * ::core::mem::size_of::<*const u8>())
-                                        .cast::<usize>() = len1;
-                                    *base
-                                        .add(::core::mem::size_of::<*const u8>())
-                                        .cast::<*mut u8>() = ptr1.cast_mut();
-                                }
-                                V9::Compound(e) => {
-                                    *base.add(0).cast::<u8>() = (1i32) as u8;
-                                    let vec3 = e;
-                                    let len3 = vec3.len();
-                                    let layout3 = _rt::alloc::Layout::from_size_align_unchecked(
-                                        vec3.len() * (2 * ::core::mem::size_of::<*const u8>()),
-                                        ::core::mem::size_of::<*const u8>(),
-                                    );
-                                    let result3 = if layout3.size() != 0 {
-                                        let ptr = _rt::alloc::alloc(layout3).cast::<u8>();
-                                        if ptr.is_null() {
-                                            _rt::alloc::handle_alloc_error(layout3);
-                                        }
-                                        ptr
-                                    } else {
-                                        ::core::ptr::null_mut()
-                                    };
-                                    for (i, e) in vec3.into_iter().enumerate() {
-                                        let base = result3
-                                            .add(i * (2 * ::core::mem::size_of::<*const u8>()));
-                                        {
-                                            let vec2 = e;
-                                            let ptr2 = vec2.as_ptr().cast::<u8>();
-                                            let len2 = vec2.len();
-                                            *base
-                                                .add(::core::mem::size_of::<*const u8>())
-                                                .cast::<usize>() = len2;
-                                            *base.add(0).cast::<*mut u8>() = ptr2.cast_mut();
+                        unsafe { drop(_handle) };
+                    }
+                }
+            }
+            impl Compiler {
+                #[allow(unused_unsafe, clippy::all)]
+                /// A compiler with nothing said yet.
+                pub fn new() -> Self {
+                    unsafe {
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "lojban:nesy/semantics@0.1.0")]
+                        unsafe extern "C" {
+                            #[link_name = "[constructor]compiler"]
+                            fn wit_import0() -> i32;
+                        }
+                        #[cfg(not(target_arch = "wasm32"))]
+                        unsafe extern "C" fn wit_import0() -> i32 {
+                            unreachable!()
+                        }
+                        let ret = unsafe { wit_import0() };
+                        unsafe { Compiler::from_handle(ret as u32) }
+                    }
+                }
+            }
+            impl Compiler {
+                #[allow(unused_unsafe, clippy::all)]
+                pub fn compile_buffer(
+                    &self,
+                    ast: &AstBuffer,
+                ) -> Result<LogicBuffer, _rt::String> {
+                    unsafe {
+                        let mut cleanup_list = _rt::Vec::new();
+                        #[cfg_attr(target_pointer_width = "64", repr(align(8)))]
+                        #[cfg_attr(target_pointer_width = "32", repr(align(4)))]
+                        struct RetArea(
+                            [::core::mem::MaybeUninit<
+                                u8,
+                            >; 5 * ::core::mem::size_of::<*const u8>()],
+                        );
+                        let mut ret_area = RetArea(
+                            [::core::mem::MaybeUninit::uninit(); 5
+                                * ::core::mem::size_of::<*const u8>()],
+                        );
+                        let super::super::super::lojban::nesy::ast_types::AstBuffer {
+                            selbris: selbris0,
+                            sumtis: sumtis0,
+                            selbri_spans: selbri_spans0,
+                            sumti_spans: sumti_spans0,
+                            sentences: sentences0,
+                            statements: statements0,
+                            roots: roots0,
+                        } = ast;
+                        let vec11 = selbris0;
+                        let len11 = vec11.len();
+                        let layout11 = _rt::alloc::Layout::from_size_align_unchecked(
+                            vec11.len() * (4 * ::core::mem::size_of::<*const u8>()),
+                            ::core::mem::size_of::<*const u8>(),
+                        );
+                        let result11 = if layout11.size() != 0 {
+                            let ptr = _rt::alloc::alloc(layout11).cast::<u8>();
+                            if ptr.is_null() {
+                                _rt::alloc::handle_alloc_error(layout11);
+                            }
+                            ptr
+                        } else {
+                            ::core::ptr::null_mut()
+                        };
+                        for (i, e) in vec11.into_iter().enumerate() {
+                            let base = result11
+                                .add(i * (4 * ::core::mem::size_of::<*const u8>()));
+                            {
+                                use super::super::super::lojban::nesy::ast_types::Selbri as V10;
+                                match e {
+                                    V10::Root(e) => {
+                                        *base.add(0).cast::<u8>() = (0i32) as u8;
+                                        let vec1 = e;
+                                        let ptr1 = vec1.as_ptr().cast::<u8>();
+                                        let len1 = vec1.len();
+                                        *base
+                                            .add(2 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<usize>() = len1;
+                                        *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<*mut u8>() = ptr1.cast_mut();
+                                    }
+                                    V10::Compound(e) => {
+                                        *base.add(0).cast::<u8>() = (1i32) as u8;
+                                        let vec3 = e;
+                                        let len3 = vec3.len();
+                                        let layout3 = _rt::alloc::Layout::from_size_align_unchecked(
+                                            vec3.len() * (2 * ::core::mem::size_of::<*const u8>()),
+                                            ::core::mem::size_of::<*const u8>(),
+                                        );
+                                        let result3 = if layout3.size() != 0 {
+                                            let ptr = _rt::alloc::alloc(layout3).cast::<u8>();
+                                            if ptr.is_null() {
+                                                _rt::alloc::handle_alloc_error(layout3);
+                                            }
+                                            ptr
+                                        } else {
+                                            ::core::ptr::null_mut()
+                                        };
+                                        for (i, e) in vec3.into_iter().enumerate() {
+                                            let base = result3
+                                                .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                                            {
+                                                let vec2 = e;
+                                                let ptr2 = vec2.as_ptr().cast::<u8>();
+                                                let len2 = vec2.len();
+                                                *base
+                                                    .add(::core::mem::size_of::<*const u8>())
+                                                    .cast::<usize>() = len2;
+                                                *base.add(0).cast::<*mut u8>() = ptr2.cast_mut();
+                                            }
                                         }
+                                        *base
+                                            .add(2 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<usize>() = len3;
+                                        *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<*mut u8>() = result3;
+                                        cleanup_list.extend_from_slice(&[(result3, layout3)]);
+                                    }
+                                    V10::Tanru(e) => {
+                                        *base.add(0).cast::<u8>() = (2i32) as u8;
+                                        let (t4_0, t4_1) = e;
+                                        *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>() = _rt::as_i32(t4_0);
+                                        *base
+                                            .add(4 + 1 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>() = _rt::as_i32(t4_1);
+                                    }
+                                    V10::Converted(e) => {
+                                        *base.add(0).cast::<u8>() = (3i32) as u8;
+                                        let (t5_0, t5_1) = e;
+                                        *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>() = (t5_0.clone() as i32) as u8;
+                                        *base
+                                            .add(4 + 1 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>() = _rt::as_i32(t5_1);
+                                    }
+                                    V10::Negated(e) => {
+                                        *base.add(0).cast::<u8>() = (4i32) as u8;
+                                        *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>() = _rt::as_i32(e);
+                                    }
+                                    V10::Grouped(e) => {
+                                        *base.add(0).cast::<u8>() = (5i32) as u8;
+                                        *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>() = _rt::as_i32(e);
+                                    }
+                                    V10::WithArgs(e) => {
+                                        *base.add(0).cast::<u8>() = (6i32) as u8;
+                                        let (t6_0, t6_1) = e;
+                                        *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>() = _rt::as_i32(t6_0);
+                                        let vec7 = t6_1;
+                                        let ptr7 = vec7.as_ptr().cast::<u8>();
+                                        let len7 = vec7.len();
+                                        *base
+                                            .add(3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<usize>() = len7;
+                                        *base
+                                            .add(2 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<*mut u8>() = ptr7.cast_mut();
+                                    }
+                                    V10::Connected(e) => {
+                                        *base.add(0).cast::<u8>() = (7i32) as u8;
+                                        let (t8_0, t8_1, t8_2) = e;
+                                        *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>() = _rt::as_i32(t8_0);
+                                        *base
+                                            .add(4 + 1 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>() = (t8_1.clone() as i32) as u8;
+                                        *base
+                                            .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>() = _rt::as_i32(t8_2);
+                                    }
+                                    V10::Abstraction(e) => {
+                                        *base.add(0).cast::<u8>() = (8i32) as u8;
+                                        let (t9_0, t9_1) = e;
+                                        *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>() = (t9_0.clone() as i32) as u8;
+                                        *base
+                                            .add(4 + 1 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>() = _rt::as_i32(t9_1);
                                     }
-                                    *base
-                                        .add(2 * ::core::mem::size_of::<*const u8>())
-                                        .cast::<usize>() = len3;
-                                    *base
-                                        .add(::core::mem::size_of::<*const u8>())
-                                        .cast::<*mut u8>() = result3;
-                                    cleanup_list.extend_from_slice(&[(result3, layout3)]);
-                                }
-                                V9::Tanru(e) => {
-                                    *base.add(0).cast::<u8>() = (2i32) as u8;
-                                    let (t4_0, t4_1) = e;
-                                    *base
-                                        .add(::core::mem::size_of::<*const u8>())
-                                        .cast::<i32>() = _rt::as_i32(t4_0);
-                                    *base
-                                        .add(4 + 1 * ::core::mem::size_of::<*const u8>())
-                                        .cast::<i32>() = _rt::as_i32(t4_1);
                                 }
-                                V9::Converted(e) => {
-                                    *base.add(0).cast::<u8>() = (3i32) as u8;
-                                    let (t5_0, t5_1) = e;
-                                    *base
-                                        .add(::core::mem::size_of::<*const u8>())
-                                        .cast::<u8>() = (t5_0.clone() as i32) as u8;
-                                    *base
-                                        .add(4 + 1 * ::core::mem::size_of::<*const u8>())
-                                        .cast::<i32>() = _rt::as_i32(t5_1);
-                                }
-                                V9::Negated(e) => {
-                                    *base.add(0).cast::<u8>() = (4i32) as u8;
-                                    *base
-                                        .add(::core::mem::size_of::<*const u8>())
-                                        .cast::<i32>() = _rt::as_i32(e);
+                            }
+                        }
+                        let vec31 = sumtis0;
+                        let len31 = vec31.len();
+                        let layout31 = _rt::alloc::Layout::from_size_align_unchecked(
+                            vec31.len() * (8 + 4 * ::core::mem::size_of::<*const u8>()),
+                            ::core::mem::size_of::<*const u8>(),
+                        );
+                        let result31 = if layout31.size() != 0 {
+                            let ptr = _rt::alloc::alloc(layout31).cast::<u8>();
+                            if ptr.is_null() {
+                                _rt::alloc::handle_alloc_error(layout31);
+                            }
+                            ptr
+                        } else {
+                            ::core::ptr::null_mut()
+                        };
+                        for (i, e) in vec31.into_iter().enumerate() {
+                            let base = result31
+                                .add(i * (8 + 4 * ::core::mem::size_of::<*const u8>()));
+                            {
+                                use super::super::super::lojban::nesy::ast_types::Sumti as V30;
+                                match e {
+                                    V30::ProSumti(e) => {
+                                        *base.add(0).cast::<u8>() = (0i32) as u8;
+                                        let vec12 = e;
+                                        let ptr12 = vec12.as_ptr().cast::<u8>();
+                                        let len12 = vec12.len();
+                                        *base
+                                            .add(2 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<usize>() = len12;
+                                        *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<*mut u8>() = ptr12.cast_mut();
+                                    }
+                                    V30::Relativized(e) => {
+                                        *base.add(0).cast::<u8>() = (1i32) as u8;
+                                        *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>() = _rt::as_i32(e);
+                                    }
+                                    V30::Description(e) => {
+                                        *base.add(0).cast::<u8>() = (2i32) as u8;
+                                        let (t13_0, t13_1, t13_2) = e;
+                                        *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>() = (t13_0.clone() as i32) as u8;
+                                        match t13_1 {
+                                            Some(e) => {
+                                                *base
+                                                    .add(4 + 1 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<u8>() = (1i32) as u8;
+                                                let super::super::super::lojban::nesy::ast_types::Quantifier {
+                                                    kind: kind14,
+                                                    count: count14,
+                                                } = e;
+                                                *base
+                                                    .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<u8>() = (kind14.clone() as i32) as u8;
+                                                *base
+                                                    .add(12 + 1 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<i32>() = _rt::as_i32(count14);
+                                            }
+                                            None => {
+                                                *base
+                                                    .add(4 + 1 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<u8>() = (0i32) as u8;
+                                            }
+                                        };
+                                        *base
+                                            .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>() = _rt::as_i32(t13_2);
+                                    }
+                                    V30::Quantified(e) => {
+                                        *base.add(0).cast::<u8>() = (3i32) as u8;
+                                        let (t15_0, t15_1) = e;
+                                        let super::super::super::lojban::nesy::ast_types::Quantifier {
+                                            kind: kind16,
+                                            count: count16,
+                                        } = t15_0;
+                                        *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>() = (kind16.clone() as i32) as u8;
+                                        *base
+                                            .add(4 + 1 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>() = _rt::as_i32(count16);
+                                        *base
+                                            .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>() = _rt::as_i32(t15_1);
+                                    }
+                                    V30::Name(e) => {
+                                        *base.add(0).cast::<u8>() = (4i32) as u8;
+                                        let vec17 = e;
+                                        let ptr17 = vec17.as_ptr().cast::<u8>();
+                                        let len17 = vec17.len();
+                                        *base
+                                            .add(2 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<usize>() = len17;
+                                        *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<*mut u8>() = ptr17.cast_mut();
+                                    }
+                                    V30::QuotedLiteral(e) => {
+                                        *base.add(0).cast::<u8>() = (5i32) as u8;
+                                        let vec18 = e;
+                                        let ptr18 = vec18.as_ptr().cast::<u8>();
+                                        let len18 = vec18.len();
+                                        *base
+                                            .add(2 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<usize>() = len18;
+                                        *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<*mut u8>() = ptr18.cast_mut();
+                                    }
+                                    V30::Unspecified => {
+                                        *base.add(0).cast::<u8>() = (6i32) as u8;
+                                    }
+                                    V30::Tagged(e) => {
+                                        *base.add(0).cast::<u8>() = (7i32) as u8;
+                                        let (t19_0, t19_1) = e;
+                                        *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>() = (t19_0.clone() as i32) as u8;
+                                        *base
+                                            .add(4 + 1 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>() = _rt::as_i32(t19_1);
+                                    }
+                                    V30::TenseTagged(e) => {
+                                        *base.add(0).cast::<u8>() = (8i32) as u8;
+                                        let (t20_0, t20_1) = e;
+                                        let super::super::super::lojban::nesy::ast_types::Tense {
+                                            direction: direction21,
+                                            distance: distance21,
+                                            aspect: aspect21,
+                                        } = t20_0;
+                                        match direction21 {
+                                            Some(e) => {
+                                                *base
+                                                    .add(::core::mem::size_of::<*const u8>())
+                                                    .cast::<u8>() = (1i32) as u8;
+                                                *base
+                                                    .add(1 + 1 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<u8>() = (e.clone() as i32) as u8;
+                                            }
+                                            None => {
+                                                *base
+                                                    .add(::core::mem::size_of::<*const u8>())
+                                                    .cast::<u8>() = (0i32) as u8;
+                                            }
+                                        };
+                                        match distance21 {
+                                            Some(e) => {
+                                                *base
+                                                    .add(2 + 1 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<u8>() = (1i32) as u8;
+                                                *base
+                                                    .add(3 + 1 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<u8>() = (e.clone() as i32) as u8;
+                                            }
+                                            None => {
+                                                *base
+                                                    .add(2 + 1 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<u8>() = (0i32) as u8;
+                                            }
+                                        };
+                                        match aspect21 {
+                                            Some(e) => {
+                                                *base
+                                                    .add(4 + 1 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<u8>() = (1i32) as u8;
+                                                *base
+                                                    .add(5 + 1 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<u8>() = (e.clone() as i32) as u8;
+                                            }
+                                            None => {
+                                                *base
+                                                    .add(4 + 1 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<u8>() = (0i32) as u8;
+                                            }
+                                        };
+                                        *base
+                                            .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>() = _rt::as_i32(t20_1);
+                                    }
+                                    V30::ModalTagged(e) => {
+                                        *base.add(0).cast::<u8>() = (9i32) as u8;
+                                        let (t22_0, t22_1) = e;
+                                        use super::super::super::lojban::nesy::ast_types::ModalTag as V24;
+                                        match t22_0 {
+                                            V24::Bai(e) => {
+                                                *base
+                                                    .add(::core::mem::size_of::<*const u8>())
+                                                    .cast::<u8>() = (0i32) as u8;
+                                                let vec23 = e;
+                                                let ptr23 = vec23.as_ptr().cast::<u8>();
+                                                let len23 = vec23.len();
+                                                *base
+                                                    .add(3 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<usize>() = len23;
+                                                *base
+                                                    .add(2 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<*mut u8>() = ptr23.cast_mut();
+                                            }
+                                            V24::Fiho(e) => {
+                                                *base
+                                                    .add(::core::mem::size_of::<*const u8>())
+                                                    .cast::<u8>() = (1i32) as u8;
+                                                *base
+                                                    .add(2 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<i32>() = _rt::as_i32(e);
+                                            }
+                                        }
+                                        *base
+                                            .add(4 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>() = _rt::as_i32(t22_1);
+                                    }
+                                    V30::Restricted(e) => {
+                                        *base.add(0).cast::<u8>() = (10i32) as u8;
+                                        let (t25_0, t25_1) = e;
+                                        *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>() = _rt::as_i32(t25_0);
+                                        let super::super::super::lojban::nesy::ast_types::RelClause {
+                                            kind: kind26,
+                                            body: body26,
+                                        } = t25_1;
+                                        *base
+                                            .add(4 + 1 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>() = (kind26.clone() as i32) as u8;
+                                        use super::super::super::lojban::nesy::ast_types::RelClauseBody as V27;
+                                        match body26 {
+                                            V27::Sentence(e) => {
+                                                *base
+                                                    .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<u8>() = (0i32) as u8;
+                                                *base
+                                                    .add(12 + 1 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<i32>() = _rt::as_i32(e);
+                                            }
+                                            V27::Sumti(e) => {
+                                                *base
+                                                    .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<u8>() = (1i32) as u8;
+                                                *base
+                                                    .add(12 + 1 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<i32>() = _rt::as_i32(e);
+                                            }
+                                        }
+                                    }
+                                    V30::Connected(e) => {
+                                        *base.add(0).cast::<u8>() = (11i32) as u8;
+                                        let (t28_0, t28_1, t28_2) = e;
+                                        *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>() = _rt::as_i32(t28_0);
+                                        let super::super::super::lojban::nesy::ast_types::LogicalConnective {
+                                            connective: connective29,
+                                            converted: converted29,
+                                            negate_left: negate_left29,
+                                            negate_right: negate_right29,
+                                        } = t28_1;
+                                        *base
+                                            .add(4 + 1 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>() = (connective29.clone() as i32) as u8;
+                                        *base
+                                            .add(5 + 1 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>() = (match converted29 {
+                                            true => 1,
+                                            false => 0,
+                                        }) as u8;
+                                        *base
+                                            .add(6 + 1 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>() = (match negate_left29 {
+                                            true => 1,
+                                            false => 0,
+                                        }) as u8;
+                                        *base
+                                            .add(7 + 1 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>() = (match negate_right29 {
+                                            true => 1,
+                                            false => 0,
+                                        }) as u8;
+                                        *base
+                                            .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>() = _rt::as_i32(t28_2);
+                                    }
                                 }
-                                V9::Grouped(e) => {
-                                    *base.add(0).cast::<u8>() = (5i32) as u8;
-                                    *base
-                                        .add(::core::mem::size_of::<*const u8>())
-                                        .cast::<i32>() = _rt::as_i32(e);
+                            }
+                        }
+                        let vec32 = selbri_spans0;
+                        let ptr32 = vec32.as_ptr().cast::<u8>();
+                        let len32 = vec32.len();
+                        let vec33 = sumti_spans0;
+                        let ptr33 = vec33.as_ptr().cast::<u8>();
+                        let len33 = vec33.len();
+                        let vec39 = sentences0;
+                        let len39 = vec39.len();
+                        let layout39 = _rt::alloc::Layout::from_size_align_unchecked(
+                            vec39.len() * (16 + 6 * ::core::mem::size_of::<*const u8>()),
+                            ::core::mem::size_of::<*const u8>(),
+                        );
+                        let result39 = if layout39.size() != 0 {
+                            let ptr = _rt::alloc::alloc(layout39).cast::<u8>();
+                            if ptr.is_null() {
+                                _rt::alloc::handle_alloc_error(layout39);
+                            }
+                            ptr
+                        } else {
+                            ::core::ptr::null_mut()
+                        };
+                        for (i, e) in vec39.into_iter().enumerate() {
+                            let base = result39
+                                .add(i * (16 + 6 * ::core::mem::size_of::<*const u8>()));
+                            {
+                                let super::super::super::lojban::nesy::ast_types::Bridi {
+                                    relation: relation34,
+                                    head_terms: head_terms34,
+                                    tail_terms: tail_terms34,
+                                    negated: negated34,
+                                    tense: tense34,
+                                    question: question34,
+                                    span: span34,
+                                } = e;
+                                *base.add(0).cast::<i32>() = _rt::as_i32(relation34);
+                                let vec35 = head_terms34;
+                                let ptr35 = vec35.as_ptr().cast::<u8>();
+                                let len35 = vec35.len();
+                                *base
+                                    .add(2 * ::core::mem::size_of::<*const u8>())
+                                    .cast::<usize>() = len35;
+                                *base
+                                    .add(::core::mem::size_of::<*const u8>())
+                                    .cast::<*mut u8>() = ptr35.cast_mut();
+                                let vec36 = tail_terms34;
+                                let ptr36 = vec36.as_ptr().cast::<u8>();
+                                let len36 = vec36.len();
+                                *base
+                                    .add(4 * ::core::mem::size_of::<*const u8>())
+                                    .cast::<usize>() = len36;
+                                *base
+                                    .add(3 * ::core::mem::size_of::<*const u8>())
+                                    .cast::<*mut u8>() = ptr36.cast_mut();
+                                *base
+                                    .add(5 * ::core::mem::size_of::<*const u8>())
+                                    .cast::<u8>() = (match negated34 {
+                                    true => 1,
+                                    false => 0,
+                                }) as u8;
+                                match tense34 {
+                                    Some(e) => {
+                                        *base
+                                            .add(1 + 5 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>() = (1i32) as u8;
+                                        let super::super::super::lojban::nesy::ast_types::Tense {
+                                            direction: direction37,
+                                            distance: distance37,
+                                            aspect: aspect37,
+                                        } = e;
+                                        match direction37 {
+                                            Some(e) => {
+                                                *base
+                                                    .add(2 + 5 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<u8>() = (1i32) as u8;
+                                                *base
+                                                    .add(3 + 5 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<u8>() = (e.clone() as i32) as u8;
+                                            }
+                                            None => {
+                                                *base
+                                                    .add(2 + 5 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<u8>() = (0i32) as u8;
+                                            }
+                                        };
+                                        match distance37 {
+                                            Some(e) => {
+                                                *base
+                                                    .add(4 + 5 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<u8>() = (1i32) as u8;
+                                                *base
+                                                    .add(5 + 5 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<u8>() = (e.clone() as i32) as u8;
+                                            }
+                                            None => {
+                                                *base
+                                                    .add(4 + 5 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<u8>() = (0i32) as u8;
+                                            }
+                                        };
+                                        match aspect37 {
+                                            Some(e) => {
+                                                *base
+                                                    .add(6 + 5 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<u8>() = (1i32) as u8;
+                                                *base
+                                                    .add(7 + 5 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<u8>() = (e.clone() as i32) as u8;
+                                            }
+                                            None => {
+                                                *base
+                                                    .add(6 + 5 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<u8>() = (0i32) as u8;
+                                            }
+                                        };
+                                    }
+                                    None => {
+                                        *base
+                                            .add(1 + 5 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>() = (0i32) as u8;
+                                    }
+                                };
+                                *base
+                                    .add(8 + 5 * ::core::mem::size_of::<*const u8>())
+                                    .cast::<u8>() = (match question34 {
+                                    true => 1,
+                                    false => 0,
+                                }) as u8;
+                                let super::super::super::lojban::nesy::ast_types::Span {
+                                    start: start38,
+                                    end: end38,
+                                } = span34;
+                                *base
+                                    .add(12 + 5 * ::core::mem::size_of::<*const u8>())
+                                    .cast::<i32>() = _rt::as_i32(start38);
+                                *base
+                                    .add(16 + 5 * ::core::mem::size_of::<*const u8>())
+                                    .cast::<i32>() = _rt::as_i32(end38);
+                            }
+                        }
+                        let vec48 = statements0;
+                        let len48 = vec48.len();
+                        let layout48 = _rt::alloc::Layout::from_size_align_unchecked(
+                            vec48.len() * (4 * ::core::mem::size_of::<*const u8>()),
+                            ::core::mem::size_of::<*const u8>(),
+                        );
+                        let result48 = if layout48.size() != 0 {
+                            let ptr = _rt::alloc::alloc(layout48).cast::<u8>();
+                            if ptr.is_null() {
+                                _rt::alloc::handle_alloc_error(layout48);
+                            }
+                            ptr
+                        } else {
+                            ::core::ptr::null_mut()
+                        };
+                        for (i, e) in vec48.into_iter().enumerate() {
+                            let base = result48
+                                .add(i * (4 * ::core::mem::size_of::<*const u8>()));
+                            {
+                                use super::super::super::lojban::nesy::ast_types::Statement as V47;
+                                match e {
+                                    V47::Simple(e) => {
+                                        *base.add(0).cast::<u8>() = (0i32) as u8;
+                                        *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>() = _rt::as_i32(e);
+                                    }
+                                    V47::Connected(e) => {
+                                        *base.add(0).cast::<u8>() = (1i32) as u8;
+                                        let (t40_0, t40_1, t40_2) = e;
+                                        let super::super::super::lojban::nesy::ast_types::LogicalConnective {
+                                            connective: connective41,
+                                            converted: converted41,
+                                            negate_left: negate_left41,
+                                            negate_right: negate_right41,
+                                        } = t40_0;
+                                        *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>() = (connective41.clone() as i32) as u8;
+                                        *base
+                                            .add(1 + 1 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>() = (match converted41 {
+                                            true => 1,
+                                            false => 0,
+                                        }) as u8;
+                                        *base
+                                            .add(2 + 1 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>() = (match negate_left41 {
+                                            true => 1,
+                                            false => 0,
+                                        }) as u8;
+                                        *base
+                                            .add(3 + 1 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>() = (match negate_right41 {
+                                            true => 1,
+                                            false => 0,
+                                        }) as u8;
+                                        *base
+                                            .add(4 + 1 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>() = _rt::as_i32(t40_1);
+                                        *base
+                                            .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>() = _rt::as_i32(t40_2);
+                                    }
+                                    V47::Prenex(e) => {
+                                        *base.add(0).cast::<u8>() = (2i32) as u8;
+                                        let (t42_0, t42_1) = e;
+                                        let vec46 = t42_0;
+                                        let len46 = vec46.len();
+                                        let layout46 = _rt::alloc::Layout::from_size_align_unchecked(
+                                            vec46.len() * 20,
+                                            4,
+                                        );
+                                        let result46 = if layout46.size() != 0 {
+                                            let ptr = _rt::alloc::alloc(layout46).cast::<u8>();
+                                            if ptr.is_null() {
+                                                _rt::alloc::handle_alloc_error(layout46);
+                                            }
+                                            ptr
+                                        } else {
+                                            ::core::ptr::null_mut()
+                                        };
+                                        for (i, e) in vec46.into_iter().enumerate() {
+                                            let base = result46.add(i * 20);
+                                            {
+                                                use super::super::super::lojban::nesy::ast_types::PrenexTerm as V45;
+                                                match e {
+                                                    V45::Variable(e) => {
+                                                        *base.add(0).cast::<u8>() = (0i32) as u8;
+                                                        let (t43_0, t43_1) = e;
+                                                        match t43_0 {
+                                                            Some(e) => {
+                                                                *base.add(4).cast::<u8>() = (1i32) as u8;
+                                                                let super::super::super::lojban::nesy::ast_types::Quantifier {
+                                                                    kind: kind44,
+                                                                    count: count44,
+                                                                } = e;
+                                                                *base.add(8).cast::<u8>() = (kind44.clone() as i32) as u8;
+                                                                *base.add(12).cast::<i32>() = _rt::as_i32(count44);
+                                                            }
+                                                            None => {
+                                                                *base.add(4).cast::<u8>() = (0i32) as u8;
+                                                            }
+                                                        };
+                                                        *base.add(16).cast::<i32>() = _rt::as_i32(t43_1);
+                                                    }
+                                                    V45::Naku => {
+                                                        *base.add(0).cast::<u8>() = (1i32) as u8;
+                                                    }
+                                                }
+                                            }
+                                        }
+                                        *base
+                                            .add(2 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<usize>() = len46;
+                                        *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<*mut u8>() = result46;
+                                        *base
+                                            .add(3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>() = _rt::as_i32(t42_1);
+                                        cleanup_list.extend_from_slice(&[(result46, layout46)]);
+                                    }
                                 }
-                                V9::WithArgs(e) => {
-                                    *base.add(0).cast::<u8>() = (6i32) as u8;
-                                    let (t6_0, t6_1) = e;
-                                    *base
+                            }
+                        }
+                        let vec49 = roots0;
+                        let ptr49 = vec49.as_ptr().cast::<u8>();
+                        let len49 = vec49.len();
+                        let ptr50 = ret_area.0.as_mut_ptr().cast::<u8>();
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "lojban:nesy/semantics@0.1.0")]
+                        unsafe extern "C" {
+                            #[link_name = "[method]compiler.compile-buffer"]
+                            fn wit_import51(
+                                _: i32,
+                                _: *mut u8,
+                                _: usize,
+                                _: *mut u8,
+                                _: usize,
+                                _: *mut u8,
+                                _: usize,
+                                _: *mut u8,
+                                _: usize,
+                                _: *mut u8,
+                                _: usize,
+                                _: *mut u8,
+                                _: usize,
+                                _: *mut u8,
+                                _: usize,
+                                _: *mut u8,
+                            );
+                        }
+                        #[cfg(not(target_arch = "wasm32"))]
+                        unsafe extern "C" fn wit_import51(
+                            _: i32,
+                            _: *mut u8,
+                            _: usize,
+                            _: *mut u8,
+                            _: usize,
+                            _: *mut u8,
+                            _: usize,
+                            _: *mut u8,
+                            _: usize,
+                            _: *mut u8,
+                            _: usize,
+                            _: *mut u8,
+                            _: usize,
+                            _: *mut u8,
+                            _: usize,
+                            _: *mut u8,
+                        ) {
+                            unreachable!()
+                        }
+                        unsafe {
+                            wit_import51(
+                                (self).handle() as i32,
+                                result11,
+                                len11,
+                                result31,
+                                len31,
+                                ptr32.cast_mut(),
+                                len32,
+                                ptr33.cast_mut(),
+                                len33,
+                                result39,
+                                len39,
+                                result48,
+                                len48,
+                                ptr49.cast_mut(),
+                                len49,
+                                ptr50,
+                            )
+                        };
+                        let l52 = i32::from(*ptr50.add(0).cast::<u8>());
+                        let result115 = match l52 {
+                            0 => {
+                                let e = {
+                                    let l53 = *ptr50
                                         .add(::core::mem::size_of::<*const u8>())
-                                        .cast::<i32>() = _rt::as_i32(t6_0);
-                                    let vec7 = t6_1;
-                                    let ptr7 = vec7.as_ptr().cast::<u8>();
-                                    let len7 = vec7.len();
-                                    *base
-                                        .add(3 * ::core::mem::size_of::<*const u8>())
-                                        .cast::<usize>() = len7;
-                                    *base
+                                        .cast::<*mut u8>();
+                                    let l54 = *ptr50
                                         .add(2 * ::core::mem::size_of::<*const u8>())
-                                        .cast::<*mut u8>() = ptr7.cast_mut();
-                                }
-                                V9::Connected(e) => {
-                                    *base.add(0).cast::<u8>() = (7i32) as u8;
-                                    let (t8_0, t8_1, t8_2) = e;
-                                    *base
+                                        .cast::<usize>();
+                                    let base108 = l53;
+                                    let len108 = l54;
+                                    let mut result108 = _rt::Vec::with_capacity(len108);
+                                    for i in 0..len108 {
+                                        let base = base108
+                                            .add(i * (5 * ::core::mem::size_of::<*const u8>()));
+                                        let e108 = {
+                                            let l55 = i32::from(*base.add(0).cast::<u8>());
+                                            use super::super::super::lojban::nesy::ast_types::LogicNode as V107;
+                                            let v107 = match l55 {
+                                                0 => {
+                                                    let e107 = {
+                                                        let l56 = *base
+                                                            .add(::core::mem::size_of::<*const u8>())
+                                                            .cast::<*mut u8>();
+                                                        let l57 = *base
+                                                            .add(2 * ::core::mem::size_of::<*const u8>())
+                                                            .cast::<usize>();
+                                                        let len58 = l57;
+                                                        let bytes58 = _rt::Vec::from_raw_parts(
+                                                            l56.cast(),
+                                                            len58,
+                                                            len58,
+                                                        );
+                                                        let l59 = *base
+                                                            .add(3 * ::core::mem::size_of::<*const u8>())
+                                                            .cast::<*mut u8>();
+                                                        let l60 = *base
+                                                            .add(4 * ::core::mem::size_of::<*const u8>())
+                                                            .cast::<usize>();
+                                                        let base73 = l59;
+                                                        let len73 = l60;
+                                                        let mut result73 = _rt::Vec::with_capacity(len73);
+                                                        for i in 0..len73 {
+                                                            let base = base73
+                                                                .add(i * (3 * ::core::mem::size_of::<*const u8>()));
+                                                            let e73 = {
+                                                                let l61 = i32::from(*base.add(0).cast::<u8>());
+                                                                use super::super::super::lojban::nesy::ast_types::LogicalTerm as V72;
+                                                                let v72 = match l61 {
+                                                                    0 => {
+                                                                        let e72 = {
+                                                                            let l62 = *base
+                                                                                .add(::core::mem::size_of::<*const u8>())
+                                                                                .cast::<*mut u8>();
+                                                                            let l63 = *base
+                                                                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                                                                .cast::<usize>();
+                                                                            let len64 = l63;
+                                                                            let bytes64 = _rt::Vec::from_raw_parts(
+                                                                                l62.cast(),
+                                                                                len64,
+                                                                                len64,
+                                                                            );
+                                                                            _rt::string_lift(bytes64)
+                                                                        };
+                                                                        V72::Variable(e72)
+                                                                    }
+                                                                    1 => {
+                                                                        let e72 = {
+                                                                            let l65 = *base
+                                                                                .add(::core::mem::size_of::<*const u8>())
+                                                                                .cast::<*mut u8>();
+                                                                            let l66 = *base
+                                                                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                                                                .cast::<usize>();
+                                                                            let len67 = l66;
+                                                                            let bytes67 = _rt::Vec::from_raw_parts(
+                                                                                l65.cast(),
+                                                                                len67,
+                                                                                len67,
+                                                                            );
+                                                                            _rt::string_lift(bytes67)
+                                                                        };
+                                                                        V72::Constant(e72)
+                                                                    }
+                                                                    2 => {
+                                                                        let e72 = {
+                                                                            let l68 = *base
+                                                                                .add(::core::mem::size_of::<*const u8>())
+                                                                                .cast::<*mut u8>();
+                                                                            let l69 = *base
+                                                                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                                                                .cast::<usize>();
+                                                                            let len70 = l69;
+                                                                            let bytes70 = _rt::Vec::from_raw_parts(
+                                                                                l68.cast(),
+                                                                                len70,
+                                                                                len70,
+                                                                            );
+                                                                            _rt::string_lift(bytes70)
+                                                                        };
+                                                                        V72::Description(e72)
+                                                                    }
+                                                                    3 => V72::Unspecified,
+                                                                    n => {
+                                                                        debug_assert_eq!(n, 4, "invalid enum discriminant");
+                                                                        let e72 = {
+                                                                            let l71 = *base
+                                                                                .add(::core::mem::size_of::<*const u8>())
+                                                                                .cast::<i32>();
+                                                                            l71 as u32
+                                                                        };
+                                                                        V72::Proposition(e72)
+                                                                    }
+                                                                };
+                                                                v72
+                                                            };
+                                                            result73.push(e73);
+                                                        }
+                                                        _rt::cabi_dealloc(
+                                                            base73,
+                                                            len73 * (3 * ::core::mem::size_of::<*const u8>()),
+                                                            ::core::mem::size_of::<*const u8>(),
+                                                        );
+                                                        (_rt::string_lift(bytes58), result73)
+                                                    };
+                                                    V107::Predicate(e107)
+                                                }
+                                                1 => {
+                                                    let e107 = {
+                                                        let l74 = *base
+                                                            .add(::core::mem::size_of::<*const u8>())
+                                                            .cast::<i32>();
+                                                        let l75 = *base
+                                                            .add(4 + 1 * ::core::mem::size_of::<*const u8>())
+                                                            .cast::<i32>();
+                                                        (l74 as u32, l75 as u32)
+                                                    };
+                                                    V107::AndNode(e107)
+                                                }
+                                                2 => {
+                                                    let e107 = {
+                                                        let l76 = *base
+                                                            .add(::core::mem::size_of::<*const u8>())
+                                                            .cast::<i32>();
+                                                        let l77 = *base
+                                                            .add(4 + 1 * ::core::mem::size_of::<*const u8>())
+                                                            .cast::<i32>();
+                                                        (l76 as u32, l77 as u32)
+                                                    };
+                                                    V107::OrNode(e107)
+                                                }
+                                                3 => {
+                                                    let e107 = {
+                                                        let l78 = *base
+                                                            .add(::core::mem::size_of::<*const u8>())
+                                                            .cast::<i32>();
+                                                        let l79 = *base
+                                                            .add(4 + 1 * ::core::mem::size_of::<*const u8>())
+                                                            .cast::<i32>();
+                                                        (l78 as u32, l79 as u32)
+                                                    };
+                                                    V107::ImpliesNode(e107)
+                                                }
+                                                4 => {
+                                                    let e107 = {
+                                                        let l80 = *base
+                                                            .add(::core::mem::size_of::<*const u8>())
+                                                            .cast::<i32>();
+                                                        l80 as u32
+                                                    };
+                                                    V107::NotNode(e107)
+                                                }
+                                                5 => {
+                                                    let e107 = {
+                                                        let l81 = *base
+                                                            .add(::core::mem::size_of::<*const u8>())
+                                                            .cast::<*mut u8>();
+                                                        let l82 = *base
+                                                            .add(2 * ::core::mem::size_of::<*const u8>())
+                                                            .cast::<usize>();
+                                                        let len83 = l82;
+                                                        let bytes83 = _rt::Vec::from_raw_parts(
+                                                            l81.cast(),
+                                                            len83,
+                                                            len83,
+                                                        );
+                                                        let l84 = *base
+                                                            .add(3 * ::core::mem::size_of::<*const u8>())
+                                                            .cast::<i32>();
+                                                        (_rt::string_lift(bytes83), l84 as u32)
+                                                    };
+                                                    V107::ExistsNode(e107)
+                                                }
+                                                6 => {
+                                                    let e107 = {
+                                                        let l85 = *base
+                                                            .add(::core::mem::size_of::<*const u8>())
+                                                            .cast::<*mut u8>();
+                                                        let l86 = *base
+                                                            .add(2 * ::core::mem::size_of::<*const u8>())
+                                                            .cast::<usize>();
+                                                        let len87 = l86;
+                                                        let bytes87 = _rt::Vec::from_raw_parts(
+                                                            l85.cast(),
+                                                            len87,
+                                                            len87,
+                                                        );
+                                                        let l88 = *base
+                                                            .add(3 * ::core::mem::size_of::<*const u8>())
+                                                            .cast::<i32>();
+                                                        (_rt::string_lift(bytes87), l88 as u32)
+                                                    };
+                                                    V107::ForAllNode(e107)
+                                                }
+                                                7 => {
+                                                    let e107 = {
+                                                        let l89 = i32::from(
+                                                            *base.add(::core::mem::size_of::<*const u8>()).cast::<u8>(),
+                                                        );
+                                                        use super::super::super::lojban::nesy::ast_types::LogicalTerm as V100;
+                                                        let v100 = match l89 {
+                                                            0 => {
+                                                                let e100 = {
+                                                                    let l90 = *base
+                                                                        .add(2 * ::core::mem::size_of::<*const u8>())
+                                                                        .cast::<*mut u8>();
+                                                                    let l91 = *base
+                                                                        .add(3 * ::core::mem::size_of::<*const u8>())
+                                                                        .cast::<usize>();
+                                                                    let len92 = l91;
+                                                                    let bytes92 = _rt::Vec::from_raw_parts(
+                                                                        l90.cast(),
+                                                                        len92,
+                                                                        len92,
+                                                                    );
+                                                                    _rt::string_lift(bytes92)
+                                                                };
+                                                                V100::Variable(e100)
+                                                            }
+                                                            1 => {
+                                                                let e100 = {
+                                                                    let l93 = *base
+                                                                        .add(2 * ::core::mem::size_of::<*const u8>())
+                                                                        .cast::<*mut u8>();
+                                                                    let l94 = *base
+                                                                        .add(3 * ::core::mem::size_of::<*const u8>())
+                                                                        .cast::<usize>();
+                                                                    let len95 = l94;
+                                                                    let bytes95 = _rt::Vec::from_raw_parts(
+                                                                        l93.cast(),
+                                                                        len95,
+                                                                        len95,
+                                                                    );
+                                                                    _rt::string_lift(bytes95)
+                                                                };
+                                                                V100::Constant(e100)
+                                                            }
+                                                            2 => {
+                                                                let e100 = {
+                                                                    let l96 = *base
+                                                                        .add(2 * ::core::mem::size_of::<*const u8>())
+                                                                        .cast::<*mut u8>();
+                                                                    let l97 = *base
+                                                                        .add(3 * ::core::mem::size_of::<*const u8>())
+                                                                        .cast::<usize>();
+                                                                    let len98 = l97;
+                                                                    let bytes98 = _rt::Vec::from_raw_parts(
+                                                                        l96.cast(),
+                                                                        len98,
+                                                                        len98,
+                                                                    );
+                                                                    _rt::string_lift(bytes98)
+                                                                };
+                                                                V100::Description(e100)
+                                                            }
+                                                            3 => V100::Unspecified,
+                                                            n => {
+                                                                debug_assert_eq!(n, 4, "invalid enum discriminant");
+                                                                let e100 = {
+                                                                    let l99 = *base
+                                                                        .add(2 * ::core::mem::size_of::<*const u8>())
+                                                                        .cast::<i32>();
+                                                                    l99 as u32
+                                                                };
+                                                                V100::Proposition(e100)
+                                                            }
+                                                        };
+                                                        let l101 = *base
+                                                            .add(4 * ::core::mem::size_of::<*const u8>())
+                                                            .cast::<i32>();
+                                                        (v100, l101 as u32)
+                                                    };
+                                                    V107::AtNode(e107)
+                                                }
+                                                n => {
+                                                    debug_assert_eq!(n, 8, "invalid enum discriminant");
+                                                    let e107 = {
+                                                        let l102 = *base
+                                                            .add(::core::mem::size_of::<*const u8>())
+                                                            .cast::<*mut u8>();
+                                                        let l103 = *base
+                                                            .add(2 * ::core::mem::size_of::<*const u8>())
+                                                            .cast::<usize>();
+                                                        let len104 = l103;
+                                                        let bytes104 = _rt::Vec::from_raw_parts(
+                                                            l102.cast(),
+                                                            len104,
+                                                            len104,
+                                                        );
+                                                        let l105 = *base
+                                                            .add(3 * ::core::mem::size_of::<*const u8>())
+                                                            .cast::<i32>();
+                                                        let l106 = *base
+                                                            .add(4 + 3 * ::core::mem::size_of::<*const u8>())
+                                                            .cast::<i32>();
+                                                        (_rt::string_lift(bytes104), l105 as u32, l106 as u32)
+                                                    };
+                                                    V107::GenericNode(e107)
+                                                }
+                                            };
+                                            v107
+                                        };
+                                        result108.push(e108);
+                                    }
+                                    _rt::cabi_dealloc(
+                                        base108,
+                                        len108 * (5 * ::core::mem::size_of::<*const u8>()),
+                                        ::core::mem::size_of::<*const u8>(),
+                                    );
+                                    let l109 = *ptr50
+                                        .add(3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>();
+                                    let l110 = *ptr50
+                                        .add(4 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    let len111 = l110;
+                                    super::super::super::lojban::nesy::ast_types::LogicBuffer {
+                                        nodes: result108,
+                                        roots: _rt::Vec::from_raw_parts(l109.cast(), len111, len111),
+                                    }
+                                };
+                                Ok(e)
+                            }
+                            1 => {
+                                let e = {
+                                    let l112 = *ptr50
                                         .add(::core::mem::size_of::<*const u8>())
-                                        .cast::<i32>() = _rt::as_i32(t8_0);
-                                    *base
-                                        .add(4 + 1 * ::core::mem::size_of::<*const u8>())
-                                        .cast::<u8>() = (t8_1.clone() as i32) as u8;
-                                    *base
-                                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
-                                        .cast::<i32>() = _rt::as_i32(t8_2);
-                                }
+                                        .cast::<*mut u8>();
+                                    let l113 = *ptr50
+                                        .add(2 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    let len114 = l113;
+                                    let bytes114 = _rt::Vec::from_raw_parts(
+                                        l112.cast(),
+                                        len114,
+                                        len114,
+                                    );
+                                    _rt::string_lift(bytes114)
+                                };
+                                Err(e)
+                            }
+                            _ => _rt::invalid_enum_discriminant(),
+                        };
+                        if layout11.size() != 0 {
+                            _rt::alloc::dealloc(result11.cast(), layout11);
+                        }
+                        if layout31.size() != 0 {
+                            _rt::alloc::dealloc(result31.cast(), layout31);
+                        }
+                        if layout39.size() != 0 {
+                            _rt::alloc::dealloc(result39.cast(), layout39);
+                        }
+                        if layout48.size() != 0 {
+                            _rt::alloc::dealloc(result48.cast(), layout48);
+                        }
+                        for (ptr, layout) in cleanup_list {
+                            if layout.size() != 0 {
+                                _rt::alloc::dealloc(ptr.cast(), layout);
                             }
                         }
+                        result115
                     }
-                    let vec19 = sumtis0;
-                    let len19 = vec19.len();
-                    let layout19 = _rt::alloc::Layout::from_size_align_unchecked(
-                        vec19.len() * (8 + 2 * ::core::mem::size_of::<*const u8>()),
-                        ::core::mem::size_of::<*const u8>(),
-                    );
-                    let result19 = if layout19.size() != 0 {
-                        let ptr = _rt::alloc::alloc(layout19).cast::<u8>();
-                        if ptr.is_null() {
-                            _rt::alloc::handle_alloc_error(layout19);
+                }
+            }
+            impl Compiler {
+                #[allow(unused_unsafe, clippy::all)]
+                /// Forget the discourse.
+                pub fn reset(&self) -> () {
+                    unsafe {
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "lojban:nesy/semantics@0.1.0")]
+                        unsafe extern "C" {
+                            #[link_name = "[method]compiler.reset"]
+                            fn wit_import0(_: i32);
                         }
-                        ptr
-                    } else {
-                        ::core::ptr::null_mut()
-                    };
-                    for (i, e) in vec19.into_iter().enumerate() {
-                        let base = result19
-                            .add(i * (8 + 2 * ::core::mem::size_of::<*const u8>()));
-                        {
-                            use super::super::super::lojban::nesy::ast_types::Sumti as V18;
-                            match e {
-                                V18::ProSumti(e) => {
-                                    *base.add(0).cast::<u8>() = (0i32) as u8;
-                                    let vec11 = e;
-                                    let ptr11 = vec11.as_ptr().cast::<u8>();
-                                    let len11 = vec11.len();
-                                    *base
-                                        .add(2 * ::core::mem::size_of::<*const u8>())
-                                        .cast::<usize>() = len11;
-                                    *base
-                                        .add(::core::mem::size_of::<*const u8>())
-                                        .cast::<*mut u8>() = ptr11.cast_mut();
-                                }
-                                V18::Description(e) => {
-                                    *base.add(0).cast::<u8>() = (1i32) as u8;
-                                    let (t12_0, t12_1) = e;
-                                    *base
-                                        .add(::core::mem::size_of::<*const u8>())
-                                        .cast::<u8>() = (t12_0.clone() as i32) as u8;
-                                    *base
-                                        .add(4 + 1 * ::core::mem::size_of::<*const u8>())
-                                        .cast::<i32>() = _rt::as_i32(t12_1);
+                        #[cfg(not(target_arch = "wasm32"))]
+                        unsafe extern "C" fn wit_import0(_: i32) {
+                            unreachable!()
+                        }
+                        unsafe { wit_import0((self).handle() as i32) };
+                    }
+                }
+            }
+            impl Compiler {
+                #[allow(unused_unsafe, clippy::all)]
+                /// A copy of the compiler whose discourse goes on independently.
+                pub fn fork(&self) -> Compiler {
+                    unsafe {
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "lojban:nesy/semantics@0.1.0")]
+                        unsafe extern "C" {
+                            #[link_name = "[method]compiler.fork"]
+                            fn wit_import0(_: i32) -> i32;
+                        }
+                        #[cfg(not(target_arch = "wasm32"))]
+                        unsafe extern "C" fn wit_import0(_: i32) -> i32 {
+                            unreachable!()
+                        }
+                        let ret = unsafe { wit_import0((self).handle() as i32) };
+                        unsafe { Compiler::from_handle(ret as u32) }
+                    }
+                }
+            }
+        }
+        #[allow(dead_code, async_fn_in_trait, unused_imports, clippy::all)]
+        pub mod reasoning {
+            #[used]
+            #[doc(hidden)]
+            static __FORCE_SECTION_REF: fn() = super::super::super::__link_custom_section_describing_imports;
+            use super::super::super::_rt;
+            pub type LogicBuffer = super::super::super::lojban::nesy::ast_types::LogicBuffer;
+            /// What a question variable was bound to.
+            #[derive(Clone)]
+            pub enum AnswerTerm {
+                /// An entity, for ma: a name, pro-sumti or Skolem constant.
+                Entity(_rt::String),
+                /// A relation, for mo.
+                Relation(_rt::String),
+            }
+            impl ::core::fmt::Debug for AnswerTerm {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    match self {
+                        AnswerTerm::Entity(e) => {
+                            f.debug_tuple("AnswerTerm::Entity").field(e).finish()
+                        }
+                        AnswerTerm::Relation(e) => {
+                            f.debug_tuple("AnswerTerm::Relation").field(e).finish()
+                        }
+                    }
+                }
+            }
+            #[derive(Clone)]
+            pub struct Binding {
+                pub variable: _rt::String,
+                pub value: AnswerTerm,
+            }
+            impl ::core::fmt::Debug for Binding {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    f.debug_struct("Binding")
+                        .field("variable", &self.variable)
+                        .field("value", &self.value)
+                        .finish()
+                }
+            }
+            /// One way of filling every question variable.
+            pub type Substitution = _rt::Vec<Binding>;
+            /// The outcome of a query.
+            #[repr(u8)]
+            #[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+            pub enum TruthValue {
+                /// The knowledge base entails the formula.
+                Entailed,
+                /// The knowledge base entails its negation.
+                Refuted,
+                /// Neither (open world only).
+                Unknown,
+            }
+            impl ::core::fmt::Debug for TruthValue {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    match self {
+                        TruthValue::Entailed => {
+                            f.debug_tuple("TruthValue::Entailed").finish()
+                        }
+                        TruthValue::Refuted => {
+                            f.debug_tuple("TruthValue::Refuted").finish()
+                        }
+                        TruthValue::Unknown => {
+                            f.debug_tuple("TruthValue::Unknown").finish()
+                        }
+                    }
+                }
+            }
+            impl TruthValue {
+                #[doc(hidden)]
+                pub unsafe fn _lift(val: u8) -> TruthValue {
+                    if !cfg!(debug_assertions) {
+                        return ::core::mem::transmute(val);
+                    }
+                    match val {
+                        0 => TruthValue::Entailed,
+                        1 => TruthValue::Refuted,
+                        2 => TruthValue::Unknown,
+                        _ => panic!("invalid enum discriminant"),
+                    }
+                }
+            }
+            /// How a query treats what the knowledge base does not say.
+            #[repr(u8)]
+            #[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+            pub enum WorldAssumption {
+                /// Whatever cannot be proven is false: na is negation as failure
+                /// and ro ranges over the known entities.
+                Closed,
+                /// Only proofs count: a formula is refuted when its negation is
+                /// entailed, and unknown when neither is.
+                Open,
+            }
+            impl ::core::fmt::Debug for WorldAssumption {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    match self {
+                        WorldAssumption::Closed => {
+                            f.debug_tuple("WorldAssumption::Closed").finish()
+                        }
+                        WorldAssumption::Open => {
+                            f.debug_tuple("WorldAssumption::Open").finish()
+                        }
+                    }
+                }
+            }
+            impl WorldAssumption {
+                #[doc(hidden)]
+                pub unsafe fn _lift(val: u8) -> WorldAssumption {
+                    if !cfg!(debug_assertions) {
+                        return ::core::mem::transmute(val);
+                    }
+                    match val {
+                        0 => WorldAssumption::Closed,
+                        1 => WorldAssumption::Open,
+                        _ => panic!("invalid enum discriminant"),
+                    }
+                }
+            }
+            /// A formula that holds together with its negation.
+            #[derive(Clone)]
+            pub struct Conflict {
+                /// The formula, as an s-expression.
+                pub formula: _rt::String,
+                /// The source sentences it was asserted or derived from.
+                pub sources: _rt::Vec<_rt::String>,
+            }
+            impl ::core::fmt::Debug for Conflict {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    f.debug_struct("Conflict")
+                        .field("formula", &self.formula)
+                        .field("sources", &self.sources)
+                        .finish()
+                }
+            }
+            /// What assert-fact does with an assertion that would make the
+            /// knowledge base inconsistent. Either way the knowledge base is left
+            /// as it was.
+            #[repr(u8)]
+            #[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+            pub enum ConflictPolicy {
+                /// Drop the assertion.
+                Reject,
+                /// Keep the assertion aside, with its conflicts (see `quarantined`).
+                Quarantine,
+            }
+            impl ::core::fmt::Debug for ConflictPolicy {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    match self {
+                        ConflictPolicy::Reject => {
+                            f.debug_tuple("ConflictPolicy::Reject").finish()
+                        }
+                        ConflictPolicy::Quarantine => {
+                            f.debug_tuple("ConflictPolicy::Quarantine").finish()
+                        }
+                    }
+                }
+            }
+            impl ConflictPolicy {
+                #[doc(hidden)]
+                pub unsafe fn _lift(val: u8) -> ConflictPolicy {
+                    if !cfg!(debug_assertions) {
+                        return ::core::mem::transmute(val);
+                    }
+                    match val {
+                        0 => ConflictPolicy::Reject,
+                        1 => ConflictPolicy::Quarantine,
+                        _ => panic!("invalid enum discriminant"),
+                    }
+                }
+            }
+            #[derive(Clone)]
+            pub enum AssertError {
+                /// The assertion contradicts the knowledge base and was not added.
+                Conflict(_rt::Vec<Conflict>),
+                /// The assertion could not be processed.
+                Failed(_rt::String),
+            }
+            impl ::core::fmt::Debug for AssertError {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    match self {
+                        AssertError::Conflict(e) => {
+                            f.debug_tuple("AssertError::Conflict").field(e).finish()
+                        }
+                        AssertError::Failed(e) => {
+                            f.debug_tuple("AssertError::Failed").field(e).finish()
+                        }
+                    }
+                }
+            }
+            impl ::core::fmt::Display for AssertError {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    write!(f, "{:?}", self)
+                }
+            }
+            impl std::error::Error for AssertError {}
+            /// Names one assert-fact call, for retracting it later.
+            pub type FactHandle = u64;
+            /// An assertion set aside by the quarantine policy.
+            #[derive(Clone)]
+            pub struct QuarantinedFact {
+                pub sources: _rt::Vec<_rt::String>,
+                pub conflicts: _rt::Vec<Conflict>,
+            }
+            impl ::core::fmt::Debug for QuarantinedFact {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    f.debug_struct("QuarantinedFact")
+                        .field("sources", &self.sources)
+                        .field("conflicts", &self.conflicts)
+                        .finish()
+                }
+            }
+            /// An isolated knowledge base: its own e-graph, entities, universals,
+            /// defaults, facts and quarantine. A host can hold any number of them
+            /// (say one per session or test case); dropping one frees its state.
+            #[derive(Debug)]
+            #[repr(transparent)]
+            pub struct KnowledgeBase {
+                handle: _rt::Resource<KnowledgeBase>,
+            }
+            impl KnowledgeBase {
+                #[doc(hidden)]
+                pub unsafe fn from_handle(handle: u32) -> Self {
+                    Self {
+                        handle: unsafe { _rt::Resource::from_handle(handle) },
+                    }
+                }
+                #[doc(hidden)]
+                pub fn take_handle(&self) -> u32 {
+                    _rt::Resource::take_handle(&self.handle)
+                }
+                #[doc(hidden)]
+                pub fn handle(&self) -> u32 {
+                    _rt::Resource::handle(&self.handle)
+                }
+            }
+            unsafe impl _rt::WasmResource for KnowledgeBase {
+                #[inline]
+                unsafe fn drop(_handle: u32) {
+                    #[cfg(not(target_arch = "wasm32"))]
+                    unreachable!();
+                    #[cfg(target_arch = "wasm32")]
+                    {
+                        #[link(wasm_import_module = "lojban:nesy/reasoning@0.1.0")]
+                        unsafe extern "C" {
+                            #[link_name = "[resource-drop]knowledge-base"]
+                            fn drop(_: u32);
+                        }
+                        unsafe { drop(_handle) };
+                    }
+                }
+            }
+            impl KnowledgeBase {
+                #[allow(unused_unsafe, clippy::all)]
+                /// An empty knowledge base.
+                pub fn new() -> Self {
+                    unsafe {
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "lojban:nesy/reasoning@0.1.0")]
+                        unsafe extern "C" {
+                            #[link_name = "[constructor]knowledge-base"]
+                            fn wit_import0() -> i32;
+                        }
+                        #[cfg(not(target_arch = "wasm32"))]
+                        unsafe extern "C" fn wit_import0() -> i32 {
+                            unreachable!()
+                        }
+                        let ret = unsafe { wit_import0() };
+                        unsafe { KnowledgeBase::from_handle(ret as u32) }
+                    }
+                }
+            }
+            impl KnowledgeBase {
+                #[allow(unused_unsafe, clippy::all)]
+                /// Assert each root of `logic`; `sources` holds the source sentence
+                /// of each root. All roots are added, or none.
+                pub fn assert_fact(
+                    &self,
+                    logic: &LogicBuffer,
+                    sources: &[_rt::String],
+                    policy: ConflictPolicy,
+                ) -> Result<FactHandle, AssertError> {
+                    unsafe {
+                        let mut cleanup_list = _rt::Vec::new();
+                        #[repr(align(8))]
+                        struct RetArea(
+                            [::core::mem::MaybeUninit<
+                                u8,
+                            >; 16 + 2 * ::core::mem::size_of::<*const u8>()],
+                        );
+                        let mut ret_area = RetArea(
+                            [::core::mem::MaybeUninit::uninit(); 16
+                                + 2 * ::core::mem::size_of::<*const u8>()],
+                        );
+                        let super::super::super::lojban::nesy::ast_types::LogicBuffer {
+                            nodes: nodes0,
+                            roots: roots0,
+                        } = logic;
+                        let vec23 = nodes0;
+                        let len23 = vec23.len();
+                        let layout23 = _rt::alloc::Layout::from_size_align_unchecked(
+                            vec23.len() * (5 * ::core::mem::size_of::<*const u8>()),
+                            ::core::mem::size_of::<*const u8>(),
+                        );
+                        let result23 = if layout23.size() != 0 {
+                            let ptr = _rt::alloc::alloc(layout23).cast::<u8>();
+                            if ptr.is_null() {
+                                _rt::alloc::handle_alloc_error(layout23);
+                            }
+                            ptr
+                        } else {
+                            ::core::ptr::null_mut()
+                        };
+                        for (i, e) in vec23.into_iter().enumerate() {
+                            let base = result23
+                                .add(i * (5 * ::core::mem::size_of::<*const u8>()));
+                            {
+                                use super::super::super::lojban::nesy::ast_types::LogicNode as V22;
+                                match e {
+                                    V22::Predicate(e) => {
+                                        *base.add(0).cast::<u8>() = (0i32) as u8;
+                                        let (t1_0, t1_1) = e;
+                                        let vec2 = t1_0;
+                                        let ptr2 = vec2.as_ptr().cast::<u8>();
+                                        let len2 = vec2.len();
+                                        *base
+                                            .add(2 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<usize>() = len2;
+                                        *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<*mut u8>() = ptr2.cast_mut();
+                                        let vec7 = t1_1;
+                                        let len7 = vec7.len();
+                                        let layout7 = _rt::alloc::Layout::from_size_align_unchecked(
+                                            vec7.len() * (3 * ::core::mem::size_of::<*const u8>()),
+                                            ::core::mem::size_of::<*const u8>(),
+                                        );
+                                        let result7 = if layout7.size() != 0 {
+                                            let ptr = _rt::alloc::alloc(layout7).cast::<u8>();
+                                            if ptr.is_null() {
+                                                _rt::alloc::handle_alloc_error(layout7);
+                                            }
+                                            ptr
+                                        } else {
+                                            ::core::ptr::null_mut()
+                                        };
+                                        for (i, e) in vec7.into_iter().enumerate() {
+                                            let base = result7
+                                                .add(i * (3 * ::core::mem::size_of::<*const u8>()));
+                                            {
+                                                use super::super::super::lojban::nesy::ast_types::LogicalTerm as V6;
+                                                match e {
+                                                    V6::Variable(e) => {
+                                                        *base.add(0).cast::<u8>() = (0i32) as u8;
+                                                        let vec3 = e;
+                                                        let ptr3 = vec3.as_ptr().cast::<u8>();
+                                                        let len3 = vec3.len();
+                                                        *base
+                                                            .add(2 * ::core::mem::size_of::<*const u8>())
+                                                            .cast::<usize>() = len3;
+                                                        *base
+                                                            .add(::core::mem::size_of::<*const u8>())
+                                                            .cast::<*mut u8>() = ptr3.cast_mut();
+                                                    }
+                                                    V6::Constant(e) => {
+                                                        *base.add(0).cast::<u8>() = (1i32) as u8;
+                                                        let vec4 = e;
+                                                        let ptr4 = vec4.as_ptr().cast::<u8>();
+                                                        let len4 = vec4.len();
+                                                        *base
+                                                            .add(2 * ::core::mem::size_of::<*const u8>())
+                                                            .cast::<usize>() = len4;
+                                                        *base
+                                                            .add(::core::mem::size_of::<*const u8>())
+                                                            .cast::<*mut u8>() = ptr4.cast_mut();
+                                                    }
+                                                    V6::Description(e) => {
+                                                        *base.add(0).cast::<u8>() = (2i32) as u8;
+                                                        let vec5 = e;
+                                                        let ptr5 = vec5.as_ptr().cast::<u8>();
+                                                        let len5 = vec5.len();
+                                                        *base
+                                                            .add(2 * ::core::mem::size_of::<*const u8>())
+                                                            .cast::<usize>() = len5;
+                                                        *base
+                                                            .add(::core::mem::size_of::<*const u8>())
+                                                            .cast::<*mut u8>() = ptr5.cast_mut();
+                                                    }
+                                                    V6::Unspecified => {
+                                                        *base.add(0).cast::<u8>() = (3i32) as u8;
+                                                    }
+                                                    V6::Proposition(e) => {
+                                                        *base.add(0).cast::<u8>() = (4i32) as u8;
+                                                        *base
+                                                            .add(::core::mem::size_of::<*const u8>())
+                                                            .cast::<i32>() = _rt::as_i32(e);
+                                                    }
+                                                }
+                                            }
+                                        }
+                                        *base
+                                            .add(4 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<usize>() = len7;
+                                        *base
+                                            .add(3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<*mut u8>() = result7;
+                                        cleanup_list.extend_from_slice(&[(result7, layout7)]);
+                                    }
+                                    V22::AndNode(e) => {
+                                        *base.add(0).cast::<u8>() = (1i32) as u8;
+                                        let (t8_0, t8_1) = e;
+                                        *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>() = _rt::as_i32(t8_0);
+                                        *base
+                                            .add(4 + 1 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>() = _rt::as_i32(t8_1);
+                                    }
+                                    V22::OrNode(e) => {
+                                        *base.add(0).cast::<u8>() = (2i32) as u8;
+                                        let (t9_0, t9_1) = e;
+                                        *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>() = _rt::as_i32(t9_0);
+                                        *base
+                                            .add(4 + 1 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>() = _rt::as_i32(t9_1);
+                                    }
+                                    V22::ImpliesNode(e) => {
+                                        *base.add(0).cast::<u8>() = (3i32) as u8;
+                                        let (t10_0, t10_1) = e;
+                                        *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>() = _rt::as_i32(t10_0);
+                                        *base
+                                            .add(4 + 1 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>() = _rt::as_i32(t10_1);
+                                    }
+                                    V22::NotNode(e) => {
+                                        *base.add(0).cast::<u8>() = (4i32) as u8;
+                                        *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>() = _rt::as_i32(e);
+                                    }
+                                    V22::ExistsNode(e) => {
+                                        *base.add(0).cast::<u8>() = (5i32) as u8;
+                                        let (t11_0, t11_1) = e;
+                                        let vec12 = t11_0;
+                                        let ptr12 = vec12.as_ptr().cast::<u8>();
+                                        let len12 = vec12.len();
+                                        *base
+                                            .add(2 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<usize>() = len12;
+                                        *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<*mut u8>() = ptr12.cast_mut();
+                                        *base
+                                            .add(3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>() = _rt::as_i32(t11_1);
+                                    }
+                                    V22::ForAllNode(e) => {
+                                        *base.add(0).cast::<u8>() = (6i32) as u8;
+                                        let (t13_0, t13_1) = e;
+                                        let vec14 = t13_0;
+                                        let ptr14 = vec14.as_ptr().cast::<u8>();
+                                        let len14 = vec14.len();
+                                        *base
+                                            .add(2 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<usize>() = len14;
+                                        *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<*mut u8>() = ptr14.cast_mut();
+                                        *base
+                                            .add(3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>() = _rt::as_i32(t13_1);
+                                    }
+                                    V22::AtNode(e) => {
+                                        *base.add(0).cast::<u8>() = (7i32) as u8;
+                                        let (t15_0, t15_1) = e;
+                                        use super::super::super::lojban::nesy::ast_types::LogicalTerm as V19;
+                                        match t15_0 {
+                                            V19::Variable(e) => {
+                                                *base
+                                                    .add(::core::mem::size_of::<*const u8>())
+                                                    .cast::<u8>() = (0i32) as u8;
+                                                let vec16 = e;
+                                                let ptr16 = vec16.as_ptr().cast::<u8>();
+                                                let len16 = vec16.len();
+                                                *base
+                                                    .add(3 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<usize>() = len16;
+                                                *base
+                                                    .add(2 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<*mut u8>() = ptr16.cast_mut();
+                                            }
+                                            V19::Constant(e) => {
+                                                *base
+                                                    .add(::core::mem::size_of::<*const u8>())
+                                                    .cast::<u8>() = (1i32) as u8;
+                                                let vec17 = e;
+                                                let ptr17 = vec17.as_ptr().cast::<u8>();
+                                                let len17 = vec17.len();
+                                                *base
+                                                    .add(3 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<usize>() = len17;
+                                                *base
+                                                    .add(2 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<*mut u8>() = ptr17.cast_mut();
+                                            }
+                                            V19::Description(e) => {
+                                                *base
+                                                    .add(::core::mem::size_of::<*const u8>())
+                                                    .cast::<u8>() = (2i32) as u8;
+                                                let vec18 = e;
+                                                let ptr18 = vec18.as_ptr().cast::<u8>();
+                                                let len18 = vec18.len();
+                                                *base
+                                                    .add(3 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<usize>() = len18;
+                                                *base
+                                                    .add(2 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<*mut u8>() = ptr18.cast_mut();
+                                            }
+                                            V19::Unspecified => {
+                                                *base
+                                                    .add(::core::mem::size_of::<*const u8>())
+                                                    .cast::<u8>() = (3i32) as u8;
+                                            }
+                                            V19::Proposition(e) => {
+                                                *base
+                                                    .add(::core::mem::size_of::<*const u8>())
+                                                    .cast::<u8>() = (4i32) as u8;
+                                                *base
+                                                    .add(2 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<i32>() = _rt::as_i32(e);
+                                            }
+                                        }
+                                        *base
+                                            .add(4 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>() = _rt::as_i32(t15_1);
+                                    }
+                                    V22::GenericNode(e) => {
+                                        *base.add(0).cast::<u8>() = (8i32) as u8;
+                                        let (t20_0, t20_1, t20_2) = e;
+                                        let vec21 = t20_0;
+                                        let ptr21 = vec21.as_ptr().cast::<u8>();
+                                        let len21 = vec21.len();
+                                        *base
+                                            .add(2 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<usize>() = len21;
+                                        *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<*mut u8>() = ptr21.cast_mut();
+                                        *base
+                                            .add(3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>() = _rt::as_i32(t20_1);
+                                        *base
+                                            .add(4 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>() = _rt::as_i32(t20_2);
+                                    }
                                 }
-                                V18::Name(e) => {
-                                    *base.add(0).cast::<u8>() = (2i32) as u8;
-                                    let vec13 = e;
-                                    let ptr13 = vec13.as_ptr().cast::<u8>();
-                                    let len13 = vec13.len();
-                                    *base
-                                        .add(2 * ::core::mem::size_of::<*const u8>())
-                                        .cast::<usize>() = len13;
-                                    *base
+                            }
+                        }
+                        let vec24 = roots0;
+                        let ptr24 = vec24.as_ptr().cast::<u8>();
+                        let len24 = vec24.len();
+                        let vec26 = sources;
+                        let len26 = vec26.len();
+                        let layout26 = _rt::alloc::Layout::from_size_align_unchecked(
+                            vec26.len() * (2 * ::core::mem::size_of::<*const u8>()),
+                            ::core::mem::size_of::<*const u8>(),
+                        );
+                        let result26 = if layout26.size() != 0 {
+                            let ptr = _rt::alloc::alloc(layout26).cast::<u8>();
+                            if ptr.is_null() {
+                                _rt::alloc::handle_alloc_error(layout26);
+                            }
+                            ptr
+                        } else {
+                            ::core::ptr::null_mut()
+                        };
+                        for (i, e) in vec26.into_iter().enumerate() {
+                            let base = result26
+                                .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                            {
+                                let vec25 = e;
+                                let ptr25 = vec25.as_ptr().cast::<u8>();
+                                let len25 = vec25.len();
+                                *base
+                                    .add(::core::mem::size_of::<*const u8>())
+                                    .cast::<usize>() = len25;
+                                *base.add(0).cast::<*mut u8>() = ptr25.cast_mut();
+                            }
+                        }
+                        let ptr27 = ret_area.0.as_mut_ptr().cast::<u8>();
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "lojban:nesy/reasoning@0.1.0")]
+                        unsafe extern "C" {
+                            #[link_name = "[method]knowledge-base.assert-fact"]
+                            fn wit_import28(
+                                _: i32,
+                                _: *mut u8,
+                                _: usize,
+                                _: *mut u8,
+                                _: usize,
+                                _: *mut u8,
+                                _: usize,
+                                _: i32,
+                                _: *mut u8,
+                            );
+                        }
+                        #[cfg(not(target_arch = "wasm32"))]
+                        unsafe extern "C" fn wit_import28(
+                            _: i32,
+                            _: *mut u8,
+                            _: usize,
+                            _: *mut u8,
+                            _: usize,
+                            _: *mut u8,
+                            _: usize,
+                            _: i32,
+                            _: *mut u8,
+                        ) {
+                            unreachable!()
+                        }
+                        unsafe {
+                            wit_import28(
+                                (self).handle() as i32,
+                                result23,
+                                len23,
+                                ptr24.cast_mut(),
+                                len24,
+                                result26,
+                                len26,
+                                policy.clone() as i32,
+                                ptr27,
+                            )
+                        };
+                        let l29 = i32::from(*ptr27.add(0).cast::<u8>());
+                        let result48 = match l29 {
+                            0 => {
+                                let e = {
+                                    let l30 = *ptr27.add(8).cast::<i64>();
+                                    l30 as u64
+                                };
+                                Ok(e)
+                            }
+                            1 => {
+                                let e = {
+                                    let l31 = i32::from(*ptr27.add(8).cast::<u8>());
+                                    let v47 = match l31 {
+                                        0 => {
+                                            let e47 = {
+                                                let l32 = *ptr27
+                                                    .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<*mut u8>();
+                                                let l33 = *ptr27
+                                                    .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<usize>();
+                                                let base43 = l32;
+                                                let len43 = l33;
+                                                let mut result43 = _rt::Vec::with_capacity(len43);
+                                                for i in 0..len43 {
+                                                    let base = base43
+                                                        .add(i * (4 * ::core::mem::size_of::<*const u8>()));
+                                                    let e43 = {
+                                                        let l34 = *base.add(0).cast::<*mut u8>();
+                                                        let l35 = *base
+                                                            .add(::core::mem::size_of::<*const u8>())
+                                                            .cast::<usize>();
+                                                        let len36 = l35;
+                                                        let bytes36 = _rt::Vec::from_raw_parts(
+                                                            l34.cast(),
+                                                            len36,
+                                                            len36,
+                                                        );
+                                                        let l37 = *base
+                                                            .add(2 * ::core::mem::size_of::<*const u8>())
+                                                            .cast::<*mut u8>();
+                                                        let l38 = *base
+                                                            .add(3 * ::core::mem::size_of::<*const u8>())
+                                                            .cast::<usize>();
+                                                        let base42 = l37;
+                                                        let len42 = l38;
+                                                        let mut result42 = _rt::Vec::with_capacity(len42);
+                                                        for i in 0..len42 {
+                                                            let base = base42
+                                                                .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                                                            let e42 = {
+                                                                let l39 = *base.add(0).cast::<*mut u8>();
+                                                                let l40 = *base
+                                                                    .add(::core::mem::size_of::<*const u8>())
+                                                                    .cast::<usize>();
+                                                                let len41 = l40;
+                                                                let bytes41 = _rt::Vec::from_raw_parts(
+                                                                    l39.cast(),
+                                                                    len41,
+                                                                    len41,
+                                                                );
+                                                                _rt::string_lift(bytes41)
+                                                            };
+                                                            result42.push(e42);
+                                                        }
+                                                        _rt::cabi_dealloc(
+                                                            base42,
+                                                            len42 * (2 * ::core::mem::size_of::<*const u8>()),
+                                                            ::core::mem::size_of::<*const u8>(),
+                                                        );
+                                                        Conflict {
+                                                            formula: _rt::string_lift(bytes36),
+                                                            sources: result42,
+                                                        }
+                                                    };
+                                                    result43.push(e43);
+                                                }
+                                                _rt::cabi_dealloc(
+                                                    base43,
+                                                    len43 * (4 * ::core::mem::size_of::<*const u8>()),
+                                                    ::core::mem::size_of::<*const u8>(),
+                                                );
+                                                result43
+                                            };
+                                            AssertError::Conflict(e47)
+                                        }
+                                        n => {
+                                            debug_assert_eq!(n, 1, "invalid enum discriminant");
+                                            let e47 = {
+                                                let l44 = *ptr27
+                                                    .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<*mut u8>();
+                                                let l45 = *ptr27
+                                                    .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<usize>();
+                                                let len46 = l45;
+                                                let bytes46 = _rt::Vec::from_raw_parts(
+                                                    l44.cast(),
+                                                    len46,
+                                                    len46,
+                                                );
+                                                _rt::string_lift(bytes46)
+                                            };
+                                            AssertError::Failed(e47)
+                                        }
+                                    };
+                                    v47
+                                };
+                                Err(e)
+                            }
+                            _ => _rt::invalid_enum_discriminant(),
+                        };
+                        if layout23.size() != 0 {
+                            _rt::alloc::dealloc(result23.cast(), layout23);
+                        }
+                        if layout26.size() != 0 {
+                            _rt::alloc::dealloc(result26.cast(), layout26);
+                        }
+                        for (ptr, layout) in cleanup_list {
+                            if layout.size() != 0 {
+                                _rt::alloc::dealloc(ptr.cast(), layout);
+                            }
+                        }
+                        result48
+                    }
+                }
+            }
+            impl KnowledgeBase {
+                #[allow(unused_unsafe, clippy::all)]
+                /// Withdraw an assertion, together with everything derived from it:
+                /// its universals' instances, its Skolem constants and any conclusion
+                /// that no remaining fact supports.
+                pub fn retract_fact(
+                    &self,
+                    handle: FactHandle,
+                ) -> Result<(), _rt::String> {
+                    unsafe {
+                        #[cfg_attr(target_pointer_width = "64", repr(align(8)))]
+                        #[cfg_attr(target_pointer_width = "32", repr(align(4)))]
+                        struct RetArea(
+                            [::core::mem::MaybeUninit<
+                                u8,
+                            >; 3 * ::core::mem::size_of::<*const u8>()],
+                        );
+                        let mut ret_area = RetArea(
+                            [::core::mem::MaybeUninit::uninit(); 3
+                                * ::core::mem::size_of::<*const u8>()],
+                        );
+                        let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "lojban:nesy/reasoning@0.1.0")]
+                        unsafe extern "C" {
+                            #[link_name = "[method]knowledge-base.retract-fact"]
+                            fn wit_import1(_: i32, _: i64, _: *mut u8);
+                        }
+                        #[cfg(not(target_arch = "wasm32"))]
+                        unsafe extern "C" fn wit_import1(_: i32, _: i64, _: *mut u8) {
+                            unreachable!()
+                        }
+                        unsafe {
+                            wit_import1(
+                                (self).handle() as i32,
+                                _rt::as_i64(handle),
+                                ptr0,
+                            )
+                        };
+                        let l2 = i32::from(*ptr0.add(0).cast::<u8>());
+                        let result6 = match l2 {
+                            0 => {
+                                let e = ();
+                                Ok(e)
+                            }
+                            1 => {
+                                let e = {
+                                    let l3 = *ptr0
                                         .add(::core::mem::size_of::<*const u8>())
-                                        .cast::<*mut u8>() = ptr13.cast_mut();
-                                }
-                                V18::QuotedLiteral(e) => {
-                                    *base.add(0).cast::<u8>() = (3i32) as u8;
-                                    let vec14 = e;
-                                    let ptr14 = vec14.as_ptr().cast::<u8>();
-                                    let len14 = vec14.len();
-                                    *base
+                                        .cast::<*mut u8>();
+                                    let l4 = *ptr0
                                         .add(2 * ::core::mem::size_of::<*const u8>())
-                                        .cast::<usize>() = len14;
-                                    *base
-                                        .add(::core::mem::size_of::<*const u8>())
-                                        .cast::<*mut u8>() = ptr14.cast_mut();
-                                }
-                                V18::Unspecified => {
-                                    *base.add(0).cast::<u8>() = (4i32) as u8;
+                                        .cast::<usize>();
+                                    let len5 = l4;
+                                    let bytes5 = _rt::Vec::from_raw_parts(
+                                        l3.cast(),
+                                        len5,
+                                        len5,
+                                    );
+                                    _rt::string_lift(bytes5)
+                                };
+                                Err(e)
+                            }
+                            _ => _rt::invalid_enum_discriminant(),
+                        };
+                        result6
+                    }
+                }
+            }
+            impl KnowledgeBase {
+                #[allow(unused_unsafe, clippy::all)]
+                pub fn quarantined(&self) -> _rt::Vec<QuarantinedFact> {
+                    unsafe {
+                        #[cfg_attr(target_pointer_width = "64", repr(align(8)))]
+                        #[cfg_attr(target_pointer_width = "32", repr(align(4)))]
+                        struct RetArea(
+                            [::core::mem::MaybeUninit<
+                                u8,
+                            >; 2 * ::core::mem::size_of::<*const u8>()],
+                        );
+                        let mut ret_area = RetArea(
+                            [::core::mem::MaybeUninit::uninit(); 2
+                                * ::core::mem::size_of::<*const u8>()],
+                        );
+                        let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "lojban:nesy/reasoning@0.1.0")]
+                        unsafe extern "C" {
+                            #[link_name = "[method]knowledge-base.quarantined"]
+                            fn wit_import1(_: i32, _: *mut u8);
+                        }
+                        #[cfg(not(target_arch = "wasm32"))]
+                        unsafe extern "C" fn wit_import1(_: i32, _: *mut u8) {
+                            unreachable!()
+                        }
+                        unsafe { wit_import1((self).handle() as i32, ptr0) };
+                        let l2 = *ptr0.add(0).cast::<*mut u8>();
+                        let l3 = *ptr0
+                            .add(::core::mem::size_of::<*const u8>())
+                            .cast::<usize>();
+                        let base22 = l2;
+                        let len22 = l3;
+                        let mut result22 = _rt::Vec::with_capacity(len22);
+                        for i in 0..len22 {
+                            let base = base22
+                                .add(i * (4 * ::core::mem::size_of::<*const u8>()));
+                            let e22 = {
+                                let l4 = *base.add(0).cast::<*mut u8>();
+                                let l5 = *base
+                                    .add(::core::mem::size_of::<*const u8>())
+                                    .cast::<usize>();
+                                let base9 = l4;
+                                let len9 = l5;
+                                let mut result9 = _rt::Vec::with_capacity(len9);
+                                for i in 0..len9 {
+                                    let base = base9
+                                        .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                                    let e9 = {
+                                        let l6 = *base.add(0).cast::<*mut u8>();
+                                        let l7 = *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<usize>();
+                                        let len8 = l7;
+                                        let bytes8 = _rt::Vec::from_raw_parts(
+                                            l6.cast(),
+                                            len8,
+                                            len8,
+                                        );
+                                        _rt::string_lift(bytes8)
+                                    };
+                                    result9.push(e9);
                                 }
-                                V18::Tagged(e) => {
-                                    *base.add(0).cast::<u8>() = (5i32) as u8;
-                                    let (t15_0, t15_1) = e;
-                                    *base
-                                        .add(::core::mem::size_of::<*const u8>())
-                                        .cast::<u8>() = (t15_0.clone() as i32) as u8;
-                                    *base
-                                        .add(4 + 1 * ::core::mem::size_of::<*const u8>())
-                                        .cast::<i32>() = _rt::as_i32(t15_1);
+                                _rt::cabi_dealloc(
+                                    base9,
+                                    len9 * (2 * ::core::mem::size_of::<*const u8>()),
+                                    ::core::mem::size_of::<*const u8>(),
+                                );
+                                let l10 = *base
+                                    .add(2 * ::core::mem::size_of::<*const u8>())
+                                    .cast::<*mut u8>();
+                                let l11 = *base
+                                    .add(3 * ::core::mem::size_of::<*const u8>())
+                                    .cast::<usize>();
+                                let base21 = l10;
+                                let len21 = l11;
+                                let mut result21 = _rt::Vec::with_capacity(len21);
+                                for i in 0..len21 {
+                                    let base = base21
+                                        .add(i * (4 * ::core::mem::size_of::<*const u8>()));
+                                    let e21 = {
+                                        let l12 = *base.add(0).cast::<*mut u8>();
+                                        let l13 = *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<usize>();
+                                        let len14 = l13;
+                                        let bytes14 = _rt::Vec::from_raw_parts(
+                                            l12.cast(),
+                                            len14,
+                                            len14,
+                                        );
+                                        let l15 = *base
+                                            .add(2 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<*mut u8>();
+                                        let l16 = *base
+                                            .add(3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<usize>();
+                                        let base20 = l15;
+                                        let len20 = l16;
+                                        let mut result20 = _rt::Vec::with_capacity(len20);
+                                        for i in 0..len20 {
+                                            let base = base20
+                                                .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                                            let e20 = {
+                                                let l17 = *base.add(0).cast::<*mut u8>();
+                                                let l18 = *base
+                                                    .add(::core::mem::size_of::<*const u8>())
+                                                    .cast::<usize>();
+                                                let len19 = l18;
+                                                let bytes19 = _rt::Vec::from_raw_parts(
+                                                    l17.cast(),
+                                                    len19,
+                                                    len19,
+                                                );
+                                                _rt::string_lift(bytes19)
+                                            };
+                                            result20.push(e20);
+                                        }
+                                        _rt::cabi_dealloc(
+                                            base20,
+                                            len20 * (2 * ::core::mem::size_of::<*const u8>()),
+                                            ::core::mem::size_of::<*const u8>(),
+                                        );
+                                        Conflict {
+                                            formula: _rt::string_lift(bytes14),
+                                            sources: result20,
+                                        }
+                                    };
+                                    result21.push(e21);
                                 }
-                                V18::Restricted(e) => {
-                                    *base.add(0).cast::<u8>() = (6i32) as u8;
-                                    let (t16_0, t16_1) = e;
-                                    *base
-                                        .add(::core::mem::size_of::<*const u8>())
-                                        .cast::<i32>() = _rt::as_i32(t16_0);
-                                    let super::super::super::lojban::nesy::ast_types::RelClause {
-                                        kind: kind17,
-                                        body_sentence: body_sentence17,
-                                    } = t16_1;
-                                    *base
-                                        .add(4 + 1 * ::core::mem::size_of::<*const u8>())
-                                        .cast::<u8>() = (kind17.clone() as i32) as u8;
-                                    *base
-                                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
-                                        .cast::<i32>() = _rt::as_i32(body_sentence17);
+                                _rt::cabi_dealloc(
+                                    base21,
+                                    len21 * (4 * ::core::mem::size_of::<*const u8>()),
+                                    ::core::mem::size_of::<*const u8>(),
+                                );
+                                QuarantinedFact {
+                                    sources: result9,
+                                    conflicts: result21,
                                 }
-                            }
+                            };
+                            result22.push(e22);
                         }
+                        _rt::cabi_dealloc(
+                            base22,
+                            len22 * (4 * ::core::mem::size_of::<*const u8>()),
+                            ::core::mem::size_of::<*const u8>(),
+                        );
+                        let result23 = result22;
+                        result23
                     }
-                    let vec23 = sentences0;
-                    let len23 = vec23.len();
-                    let layout23 = _rt::alloc::Layout::from_size_align_unchecked(
-                        vec23.len() * (6 * ::core::mem::size_of::<*const u8>()),
-                        ::core::mem::size_of::<*const u8>(),
-                    );
-                    let result23 = if layout23.size() != 0 {
-                        let ptr = _rt::alloc::alloc(layout23).cast::<u8>();
-                        if ptr.is_null() {
-                            _rt::alloc::handle_alloc_error(layout23);
+                }
+            }
+            impl KnowledgeBase {
+                #[allow(unused_unsafe, clippy::all)]
+                pub fn query_entailment(
+                    &self,
+                    logic: &LogicBuffer,
+                    assumption: WorldAssumption,
+                ) -> Result<TruthValue, _rt::String> {
+                    unsafe {
+                        let mut cleanup_list = _rt::Vec::new();
+                        #[cfg_attr(target_pointer_width = "64", repr(align(8)))]
+                        #[cfg_attr(target_pointer_width = "32", repr(align(4)))]
+                        struct RetArea(
+                            [::core::mem::MaybeUninit<
+                                u8,
+                            >; 3 * ::core::mem::size_of::<*const u8>()],
+                        );
+                        let mut ret_area = RetArea(
+                            [::core::mem::MaybeUninit::uninit(); 3
+                                * ::core::mem::size_of::<*const u8>()],
+                        );
+                        let super::super::super::lojban::nesy::ast_types::LogicBuffer {
+                            nodes: nodes0,
+                            roots: roots0,
+                        } = logic;
+                        let vec23 = nodes0;
+                        let len23 = vec23.len();
+                        let layout23 = _rt::alloc::Layout::from_size_align_unchecked(
+                            vec23.len() * (5 * ::core::mem::size_of::<*const u8>()),
+                            ::core::mem::size_of::<*const u8>(),
+                        );
+                        let result23 = if layout23.size() != 0 {
+                            let ptr = _rt::alloc::alloc(layout23).cast::<u8>();
+                            if ptr.is_null() {
+                                _rt::alloc::handle_alloc_error(layout23);
+                            }
+                            ptr
+                        } else {
+                            ::core::ptr::null_mut()
+                        };
+                        for (i, e) in vec23.into_iter().enumerate() {
+                            let base = result23
+                                .add(i * (5 * ::core::mem::size_of::<*const u8>()));
+                            {
+                                use super::super::super::lojban::nesy::ast_types::LogicNode as V22;
+                                match e {
+                                    V22::Predicate(e) => {
+                                        *base.add(0).cast::<u8>() = (0i32) as u8;
+                                        let (t1_0, t1_1) = e;
+                                        let vec2 = t1_0;
+                                        let ptr2 = vec2.as_ptr().cast::<u8>();
+                                        let len2 = vec2.len();
+                                        *base
+                                            .add(2 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<usize>() = len2;
+                                        *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<*mut u8>() = ptr2.cast_mut();
+                                        let vec7 = t1_1;
+                                        let len7 = vec7.len();
+                                        let layout7 = _rt::alloc::Layout::from_size_align_unchecked(
+                                            vec7.len() * (3 * ::core::mem::size_of::<*const u8>()),
+                                            ::core::mem::size_of::<*const u8>(),
+                                        );
+                                        let result7 = if layout7.size() != 0 {
+                                            let ptr = _rt::alloc::alloc(layout7).cast::<u8>();
+                                            if ptr.is_null() {
+                                                _rt::alloc::handle_alloc_error(layout7);
+                                            }
+                                            ptr
+                                        } else {
+                                            ::core::ptr::null_mut()
+                                        };
+                                        for (i, e) in vec7.into_iter().enumerate() {
+                                            let base = result7
+                                                .add(i * (3 * ::core::mem::size_of::<*const u8>()));
+                                            {
+                                                use super::super::super::lojban::nesy::ast_types::LogicalTerm as V6;
+                                                match e {
+                                                    V6::Variable(e) => {
+                                                        *base.add(0).cast::<u8>() = (0i32) as u8;
+                                                        let vec3 = e;
+                                                        let ptr3 = vec3.as_ptr().cast::<u8>();
+                                                        let len3 = vec3.len();
+                                                        *base
+                                                            .add(2 * ::core::mem::size_of::<*const u8>())
+                                                            .cast::<usize>() = len3;
+                                                        *base
+                                                            .add(::core::mem::size_of::<*const u8>())
+                                                            .cast::<*mut u8>() = ptr3.cast_mut();
+                                                    }
+                                                    V6::Constant(e) => {
+                                                        *base.add(0).cast::<u8>() = (1i32) as u8;
+                                                        let vec4 = e;
+                                                        let ptr4 = vec4.as_ptr().cast::<u8>();
+                                                        let len4 = vec4.len();
+                                                        *base
+                                                            .add(2 * ::core::mem::size_of::<*const u8>())
+                                                            .cast::<usize>() = len4;
+                                                        *base
+                                                            .add(::core::mem::size_of::<*const u8>())
+                                                            .cast::<*mut u8>() = ptr4.cast_mut();
+                                                    }
+                                                    V6::Description(e) => {
+                                                        *base.add(0).cast::<u8>() = (2i32) as u8;
+                                                        let vec5 = e;
+                                                        let ptr5 = vec5.as_ptr().cast::<u8>();
+                                                        let len5 = vec5.len();
+                                                        *base
+                                                            .add(2 * ::core::mem::size_of::<*const u8>())
+                                                            .cast::<usize>() = len5;
+                                                        *base
+                                                            .add(::core::mem::size_of::<*const u8>())
+                                                            .cast::<*mut u8>() = ptr5.cast_mut();
+                                                    }
+                                                    V6::Unspecified => {
+                                                        *base.add(0).cast::<u8>() = (3i32) as u8;
+                                                    }
+                                                    V6::Proposition(e) => {
+                                                        *base.add(0).cast::<u8>() = (4i32) as u8;
+                                                        *base
+                                                            .add(::core::mem::size_of::<*const u8>())
+                                                            .cast::<i32>() = _rt::as_i32(e);
+                                                    }
+                                                }
+                                            }
+                                        }
+                                        *base
+                                            .add(4 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<usize>() = len7;
+                                        *base
+                                            .add(3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<*mut u8>() = result7;
+                                        cleanup_list.extend_from_slice(&[(result7, layout7)]);
+                                    }
+                                    V22::AndNode(e) => {
+                                        *base.add(0).cast::<u8>() = (1i32) as u8;
+                                        let (t8_0, t8_1) = e;
+                                        *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>() = _rt::as_i32(t8_0);
+                                        *base
+                                            .add(4 + 1 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>() = _rt::as_i32(t8_1);
+                                    }
+                                    V22::OrNode(e) => {
+                                        *base.add(0).cast::<u8>() = (2i32) as u8;
+                                        let (t9_0, t9_1) = e;
+                                        *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>() = _rt::as_i32(t9_0);
+                                        *base
+                                            .add(4 + 1 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>() = _rt::as_i32(t9_1);
+                                    }
+                                    V22::ImpliesNode(e) => {
+                                        *base.add(0).cast::<u8>() = (3i32) as u8;
+                                        let (t10_0, t10_1) = e;
+                                        *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>() = _rt::as_i32(t10_0);
+                                        *base
+                                            .add(4 + 1 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>() = _rt::as_i32(t10_1);
+                                    }
+                                    V22::NotNode(e) => {
+                                        *base.add(0).cast::<u8>() = (4i32) as u8;
+                                        *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>() = _rt::as_i32(e);
+                                    }
+                                    V22::ExistsNode(e) => {
+                                        *base.add(0).cast::<u8>() = (5i32) as u8;
+                                        let (t11_0, t11_1) = e;
+                                        let vec12 = t11_0;
+                                        let ptr12 = vec12.as_ptr().cast::<u8>();
+                                        let len12 = vec12.len();
+                                        *base
+                                            .add(2 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<usize>() = len12;
+                                        *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<*mut u8>() = ptr12.cast_mut();
+                                        *base
+                                            .add(3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>() = _rt::as_i32(t11_1);
+                                    }
+                                    V22::ForAllNode(e) => {
+                                        *base.add(0).cast::<u8>() = (6i32) as u8;
+                                        let (t13_0, t13_1) = e;
+                                        let vec14 = t13_0;
+                                        let ptr14 = vec14.as_ptr().cast::<u8>();
+                                        let len14 = vec14.len();
+                                        *base
+                                            .add(2 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<usize>() = len14;
+                                        *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<*mut u8>() = ptr14.cast_mut();
+                                        *base
+                                            .add(3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>() = _rt::as_i32(t13_1);
+                                    }
+                                    V22::AtNode(e) => {
+                                        *base.add(0).cast::<u8>() = (7i32) as u8;
+                                        let (t15_0, t15_1) = e;
+                                        use super::super::super::lojban::nesy::ast_types::LogicalTerm as V19;
+                                        match t15_0 {
+                                            V19::Variable(e) => {
+                                                *base
+                                                    .add(::core::mem::size_of::<*const u8>())
+                                                    .cast::<u8>() = (0i32) as u8;
+                                                let vec16 = e;
+                                                let ptr16 = vec16.as_ptr().cast::<u8>();
+                                                let len16 = vec16.len();
+                                                *base
+                                                    .add(3 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<usize>() = len16;
+                                                *base
+                                                    .add(2 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<*mut u8>() = ptr16.cast_mut();
+                                            }
+                                            V19::Constant(e) => {
+                                                *base
+                                                    .add(::core::mem::size_of::<*const u8>())
+                                                    .cast::<u8>() = (1i32) as u8;
+                                                let vec17 = e;
+                                                let ptr17 = vec17.as_ptr().cast::<u8>();
+                                                let len17 = vec17.len();
+                                                *base
+                                                    .add(3 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<usize>() = len17;
+                                                *base
+                                                    .add(2 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<*mut u8>() = ptr17.cast_mut();
+                                            }
+                                            V19::Description(e) => {
+                                                *base
+                                                    .add(::core::mem::size_of::<*const u8>())
+                                                    .cast::<u8>() = (2i32) as u8;
+                                                let vec18 = e;
+                                                let ptr18 = vec18.as_ptr().cast::<u8>();
+                                                let len18 = vec18.len();
+                                                *base
+                                                    .add(3 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<usize>() = len18;
+                                                *base
+                                                    .add(2 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<*mut u8>() = ptr18.cast_mut();
+                                            }
+                                            V19::Unspecified => {
+                                                *base
+                                                    .add(::core::mem::size_of::<*const u8>())
+                                                    .cast::<u8>() = (3i32) as u8;
+                                            }
+                                            V19::Proposition(e) => {
+                                                *base
+                                                    .add(::core::mem::size_of::<*const u8>())
+                                                    .cast::<u8>() = (4i32) as u8;
+                                                *base
+                                                    .add(2 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<i32>() = _rt::as_i32(e);
+                                            }
+                                        }
+                                        *base
+                                            .add(4 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>() = _rt::as_i32(t15_1);
+                                    }
+                                    V22::GenericNode(e) => {
+                                        *base.add(0).cast::<u8>() = (8i32) as u8;
+                                        let (t20_0, t20_1, t20_2) = e;
+                                        let vec21 = t20_0;
+                                        let ptr21 = vec21.as_ptr().cast::<u8>();
+                                        let len21 = vec21.len();
+                                        *base
+                                            .add(2 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<usize>() = len21;
+                                        *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<*mut u8>() = ptr21.cast_mut();
+                                        *base
+                                            .add(3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>() = _rt::as_i32(t20_1);
+                                        *base
+                                            .add(4 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>() = _rt::as_i32(t20_2);
+                                    }
+                                }
+                            }
                         }
-                        ptr
-                    } else {
-                        ::core::ptr::null_mut()
-                    };
-                    for (i, e) in vec23.into_iter().enumerate() {
-                        let base = result23
-                            .add(i * (6 * ::core::mem::size_of::<*const u8>()));
-                        {
-                            let super::super::super::lojban::nesy::ast_types::Bridi {
-                                relation: relation20,
-                                head_terms: head_terms20,
-                                tail_terms: tail_terms20,
-                                negated: negated20,
-                            } = e;
-                            *base.add(0).cast::<i32>() = _rt::as_i32(relation20);
-                            let vec21 = head_terms20;
-                            let ptr21 = vec21.as_ptr().cast::<u8>();
-                            let len21 = vec21.len();
-                            *base
-                                .add(2 * ::core::mem::size_of::<*const u8>())
-                                .cast::<usize>() = len21;
-                            *base
-                                .add(::core::mem::size_of::<*const u8>())
-                                .cast::<*mut u8>() = ptr21.cast_mut();
-                            let vec22 = tail_terms20;
-                            let ptr22 = vec22.as_ptr().cast::<u8>();
-                            let len22 = vec22.len();
-                            *base
-                                .add(4 * ::core::mem::size_of::<*const u8>())
-                                .cast::<usize>() = len22;
-                            *base
-                                .add(3 * ::core::mem::size_of::<*const u8>())
-                                .cast::<*mut u8>() = ptr22.cast_mut();
-                            *base
-                                .add(5 * ::core::mem::size_of::<*const u8>())
-                                .cast::<u8>() = (match negated20 {
-                                true => 1,
-                                false => 0,
-                            }) as u8;
+                        let vec24 = roots0;
+                        let ptr24 = vec24.as_ptr().cast::<u8>();
+                        let len24 = vec24.len();
+                        let ptr25 = ret_area.0.as_mut_ptr().cast::<u8>();
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "lojban:nesy/reasoning@0.1.0")]
+                        unsafe extern "C" {
+                            #[link_name = "[method]knowledge-base.query-entailment"]
+                            fn wit_import26(
+                                _: i32,
+                                _: *mut u8,
+                                _: usize,
+                                _: *mut u8,
+                                _: usize,
+                                _: i32,
+                                _: *mut u8,
+                            );
                         }
-                    }
-                    let vec24 = roots0;
-                    let ptr24 = vec24.as_ptr().cast::<u8>();
-                    let len24 = vec24.len();
-                    let ptr25 = ret_area.0.as_mut_ptr().cast::<u8>();
-                    #[cfg(target_arch = "wasm32")]
-                    #[link(wasm_import_module = "lojban:nesy/semantics@0.1.0")]
-                    unsafe extern "C" {
-                        #[link_name = "compile-buffer"]
-                        fn wit_import26(
-                            _: *mut u8,
-                            _: usize,
-                            _: *mut u8,
-                            _: usize,
+                        #[cfg(not(target_arch = "wasm32"))]
+                        unsafe extern "C" fn wit_import26(
+                            _: i32,
                             _: *mut u8,
                             _: usize,
                             _: *mut u8,
                             _: usize,
+                            _: i32,
                             _: *mut u8,
-                        );
-                    }
-                    #[cfg(not(target_arch = "wasm32"))]
-                    unsafe extern "C" fn wit_import26(
-                        _: *mut u8,
-                        _: usize,
-                        _: *mut u8,
-                        _: usize,
-                        _: *mut u8,
-                        _: usize,
-                        _: *mut u8,
-                        _: usize,
-                        _: *mut u8,
-                    ) {
-                        unreachable!()
+                        ) {
+                            unreachable!()
+                        }
+                        unsafe {
+                            wit_import26(
+                                (self).handle() as i32,
+                                result23,
+                                len23,
+                                ptr24.cast_mut(),
+                                len24,
+                                assumption.clone() as i32,
+                                ptr25,
+                            )
+                        };
+                        let l27 = i32::from(*ptr25.add(0).cast::<u8>());
+                        let result32 = match l27 {
+                            0 => {
+                                let e = {
+                                    let l28 = i32::from(
+                                        *ptr25.add(::core::mem::size_of::<*const u8>()).cast::<u8>(),
+                                    );
+                                    TruthValue::_lift(l28 as u8)
+                                };
+                                Ok(e)
+                            }
+                            1 => {
+                                let e = {
+                                    let l29 = *ptr25
+                                        .add(::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>();
+                                    let l30 = *ptr25
+                                        .add(2 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    let len31 = l30;
+                                    let bytes31 = _rt::Vec::from_raw_parts(
+                                        l29.cast(),
+                                        len31,
+                                        len31,
+                                    );
+                                    _rt::string_lift(bytes31)
+                                };
+                                Err(e)
+                            }
+                            _ => _rt::invalid_enum_discriminant(),
+                        };
+                        if layout23.size() != 0 {
+                            _rt::alloc::dealloc(result23.cast(), layout23);
+                        }
+                        for (ptr, layout) in cleanup_list {
+                            if layout.size() != 0 {
+                                _rt::alloc::dealloc(ptr.cast(), layout);
+                            }
+                        }
+                        result32
                     }
+                }
+            }
+            impl KnowledgeBase {
+                #[allow(unused_unsafe, clippy::all)]
+                /// The substitutions for `variables` under which the formula is
+                /// entailed. A variable used as a relation name is a predicate variable.
+                pub fn query_bindings(
+                    &self,
+                    logic: &LogicBuffer,
+                    variables: &[_rt::String],
+                    assumption: WorldAssumption,
+                ) -> Result<_rt::Vec<Substitution>, _rt::String> {
                     unsafe {
-                        wit_import26(
-                            result10,
-                            len10,
-                            result19,
-                            len19,
-                            result23,
-                            len23,
-                            ptr24.cast_mut(),
-                            len24,
-                            ptr25,
-                        )
-                    };
-                    let l27 = i32::from(*ptr25.add(0).cast::<u8>());
-                    let result69 = match l27 {
-                        0 => {
-                            let e = {
-                                let l28 = *ptr25
-                                    .add(::core::mem::size_of::<*const u8>())
-                                    .cast::<*mut u8>();
-                                let l29 = *ptr25
-                                    .add(2 * ::core::mem::size_of::<*const u8>())
-                                    .cast::<usize>();
-                                let base62 = l28;
-                                let len62 = l29;
-                                let mut result62 = _rt::Vec::with_capacity(len62);
-                                for i in 0..len62 {
-                                    let base = base62
-                                        .add(i * (5 * ::core::mem::size_of::<*const u8>()));
-                                    let e62 = {
-                                        let l30 = i32::from(*base.add(0).cast::<u8>());
-                                        use super::super::super::lojban::nesy::ast_types::LogicNode as V61;
-                                        let v61 = match l30 {
-                                            0 => {
-                                                let e61 = {
-                                                    let l31 = *base
-                                                        .add(::core::mem::size_of::<*const u8>())
-                                                        .cast::<*mut u8>();
-                                                    let l32 = *base
-                                                        .add(2 * ::core::mem::size_of::<*const u8>())
-                                                        .cast::<usize>();
-                                                    let len33 = l32;
-                                                    let bytes33 = _rt::Vec::from_raw_parts(
-                                                        l31.cast(),
-                                                        len33,
-                                                        len33,
-                                                    );
-                                                    let l34 = *base
-                                                        .add(3 * ::core::mem::size_of::<*const u8>())
-                                                        .cast::<*mut u8>();
-                                                    let l35 = *base
-                                                        .add(4 * ::core::mem::size_of::<*const u8>())
-                                                        .cast::<usize>();
-                                                    let base47 = l34;
-                                                    let len47 = l35;
-                                                    let mut result47 = _rt::Vec::with_capacity(len47);
-                                                    for i in 0..len47 {
-                                                        let base = base47
-                                                            .add(i * (3 * ::core::mem::size_of::<*const u8>()));
-                                                        let e47 = {
-                                                            let l36 = i32::from(*base.add(0).cast::<u8>());
-                                                            use super::super::super::lojban::nesy::ast_types::LogicalTerm as V46;
-                                                            let v46 = match l36 {
-                                                                0 => {
-                                                                    let e46 = {
-                                                                        let l37 = *base
-                                                                            .add(::core::mem::size_of::<*const u8>())
-                                                                            .cast::<*mut u8>();
-                                                                        let l38 = *base
-                                                                            .add(2 * ::core::mem::size_of::<*const u8>())
-                                                                            .cast::<usize>();
-                                                                        let len39 = l38;
-                                                                        let bytes39 = _rt::Vec::from_raw_parts(
-                                                                            l37.cast(),
-                                                                            len39,
-                                                                            len39,
-                                                                        );
-                                                                        _rt::string_lift(bytes39)
-                                                                    };
-                                                                    V46::Variable(e46)
-                                                                }
-                                                                1 => {
-                                                                    let e46 = {
-                                                                        let l40 = *base
-                                                                            .add(::core::mem::size_of::<*const u8>())
-                                                                            .cast::<*mut u8>();
-                                                                        let l41 = *base
-                                                                            .add(2 * ::core::mem::size_of::<*const u8>())
-                                                                            .cast::<usize>();
-                                                                        let len42 = l41;
-                                                                        let bytes42 = _rt::Vec::from_raw_parts(
-                                                                            l40.cast(),
-                                                                            len42,
-                                                                            len42,
-                                                                        );
-                                                                        _rt::string_lift(bytes42)
-                                                                    };
-                                                                    V46::Constant(e46)
-                                                                }
-                                                                2 => {
-                                                                    let e46 = {
-                                                                        let l43 = *base
-                                                                            .add(::core::mem::size_of::<*const u8>())
-                                                                            .cast::<*mut u8>();
-                                                                        let l44 = *base
-                                                                            .add(2 * ::core::mem::size_of::<*const u8>())
-                                                                            .cast::<usize>();
-                                                                        let len45 = l44;
-                                                                        let bytes45 = _rt::Vec::from_raw_parts(
-                                                                            l43.cast(),
-                                                                            len45,
-                                                                            len45,
-                                                                        );
-                                                                        _rt::string_lift(bytes45)
-                                                                    };
-                                                                    V46::Description(e46)
-                                                                }
-                                                                n => {
-                                                                    debug_assert_eq!(n, 3, "invalid enum discriminant");
-                                                                    V46::Unspecified
-                                                                }
-                                                            };
-                                                            v46
-                                                        };
-                                                        result47.push(e47);
+                        let mut cleanup_list = _rt::Vec::new();
+                        #[cfg_attr(target_pointer_width = "64", repr(align(8)))]
+                        #[cfg_attr(target_pointer_width = "32", repr(align(4)))]
+                        struct RetArea(
+                            [::core::mem::MaybeUninit<
+                                u8,
+                            >; 3 * ::core::mem::size_of::<*const u8>()],
+                        );
+                        let mut ret_area = RetArea(
+                            [::core::mem::MaybeUninit::uninit(); 3
+                                * ::core::mem::size_of::<*const u8>()],
+                        );
+                        let super::super::super::lojban::nesy::ast_types::LogicBuffer {
+                            nodes: nodes0,
+                            roots: roots0,
+                        } = logic;
+                        let vec23 = nodes0;
+                        let len23 = vec23.len();
+                        let layout23 = _rt::alloc::Layout::from_size_align_unchecked(
+                            vec23.len() * (5 * ::core::mem::size_of::<*const u8>()),
+                            ::core::mem::size_of::<*const u8>(),
+                        );
+                        let result23 = if layout23.size() != 0 {
+                            let ptr = _rt::alloc::alloc(layout23).cast::<u8>();
+                            if ptr.is_null() {
+                                _rt::alloc::handle_alloc_error(layout23);
+                            }
+                            ptr
+                        } else {
+                            ::core::ptr::null_mut()
+                        };
+                        for (i, e) in vec23.into_iter().enumerate() {
+                            let base = result23
+                                .add(i * (5 * ::core::mem::size_of::<*const u8>()));
+                            {
+                                use super::super::super::lojban::nesy::ast_types::LogicNode as V22;
+                                match e {
+                                    V22::Predicate(e) => {
+                                        *base.add(0).cast::<u8>() = (0i32) as u8;
+                                        let (t1_0, t1_1) = e;
+                                        let vec2 = t1_0;
+                                        let ptr2 = vec2.as_ptr().cast::<u8>();
+                                        let len2 = vec2.len();
+                                        *base
+                                            .add(2 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<usize>() = len2;
+                                        *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<*mut u8>() = ptr2.cast_mut();
+                                        let vec7 = t1_1;
+                                        let len7 = vec7.len();
+                                        let layout7 = _rt::alloc::Layout::from_size_align_unchecked(
+                                            vec7.len() * (3 * ::core::mem::size_of::<*const u8>()),
+                                            ::core::mem::size_of::<*const u8>(),
+                                        );
+                                        let result7 = if layout7.size() != 0 {
+                                            let ptr = _rt::alloc::alloc(layout7).cast::<u8>();
+                                            if ptr.is_null() {
+                                                _rt::alloc::handle_alloc_error(layout7);
+                                            }
+                                            ptr
+                                        } else {
+                                            ::core::ptr::null_mut()
+                                        };
+                                        for (i, e) in vec7.into_iter().enumerate() {
+                                            let base = result7
+                                                .add(i * (3 * ::core::mem::size_of::<*const u8>()));
+                                            {
+                                                use super::super::super::lojban::nesy::ast_types::LogicalTerm as V6;
+                                                match e {
+                                                    V6::Variable(e) => {
+                                                        *base.add(0).cast::<u8>() = (0i32) as u8;
+                                                        let vec3 = e;
+                                                        let ptr3 = vec3.as_ptr().cast::<u8>();
+                                                        let len3 = vec3.len();
+                                                        *base
+                                                            .add(2 * ::core::mem::size_of::<*const u8>())
+                                                            .cast::<usize>() = len3;
+                                                        *base
+                                                            .add(::core::mem::size_of::<*const u8>())
+                                                            .cast::<*mut u8>() = ptr3.cast_mut();
                                                     }
-                                                    _rt::cabi_dealloc(
-                                                        base47,
-                                                        len47 * (3 * ::core::mem::size_of::<*const u8>()),
-                                                        ::core::mem::size_of::<*const u8>(),
-                                                    );
-                                                    (_rt::string_lift(bytes33), result47)
-                                                };
-                                                V61::Predicate(e61)
+                                                    V6::Constant(e) => {
+                                                        *base.add(0).cast::<u8>() = (1i32) as u8;
+                                                        let vec4 = e;
+                                                        let ptr4 = vec4.as_ptr().cast::<u8>();
+                                                        let len4 = vec4.len();
+                                                        *base
+                                                            .add(2 * ::core::mem::size_of::<*const u8>())
+                                                            .cast::<usize>() = len4;
+                                                        *base
+                                                            .add(::core::mem::size_of::<*const u8>())
+                                                            .cast::<*mut u8>() = ptr4.cast_mut();
+                                                    }
+                                                    V6::Description(e) => {
+                                                        *base.add(0).cast::<u8>() = (2i32) as u8;
+                                                        let vec5 = e;
+                                                        let ptr5 = vec5.as_ptr().cast::<u8>();
+                                                        let len5 = vec5.len();
+                                                        *base
+                                                            .add(2 * ::core::mem::size_of::<*const u8>())
+                                                            .cast::<usize>() = len5;
+                                                        *base
+                                                            .add(::core::mem::size_of::<*const u8>())
+                                                            .cast::<*mut u8>() = ptr5.cast_mut();
+                                                    }
+                                                    V6::Unspecified => {
+                                                        *base.add(0).cast::<u8>() = (3i32) as u8;
+                                                    }
+                                                    V6::Proposition(e) => {
+                                                        *base.add(0).cast::<u8>() = (4i32) as u8;
+                                                        *base
+                                                            .add(::core::mem::size_of::<*const u8>())
+                                                            .cast::<i32>() = _rt::as_i32(e);
+                                                    }
+                                                }
                                             }
-                                            1 => {
-                                                let e61 = {
-                                                    let l48 = *base
-                                                        .add(::core::mem::size_of::<*const u8>())
-                                                        .cast::<i32>();
-                                                    let l49 = *base
-                                                        .add(4 + 1 * ::core::mem::size_of::<*const u8>())
-                                                        .cast::<i32>();
-                                                    (l48 as u32, l49 as u32)
-                                                };
-                                                V61::AndNode(e61)
+                                        }
+                                        *base
+                                            .add(4 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<usize>() = len7;
+                                        *base
+                                            .add(3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<*mut u8>() = result7;
+                                        cleanup_list.extend_from_slice(&[(result7, layout7)]);
+                                    }
+                                    V22::AndNode(e) => {
+                                        *base.add(0).cast::<u8>() = (1i32) as u8;
+                                        let (t8_0, t8_1) = e;
+                                        *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>() = _rt::as_i32(t8_0);
+                                        *base
+                                            .add(4 + 1 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>() = _rt::as_i32(t8_1);
+                                    }
+                                    V22::OrNode(e) => {
+                                        *base.add(0).cast::<u8>() = (2i32) as u8;
+                                        let (t9_0, t9_1) = e;
+                                        *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>() = _rt::as_i32(t9_0);
+                                        *base
+                                            .add(4 + 1 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>() = _rt::as_i32(t9_1);
+                                    }
+                                    V22::ImpliesNode(e) => {
+                                        *base.add(0).cast::<u8>() = (3i32) as u8;
+                                        let (t10_0, t10_1) = e;
+                                        *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>() = _rt::as_i32(t10_0);
+                                        *base
+                                            .add(4 + 1 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>() = _rt::as_i32(t10_1);
+                                    }
+                                    V22::NotNode(e) => {
+                                        *base.add(0).cast::<u8>() = (4i32) as u8;
+                                        *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>() = _rt::as_i32(e);
+                                    }
+                                    V22::ExistsNode(e) => {
+                                        *base.add(0).cast::<u8>() = (5i32) as u8;
+                                        let (t11_0, t11_1) = e;
+                                        let vec12 = t11_0;
+                                        let ptr12 = vec12.as_ptr().cast::<u8>();
+                                        let len12 = vec12.len();
+                                        *base
+                                            .add(2 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<usize>() = len12;
+                                        *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<*mut u8>() = ptr12.cast_mut();
+                                        *base
+                                            .add(3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>() = _rt::as_i32(t11_1);
+                                    }
+                                    V22::ForAllNode(e) => {
+                                        *base.add(0).cast::<u8>() = (6i32) as u8;
+                                        let (t13_0, t13_1) = e;
+                                        let vec14 = t13_0;
+                                        let ptr14 = vec14.as_ptr().cast::<u8>();
+                                        let len14 = vec14.len();
+                                        *base
+                                            .add(2 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<usize>() = len14;
+                                        *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<*mut u8>() = ptr14.cast_mut();
+                                        *base
+                                            .add(3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>() = _rt::as_i32(t13_1);
+                                    }
+                                    V22::AtNode(e) => {
+                                        *base.add(0).cast::<u8>() = (7i32) as u8;
+                                        let (t15_0, t15_1) = e;
+                                        use super::super::super::lojban::nesy::ast_types::LogicalTerm as V19;
+                                        match t15_0 {
+                                            V19::Variable(e) => {
+                                                *base
+                                                    .add(::core::mem::size_of::<*const u8>())
+                                                    .cast::<u8>() = (0i32) as u8;
+                                                let vec16 = e;
+                                                let ptr16 = vec16.as_ptr().cast::<u8>();
+                                                let len16 = vec16.len();
+                                                *base
+                                                    .add(3 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<usize>() = len16;
+                                                *base
+                                                    .add(2 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<*mut u8>() = ptr16.cast_mut();
                                             }
-                                            2 => {
-                                                let e61 = {
-                                                    let l50 = *base
-                                                        .add(::core::mem::size_of::<*const u8>())
-                                                        .cast::<i32>();
-                                                    let l51 = *base
-                                                        .add(4 + 1 * ::core::mem::size_of::<*const u8>())
-                                                        .cast::<i32>();
-                                                    (l50 as u32, l51 as u32)
-                                                };
-                                                V61::OrNode(e61)
+                                            V19::Constant(e) => {
+                                                *base
+                                                    .add(::core::mem::size_of::<*const u8>())
+                                                    .cast::<u8>() = (1i32) as u8;
+                                                let vec17 = e;
+                                                let ptr17 = vec17.as_ptr().cast::<u8>();
+                                                let len17 = vec17.len();
+                                                *base
+                                                    .add(3 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<usize>() = len17;
+                                                *base
+                                                    .add(2 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<*mut u8>() = ptr17.cast_mut();
                                             }
-                                            3 => {
-                                                let e61 = {
-                                                    let l52 = *base
-                                                        .add(::core::mem::size_of::<*const u8>())
-                                                        .cast::<i32>();
-                                                    l52 as u32
-                                                };
-                                                V61::NotNode(e61)
+                                            V19::Description(e) => {
+                                                *base
+                                                    .add(::core::mem::size_of::<*const u8>())
+                                                    .cast::<u8>() = (2i32) as u8;
+                                                let vec18 = e;
+                                                let ptr18 = vec18.as_ptr().cast::<u8>();
+                                                let len18 = vec18.len();
+                                                *base
+                                                    .add(3 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<usize>() = len18;
+                                                *base
+                                                    .add(2 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<*mut u8>() = ptr18.cast_mut();
                                             }
-                                            4 => {
-                                                let e61 = {
-                                                    let l53 = *base
-                                                        .add(::core::mem::size_of::<*const u8>())
-                                                        .cast::<*mut u8>();
-                                                    let l54 = *base
-                                                        .add(2 * ::core::mem::size_of::<*const u8>())
-                                                        .cast::<usize>();
-                                                    let len55 = l54;
-                                                    let bytes55 = _rt::Vec::from_raw_parts(
-                                                        l53.cast(),
-                                                        len55,
-                                                        len55,
-                                                    );
-                                                    let l56 = *base
-                                                        .add(3 * ::core::mem::size_of::<*const u8>())
-                                                        .cast::<i32>();
-                                                    (_rt::string_lift(bytes55), l56 as u32)
-                                                };
-                                                V61::ExistsNode(e61)
+                                            V19::Unspecified => {
+                                                *base
+                                                    .add(::core::mem::size_of::<*const u8>())
+                                                    .cast::<u8>() = (3i32) as u8;
                                             }
-                                            n => {
-                                                debug_assert_eq!(n, 5, "invalid enum discriminant");
-                                                let e61 = {
-                                                    let l57 = *base
+                                            V19::Proposition(e) => {
+                                                *base
+                                                    .add(::core::mem::size_of::<*const u8>())
+                                                    .cast::<u8>() = (4i32) as u8;
+                                                *base
+                                                    .add(2 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<i32>() = _rt::as_i32(e);
+                                            }
+                                        }
+                                        *base
+                                            .add(4 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>() = _rt::as_i32(t15_1);
+                                    }
+                                    V22::GenericNode(e) => {
+                                        *base.add(0).cast::<u8>() = (8i32) as u8;
+                                        let (t20_0, t20_1, t20_2) = e;
+                                        let vec21 = t20_0;
+                                        let ptr21 = vec21.as_ptr().cast::<u8>();
+                                        let len21 = vec21.len();
+                                        *base
+                                            .add(2 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<usize>() = len21;
+                                        *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<*mut u8>() = ptr21.cast_mut();
+                                        *base
+                                            .add(3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>() = _rt::as_i32(t20_1);
+                                        *base
+                                            .add(4 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>() = _rt::as_i32(t20_2);
+                                    }
+                                }
+                            }
+                        }
+                        let vec24 = roots0;
+                        let ptr24 = vec24.as_ptr().cast::<u8>();
+                        let len24 = vec24.len();
+                        let vec26 = variables;
+                        let len26 = vec26.len();
+                        let layout26 = _rt::alloc::Layout::from_size_align_unchecked(
+                            vec26.len() * (2 * ::core::mem::size_of::<*const u8>()),
+                            ::core::mem::size_of::<*const u8>(),
+                        );
+                        let result26 = if layout26.size() != 0 {
+                            let ptr = _rt::alloc::alloc(layout26).cast::<u8>();
+                            if ptr.is_null() {
+                                _rt::alloc::handle_alloc_error(layout26);
+                            }
+                            ptr
+                        } else {
+                            ::core::ptr::null_mut()
+                        };
+                        for (i, e) in vec26.into_iter().enumerate() {
+                            let base = result26
+                                .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                            {
+                                let vec25 = e;
+                                let ptr25 = vec25.as_ptr().cast::<u8>();
+                                let len25 = vec25.len();
+                                *base
+                                    .add(::core::mem::size_of::<*const u8>())
+                                    .cast::<usize>() = len25;
+                                *base.add(0).cast::<*mut u8>() = ptr25.cast_mut();
+                            }
+                        }
+                        let ptr27 = ret_area.0.as_mut_ptr().cast::<u8>();
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "lojban:nesy/reasoning@0.1.0")]
+                        unsafe extern "C" {
+                            #[link_name = "[method]knowledge-base.query-bindings"]
+                            fn wit_import28(
+                                _: i32,
+                                _: *mut u8,
+                                _: usize,
+                                _: *mut u8,
+                                _: usize,
+                                _: *mut u8,
+                                _: usize,
+                                _: i32,
+                                _: *mut u8,
+                            );
+                        }
+                        #[cfg(not(target_arch = "wasm32"))]
+                        unsafe extern "C" fn wit_import28(
+                            _: i32,
+                            _: *mut u8,
+                            _: usize,
+                            _: *mut u8,
+                            _: usize,
+                            _: *mut u8,
+                            _: usize,
+                            _: i32,
+                            _: *mut u8,
+                        ) {
+                            unreachable!()
+                        }
+                        unsafe {
+                            wit_import28(
+                                (self).handle() as i32,
+                                result23,
+                                len23,
+                                ptr24.cast_mut(),
+                                len24,
+                                result26,
+                                len26,
+                                assumption.clone() as i32,
+                                ptr27,
+                            )
+                        };
+                        let l29 = i32::from(*ptr27.add(0).cast::<u8>());
+                        let result50 = match l29 {
+                            0 => {
+                                let e = {
+                                    let l30 = *ptr27
+                                        .add(::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>();
+                                    let l31 = *ptr27
+                                        .add(2 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    let base46 = l30;
+                                    let len46 = l31;
+                                    let mut result46 = _rt::Vec::with_capacity(len46);
+                                    for i in 0..len46 {
+                                        let base = base46
+                                            .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                                        let e46 = {
+                                            let l32 = *base.add(0).cast::<*mut u8>();
+                                            let l33 = *base
+                                                .add(::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>();
+                                            let base45 = l32;
+                                            let len45 = l33;
+                                            let mut result45 = _rt::Vec::with_capacity(len45);
+                                            for i in 0..len45 {
+                                                let base = base45
+                                                    .add(i * (5 * ::core::mem::size_of::<*const u8>()));
+                                                let e45 = {
+                                                    let l34 = *base.add(0).cast::<*mut u8>();
+                                                    let l35 = *base
                                                         .add(::core::mem::size_of::<*const u8>())
-                                                        .cast::<*mut u8>();
-                                                    let l58 = *base
-                                                        .add(2 * ::core::mem::size_of::<*const u8>())
                                                         .cast::<usize>();
-                                                    let len59 = l58;
-                                                    let bytes59 = _rt::Vec::from_raw_parts(
-                                                        l57.cast(),
-                                                        len59,
-                                                        len59,
+                                                    let len36 = l35;
+                                                    let bytes36 = _rt::Vec::from_raw_parts(
+                                                        l34.cast(),
+                                                        len36,
+                                                        len36,
                                                     );
-                                                    let l60 = *base
-                                                        .add(3 * ::core::mem::size_of::<*const u8>())
-                                                        .cast::<i32>();
-                                                    (_rt::string_lift(bytes59), l60 as u32)
+                                                    let l37 = i32::from(
+                                                        *base
+                                                            .add(2 * ::core::mem::size_of::<*const u8>())
+                                                            .cast::<u8>(),
+                                                    );
+                                                    let v44 = match l37 {
+                                                        0 => {
+                                                            let e44 = {
+                                                                let l38 = *base
+                                                                    .add(3 * ::core::mem::size_of::<*const u8>())
+                                                                    .cast::<*mut u8>();
+                                                                let l39 = *base
+                                                                    .add(4 * ::core::mem::size_of::<*const u8>())
+                                                                    .cast::<usize>();
+                                                                let len40 = l39;
+                                                                let bytes40 = _rt::Vec::from_raw_parts(
+                                                                    l38.cast(),
+                                                                    len40,
+                                                                    len40,
+                                                                );
+                                                                _rt::string_lift(bytes40)
+                                                            };
+                                                            AnswerTerm::Entity(e44)
+                                                        }
+                                                        n => {
+                                                            debug_assert_eq!(n, 1, "invalid enum discriminant");
+                                                            let e44 = {
+                                                                let l41 = *base
+                                                                    .add(3 * ::core::mem::size_of::<*const u8>())
+                                                                    .cast::<*mut u8>();
+                                                                let l42 = *base
+                                                                    .add(4 * ::core::mem::size_of::<*const u8>())
+                                                                    .cast::<usize>();
+                                                                let len43 = l42;
+                                                                let bytes43 = _rt::Vec::from_raw_parts(
+                                                                    l41.cast(),
+                                                                    len43,
+                                                                    len43,
+                                                                );
+                                                                _rt::string_lift(bytes43)
+                                                            };
+                                                            AnswerTerm::Relation(e44)
+                                                        }
+                                                    };
+                                                    Binding {
+                                                        variable: _rt::string_lift(bytes36),
+                                                        value: v44,
+                                                    }
                                                 };
-                                                V61::ForAllNode(e61)
+                                                result45.push(e45);
                                             }
+                                            _rt::cabi_dealloc(
+                                                base45,
+                                                len45 * (5 * ::core::mem::size_of::<*const u8>()),
+                                                ::core::mem::size_of::<*const u8>(),
+                                            );
+                                            result45
                                         };
-                                        v61
-                                    };
-                                    result62.push(e62);
-                                }
-                                _rt::cabi_dealloc(
-                                    base62,
-                                    len62 * (5 * ::core::mem::size_of::<*const u8>()),
-                                    ::core::mem::size_of::<*const u8>(),
-                                );
-                                let l63 = *ptr25
-                                    .add(3 * ::core::mem::size_of::<*const u8>())
-                                    .cast::<*mut u8>();
-                                let l64 = *ptr25
-                                    .add(4 * ::core::mem::size_of::<*const u8>())
-                                    .cast::<usize>();
-                                let len65 = l64;
-                                super::super::super::lojban::nesy::ast_types::LogicBuffer {
-                                    nodes: result62,
-                                    roots: _rt::Vec::from_raw_parts(l63.cast(), len65, len65),
-                                }
-                            };
-                            Ok(e)
+                                        result46.push(e46);
+                                    }
+                                    _rt::cabi_dealloc(
+                                        base46,
+                                        len46 * (2 * ::core::mem::size_of::<*const u8>()),
+                                        ::core::mem::size_of::<*const u8>(),
+                                    );
+                                    result46
+                                };
+                                Ok(e)
+                            }
+                            1 => {
+                                let e = {
+                                    let l47 = *ptr27
+                                        .add(::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>();
+                                    let l48 = *ptr27
+                                        .add(2 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    let len49 = l48;
+                                    let bytes49 = _rt::Vec::from_raw_parts(
+                                        l47.cast(),
+                                        len49,
+                                        len49,
+                                    );
+                                    _rt::string_lift(bytes49)
+                                };
+                                Err(e)
+                            }
+                            _ => _rt::invalid_enum_discriminant(),
+                        };
+                        if layout23.size() != 0 {
+                            _rt::alloc::dealloc(result23.cast(), layout23);
                         }
-                        1 => {
-                            let e = {
-                                let l66 = *ptr25
-                                    .add(::core::mem::size_of::<*const u8>())
-                                    .cast::<*mut u8>();
-                                let l67 = *ptr25
-                                    .add(2 * ::core::mem::size_of::<*const u8>())
-                                    .cast::<usize>();
-                                let len68 = l67;
-                                let bytes68 = _rt::Vec::from_raw_parts(
-                                    l66.cast(),
-                                    len68,
-                                    len68,
-                                );
-                                _rt::string_lift(bytes68)
-                            };
-                            Err(e)
+                        if layout26.size() != 0 {
+                            _rt::alloc::dealloc(result26.cast(), layout26);
                         }
-                        _ => _rt::invalid_enum_discriminant(),
-                    };
-                    if layout10.size() != 0 {
-                        _rt::alloc::dealloc(result10.cast(), layout10);
-                    }
-                    if layout19.size() != 0 {
-                        _rt::alloc::dealloc(result19.cast(), layout19);
+                        for (ptr, layout) in cleanup_list {
+                            if layout.size() != 0 {
+                                _rt::alloc::dealloc(ptr.cast(), layout);
+                            }
+                        }
+                        result50
                     }
-                    if layout23.size() != 0 {
-                        _rt::alloc::dealloc(result23.cast(), layout23);
+                }
+            }
+            impl KnowledgeBase {
+                #[allow(unused_unsafe, clippy::all)]
+                /// Forget everything asserted and quarantined.
+                pub fn reset(&self) -> () {
+                    unsafe {
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "lojban:nesy/reasoning@0.1.0")]
+                        unsafe extern "C" {
+                            #[link_name = "[method]knowledge-base.reset"]
+                            fn wit_import0(_: i32);
+                        }
+                        #[cfg(not(target_arch = "wasm32"))]
+                        unsafe extern "C" fn wit_import0(_: i32) {
+                            unreachable!()
+                        }
+                        unsafe { wit_import0((self).handle() as i32) };
                     }
-                    for (ptr, layout) in cleanup_list {
-                        if layout.size() != 0 {
-                            _rt::alloc::dealloc(ptr.cast(), layout);
+                }
+            }
+            impl KnowledgeBase {
+                #[allow(unused_unsafe, clippy::all)]
+                /// A copy of the knowledge base that evolves independently of it.
+                pub fn fork(&self) -> KnowledgeBase {
+                    unsafe {
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "lojban:nesy/reasoning@0.1.0")]
+                        unsafe extern "C" {
+                            #[link_name = "[method]knowledge-base.fork"]
+                            fn wit_import0(_: i32) -> i32;
                         }
+                        #[cfg(not(target_arch = "wasm32"))]
+                        unsafe extern "C" fn wit_import0(_: i32) -> i32 {
+                            unreachable!()
+                        }
+                        let ret = unsafe { wit_import0((self).handle() as i32) };
+                        unsafe { KnowledgeBase::from_handle(ret as u32) }
                     }
-                    result69
                 }
             }
         }
-        #[allow(dead_code, async_fn_in_trait, unused_imports, clippy::all)]
-        pub mod reasoning {
-            #[used]
-            #[doc(hidden)]
-            static __FORCE_SECTION_REF: fn() = super::super::super::__link_custom_section_describing_imports;
-            use super::super::super::_rt;
-            pub type LogicBuffer = super::super::super::lojban::nesy::ast_types::LogicBuffer;
-            #[allow(unused_unsafe, clippy::all)]
-            pub fn assert_fact(logic: &LogicBuffer) -> Result<(), _rt::String> {
-                unsafe {
-                    let mut cleanup_list = _rt::Vec::new();
-                    #[cfg_attr(target_pointer_width = "64", repr(align(8)))]
-                    #[cfg_attr(target_pointer_width = "32", repr(align(4)))]
-                    struct RetArea(
-                        [::core::mem::MaybeUninit<
-                            u8,
-                        >; 3 * ::core::mem::size_of::<*const u8>()],
-                    );
-                    let mut ret_area = RetArea(
-                        [::core::mem::MaybeUninit::uninit(); 3
-                            * ::core::mem::size_of::<*const u8>()],
-                    );
-                    let super::super::super::lojban::nesy::ast_types::LogicBuffer {
-                        nodes: nodes0,
-                        roots: roots0,
-                    } = logic;
-                    let vec15 = nodes0;
-                    let len15 = vec15.len();
-                    let layout15 = _rt::alloc::Layout::from_size_align_unchecked(
-                        vec15.len() * (5 * ::core::mem::size_of::<*const u8>()),
-                        ::core::mem::size_of::<*const u8>(),
-                    );
-                    let result15 = if layout15.size() != 0 {
-                        let ptr = _rt::alloc::alloc(layout15).cast::<u8>();
-                        if ptr.is_null() {
-                            _rt::alloc::handle_alloc_error(layout15);
+    }
+}
+#[rustfmt::skip]
+#[allow(dead_code, clippy::all)]
+pub mod exports {
+    pub mod lojban {
+        pub mod nesy {
+            /// Lojban text in, answers out: the host-facing side of engine-pipeline.
+            #[allow(dead_code, async_fn_in_trait, unused_imports, clippy::all)]
+            pub mod engine {
+                #[used]
+                #[doc(hidden)]
+                static __FORCE_SECTION_REF: fn() = super::super::super::super::__link_custom_section_describing_imports;
+                use super::super::super::super::_rt;
+                pub type AssertError = super::super::super::super::lojban::nesy::reasoning::AssertError;
+                pub type ConflictPolicy = super::super::super::super::lojban::nesy::reasoning::ConflictPolicy;
+                pub type FactHandle = super::super::super::super::lojban::nesy::reasoning::FactHandle;
+                pub type QuarantinedFact = super::super::super::super::lojban::nesy::reasoning::QuarantinedFact;
+                pub type Substitution = super::super::super::super::lojban::nesy::reasoning::Substitution;
+                pub type TruthValue = super::super::super::super::lojban::nesy::reasoning::TruthValue;
+                pub type WorldAssumption = super::super::super::super::lojban::nesy::reasoning::WorldAssumption;
+                /// The answer to a question: a truth value for xu (and for text
+                /// without question words), the satisfying substitutions for ma and mo.
+                #[derive(Clone)]
+                pub enum Answer {
+                    Truth(TruthValue),
+                    Bindings(_rt::Vec<Substitution>),
+                }
+                impl ::core::fmt::Debug for Answer {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        match self {
+                            Answer::Truth(e) => {
+                                f.debug_tuple("Answer::Truth").field(e).finish()
+                            }
+                            Answer::Bindings(e) => {
+                                f.debug_tuple("Answer::Bindings").field(e).finish()
+                            }
                         }
-                        ptr
-                    } else {
-                        ::core::ptr::null_mut()
-                    };
-                    for (i, e) in vec15.into_iter().enumerate() {
-                        let base = result15
-                            .add(i * (5 * ::core::mem::size_of::<*const u8>()));
+                    }
+                }
+                /// A successful assert-text.
+                #[derive(Clone)]
+                pub struct Assertion {
+                    /// The handle to retract the text with.
+                    pub handle: FactHandle,
+                    /// The number of root facts inserted.
+                    pub roots: u32,
+                    /// One message per region that did not parse and was skipped (up
+                    /// to the next `.i` or `ni'o`); empty when the whole text parsed.
+                    pub diagnostics: _rt::Vec<_rt::String>,
+                }
+                impl ::core::fmt::Debug for Assertion {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        f.debug_struct("Assertion")
+                            .field("handle", &self.handle)
+                            .field("roots", &self.roots)
+                            .field("diagnostics", &self.diagnostics)
+                            .finish()
+                    }
+                }
+                /// A reasoning knowledge base fed and queried with Lojban text. Each
+                /// has its own discourse, so ri or ko'a in one never refers to what
+                /// was said to another.
+                #[derive(Debug)]
+                #[repr(transparent)]
+                pub struct KnowledgeBase {
+                    handle: _rt::Resource<KnowledgeBase>,
+                }
+                type _KnowledgeBaseRep<T> = Option<T>;
+                impl KnowledgeBase {
+                    /// Creates a new resource from the specified representation.
+                    ///
+                    /// This function will create a new resource handle by moving `val` onto
+                    /// the heap and then passing that heap pointer to the component model to
+                    /// create a handle. The owned handle is then returned as `KnowledgeBase`.
+                    pub fn new<T: GuestKnowledgeBase>(val: T) -> Self {
+                        Self::type_guard::<T>();
+                        let val: _KnowledgeBaseRep<T> = Some(val);
+                        let ptr: *mut _KnowledgeBaseRep<T> = _rt::Box::into_raw(
+                            _rt::Box::new(val),
+                        );
+                        unsafe { Self::from_handle(T::_resource_new(ptr.cast())) }
+                    }
+                    /// Gets access to the underlying `T` which represents this resource.
+                    pub fn get<T: GuestKnowledgeBase>(&self) -> &T {
+                        let ptr = unsafe { &*self.as_ptr::<T>() };
+                        ptr.as_ref().unwrap()
+                    }
+                    /// Gets mutable access to the underlying `T` which represents this
+                    /// resource.
+                    pub fn get_mut<T: GuestKnowledgeBase>(&mut self) -> &mut T {
+                        let ptr = unsafe { &mut *self.as_ptr::<T>() };
+                        ptr.as_mut().unwrap()
+                    }
+                    /// Consumes this resource and returns the underlying `T`.
+                    pub fn into_inner<T: GuestKnowledgeBase>(self) -> T {
+                        let ptr = unsafe { &mut *self.as_ptr::<T>() };
+                        ptr.take().unwrap()
+                    }
+                    #[doc(hidden)]
+                    pub unsafe fn from_handle(handle: u32) -> Self {
+                        Self {
+                            handle: unsafe { _rt::Resource::from_handle(handle) },
+                        }
+                    }
+                    #[doc(hidden)]
+                    pub fn take_handle(&self) -> u32 {
+                        _rt::Resource::take_handle(&self.handle)
+                    }
+                    #[doc(hidden)]
+                    pub fn handle(&self) -> u32 {
+                        _rt::Resource::handle(&self.handle)
+                    }
+                    #[doc(hidden)]
+                    fn type_guard<T: 'static>() {
+                        use core::any::TypeId;
+                        static mut LAST_TYPE: Option<TypeId> = None;
+                        unsafe {
+                            assert!(! cfg!(target_feature = "atomics"));
+                            let id = TypeId::of::<T>();
+                            match LAST_TYPE {
+                                Some(ty) => {
+                                    assert!(
+                                        ty == id, "cannot use two types with this resource type"
+                                    )
+                                }
+                                None => LAST_TYPE = Some(id),
+                            }
+                        }
+                    }
+                    #[doc(hidden)]
+                    pub unsafe fn dtor<T: 'static>(handle: *mut u8) {
+                        Self::type_guard::<T>();
+                        let _ = unsafe {
+                            _rt::Box::from_raw(handle as *mut _KnowledgeBaseRep<T>)
+                        };
+                    }
+                    fn as_ptr<T: GuestKnowledgeBase>(
+                        &self,
+                    ) -> *mut _KnowledgeBaseRep<T> {
+                        KnowledgeBase::type_guard::<T>();
+                        T::_resource_rep(self.handle()).cast()
+                    }
+                }
+                /// A borrowed version of [`KnowledgeBase`] which represents a borrowed value
+                /// with the lifetime `'a`.
+                #[derive(Debug)]
+                #[repr(transparent)]
+                pub struct KnowledgeBaseBorrow<'a> {
+                    rep: *mut u8,
+                    _marker: core::marker::PhantomData<&'a KnowledgeBase>,
+                }
+                impl<'a> KnowledgeBaseBorrow<'a> {
+                    #[doc(hidden)]
+                    pub unsafe fn lift(rep: usize) -> Self {
+                        Self {
+                            rep: rep as *mut u8,
+                            _marker: core::marker::PhantomData,
+                        }
+                    }
+                    /// Gets access to the underlying `T` in this resource.
+                    pub fn get<T: GuestKnowledgeBase>(&self) -> &T {
+                        let ptr = unsafe { &mut *self.as_ptr::<T>() };
+                        ptr.as_ref().unwrap()
+                    }
+                    fn as_ptr<T: 'static>(&self) -> *mut _KnowledgeBaseRep<T> {
+                        KnowledgeBase::type_guard::<T>();
+                        self.rep.cast()
+                    }
+                }
+                unsafe impl _rt::WasmResource for KnowledgeBase {
+                    #[inline]
+                    unsafe fn drop(_handle: u32) {
+                        #[cfg(not(target_arch = "wasm32"))]
+                        unreachable!();
+                        #[cfg(target_arch = "wasm32")]
                         {
-                            use super::super::super::lojban::nesy::ast_types::LogicNode as V14;
-                            match e {
-                                V14::Predicate(e) => {
-                                    *base.add(0).cast::<u8>() = (0i32) as u8;
-                                    let (t1_0, t1_1) = e;
-                                    let vec2 = t1_0;
-                                    let ptr2 = vec2.as_ptr().cast::<u8>();
-                                    let len2 = vec2.len();
-                                    *base
-                                        .add(2 * ::core::mem::size_of::<*const u8>())
-                                        .cast::<usize>() = len2;
+                            #[link(
+                                wasm_import_module = "[export]lojban:nesy/engine@0.1.0"
+                            )]
+                            unsafe extern "C" {
+                                #[link_name = "[resource-drop]knowledge-base"]
+                                fn drop(_: u32);
+                            }
+                            unsafe { drop(_handle) };
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_constructor_knowledge_base_cabi<
+                    T: GuestKnowledgeBase,
+                >() -> i32 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let result0 = KnowledgeBase::new(T::new());
+                    (result0).take_handle() as i32
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_knowledge_base_assert_text_cabi<
+                    T: GuestKnowledgeBase,
+                >(arg0: *mut u8, arg1: *mut u8, arg2: usize, arg3: i32) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let len0 = arg2;
+                    let bytes0 = _rt::Vec::from_raw_parts(arg1.cast(), len0, len0);
+                    let result1 = T::assert_text(
+                        unsafe { KnowledgeBaseBorrow::lift(arg0 as u32 as usize) }.get(),
+                        _rt::string_lift(bytes0),
+                        super::super::super::super::lojban::nesy::reasoning::ConflictPolicy::_lift(
+                            arg3 as u8,
+                        ),
+                    );
+                    let ptr2 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    match result1 {
+                        Ok(e) => {
+                            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+                            let Assertion {
+                                handle: handle3,
+                                roots: roots3,
+                                diagnostics: diagnostics3,
+                            } = e;
+                            *ptr2.add(8).cast::<i64>() = _rt::as_i64(handle3);
+                            *ptr2.add(16).cast::<i32>() = _rt::as_i32(roots3);
+                            let vec5 = diagnostics3;
+                            let len5 = vec5.len();
+                            let layout5 = _rt::alloc::Layout::from_size_align_unchecked(
+                                vec5.len() * (2 * ::core::mem::size_of::<*const u8>()),
+                                ::core::mem::size_of::<*const u8>(),
+                            );
+                            let result5 = if layout5.size() != 0 {
+                                let ptr = _rt::alloc::alloc(layout5).cast::<u8>();
+                                if ptr.is_null() {
+                                    _rt::alloc::handle_alloc_error(layout5);
+                                }
+                                ptr
+                            } else {
+                                ::core::ptr::null_mut()
+                            };
+                            for (i, e) in vec5.into_iter().enumerate() {
+                                let base = result5
+                                    .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                                {
+                                    let vec4 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr4 = vec4.as_ptr().cast::<u8>();
+                                    let len4 = vec4.len();
+                                    ::core::mem::forget(vec4);
                                     *base
                                         .add(::core::mem::size_of::<*const u8>())
-                                        .cast::<*mut u8>() = ptr2.cast_mut();
-                                    let vec7 = t1_1;
-                                    let len7 = vec7.len();
-                                    let layout7 = _rt::alloc::Layout::from_size_align_unchecked(
-                                        vec7.len() * (3 * ::core::mem::size_of::<*const u8>()),
+                                        .cast::<usize>() = len4;
+                                    *base.add(0).cast::<*mut u8>() = ptr4.cast_mut();
+                                }
+                            }
+                            *ptr2
+                                .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len5;
+                            *ptr2
+                                .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>() = result5;
+                        }
+                        Err(e) => {
+                            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+                            use super::super::super::super::lojban::nesy::reasoning::AssertError as V12;
+                            match e {
+                                V12::Conflict(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (0i32) as u8;
+                                    let vec10 = e;
+                                    let len10 = vec10.len();
+                                    let layout10 = _rt::alloc::Layout::from_size_align_unchecked(
+                                        vec10.len() * (4 * ::core::mem::size_of::<*const u8>()),
                                         ::core::mem::size_of::<*const u8>(),
                                     );
-                                    let result7 = if layout7.size() != 0 {
-                                        let ptr = _rt::alloc::alloc(layout7).cast::<u8>();
+                                    let result10 = if layout10.size() != 0 {
+                                        let ptr = _rt::alloc::alloc(layout10).cast::<u8>();
                                         if ptr.is_null() {
-                                            _rt::alloc::handle_alloc_error(layout7);
+                                            _rt::alloc::handle_alloc_error(layout10);
                                         }
                                         ptr
                                     } else {
                                         ::core::ptr::null_mut()
                                     };
-                                    for (i, e) in vec7.into_iter().enumerate() {
-                                        let base = result7
-                                            .add(i * (3 * ::core::mem::size_of::<*const u8>()));
+                                    for (i, e) in vec10.into_iter().enumerate() {
+                                        let base = result10
+                                            .add(i * (4 * ::core::mem::size_of::<*const u8>()));
                                         {
-                                            use super::super::super::lojban::nesy::ast_types::LogicalTerm as V6;
-                                            match e {
-                                                V6::Variable(e) => {
-                                                    *base.add(0).cast::<u8>() = (0i32) as u8;
-                                                    let vec3 = e;
-                                                    let ptr3 = vec3.as_ptr().cast::<u8>();
-                                                    let len3 = vec3.len();
-                                                    *base
-                                                        .add(2 * ::core::mem::size_of::<*const u8>())
-                                                        .cast::<usize>() = len3;
-                                                    *base
-                                                        .add(::core::mem::size_of::<*const u8>())
-                                                        .cast::<*mut u8>() = ptr3.cast_mut();
+                                            let super::super::super::super::lojban::nesy::reasoning::Conflict {
+                                                formula: formula6,
+                                                sources: sources6,
+                                            } = e;
+                                            let vec7 = (formula6.into_bytes()).into_boxed_slice();
+                                            let ptr7 = vec7.as_ptr().cast::<u8>();
+                                            let len7 = vec7.len();
+                                            ::core::mem::forget(vec7);
+                                            *base
+                                                .add(::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>() = len7;
+                                            *base.add(0).cast::<*mut u8>() = ptr7.cast_mut();
+                                            let vec9 = sources6;
+                                            let len9 = vec9.len();
+                                            let layout9 = _rt::alloc::Layout::from_size_align_unchecked(
+                                                vec9.len() * (2 * ::core::mem::size_of::<*const u8>()),
+                                                ::core::mem::size_of::<*const u8>(),
+                                            );
+                                            let result9 = if layout9.size() != 0 {
+                                                let ptr = _rt::alloc::alloc(layout9).cast::<u8>();
+                                                if ptr.is_null() {
+                                                    _rt::alloc::handle_alloc_error(layout9);
                                                 }
-                                                V6::Constant(e) => {
-                                                    *base.add(0).cast::<u8>() = (1i32) as u8;
-                                                    let vec4 = e;
-                                                    let ptr4 = vec4.as_ptr().cast::<u8>();
-                                                    let len4 = vec4.len();
-                                                    *base
-                                                        .add(2 * ::core::mem::size_of::<*const u8>())
-                                                        .cast::<usize>() = len4;
+                                                ptr
+                                            } else {
+                                                ::core::ptr::null_mut()
+                                            };
+                                            for (i, e) in vec9.into_iter().enumerate() {
+                                                let base = result9
+                                                    .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                                                {
+                                                    let vec8 = (e.into_bytes()).into_boxed_slice();
+                                                    let ptr8 = vec8.as_ptr().cast::<u8>();
+                                                    let len8 = vec8.len();
+                                                    ::core::mem::forget(vec8);
                                                     *base
                                                         .add(::core::mem::size_of::<*const u8>())
-                                                        .cast::<*mut u8>() = ptr4.cast_mut();
+                                                        .cast::<usize>() = len8;
+                                                    *base.add(0).cast::<*mut u8>() = ptr8.cast_mut();
                                                 }
-                                                V6::Description(e) => {
-                                                    *base.add(0).cast::<u8>() = (2i32) as u8;
-                                                    let vec5 = e;
-                                                    let ptr5 = vec5.as_ptr().cast::<u8>();
-                                                    let len5 = vec5.len();
-                                                    *base
-                                                        .add(2 * ::core::mem::size_of::<*const u8>())
-                                                        .cast::<usize>() = len5;
-                                                    *base
+                                            }
+                                            *base
+                                                .add(3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>() = len9;
+                                            *base
+                                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>() = result9;
+                                        }
+                                    }
+                                    *ptr2
+                                        .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len10;
+                                    *ptr2
+                                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>() = result10;
+                                }
+                                V12::Failed(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (1i32) as u8;
+                                    let vec11 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr11 = vec11.as_ptr().cast::<u8>();
+                                    let len11 = vec11.len();
+                                    ::core::mem::forget(vec11);
+                                    *ptr2
+                                        .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len11;
+                                    *ptr2
+                                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>() = ptr11.cast_mut();
+                                }
+                            }
+                        }
+                    };
+                    ptr2
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_knowledge_base_assert_text<
+                    T: GuestKnowledgeBase,
+                >(arg0: *mut u8) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {
+                            let l1 = *arg0
+                                .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l2 = *arg0
+                                .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            let base5 = l1;
+                            let len5 = l2;
+                            for i in 0..len5 {
+                                let base = base5
+                                    .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                                {
+                                    let l3 = *base.add(0).cast::<*mut u8>();
+                                    let l4 = *base
+                                        .add(::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l3, l4, 1);
+                                }
+                            }
+                            _rt::cabi_dealloc(
+                                base5,
+                                len5 * (2 * ::core::mem::size_of::<*const u8>()),
+                                ::core::mem::size_of::<*const u8>(),
+                            );
+                        }
+                        _ => {
+                            let l6 = i32::from(*arg0.add(8).cast::<u8>());
+                            match l6 {
+                                0 => {
+                                    let l7 = *arg0
+                                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>();
+                                    let l8 = *arg0
+                                        .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    let base16 = l7;
+                                    let len16 = l8;
+                                    for i in 0..len16 {
+                                        let base = base16
+                                            .add(i * (4 * ::core::mem::size_of::<*const u8>()));
+                                        {
+                                            let l9 = *base.add(0).cast::<*mut u8>();
+                                            let l10 = *base
+                                                .add(::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>();
+                                            _rt::cabi_dealloc(l9, l10, 1);
+                                            let l11 = *base
+                                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>();
+                                            let l12 = *base
+                                                .add(3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>();
+                                            let base15 = l11;
+                                            let len15 = l12;
+                                            for i in 0..len15 {
+                                                let base = base15
+                                                    .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                                                {
+                                                    let l13 = *base.add(0).cast::<*mut u8>();
+                                                    let l14 = *base
                                                         .add(::core::mem::size_of::<*const u8>())
-                                                        .cast::<*mut u8>() = ptr5.cast_mut();
-                                                }
-                                                V6::Unspecified => {
-                                                    *base.add(0).cast::<u8>() = (3i32) as u8;
+                                                        .cast::<usize>();
+                                                    _rt::cabi_dealloc(l13, l14, 1);
                                                 }
                                             }
+                                            _rt::cabi_dealloc(
+                                                base15,
+                                                len15 * (2 * ::core::mem::size_of::<*const u8>()),
+                                                ::core::mem::size_of::<*const u8>(),
+                                            );
                                         }
                                     }
-                                    *base
-                                        .add(4 * ::core::mem::size_of::<*const u8>())
-                                        .cast::<usize>() = len7;
-                                    *base
-                                        .add(3 * ::core::mem::size_of::<*const u8>())
-                                        .cast::<*mut u8>() = result7;
-                                    cleanup_list.extend_from_slice(&[(result7, layout7)]);
+                                    _rt::cabi_dealloc(
+                                        base16,
+                                        len16 * (4 * ::core::mem::size_of::<*const u8>()),
+                                        ::core::mem::size_of::<*const u8>(),
+                                    );
                                 }
-                                V14::AndNode(e) => {
-                                    *base.add(0).cast::<u8>() = (1i32) as u8;
-                                    let (t8_0, t8_1) = e;
-                                    *base
-                                        .add(::core::mem::size_of::<*const u8>())
-                                        .cast::<i32>() = _rt::as_i32(t8_0);
-                                    *base
-                                        .add(4 + 1 * ::core::mem::size_of::<*const u8>())
-                                        .cast::<i32>() = _rt::as_i32(t8_1);
+                                _ => {
+                                    let l17 = *arg0
+                                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>();
+                                    let l18 = *arg0
+                                        .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l17, l18, 1);
                                 }
-                                V14::OrNode(e) => {
-                                    *base.add(0).cast::<u8>() = (2i32) as u8;
-                                    let (t9_0, t9_1) = e;
-                                    *base
-                                        .add(::core::mem::size_of::<*const u8>())
-                                        .cast::<i32>() = _rt::as_i32(t9_0);
-                                    *base
-                                        .add(4 + 1 * ::core::mem::size_of::<*const u8>())
-                                        .cast::<i32>() = _rt::as_i32(t9_1);
+                            }
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_knowledge_base_retract_cabi<
+                    T: GuestKnowledgeBase,
+                >(arg0: *mut u8, arg1: i64) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let result0 = T::retract(
+                        unsafe { KnowledgeBaseBorrow::lift(arg0 as u32 as usize) }.get(),
+                        arg1 as u64,
+                    );
+                    let ptr1 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    match result0 {
+                        Ok(_) => {
+                            *ptr1.add(0).cast::<u8>() = (0i32) as u8;
+                        }
+                        Err(e) => {
+                            *ptr1.add(0).cast::<u8>() = (1i32) as u8;
+                            let vec2 = (e.into_bytes()).into_boxed_slice();
+                            let ptr2 = vec2.as_ptr().cast::<u8>();
+                            let len2 = vec2.len();
+                            ::core::mem::forget(vec2);
+                            *ptr1
+                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len2;
+                            *ptr1
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>() = ptr2.cast_mut();
+                        }
+                    };
+                    ptr1
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_knowledge_base_retract<
+                    T: GuestKnowledgeBase,
+                >(arg0: *mut u8) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {}
+                        _ => {
+                            let l1 = *arg0
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l2 = *arg0
+                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            _rt::cabi_dealloc(l1, l2, 1);
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_knowledge_base_quarantined_cabi<
+                    T: GuestKnowledgeBase,
+                >(arg0: *mut u8) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let result0 = T::quarantined(
+                        unsafe { KnowledgeBaseBorrow::lift(arg0 as u32 as usize) }.get(),
+                    );
+                    let ptr1 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    let vec10 = result0;
+                    let len10 = vec10.len();
+                    let layout10 = _rt::alloc::Layout::from_size_align_unchecked(
+                        vec10.len() * (4 * ::core::mem::size_of::<*const u8>()),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let result10 = if layout10.size() != 0 {
+                        let ptr = _rt::alloc::alloc(layout10).cast::<u8>();
+                        if ptr.is_null() {
+                            _rt::alloc::handle_alloc_error(layout10);
+                        }
+                        ptr
+                    } else {
+                        ::core::ptr::null_mut()
+                    };
+                    for (i, e) in vec10.into_iter().enumerate() {
+                        let base = result10
+                            .add(i * (4 * ::core::mem::size_of::<*const u8>()));
+                        {
+                            let super::super::super::super::lojban::nesy::reasoning::QuarantinedFact {
+                                sources: sources2,
+                                conflicts: conflicts2,
+                            } = e;
+                            let vec4 = sources2;
+                            let len4 = vec4.len();
+                            let layout4 = _rt::alloc::Layout::from_size_align_unchecked(
+                                vec4.len() * (2 * ::core::mem::size_of::<*const u8>()),
+                                ::core::mem::size_of::<*const u8>(),
+                            );
+                            let result4 = if layout4.size() != 0 {
+                                let ptr = _rt::alloc::alloc(layout4).cast::<u8>();
+                                if ptr.is_null() {
+                                    _rt::alloc::handle_alloc_error(layout4);
                                 }
-                                V14::NotNode(e) => {
-                                    *base.add(0).cast::<u8>() = (3i32) as u8;
+                                ptr
+                            } else {
+                                ::core::ptr::null_mut()
+                            };
+                            for (i, e) in vec4.into_iter().enumerate() {
+                                let base = result4
+                                    .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                                {
+                                    let vec3 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr3 = vec3.as_ptr().cast::<u8>();
+                                    let len3 = vec3.len();
+                                    ::core::mem::forget(vec3);
                                     *base
                                         .add(::core::mem::size_of::<*const u8>())
-                                        .cast::<i32>() = _rt::as_i32(e);
+                                        .cast::<usize>() = len3;
+                                    *base.add(0).cast::<*mut u8>() = ptr3.cast_mut();
                                 }
-                                V14::ExistsNode(e) => {
-                                    *base.add(0).cast::<u8>() = (4i32) as u8;
-                                    let (t10_0, t10_1) = e;
-                                    let vec11 = t10_0;
-                                    let ptr11 = vec11.as_ptr().cast::<u8>();
-                                    let len11 = vec11.len();
-                                    *base
-                                        .add(2 * ::core::mem::size_of::<*const u8>())
-                                        .cast::<usize>() = len11;
+                            }
+                            *base
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len4;
+                            *base.add(0).cast::<*mut u8>() = result4;
+                            let vec9 = conflicts2;
+                            let len9 = vec9.len();
+                            let layout9 = _rt::alloc::Layout::from_size_align_unchecked(
+                                vec9.len() * (4 * ::core::mem::size_of::<*const u8>()),
+                                ::core::mem::size_of::<*const u8>(),
+                            );
+                            let result9 = if layout9.size() != 0 {
+                                let ptr = _rt::alloc::alloc(layout9).cast::<u8>();
+                                if ptr.is_null() {
+                                    _rt::alloc::handle_alloc_error(layout9);
+                                }
+                                ptr
+                            } else {
+                                ::core::ptr::null_mut()
+                            };
+                            for (i, e) in vec9.into_iter().enumerate() {
+                                let base = result9
+                                    .add(i * (4 * ::core::mem::size_of::<*const u8>()));
+                                {
+                                    let super::super::super::super::lojban::nesy::reasoning::Conflict {
+                                        formula: formula5,
+                                        sources: sources5,
+                                    } = e;
+                                    let vec6 = (formula5.into_bytes()).into_boxed_slice();
+                                    let ptr6 = vec6.as_ptr().cast::<u8>();
+                                    let len6 = vec6.len();
+                                    ::core::mem::forget(vec6);
                                     *base
                                         .add(::core::mem::size_of::<*const u8>())
-                                        .cast::<*mut u8>() = ptr11.cast_mut();
+                                        .cast::<usize>() = len6;
+                                    *base.add(0).cast::<*mut u8>() = ptr6.cast_mut();
+                                    let vec8 = sources5;
+                                    let len8 = vec8.len();
+                                    let layout8 = _rt::alloc::Layout::from_size_align_unchecked(
+                                        vec8.len() * (2 * ::core::mem::size_of::<*const u8>()),
+                                        ::core::mem::size_of::<*const u8>(),
+                                    );
+                                    let result8 = if layout8.size() != 0 {
+                                        let ptr = _rt::alloc::alloc(layout8).cast::<u8>();
+                                        if ptr.is_null() {
+                                            _rt::alloc::handle_alloc_error(layout8);
+                                        }
+                                        ptr
+                                    } else {
+                                        ::core::ptr::null_mut()
+                                    };
+                                    for (i, e) in vec8.into_iter().enumerate() {
+                                        let base = result8
+                                            .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                                        {
+                                            let vec7 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr7 = vec7.as_ptr().cast::<u8>();
+                                            let len7 = vec7.len();
+                                            ::core::mem::forget(vec7);
+                                            *base
+                                                .add(::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>() = len7;
+                                            *base.add(0).cast::<*mut u8>() = ptr7.cast_mut();
+                                        }
+                                    }
                                     *base
                                         .add(3 * ::core::mem::size_of::<*const u8>())
-                                        .cast::<i32>() = _rt::as_i32(t10_1);
-                                }
-                                V14::ForAllNode(e) => {
-                                    *base.add(0).cast::<u8>() = (5i32) as u8;
-                                    let (t12_0, t12_1) = e;
-                                    let vec13 = t12_0;
-                                    let ptr13 = vec13.as_ptr().cast::<u8>();
-                                    let len13 = vec13.len();
+                                        .cast::<usize>() = len8;
                                     *base
                                         .add(2 * ::core::mem::size_of::<*const u8>())
-                                        .cast::<usize>() = len13;
-                                    *base
+                                        .cast::<*mut u8>() = result8;
+                                }
+                            }
+                            *base
+                                .add(3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len9;
+                            *base
+                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>() = result9;
+                        }
+                    }
+                    *ptr1.add(::core::mem::size_of::<*const u8>()).cast::<usize>() = len10;
+                    *ptr1.add(0).cast::<*mut u8>() = result10;
+                    ptr1
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_knowledge_base_quarantined<
+                    T: GuestKnowledgeBase,
+                >(arg0: *mut u8) {
+                    let l0 = *arg0.add(0).cast::<*mut u8>();
+                    let l1 = *arg0
+                        .add(::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base17 = l0;
+                    let len17 = l1;
+                    for i in 0..len17 {
+                        let base = base17
+                            .add(i * (4 * ::core::mem::size_of::<*const u8>()));
+                        {
+                            let l2 = *base.add(0).cast::<*mut u8>();
+                            let l3 = *base
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            let base6 = l2;
+                            let len6 = l3;
+                            for i in 0..len6 {
+                                let base = base6
+                                    .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                                {
+                                    let l4 = *base.add(0).cast::<*mut u8>();
+                                    let l5 = *base
                                         .add(::core::mem::size_of::<*const u8>())
-                                        .cast::<*mut u8>() = ptr13.cast_mut();
-                                    *base
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l4, l5, 1);
+                                }
+                            }
+                            _rt::cabi_dealloc(
+                                base6,
+                                len6 * (2 * ::core::mem::size_of::<*const u8>()),
+                                ::core::mem::size_of::<*const u8>(),
+                            );
+                            let l7 = *base
+                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l8 = *base
+                                .add(3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            let base16 = l7;
+                            let len16 = l8;
+                            for i in 0..len16 {
+                                let base = base16
+                                    .add(i * (4 * ::core::mem::size_of::<*const u8>()));
+                                {
+                                    let l9 = *base.add(0).cast::<*mut u8>();
+                                    let l10 = *base
+                                        .add(::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l9, l10, 1);
+                                    let l11 = *base
+                                        .add(2 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>();
+                                    let l12 = *base
                                         .add(3 * ::core::mem::size_of::<*const u8>())
-                                        .cast::<i32>() = _rt::as_i32(t12_1);
+                                        .cast::<usize>();
+                                    let base15 = l11;
+                                    let len15 = l12;
+                                    for i in 0..len15 {
+                                        let base = base15
+                                            .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                                        {
+                                            let l13 = *base.add(0).cast::<*mut u8>();
+                                            let l14 = *base
+                                                .add(::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>();
+                                            _rt::cabi_dealloc(l13, l14, 1);
+                                        }
+                                    }
+                                    _rt::cabi_dealloc(
+                                        base15,
+                                        len15 * (2 * ::core::mem::size_of::<*const u8>()),
+                                        ::core::mem::size_of::<*const u8>(),
+                                    );
                                 }
                             }
+                            _rt::cabi_dealloc(
+                                base16,
+                                len16 * (4 * ::core::mem::size_of::<*const u8>()),
+                                ::core::mem::size_of::<*const u8>(),
+                            );
                         }
                     }
-                    let vec16 = roots0;
-                    let ptr16 = vec16.as_ptr().cast::<u8>();
-                    let len16 = vec16.len();
-                    let ptr17 = ret_area.0.as_mut_ptr().cast::<u8>();
-                    #[cfg(target_arch = "wasm32")]
-                    #[link(wasm_import_module = "lojban:nesy/reasoning@0.1.0")]
-                    unsafe extern "C" {
-                        #[link_name = "assert-fact"]
-                        fn wit_import18(
-                            _: *mut u8,
-                            _: usize,
-                            _: *mut u8,
-                            _: usize,
-                            _: *mut u8,
-                        );
-                    }
-                    #[cfg(not(target_arch = "wasm32"))]
-                    unsafe extern "C" fn wit_import18(
-                        _: *mut u8,
-                        _: usize,
-                        _: *mut u8,
-                        _: usize,
-                        _: *mut u8,
-                    ) {
-                        unreachable!()
-                    }
-                    unsafe {
-                        wit_import18(result15, len15, ptr16.cast_mut(), len16, ptr17)
-                    };
-                    let l19 = i32::from(*ptr17.add(0).cast::<u8>());
-                    let result23 = match l19 {
-                        0 => {
-                            let e = ();
-                            Ok(e)
+                    _rt::cabi_dealloc(
+                        base17,
+                        len17 * (4 * ::core::mem::size_of::<*const u8>()),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_knowledge_base_query_text_cabi<
+                    T: GuestKnowledgeBase,
+                >(arg0: *mut u8, arg1: *mut u8, arg2: usize, arg3: i32) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let len0 = arg2;
+                    let bytes0 = _rt::Vec::from_raw_parts(arg1.cast(), len0, len0);
+                    let result1 = T::query_text(
+                        unsafe { KnowledgeBaseBorrow::lift(arg0 as u32 as usize) }.get(),
+                        _rt::string_lift(bytes0),
+                        super::super::super::super::lojban::nesy::reasoning::WorldAssumption::_lift(
+                            arg3 as u8,
+                        ),
+                    );
+                    let ptr2 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    match result1 {
+                        Ok(e) => {
+                            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+                            *ptr2
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (e.clone() as i32) as u8;
                         }
-                        1 => {
-                            let e = {
-                                let l20 = *ptr17
-                                    .add(::core::mem::size_of::<*const u8>())
-                                    .cast::<*mut u8>();
-                                let l21 = *ptr17
-                                    .add(2 * ::core::mem::size_of::<*const u8>())
-                                    .cast::<usize>();
-                                let len22 = l21;
-                                let bytes22 = _rt::Vec::from_raw_parts(
-                                    l20.cast(),
-                                    len22,
-                                    len22,
-                                );
-                                _rt::string_lift(bytes22)
-                            };
-                            Err(e)
+                        Err(e) => {
+                            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+                            let vec3 = (e.into_bytes()).into_boxed_slice();
+                            let ptr3 = vec3.as_ptr().cast::<u8>();
+                            let len3 = vec3.len();
+                            ::core::mem::forget(vec3);
+                            *ptr2
+                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len3;
+                            *ptr2
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>() = ptr3.cast_mut();
                         }
-                        _ => _rt::invalid_enum_discriminant(),
                     };
-                    if layout15.size() != 0 {
-                        _rt::alloc::dealloc(result15.cast(), layout15);
-                    }
-                    for (ptr, layout) in cleanup_list {
-                        if layout.size() != 0 {
-                            _rt::alloc::dealloc(ptr.cast(), layout);
+                    ptr2
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_knowledge_base_query_text<
+                    T: GuestKnowledgeBase,
+                >(arg0: *mut u8) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {}
+                        _ => {
+                            let l1 = *arg0
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l2 = *arg0
+                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            _rt::cabi_dealloc(l1, l2, 1);
                         }
                     }
-                    result23
                 }
-            }
-            #[allow(unused_unsafe, clippy::all)]
-            pub fn query_entailment(logic: &LogicBuffer) -> Result<bool, _rt::String> {
-                unsafe {
-                    let mut cleanup_list = _rt::Vec::new();
-                    #[cfg_attr(target_pointer_width = "64", repr(align(8)))]
-                    #[cfg_attr(target_pointer_width = "32", repr(align(4)))]
-                    struct RetArea(
-                        [::core::mem::MaybeUninit<
-                            u8,
-                        >; 3 * ::core::mem::size_of::<*const u8>()],
-                    );
-                    let mut ret_area = RetArea(
-                        [::core::mem::MaybeUninit::uninit(); 3
-                            * ::core::mem::size_of::<*const u8>()],
-                    );
-                    let super::super::super::lojban::nesy::ast_types::LogicBuffer {
-                        nodes: nodes0,
-                        roots: roots0,
-                    } = logic;
-                    let vec15 = nodes0;
-                    let len15 = vec15.len();
-                    let layout15 = _rt::alloc::Layout::from_size_align_unchecked(
-                        vec15.len() * (5 * ::core::mem::size_of::<*const u8>()),
-                        ::core::mem::size_of::<*const u8>(),
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_knowledge_base_ask_text_cabi<
+                    T: GuestKnowledgeBase,
+                >(arg0: *mut u8, arg1: *mut u8, arg2: usize, arg3: i32) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let len0 = arg2;
+                    let bytes0 = _rt::Vec::from_raw_parts(arg1.cast(), len0, len0);
+                    let result1 = T::ask_text(
+                        unsafe { KnowledgeBaseBorrow::lift(arg0 as u32 as usize) }.get(),
+                        _rt::string_lift(bytes0),
+                        super::super::super::super::lojban::nesy::reasoning::WorldAssumption::_lift(
+                            arg3 as u8,
+                        ),
                     );
-                    let result15 = if layout15.size() != 0 {
-                        let ptr = _rt::alloc::alloc(layout15).cast::<u8>();
-                        if ptr.is_null() {
-                            _rt::alloc::handle_alloc_error(layout15);
-                        }
-                        ptr
-                    } else {
-                        ::core::ptr::null_mut()
-                    };
-                    for (i, e) in vec15.into_iter().enumerate() {
-                        let base = result15
-                            .add(i * (5 * ::core::mem::size_of::<*const u8>()));
-                        {
-                            use super::super::super::lojban::nesy::ast_types::LogicNode as V14;
+                    let ptr2 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    match result1 {
+                        Ok(e) => {
+                            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
                             match e {
-                                V14::Predicate(e) => {
-                                    *base.add(0).cast::<u8>() = (0i32) as u8;
-                                    let (t1_0, t1_1) = e;
-                                    let vec2 = t1_0;
-                                    let ptr2 = vec2.as_ptr().cast::<u8>();
-                                    let len2 = vec2.len();
-                                    *base
+                                Answer::Truth(e) => {
+                                    *ptr2
+                                        .add(::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (0i32) as u8;
+                                    *ptr2
                                         .add(2 * ::core::mem::size_of::<*const u8>())
-                                        .cast::<usize>() = len2;
-                                    *base
+                                        .cast::<u8>() = (e.clone() as i32) as u8;
+                                }
+                                Answer::Bindings(e) => {
+                                    *ptr2
                                         .add(::core::mem::size_of::<*const u8>())
-                                        .cast::<*mut u8>() = ptr2.cast_mut();
-                                    let vec7 = t1_1;
-                                    let len7 = vec7.len();
-                                    let layout7 = _rt::alloc::Layout::from_size_align_unchecked(
-                                        vec7.len() * (3 * ::core::mem::size_of::<*const u8>()),
+                                        .cast::<u8>() = (1i32) as u8;
+                                    let vec9 = e;
+                                    let len9 = vec9.len();
+                                    let layout9 = _rt::alloc::Layout::from_size_align_unchecked(
+                                        vec9.len() * (2 * ::core::mem::size_of::<*const u8>()),
                                         ::core::mem::size_of::<*const u8>(),
                                     );
-                                    let result7 = if layout7.size() != 0 {
-                                        let ptr = _rt::alloc::alloc(layout7).cast::<u8>();
+                                    let result9 = if layout9.size() != 0 {
+                                        let ptr = _rt::alloc::alloc(layout9).cast::<u8>();
                                         if ptr.is_null() {
-                                            _rt::alloc::handle_alloc_error(layout7);
+                                            _rt::alloc::handle_alloc_error(layout9);
                                         }
                                         ptr
                                     } else {
                                         ::core::ptr::null_mut()
                                     };
-                                    for (i, e) in vec7.into_iter().enumerate() {
-                                        let base = result7
-                                            .add(i * (3 * ::core::mem::size_of::<*const u8>()));
+                                    for (i, e) in vec9.into_iter().enumerate() {
+                                        let base = result9
+                                            .add(i * (2 * ::core::mem::size_of::<*const u8>()));
                                         {
-                                            use super::super::super::lojban::nesy::ast_types::LogicalTerm as V6;
-                                            match e {
-                                                V6::Variable(e) => {
-                                                    *base.add(0).cast::<u8>() = (0i32) as u8;
-                                                    let vec3 = e;
-                                                    let ptr3 = vec3.as_ptr().cast::<u8>();
-                                                    let len3 = vec3.len();
-                                                    *base
-                                                        .add(2 * ::core::mem::size_of::<*const u8>())
-                                                        .cast::<usize>() = len3;
-                                                    *base
-                                                        .add(::core::mem::size_of::<*const u8>())
-                                                        .cast::<*mut u8>() = ptr3.cast_mut();
+                                            let vec8 = e;
+                                            let len8 = vec8.len();
+                                            let layout8 = _rt::alloc::Layout::from_size_align_unchecked(
+                                                vec8.len() * (5 * ::core::mem::size_of::<*const u8>()),
+                                                ::core::mem::size_of::<*const u8>(),
+                                            );
+                                            let result8 = if layout8.size() != 0 {
+                                                let ptr = _rt::alloc::alloc(layout8).cast::<u8>();
+                                                if ptr.is_null() {
+                                                    _rt::alloc::handle_alloc_error(layout8);
                                                 }
-                                                V6::Constant(e) => {
-                                                    *base.add(0).cast::<u8>() = (1i32) as u8;
-                                                    let vec4 = e;
+                                                ptr
+                                            } else {
+                                                ::core::ptr::null_mut()
+                                            };
+                                            for (i, e) in vec8.into_iter().enumerate() {
+                                                let base = result8
+                                                    .add(i * (5 * ::core::mem::size_of::<*const u8>()));
+                                                {
+                                                    let super::super::super::super::lojban::nesy::reasoning::Binding {
+                                                        variable: variable3,
+                                                        value: value3,
+                                                    } = e;
+                                                    let vec4 = (variable3.into_bytes()).into_boxed_slice();
                                                     let ptr4 = vec4.as_ptr().cast::<u8>();
                                                     let len4 = vec4.len();
-                                                    *base
-                                                        .add(2 * ::core::mem::size_of::<*const u8>())
-                                                        .cast::<usize>() = len4;
-                                                    *base
-                                                        .add(::core::mem::size_of::<*const u8>())
-                                                        .cast::<*mut u8>() = ptr4.cast_mut();
-                                                }
-                                                V6::Description(e) => {
-                                                    *base.add(0).cast::<u8>() = (2i32) as u8;
-                                                    let vec5 = e;
-                                                    let ptr5 = vec5.as_ptr().cast::<u8>();
-                                                    let len5 = vec5.len();
-                                                    *base
-                                                        .add(2 * ::core::mem::size_of::<*const u8>())
-                                                        .cast::<usize>() = len5;
+                                                    ::core::mem::forget(vec4);
                                                     *base
                                                         .add(::core::mem::size_of::<*const u8>())
-                                                        .cast::<*mut u8>() = ptr5.cast_mut();
-                                                }
-                                                V6::Unspecified => {
-                                                    *base.add(0).cast::<u8>() = (3i32) as u8;
+                                                        .cast::<usize>() = len4;
+                                                    *base.add(0).cast::<*mut u8>() = ptr4.cast_mut();
+                                                    use super::super::super::super::lojban::nesy::reasoning::AnswerTerm as V7;
+                                                    match value3 {
+                                                        V7::Entity(e) => {
+                                                            *base
+                                                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                                                .cast::<u8>() = (0i32) as u8;
+                                                            let vec5 = (e.into_bytes()).into_boxed_slice();
+                                                            let ptr5 = vec5.as_ptr().cast::<u8>();
+                                                            let len5 = vec5.len();
+                                                            ::core::mem::forget(vec5);
+                                                            *base
+                                                                .add(4 * ::core::mem::size_of::<*const u8>())
+                                                                .cast::<usize>() = len5;
+                                                            *base
+                                                                .add(3 * ::core::mem::size_of::<*const u8>())
+                                                                .cast::<*mut u8>() = ptr5.cast_mut();
+                                                        }
+                                                        V7::Relation(e) => {
+                                                            *base
+                                                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                                                .cast::<u8>() = (1i32) as u8;
+                                                            let vec6 = (e.into_bytes()).into_boxed_slice();
+                                                            let ptr6 = vec6.as_ptr().cast::<u8>();
+                                                            let len6 = vec6.len();
+                                                            ::core::mem::forget(vec6);
+                                                            *base
+                                                                .add(4 * ::core::mem::size_of::<*const u8>())
+                                                                .cast::<usize>() = len6;
+                                                            *base
+                                                                .add(3 * ::core::mem::size_of::<*const u8>())
+                                                                .cast::<*mut u8>() = ptr6.cast_mut();
+                                                        }
+                                                    }
                                                 }
                                             }
+                                            *base
+                                                .add(::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>() = len8;
+                                            *base.add(0).cast::<*mut u8>() = result8;
                                         }
                                     }
-                                    *base
-                                        .add(4 * ::core::mem::size_of::<*const u8>())
-                                        .cast::<usize>() = len7;
-                                    *base
+                                    *ptr2
                                         .add(3 * ::core::mem::size_of::<*const u8>())
-                                        .cast::<*mut u8>() = result7;
-                                    cleanup_list.extend_from_slice(&[(result7, layout7)]);
-                                }
-                                V14::AndNode(e) => {
-                                    *base.add(0).cast::<u8>() = (1i32) as u8;
-                                    let (t8_0, t8_1) = e;
-                                    *base
-                                        .add(::core::mem::size_of::<*const u8>())
-                                        .cast::<i32>() = _rt::as_i32(t8_0);
-                                    *base
-                                        .add(4 + 1 * ::core::mem::size_of::<*const u8>())
-                                        .cast::<i32>() = _rt::as_i32(t8_1);
-                                }
-                                V14::OrNode(e) => {
-                                    *base.add(0).cast::<u8>() = (2i32) as u8;
-                                    let (t9_0, t9_1) = e;
-                                    *base
-                                        .add(::core::mem::size_of::<*const u8>())
-                                        .cast::<i32>() = _rt::as_i32(t9_0);
-                                    *base
-                                        .add(4 + 1 * ::core::mem::size_of::<*const u8>())
-                                        .cast::<i32>() = _rt::as_i32(t9_1);
-                                }
-                                V14::NotNode(e) => {
-                                    *base.add(0).cast::<u8>() = (3i32) as u8;
-                                    *base
-                                        .add(::core::mem::size_of::<*const u8>())
-                                        .cast::<i32>() = _rt::as_i32(e);
-                                }
-                                V14::ExistsNode(e) => {
-                                    *base.add(0).cast::<u8>() = (4i32) as u8;
-                                    let (t10_0, t10_1) = e;
-                                    let vec11 = t10_0;
-                                    let ptr11 = vec11.as_ptr().cast::<u8>();
-                                    let len11 = vec11.len();
-                                    *base
+                                        .cast::<usize>() = len9;
+                                    *ptr2
                                         .add(2 * ::core::mem::size_of::<*const u8>())
-                                        .cast::<usize>() = len11;
-                                    *base
-                                        .add(::core::mem::size_of::<*const u8>())
-                                        .cast::<*mut u8>() = ptr11.cast_mut();
-                                    *base
-                                        .add(3 * ::core::mem::size_of::<*const u8>())
-                                        .cast::<i32>() = _rt::as_i32(t10_1);
+                                        .cast::<*mut u8>() = result9;
                                 }
-                                V14::ForAllNode(e) => {
-                                    *base.add(0).cast::<u8>() = (5i32) as u8;
-                                    let (t12_0, t12_1) = e;
-                                    let vec13 = t12_0;
-                                    let ptr13 = vec13.as_ptr().cast::<u8>();
-                                    let len13 = vec13.len();
-                                    *base
+                            }
+                        }
+                        Err(e) => {
+                            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+                            let vec10 = (e.into_bytes()).into_boxed_slice();
+                            let ptr10 = vec10.as_ptr().cast::<u8>();
+                            let len10 = vec10.len();
+                            ::core::mem::forget(vec10);
+                            *ptr2
+                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len10;
+                            *ptr2
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>() = ptr10.cast_mut();
+                        }
+                    };
+                    ptr2
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_knowledge_base_ask_text<
+                    T: GuestKnowledgeBase,
+                >(arg0: *mut u8) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {
+                            let l1 = i32::from(
+                                *arg0.add(::core::mem::size_of::<*const u8>()).cast::<u8>(),
+                            );
+                            match l1 {
+                                0 => {}
+                                _ => {
+                                    let l2 = *arg0
                                         .add(2 * ::core::mem::size_of::<*const u8>())
-                                        .cast::<usize>() = len13;
-                                    *base
-                                        .add(::core::mem::size_of::<*const u8>())
-                                        .cast::<*mut u8>() = ptr13.cast_mut();
-                                    *base
+                                        .cast::<*mut u8>();
+                                    let l3 = *arg0
                                         .add(3 * ::core::mem::size_of::<*const u8>())
-                                        .cast::<i32>() = _rt::as_i32(t12_1);
+                                        .cast::<usize>();
+                                    let base14 = l2;
+                                    let len14 = l3;
+                                    for i in 0..len14 {
+                                        let base = base14
+                                            .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                                        {
+                                            let l4 = *base.add(0).cast::<*mut u8>();
+                                            let l5 = *base
+                                                .add(::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>();
+                                            let base13 = l4;
+                                            let len13 = l5;
+                                            for i in 0..len13 {
+                                                let base = base13
+                                                    .add(i * (5 * ::core::mem::size_of::<*const u8>()));
+                                                {
+                                                    let l6 = *base.add(0).cast::<*mut u8>();
+                                                    let l7 = *base
+                                                        .add(::core::mem::size_of::<*const u8>())
+                                                        .cast::<usize>();
+                                                    _rt::cabi_dealloc(l6, l7, 1);
+                                                    let l8 = i32::from(
+                                                        *base
+                                                            .add(2 * ::core::mem::size_of::<*const u8>())
+                                                            .cast::<u8>(),
+                                                    );
+                                                    match l8 {
+                                                        0 => {
+                                                            let l9 = *base
+                                                                .add(3 * ::core::mem::size_of::<*const u8>())
+                                                                .cast::<*mut u8>();
+                                                            let l10 = *base
+                                                                .add(4 * ::core::mem::size_of::<*const u8>())
+                                                                .cast::<usize>();
+                                                            _rt::cabi_dealloc(l9, l10, 1);
+                                                        }
+                                                        _ => {
+                                                            let l11 = *base
+                                                                .add(3 * ::core::mem::size_of::<*const u8>())
+                                                                .cast::<*mut u8>();
+                                                            let l12 = *base
+                                                                .add(4 * ::core::mem::size_of::<*const u8>())
+                                                                .cast::<usize>();
+                                                            _rt::cabi_dealloc(l11, l12, 1);
+                                                        }
+                                                    }
+                                                }
+                                            }
+                                            _rt::cabi_dealloc(
+                                                base13,
+                                                len13 * (5 * ::core::mem::size_of::<*const u8>()),
+                                                ::core::mem::size_of::<*const u8>(),
+                                            );
+                                        }
+                                    }
+                                    _rt::cabi_dealloc(
+                                        base14,
+                                        len14 * (2 * ::core::mem::size_of::<*const u8>()),
+                                        ::core::mem::size_of::<*const u8>(),
+                                    );
                                 }
                             }
                         }
+                        _ => {
+                            let l15 = *arg0
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l16 = *arg0
+                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            _rt::cabi_dealloc(l15, l16, 1);
+                        }
                     }
-                    let vec16 = roots0;
-                    let ptr16 = vec16.as_ptr().cast::<u8>();
-                    let len16 = vec16.len();
-                    let ptr17 = ret_area.0.as_mut_ptr().cast::<u8>();
-                    #[cfg(target_arch = "wasm32")]
-                    #[link(wasm_import_module = "lojban:nesy/reasoning@0.1.0")]
-                    unsafe extern "C" {
-                        #[link_name = "query-entailment"]
-                        fn wit_import18(
-                            _: *mut u8,
-                            _: usize,
-                            _: *mut u8,
-                            _: usize,
-                            _: *mut u8,
-                        );
-                    }
-                    #[cfg(not(target_arch = "wasm32"))]
-                    unsafe extern "C" fn wit_import18(
-                        _: *mut u8,
-                        _: usize,
-                        _: *mut u8,
-                        _: usize,
-                        _: *mut u8,
-                    ) {
-                        unreachable!()
-                    }
-                    unsafe {
-                        wit_import18(result15, len15, ptr16.cast_mut(), len16, ptr17)
-                    };
-                    let l19 = i32::from(*ptr17.add(0).cast::<u8>());
-                    let result24 = match l19 {
-                        0 => {
-                            let e = {
-                                let l20 = i32::from(
-                                    *ptr17.add(::core::mem::size_of::<*const u8>()).cast::<u8>(),
-                                );
-                                _rt::bool_lift(l20 as u8)
-                            };
-                            Ok(e)
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_knowledge_base_reset_cabi<
+                    T: GuestKnowledgeBase,
+                >(arg0: *mut u8) {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    T::reset(
+                        unsafe { KnowledgeBaseBorrow::lift(arg0 as u32 as usize) }.get(),
+                    );
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_knowledge_base_fork_cabi<
+                    T: GuestKnowledgeBase,
+                >(arg0: *mut u8) -> i32 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let result0 = T::fork(
+                        unsafe { KnowledgeBaseBorrow::lift(arg0 as u32 as usize) }.get(),
+                    );
+                    (result0).take_handle() as i32
+                }
+                pub trait Guest {
+                    type KnowledgeBase: GuestKnowledgeBase;
+                }
+                pub trait GuestKnowledgeBase: 'static {
+                    #[doc(hidden)]
+                    unsafe fn _resource_new(val: *mut u8) -> u32
+                    where
+                        Self: Sized,
+                    {
+                        #[cfg(not(target_arch = "wasm32"))]
+                        {
+                            let _ = val;
+                            unreachable!();
                         }
-                        1 => {
-                            let e = {
-                                let l21 = *ptr17
-                                    .add(::core::mem::size_of::<*const u8>())
-                                    .cast::<*mut u8>();
-                                let l22 = *ptr17
-                                    .add(2 * ::core::mem::size_of::<*const u8>())
-                                    .cast::<usize>();
-                                let len23 = l22;
-                                let bytes23 = _rt::Vec::from_raw_parts(
-                                    l21.cast(),
-                                    len23,
-                                    len23,
-                                );
-                                _rt::string_lift(bytes23)
-                            };
-                            Err(e)
+                        #[cfg(target_arch = "wasm32")]
+                        {
+                            #[link(
+                                wasm_import_module = "[export]lojban:nesy/engine@0.1.0"
+                            )]
+                            unsafe extern "C" {
+                                #[link_name = "[resource-new]knowledge-base"]
+                                fn new(_: *mut u8) -> u32;
+                            }
+                            unsafe { new(val) }
                         }
-                        _ => _rt::invalid_enum_discriminant(),
-                    };
-                    if layout15.size() != 0 {
-                        _rt::alloc::dealloc(result15.cast(), layout15);
                     }
-                    for (ptr, layout) in cleanup_list {
-                        if layout.size() != 0 {
-                            _rt::alloc::dealloc(ptr.cast(), layout);
+                    #[doc(hidden)]
+                    fn _resource_rep(handle: u32) -> *mut u8
+                    where
+                        Self: Sized,
+                    {
+                        #[cfg(not(target_arch = "wasm32"))]
+                        {
+                            let _ = handle;
+                            unreachable!();
+                        }
+                        #[cfg(target_arch = "wasm32")]
+                        {
+                            #[link(
+                                wasm_import_module = "[export]lojban:nesy/engine@0.1.0"
+                            )]
+                            unsafe extern "C" {
+                                #[link_name = "[resource-rep]knowledge-base"]
+                                fn rep(_: u32) -> *mut u8;
+                            }
+                            unsafe { rep(handle) }
                         }
                     }
-                    result24
+                    /// An empty knowledge base.
+                    fn new() -> Self;
+                    /// Assert Lojban text as facts. Sentences that do not parse are
+                    /// skipped and reported in the assertion's diagnostics, so the rest
+                    /// of a corpus still goes in; text that does not lex, in which no
+                    /// sentence parses, or that asks a xu, ma or mo question fails as
+                    /// a whole.
+                    fn assert_text(
+                        &self,
+                        input: _rt::String,
+                        policy: ConflictPolicy,
+                    ) -> Result<Assertion, AssertError>;
+                    /// Retract text asserted earlier, and what was derived from it.
+                    fn retract(&self, handle: FactHandle) -> Result<(), _rt::String>;
+                    fn quarantined(&self) -> _rt::Vec<QuarantinedFact>;
+                    /// Query whether Lojban text is entailed or refuted. Unlike
+                    /// assert-text, a query must parse completely.
+                    fn query_text(
+                        &self,
+                        input: _rt::String,
+                        assumption: WorldAssumption,
+                    ) -> Result<TruthValue, _rt::String>;
+                    /// Ask a xu, ma or mo question.
+                    fn ask_text(
+                        &self,
+                        input: _rt::String,
+                        assumption: WorldAssumption,
+                    ) -> Result<Answer, _rt::String>;
+                    /// Forget everything asserted and quarantined, and the discourse.
+                    fn reset(&self) -> ();
+                    /// A copy of the knowledge base, discourse included, that evolves
+                    /// independently of it.
+                    fn fork(&self) -> KnowledgeBase;
+                }
+                #[doc(hidden)]
+                macro_rules! __export_lojban_nesy_engine_0_1_0_cabi {
+                    ($ty:ident with_types_in $($path_to_types:tt)*) => {
+                        const _ : () = { #[unsafe (export_name =
+                        "lojban:nesy/engine@0.1.0#[constructor]knowledge-base")] unsafe
+                        extern "C" fn export_constructor_knowledge_base() -> i32 { unsafe
+                        { $($path_to_types)*::
+                        _export_constructor_knowledge_base_cabi::<<$ty as
+                        $($path_to_types)*:: Guest >::KnowledgeBase > () } } #[unsafe
+                        (export_name =
+                        "lojban:nesy/engine@0.1.0#[method]knowledge-base.assert-text")]
+                        unsafe extern "C" fn
+                        export_method_knowledge_base_assert_text(arg0 : * mut u8, arg1 :
+                        * mut u8, arg2 : usize, arg3 : i32,) -> * mut u8 { unsafe {
+                        $($path_to_types)*::
+                        _export_method_knowledge_base_assert_text_cabi::<<$ty as
+                        $($path_to_types)*:: Guest >::KnowledgeBase > (arg0, arg1, arg2,
+                        arg3) } } #[unsafe (export_name =
+                        "cabi_post_lojban:nesy/engine@0.1.0#[method]knowledge-base.assert-text")]
+                        unsafe extern "C" fn
+                        _post_return_method_knowledge_base_assert_text(arg0 : * mut u8,)
+                        { unsafe { $($path_to_types)*::
+                        __post_return_method_knowledge_base_assert_text::<<$ty as
+                        $($path_to_types)*:: Guest >::KnowledgeBase > (arg0) } } #[unsafe
+                        (export_name =
+                        "lojban:nesy/engine@0.1.0#[method]knowledge-base.retract")]
+                        unsafe extern "C" fn export_method_knowledge_base_retract(arg0 :
+                        * mut u8, arg1 : i64,) -> * mut u8 { unsafe {
+                        $($path_to_types)*::
+                        _export_method_knowledge_base_retract_cabi::<<$ty as
+                        $($path_to_types)*:: Guest >::KnowledgeBase > (arg0, arg1) } }
+                        #[unsafe (export_name =
+                        "cabi_post_lojban:nesy/engine@0.1.0#[method]knowledge-base.retract")]
+                        unsafe extern "C" fn
+                        _post_return_method_knowledge_base_retract(arg0 : * mut u8,) {
+                        unsafe { $($path_to_types)*::
+                        __post_return_method_knowledge_base_retract::<<$ty as
+                        $($path_to_types)*:: Guest >::KnowledgeBase > (arg0) } } #[unsafe
+                        (export_name =
+                        "lojban:nesy/engine@0.1.0#[method]knowledge-base.quarantined")]
+                        unsafe extern "C" fn
+                        export_method_knowledge_base_quarantined(arg0 : * mut u8,) -> *
+                        mut u8 { unsafe { $($path_to_types)*::
+                        _export_method_knowledge_base_quarantined_cabi::<<$ty as
+                        $($path_to_types)*:: Guest >::KnowledgeBase > (arg0) } } #[unsafe
+                        (export_name =
+                        "cabi_post_lojban:nesy/engine@0.1.0#[method]knowledge-base.quarantined")]
+                        unsafe extern "C" fn
+                        _post_return_method_knowledge_base_quarantined(arg0 : * mut u8,)
+                        { unsafe { $($path_to_types)*::
+                        __post_return_method_knowledge_base_quarantined::<<$ty as
+                        $($path_to_types)*:: Guest >::KnowledgeBase > (arg0) } } #[unsafe
+                        (export_name =
+                        "lojban:nesy/engine@0.1.0#[method]knowledge-base.query-text")]
+                        unsafe extern "C" fn export_method_knowledge_base_query_text(arg0
+                        : * mut u8, arg1 : * mut u8, arg2 : usize, arg3 : i32,) -> * mut
+                        u8 { unsafe { $($path_to_types)*::
+                        _export_method_knowledge_base_query_text_cabi::<<$ty as
+                        $($path_to_types)*:: Guest >::KnowledgeBase > (arg0, arg1, arg2,
+                        arg3) } } #[unsafe (export_name =
+                        "cabi_post_lojban:nesy/engine@0.1.0#[method]knowledge-base.query-text")]
+                        unsafe extern "C" fn
+                        _post_return_method_knowledge_base_query_text(arg0 : * mut u8,) {
+                        unsafe { $($path_to_types)*::
+                        __post_return_method_knowledge_base_query_text::<<$ty as
+                        $($path_to_types)*:: Guest >::KnowledgeBase > (arg0) } } #[unsafe
+                        (export_name =
+                        "lojban:nesy/engine@0.1.0#[method]knowledge-base.ask-text")]
+                        unsafe extern "C" fn export_method_knowledge_base_ask_text(arg0 :
+                        * mut u8, arg1 : * mut u8, arg2 : usize, arg3 : i32,) -> * mut u8
+                        { unsafe { $($path_to_types)*::
+                        _export_method_knowledge_base_ask_text_cabi::<<$ty as
+                        $($path_to_types)*:: Guest >::KnowledgeBase > (arg0, arg1, arg2,
+                        arg3) } } #[unsafe (export_name =
+                        "cabi_post_lojban:nesy/engine@0.1.0#[method]knowledge-base.ask-text")]
+                        unsafe extern "C" fn
+                        _post_return_method_knowledge_base_ask_text(arg0 : * mut u8,) {
+                        unsafe { $($path_to_types)*::
+                        __post_return_method_knowledge_base_ask_text::<<$ty as
+                        $($path_to_types)*:: Guest >::KnowledgeBase > (arg0) } } #[unsafe
+                        (export_name =
+                        "lojban:nesy/engine@0.1.0#[method]knowledge-base.reset")] unsafe
+                        extern "C" fn export_method_knowledge_base_reset(arg0 : * mut
+                        u8,) { unsafe { $($path_to_types)*::
+                        _export_method_knowledge_base_reset_cabi::<<$ty as
+                        $($path_to_types)*:: Guest >::KnowledgeBase > (arg0) } } #[unsafe
+                        (export_name =
+                        "lojban:nesy/engine@0.1.0#[method]knowledge-base.fork")] unsafe
+                        extern "C" fn export_method_knowledge_base_fork(arg0 : * mut u8,)
+                        -> i32 { unsafe { $($path_to_types)*::
+                        _export_method_knowledge_base_fork_cabi::<<$ty as
+                        $($path_to_types)*:: Guest >::KnowledgeBase > (arg0) } } const _
+                        : () = { #[doc(hidden)] #[unsafe (export_name =
+                        "lojban:nesy/engine@0.1.0#[dtor]knowledge-base")]
+                        #[allow(non_snake_case)] unsafe extern "C" fn dtor(rep : * mut
+                        u8) { unsafe { $($path_to_types)*:: KnowledgeBase::dtor::< <$ty
+                        as $($path_to_types)*:: Guest >::KnowledgeBase > (rep) } } }; };
+                    };
                 }
+                #[doc(hidden)]
+                pub(crate) use __export_lojban_nesy_engine_0_1_0_cabi;
+                #[repr(align(8))]
+                struct _RetArea(
+                    [::core::mem::MaybeUninit<
+                        u8,
+                    >; 24 + 2 * ::core::mem::size_of::<*const u8>()],
+                );
+                static mut _RET_AREA: _RetArea = _RetArea(
+                    [::core::mem::MaybeUninit::uninit(); 24
+                        + 2 * ::core::mem::size_of::<*const u8>()],
+                );
             }
         }
     }
@@ -2199,6 +6537,13 @@ mod _rt {
         let layout = alloc::Layout::from_size_align_unchecked(size, align);
         alloc::dealloc(ptr, layout);
     }
+    pub unsafe fn invalid_enum_discriminant<T>() -> T {
+        if cfg!(debug_assertions) {
+            panic!("invalid enum discriminant")
+        } else {
+            unsafe { core::hint::unreachable_unchecked() }
+        }
+    }
     pub unsafe fn bool_lift(val: u8) -> bool {
         if cfg!(debug_assertions) {
             match val {
@@ -2210,11 +6555,78 @@ mod _rt {
             val != 0
         }
     }
-    pub unsafe fn invalid_enum_discriminant<T>() -> T {
-        if cfg!(debug_assertions) {
-            panic!("invalid enum discriminant")
-        } else {
-            unsafe { core::hint::unreachable_unchecked() }
+    use core::fmt;
+    use core::marker;
+    use core::sync::atomic::{AtomicU32, Ordering::Relaxed};
+    /// A type which represents a component model resource, either imported or
+    /// exported into this component.
+    ///
+    /// This is a low-level wrapper which handles the lifetime of the resource
+    /// (namely this has a destructor). The `T` provided defines the component model
+    /// intrinsics that this wrapper uses.
+    ///
+    /// One of the chief purposes of this type is to provide `Deref` implementations
+    /// to access the underlying data when it is owned.
+    ///
+    /// This type is primarily used in generated code for exported and imported
+    /// resources.
+    #[repr(transparent)]
+    pub struct Resource<T: WasmResource> {
+        handle: AtomicU32,
+        _marker: marker::PhantomData<T>,
+    }
+    /// A trait which all wasm resources implement, namely providing the ability to
+    /// drop a resource.
+    ///
+    /// This generally is implemented by generated code, not user-facing code.
+    #[allow(clippy::missing_safety_doc)]
+    pub unsafe trait WasmResource {
+        /// Invokes the `[resource-drop]...` intrinsic.
+        unsafe fn drop(handle: u32);
+    }
+    impl<T: WasmResource> Resource<T> {
+        #[doc(hidden)]
+        pub unsafe fn from_handle(handle: u32) -> Self {
+            debug_assert!(handle != u32::MAX);
+            Self {
+                handle: AtomicU32::new(handle),
+                _marker: marker::PhantomData,
+            }
+        }
+        /// Takes ownership of the handle owned by `resource`.
+        ///
+        /// Note that this ideally would be `into_handle` taking `Resource<T>` by
+        /// ownership. The code generator does not enable that in all situations,
+        /// unfortunately, so this is provided instead.
+        ///
+        /// Also note that `take_handle` is in theory only ever called on values
+        /// owned by a generated function. For example a generated function might
+        /// take `Resource<T>` as an argument but then call `take_handle` on a
+        /// reference to that argument. In that sense the dynamic nature of
+        /// `take_handle` should only be exposed internally to generated code, not
+        /// to user code.
+        #[doc(hidden)]
+        pub fn take_handle(resource: &Resource<T>) -> u32 {
+            resource.handle.swap(u32::MAX, Relaxed)
+        }
+        #[doc(hidden)]
+        pub fn handle(resource: &Resource<T>) -> u32 {
+            resource.handle.load(Relaxed)
+        }
+    }
+    impl<T: WasmResource> fmt::Debug for Resource<T> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_struct("Resource").field("handle", &self.handle).finish()
+        }
+    }
+    impl<T: WasmResource> Drop for Resource<T> {
+        fn drop(&mut self) {
+            unsafe {
+                match self.handle.load(Relaxed) {
+                    u32::MAX => {}
+                    other => T::drop(other),
+                }
+            }
         }
     }
     pub use alloc_crate::alloc;
@@ -2277,10 +6689,34 @@ mod _rt {
             self as i32
         }
     }
+    pub fn as_i64<T: AsI64>(t: T) -> i64 {
+        t.as_i64()
+    }
+    pub trait AsI64 {
+        fn as_i64(self) -> i64;
+    }
+    impl<'a, T: Copy + AsI64> AsI64 for &'a T {
+        fn as_i64(self) -> i64 {
+            (*self).as_i64()
+        }
+    }
+    impl AsI64 for i64 {
+        #[inline]
+        fn as_i64(self) -> i64 {
+            self as i64
+        }
+    }
+    impl AsI64 for u64 {
+        #[inline]
+        fn as_i64(self) -> i64 {
+            self as i64
+        }
+    }
     #[cfg(target_arch = "wasm32")]
     pub fn run_ctors_once() {
         wit_bindgen_rt::run_ctors_once();
     }
+    pub use alloc_crate::boxed::Box;
     extern crate alloc as alloc_crate;
 }
 /// Generates `#[unsafe(no_mangle)]` functions to export the specified type as
@@ -2307,7 +6743,9 @@ macro_rules! __export_engine_pipeline_impl {
     };
     ($ty:ident with_types_in $($path_to_types_root:tt)*) => {
         $($path_to_types_root)*:: __export_world_engine_pipeline_cabi!($ty with_types_in
-        $($path_to_types_root)*);
+        $($path_to_types_root)*); $($path_to_types_root)*::
+        exports::lojban::nesy::engine::__export_lojban_nesy_engine_0_1_0_cabi!($ty
+        with_types_in $($path_to_types_root)*:: exports::lojban::nesy::engine);
     };
 }
 #[doc(inline)]
@@ -2318,42 +6756,103 @@ pub(crate) use __export_engine_pipeline_impl as export;
 )]
 #[doc(hidden)]
 #[allow(clippy::octal_escapes)]
-pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 1517] = *b"\
-\0asm\x0d\0\x01\0\0\x19\x16wit-component-encoding\x04\0\x07\xe7\x0a\x01A\x02\x01\
-A\x13\x01B0\x01y\x04\0\x09selbri-id\x03\0\0\x01y\x04\0\x08sumti-id\x03\0\x02\x01\
+pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 4318] = *b"\
+\0asm\x0d\0\x01\0\0\x19\x16wit-component-encoding\x04\0\x07\xd8\x20\x01A\x02\x01\
+A\x17\x01B\\\x01y\x04\0\x09selbri-id\x03\0\0\x01y\x04\0\x08sumti-id\x03\0\x02\x01\
 m\x05\x02fa\x02fe\x02fi\x02fo\x02fu\x04\0\x09place-tag\x03\0\x04\x01m\x04\x02se\x02\
 te\x02ve\x02xe\x04\0\x0aconversion\x03\0\x06\x01m\x04\x02je\x02ja\x02jo\x02ju\x04\
-\0\x0aconnective\x03\0\x08\x01m\x05\x02lo\x02le\x02la\x05ro-lo\x05ro-le\x04\0\x05\
-gadri\x03\0\x0a\x01m\x02\x03poi\x03noi\x04\0\x0frel-clause-kind\x03\0\x0c\x01r\x02\
-\x04kind\x0d\x0dbody-sentencey\x04\0\x0arel-clause\x03\0\x0e\x01o\x02\x0b\x01\x01\
-o\x02\x05\x03\x01o\x02\x03\x0f\x01q\x07\x09pro-sumti\x01s\0\x0bdescription\x01\x10\
-\0\x04name\x01s\0\x0equoted-literal\x01s\0\x0bunspecified\0\0\x06tagged\x01\x11\0\
-\x0arestricted\x01\x12\0\x04\0\x05sumti\x03\0\x13\x01ps\x01o\x02\x01\x01\x01o\x02\
-\x07\x01\x01p\x03\x01o\x02\x01\x18\x01o\x03\x01\x09\x01\x01q\x08\x04root\x01s\0\x08\
-compound\x01\x15\0\x05tanru\x01\x16\0\x09converted\x01\x17\0\x07negated\x01\x01\0\
-\x07grouped\x01\x01\0\x09with-args\x01\x19\0\x09connected\x01\x1a\0\x04\0\x06sel\
-bri\x03\0\x1b\x01r\x04\x08relation\x01\x0ahead-terms\x18\x0atail-terms\x18\x07ne\
-gated\x7f\x04\0\x05bridi\x03\0\x1d\x01p\x1c\x01p\x14\x01p\x1e\x01py\x01r\x04\x07\
-selbris\x1f\x06sumtis\x20\x09sentences!\x05roots\"\x04\0\x0aast-buffer\x03\0#\x01\
-q\x04\x08variable\x01s\0\x08constant\x01s\0\x0bdescription\x01s\0\x0bunspecified\
-\0\0\x04\0\x0clogical-term\x03\0%\x01p&\x01o\x02s'\x01o\x02yy\x01o\x02sy\x01q\x06\
-\x09predicate\x01(\0\x08and-node\x01)\0\x07or-node\x01)\0\x08not-node\x01y\0\x0b\
-exists-node\x01*\0\x0cfor-all-node\x01*\0\x04\0\x0alogic-node\x03\0+\x01p,\x01r\x02\
-\x05nodes-\x05roots\"\x04\0\x0clogic-buffer\x03\0.\x03\0\x1blojban:nesy/ast-type\
-s@0.1.0\x05\0\x02\x03\0\0\x0aast-buffer\x01B\x05\x02\x03\x02\x01\x01\x04\0\x0aas\
-t-buffer\x03\0\0\x01j\x01\x01\x01s\x01@\x01\x05inputs\0\x02\x04\0\x0aparse-text\x01\
-\x03\x03\0\x18lojban:nesy/parser@0.1.0\x05\x02\x02\x03\0\0\x0clogic-buffer\x01B\x07\
-\x02\x03\x02\x01\x01\x04\0\x0aast-buffer\x03\0\0\x02\x03\x02\x01\x03\x04\0\x0clo\
-gic-buffer\x03\0\x02\x01j\x01\x03\x01s\x01@\x01\x03ast\x01\0\x04\x04\0\x0ecompil\
-e-buffer\x01\x05\x03\0\x1blojban:nesy/semantics@0.1.0\x05\x04\x01B\x08\x02\x03\x02\
-\x01\x03\x04\0\x0clogic-buffer\x03\0\0\x01j\0\x01s\x01@\x01\x05logic\x01\0\x02\x04\
-\0\x0bassert-fact\x01\x03\x01j\x01\x7f\x01s\x01@\x01\x05logic\x01\0\x04\x04\0\x10\
-query-entailment\x01\x05\x03\0\x1blojban:nesy/reasoning@0.1.0\x05\x05\x01j\x01y\x01\
-s\x01@\x01\x05inputs\0\x06\x04\0\x0bassert-text\x01\x07\x01j\x01\x7f\x01s\x01@\x01\
-\x05inputs\0\x08\x04\0\x0aquery-text\x01\x09\x01j\x01s\x01s\x01@\x01\x05inputs\0\
-\x0a\x04\0\x0dcompile-debug\x01\x0b\x04\0!lojban:nesy/engine-pipeline@0.1.0\x04\0\
-\x0b\x15\x01\0\x0fengine-pipeline\x03\0\0\0G\x09producers\x01\x0cprocessed-by\x02\
-\x0dwit-component\x070.227.1\x10wit-bindgen-rust\x060.41.0";
+\0\x0aconnective\x03\0\x08\x01m\x0b\x02lo\x02le\x02la\x03loi\x03lei\x03lai\x04lo\
+hi\x04lehi\x04lahi\x04lohe\x04lehe\x04\0\x05gadri\x03\0\x0a\x01m\x08\x03poi\x03n\
+oi\x03voi\x03goi\x02pe\x02ne\x02po\x04pohe\x04\0\x0frel-clause-kind\x03\0\x0c\x01\
+m\x05\x02nu\x04duhu\x02ka\x02ni\x04siho\x04\0\x10abstraction-kind\x03\0\x0e\x01m\
+\x03\x02pu\x02ca\x02ba\x04\0\x0etime-direction\x03\0\x10\x01m\x03\x02zi\x02za\x02\
+zu\x04\0\x0dtime-distance\x03\0\x12\x01m\x03\x04coha\x04cohu\x04caho\x04\0\x06as\
+pect\x03\0\x14\x01m\x03\x03all\x08at-least\x07exactly\x04\0\x0fquantifier-kind\x03\
+\0\x16\x01r\x02\x05starty\x03endy\x04\0\x04span\x03\0\x18\x01k\x11\x01k\x13\x01k\
+\x15\x01r\x03\x09direction\x1a\x08distance\x1b\x06aspect\x1c\x04\0\x05tense\x03\0\
+\x1d\x01r\x02\x04kind\x17\x05county\x04\0\x0aquantifier\x03\0\x1f\x01q\x02\x03ba\
+i\x01s\0\x04fiho\x01\x01\0\x04\0\x09modal-tag\x03\0!\x01r\x04\x0aconnective\x09\x09\
+converted\x7f\x0bnegate-left\x7f\x0cnegate-right\x7f\x04\0\x12logical-connective\
+\x03\0#\x01q\x02\x08sentence\x01y\0\x05sumti\x01\x03\0\x04\0\x0frel-clause-body\x03\
+\0%\x01r\x02\x04kind\x0d\x04body&\x04\0\x0arel-clause\x03\0'\x01k\x20\x01o\x03\x0b\
+)\x01\x01o\x02\x20\x03\x01o\x02\x05\x03\x01o\x02\x1e\x03\x01o\x02\"\x03\x01o\x02\
+\x03(\x01o\x03\x03$\x03\x01q\x0c\x09pro-sumti\x01s\0\x0brelativized\x01y\0\x0bde\
+scription\x01*\0\x0aquantified\x01+\0\x04name\x01s\0\x0equoted-literal\x01s\0\x0b\
+unspecified\0\0\x06tagged\x01,\0\x0ctense-tagged\x01-\0\x0cmodal-tagged\x01.\0\x0a\
+restricted\x01/\0\x09connected\x010\0\x04\0\x05sumti\x03\01\x01ps\x01o\x02\x01\x01\
+\x01o\x02\x07\x01\x01p\x03\x01o\x02\x016\x01o\x03\x01\x09\x01\x01o\x02\x0fy\x01q\
+\x09\x04root\x01s\0\x08compound\x013\0\x05tanru\x014\0\x09converted\x015\0\x07ne\
+gated\x01\x01\0\x07grouped\x01\x01\0\x09with-args\x017\0\x09connected\x018\0\x0b\
+abstraction\x019\0\x04\0\x06selbri\x03\0:\x01k\x1e\x01r\x07\x08relation\x01\x0ah\
+ead-terms6\x0atail-terms6\x07negated\x7f\x05tense<\x08question\x7f\x04span\x19\x04\
+\0\x05bridi\x03\0=\x01o\x02)\x03\x01q\x02\x08variable\x01?\0\x04naku\0\0\x04\0\x0b\
+prenex-term\x03\0@\x01o\x03$yy\x01p\xc1\0\x01o\x02\xc3\0y\x01q\x03\x06simple\x01\
+y\0\x09connected\x01\xc2\0\0\x06prenex\x01\xc4\0\0\x04\0\x09statement\x03\0E\x01\
+p;\x01p2\x01p\x19\x01p>\x01p\xc6\0\x01py\x01r\x07\x07selbris\xc7\0\x06sumtis\xc8\
+\0\x0cselbri-spans\xc9\0\x0bsumti-spans\xc9\0\x09sentences\xca\0\x0astatements\xcb\
+\0\x05roots\xcc\0\x04\0\x0aast-buffer\x03\0M\x01q\x05\x08variable\x01s\0\x08cons\
+tant\x01s\0\x0bdescription\x01s\0\x0bunspecified\0\0\x0bproposition\x01y\0\x04\0\
+\x0clogical-term\x03\0O\x01p\xd0\0\x01o\x02s\xd1\0\x01o\x02yy\x01o\x02sy\x01o\x02\
+\xd0\0y\x01o\x03syy\x01q\x09\x09predicate\x01\xd2\0\0\x08and-node\x01\xd3\0\0\x07\
+or-node\x01\xd3\0\0\x0cimplies-node\x01\xd3\0\0\x08not-node\x01y\0\x0bexists-nod\
+e\x01\xd4\0\0\x0cfor-all-node\x01\xd4\0\0\x07at-node\x01\xd5\0\0\x0cgeneric-node\
+\x01\xd6\0\0\x04\0\x0alogic-node\x03\0W\x01p\xd8\0\x01r\x02\x05nodes\xd9\0\x05ro\
+ots\xcc\0\x04\0\x0clogic-buffer\x03\0Z\x03\0\x1blojban:nesy/ast-types@0.1.0\x05\0\
+\x02\x03\0\0\x0aast-buffer\x02\x03\0\0\x04span\x01B\x0d\x02\x03\x02\x01\x01\x04\0\
+\x0aast-buffer\x03\0\0\x02\x03\x02\x01\x02\x04\0\x04span\x03\0\x02\x01m\x02\x06s\
+trict\x07lenient\x04\0\x08lex-mode\x03\0\x04\x01r\x02\x07messages\x04span\x03\x04\
+\0\x0adiagnostic\x03\0\x06\x01p\x07\x01r\x02\x03ast\x01\x0bdiagnostics\x08\x04\0\
+\x0cparse-result\x03\0\x09\x01@\x02\x05inputs\x04mode\x05\0\x0a\x04\0\x0aparse-t\
+ext\x01\x0b\x03\0\x18lojban:nesy/parser@0.1.0\x05\x03\x02\x03\0\0\x0clogic-buffe\
+r\x01B\x10\x02\x03\x02\x01\x01\x04\0\x0aast-buffer\x03\0\0\x02\x03\x02\x01\x04\x04\
+\0\x0clogic-buffer\x03\0\x02\x04\0\x08compiler\x03\x01\x01i\x04\x01@\0\0\x05\x04\
+\0\x15[constructor]compiler\x01\x06\x01h\x04\x01j\x01\x03\x01s\x01@\x02\x04self\x07\
+\x03ast\x01\0\x08\x04\0\x1f[method]compiler.compile-buffer\x01\x09\x01@\x01\x04s\
+elf\x07\x01\0\x04\0\x16[method]compiler.reset\x01\x0a\x01@\x01\x04self\x07\0\x05\
+\x04\0\x15[method]compiler.fork\x01\x0b\x03\0\x1blojban:nesy/semantics@0.1.0\x05\
+\x05\x01B1\x02\x03\x02\x01\x04\x04\0\x0clogic-buffer\x03\0\0\x01q\x02\x06entity\x01\
+s\0\x08relation\x01s\0\x04\0\x0banswer-term\x03\0\x02\x01r\x02\x08variables\x05v\
+alue\x03\x04\0\x07binding\x03\0\x04\x01p\x05\x04\0\x0csubstitution\x03\0\x06\x01\
+m\x03\x08entailed\x07refuted\x07unknown\x04\0\x0btruth-value\x03\0\x08\x01m\x02\x06\
+closed\x04open\x04\0\x10world-assumption\x03\0\x0a\x01ps\x01r\x02\x07formulas\x07\
+sources\x0c\x04\0\x08conflict\x03\0\x0d\x01m\x02\x06reject\x0aquarantine\x04\0\x0f\
+conflict-policy\x03\0\x0f\x01p\x0e\x01q\x02\x08conflict\x01\x11\0\x06failed\x01s\
+\0\x04\0\x0cassert-error\x03\0\x12\x01w\x04\0\x0bfact-handle\x03\0\x14\x01r\x02\x07\
+sources\x0c\x09conflicts\x11\x04\0\x10quarantined-fact\x03\0\x16\x04\0\x0eknowle\
+dge-base\x03\x01\x01i\x18\x01@\0\0\x19\x04\0\x1b[constructor]knowledge-base\x01\x1a\
+\x01h\x18\x01j\x01\x15\x01\x13\x01@\x04\x04self\x1b\x05logic\x01\x07sources\x0c\x06\
+policy\x10\0\x1c\x04\0\"[method]knowledge-base.assert-fact\x01\x1d\x01j\0\x01s\x01\
+@\x02\x04self\x1b\x06handle\x15\0\x1e\x04\0#[method]knowledge-base.retract-fact\x01\
+\x1f\x01p\x17\x01@\x01\x04self\x1b\0\x20\x04\0\"[method]knowledge-base.quarantin\
+ed\x01!\x01j\x01\x09\x01s\x01@\x03\x04self\x1b\x05logic\x01\x0aassumption\x0b\0\"\
+\x04\0'[method]knowledge-base.query-entailment\x01#\x01p\x07\x01j\x01$\x01s\x01@\
+\x04\x04self\x1b\x05logic\x01\x09variables\x0c\x0aassumption\x0b\0%\x04\0%[metho\
+d]knowledge-base.query-bindings\x01&\x01@\x01\x04self\x1b\x01\0\x04\0\x1c[method\
+]knowledge-base.reset\x01'\x01@\x01\x04self\x1b\0\x19\x04\0\x1b[method]knowledge\
+-base.fork\x01(\x03\0\x1blojban:nesy/reasoning@0.1.0\x05\x06\x01j\x01s\x01s\x01@\
+\x01\x05inputs\0\x07\x04\0\x0dcompile-debug\x01\x08\x02\x03\0\x03\x0cassert-erro\
+r\x02\x03\0\x03\x0fconflict-policy\x02\x03\0\x03\x0bfact-handle\x02\x03\0\x03\x10\
+quarantined-fact\x02\x03\0\x03\x0csubstitution\x02\x03\0\x03\x0btruth-value\x02\x03\
+\0\x03\x10world-assumption\x01B,\x02\x03\x02\x01\x09\x04\0\x0cassert-error\x03\0\
+\0\x02\x03\x02\x01\x0a\x04\0\x0fconflict-policy\x03\0\x02\x02\x03\x02\x01\x0b\x04\
+\0\x0bfact-handle\x03\0\x04\x02\x03\x02\x01\x0c\x04\0\x10quarantined-fact\x03\0\x06\
+\x02\x03\x02\x01\x0d\x04\0\x0csubstitution\x03\0\x08\x02\x03\x02\x01\x0e\x04\0\x0b\
+truth-value\x03\0\x0a\x02\x03\x02\x01\x0f\x04\0\x10world-assumption\x03\0\x0c\x01\
+p\x09\x01q\x02\x05truth\x01\x0b\0\x08bindings\x01\x0e\0\x04\0\x06answer\x03\0\x0f\
+\x01ps\x01r\x03\x06handle\x05\x05rootsy\x0bdiagnostics\x11\x04\0\x09assertion\x03\
+\0\x12\x04\0\x0eknowledge-base\x03\x01\x01i\x14\x01@\0\0\x15\x04\0\x1b[construct\
+or]knowledge-base\x01\x16\x01h\x14\x01j\x01\x13\x01\x01\x01@\x03\x04self\x17\x05\
+inputs\x06policy\x03\0\x18\x04\0\"[method]knowledge-base.assert-text\x01\x19\x01\
+j\0\x01s\x01@\x02\x04self\x17\x06handle\x05\0\x1a\x04\0\x1e[method]knowledge-bas\
+e.retract\x01\x1b\x01p\x07\x01@\x01\x04self\x17\0\x1c\x04\0\"[method]knowledge-b\
+ase.quarantined\x01\x1d\x01j\x01\x0b\x01s\x01@\x03\x04self\x17\x05inputs\x0aassu\
+mption\x0d\0\x1e\x04\0![method]knowledge-base.query-text\x01\x1f\x01j\x01\x10\x01\
+s\x01@\x03\x04self\x17\x05inputs\x0aassumption\x0d\0\x20\x04\0\x1f[method]knowle\
+dge-base.ask-text\x01!\x01@\x01\x04self\x17\x01\0\x04\0\x1c[method]knowledge-bas\
+e.reset\x01\"\x01@\x01\x04self\x17\0\x15\x04\0\x1b[method]knowledge-base.fork\x01\
+#\x04\0\x18lojban:nesy/engine@0.1.0\x05\x10\x04\0!lojban:nesy/engine-pipeline@0.\
+1.0\x04\0\x0b\x15\x01\0\x0fengine-pipeline\x03\0\0\0G\x09producers\x01\x0cproces\
+sed-by\x02\x0dwit-component\x070.227.1\x10wit-bindgen-rust\x060.41.0";
 #[inline(never)]
 #[doc(hidden)]
 pub fn __link_custom_section_describing_imports() {
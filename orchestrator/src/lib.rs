@@ -2,16 +2,169 @@
 mod bindings;
 
 use bindings::Guest;
-use bindings::lojban::nesy::ast_types::{LogicBuffer, LogicNode, LogicalTerm};
+use bindings::exports::lojban::nesy::engine::{
+    self, Answer, AssertError, Assertion, ConflictPolicy, FactHandle, GuestKnowledgeBase,
+    QuarantinedFact, TruthValue, WorldAssumption,
+};
+use bindings::lojban::nesy::ast_types::{
+    AstBuffer, LogicBuffer, LogicNode, LogicalTerm, Selbri, Statement, Sumti,
+};
 use bindings::lojban::nesy::{parser, reasoning, semantics};
 
 struct EnginePipeline;
 
 // ─── Shared pipeline: text → AST → LogicBuffer ───
 
-fn compile_pipeline(text: &str) -> Result<LogicBuffer, String> {
-    let ast = parser::parse_text(text).map_err(|e| format!("Parse: {}", e))?;
-    semantics::compile_buffer(&ast).map_err(|e| format!("Semantics: {}", e))
+fn compile_pipeline(compiler: &semantics::Compiler, text: &str) -> Result<LogicBuffer, String> {
+    let ast = parse_pipeline(text)?;
+    compiler
+        .compile_buffer(&ast)
+        .map_err(|e| format!("Semantics: {}", e))
+}
+
+/// Parse text that must parse completely (queries, debug output).
+fn parse_pipeline(text: &str) -> Result<AstBuffer, String> {
+    let (ast, diagnostics) = parse_recovering(text)?;
+    if !diagnostics.is_empty() {
+        return Err(format!("Parse: {}", diagnostics.join("\n")));
+    }
+    Ok(ast)
+}
+
+/// Parse text for assertion: the statements that parsed, with a message
+/// for each region the parser skipped. A lex error still fails the whole
+/// text (strict lexing parses nothing), as does text in which no
+/// statement parsed.
+fn parse_recovering(text: &str) -> Result<(AstBuffer, Vec<String>), String> {
+    let parsed = parser::parse_text(text, parser::LexMode::Strict);
+    let messages: Vec<String> = parsed.diagnostics.into_iter().map(|d| d.message).collect();
+    if !messages.is_empty() && parsed.ast.roots.is_empty() {
+        return Err(format!("Parse: {}", messages.join("\n")));
+    }
+    Ok((parsed.ast, messages))
+}
+
+/// The source text of each root statement, for conflict reports.
+fn root_sources(text: &str, ast: &AstBuffer) -> Vec<String> {
+    ast.roots
+        .iter()
+        .map(|&root| {
+            statement_span(ast, root)
+                .and_then(|(start, end)| text.get(start as usize..end as usize))
+                .unwrap_or(text)
+                .trim()
+                .to_string()
+        })
+        .collect()
+}
+
+/// The byte range a statement's bridi cover.
+fn statement_span(ast: &AstBuffer, statement_id: u32) -> Option<(u32, u32)> {
+    match &ast.statements[statement_id as usize] {
+        Statement::Simple(bridi) => {
+            let span = ast.sentences.get(*bridi as usize)?.span;
+            Some((span.start, span.end))
+        }
+        Statement::Connected((_, left, right)) => {
+            let (left_start, left_end) = statement_span(ast, *left)?;
+            let (right_start, right_end) = statement_span(ast, *right)?;
+            Some((left_start.min(right_start), left_end.max(right_end)))
+        }
+        Statement::Prenex((_, body)) => statement_span(ast, *body),
+    }
+}
+
+// ─── Questions ───
+
+/// Compile a question. A text with ma or mo asks for its blanks, which
+/// are returned with the buffer; anything else (xu or a plain bridi)
+/// asks whether it is true, and has none.
+fn compile_question(
+    compiler: &semantics::Compiler,
+    text: &str,
+) -> Result<(LogicBuffer, Vec<String>), String> {
+    let ast = parse_pipeline(text)?;
+    let buf = compiler
+        .compile_buffer(&ast)
+        .map_err(|e| format!("Semantics: {}", e))?;
+    let blanks = if asks_blanks(&ast) {
+        question_variables(&buf)
+    } else {
+        Vec::new()
+    };
+    Ok((buf, blanks))
+}
+
+/// Whether the text has a ma or mo in it.
+fn asks_blanks(ast: &AstBuffer) -> bool {
+    ast.sumtis
+        .iter()
+        .any(|s| matches!(s, Sumti::ProSumti(p) if p == "ma"))
+        || ast
+            .selbris
+            .iter()
+            .any(|s| matches!(s, Selbri::Root(r) if r == "mo"))
+}
+
+/// Whether the text asks rather than claims: a xu, ma or mo question.
+fn is_question(ast: &AstBuffer) -> bool {
+    asks_blanks(ast) || ast.sentences.iter().any(|bridi| bridi.question)
+}
+
+/// The variables a compiled question leaves to be filled: the free ones
+/// (each ma) and the mo relation.
+fn question_variables(buffer: &LogicBuffer) -> Vec<String> {
+    let mut variables = Vec::new();
+    for &root in &buffer.roots {
+        collect_blanks(buffer, root, &mut Vec::new(), &mut variables);
+    }
+    variables
+}
+
+fn collect_blanks(
+    buffer: &LogicBuffer,
+    node_id: u32,
+    bound: &mut Vec<String>,
+    blanks: &mut Vec<String>,
+) {
+    match &buffer.nodes[node_id as usize] {
+        LogicNode::Predicate((rel, args)) => {
+            if rel == "mo" {
+                add_blank(rel, bound, blanks);
+            }
+            for arg in args {
+                match arg {
+                    LogicalTerm::Variable(v) => add_blank(v, bound, blanks),
+                    LogicalTerm::Proposition(inner) => {
+                        collect_blanks(buffer, *inner, bound, blanks)
+                    }
+                    _ => {}
+                }
+            }
+        }
+        LogicNode::AndNode((l, r)) | LogicNode::OrNode((l, r)) | LogicNode::ImpliesNode((l, r)) => {
+            collect_blanks(buffer, *l, bound, blanks);
+            collect_blanks(buffer, *r, bound, blanks);
+        }
+        LogicNode::NotNode(inner) => collect_blanks(buffer, *inner, bound, blanks),
+        LogicNode::AtNode((time, inner)) => {
+            if let LogicalTerm::Variable(v) = time {
+                add_blank(v, bound, blanks);
+            }
+            collect_blanks(buffer, *inner, bound, blanks);
+        }
+        LogicNode::ExistsNode((v, body)) | LogicNode::ForAllNode((v, body)) => {
+            bound.push(v.clone());
+            collect_blanks(buffer, *body, bound, blanks);
+            bound.pop();
+        }
+        LogicNode::GenericNode((v, restrictor, body)) => {
+            bound.push(v.clone());
+            collect_blanks(buffer, *restrictor, bound, blanks);
+            collect_blanks(buffer, *body, bound, blanks);
+            bound.pop();
+        }
+    }
 }
 
 fn debug_sexp(buffer: &LogicBuffer) -> String {
@@ -23,23 +176,115 @@ fn debug_sexp(buffer: &LogicBuffer) -> String {
         .join("\n")
 }
 
+fn add_blank(name: &String, bound: &[String], blanks: &mut Vec<String>) {
+    if !bound.contains(name) && !blanks.contains(name) {
+        blanks.push(name.clone());
+    }
+}
+
 // ─── WIT exports ───
 
 impl Guest for EnginePipeline {
-    fn assert_text(input: String) -> Result<u32, String> {
-        let buf = compile_pipeline(&input)?;
-        reasoning::assert_fact(&buf).map_err(|e| format!("Reasoning: {}", e))?;
-        Ok(buf.roots.len() as u32)
+    /// Compiled with a fresh discourse: no knowledge base's ri or ko'a.
+    fn compile_debug(input: String) -> Result<String, String> {
+        let buf = compile_pipeline(&semantics::Compiler::new(), &input)?;
+        Ok(debug_sexp(&buf))
     }
+}
+
+impl engine::Guest for EnginePipeline {
+    type KnowledgeBase = KnowledgeBase;
+}
+
+/// A text-level knowledge base: Lojban goes through the parser and its
+/// own semantic compiler, which keeps the discourse (ri, ko'a, go'i) of
+/// what was said to it, then into the reasoning knowledge base it wraps.
+struct KnowledgeBase {
+    compiler: semantics::Compiler,
+    facts: reasoning::KnowledgeBase,
+}
 
-    fn query_text(input: String) -> Result<bool, String> {
-        let buf = compile_pipeline(&input)?;
-        reasoning::query_entailment(&buf).map_err(|e| format!("Reasoning: {}", e))
+impl GuestKnowledgeBase for KnowledgeBase {
+    fn new() -> Self {
+        Self {
+            compiler: semantics::Compiler::new(),
+            facts: reasoning::KnowledgeBase::new(),
+        }
     }
 
-    fn compile_debug(input: String) -> Result<String, String> {
-        let buf = compile_pipeline(&input)?;
-        Ok(debug_sexp(&buf))
+    fn assert_text(&self, input: String, policy: ConflictPolicy) -> Result<Assertion, AssertError> {
+        let (ast, diagnostics) = parse_recovering(&input).map_err(AssertError::Failed)?;
+        if is_question(&ast) {
+            return Err(AssertError::Failed(
+                "a xu, ma or mo question cannot be asserted; ask it instead".to_string(),
+            ));
+        }
+        let buf = self
+            .compiler
+            .compile_buffer(&ast)
+            .map_err(|e| AssertError::Failed(format!("Semantics: {}", e)))?;
+        let sources = root_sources(&input, &ast);
+        let handle = self
+            .facts
+            .assert_fact(&buf, &sources, policy)
+            .map_err(|e| match e {
+                AssertError::Failed(e) => AssertError::Failed(format!("Reasoning: {}", e)),
+                conflict => conflict,
+            })?;
+        Ok(Assertion {
+            handle,
+            roots: buf.roots.len() as u32,
+            diagnostics,
+        })
+    }
+
+    fn retract(&self, handle: FactHandle) -> Result<(), String> {
+        self.facts
+            .retract_fact(handle)
+            .map_err(|e| format!("Reasoning: {}", e))
+    }
+
+    fn quarantined(&self) -> Vec<QuarantinedFact> {
+        self.facts.quarantined()
+    }
+
+    fn query_text(&self, input: String, assumption: WorldAssumption) -> Result<TruthValue, String> {
+        match self.ask_text(input, assumption)? {
+            Answer::Truth(value) => Ok(value),
+            // A ma/mo question holds when it has an answer
+            Answer::Bindings(answers) if !answers.is_empty() => Ok(TruthValue::Entailed),
+            Answer::Bindings(_) => match assumption {
+                WorldAssumption::Closed => Ok(TruthValue::Refuted),
+                WorldAssumption::Open => Ok(TruthValue::Unknown),
+            },
+        }
+    }
+
+    fn ask_text(&self, input: String, assumption: WorldAssumption) -> Result<Answer, String> {
+        let (buf, blanks) = compile_question(&self.compiler, &input)?;
+        if blanks.is_empty() {
+            return self
+                .facts
+                .query_entailment(&buf, assumption)
+                .map(Answer::Truth)
+                .map_err(|e| format!("Reasoning: {}", e));
+        }
+        self.facts
+            .query_bindings(&buf, &blanks, assumption)
+            .map(Answer::Bindings)
+            .map_err(|e| format!("Reasoning: {}", e))
+    }
+
+    fn reset(&self) {
+        self.compiler.reset();
+        self.facts.reset();
+    }
+
+    fn fork(&self) -> engine::KnowledgeBase {
+        engine::KnowledgeBase::new(Self {
+            compiler: self.compiler.fork(),
+            facts: self.facts.fork(),
+        })
     }
 }
 
@@ -50,13 +295,7 @@ fn reconstruct_sexp(buffer: &LogicBuffer, node_id: u32) -> String {
         LogicNode::Predicate((rel, args)) => {
             let mut args_str = String::from("(Nil)");
             for arg in args.iter().rev() {
-                let term_str = match arg {
-                    LogicalTerm::Variable(v) => format!("(Var \"{}\")", v),
-                    LogicalTerm::Constant(c) => format!("(Const \"{}\")", c),
-                    LogicalTerm::Description(d) => format!("(Desc \"{}\")", d),
-                    LogicalTerm::Unspecified => "(Zoe)".to_string(),
-                };
-                args_str = format!("(Cons {} {})", term_str, args_str);
+                args_str = format!("(Cons {} {})", reconstruct_term(buffer, arg), args_str);
             }
             format!("(Pred \"{}\" {})", rel, args_str)
         }
@@ -74,6 +313,13 @@ fn reconstruct_sexp(buffer: &LogicBuffer, node_id: u32) -> String {
                 reconstruct_sexp(buffer, *r)
             )
         }
+        LogicNode::ImpliesNode((l, r)) => {
+            format!(
+                "(Implies {} {})",
+                reconstruct_sexp(buffer, *l),
+                reconstruct_sexp(buffer, *r)
+            )
+        }
         LogicNode::NotNode(inner) => {
             format!("(Not {})", reconstruct_sexp(buffer, *inner))
         }
@@ -83,7 +329,33 @@ fn reconstruct_sexp(buffer: &LogicBuffer, node_id: u32) -> String {
         LogicNode::ForAllNode((v, body)) => {
             format!("(ForAll \"{}\" {})", v, reconstruct_sexp(buffer, *body))
         }
+        LogicNode::AtNode((time, body)) => {
+            format!(
+                "(At {} {})",
+                reconstruct_term(buffer, time),
+                reconstruct_sexp(buffer, *body)
+            )
+        }
+        LogicNode::GenericNode((v, restrictor, body)) => {
+            format!(
+                "(Generic \"{}\" {} {})",
+                v,
+                reconstruct_sexp(buffer, *restrictor),
+                reconstruct_sexp(buffer, *body)
+            )
+        }
+    }
+}
+
+fn reconstruct_term(buffer: &LogicBuffer, term: &LogicalTerm) -> String {
+    match term {
+        LogicalTerm::Variable(v) => format!("(Var \"{}\")", v),
+        LogicalTerm::Constant(c) => format!("(Const \"{}\")", c),
+        LogicalTerm::Description(d) => format!("(Desc \"{}\")", d),
+        LogicalTerm::Unspecified => "(Zoe)".to_string(),
+        LogicalTerm::Proposition(inner) => format!("(Prop {})", reconstruct_sexp(buffer, *inner)),
     }
 }
 
+#[cfg(target_arch = "wasm32")]
 bindings::export!(EnginePipeline with_types_in bindings);
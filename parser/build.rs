@@ -0,0 +1,83 @@
+use std::env;
+use std::fs;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+fn main() {
+    println!("cargo:rerun-if-changed=../jbovlaste-en.xml");
+
+    let xml_path = "../jbovlaste-en.xml";
+    let content = fs::read_to_string(xml_path).expect("Failed to read jbovlaste-en.xml");
+
+    // cmavo → selma'o, first entry wins for duplicated words
+    let mut entries: Vec<(String, String)> = Vec::new();
+
+    // Iterate through every <valsi> block, bypassing XML strictness
+    // (jbovlaste XML is riddled with formatting errors that crash strict parsers)
+    for block in content.split("<valsi ") {
+        let word = extract_attribute(block, "word=\"");
+        let typ = extract_attribute(block, "type=\"");
+
+        if word.is_empty() || !typ.starts_with("cmavo") {
+            continue;
+        }
+
+        let Some(selmaho) = extract_selmaho(block).map(base_selmaho) else {
+            continue;
+        };
+        if !selmaho.is_empty() && !entries.iter().any(|(w, _)| w == word) {
+            entries.push((word.to_string(), format!("{:?}", selmaho)));
+        }
+    }
+
+    let mut map = phf_codegen::Map::new();
+    for (cmavo, selmaho) in &entries {
+        map.entry(cmavo.as_str(), selmaho);
+    }
+
+    // Build-time diagnostics
+    println!(
+        "cargo:warning=jbovlaste: {} cmavo with selma'o",
+        entries.len()
+    );
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    let dest_path = Path::new(&out_dir).join("generated_selmaho.rs");
+    let mut file = BufWriter::new(File::create(&dest_path).unwrap());
+
+    writeln!(
+        &mut file,
+        "pub static CMAVO_SELMAHO: phf::Map<&'static str, &'static str> = \n{};",
+        map.build()
+    )
+    .unwrap();
+}
+
+/// Extracts an XML attribute value from a block using string scanning.
+fn extract_attribute<'a>(block: &'a str, attr: &str) -> &'a str {
+    if let Some(start) = block.find(attr) {
+        let after_attr = &block[start + attr.len()..];
+        if let Some(end) = after_attr.find('"') {
+            return &after_attr[..end];
+        }
+    }
+    ""
+}
+
+/// Extracts the <selmaho> text from a cmavo block.
+fn extract_selmaho(block: &str) -> Option<&str> {
+    let start = block.find("<selmaho>")?;
+    let rest = &block[start + 9..]; // 9 = "<selmaho>".len()
+    let end = rest.find("</selmaho>")?;
+    Some(rest[..end].trim())
+}
+
+/// Strips jbovlaste's subclass suffixes: "UI3a" → "UI", "KOhA3" → "KOhA",
+/// "BAI*" → "BAI". Selma'o names are uppercase with `h` for the apostrophe.
+fn base_selmaho(selmaho: &str) -> &str {
+    let end = selmaho
+        .find(|c: char| !(c.is_ascii_uppercase() || c == 'h'))
+        .unwrap_or(selmaho.len());
+    &selmaho[..end]
+}
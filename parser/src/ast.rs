@@ -9,7 +9,56 @@
 //   6. ke/ke'e tanru grouping
 //   7. je/ja/jo/ju connectives
 //   8. ku/vau/ku'o/kei terminators
-// Plus: se/te/ve/xe conversion, lo/le/la gadri, ro quantifier, extended pro-sumti
+// Plus: se/te/ve/xe conversion, lo/le/la gadri, ro quantifier, extended pro-sumti,
+//       nu/du'u/ka/ni/si'o abstractions, PU/ZI/ZAhO tense, BAI/fi'o modal tags,
+//       GA forethought connectives (ge/ga/go/gu/ganai ... gi), .e/.a/.o/.u sumti connectives,
+//       .ije/.ija/.ijo/.iju sentence connectives with bo/ke grouping,
+//       zo'u prenexes (ro/su'o da, naku), quantified sumti (re lo gerku, lo ci gerku)
+
+// ─── Source spans ────────────────────────────────────────────────
+
+/// Byte range in the source text, end-exclusive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Span {
+    pub fn new(start: usize, end: usize) -> Self {
+        Self { start, end }
+    }
+
+    /// The smallest span covering both `self` and `other`.
+    pub fn to(self, other: Span) -> Span {
+        Span::new(self.start.min(other.start), self.end.max(other.end))
+    }
+
+    /// "line L, column C: message" followed by the source line and a
+    /// caret under the span. Columns count characters, not bytes.
+    pub fn render(self, source: &str, message: &str) -> String {
+        let start = self.start.min(source.len());
+        let line_start = source[..start].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = source[start..]
+            .find('\n')
+            .map_or(source.len(), |i| start + i);
+        let line = source[..start].matches('\n').count() + 1;
+        let column = source[line_start..start].chars().count() + 1;
+        let width = source[start..self.end.clamp(start, line_end)]
+            .chars()
+            .count()
+            .max(1);
+        format!(
+            "line {}, column {}: {}\n  {}\n  {}{}",
+            line,
+            column,
+            message,
+            &source[line_start..line_end],
+            " ".repeat(column - 1),
+            "^".repeat(width)
+        )
+    }
+}
 
 // ─── Enums for grammatical markers ───────────────────────────────
 
@@ -50,7 +99,18 @@ pub enum Connective {
     Je, // AND (∧)
     Ja, // OR  (∨)
     Jo, // IFF (↔)
-    Ju, // XOR (⊕)
+    Ju, // A whether or not B
+}
+
+/// A fully specified logical connective, as used by GA forethought
+/// (se? GA nai? ... gi nai? ...) and by A / .i JA afterthought
+/// (na? se? A nai?) connection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LogicalConnective {
+    pub connective: Connective, // ge/.e/.ije → Je, ga/.a/.ija → Ja, ...
+    pub converted: bool,        // se: swap the operands
+    pub negate_left: bool,      // first operand negated (ganai, na.e)
+    pub negate_right: bool,     // second operand negated (ginai, .enai)
 }
 
 /// Gadri (descriptor) type
@@ -59,15 +119,87 @@ pub enum Gadri {
     Lo,   // veridical description (∃ in FOL)
     Le,   // non-veridical reference (specific referent)
     La,   // named entity (proper name)
-    RoLo, // universal veridical (∀ in FOL): ro lo
-    RoLe, // universal referential (∀ over specific set): ro le
+    Loi,  // mass of things that are
+    Lei,  // mass of things described as
+    Lai,  // mass of things named
+    Lohi, // lo'i: set of things that are
+    Lehi, // le'i: set of things described as
+    Lahi, // la'i: set of things named
+    Lohe, // lo'e: the typical one (a defeasible generic)
+    Lehe, // le'e: the stereotypical one
+}
+
+/// Quantifier on a prenex variable or a sumti (outer: "re lo gerku",
+/// inner: "lo ci gerku")
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Quantifier {
+    Ro,           // every (∀)
+    AtLeast(u32), // su'o [PA]: at least N (bare su'o is ∃)
+    Exactly(u32), // PA: exactly N (no = none)
 }
 
 /// Relative clause type
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum RelClauseKind {
-    Poi, // restrictive (intersective)
-    Noi, // non-restrictive (appositive)
+    Poi,  // restrictive (intersective)
+    Noi,  // non-restrictive (appositive)
+    Voi,  // restrictive, non-veridical: described as, not claimed
+    Goi,  // assignment: binds a ko'a-series pro-sumti
+    Pe,   // restrictive association: "le gerku pe mi", "le mi gerku"
+    Ne,   // non-restrictive association
+    Po,   // ownership (alienable)
+    Pohe, // po'e: intrinsic possession
+}
+
+/// NU-class abstractor: turns a bridi into a selbri over reified propositions
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AbstractionKind {
+    Nu,   // event/state: x1 is an event of [bridi]
+    Duhu, // du'u: x1 is the predication [bridi], expressed by x2
+    Ka,   // property: x1 is the property [bridi]
+    Ni,   // amount: x1 is the amount of [bridi] on scale x2
+    Siho, // si'o: x1 is the concept [bridi] in the mind of x2
+}
+
+/// PU: temporal direction relative to the reference point
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeDirection {
+    Pu, // past (before)
+    Ca, // present (during)
+    Ba, // future (after)
+}
+
+/// ZI: temporal distance from the reference point
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeDistance {
+    Zi, // short
+    Za, // medium
+    Zu, // long
+}
+
+/// ZAhO: event contour (aspect)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Aspect {
+    Coha, // co'a: inchoative (beginning)
+    Cohu, // co'u: cessative (ending)
+    Caho, // ca'o: continuative (in progress)
+}
+
+/// A simple tense: PU? ZI? ZAhO? (at least one part present)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Tense {
+    pub direction: Option<TimeDirection>,
+    pub distance: Option<TimeDistance>,
+    pub aspect: Option<Aspect>,
+}
+
+/// Modal (case) tag on a sumti: adds a place outside the selbri's own structure
+#[derive(Debug, Clone, PartialEq)]
+pub enum ModalTag {
+    /// BAI cmavo: ri'a, mu'i, pi'o, ka'a, ...
+    Bai(String),
+    /// Ad-hoc modal: fi'o + selbri + [fe'u]
+    Fiho(Box<Selbri>),
 }
 
 // ─── Core AST nodes ──────────────────────────────────────────────
@@ -78,8 +210,20 @@ pub enum Sumti {
     /// Pro-sumti: mi, do, ko'a..ko'u, da/de/di, ti/ta/tu, ri/ra/ru, etc.
     ProSumti(String),
 
-    /// Gadri-description: lo/le/la/ro lo/ro le + selbri [+ ku]
-    Description { gadri: Gadri, inner: Box<Selbri> },
+    /// ke'a [xi N]: the sumti restricted by the N-th enclosing relative
+    /// clause, counting outwards from 1 (the innermost)
+    Relativized(u32),
+
+    /// Gadri-description: lo/le/la + inner quantifier? + selbri [+ ku]
+    Description {
+        gadri: Gadri,
+        count: Option<Quantifier>,
+        inner: Box<Selbri>,
+    },
+
+    /// Outer quantifier + description or da/de/di:
+    /// "ro lo gerku", "re lo gerku", "su'o da", "no lo prenu"
+    Quantified(Quantifier, Box<Sumti>),
 
     /// la + cmevla name(s)
     Name(String),
@@ -93,18 +237,43 @@ pub enum Sumti {
     /// Place-tagged sumti: fa/fe/fi/fo/fu + sumti
     Tagged(PlaceTag, Box<Sumti>),
 
+    /// Tense-tagged sumti (sumti tcita): tense + sumti,
+    /// e.g. "pu lo nu do klama" — before the event of you going
+    TenseTagged(Tense, Box<Sumti>),
+
+    /// Modal-tagged sumti: (BAI | fi'o selbri fe'u) + sumti,
+    /// e.g. "ri'a lo nu carvi" — physically caused by rain
+    ModalTagged(ModalTag, Box<Sumti>),
+
     /// Sumti with relative clause: sumti + (poi|noi) sentence [ku'o]
+    /// or sumti + goi sumti [ge'u]
     Restricted {
         inner: Box<Sumti>,
         clause: RelClause,
     },
+
+    /// Logically connected sumti: GA sumti gi sumti | sumti A sumti
+    /// e.g. "ge mi gi do", "mi .e do" — both me and you
+    Connected {
+        left: Box<Sumti>,
+        connective: LogicalConnective,
+        right: Box<Sumti>,
+    },
 }
 
 /// A relative clause attached to a sumti.
 #[derive(Debug, Clone, PartialEq)]
 pub struct RelClause {
     pub kind: RelClauseKind,
-    pub body: Box<Bridi>,
+    pub body: RelClauseBody,
+}
+
+/// What a relative clause attaches: a sentence (poi, noi, voi) or a sumti
+/// (goi and the relative phrases pe, ne, po, po'e).
+#[derive(Debug, Clone, PartialEq)]
+pub enum RelClauseBody {
+    Sentence(Box<Bridi>),
+    Sumti(Box<Sumti>),
 }
 
 /// The main predicate/relation in a bridi.
@@ -138,6 +307,19 @@ pub enum Selbri {
         connective: Connective,
         right: Box<Selbri>,
     },
+
+    /// Abstraction: (nu|du'u|ka|ni|si'o) + sentence + [kei]
+    Abstraction(AbstractionKind, Box<Bridi>),
+}
+
+/// A term of a prenex (the part before zo'u).
+#[derive(Debug, Clone, PartialEq)]
+pub enum PrenexTerm {
+    /// quantifier? + da/de/di (possibly with relative clauses);
+    /// a bare variable is existential
+    Variable(Option<Quantifier>, Sumti),
+    /// na ku: negates everything to its right
+    Naku,
 }
 
 /// A single bridi (predication).
@@ -147,10 +329,41 @@ pub struct Bridi {
     pub head_terms: Vec<Sumti>, // terms before selbri (cu-separated)
     pub tail_terms: Vec<Sumti>, // terms after selbri
     pub negated: bool,          // sentence-level na (before all terms)
+    pub tense: Option<Tense>,   // sentence tense: before selbri, or tense + ku
+    pub question: bool,         // xu: asked rather than claimed
+    pub span: Span,             // the whole bridi
+    pub selbri_span: Span,
+    pub head_spans: Vec<Span>, // one per head term
+    pub tail_spans: Vec<Span>, // one per tail term
+}
+
+/// Sentence-level logical structure over top-level bridi.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Statement {
+    /// A single bridi (index into `ParsedText::sentences`)
+    Simple(usize),
+
+    /// Connected statements: GA statement gi statement | statement .i JA statement
+    /// e.g. "ganai carvi gi lo loldi cu cilmo" — if it rains, the ground is wet
+    Connected {
+        left: Box<Statement>,
+        connective: LogicalConnective,
+        right: Box<Statement>,
+    },
+
+    /// Prenex + body: terms zo'u statement
+    /// e.g. "ro da su'o de zo'u da prami de" — ∀x ∃y. prami(x, y)
+    Prenex {
+        terms: Vec<PrenexTerm>,
+        body: Box<Statement>,
+    },
 }
 
 /// A complete parsed text: one or more sentences.
 #[derive(Debug, Clone, PartialEq)]
 pub struct ParsedText {
+    /// Every top-level bridi, in source order.
     pub sentences: Vec<Bridi>,
+    /// The independent statements of the text; a plain `.i` starts a new one.
+    pub statements: Vec<Statement>,
 }
@@ -130,8 +130,14 @@ pub mod lojban {
                 Lo,
                 Le,
                 La,
-                RoLo,
-                RoLe,
+                Loi,
+                Lei,
+                Lai,
+                Lohi,
+                Lehi,
+                Lahi,
+                Lohe,
+                Lehe,
             }
             impl ::core::fmt::Debug for Gadri {
                 fn fmt(
@@ -142,8 +148,14 @@ pub mod lojban {
                         Gadri::Lo => f.debug_tuple("Gadri::Lo").finish(),
                         Gadri::Le => f.debug_tuple("Gadri::Le").finish(),
                         Gadri::La => f.debug_tuple("Gadri::La").finish(),
-                        Gadri::RoLo => f.debug_tuple("Gadri::RoLo").finish(),
-                        Gadri::RoLe => f.debug_tuple("Gadri::RoLe").finish(),
+                        Gadri::Loi => f.debug_tuple("Gadri::Loi").finish(),
+                        Gadri::Lei => f.debug_tuple("Gadri::Lei").finish(),
+                        Gadri::Lai => f.debug_tuple("Gadri::Lai").finish(),
+                        Gadri::Lohi => f.debug_tuple("Gadri::Lohi").finish(),
+                        Gadri::Lehi => f.debug_tuple("Gadri::Lehi").finish(),
+                        Gadri::Lahi => f.debug_tuple("Gadri::Lahi").finish(),
+                        Gadri::Lohe => f.debug_tuple("Gadri::Lohe").finish(),
+                        Gadri::Lehe => f.debug_tuple("Gadri::Lehe").finish(),
                     }
                 }
             }
@@ -157,8 +169,14 @@ pub mod lojban {
                         0 => Gadri::Lo,
                         1 => Gadri::Le,
                         2 => Gadri::La,
-                        3 => Gadri::RoLo,
-                        4 => Gadri::RoLe,
+                        3 => Gadri::Loi,
+                        4 => Gadri::Lei,
+                        5 => Gadri::Lai,
+                        6 => Gadri::Lohi,
+                        7 => Gadri::Lehi,
+                        8 => Gadri::Lahi,
+                        9 => Gadri::Lohe,
+                        10 => Gadri::Lehe,
                         _ => panic!("invalid enum discriminant"),
                     }
                 }
@@ -168,6 +186,12 @@ pub mod lojban {
             pub enum RelClauseKind {
                 Poi,
                 Noi,
+                Voi,
+                Goi,
+                Pe,
+                Ne,
+                Po,
+                Pohe,
             }
             impl ::core::fmt::Debug for RelClauseKind {
                 fn fmt(
@@ -181,6 +205,18 @@ pub mod lojban {
                         RelClauseKind::Noi => {
                             f.debug_tuple("RelClauseKind::Noi").finish()
                         }
+                        RelClauseKind::Voi => {
+                            f.debug_tuple("RelClauseKind::Voi").finish()
+                        }
+                        RelClauseKind::Goi => {
+                            f.debug_tuple("RelClauseKind::Goi").finish()
+                        }
+                        RelClauseKind::Pe => f.debug_tuple("RelClauseKind::Pe").finish(),
+                        RelClauseKind::Ne => f.debug_tuple("RelClauseKind::Ne").finish(),
+                        RelClauseKind::Po => f.debug_tuple("RelClauseKind::Po").finish(),
+                        RelClauseKind::Pohe => {
+                            f.debug_tuple("RelClauseKind::Pohe").finish()
+                        }
                     }
                 }
             }
@@ -193,15 +229,330 @@ pub mod lojban {
                     match val {
                         0 => RelClauseKind::Poi,
                         1 => RelClauseKind::Noi,
+                        2 => RelClauseKind::Voi,
+                        3 => RelClauseKind::Goi,
+                        4 => RelClauseKind::Pe,
+                        5 => RelClauseKind::Ne,
+                        6 => RelClauseKind::Po,
+                        7 => RelClauseKind::Pohe,
+                        _ => panic!("invalid enum discriminant"),
+                    }
+                }
+            }
+            #[repr(u8)]
+            #[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+            pub enum AbstractionKind {
+                Nu,
+                Duhu,
+                Ka,
+                Ni,
+                Siho,
+            }
+            impl ::core::fmt::Debug for AbstractionKind {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    match self {
+                        AbstractionKind::Nu => {
+                            f.debug_tuple("AbstractionKind::Nu").finish()
+                        }
+                        AbstractionKind::Duhu => {
+                            f.debug_tuple("AbstractionKind::Duhu").finish()
+                        }
+                        AbstractionKind::Ka => {
+                            f.debug_tuple("AbstractionKind::Ka").finish()
+                        }
+                        AbstractionKind::Ni => {
+                            f.debug_tuple("AbstractionKind::Ni").finish()
+                        }
+                        AbstractionKind::Siho => {
+                            f.debug_tuple("AbstractionKind::Siho").finish()
+                        }
+                    }
+                }
+            }
+            impl AbstractionKind {
+                #[doc(hidden)]
+                pub unsafe fn _lift(val: u8) -> AbstractionKind {
+                    if !cfg!(debug_assertions) {
+                        return ::core::mem::transmute(val);
+                    }
+                    match val {
+                        0 => AbstractionKind::Nu,
+                        1 => AbstractionKind::Duhu,
+                        2 => AbstractionKind::Ka,
+                        3 => AbstractionKind::Ni,
+                        4 => AbstractionKind::Siho,
+                        _ => panic!("invalid enum discriminant"),
+                    }
+                }
+            }
+            #[repr(u8)]
+            #[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+            pub enum TimeDirection {
+                Pu,
+                Ca,
+                Ba,
+            }
+            impl ::core::fmt::Debug for TimeDirection {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    match self {
+                        TimeDirection::Pu => f.debug_tuple("TimeDirection::Pu").finish(),
+                        TimeDirection::Ca => f.debug_tuple("TimeDirection::Ca").finish(),
+                        TimeDirection::Ba => f.debug_tuple("TimeDirection::Ba").finish(),
+                    }
+                }
+            }
+            impl TimeDirection {
+                #[doc(hidden)]
+                pub unsafe fn _lift(val: u8) -> TimeDirection {
+                    if !cfg!(debug_assertions) {
+                        return ::core::mem::transmute(val);
+                    }
+                    match val {
+                        0 => TimeDirection::Pu,
+                        1 => TimeDirection::Ca,
+                        2 => TimeDirection::Ba,
+                        _ => panic!("invalid enum discriminant"),
+                    }
+                }
+            }
+            #[repr(u8)]
+            #[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+            pub enum TimeDistance {
+                Zi,
+                Za,
+                Zu,
+            }
+            impl ::core::fmt::Debug for TimeDistance {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    match self {
+                        TimeDistance::Zi => f.debug_tuple("TimeDistance::Zi").finish(),
+                        TimeDistance::Za => f.debug_tuple("TimeDistance::Za").finish(),
+                        TimeDistance::Zu => f.debug_tuple("TimeDistance::Zu").finish(),
+                    }
+                }
+            }
+            impl TimeDistance {
+                #[doc(hidden)]
+                pub unsafe fn _lift(val: u8) -> TimeDistance {
+                    if !cfg!(debug_assertions) {
+                        return ::core::mem::transmute(val);
+                    }
+                    match val {
+                        0 => TimeDistance::Zi,
+                        1 => TimeDistance::Za,
+                        2 => TimeDistance::Zu,
+                        _ => panic!("invalid enum discriminant"),
+                    }
+                }
+            }
+            #[repr(u8)]
+            #[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+            pub enum Aspect {
+                Coha,
+                Cohu,
+                Caho,
+            }
+            impl ::core::fmt::Debug for Aspect {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    match self {
+                        Aspect::Coha => f.debug_tuple("Aspect::Coha").finish(),
+                        Aspect::Cohu => f.debug_tuple("Aspect::Cohu").finish(),
+                        Aspect::Caho => f.debug_tuple("Aspect::Caho").finish(),
+                    }
+                }
+            }
+            impl Aspect {
+                #[doc(hidden)]
+                pub unsafe fn _lift(val: u8) -> Aspect {
+                    if !cfg!(debug_assertions) {
+                        return ::core::mem::transmute(val);
+                    }
+                    match val {
+                        0 => Aspect::Coha,
+                        1 => Aspect::Cohu,
+                        2 => Aspect::Caho,
+                        _ => panic!("invalid enum discriminant"),
+                    }
+                }
+            }
+            #[repr(u8)]
+            #[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+            pub enum QuantifierKind {
+                All,
+                AtLeast,
+                Exactly,
+            }
+            impl ::core::fmt::Debug for QuantifierKind {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    match self {
+                        QuantifierKind::All => {
+                            f.debug_tuple("QuantifierKind::All").finish()
+                        }
+                        QuantifierKind::AtLeast => {
+                            f.debug_tuple("QuantifierKind::AtLeast").finish()
+                        }
+                        QuantifierKind::Exactly => {
+                            f.debug_tuple("QuantifierKind::Exactly").finish()
+                        }
+                    }
+                }
+            }
+            impl QuantifierKind {
+                #[doc(hidden)]
+                pub unsafe fn _lift(val: u8) -> QuantifierKind {
+                    if !cfg!(debug_assertions) {
+                        return ::core::mem::transmute(val);
+                    }
+                    match val {
+                        0 => QuantifierKind::All,
+                        1 => QuantifierKind::AtLeast,
+                        2 => QuantifierKind::Exactly,
                         _ => panic!("invalid enum discriminant"),
                     }
                 }
             }
+            /// Byte range in the source text, end-exclusive.
+            #[repr(C)]
+            #[derive(Clone, Copy)]
+            pub struct Span {
+                pub start: u32,
+                pub end: u32,
+            }
+            impl ::core::fmt::Debug for Span {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    f.debug_struct("Span")
+                        .field("start", &self.start)
+                        .field("end", &self.end)
+                        .finish()
+                }
+            }
+            /// PU? ZI? ZAhO? — at least one part is present.
+            #[repr(C)]
+            #[derive(Clone, Copy)]
+            pub struct Tense {
+                pub direction: Option<TimeDirection>,
+                pub distance: Option<TimeDistance>,
+                pub aspect: Option<Aspect>,
+            }
+            impl ::core::fmt::Debug for Tense {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    f.debug_struct("Tense")
+                        .field("direction", &self.direction)
+                        .field("distance", &self.distance)
+                        .field("aspect", &self.aspect)
+                        .finish()
+                }
+            }
+            /// ro → all; su'o N → at-least N (bare su'o: 1); PA → exactly N
+            /// (no: exactly 0). `count` is unused for `all`.
+            #[repr(C)]
+            #[derive(Clone, Copy)]
+            pub struct Quantifier {
+                pub kind: QuantifierKind,
+                pub count: u32,
+            }
+            impl ::core::fmt::Debug for Quantifier {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    f.debug_struct("Quantifier")
+                        .field("kind", &self.kind)
+                        .field("count", &self.count)
+                        .finish()
+                }
+            }
+            /// BAI cmavo (e.g. "ri'a") or ad-hoc fi'o + selbri.
+            #[derive(Clone)]
+            pub enum ModalTag {
+                Bai(_rt::String),
+                Fiho(SelbriId),
+            }
+            impl ::core::fmt::Debug for ModalTag {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    match self {
+                        ModalTag::Bai(e) => {
+                            f.debug_tuple("ModalTag::Bai").field(e).finish()
+                        }
+                        ModalTag::Fiho(e) => {
+                            f.debug_tuple("ModalTag::Fiho").field(e).finish()
+                        }
+                    }
+                }
+            }
+            /// se? GA nai? ... gi nai? ... — a connective with conversion and negation.
+            #[repr(C)]
+            #[derive(Clone, Copy)]
+            pub struct LogicalConnective {
+                pub connective: Connective,
+                pub converted: bool,
+                pub negate_left: bool,
+                pub negate_right: bool,
+            }
+            impl ::core::fmt::Debug for LogicalConnective {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    f.debug_struct("LogicalConnective")
+                        .field("connective", &self.connective)
+                        .field("converted", &self.converted)
+                        .field("negate-left", &self.negate_left)
+                        .field("negate-right", &self.negate_right)
+                        .finish()
+                }
+            }
+            /// poi/noi/voi attach a sentence (an index into `sentences`); goi
+            /// and the relative phrases pe/ne/po/po'e attach a sumti.
+            #[derive(Clone, Copy)]
+            pub enum RelClauseBody {
+                Sentence(u32),
+                Sumti(SumtiId),
+            }
+            impl ::core::fmt::Debug for RelClauseBody {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    match self {
+                        RelClauseBody::Sentence(e) => {
+                            f.debug_tuple("RelClauseBody::Sentence").field(e).finish()
+                        }
+                        RelClauseBody::Sumti(e) => {
+                            f.debug_tuple("RelClauseBody::Sumti").field(e).finish()
+                        }
+                    }
+                }
+            }
             #[repr(C)]
             #[derive(Clone, Copy)]
             pub struct RelClause {
                 pub kind: RelClauseKind,
-                pub body_sentence: u32,
+                pub body: RelClauseBody,
             }
             impl ::core::fmt::Debug for RelClause {
                 fn fmt(
@@ -210,19 +561,27 @@ pub mod lojban {
                 ) -> ::core::fmt::Result {
                     f.debug_struct("RelClause")
                         .field("kind", &self.kind)
-                        .field("body-sentence", &self.body_sentence)
+                        .field("body", &self.body)
                         .finish()
                 }
             }
             #[derive(Clone)]
             pub enum Sumti {
                 ProSumti(_rt::String),
-                Description((Gadri, SelbriId)),
+                /// ke'a xi N; 1 is the innermost relative clause
+                Relativized(u32),
+                /// gadri, inner quantifier ("lo ci gerku"), selbri
+                Description((Gadri, Option<Quantifier>, SelbriId)),
+                /// Outer quantifier on a description or da/de/di ("re lo gerku")
+                Quantified((Quantifier, SumtiId)),
                 Name(_rt::String),
                 QuotedLiteral(_rt::String),
                 Unspecified,
                 Tagged((PlaceTag, SumtiId)),
+                TenseTagged((Tense, SumtiId)),
+                ModalTagged((ModalTag, SumtiId)),
                 Restricted((SumtiId, RelClause)),
+                Connected((SumtiId, LogicalConnective, SumtiId)),
             }
             impl ::core::fmt::Debug for Sumti {
                 fn fmt(
@@ -233,9 +592,15 @@ pub mod lojban {
                         Sumti::ProSumti(e) => {
                             f.debug_tuple("Sumti::ProSumti").field(e).finish()
                         }
+                        Sumti::Relativized(e) => {
+                            f.debug_tuple("Sumti::Relativized").field(e).finish()
+                        }
                         Sumti::Description(e) => {
                             f.debug_tuple("Sumti::Description").field(e).finish()
                         }
+                        Sumti::Quantified(e) => {
+                            f.debug_tuple("Sumti::Quantified").field(e).finish()
+                        }
                         Sumti::Name(e) => f.debug_tuple("Sumti::Name").field(e).finish(),
                         Sumti::QuotedLiteral(e) => {
                             f.debug_tuple("Sumti::QuotedLiteral").field(e).finish()
@@ -246,9 +611,18 @@ pub mod lojban {
                         Sumti::Tagged(e) => {
                             f.debug_tuple("Sumti::Tagged").field(e).finish()
                         }
+                        Sumti::TenseTagged(e) => {
+                            f.debug_tuple("Sumti::TenseTagged").field(e).finish()
+                        }
+                        Sumti::ModalTagged(e) => {
+                            f.debug_tuple("Sumti::ModalTagged").field(e).finish()
+                        }
                         Sumti::Restricted(e) => {
                             f.debug_tuple("Sumti::Restricted").field(e).finish()
                         }
+                        Sumti::Connected(e) => {
+                            f.debug_tuple("Sumti::Connected").field(e).finish()
+                        }
                     }
                 }
             }
@@ -262,6 +636,8 @@ pub mod lojban {
                 Grouped(SelbriId),
                 WithArgs((SelbriId, _rt::Vec<SumtiId>)),
                 Connected((SelbriId, Connective, SelbriId)),
+                /// NU-class abstraction; the u32 indexes the body in `sentences`.
+                Abstraction((AbstractionKind, u32)),
             }
             impl ::core::fmt::Debug for Selbri {
                 fn fmt(
@@ -293,6 +669,9 @@ pub mod lojban {
                         Selbri::Connected(e) => {
                             f.debug_tuple("Selbri::Connected").field(e).finish()
                         }
+                        Selbri::Abstraction(e) => {
+                            f.debug_tuple("Selbri::Abstraction").field(e).finish()
+                        }
                     }
                 }
             }
@@ -302,6 +681,10 @@ pub mod lojban {
                 pub head_terms: _rt::Vec<SumtiId>,
                 pub tail_terms: _rt::Vec<SumtiId>,
                 pub negated: bool,
+                pub tense: Option<Tense>,
+                /// Marked with xu: a yes/no question rather than a claim.
+                pub question: bool,
+                pub span: Span,
             }
             impl ::core::fmt::Debug for Bridi {
                 fn fmt(
@@ -313,16 +696,71 @@ pub mod lojban {
                         .field("head-terms", &self.head_terms)
                         .field("tail-terms", &self.tail_terms)
                         .field("negated", &self.negated)
+                        .field("tense", &self.tense)
+                        .field("question", &self.question)
+                        .field("span", &self.span)
                         .finish()
                 }
             }
+            #[derive(Clone, Copy)]
+            pub enum PrenexTerm {
+                /// quantifier? + da/de/di; a bare variable is existential.
+                Variable((Option<Quantifier>, SumtiId)),
+                Naku,
+            }
+            impl ::core::fmt::Debug for PrenexTerm {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    match self {
+                        PrenexTerm::Variable(e) => {
+                            f.debug_tuple("PrenexTerm::Variable").field(e).finish()
+                        }
+                        PrenexTerm::Naku => f.debug_tuple("PrenexTerm::Naku").finish(),
+                    }
+                }
+            }
+            #[derive(Clone)]
+            pub enum Statement {
+                /// A single top-level bridi; the u32 indexes it in `sentences`.
+                Simple(u32),
+                /// Connected statements (GA ... gi, .i JA); the u32s index `statements`.
+                Connected((LogicalConnective, u32, u32)),
+                /// terms zo'u statement; the u32 indexes the body in `statements`.
+                Prenex((_rt::Vec<PrenexTerm>, u32)),
+            }
+            impl ::core::fmt::Debug for Statement {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    match self {
+                        Statement::Simple(e) => {
+                            f.debug_tuple("Statement::Simple").field(e).finish()
+                        }
+                        Statement::Connected(e) => {
+                            f.debug_tuple("Statement::Connected").field(e).finish()
+                        }
+                        Statement::Prenex(e) => {
+                            f.debug_tuple("Statement::Prenex").field(e).finish()
+                        }
+                    }
+                }
+            }
             #[derive(Clone)]
             pub struct AstBuffer {
                 pub selbris: _rt::Vec<Selbri>,
                 pub sumtis: _rt::Vec<Sumti>,
+                /// Source spans parallel to `selbris` and `sumtis`. Nested nodes
+                /// (description selbri, be/rel clause arguments, ...) share the span
+                /// of the term or selbri they belong to.
+                pub selbri_spans: _rt::Vec<Span>,
+                pub sumti_spans: _rt::Vec<Span>,
                 pub sentences: _rt::Vec<Bridi>,
-                /// Indices into `sentences` for top-level sentences only.
-                /// Rel clause bodies live in `sentences` but are NOT roots.
+                pub statements: _rt::Vec<Statement>,
+                /// Indices into `statements` for top-level statements only.
+                /// Rel clause and abstraction bodies live in `sentences` but are NOT roots.
                 pub roots: _rt::Vec<u32>,
             }
             impl ::core::fmt::Debug for AstBuffer {
@@ -333,7 +771,10 @@ pub mod lojban {
                     f.debug_struct("AstBuffer")
                         .field("selbris", &self.selbris)
                         .field("sumtis", &self.sumtis)
+                        .field("selbri-spans", &self.selbri_spans)
+                        .field("sumti-spans", &self.sumti_spans)
                         .field("sentences", &self.sentences)
+                        .field("statements", &self.statements)
                         .field("roots", &self.roots)
                         .finish()
                 }
@@ -345,6 +786,8 @@ pub mod lojban {
                 Constant(_rt::String),
                 Description(_rt::String),
                 Unspecified,
+                /// Reified proposition; the u32 indexes its formula in `nodes`.
+                Proposition(u32),
             }
             impl ::core::fmt::Debug for LogicalTerm {
                 fn fmt(
@@ -364,6 +807,9 @@ pub mod lojban {
                         LogicalTerm::Unspecified => {
                             f.debug_tuple("LogicalTerm::Unspecified").finish()
                         }
+                        LogicalTerm::Proposition(e) => {
+                            f.debug_tuple("LogicalTerm::Proposition").field(e).finish()
+                        }
                     }
                 }
             }
@@ -372,9 +818,15 @@ pub mod lojban {
                 Predicate((_rt::String, _rt::Vec<LogicalTerm>)),
                 AndNode((u32, u32)),
                 OrNode((u32, u32)),
+                ImpliesNode((u32, u32)),
                 NotNode(u32),
                 ExistsNode((_rt::String, u32)),
                 ForAllNode((_rt::String, u32)),
+                /// Time-indexed formula: the body holds at the given interval.
+                AtNode((LogicalTerm, u32)),
+                /// Defeasible generic (lo'e): variable, restrictor, body. Each x
+                /// meeting the restrictor meets the body unless that is contradicted.
+                GenericNode((_rt::String, u32, u32)),
             }
             impl ::core::fmt::Debug for LogicNode {
                 fn fmt(
@@ -391,6 +843,9 @@ pub mod lojban {
                         LogicNode::OrNode(e) => {
                             f.debug_tuple("LogicNode::OrNode").field(e).finish()
                         }
+                        LogicNode::ImpliesNode(e) => {
+                            f.debug_tuple("LogicNode::ImpliesNode").field(e).finish()
+                        }
                         LogicNode::NotNode(e) => {
                             f.debug_tuple("LogicNode::NotNode").field(e).finish()
                         }
@@ -400,6 +855,12 @@ pub mod lojban {
                         LogicNode::ForAllNode(e) => {
                             f.debug_tuple("LogicNode::ForAllNode").field(e).finish()
                         }
+                        LogicNode::AtNode(e) => {
+                            f.debug_tuple("LogicNode::AtNode").field(e).finish()
+                        }
+                        LogicNode::GenericNode(e) => {
+                            f.debug_tuple("LogicNode::GenericNode").field(e).finish()
+                        }
                     }
                 }
             }
@@ -434,570 +895,1182 @@ pub mod exports {
                 static __FORCE_SECTION_REF: fn() = super::super::super::super::__link_custom_section_describing_imports;
                 use super::super::super::super::_rt;
                 pub type AstBuffer = super::super::super::super::lojban::nesy::ast_types::AstBuffer;
+                pub type Span = super::super::super::super::lojban::nesy::ast_types::Span;
+                /// What to do with input that is not Lojban (unknown characters,
+                /// malformed words).
+                #[repr(u8)]
+                #[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+                pub enum LexMode {
+                    /// Report the first invalid segment and parse nothing.
+                    Strict,
+                    /// Report and skip every invalid segment, parsing the rest.
+                    Lenient,
+                }
+                impl ::core::fmt::Debug for LexMode {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        match self {
+                            LexMode::Strict => f.debug_tuple("LexMode::Strict").finish(),
+                            LexMode::Lenient => {
+                                f.debug_tuple("LexMode::Lenient").finish()
+                            }
+                        }
+                    }
+                }
+                impl LexMode {
+                    #[doc(hidden)]
+                    pub unsafe fn _lift(val: u8) -> LexMode {
+                        if !cfg!(debug_assertions) {
+                            return ::core::mem::transmute(val);
+                        }
+                        match val {
+                            0 => LexMode::Strict,
+                            1 => LexMode::Lenient,
+                            _ => panic!("invalid enum discriminant"),
+                        }
+                    }
+                }
+                /// A lex or parse error; `message` is rendered with line, column and a
+                /// caret snippet of the offending source.
+                #[derive(Clone)]
+                pub struct Diagnostic {
+                    pub message: _rt::String,
+                    pub span: Span,
+                }
+                impl ::core::fmt::Debug for Diagnostic {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        f.debug_struct("Diagnostic")
+                            .field("message", &self.message)
+                            .field("span", &self.span)
+                            .finish()
+                    }
+                }
+                /// Every statement that parsed, plus one diagnostic per invalid
+                /// segment the lexer reported and per region the parser skipped (up
+                /// to the next `.i` or `ni'o`), in source order. Empty diagnostics
+                /// means the whole input parsed.
+                #[derive(Clone)]
+                pub struct ParseResult {
+                    pub ast: AstBuffer,
+                    pub diagnostics: _rt::Vec<Diagnostic>,
+                }
+                impl ::core::fmt::Debug for ParseResult {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        f.debug_struct("ParseResult")
+                            .field("ast", &self.ast)
+                            .field("diagnostics", &self.diagnostics)
+                            .finish()
+                    }
+                }
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
                 pub unsafe fn _export_parse_text_cabi<T: Guest>(
                     arg0: *mut u8,
                     arg1: usize,
+                    arg2: i32,
                 ) -> *mut u8 {
                     #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
                     let len0 = arg1;
                     let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
-                    let result1 = T::parse_text(_rt::string_lift(bytes0));
+                    let result1 = T::parse_text(
+                        _rt::string_lift(bytes0),
+                        LexMode::_lift(arg2 as u8),
+                    );
                     let ptr2 = (&raw mut _RET_AREA.0).cast::<u8>();
-                    match result1 {
-                        Ok(e) => {
-                            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
-                            let super::super::super::super::lojban::nesy::ast_types::AstBuffer {
-                                selbris: selbris3,
-                                sumtis: sumtis3,
-                                sentences: sentences3,
-                                roots: roots3,
-                            } = e;
-                            let vec13 = selbris3;
-                            let len13 = vec13.len();
-                            let layout13 = _rt::alloc::Layout::from_size_align_unchecked(
-                                vec13.len() * (4 * ::core::mem::size_of::<*const u8>()),
-                                ::core::mem::size_of::<*const u8>(),
-                            );
-                            let result13 = if layout13.size() != 0 {
-                                let ptr = _rt::alloc::alloc(layout13).cast::<u8>();
-                                if ptr.is_null() {
-                                    _rt::alloc::handle_alloc_error(layout13);
+                    let ParseResult { ast: ast3, diagnostics: diagnostics3 } = result1;
+                    let super::super::super::super::lojban::nesy::ast_types::AstBuffer {
+                        selbris: selbris4,
+                        sumtis: sumtis4,
+                        selbri_spans: selbri_spans4,
+                        sumti_spans: sumti_spans4,
+                        sentences: sentences4,
+                        statements: statements4,
+                        roots: roots4,
+                    } = ast3;
+                    let vec15 = selbris4;
+                    let len15 = vec15.len();
+                    let layout15 = _rt::alloc::Layout::from_size_align_unchecked(
+                        vec15.len() * (4 * ::core::mem::size_of::<*const u8>()),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let result15 = if layout15.size() != 0 {
+                        let ptr = _rt::alloc::alloc(layout15).cast::<u8>();
+                        if ptr.is_null() {
+                            _rt::alloc::handle_alloc_error(layout15);
+                        }
+                        ptr
+                    } else {
+                        ::core::ptr::null_mut()
+                    };
+                    for (i, e) in vec15.into_iter().enumerate() {
+                        let base = result15
+                            .add(i * (4 * ::core::mem::size_of::<*const u8>()));
+                        {
+                            use super::super::super::super::lojban::nesy::ast_types::Selbri as V14;
+                            match e {
+                                V14::Root(e) => {
+                                    *base.add(0).cast::<u8>() = (0i32) as u8;
+                                    let vec5 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr5 = vec5.as_ptr().cast::<u8>();
+                                    let len5 = vec5.len();
+                                    ::core::mem::forget(vec5);
+                                    *base
+                                        .add(2 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len5;
+                                    *base
+                                        .add(::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>() = ptr5.cast_mut();
                                 }
-                                ptr
-                            } else {
-                                ::core::ptr::null_mut()
-                            };
-                            for (i, e) in vec13.into_iter().enumerate() {
-                                let base = result13
-                                    .add(i * (4 * ::core::mem::size_of::<*const u8>()));
-                                {
-                                    use super::super::super::super::lojban::nesy::ast_types::Selbri as V12;
-                                    match e {
-                                        V12::Root(e) => {
-                                            *base.add(0).cast::<u8>() = (0i32) as u8;
-                                            let vec4 = (e.into_bytes()).into_boxed_slice();
-                                            let ptr4 = vec4.as_ptr().cast::<u8>();
-                                            let len4 = vec4.len();
-                                            ::core::mem::forget(vec4);
-                                            *base
-                                                .add(2 * ::core::mem::size_of::<*const u8>())
-                                                .cast::<usize>() = len4;
-                                            *base
-                                                .add(::core::mem::size_of::<*const u8>())
-                                                .cast::<*mut u8>() = ptr4.cast_mut();
+                                V14::Compound(e) => {
+                                    *base.add(0).cast::<u8>() = (1i32) as u8;
+                                    let vec7 = e;
+                                    let len7 = vec7.len();
+                                    let layout7 = _rt::alloc::Layout::from_size_align_unchecked(
+                                        vec7.len() * (2 * ::core::mem::size_of::<*const u8>()),
+                                        ::core::mem::size_of::<*const u8>(),
+                                    );
+                                    let result7 = if layout7.size() != 0 {
+                                        let ptr = _rt::alloc::alloc(layout7).cast::<u8>();
+                                        if ptr.is_null() {
+                                            _rt::alloc::handle_alloc_error(layout7);
                                         }
-                                        V12::Compound(e) => {
-                                            *base.add(0).cast::<u8>() = (1i32) as u8;
-                                            let vec6 = e;
+                                        ptr
+                                    } else {
+                                        ::core::ptr::null_mut()
+                                    };
+                                    for (i, e) in vec7.into_iter().enumerate() {
+                                        let base = result7
+                                            .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                                        {
+                                            let vec6 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr6 = vec6.as_ptr().cast::<u8>();
                                             let len6 = vec6.len();
-                                            let layout6 = _rt::alloc::Layout::from_size_align_unchecked(
-                                                vec6.len() * (2 * ::core::mem::size_of::<*const u8>()),
-                                                ::core::mem::size_of::<*const u8>(),
-                                            );
-                                            let result6 = if layout6.size() != 0 {
-                                                let ptr = _rt::alloc::alloc(layout6).cast::<u8>();
-                                                if ptr.is_null() {
-                                                    _rt::alloc::handle_alloc_error(layout6);
-                                                }
-                                                ptr
-                                            } else {
-                                                ::core::ptr::null_mut()
-                                            };
-                                            for (i, e) in vec6.into_iter().enumerate() {
-                                                let base = result6
-                                                    .add(i * (2 * ::core::mem::size_of::<*const u8>()));
-                                                {
-                                                    let vec5 = (e.into_bytes()).into_boxed_slice();
-                                                    let ptr5 = vec5.as_ptr().cast::<u8>();
-                                                    let len5 = vec5.len();
-                                                    ::core::mem::forget(vec5);
-                                                    *base
-                                                        .add(::core::mem::size_of::<*const u8>())
-                                                        .cast::<usize>() = len5;
-                                                    *base.add(0).cast::<*mut u8>() = ptr5.cast_mut();
-                                                }
-                                            }
-                                            *base
-                                                .add(2 * ::core::mem::size_of::<*const u8>())
-                                                .cast::<usize>() = len6;
-                                            *base
-                                                .add(::core::mem::size_of::<*const u8>())
-                                                .cast::<*mut u8>() = result6;
-                                        }
-                                        V12::Tanru(e) => {
-                                            *base.add(0).cast::<u8>() = (2i32) as u8;
-                                            let (t7_0, t7_1) = e;
-                                            *base
-                                                .add(::core::mem::size_of::<*const u8>())
-                                                .cast::<i32>() = _rt::as_i32(t7_0);
-                                            *base
-                                                .add(4 + 1 * ::core::mem::size_of::<*const u8>())
-                                                .cast::<i32>() = _rt::as_i32(t7_1);
-                                        }
-                                        V12::Converted(e) => {
-                                            *base.add(0).cast::<u8>() = (3i32) as u8;
-                                            let (t8_0, t8_1) = e;
+                                            ::core::mem::forget(vec6);
                                             *base
                                                 .add(::core::mem::size_of::<*const u8>())
-                                                .cast::<u8>() = (t8_0.clone() as i32) as u8;
-                                            *base
-                                                .add(4 + 1 * ::core::mem::size_of::<*const u8>())
-                                                .cast::<i32>() = _rt::as_i32(t8_1);
+                                                .cast::<usize>() = len6;
+                                            *base.add(0).cast::<*mut u8>() = ptr6.cast_mut();
                                         }
-                                        V12::Negated(e) => {
-                                            *base.add(0).cast::<u8>() = (4i32) as u8;
-                                            *base
-                                                .add(::core::mem::size_of::<*const u8>())
-                                                .cast::<i32>() = _rt::as_i32(e);
+                                    }
+                                    *base
+                                        .add(2 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len7;
+                                    *base
+                                        .add(::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>() = result7;
+                                }
+                                V14::Tanru(e) => {
+                                    *base.add(0).cast::<u8>() = (2i32) as u8;
+                                    let (t8_0, t8_1) = e;
+                                    *base
+                                        .add(::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(t8_0);
+                                    *base
+                                        .add(4 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(t8_1);
+                                }
+                                V14::Converted(e) => {
+                                    *base.add(0).cast::<u8>() = (3i32) as u8;
+                                    let (t9_0, t9_1) = e;
+                                    *base
+                                        .add(::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (t9_0.clone() as i32) as u8;
+                                    *base
+                                        .add(4 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(t9_1);
+                                }
+                                V14::Negated(e) => {
+                                    *base.add(0).cast::<u8>() = (4i32) as u8;
+                                    *base
+                                        .add(::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                V14::Grouped(e) => {
+                                    *base.add(0).cast::<u8>() = (5i32) as u8;
+                                    *base
+                                        .add(::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                V14::WithArgs(e) => {
+                                    *base.add(0).cast::<u8>() = (6i32) as u8;
+                                    let (t10_0, t10_1) = e;
+                                    *base
+                                        .add(::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(t10_0);
+                                    let vec11 = (t10_1).into_boxed_slice();
+                                    let ptr11 = vec11.as_ptr().cast::<u8>();
+                                    let len11 = vec11.len();
+                                    ::core::mem::forget(vec11);
+                                    *base
+                                        .add(3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len11;
+                                    *base
+                                        .add(2 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>() = ptr11.cast_mut();
+                                }
+                                V14::Connected(e) => {
+                                    *base.add(0).cast::<u8>() = (7i32) as u8;
+                                    let (t12_0, t12_1, t12_2) = e;
+                                    *base
+                                        .add(::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(t12_0);
+                                    *base
+                                        .add(4 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (t12_1.clone() as i32) as u8;
+                                    *base
+                                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(t12_2);
+                                }
+                                V14::Abstraction(e) => {
+                                    *base.add(0).cast::<u8>() = (8i32) as u8;
+                                    let (t13_0, t13_1) = e;
+                                    *base
+                                        .add(::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (t13_0.clone() as i32) as u8;
+                                    *base
+                                        .add(4 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(t13_1);
+                                }
+                            }
+                        }
+                    }
+                    *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<usize>() = len15;
+                    *ptr2.add(0).cast::<*mut u8>() = result15;
+                    let vec35 = sumtis4;
+                    let len35 = vec35.len();
+                    let layout35 = _rt::alloc::Layout::from_size_align_unchecked(
+                        vec35.len() * (8 + 4 * ::core::mem::size_of::<*const u8>()),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let result35 = if layout35.size() != 0 {
+                        let ptr = _rt::alloc::alloc(layout35).cast::<u8>();
+                        if ptr.is_null() {
+                            _rt::alloc::handle_alloc_error(layout35);
+                        }
+                        ptr
+                    } else {
+                        ::core::ptr::null_mut()
+                    };
+                    for (i, e) in vec35.into_iter().enumerate() {
+                        let base = result35
+                            .add(i * (8 + 4 * ::core::mem::size_of::<*const u8>()));
+                        {
+                            use super::super::super::super::lojban::nesy::ast_types::Sumti as V34;
+                            match e {
+                                V34::ProSumti(e) => {
+                                    *base.add(0).cast::<u8>() = (0i32) as u8;
+                                    let vec16 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr16 = vec16.as_ptr().cast::<u8>();
+                                    let len16 = vec16.len();
+                                    ::core::mem::forget(vec16);
+                                    *base
+                                        .add(2 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len16;
+                                    *base
+                                        .add(::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>() = ptr16.cast_mut();
+                                }
+                                V34::Relativized(e) => {
+                                    *base.add(0).cast::<u8>() = (1i32) as u8;
+                                    *base
+                                        .add(::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                V34::Description(e) => {
+                                    *base.add(0).cast::<u8>() = (2i32) as u8;
+                                    let (t17_0, t17_1, t17_2) = e;
+                                    *base
+                                        .add(::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (t17_0.clone() as i32) as u8;
+                                    match t17_1 {
+                                        Some(e) => {
+                                            *base
+                                                .add(4 + 1 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (1i32) as u8;
+                                            let super::super::super::super::lojban::nesy::ast_types::Quantifier {
+                                                kind: kind18,
+                                                count: count18,
+                                            } = e;
+                                            *base
+                                                .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (kind18.clone() as i32) as u8;
+                                            *base
+                                                .add(12 + 1 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>() = _rt::as_i32(count18);
                                         }
-                                        V12::Grouped(e) => {
-                                            *base.add(0).cast::<u8>() = (5i32) as u8;
+                                        None => {
+                                            *base
+                                                .add(4 + 1 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    *base
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(t17_2);
+                                }
+                                V34::Quantified(e) => {
+                                    *base.add(0).cast::<u8>() = (3i32) as u8;
+                                    let (t19_0, t19_1) = e;
+                                    let super::super::super::super::lojban::nesy::ast_types::Quantifier {
+                                        kind: kind20,
+                                        count: count20,
+                                    } = t19_0;
+                                    *base
+                                        .add(::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (kind20.clone() as i32) as u8;
+                                    *base
+                                        .add(4 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(count20);
+                                    *base
+                                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(t19_1);
+                                }
+                                V34::Name(e) => {
+                                    *base.add(0).cast::<u8>() = (4i32) as u8;
+                                    let vec21 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr21 = vec21.as_ptr().cast::<u8>();
+                                    let len21 = vec21.len();
+                                    ::core::mem::forget(vec21);
+                                    *base
+                                        .add(2 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len21;
+                                    *base
+                                        .add(::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>() = ptr21.cast_mut();
+                                }
+                                V34::QuotedLiteral(e) => {
+                                    *base.add(0).cast::<u8>() = (5i32) as u8;
+                                    let vec22 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr22 = vec22.as_ptr().cast::<u8>();
+                                    let len22 = vec22.len();
+                                    ::core::mem::forget(vec22);
+                                    *base
+                                        .add(2 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len22;
+                                    *base
+                                        .add(::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>() = ptr22.cast_mut();
+                                }
+                                V34::Unspecified => {
+                                    *base.add(0).cast::<u8>() = (6i32) as u8;
+                                }
+                                V34::Tagged(e) => {
+                                    *base.add(0).cast::<u8>() = (7i32) as u8;
+                                    let (t23_0, t23_1) = e;
+                                    *base
+                                        .add(::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (t23_0.clone() as i32) as u8;
+                                    *base
+                                        .add(4 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(t23_1);
+                                }
+                                V34::TenseTagged(e) => {
+                                    *base.add(0).cast::<u8>() = (8i32) as u8;
+                                    let (t24_0, t24_1) = e;
+                                    let super::super::super::super::lojban::nesy::ast_types::Tense {
+                                        direction: direction25,
+                                        distance: distance25,
+                                        aspect: aspect25,
+                                    } = t24_0;
+                                    match direction25 {
+                                        Some(e) => {
                                             *base
                                                 .add(::core::mem::size_of::<*const u8>())
-                                                .cast::<i32>() = _rt::as_i32(e);
+                                                .cast::<u8>() = (1i32) as u8;
+                                            *base
+                                                .add(1 + 1 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (e.clone() as i32) as u8;
                                         }
-                                        V12::WithArgs(e) => {
-                                            *base.add(0).cast::<u8>() = (6i32) as u8;
-                                            let (t9_0, t9_1) = e;
+                                        None => {
                                             *base
                                                 .add(::core::mem::size_of::<*const u8>())
-                                                .cast::<i32>() = _rt::as_i32(t9_0);
-                                            let vec10 = (t9_1).into_boxed_slice();
-                                            let ptr10 = vec10.as_ptr().cast::<u8>();
-                                            let len10 = vec10.len();
-                                            ::core::mem::forget(vec10);
+                                                .cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    match distance25 {
+                                        Some(e) => {
                                             *base
-                                                .add(3 * ::core::mem::size_of::<*const u8>())
-                                                .cast::<usize>() = len10;
+                                                .add(2 + 1 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (1i32) as u8;
                                             *base
-                                                .add(2 * ::core::mem::size_of::<*const u8>())
-                                                .cast::<*mut u8>() = ptr10.cast_mut();
+                                                .add(3 + 1 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (e.clone() as i32) as u8;
                                         }
-                                        V12::Connected(e) => {
-                                            *base.add(0).cast::<u8>() = (7i32) as u8;
-                                            let (t11_0, t11_1, t11_2) = e;
+                                        None => {
                                             *base
-                                                .add(::core::mem::size_of::<*const u8>())
-                                                .cast::<i32>() = _rt::as_i32(t11_0);
+                                                .add(2 + 1 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    match aspect25 {
+                                        Some(e) => {
                                             *base
                                                 .add(4 + 1 * ::core::mem::size_of::<*const u8>())
-                                                .cast::<u8>() = (t11_1.clone() as i32) as u8;
+                                                .cast::<u8>() = (1i32) as u8;
                                             *base
-                                                .add(8 + 1 * ::core::mem::size_of::<*const u8>())
-                                                .cast::<i32>() = _rt::as_i32(t11_2);
+                                                .add(5 + 1 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (e.clone() as i32) as u8;
                                         }
-                                    }
-                                }
-                            }
-                            *ptr2
-                                .add(2 * ::core::mem::size_of::<*const u8>())
-                                .cast::<usize>() = len13;
-                            *ptr2
-                                .add(::core::mem::size_of::<*const u8>())
-                                .cast::<*mut u8>() = result13;
-                            let vec22 = sumtis3;
-                            let len22 = vec22.len();
-                            let layout22 = _rt::alloc::Layout::from_size_align_unchecked(
-                                vec22.len() * (8 + 2 * ::core::mem::size_of::<*const u8>()),
-                                ::core::mem::size_of::<*const u8>(),
-                            );
-                            let result22 = if layout22.size() != 0 {
-                                let ptr = _rt::alloc::alloc(layout22).cast::<u8>();
-                                if ptr.is_null() {
-                                    _rt::alloc::handle_alloc_error(layout22);
+                                        None => {
+                                            *base
+                                                .add(4 + 1 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    *base
+                                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(t24_1);
                                 }
-                                ptr
-                            } else {
-                                ::core::ptr::null_mut()
-                            };
-                            for (i, e) in vec22.into_iter().enumerate() {
-                                let base = result22
-                                    .add(i * (8 + 2 * ::core::mem::size_of::<*const u8>()));
-                                {
-                                    use super::super::super::super::lojban::nesy::ast_types::Sumti as V21;
-                                    match e {
-                                        V21::ProSumti(e) => {
-                                            *base.add(0).cast::<u8>() = (0i32) as u8;
-                                            let vec14 = (e.into_bytes()).into_boxed_slice();
-                                            let ptr14 = vec14.as_ptr().cast::<u8>();
-                                            let len14 = vec14.len();
-                                            ::core::mem::forget(vec14);
+                                V34::ModalTagged(e) => {
+                                    *base.add(0).cast::<u8>() = (9i32) as u8;
+                                    let (t26_0, t26_1) = e;
+                                    use super::super::super::super::lojban::nesy::ast_types::ModalTag as V28;
+                                    match t26_0 {
+                                        V28::Bai(e) => {
+                                            *base
+                                                .add(::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (0i32) as u8;
+                                            let vec27 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr27 = vec27.as_ptr().cast::<u8>();
+                                            let len27 = vec27.len();
+                                            ::core::mem::forget(vec27);
+                                            *base
+                                                .add(3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>() = len27;
                                             *base
                                                 .add(2 * ::core::mem::size_of::<*const u8>())
-                                                .cast::<usize>() = len14;
+                                                .cast::<*mut u8>() = ptr27.cast_mut();
+                                        }
+                                        V28::Fiho(e) => {
                                             *base
                                                 .add(::core::mem::size_of::<*const u8>())
-                                                .cast::<*mut u8>() = ptr14.cast_mut();
+                                                .cast::<u8>() = (1i32) as u8;
+                                            *base
+                                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>() = _rt::as_i32(e);
                                         }
-                                        V21::Description(e) => {
-                                            *base.add(0).cast::<u8>() = (1i32) as u8;
-                                            let (t15_0, t15_1) = e;
+                                    }
+                                    *base
+                                        .add(4 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(t26_1);
+                                }
+                                V34::Restricted(e) => {
+                                    *base.add(0).cast::<u8>() = (10i32) as u8;
+                                    let (t29_0, t29_1) = e;
+                                    *base
+                                        .add(::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(t29_0);
+                                    let super::super::super::super::lojban::nesy::ast_types::RelClause {
+                                        kind: kind30,
+                                        body: body30,
+                                    } = t29_1;
+                                    *base
+                                        .add(4 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (kind30.clone() as i32) as u8;
+                                    use super::super::super::super::lojban::nesy::ast_types::RelClauseBody as V31;
+                                    match body30 {
+                                        V31::Sentence(e) => {
                                             *base
-                                                .add(::core::mem::size_of::<*const u8>())
-                                                .cast::<u8>() = (t15_0.clone() as i32) as u8;
+                                                .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (0i32) as u8;
                                             *base
-                                                .add(4 + 1 * ::core::mem::size_of::<*const u8>())
-                                                .cast::<i32>() = _rt::as_i32(t15_1);
+                                                .add(12 + 1 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>() = _rt::as_i32(e);
                                         }
-                                        V21::Name(e) => {
-                                            *base.add(0).cast::<u8>() = (2i32) as u8;
-                                            let vec16 = (e.into_bytes()).into_boxed_slice();
-                                            let ptr16 = vec16.as_ptr().cast::<u8>();
-                                            let len16 = vec16.len();
-                                            ::core::mem::forget(vec16);
+                                        V31::Sumti(e) => {
                                             *base
-                                                .add(2 * ::core::mem::size_of::<*const u8>())
-                                                .cast::<usize>() = len16;
+                                                .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (1i32) as u8;
                                             *base
-                                                .add(::core::mem::size_of::<*const u8>())
-                                                .cast::<*mut u8>() = ptr16.cast_mut();
+                                                .add(12 + 1 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>() = _rt::as_i32(e);
                                         }
-                                        V21::QuotedLiteral(e) => {
-                                            *base.add(0).cast::<u8>() = (3i32) as u8;
-                                            let vec17 = (e.into_bytes()).into_boxed_slice();
-                                            let ptr17 = vec17.as_ptr().cast::<u8>();
-                                            let len17 = vec17.len();
-                                            ::core::mem::forget(vec17);
+                                    }
+                                }
+                                V34::Connected(e) => {
+                                    *base.add(0).cast::<u8>() = (11i32) as u8;
+                                    let (t32_0, t32_1, t32_2) = e;
+                                    *base
+                                        .add(::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(t32_0);
+                                    let super::super::super::super::lojban::nesy::ast_types::LogicalConnective {
+                                        connective: connective33,
+                                        converted: converted33,
+                                        negate_left: negate_left33,
+                                        negate_right: negate_right33,
+                                    } = t32_1;
+                                    *base
+                                        .add(4 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (connective33.clone() as i32) as u8;
+                                    *base
+                                        .add(5 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (match converted33 {
+                                        true => 1,
+                                        false => 0,
+                                    }) as u8;
+                                    *base
+                                        .add(6 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (match negate_left33 {
+                                        true => 1,
+                                        false => 0,
+                                    }) as u8;
+                                    *base
+                                        .add(7 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (match negate_right33 {
+                                        true => 1,
+                                        false => 0,
+                                    }) as u8;
+                                    *base
+                                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(t32_2);
+                                }
+                            }
+                        }
+                    }
+                    *ptr2.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len35;
+                    *ptr2
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = result35;
+                    let vec36 = (selbri_spans4).into_boxed_slice();
+                    let ptr36 = vec36.as_ptr().cast::<u8>();
+                    let len36 = vec36.len();
+                    ::core::mem::forget(vec36);
+                    *ptr2.add(5 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len36;
+                    *ptr2
+                        .add(4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr36.cast_mut();
+                    let vec37 = (sumti_spans4).into_boxed_slice();
+                    let ptr37 = vec37.as_ptr().cast::<u8>();
+                    let len37 = vec37.len();
+                    ::core::mem::forget(vec37);
+                    *ptr2.add(7 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len37;
+                    *ptr2
+                        .add(6 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr37.cast_mut();
+                    let vec43 = sentences4;
+                    let len43 = vec43.len();
+                    let layout43 = _rt::alloc::Layout::from_size_align_unchecked(
+                        vec43.len() * (16 + 6 * ::core::mem::size_of::<*const u8>()),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let result43 = if layout43.size() != 0 {
+                        let ptr = _rt::alloc::alloc(layout43).cast::<u8>();
+                        if ptr.is_null() {
+                            _rt::alloc::handle_alloc_error(layout43);
+                        }
+                        ptr
+                    } else {
+                        ::core::ptr::null_mut()
+                    };
+                    for (i, e) in vec43.into_iter().enumerate() {
+                        let base = result43
+                            .add(i * (16 + 6 * ::core::mem::size_of::<*const u8>()));
+                        {
+                            let super::super::super::super::lojban::nesy::ast_types::Bridi {
+                                relation: relation38,
+                                head_terms: head_terms38,
+                                tail_terms: tail_terms38,
+                                negated: negated38,
+                                tense: tense38,
+                                question: question38,
+                                span: span38,
+                            } = e;
+                            *base.add(0).cast::<i32>() = _rt::as_i32(relation38);
+                            let vec39 = (head_terms38).into_boxed_slice();
+                            let ptr39 = vec39.as_ptr().cast::<u8>();
+                            let len39 = vec39.len();
+                            ::core::mem::forget(vec39);
+                            *base
+                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len39;
+                            *base
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>() = ptr39.cast_mut();
+                            let vec40 = (tail_terms38).into_boxed_slice();
+                            let ptr40 = vec40.as_ptr().cast::<u8>();
+                            let len40 = vec40.len();
+                            ::core::mem::forget(vec40);
+                            *base
+                                .add(4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len40;
+                            *base
+                                .add(3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>() = ptr40.cast_mut();
+                            *base
+                                .add(5 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (match negated38 {
+                                true => 1,
+                                false => 0,
+                            }) as u8;
+                            match tense38 {
+                                Some(e) => {
+                                    *base
+                                        .add(1 + 5 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (1i32) as u8;
+                                    let super::super::super::super::lojban::nesy::ast_types::Tense {
+                                        direction: direction41,
+                                        distance: distance41,
+                                        aspect: aspect41,
+                                    } = e;
+                                    match direction41 {
+                                        Some(e) => {
                                             *base
-                                                .add(2 * ::core::mem::size_of::<*const u8>())
-                                                .cast::<usize>() = len17;
+                                                .add(2 + 5 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (1i32) as u8;
                                             *base
-                                                .add(::core::mem::size_of::<*const u8>())
-                                                .cast::<*mut u8>() = ptr17.cast_mut();
+                                                .add(3 + 5 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (e.clone() as i32) as u8;
                                         }
-                                        V21::Unspecified => {
-                                            *base.add(0).cast::<u8>() = (4i32) as u8;
+                                        None => {
+                                            *base
+                                                .add(2 + 5 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (0i32) as u8;
                                         }
-                                        V21::Tagged(e) => {
-                                            *base.add(0).cast::<u8>() = (5i32) as u8;
-                                            let (t18_0, t18_1) = e;
+                                    };
+                                    match distance41 {
+                                        Some(e) => {
                                             *base
-                                                .add(::core::mem::size_of::<*const u8>())
-                                                .cast::<u8>() = (t18_0.clone() as i32) as u8;
+                                                .add(4 + 5 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (1i32) as u8;
                                             *base
-                                                .add(4 + 1 * ::core::mem::size_of::<*const u8>())
-                                                .cast::<i32>() = _rt::as_i32(t18_1);
+                                                .add(5 + 5 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (e.clone() as i32) as u8;
                                         }
-                                        V21::Restricted(e) => {
-                                            *base.add(0).cast::<u8>() = (6i32) as u8;
-                                            let (t19_0, t19_1) = e;
+                                        None => {
                                             *base
-                                                .add(::core::mem::size_of::<*const u8>())
-                                                .cast::<i32>() = _rt::as_i32(t19_0);
-                                            let super::super::super::super::lojban::nesy::ast_types::RelClause {
-                                                kind: kind20,
-                                                body_sentence: body_sentence20,
-                                            } = t19_1;
+                                                .add(4 + 5 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    match aspect41 {
+                                        Some(e) => {
                                             *base
-                                                .add(4 + 1 * ::core::mem::size_of::<*const u8>())
-                                                .cast::<u8>() = (kind20.clone() as i32) as u8;
+                                                .add(6 + 5 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (1i32) as u8;
                                             *base
-                                                .add(8 + 1 * ::core::mem::size_of::<*const u8>())
-                                                .cast::<i32>() = _rt::as_i32(body_sentence20);
+                                                .add(7 + 5 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (e.clone() as i32) as u8;
                                         }
-                                    }
+                                        None => {
+                                            *base
+                                                .add(6 + 5 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
                                 }
-                            }
-                            *ptr2
-                                .add(4 * ::core::mem::size_of::<*const u8>())
-                                .cast::<usize>() = len22;
-                            *ptr2
-                                .add(3 * ::core::mem::size_of::<*const u8>())
-                                .cast::<*mut u8>() = result22;
-                            let vec26 = sentences3;
-                            let len26 = vec26.len();
-                            let layout26 = _rt::alloc::Layout::from_size_align_unchecked(
-                                vec26.len() * (6 * ::core::mem::size_of::<*const u8>()),
-                                ::core::mem::size_of::<*const u8>(),
-                            );
-                            let result26 = if layout26.size() != 0 {
-                                let ptr = _rt::alloc::alloc(layout26).cast::<u8>();
-                                if ptr.is_null() {
-                                    _rt::alloc::handle_alloc_error(layout26);
+                                None => {
+                                    *base
+                                        .add(1 + 5 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (0i32) as u8;
                                 }
-                                ptr
-                            } else {
-                                ::core::ptr::null_mut()
                             };
-                            for (i, e) in vec26.into_iter().enumerate() {
-                                let base = result26
-                                    .add(i * (6 * ::core::mem::size_of::<*const u8>()));
-                                {
-                                    let super::super::super::super::lojban::nesy::ast_types::Bridi {
-                                        relation: relation23,
-                                        head_terms: head_terms23,
-                                        tail_terms: tail_terms23,
-                                        negated: negated23,
-                                    } = e;
-                                    *base.add(0).cast::<i32>() = _rt::as_i32(relation23);
-                                    let vec24 = (head_terms23).into_boxed_slice();
-                                    let ptr24 = vec24.as_ptr().cast::<u8>();
-                                    let len24 = vec24.len();
-                                    ::core::mem::forget(vec24);
+                            *base
+                                .add(8 + 5 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (match question38 {
+                                true => 1,
+                                false => 0,
+                            }) as u8;
+                            let super::super::super::super::lojban::nesy::ast_types::Span {
+                                start: start42,
+                                end: end42,
+                            } = span38;
+                            *base
+                                .add(12 + 5 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(start42);
+                            *base
+                                .add(16 + 5 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(end42);
+                        }
+                    }
+                    *ptr2.add(9 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len43;
+                    *ptr2
+                        .add(8 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = result43;
+                    let vec52 = statements4;
+                    let len52 = vec52.len();
+                    let layout52 = _rt::alloc::Layout::from_size_align_unchecked(
+                        vec52.len() * (4 * ::core::mem::size_of::<*const u8>()),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let result52 = if layout52.size() != 0 {
+                        let ptr = _rt::alloc::alloc(layout52).cast::<u8>();
+                        if ptr.is_null() {
+                            _rt::alloc::handle_alloc_error(layout52);
+                        }
+                        ptr
+                    } else {
+                        ::core::ptr::null_mut()
+                    };
+                    for (i, e) in vec52.into_iter().enumerate() {
+                        let base = result52
+                            .add(i * (4 * ::core::mem::size_of::<*const u8>()));
+                        {
+                            use super::super::super::super::lojban::nesy::ast_types::Statement as V51;
+                            match e {
+                                V51::Simple(e) => {
+                                    *base.add(0).cast::<u8>() = (0i32) as u8;
                                     *base
-                                        .add(2 * ::core::mem::size_of::<*const u8>())
-                                        .cast::<usize>() = len24;
+                                        .add(::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                V51::Connected(e) => {
+                                    *base.add(0).cast::<u8>() = (1i32) as u8;
+                                    let (t44_0, t44_1, t44_2) = e;
+                                    let super::super::super::super::lojban::nesy::ast_types::LogicalConnective {
+                                        connective: connective45,
+                                        converted: converted45,
+                                        negate_left: negate_left45,
+                                        negate_right: negate_right45,
+                                    } = t44_0;
                                     *base
                                         .add(::core::mem::size_of::<*const u8>())
-                                        .cast::<*mut u8>() = ptr24.cast_mut();
-                                    let vec25 = (tail_terms23).into_boxed_slice();
-                                    let ptr25 = vec25.as_ptr().cast::<u8>();
-                                    let len25 = vec25.len();
-                                    ::core::mem::forget(vec25);
+                                        .cast::<u8>() = (connective45.clone() as i32) as u8;
                                     *base
-                                        .add(4 * ::core::mem::size_of::<*const u8>())
-                                        .cast::<usize>() = len25;
+                                        .add(1 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (match converted45 {
+                                        true => 1,
+                                        false => 0,
+                                    }) as u8;
                                     *base
-                                        .add(3 * ::core::mem::size_of::<*const u8>())
-                                        .cast::<*mut u8>() = ptr25.cast_mut();
+                                        .add(2 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (match negate_left45 {
+                                        true => 1,
+                                        false => 0,
+                                    }) as u8;
                                     *base
-                                        .add(5 * ::core::mem::size_of::<*const u8>())
-                                        .cast::<u8>() = (match negated23 {
+                                        .add(3 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (match negate_right45 {
                                         true => 1,
                                         false => 0,
                                     }) as u8;
+                                    *base
+                                        .add(4 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(t44_1);
+                                    *base
+                                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(t44_2);
+                                }
+                                V51::Prenex(e) => {
+                                    *base.add(0).cast::<u8>() = (2i32) as u8;
+                                    let (t46_0, t46_1) = e;
+                                    let vec50 = t46_0;
+                                    let len50 = vec50.len();
+                                    let layout50 = _rt::alloc::Layout::from_size_align_unchecked(
+                                        vec50.len() * 20,
+                                        4,
+                                    );
+                                    let result50 = if layout50.size() != 0 {
+                                        let ptr = _rt::alloc::alloc(layout50).cast::<u8>();
+                                        if ptr.is_null() {
+                                            _rt::alloc::handle_alloc_error(layout50);
+                                        }
+                                        ptr
+                                    } else {
+                                        ::core::ptr::null_mut()
+                                    };
+                                    for (i, e) in vec50.into_iter().enumerate() {
+                                        let base = result50.add(i * 20);
+                                        {
+                                            use super::super::super::super::lojban::nesy::ast_types::PrenexTerm as V49;
+                                            match e {
+                                                V49::Variable(e) => {
+                                                    *base.add(0).cast::<u8>() = (0i32) as u8;
+                                                    let (t47_0, t47_1) = e;
+                                                    match t47_0 {
+                                                        Some(e) => {
+                                                            *base.add(4).cast::<u8>() = (1i32) as u8;
+                                                            let super::super::super::super::lojban::nesy::ast_types::Quantifier {
+                                                                kind: kind48,
+                                                                count: count48,
+                                                            } = e;
+                                                            *base.add(8).cast::<u8>() = (kind48.clone() as i32) as u8;
+                                                            *base.add(12).cast::<i32>() = _rt::as_i32(count48);
+                                                        }
+                                                        None => {
+                                                            *base.add(4).cast::<u8>() = (0i32) as u8;
+                                                        }
+                                                    };
+                                                    *base.add(16).cast::<i32>() = _rt::as_i32(t47_1);
+                                                }
+                                                V49::Naku => {
+                                                    *base.add(0).cast::<u8>() = (1i32) as u8;
+                                                }
+                                            }
+                                        }
+                                    }
+                                    *base
+                                        .add(2 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len50;
+                                    *base
+                                        .add(::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>() = result50;
+                                    *base
+                                        .add(3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(t46_1);
                                 }
                             }
-                            *ptr2
-                                .add(6 * ::core::mem::size_of::<*const u8>())
-                                .cast::<usize>() = len26;
-                            *ptr2
-                                .add(5 * ::core::mem::size_of::<*const u8>())
-                                .cast::<*mut u8>() = result26;
-                            let vec27 = (roots3).into_boxed_slice();
-                            let ptr27 = vec27.as_ptr().cast::<u8>();
-                            let len27 = vec27.len();
-                            ::core::mem::forget(vec27);
-                            *ptr2
-                                .add(8 * ::core::mem::size_of::<*const u8>())
-                                .cast::<usize>() = len27;
-                            *ptr2
-                                .add(7 * ::core::mem::size_of::<*const u8>())
-                                .cast::<*mut u8>() = ptr27.cast_mut();
-                        }
-                        Err(e) => {
-                            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
-                            let vec28 = (e.into_bytes()).into_boxed_slice();
-                            let ptr28 = vec28.as_ptr().cast::<u8>();
-                            let len28 = vec28.len();
-                            ::core::mem::forget(vec28);
-                            *ptr2
-                                .add(2 * ::core::mem::size_of::<*const u8>())
-                                .cast::<usize>() = len28;
-                            *ptr2
-                                .add(::core::mem::size_of::<*const u8>())
-                                .cast::<*mut u8>() = ptr28.cast_mut();
                         }
+                    }
+                    *ptr2
+                        .add(11 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len52;
+                    *ptr2
+                        .add(10 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = result52;
+                    let vec53 = (roots4).into_boxed_slice();
+                    let ptr53 = vec53.as_ptr().cast::<u8>();
+                    let len53 = vec53.len();
+                    ::core::mem::forget(vec53);
+                    *ptr2
+                        .add(13 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len53;
+                    *ptr2
+                        .add(12 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr53.cast_mut();
+                    let vec57 = diagnostics3;
+                    let len57 = vec57.len();
+                    let layout57 = _rt::alloc::Layout::from_size_align_unchecked(
+                        vec57.len() * (8 + 2 * ::core::mem::size_of::<*const u8>()),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let result57 = if layout57.size() != 0 {
+                        let ptr = _rt::alloc::alloc(layout57).cast::<u8>();
+                        if ptr.is_null() {
+                            _rt::alloc::handle_alloc_error(layout57);
+                        }
+                        ptr
+                    } else {
+                        ::core::ptr::null_mut()
                     };
+                    for (i, e) in vec57.into_iter().enumerate() {
+                        let base = result57
+                            .add(i * (8 + 2 * ::core::mem::size_of::<*const u8>()));
+                        {
+                            let Diagnostic { message: message54, span: span54 } = e;
+                            let vec55 = (message54.into_bytes()).into_boxed_slice();
+                            let ptr55 = vec55.as_ptr().cast::<u8>();
+                            let len55 = vec55.len();
+                            ::core::mem::forget(vec55);
+                            *base
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len55;
+                            *base.add(0).cast::<*mut u8>() = ptr55.cast_mut();
+                            let super::super::super::super::lojban::nesy::ast_types::Span {
+                                start: start56,
+                                end: end56,
+                            } = span54;
+                            *base
+                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(start56);
+                            *base
+                                .add(4 + 2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(end56);
+                        }
+                    }
+                    *ptr2
+                        .add(15 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len57;
+                    *ptr2
+                        .add(14 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = result57;
                     ptr2
                 }
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
                 pub unsafe fn __post_return_parse_text<T: Guest>(arg0: *mut u8) {
-                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
-                    match l0 {
-                        0 => {
-                            let l1 = *arg0
-                                .add(::core::mem::size_of::<*const u8>())
-                                .cast::<*mut u8>();
-                            let l2 = *arg0
-                                .add(2 * ::core::mem::size_of::<*const u8>())
-                                .cast::<usize>();
-                            let base14 = l1;
-                            let len14 = l2;
-                            for i in 0..len14 {
-                                let base = base14
-                                    .add(i * (4 * ::core::mem::size_of::<*const u8>()));
-                                {
-                                    let l3 = i32::from(*base.add(0).cast::<u8>());
-                                    match l3 {
-                                        0 => {
-                                            let l4 = *base
-                                                .add(::core::mem::size_of::<*const u8>())
-                                                .cast::<*mut u8>();
-                                            let l5 = *base
-                                                .add(2 * ::core::mem::size_of::<*const u8>())
-                                                .cast::<usize>();
-                                            _rt::cabi_dealloc(l4, l5, 1);
-                                        }
-                                        1 => {
-                                            let l6 = *base
+                    let l0 = *arg0.add(0).cast::<*mut u8>();
+                    let l1 = *arg0
+                        .add(::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base13 = l0;
+                    let len13 = l1;
+                    for i in 0..len13 {
+                        let base = base13
+                            .add(i * (4 * ::core::mem::size_of::<*const u8>()));
+                        {
+                            let l2 = i32::from(*base.add(0).cast::<u8>());
+                            match l2 {
+                                0 => {
+                                    let l3 = *base
+                                        .add(::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>();
+                                    let l4 = *base
+                                        .add(2 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l3, l4, 1);
+                                }
+                                1 => {
+                                    let l5 = *base
+                                        .add(::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>();
+                                    let l6 = *base
+                                        .add(2 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    let base9 = l5;
+                                    let len9 = l6;
+                                    for i in 0..len9 {
+                                        let base = base9
+                                            .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                                        {
+                                            let l7 = *base.add(0).cast::<*mut u8>();
+                                            let l8 = *base
                                                 .add(::core::mem::size_of::<*const u8>())
-                                                .cast::<*mut u8>();
-                                            let l7 = *base
-                                                .add(2 * ::core::mem::size_of::<*const u8>())
                                                 .cast::<usize>();
-                                            let base10 = l6;
-                                            let len10 = l7;
-                                            for i in 0..len10 {
-                                                let base = base10
-                                                    .add(i * (2 * ::core::mem::size_of::<*const u8>()));
-                                                {
-                                                    let l8 = *base.add(0).cast::<*mut u8>();
-                                                    let l9 = *base
-                                                        .add(::core::mem::size_of::<*const u8>())
-                                                        .cast::<usize>();
-                                                    _rt::cabi_dealloc(l8, l9, 1);
-                                                }
-                                            }
-                                            _rt::cabi_dealloc(
-                                                base10,
-                                                len10 * (2 * ::core::mem::size_of::<*const u8>()),
-                                                ::core::mem::size_of::<*const u8>(),
-                                            );
+                                            _rt::cabi_dealloc(l7, l8, 1);
                                         }
-                                        2 => {}
-                                        3 => {}
-                                        4 => {}
-                                        5 => {}
-                                        6 => {
-                                            let l11 = *base
-                                                .add(2 * ::core::mem::size_of::<*const u8>())
-                                                .cast::<*mut u8>();
-                                            let l12 = *base
-                                                .add(3 * ::core::mem::size_of::<*const u8>())
-                                                .cast::<usize>();
-                                            let base13 = l11;
-                                            let len13 = l12;
-                                            _rt::cabi_dealloc(base13, len13 * 4, 4);
-                                        }
-                                        _ => {}
                                     }
+                                    _rt::cabi_dealloc(
+                                        base9,
+                                        len9 * (2 * ::core::mem::size_of::<*const u8>()),
+                                        ::core::mem::size_of::<*const u8>(),
+                                    );
                                 }
+                                2 => {}
+                                3 => {}
+                                4 => {}
+                                5 => {}
+                                6 => {
+                                    let l10 = *base
+                                        .add(2 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>();
+                                    let l11 = *base
+                                        .add(3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    let base12 = l10;
+                                    let len12 = l11;
+                                    _rt::cabi_dealloc(base12, len12 * 4, 4);
+                                }
+                                7 => {}
+                                _ => {}
                             }
-                            _rt::cabi_dealloc(
-                                base14,
-                                len14 * (4 * ::core::mem::size_of::<*const u8>()),
-                                ::core::mem::size_of::<*const u8>(),
-                            );
-                            let l15 = *arg0
-                                .add(3 * ::core::mem::size_of::<*const u8>())
-                                .cast::<*mut u8>();
-                            let l16 = *arg0
-                                .add(4 * ::core::mem::size_of::<*const u8>())
-                                .cast::<usize>();
-                            let base24 = l15;
-                            let len24 = l16;
-                            for i in 0..len24 {
-                                let base = base24
-                                    .add(i * (8 + 2 * ::core::mem::size_of::<*const u8>()));
-                                {
-                                    let l17 = i32::from(*base.add(0).cast::<u8>());
-                                    match l17 {
+                        }
+                    }
+                    _rt::cabi_dealloc(
+                        base13,
+                        len13 * (4 * ::core::mem::size_of::<*const u8>()),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let l14 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l15 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base26 = l14;
+                    let len26 = l15;
+                    for i in 0..len26 {
+                        let base = base26
+                            .add(i * (8 + 4 * ::core::mem::size_of::<*const u8>()));
+                        {
+                            let l16 = i32::from(*base.add(0).cast::<u8>());
+                            match l16 {
+                                0 => {
+                                    let l17 = *base
+                                        .add(::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>();
+                                    let l18 = *base
+                                        .add(2 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l17, l18, 1);
+                                }
+                                1 => {}
+                                2 => {}
+                                3 => {}
+                                4 => {
+                                    let l19 = *base
+                                        .add(::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>();
+                                    let l20 = *base
+                                        .add(2 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l19, l20, 1);
+                                }
+                                5 => {
+                                    let l21 = *base
+                                        .add(::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>();
+                                    let l22 = *base
+                                        .add(2 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l21, l22, 1);
+                                }
+                                6 => {}
+                                7 => {}
+                                8 => {}
+                                9 => {
+                                    let l23 = i32::from(
+                                        *base.add(::core::mem::size_of::<*const u8>()).cast::<u8>(),
+                                    );
+                                    match l23 {
                                         0 => {
-                                            let l18 = *base
-                                                .add(::core::mem::size_of::<*const u8>())
-                                                .cast::<*mut u8>();
-                                            let l19 = *base
+                                            let l24 = *base
                                                 .add(2 * ::core::mem::size_of::<*const u8>())
-                                                .cast::<usize>();
-                                            _rt::cabi_dealloc(l18, l19, 1);
-                                        }
-                                        1 => {}
-                                        2 => {
-                                            let l20 = *base
-                                                .add(::core::mem::size_of::<*const u8>())
                                                 .cast::<*mut u8>();
-                                            let l21 = *base
-                                                .add(2 * ::core::mem::size_of::<*const u8>())
-                                                .cast::<usize>();
-                                            _rt::cabi_dealloc(l20, l21, 1);
-                                        }
-                                        3 => {
-                                            let l22 = *base
-                                                .add(::core::mem::size_of::<*const u8>())
-                                                .cast::<*mut u8>();
-                                            let l23 = *base
-                                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                            let l25 = *base
+                                                .add(3 * ::core::mem::size_of::<*const u8>())
                                                 .cast::<usize>();
-                                            _rt::cabi_dealloc(l22, l23, 1);
+                                            _rt::cabi_dealloc(l24, l25, 1);
                                         }
-                                        4 => {}
-                                        5 => {}
                                         _ => {}
                                     }
                                 }
+                                10 => {}
+                                _ => {}
                             }
-                            _rt::cabi_dealloc(
-                                base24,
-                                len24 * (8 + 2 * ::core::mem::size_of::<*const u8>()),
-                                ::core::mem::size_of::<*const u8>(),
-                            );
-                            let l25 = *arg0
-                                .add(5 * ::core::mem::size_of::<*const u8>())
+                        }
+                    }
+                    _rt::cabi_dealloc(
+                        base26,
+                        len26 * (8 + 4 * ::core::mem::size_of::<*const u8>()),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let l27 = *arg0
+                        .add(4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l28 = *arg0
+                        .add(5 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base29 = l27;
+                    let len29 = l28;
+                    _rt::cabi_dealloc(base29, len29 * 8, 4);
+                    let l30 = *arg0
+                        .add(6 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l31 = *arg0
+                        .add(7 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base32 = l30;
+                    let len32 = l31;
+                    _rt::cabi_dealloc(base32, len32 * 8, 4);
+                    let l33 = *arg0
+                        .add(8 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l34 = *arg0
+                        .add(9 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base41 = l33;
+                    let len41 = l34;
+                    for i in 0..len41 {
+                        let base = base41
+                            .add(i * (16 + 6 * ::core::mem::size_of::<*const u8>()));
+                        {
+                            let l35 = *base
+                                .add(::core::mem::size_of::<*const u8>())
                                 .cast::<*mut u8>();
-                            let l26 = *arg0
-                                .add(6 * ::core::mem::size_of::<*const u8>())
+                            let l36 = *base
+                                .add(2 * ::core::mem::size_of::<*const u8>())
                                 .cast::<usize>();
-                            let base33 = l25;
-                            let len33 = l26;
-                            for i in 0..len33 {
-                                let base = base33
-                                    .add(i * (6 * ::core::mem::size_of::<*const u8>()));
-                                {
-                                    let l27 = *base
+                            let base37 = l35;
+                            let len37 = l36;
+                            _rt::cabi_dealloc(base37, len37 * 4, 4);
+                            let l38 = *base
+                                .add(3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l39 = *base
+                                .add(4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            let base40 = l38;
+                            let len40 = l39;
+                            _rt::cabi_dealloc(base40, len40 * 4, 4);
+                        }
+                    }
+                    _rt::cabi_dealloc(
+                        base41,
+                        len41 * (16 + 6 * ::core::mem::size_of::<*const u8>()),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let l42 = *arg0
+                        .add(10 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l43 = *arg0
+                        .add(11 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base48 = l42;
+                    let len48 = l43;
+                    for i in 0..len48 {
+                        let base = base48
+                            .add(i * (4 * ::core::mem::size_of::<*const u8>()));
+                        {
+                            let l44 = i32::from(*base.add(0).cast::<u8>());
+                            match l44 {
+                                0 => {}
+                                1 => {}
+                                _ => {
+                                    let l45 = *base
                                         .add(::core::mem::size_of::<*const u8>())
                                         .cast::<*mut u8>();
-                                    let l28 = *base
+                                    let l46 = *base
                                         .add(2 * ::core::mem::size_of::<*const u8>())
                                         .cast::<usize>();
-                                    let base29 = l27;
-                                    let len29 = l28;
-                                    _rt::cabi_dealloc(base29, len29 * 4, 4);
-                                    let l30 = *base
-                                        .add(3 * ::core::mem::size_of::<*const u8>())
-                                        .cast::<*mut u8>();
-                                    let l31 = *base
-                                        .add(4 * ::core::mem::size_of::<*const u8>())
-                                        .cast::<usize>();
-                                    let base32 = l30;
-                                    let len32 = l31;
-                                    _rt::cabi_dealloc(base32, len32 * 4, 4);
+                                    let base47 = l45;
+                                    let len47 = l46;
+                                    _rt::cabi_dealloc(base47, len47 * 20, 4);
                                 }
                             }
-                            _rt::cabi_dealloc(
-                                base33,
-                                len33 * (6 * ::core::mem::size_of::<*const u8>()),
-                                ::core::mem::size_of::<*const u8>(),
-                            );
-                            let l34 = *arg0
-                                .add(7 * ::core::mem::size_of::<*const u8>())
-                                .cast::<*mut u8>();
-                            let l35 = *arg0
-                                .add(8 * ::core::mem::size_of::<*const u8>())
-                                .cast::<usize>();
-                            let base36 = l34;
-                            let len36 = l35;
-                            _rt::cabi_dealloc(base36, len36 * 4, 4);
                         }
-                        _ => {
-                            let l37 = *arg0
+                    }
+                    _rt::cabi_dealloc(
+                        base48,
+                        len48 * (4 * ::core::mem::size_of::<*const u8>()),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let l49 = *arg0
+                        .add(12 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l50 = *arg0
+                        .add(13 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base51 = l49;
+                    let len51 = l50;
+                    _rt::cabi_dealloc(base51, len51 * 4, 4);
+                    let l52 = *arg0
+                        .add(14 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l53 = *arg0
+                        .add(15 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base56 = l52;
+                    let len56 = l53;
+                    for i in 0..len56 {
+                        let base = base56
+                            .add(i * (8 + 2 * ::core::mem::size_of::<*const u8>()));
+                        {
+                            let l54 = *base.add(0).cast::<*mut u8>();
+                            let l55 = *base
                                 .add(::core::mem::size_of::<*const u8>())
-                                .cast::<*mut u8>();
-                            let l38 = *arg0
-                                .add(2 * ::core::mem::size_of::<*const u8>())
                                 .cast::<usize>();
-                            _rt::cabi_dealloc(l37, l38, 1);
+                            _rt::cabi_dealloc(l54, l55, 1);
                         }
                     }
+                    _rt::cabi_dealloc(
+                        base56,
+                        len56 * (8 + 2 * ::core::mem::size_of::<*const u8>()),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
                 }
                 pub trait Guest {
-                    fn parse_text(input: _rt::String) -> Result<AstBuffer, _rt::String>;
+                    fn parse_text(input: _rt::String, mode: LexMode) -> ParseResult;
                 }
                 #[doc(hidden)]
                 macro_rules! __export_lojban_nesy_parser_0_1_0_cabi {
                     ($ty:ident with_types_in $($path_to_types:tt)*) => {
                         const _ : () = { #[unsafe (export_name =
                         "lojban:nesy/parser@0.1.0#parse-text")] unsafe extern "C" fn
-                        export_parse_text(arg0 : * mut u8, arg1 : usize,) -> * mut u8 {
-                        unsafe { $($path_to_types)*:: _export_parse_text_cabi::<$ty >
-                        (arg0, arg1) } } #[unsafe (export_name =
-                        "cabi_post_lojban:nesy/parser@0.1.0#parse-text")] unsafe extern
-                        "C" fn _post_return_parse_text(arg0 : * mut u8,) { unsafe {
-                        $($path_to_types)*:: __post_return_parse_text::<$ty > (arg0) } }
-                        };
+                        export_parse_text(arg0 : * mut u8, arg1 : usize, arg2 : i32,) ->
+                        * mut u8 { unsafe { $($path_to_types)*::
+                        _export_parse_text_cabi::<$ty > (arg0, arg1, arg2) } } #[unsafe
+                        (export_name = "cabi_post_lojban:nesy/parser@0.1.0#parse-text")]
+                        unsafe extern "C" fn _post_return_parse_text(arg0 : * mut u8,) {
+                        unsafe { $($path_to_types)*:: __post_return_parse_text::<$ty >
+                        (arg0) } } };
                     };
                 }
                 #[doc(hidden)]
@@ -1007,10 +2080,10 @@ pub mod exports {
                 struct _RetArea(
                     [::core::mem::MaybeUninit<
                         u8,
-                    >; 9 * ::core::mem::size_of::<*const u8>()],
+                    >; 16 * ::core::mem::size_of::<*const u8>()],
                 );
                 static mut _RET_AREA: _RetArea = _RetArea(
-                    [::core::mem::MaybeUninit::uninit(); 9
+                    [::core::mem::MaybeUninit::uninit(); 16
                         * ::core::mem::size_of::<*const u8>()],
                 );
             }
@@ -1138,33 +2211,56 @@ pub(crate) use __export_parser_component_impl as export;
 )]
 #[doc(hidden)]
 #[allow(clippy::octal_escapes)]
-pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 1155] = *b"\
-\0asm\x0d\0\x01\0\0\x19\x16wit-component-encoding\x04\0\x07\xfc\x07\x01A\x02\x01\
-A\x05\x01B0\x01y\x04\0\x09selbri-id\x03\0\0\x01y\x04\0\x08sumti-id\x03\0\x02\x01\
+pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 2165] = *b"\
+\0asm\x0d\0\x01\0\0\x19\x16wit-component-encoding\x04\0\x07\xee\x0f\x01A\x02\x01\
+A\x06\x01B\\\x01y\x04\0\x09selbri-id\x03\0\0\x01y\x04\0\x08sumti-id\x03\0\x02\x01\
 m\x05\x02fa\x02fe\x02fi\x02fo\x02fu\x04\0\x09place-tag\x03\0\x04\x01m\x04\x02se\x02\
 te\x02ve\x02xe\x04\0\x0aconversion\x03\0\x06\x01m\x04\x02je\x02ja\x02jo\x02ju\x04\
-\0\x0aconnective\x03\0\x08\x01m\x05\x02lo\x02le\x02la\x05ro-lo\x05ro-le\x04\0\x05\
-gadri\x03\0\x0a\x01m\x02\x03poi\x03noi\x04\0\x0frel-clause-kind\x03\0\x0c\x01r\x02\
-\x04kind\x0d\x0dbody-sentencey\x04\0\x0arel-clause\x03\0\x0e\x01o\x02\x0b\x01\x01\
-o\x02\x05\x03\x01o\x02\x03\x0f\x01q\x07\x09pro-sumti\x01s\0\x0bdescription\x01\x10\
-\0\x04name\x01s\0\x0equoted-literal\x01s\0\x0bunspecified\0\0\x06tagged\x01\x11\0\
-\x0arestricted\x01\x12\0\x04\0\x05sumti\x03\0\x13\x01ps\x01o\x02\x01\x01\x01o\x02\
-\x07\x01\x01p\x03\x01o\x02\x01\x18\x01o\x03\x01\x09\x01\x01q\x08\x04root\x01s\0\x08\
-compound\x01\x15\0\x05tanru\x01\x16\0\x09converted\x01\x17\0\x07negated\x01\x01\0\
-\x07grouped\x01\x01\0\x09with-args\x01\x19\0\x09connected\x01\x1a\0\x04\0\x06sel\
-bri\x03\0\x1b\x01r\x04\x08relation\x01\x0ahead-terms\x18\x0atail-terms\x18\x07ne\
-gated\x7f\x04\0\x05bridi\x03\0\x1d\x01p\x1c\x01p\x14\x01p\x1e\x01py\x01r\x04\x07\
-selbris\x1f\x06sumtis\x20\x09sentences!\x05roots\"\x04\0\x0aast-buffer\x03\0#\x01\
-q\x04\x08variable\x01s\0\x08constant\x01s\0\x0bdescription\x01s\0\x0bunspecified\
-\0\0\x04\0\x0clogical-term\x03\0%\x01p&\x01o\x02s'\x01o\x02yy\x01o\x02sy\x01q\x06\
-\x09predicate\x01(\0\x08and-node\x01)\0\x07or-node\x01)\0\x08not-node\x01y\0\x0b\
-exists-node\x01*\0\x0cfor-all-node\x01*\0\x04\0\x0alogic-node\x03\0+\x01p,\x01r\x02\
-\x05nodes-\x05roots\"\x04\0\x0clogic-buffer\x03\0.\x03\0\x1blojban:nesy/ast-type\
-s@0.1.0\x05\0\x02\x03\0\0\x0aast-buffer\x01B\x05\x02\x03\x02\x01\x01\x04\0\x0aas\
-t-buffer\x03\0\0\x01j\x01\x01\x01s\x01@\x01\x05inputs\0\x02\x04\0\x0aparse-text\x01\
-\x03\x04\0\x18lojban:nesy/parser@0.1.0\x05\x02\x04\0\"lojban:nesy/parser-compone\
-nt@0.1.0\x04\0\x0b\x16\x01\0\x10parser-component\x03\0\0\0G\x09producers\x01\x0c\
-processed-by\x02\x0dwit-component\x070.227.1\x10wit-bindgen-rust\x060.41.0";
+\0\x0aconnective\x03\0\x08\x01m\x0b\x02lo\x02le\x02la\x03loi\x03lei\x03lai\x04lo\
+hi\x04lehi\x04lahi\x04lohe\x04lehe\x04\0\x05gadri\x03\0\x0a\x01m\x08\x03poi\x03n\
+oi\x03voi\x03goi\x02pe\x02ne\x02po\x04pohe\x04\0\x0frel-clause-kind\x03\0\x0c\x01\
+m\x05\x02nu\x04duhu\x02ka\x02ni\x04siho\x04\0\x10abstraction-kind\x03\0\x0e\x01m\
+\x03\x02pu\x02ca\x02ba\x04\0\x0etime-direction\x03\0\x10\x01m\x03\x02zi\x02za\x02\
+zu\x04\0\x0dtime-distance\x03\0\x12\x01m\x03\x04coha\x04cohu\x04caho\x04\0\x06as\
+pect\x03\0\x14\x01m\x03\x03all\x08at-least\x07exactly\x04\0\x0fquantifier-kind\x03\
+\0\x16\x01r\x02\x05starty\x03endy\x04\0\x04span\x03\0\x18\x01k\x11\x01k\x13\x01k\
+\x15\x01r\x03\x09direction\x1a\x08distance\x1b\x06aspect\x1c\x04\0\x05tense\x03\0\
+\x1d\x01r\x02\x04kind\x17\x05county\x04\0\x0aquantifier\x03\0\x1f\x01q\x02\x03ba\
+i\x01s\0\x04fiho\x01\x01\0\x04\0\x09modal-tag\x03\0!\x01r\x04\x0aconnective\x09\x09\
+converted\x7f\x0bnegate-left\x7f\x0cnegate-right\x7f\x04\0\x12logical-connective\
+\x03\0#\x01q\x02\x08sentence\x01y\0\x05sumti\x01\x03\0\x04\0\x0frel-clause-body\x03\
+\0%\x01r\x02\x04kind\x0d\x04body&\x04\0\x0arel-clause\x03\0'\x01k\x20\x01o\x03\x0b\
+)\x01\x01o\x02\x20\x03\x01o\x02\x05\x03\x01o\x02\x1e\x03\x01o\x02\"\x03\x01o\x02\
+\x03(\x01o\x03\x03$\x03\x01q\x0c\x09pro-sumti\x01s\0\x0brelativized\x01y\0\x0bde\
+scription\x01*\0\x0aquantified\x01+\0\x04name\x01s\0\x0equoted-literal\x01s\0\x0b\
+unspecified\0\0\x06tagged\x01,\0\x0ctense-tagged\x01-\0\x0cmodal-tagged\x01.\0\x0a\
+restricted\x01/\0\x09connected\x010\0\x04\0\x05sumti\x03\01\x01ps\x01o\x02\x01\x01\
+\x01o\x02\x07\x01\x01p\x03\x01o\x02\x016\x01o\x03\x01\x09\x01\x01o\x02\x0fy\x01q\
+\x09\x04root\x01s\0\x08compound\x013\0\x05tanru\x014\0\x09converted\x015\0\x07ne\
+gated\x01\x01\0\x07grouped\x01\x01\0\x09with-args\x017\0\x09connected\x018\0\x0b\
+abstraction\x019\0\x04\0\x06selbri\x03\0:\x01k\x1e\x01r\x07\x08relation\x01\x0ah\
+ead-terms6\x0atail-terms6\x07negated\x7f\x05tense<\x08question\x7f\x04span\x19\x04\
+\0\x05bridi\x03\0=\x01o\x02)\x03\x01q\x02\x08variable\x01?\0\x04naku\0\0\x04\0\x0b\
+prenex-term\x03\0@\x01o\x03$yy\x01p\xc1\0\x01o\x02\xc3\0y\x01q\x03\x06simple\x01\
+y\0\x09connected\x01\xc2\0\0\x06prenex\x01\xc4\0\0\x04\0\x09statement\x03\0E\x01\
+p;\x01p2\x01p\x19\x01p>\x01p\xc6\0\x01py\x01r\x07\x07selbris\xc7\0\x06sumtis\xc8\
+\0\x0cselbri-spans\xc9\0\x0bsumti-spans\xc9\0\x09sentences\xca\0\x0astatements\xcb\
+\0\x05roots\xcc\0\x04\0\x0aast-buffer\x03\0M\x01q\x05\x08variable\x01s\0\x08cons\
+tant\x01s\0\x0bdescription\x01s\0\x0bunspecified\0\0\x0bproposition\x01y\0\x04\0\
+\x0clogical-term\x03\0O\x01p\xd0\0\x01o\x02s\xd1\0\x01o\x02yy\x01o\x02sy\x01o\x02\
+\xd0\0y\x01o\x03syy\x01q\x09\x09predicate\x01\xd2\0\0\x08and-node\x01\xd3\0\0\x07\
+or-node\x01\xd3\0\0\x0cimplies-node\x01\xd3\0\0\x08not-node\x01y\0\x0bexists-nod\
+e\x01\xd4\0\0\x0cfor-all-node\x01\xd4\0\0\x07at-node\x01\xd5\0\0\x0cgeneric-node\
+\x01\xd6\0\0\x04\0\x0alogic-node\x03\0W\x01p\xd8\0\x01r\x02\x05nodes\xd9\0\x05ro\
+ots\xcc\0\x04\0\x0clogic-buffer\x03\0Z\x03\0\x1blojban:nesy/ast-types@0.1.0\x05\0\
+\x02\x03\0\0\x0aast-buffer\x02\x03\0\0\x04span\x01B\x0d\x02\x03\x02\x01\x01\x04\0\
+\x0aast-buffer\x03\0\0\x02\x03\x02\x01\x02\x04\0\x04span\x03\0\x02\x01m\x02\x06s\
+trict\x07lenient\x04\0\x08lex-mode\x03\0\x04\x01r\x02\x07messages\x04span\x03\x04\
+\0\x0adiagnostic\x03\0\x06\x01p\x07\x01r\x02\x03ast\x01\x0bdiagnostics\x08\x04\0\
+\x0cparse-result\x03\0\x09\x01@\x02\x05inputs\x04mode\x05\0\x0a\x04\0\x0aparse-t\
+ext\x01\x0b\x04\0\x18lojban:nesy/parser@0.1.0\x05\x03\x04\0\"lojban:nesy/parser-\
+component@0.1.0\x04\0\x0b\x16\x01\0\x10parser-component\x03\0\0\0G\x09producers\x01\
+\x0cprocessed-by\x02\x0dwit-component\x070.227.1\x10wit-bindgen-rust\x060.41.0";
 #[inline(never)]
 #[doc(hidden)]
 pub fn __link_custom_section_describing_imports() {
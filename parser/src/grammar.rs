@@ -3,25 +3,46 @@
 // Operates on the preprocessed NormalizedToken stream.
 // Grammar (subset of CLL, expanded incrementally):
 //
-//   text        → sentence (.i sentence)*
-//   sentence    → terms? cu? selbri tail? vau?
+//   text        → statement ((.i | ni'o) statement)*
+//   statement   → prenex statement | statement_2 (.i ijek statement_2)*
+//   prenex      → (na ku | quantifier? (da|de|di) rel_clause*)+ zo'u
+//   statement_2 → statement_3 (.i ijek bo statement_2)?
+//   statement_3 → ke statement ke'e? | gek statement_3 gi nai? statement_3
+//                | sentence
+//   sentence    → xu? terms? cu? tense? selbri tail? xu? vau?
+//                | xu? terms cu? tense?   (observative fragment; selbri is co'e)
 //   tail        → terms
-//   terms       → term+
-//   term        → place_tag? sumti
-//   sumti       → la_name | description | pro_sumti | quoted
-//                | sumti rel_clause
-//   la_name     → la cmevla+
-//   description → ro? (lo|le) selbri ku?
-//   rel_clause  → (poi|noi) sentence ku'o?
+//   terms       → (term | tense ku?)+
+//   term        → place_tag? sumti | tense sumti | modal sumti
+//   sumti       → sumti_1 (ek sumti_1)*   (left-grouping)
+//   sumti_1     → la_name | description | pro_sumti | ke'a (xi number)? | quoted
+//                | quantifier (description | da | de | di)
+//                | gek sumti gi nai? sumti | sumti_1 rel_clause
+//   la_name     → (la|lai|la'i) cmevla+ | (la|lai|la'i) selbri ku?
+//   description → gadri quantifier? possessor? selbri ku?
+//   gadri       → lo | le | loi | lei | lo'i | le'i | lo'e | le'e
+//   possessor   → sumti_1                  (≡ selbri pe sumti_1)
+//   quantifier  → ro | su'o number? | number
+//   number      → (no|pa|re|ci|vo|mu|xa|ze|bi|so)+
+//   rel_clause  → (poi|noi|voi) sentence ku'o?
+//               | (goi|pe|ne|po|po'e) sumti_1 ge'u?
+//                 (the phrase sumti takes no rel clauses of its own)
 //   selbri      → na? selbri_conn
 //   selbri_conn → selbri_2 ((je|ja|jo|ju) selbri_2)*
 //   selbri_2    → conversion? tanru
-//   tanru       → tanru_unit+   (right-grouping)
-//   tanru_unit  → brivla | ke selbri ke'e? | tanru_unit be_clause
+//   tanru       → tanru_bo+   (right-grouping)
+//   tanru_bo    → tanru_unit (bo tanru_unit)*   (binds tighter; right-grouping)
+//   tanru_unit  → brivla | ke selbri ke'e? | abstraction | tanru_unit be_clause
+//   abstraction → (nu|du'u|ka|ni|si'o) sentence kei?
 //   be_clause   → be sumti (bei sumti)* be'o?
-//   brivla      → gismu | lujvo | compound
+//   brivla      → gismu | lujvo | fu'ivla | compound | co'e | go'i | mo
 //   conversion  → se | te | ve | xe
 //   place_tag   → fa | fe | fi | fo | fu
+//   tense       → (pu|ca|ba)? (zi|za|zu)? (co'a|co'u|ca'o)?   (non-empty)
+//   modal       → BAI | fi'o selbri fe'u?
+//   gek         → se? (ge|ga|go|gu) nai?
+//   ek          → na? se? (.e|.a|.o|.u) nai?
+//   ijek        → na? se? (je|ja|jo|ju) nai?
 
 use crate::ast::*;
 use crate::lexer::LojbanToken;
@@ -30,11 +51,45 @@ use crate::preprocessor::NormalizedToken;
 /// Maximum recursion depth to prevent stack overflow on pathological input.
 const MAX_DEPTH: usize = 64;
 
+/// Selma'o BAI: modal tags derived from gismu (CLL 9.16).
+const BAI_CMAVO: &[&str] = &[
+    "ba'i", "bai", "bau", "be'i", "ca'i", "cau", "ci'e", "ci'o", "ci'u", "cu'u", "de'i", "di'o",
+    "du'i", "du'o", "fa'e", "fau", "fi'e", "ga'a", "gau", "ja'e", "ja'i", "ji'e", "ji'o", "ji'u",
+    "ka'a", "ka'i", "kai", "ki'i", "ki'u", "koi", "ku'u", "la'u", "le'a", "li'e", "ma'e", "ma'i",
+    "mau", "me'a", "me'e", "mu'i", "mu'u", "ni'i", "pa'a", "pa'u", "pi'o", "po'i", "pu'a", "pu'e",
+    "ra'a", "ra'i", "rai", "ri'a", "ri'i", "sau", "si'u", "ta'i", "tai", "ti'i", "ti'u", "tu'i",
+    "va'o", "va'u", "zau", "zu'e",
+];
+
+/// Descriptors taking a selbri; the la series also takes names.
+const DESCRIPTION_GADRI: &[(&str, Gadri)] = &[
+    ("lo", Gadri::Lo),
+    ("le", Gadri::Le),
+    ("loi", Gadri::Loi),
+    ("lei", Gadri::Lei),
+    ("lo'i", Gadri::Lohi),
+    ("le'i", Gadri::Lehi),
+    ("lo'e", Gadri::Lohe),
+    ("le'e", Gadri::Lehe),
+];
+
+/// Name descriptors (selma'o LA).
+const NAME_GADRI: &[(&str, Gadri)] = &[
+    ("la", Gadri::La),
+    ("lai", Gadri::Lai),
+    ("la'i", Gadri::Lahi),
+];
+
+/// Selma'o PA digits 0–9, in order (CLL 18.2).
+const PA_DIGITS: &[&str] = &["no", "pa", "re", "ci", "vo", "mu", "xa", "ze", "bi", "so"];
+
 /// Parse error with context.
 #[derive(Debug, Clone)]
 pub struct ParseError {
     pub message: String,
     pub position: usize,
+    /// Source span of the offending token (empty at end of input)
+    pub span: Span,
 }
 
 impl std::fmt::Display for ParseError {
@@ -47,16 +102,30 @@ impl std::fmt::Display for ParseError {
     }
 }
 
+impl ParseError {
+    /// Render against the source text with line, column and a caret
+    /// snippet:
+    ///
+    /// ```text
+    /// parse error at line 2, column 4: expected selbri
+    ///   mi ku
+    ///      ^^
+    /// ```
+    pub fn render(&self, source: &str) -> String {
+        format!("parse error at {}", self.span.render(source, &self.message))
+    }
+}
+
 /// Recursive descent parser over the preprocessed token stream.
 pub struct Parser<'a> {
-    tokens: &'a [NormalizedToken<'a>],
+    tokens: &'a [(NormalizedToken<'a>, Span)],
     pos: usize,
     depth: usize,
 }
 
 #[allow(dead_code)]
 impl<'a> Parser<'a> {
-    pub fn new(tokens: &'a [NormalizedToken<'a>]) -> Self {
+    pub fn new(tokens: &'a [(NormalizedToken<'a>, Span)]) -> Self {
         Self {
             tokens,
             pos: 0,
@@ -86,7 +155,7 @@ impl<'a> Parser<'a> {
     }
 
     fn peek(&self) -> Option<&NormalizedToken<'a>> {
-        self.tokens.get(self.pos)
+        self.tokens.get(self.pos).map(|(t, _)| t)
     }
 
     fn peek_cmavo(&self) -> Option<&'a str> {
@@ -97,17 +166,20 @@ impl<'a> Parser<'a> {
     }
 
     fn peek_is_cmavo(&self, target: &str) -> bool {
-        self.peek_cmavo().map_or(false, |s| s == target)
+        self.peek_cmavo() == Some(target)
     }
 
     fn peek_is_any_cmavo(&self, targets: &[&str]) -> bool {
-        self.peek_cmavo().map_or(false, |s| targets.contains(&s))
+        self.peek_cmavo().is_some_and(|s| targets.contains(&s))
     }
 
-    fn peek_is_gismu(&self) -> bool {
+    fn peek_is_brivla(&self) -> bool {
         matches!(
             self.peek(),
-            Some(NormalizedToken::Standard(LojbanToken::Gismu, _))
+            Some(NormalizedToken::Standard(
+                LojbanToken::Gismu | LojbanToken::Lujvo | LojbanToken::Fuhivla,
+                _
+            ))
         )
     }
 
@@ -119,7 +191,7 @@ impl<'a> Parser<'a> {
     }
 
     fn advance(&mut self) -> Option<&NormalizedToken<'a>> {
-        let t = self.tokens.get(self.pos);
+        let t = self.tokens.get(self.pos).map(|(t, _)| t);
         if t.is_some() {
             self.pos += 1;
         }
@@ -138,12 +210,12 @@ impl<'a> Parser<'a> {
 
     /// Consume any of the given cmavo. Returns the matched one, or None.
     fn eat_any_cmavo(&mut self, targets: &[&str]) -> Option<String> {
-        if let Some(s) = self.peek_cmavo() {
-            if targets.contains(&s) {
-                let owned = s.to_string();
-                self.pos += 1;
-                return Some(owned);
-            }
+        if let Some(s) = self.peek_cmavo()
+            && targets.contains(&s)
+        {
+            let owned = s.to_string();
+            self.pos += 1;
+            return Some(owned);
         }
         None
     }
@@ -171,13 +243,38 @@ impl<'a> Parser<'a> {
         self.pos = pos;
     }
 
+    // ─── Spans ────────────────────────────────────────────────
+
+    /// Span of the current token; an empty span after the last token at
+    /// end of input.
+    fn current_span(&self) -> Span {
+        match self.tokens.get(self.pos) {
+            Some((_, span)) => *span,
+            None => {
+                let end = self.tokens.last().map_or(0, |(_, span)| span.end);
+                Span::new(end, end)
+            }
+        }
+    }
+
+    /// Span covering the tokens consumed since position `start`; empty at
+    /// the current token if nothing was consumed.
+    fn span_since(&self, start: usize) -> Span {
+        if self.pos > start {
+            self.tokens[start].1.to(self.tokens[self.pos - 1].1)
+        } else {
+            let at = self.current_span().start;
+            Span::new(at, at)
+        }
+    }
+
     /// Check if the current position is at a `.i` sentence separator.
     fn at_dot_i(&self) -> bool {
         if self.pos + 1 >= self.tokens.len() {
             return false;
         }
         matches!(
-            (&self.tokens[self.pos], &self.tokens[self.pos + 1]),
+            (&self.tokens[self.pos].0, &self.tokens[self.pos + 1].0),
             (
                 NormalizedToken::Standard(LojbanToken::Pause, _),
                 NormalizedToken::Standard(LojbanToken::Cmavo, "i")
@@ -200,70 +297,369 @@ impl<'a> Parser<'a> {
         if self.at_end() {
             return true;
         }
-        if self.at_dot_i() {
+        if self.at_separator() {
             return true;
         }
         self.peek_is_any_cmavo(&["ku'o", "kei", "vau"])
     }
 
+    /// Check if the current position is at a statement separator: `.i`
+    /// or the paragraph break `ni'o`.
+    fn at_separator(&self) -> bool {
+        self.at_dot_i() || self.peek_is_cmavo("ni'o")
+    }
+
+    /// Consume a `.i` or `ni'o` separator if present.
+    fn eat_separator(&mut self) -> bool {
+        self.eat_dot_i() || self.eat_cmavo("ni'o")
+    }
+
+    /// Skip to the next statement separator (or end of input) after a
+    /// failed statement.
+    fn synchronize(&mut self) {
+        self.depth = 0;
+        while !self.at_end() && !self.at_separator() {
+            self.pos += 1;
+        }
+    }
+
     // ─── Top-level entry point ────────────────────────────────
 
-    /// Parse a complete text: sentence (.i sentence)*
+    /// Parse a complete text: statement ((.i | ni'o) statement)*
     pub fn parse_text(&mut self) -> Result<ParsedText, ParseError> {
+        let (parsed, mut errors) = self.parse_document(false);
+        if errors.is_empty() {
+            Ok(parsed)
+        } else {
+            Err(errors.remove(0))
+        }
+    }
+
+    /// Parse a complete text, recovering from errors: a statement that
+    /// fails to parse is reported and skipped up to the next `.i` or
+    /// `ni'o`. Returns every statement that parsed plus one error per
+    /// skipped region.
+    pub fn parse_text_recovering(&mut self) -> (ParsedText, Vec<ParseError>) {
+        self.parse_document(true)
+    }
+
+    fn parse_document(&mut self, recover: bool) -> (ParsedText, Vec<ParseError>) {
         let mut sentences = Vec::new();
+        let mut statements = Vec::new();
+        let mut errors = Vec::new();
 
-        while self.eat_dot_i() || self.eat_pause() {}
+        while self.eat_separator() || self.eat_pause() {}
 
         if self.at_end() {
-            return Err(self.error("empty input"));
+            errors.push(self.error("empty input"));
+        }
+
+        while !self.at_end() {
+            let saved_len = sentences.len();
+            match self.parse_statement(&mut sentences) {
+                Ok(statement) => statements.push(statement),
+                Err(e) => {
+                    // Drop bridi pushed by the failed statement
+                    sentences.truncate(saved_len);
+                    errors.push(e);
+                    if !recover {
+                        break;
+                    }
+                    self.synchronize();
+                }
+            }
+
+            while !self.at_separator() && self.eat_pause() {}
+
+            // Detect unconsumed tokens
+            if !self.at_end() && !self.at_separator() {
+                errors.push(self.unconsumed_error());
+                if !recover {
+                    break;
+                }
+                self.synchronize();
+            }
+
+            while self.eat_separator() || self.eat_pause() {}
+        }
+
+        let parsed = ParsedText {
+            sentences,
+            statements,
+        };
+        (parsed, errors)
+    }
+
+    fn unconsumed_error(&self) -> ParseError {
+        let remaining = self.tokens.len() - self.pos;
+        let next_desc = match self.peek() {
+            Some(NormalizedToken::Standard(kind, text)) => {
+                format!("{:?}(\"{}\")", kind, text)
+            }
+            Some(NormalizedToken::Quoted(text)) => format!("Quoted(\"{}\")", text),
+            Some(NormalizedToken::Glued(parts)) => format!("Glued({:?})", parts),
+            None => "???".to_string(),
+        };
+        ParseError {
+            message: format!(
+                "{} unconsumed token(s) starting with {} at position {}",
+                remaining, next_desc, self.pos
+            ),
+            position: self.pos,
+            span: self.current_span(),
+        }
+    }
+
+    // ─── Statement ────────────────────────────────────────────
+
+    /// prenex statement | statement_2 (.i ijek statement_2)*
+    ///
+    /// Each bridi is appended to `sentences`; the returned statement
+    /// refers to them by index. A plain `.i` ends the statement.
+    fn parse_statement(&mut self, sentences: &mut Vec<Bridi>) -> Result<Statement, ParseError> {
+        if let Some(terms) = self.try_parse_prenex() {
+            self.enter()?;
+            let body = self.parse_statement(sentences);
+            self.leave();
+            return Ok(Statement::Prenex {
+                terms,
+                body: Box::new(body?),
+            });
         }
 
-        sentences.push(self.parse_sentence()?);
+        let mut left = self.parse_statement_2(sentences)?;
 
         loop {
-            if !self.eat_dot_i() {
-                while self.eat_pause() {}
-                if !self.eat_dot_i() {
+            let saved = self.save();
+            let connective = match self.try_parse_dot_i_ijek() {
+                Some(c) if !self.peek_is_cmavo("bo") => c,
+                _ => {
+                    self.restore(saved);
                     break;
                 }
-            }
+            };
+            let right = self.parse_statement_2(sentences)?;
+            left = Statement::Connected {
+                left: Box::new(left),
+                connective,
+                right: Box::new(right),
+            };
+        }
+
+        Ok(left)
+    }
 
-            while self.eat_pause() {}
+    /// (na ku | quantifier? (da|de|di) rel_clause*)+ zo'u
+    fn try_parse_prenex(&mut self) -> Option<Vec<PrenexTerm>> {
+        let saved = self.save();
+        let mut terms = Vec::new();
 
-            if self.at_end() {
+        loop {
+            if self.peek_is_cmavo("na") {
+                let before = self.save();
+                self.pos += 1;
+                if self.eat_cmavo("ku") {
+                    terms.push(PrenexTerm::Naku);
+                    continue;
+                }
+                self.restore(before);
                 break;
             }
 
-            if self.at_dot_i() {
-                continue;
+            let before = self.save();
+            let quantifier = self.try_parse_quantifier();
+
+            match self.peek_cmavo() {
+                Some(v @ ("da" | "de" | "di")) => {
+                    self.pos += 1;
+                    let mut variable = Sumti::ProSumti(v.to_string());
+                    while let Some(clause) = self.try_parse_rel_clause() {
+                        variable = Sumti::Restricted {
+                            inner: Box::new(variable),
+                            clause,
+                        };
+                    }
+                    terms.push(PrenexTerm::Variable(quantifier, variable));
+                }
+                _ => {
+                    self.restore(before);
+                    break;
+                }
             }
+        }
 
-            sentences.push(self.parse_sentence()?);
+        if terms.is_empty() || !self.eat_cmavo("zo'u") {
+            self.restore(saved);
+            return None;
         }
+        Some(terms)
+    }
 
-        while self.eat_pause() {}
+    /// statement_3 (.i ijek bo statement_2)? — "A .i je B .i ja bo C"
+    /// groups as A ∧ (B ∨ C).
+    fn parse_statement_2(&mut self, sentences: &mut Vec<Bridi>) -> Result<Statement, ParseError> {
+        let left = self.parse_statement_3(sentences)?;
+
+        let saved = self.save();
+        let connective = match self.try_parse_dot_i_ijek() {
+            Some(c) if self.eat_cmavo("bo") => c,
+            _ => {
+                self.restore(saved);
+                return Ok(left);
+            }
+        };
+
+        self.enter()?;
+        let right = self.parse_statement_2(sentences);
+        self.leave();
+
+        Ok(Statement::Connected {
+            left: Box::new(left),
+            connective,
+            right: Box::new(right?),
+        })
+    }
+
+    /// ke statement ke'e? | gek statement_3 gi nai? statement_3 | sentence
+    ///
+    /// A forethought connective that does not connect two full sentences
+    /// (e.g. "ge mi gi do klama") is retried as an ordinary sentence with
+    /// a connected sumti.
+    fn parse_statement_3(&mut self, sentences: &mut Vec<Bridi>) -> Result<Statement, ParseError> {
+        let saved = self.save();
+        let saved_len = sentences.len();
 
-        // Detect unconsumed tokens
-        if !self.at_end() {
-            let remaining = self.tokens.len() - self.pos;
-            let next_desc = match self.peek() {
-                Some(NormalizedToken::Standard(kind, text)) => {
-                    format!("{:?}(\"{}\")", kind, text)
+        // "ke" may open a grouped selbri (observative) or a statement
+        // group; prefer the selbri reading when it spans the statement.
+        if self.peek_is_cmavo("ke") {
+            match self.parse_sentence() {
+                Ok(bridi)
+                    if self.at_sentence_boundary() || self.peek_is_any_cmavo(&["gi", "ke'e"]) =>
+                {
+                    sentences.push(bridi);
+                    return Ok(Statement::Simple(sentences.len() - 1));
                 }
-                Some(NormalizedToken::Quoted(text)) => format!("Quoted(\"{}\")", text),
-                Some(NormalizedToken::Glued(parts)) => format!("Glued({:?})", parts),
-                None => "???".to_string(),
-            };
-            return Err(ParseError {
-                message: format!(
-                    "{} unconsumed token(s) starting with {} at position {}",
-                    remaining, next_desc, self.pos
-                ),
-                position: self.pos,
-            });
+                _ => self.restore(saved),
+            }
+
+            self.pos += 1;
+            self.enter()?;
+            let group = self.parse_statement(sentences);
+            self.leave();
+            match group {
+                Ok(statement) => {
+                    self.eat_cmavo("ke'e");
+                    return Ok(statement);
+                }
+                Err(_) => {
+                    self.restore(saved);
+                    sentences.truncate(saved_len);
+                }
+            }
+        }
+
+        if let Some(connective) = self.try_parse_gek() {
+            match self.parse_forethought_statement(connective, sentences) {
+                Ok(statement) => return Ok(statement),
+                Err(_) => {
+                    self.restore(saved);
+                    sentences.truncate(saved_len);
+                }
+            }
+        }
+
+        let bridi = self.parse_sentence()?;
+        sentences.push(bridi);
+        Ok(Statement::Simple(sentences.len() - 1))
+    }
+
+    /// .i na? se? (je|ja|jo|ju) nai? — a sentence separator carrying a
+    /// connective. Consumes nothing unless both parts are present.
+    fn try_parse_dot_i_ijek(&mut self) -> Option<LogicalConnective> {
+        let saved = self.save();
+        if !self.eat_dot_i() {
+            return None;
+        }
+
+        let negate_left = self.eat_cmavo("na");
+        let converted = self.eat_cmavo("se");
+        let connective = match self.peek_cmavo() {
+            Some("je") => Connective::Je,
+            Some("ja") => Connective::Ja,
+            Some("jo") => Connective::Jo,
+            Some("ju") => Connective::Ju,
+            _ => {
+                self.restore(saved);
+                return None;
+            }
+        };
+        self.pos += 1;
+        let negate_right = self.eat_cmavo("nai");
+
+        Some(LogicalConnective {
+            connective,
+            converted,
+            negate_left,
+            negate_right,
+        })
+    }
+
+    fn parse_forethought_statement(
+        &mut self,
+        mut connective: LogicalConnective,
+        sentences: &mut Vec<Bridi>,
+    ) -> Result<Statement, ParseError> {
+        self.enter()?;
+
+        let left = match self.parse_statement_3(sentences) {
+            Ok(s) => s,
+            Err(e) => {
+                self.leave();
+                return Err(e);
+            }
+        };
+
+        if !self.eat_cmavo("gi") {
+            self.leave();
+            return Err(self.error("expected 'gi' in forethought connective"));
         }
+        connective.negate_right = self.eat_cmavo("nai");
+
+        let right = self.parse_statement_3(sentences);
+        self.leave();
+
+        Ok(Statement::Connected {
+            left: Box::new(left),
+            connective,
+            right: Box::new(right?),
+        })
+    }
+
+    /// se? (ge|ga|go|gu) nai? — the opening half of a forethought connective.
+    /// `negate_right` is filled in by the caller when it reaches `gi nai`.
+    fn try_parse_gek(&mut self) -> Option<LogicalConnective> {
+        let saved = self.save();
+        let converted = self.eat_cmavo("se");
+
+        let connective = match self.peek_cmavo() {
+            Some("ge") => Connective::Je,
+            Some("ga") => Connective::Ja,
+            Some("go") => Connective::Jo,
+            Some("gu") => Connective::Ju,
+            _ => {
+                self.restore(saved);
+                return None;
+            }
+        };
+        self.pos += 1;
+        let negate_left = self.eat_cmavo("nai");
 
-        Ok(ParsedText { sentences })
+        Some(LogicalConnective {
+            connective,
+            converted,
+            negate_left,
+            negate_right: false,
+        })
     }
 
     // ─── Sentence ─────────────────────────────────────────────
@@ -271,26 +667,57 @@ impl<'a> Parser<'a> {
     fn parse_sentence(&mut self) -> Result<Bridi, ParseError> {
         self.enter()?;
 
-        let head_terms = self.parse_terms();
+        let start = self.pos;
+        let question = self.eat_cmavo("xu");
+        let mut tense = None;
+        let (head_terms, head_spans) = self.parse_terms(&mut tense);
         self.eat_cmavo("cu");
 
-        let selbri = if let Some(s) = self.try_parse_selbri()? {
-            s
-        } else {
-            if head_terms.is_empty() {
+        if let Some(t) = self.try_parse_tense() {
+            tense.get_or_insert(t);
+        }
+
+        let selbri_start = self.pos;
+        let selbri = match self.try_parse_selbri() {
+            Ok(Some(s)) => s,
+            Ok(None) => {
+                self.leave();
+                if head_terms.is_empty() {
+                    return Err(self.error("expected selbri or terms"));
+                }
+                // Terms alone ("lo gerku") leave the selbri elliptical:
+                // "lo gerku cu co'e" — there's a dog. Only at a sentence
+                // boundary, so "ge mi gi do klama" stays a connected sumti.
+                if !self.at_sentence_boundary() {
+                    return Err(self.error("expected selbri"));
+                }
+                return Ok(Bridi {
+                    selbri: Selbri::Root("co'e".to_string()),
+                    head_terms,
+                    tail_terms: Vec::new(),
+                    negated: false,
+                    tense,
+                    question,
+                    span: self.span_since(start),
+                    selbri_span: self.span_since(self.pos),
+                    head_spans,
+                    tail_spans: Vec::new(),
+                });
+            }
+            Err(e) => {
                 self.leave();
-                return Err(self.error("expected selbri or terms"));
+                return Err(e);
             }
-            self.leave();
-            return Err(self.error("observative sentences not yet supported"));
         };
 
+        let selbri_span = self.span_since(selbri_start);
         let (selbri, negated) = match selbri {
             Selbri::Negated(inner) => (*inner, true),
             other => (other, false),
         };
 
-        let tail_terms = self.parse_terms();
+        let (tail_terms, tail_spans) = self.parse_terms(&mut tense);
+        let question = self.eat_cmavo("xu") || question;
         self.eat_cmavo("vau");
 
         self.leave();
@@ -299,6 +726,12 @@ impl<'a> Parser<'a> {
             head_terms,
             tail_terms,
             negated,
+            tense,
+            question,
+            span: self.span_since(start),
+            selbri_span,
+            head_spans,
+            tail_spans,
         })
     }
 
@@ -306,10 +739,16 @@ impl<'a> Parser<'a> {
         if self.pos + 1 >= self.tokens.len() {
             return false;
         }
-        match &self.tokens[self.pos + 1] {
-            NormalizedToken::Standard(LojbanToken::Gismu, _) => true,
+        match &self.tokens[self.pos + 1].0 {
+            NormalizedToken::Standard(
+                LojbanToken::Gismu | LojbanToken::Lujvo | LojbanToken::Fuhivla,
+                _,
+            ) => true,
             NormalizedToken::Standard(LojbanToken::Cmavo, s) => {
-                matches!(*s, "se" | "te" | "ve" | "xe" | "ke" | "na" | "nu")
+                matches!(
+                    *s,
+                    "se" | "te" | "ve" | "xe" | "ke" | "na" | "nu" | "du'u" | "ka" | "ni" | "si'o"
+                )
             }
             _ => false,
         }
@@ -317,17 +756,47 @@ impl<'a> Parser<'a> {
 
     // ─── Terms ────────────────────────────────────────────────
 
-    fn parse_terms(&mut self) -> Vec<Sumti> {
+    /// Parse terms and their spans; a free-standing `tense ku` sets the
+    /// sentence tense (the first one wins).
+    fn parse_terms(&mut self, tense: &mut Option<Tense>) -> (Vec<Sumti>, Vec<Span>) {
         let mut terms = Vec::new();
-        while let Some(term) = self.try_parse_term() {
-            terms.push(term);
+        let mut spans = Vec::new();
+        loop {
+            if let Some(t) = self.try_parse_tense_ku() {
+                tense.get_or_insert(t);
+                continue;
+            }
+            let start = self.pos;
+            match self.try_parse_term() {
+                Some(term) => {
+                    terms.push(term);
+                    spans.push(self.span_since(start));
+                }
+                None => break,
+            }
         }
-        terms
+        (terms, spans)
     }
 
     fn try_parse_term(&mut self) -> Option<Sumti> {
         let saved = self.save();
 
+        if let Some(tense) = self.try_parse_tense() {
+            if let Some(sumti) = self.try_parse_sumti() {
+                return Some(Sumti::TenseTagged(tense, Box::new(sumti)));
+            }
+            self.restore(saved);
+            return None;
+        }
+
+        if let Some(modal) = self.try_parse_modal() {
+            if let Some(sumti) = self.try_parse_sumti() {
+                return Some(Sumti::ModalTagged(modal, Box::new(sumti)));
+            }
+            self.restore(saved);
+            return None;
+        }
+
         if let Some(tag) = self.try_parse_place_tag() {
             if let Some(sumti) = self.try_parse_sumti() {
                 return Some(Sumti::Tagged(tag, Box::new(sumti)));
@@ -352,93 +821,301 @@ impl<'a> Parser<'a> {
         Some(tag)
     }
 
-    // ─── Sumti ────────────────────────────────────────────────
+    // ─── Modal tags ───────────────────────────────────────────
 
-    fn try_parse_sumti(&mut self) -> Option<Sumti> {
-        let mut sumti = self.try_parse_bare_sumti()?;
+    /// BAI | fi'o selbri fe'u?
+    fn try_parse_modal(&mut self) -> Option<ModalTag> {
+        let cmavo = self.peek_cmavo()?;
 
-        while let Some(clause) = self.try_parse_rel_clause() {
-            sumti = Sumti::Restricted {
-                inner: Box::new(sumti),
-                clause,
-            };
+        if BAI_CMAVO.contains(&cmavo) {
+            self.pos += 1;
+            return Some(ModalTag::Bai(cmavo.to_string()));
         }
 
-        Some(sumti)
+        if cmavo == "fi'o" {
+            let saved = self.save();
+            self.pos += 1;
+            match self.try_parse_tanru() {
+                Some(selbri) => {
+                    self.eat_cmavo("fe'u");
+                    return Some(ModalTag::Fiho(Box::new(selbri)));
+                }
+                None => {
+                    self.restore(saved);
+                    return None;
+                }
+            }
+        }
+
+        None
     }
 
-    fn try_parse_bare_sumti(&mut self) -> Option<Sumti> {
-        if self.peek_is_cmavo("la") {
-            return self.try_parse_la_name();
+    // ─── Tense ────────────────────────────────────────────────
+
+    /// (pu|ca|ba)? (zi|za|zu)? (co'a|co'u|ca'o)? — at least one part.
+    fn try_parse_tense(&mut self) -> Option<Tense> {
+        let direction = match self.peek_cmavo() {
+            Some("pu") => Some(TimeDirection::Pu),
+            Some("ca") => Some(TimeDirection::Ca),
+            Some("ba") => Some(TimeDirection::Ba),
+            _ => None,
+        };
+        if direction.is_some() {
+            self.pos += 1;
         }
 
-        // ── Phase 6b: ro lo / ro le (universal quantification) ──
-        if self.peek_is_cmavo("ro") {
-            return self.try_parse_ro_description();
+        let distance = match self.peek_cmavo() {
+            Some("zi") => Some(TimeDistance::Zi),
+            Some("za") => Some(TimeDistance::Za),
+            Some("zu") => Some(TimeDistance::Zu),
+            _ => None,
+        };
+        if distance.is_some() {
+            self.pos += 1;
         }
 
-        if self.peek_is_any_cmavo(&["lo", "le"]) {
-            return self.try_parse_description();
+        let aspect = match self.peek_cmavo() {
+            Some("co'a") => Some(Aspect::Coha),
+            Some("co'u") => Some(Aspect::Cohu),
+            Some("ca'o") => Some(Aspect::Caho),
+            _ => None,
+        };
+        if aspect.is_some() {
+            self.pos += 1;
         }
 
-        if let Some(pro) = self.try_parse_pro_sumti() {
-            return Some(pro);
+        if direction.is_none() && distance.is_none() && aspect.is_none() {
+            return None;
         }
 
-        if let Some(NormalizedToken::Quoted(s)) = self.peek() {
-            let owned = s.to_string();
-            self.pos += 1;
-            return Some(Sumti::QuotedLiteral(owned));
+        Some(Tense {
+            direction,
+            distance,
+            aspect,
+        })
+    }
+
+    /// tense ku — or a bare tense at a sentence boundary ("mi klama pu").
+    fn try_parse_tense_ku(&mut self) -> Option<Tense> {
+        let saved = self.save();
+        let tense = self.try_parse_tense()?;
+
+        if self.eat_cmavo("ku") || self.at_sentence_boundary() {
+            return Some(tense);
         }
 
+        self.restore(saved);
         None
     }
 
-    /// ro (lo|le) selbri ku? — universal quantification
-    fn try_parse_ro_description(&mut self) -> Option<Sumti> {
-        if !self.peek_is_cmavo("ro") {
-            return None;
+    // ─── Sumti ────────────────────────────────────────────────
+
+    /// sumti_1 (ek sumti_1)* — "A .e B .a C" groups as (A .e B) .a C.
+    fn try_parse_sumti(&mut self) -> Option<Sumti> {
+        let mut sumti = self.try_parse_restricted_sumti()?;
+
+        loop {
+            let saved = self.save();
+            let connective = match self.try_parse_ek() {
+                Some(c) => c,
+                None => break,
+            };
+            match self.try_parse_restricted_sumti() {
+                Some(right) => {
+                    sumti = Sumti::Connected {
+                        left: Box::new(sumti),
+                        connective,
+                        right: Box::new(right),
+                    };
+                }
+                None => {
+                    self.restore(saved);
+                    break;
+                }
+            }
         }
 
+        Some(sumti)
+    }
+
+    /// na? se? (.e|.a|.o|.u) nai? — pauses may separate the parts
+    /// ("na.e", "se.u").
+    fn try_parse_ek(&mut self) -> Option<LogicalConnective> {
         let saved = self.save();
-        self.pos += 1; // consume ro
 
-        // Must be followed by lo or le
-        let gadri = match self.peek_cmavo() {
-            Some("lo") => {
-                self.pos += 1;
-                Gadri::RoLo
-            }
-            Some("le") => {
-                self.pos += 1;
-                Gadri::RoLe
-            }
+        while self.eat_pause() {}
+        let negate_left = self.eat_cmavo("na");
+        while self.eat_pause() {}
+        let converted = self.eat_cmavo("se");
+        while self.eat_pause() {}
+
+        let connective = match self.peek_cmavo() {
+            Some("e") => Connective::Je,
+            Some("a") => Connective::Ja,
+            Some("o") => Connective::Jo,
+            Some("u") => Connective::Ju,
             _ => {
                 self.restore(saved);
                 return None;
             }
         };
+        self.pos += 1;
+        let negate_right = self.eat_cmavo("nai");
 
-        let selbri = match self.try_parse_selbri_for_description() {
-            Some(s) => s,
+        Some(LogicalConnective {
+            connective,
+            converted,
+            negate_left,
+            negate_right,
+        })
+    }
+
+    /// sumti_1: a bare sumti with any number of relative clauses.
+    fn try_parse_restricted_sumti(&mut self) -> Option<Sumti> {
+        let mut sumti = self.try_parse_bare_sumti()?;
+
+        while let Some(clause) = self.try_parse_rel_clause() {
+            sumti = Sumti::Restricted {
+                inner: Box::new(sumti),
+                clause,
+            };
+        }
+
+        Some(sumti)
+    }
+
+    fn try_parse_bare_sumti(&mut self) -> Option<Sumti> {
+        if let Some(connected) = self.try_parse_forethought_sumti() {
+            return Some(connected);
+        }
+
+        if Self::gadri(NAME_GADRI, self.peek_cmavo()).is_some() {
+            return self.try_parse_la_name();
+        }
+
+        if let Some(quantified) = self.try_parse_quantified_sumti() {
+            return Some(quantified);
+        }
+
+        if Self::gadri(DESCRIPTION_GADRI, self.peek_cmavo()).is_some() {
+            return self.try_parse_description();
+        }
+
+        if let Some(pro) = self.try_parse_pro_sumti() {
+            return Some(pro);
+        }
+
+        if let Some(NormalizedToken::Quoted(s)) = self.peek() {
+            let owned = s.to_string();
+            self.pos += 1;
+            return Some(Sumti::QuotedLiteral(owned));
+        }
+
+        None
+    }
+
+    /// gek sumti gi nai? sumti
+    fn try_parse_forethought_sumti(&mut self) -> Option<Sumti> {
+        let saved = self.save();
+        let connective = self.try_parse_gek()?;
+
+        if self.enter().is_err() {
+            self.leave();
+            self.restore(saved);
+            return None;
+        }
+        let result = self.parse_forethought_sumti_operands(connective);
+        self.leave();
+
+        if result.is_none() {
+            self.restore(saved);
+        }
+        result
+    }
+
+    fn parse_forethought_sumti_operands(
+        &mut self,
+        mut connective: LogicalConnective,
+    ) -> Option<Sumti> {
+        let left = self.try_parse_sumti()?;
+        if !self.eat_cmavo("gi") {
+            return None;
+        }
+        connective.negate_right = self.eat_cmavo("nai");
+        let right = self.try_parse_sumti()?;
+
+        Some(Sumti::Connected {
+            left: Box::new(left),
+            connective,
+            right: Box::new(right),
+        })
+    }
+
+    /// quantifier (description | da | de | di) — "ro lo gerku",
+    /// "re le mlatu", "su'o da", "no lo prenu", "ro loi gerku"
+    fn try_parse_quantified_sumti(&mut self) -> Option<Sumti> {
+        let saved = self.save();
+        let quantifier = self.try_parse_quantifier()?;
+
+        let inner = match self.peek_cmavo() {
+            c if Self::gadri(DESCRIPTION_GADRI, c).is_some() => self.try_parse_description(),
+            Some(v @ ("da" | "de" | "di")) => {
+                self.pos += 1;
+                Some(Sumti::ProSumti(v.to_string()))
+            }
+            _ => None,
+        };
+
+        match inner {
+            Some(inner) => Some(Sumti::Quantified(quantifier, Box::new(inner))),
             None => {
                 self.restore(saved);
-                return None;
+                None
             }
-        };
+        }
+    }
 
-        self.eat_cmavo("ku");
+    /// ro | su'o number? | number
+    fn try_parse_quantifier(&mut self) -> Option<Quantifier> {
+        if self.eat_cmavo("ro") {
+            return Some(Quantifier::Ro);
+        }
+        if self.eat_cmavo("su'o") {
+            return Some(Quantifier::AtLeast(self.try_parse_number().unwrap_or(1)));
+        }
+        self.try_parse_number().map(Quantifier::Exactly)
+    }
 
-        Some(Sumti::Description {
-            gadri,
-            inner: Box::new(selbri),
-        })
+    /// PA digit string, read in base ten: "pa no" → 10
+    fn try_parse_number(&mut self) -> Option<u32> {
+        let mut value: Option<u32> = None;
+        while let Some(digit) = self
+            .peek_cmavo()
+            .and_then(|c| PA_DIGITS.iter().position(|d| *d == c))
+        {
+            self.pos += 1;
+            value = Some(
+                value
+                    .unwrap_or(0)
+                    .saturating_mul(10)
+                    .saturating_add(digit as u32),
+            );
+        }
+        value
+    }
+
+    fn gadri(table: &[(&str, Gadri)], cmavo: Option<&str>) -> Option<Gadri> {
+        let cmavo = cmavo?;
+        table
+            .iter()
+            .find(|(g, _)| *g == cmavo)
+            .map(|&(_, gadri)| gadri)
     }
 
+    /// la/lai/la'i + names or a selbri. A name under lai or la'i becomes
+    /// the root of a description: the mass or set of those so named.
     fn try_parse_la_name(&mut self) -> Option<Sumti> {
-        if !self.peek_is_cmavo("la") {
-            return None;
-        }
+        let gadri = Self::gadri(NAME_GADRI, self.peek_cmavo())?;
 
         let saved = self.save();
         self.pos += 1;
@@ -456,7 +1133,8 @@ impl<'a> Parser<'a> {
             if let Some(selbri) = self.try_parse_selbri_for_description() {
                 self.eat_cmavo("ku");
                 return Some(Sumti::Description {
-                    gadri: Gadri::La,
+                    gadri,
+                    count: None,
                     inner: Box::new(selbri),
                 });
             }
@@ -464,19 +1142,27 @@ impl<'a> Parser<'a> {
             return None;
         }
 
-        Some(Sumti::Name(name_parts.join(" ")))
+        let name = name_parts.join(" ");
+        Some(match gadri {
+            Gadri::La => Sumti::Name(name),
+            _ => Sumti::Description {
+                gadri,
+                count: None,
+                inner: Box::new(Selbri::Root(name)),
+            },
+        })
     }
 
-    /// (lo|le) selbri ku?
+    /// gadri quantifier? possessor? selbri ku? — a possessor sumti
+    /// between gadri and selbri is an implicit pe: "le mi gerku" =
+    /// "le gerku pe mi".
     fn try_parse_description(&mut self) -> Option<Sumti> {
-        let gadri = match self.peek_cmavo()? {
-            "lo" => Gadri::Lo,
-            "le" => Gadri::Le,
-            _ => return None,
-        };
+        let gadri = Self::gadri(DESCRIPTION_GADRI, self.peek_cmavo())?;
 
         let saved = self.save();
         self.pos += 1;
+        let count = self.try_parse_quantifier();
+        let possessor = self.try_parse_possessor();
 
         let selbri = match self.try_parse_selbri_for_description() {
             Some(s) => s,
@@ -488,12 +1174,36 @@ impl<'a> Parser<'a> {
 
         self.eat_cmavo("ku");
 
-        Some(Sumti::Description {
+        let description = Sumti::Description {
             gadri,
+            count,
             inner: Box::new(selbri),
+        };
+        Some(match possessor {
+            Some(possessor) => Sumti::Restricted {
+                inner: Box::new(description),
+                clause: RelClause {
+                    kind: RelClauseKind::Pe,
+                    body: RelClauseBody::Sumti(Box::new(possessor)),
+                },
+            },
+            None => description,
         })
     }
 
+    /// A sumti directly after the gadri, kept only if a selbri follows it.
+    fn try_parse_possessor(&mut self) -> Option<Sumti> {
+        let saved = self.save();
+        let possessor = self.try_parse_bare_sumti()?;
+        let before_selbri = self.save();
+        if self.try_parse_selbri_for_description().is_some() {
+            self.restore(before_selbri);
+            return Some(possessor);
+        }
+        self.restore(saved);
+        None
+    }
+
     fn try_parse_selbri_for_description(&mut self) -> Option<Selbri> {
         self.try_parse_tanru()
     }
@@ -501,6 +1211,20 @@ impl<'a> Parser<'a> {
     fn try_parse_pro_sumti(&mut self) -> Option<Sumti> {
         let cmavo = self.peek_cmavo()?;
 
+        if cmavo == "ke'a" {
+            self.pos += 1;
+            let saved = self.save();
+            let depth = if self.eat_cmavo("xi") {
+                self.try_parse_number()
+            } else {
+                None
+            };
+            if depth.is_none() {
+                self.restore(saved);
+            }
+            return Some(Sumti::Relativized(depth.unwrap_or(1)));
+        }
+
         let result = match cmavo {
             "zo'e" => Sumti::Unspecified,
             "mi" | "do" | "mi'o" | "mi'a" | "ma'a" | "do'o" | "ko" => {
@@ -522,12 +1246,34 @@ impl<'a> Parser<'a> {
         let kind = match self.peek_cmavo()? {
             "poi" => RelClauseKind::Poi,
             "noi" => RelClauseKind::Noi,
+            "voi" => RelClauseKind::Voi,
+            "goi" => RelClauseKind::Goi,
+            "pe" => RelClauseKind::Pe,
+            "ne" => RelClauseKind::Ne,
+            "po" => RelClauseKind::Po,
+            "po'e" => RelClauseKind::Pohe,
             _ => return None,
         };
 
         let saved = self.save();
         self.pos += 1;
 
+        // Relative phrases attach a sumti instead of a sentence
+        if !matches!(
+            kind,
+            RelClauseKind::Poi | RelClauseKind::Noi | RelClauseKind::Voi
+        ) {
+            let Some(sumti) = self.try_parse_bare_sumti() else {
+                self.restore(saved);
+                return None;
+            };
+            self.eat_cmavo("ge'u");
+            return Some(RelClause {
+                kind,
+                body: RelClauseBody::Sumti(Box::new(sumti)),
+            });
+        }
+
         let body = match self.parse_sentence() {
             Ok(bridi) => bridi,
             Err(_) => {
@@ -540,7 +1286,7 @@ impl<'a> Parser<'a> {
 
         Some(RelClause {
             kind,
-            body: Box::new(body),
+            body: RelClauseBody::Sentence(Box::new(body)),
         })
     }
 
@@ -630,7 +1376,7 @@ impl<'a> Parser<'a> {
     fn try_parse_tanru(&mut self) -> Option<Selbri> {
         let mut units: Vec<Selbri> = Vec::new();
 
-        while let Some(unit) = self.try_parse_tanru_unit() {
+        while let Some(unit) = self.try_parse_tanru_bo() {
             units.push(unit);
         }
 
@@ -646,6 +1392,33 @@ impl<'a> Parser<'a> {
         Some(result)
     }
 
+    /// tanru_unit (bo tanru_unit)* — "sutra bo gerku zdani" groups as
+    /// (sutra gerku) zdani.
+    fn try_parse_tanru_bo(&mut self) -> Option<Selbri> {
+        let mut units = vec![self.try_parse_tanru_unit()?];
+
+        loop {
+            let saved = self.save();
+            if !self.eat_cmavo("bo") {
+                break;
+            }
+            match self.try_parse_tanru_unit() {
+                Some(unit) => units.push(unit),
+                None => {
+                    self.restore(saved);
+                    break;
+                }
+            }
+        }
+
+        let mut result = units.pop().unwrap();
+        while let Some(modifier) = units.pop() {
+            result = Selbri::Tanru(Box::new(modifier), Box::new(result));
+        }
+
+        Some(result)
+    }
+
     fn try_parse_tanru_unit(&mut self) -> Option<Selbri> {
         let mut unit = self.try_parse_tanru_unit_base()?;
 
@@ -680,10 +1453,20 @@ impl<'a> Parser<'a> {
             }
         }
 
-        if self.peek_is_gismu() {
-            if let Some(NormalizedToken::Standard(_, s)) = self.advance() {
-                return Some(Selbri::Root(s.to_string()));
-            }
+        if let Some(abstraction) = self.try_parse_abstraction() {
+            return Some(abstraction);
+        }
+
+        if self.peek_is_brivla()
+            && let Some(NormalizedToken::Standard(_, s)) = self.advance()
+        {
+            return Some(Selbri::Root(s.to_string()));
+        }
+
+        // co'e: the elliptical selbri; go'i: repeat the previous bridi;
+        // mo: ask for the selbri (GOhA)
+        if let Some(goha) = self.eat_any_cmavo(&["co'e", "go'i", "mo"]) {
+            return Some(Selbri::Root(goha));
         }
 
         if let Some(NormalizedToken::Glued(parts)) = self.peek() {
@@ -695,6 +1478,33 @@ impl<'a> Parser<'a> {
         None
     }
 
+    /// (nu|du'u|ka|ni|si'o) sentence kei?
+    fn try_parse_abstraction(&mut self) -> Option<Selbri> {
+        let kind = match self.peek_cmavo()? {
+            "nu" => AbstractionKind::Nu,
+            "du'u" => AbstractionKind::Duhu,
+            "ka" => AbstractionKind::Ka,
+            "ni" => AbstractionKind::Ni,
+            "si'o" => AbstractionKind::Siho,
+            _ => return None,
+        };
+
+        let saved = self.save();
+        self.pos += 1;
+
+        let body = match self.parse_sentence() {
+            Ok(bridi) => bridi,
+            Err(_) => {
+                self.restore(saved);
+                return None;
+            }
+        };
+
+        self.eat_cmavo("kei");
+
+        Some(Selbri::Abstraction(kind, Box::new(body)))
+    }
+
     fn parse_be_clause(&mut self, core: Selbri) -> Selbri {
         self.eat_cmavo("be");
 
@@ -728,15 +1538,27 @@ impl<'a> Parser<'a> {
         ParseError {
             message: message.to_string(),
             position: self.pos,
+            span: self.current_span(),
         }
     }
 }
 
 // ─── Public entry point ───────────────────────────────────────────
 
-pub fn parse_tokens_to_ast(tokens: &[NormalizedToken<'_>]) -> Result<ParsedText, String> {
-    let mut parser = Parser::new(tokens);
-    parser.parse_text().map_err(|e| e.to_string())
+pub fn parse_tokens(tokens: &[(NormalizedToken<'_>, Span)]) -> Result<ParsedText, ParseError> {
+    Parser::new(tokens).parse_text()
+}
+
+/// Parse with error recovery: the statements that parsed plus every
+/// error, each skipped up to the next `.i` or `ni'o`.
+pub fn parse_tokens_recovering(
+    tokens: &[(NormalizedToken<'_>, Span)],
+) -> (ParsedText, Vec<ParseError>) {
+    Parser::new(tokens).parse_text_recovering()
+}
+
+pub fn parse_tokens_to_ast(tokens: &[(NormalizedToken<'_>, Span)]) -> Result<ParsedText, String> {
+    parse_tokens(tokens).map_err(|e| e.to_string())
 }
 
 // ─── Tests ────────────────────────────────────────────────────────
@@ -771,14 +1593,23 @@ mod tests {
         NormalizedToken::Glued(parts)
     }
 
+    /// Attach placeholder spans to hand-built tokens.
+    fn unspanned<'a>(tokens: &[NormalizedToken<'a>]) -> Vec<(NormalizedToken<'a>, Span)> {
+        tokens
+            .iter()
+            .map(|t| (t.clone(), Span::default()))
+            .collect()
+    }
+
     /// Helper: parse tokens, assert success, return ParsedText
     fn parse_ok(tokens: &[NormalizedToken<'_>]) -> ParsedText {
-        parse_tokens_to_ast(tokens).unwrap_or_else(|e| panic!("unexpected parse error: {}", e))
+        parse_tokens_to_ast(&unspanned(tokens))
+            .unwrap_or_else(|e| panic!("unexpected parse error: {}", e))
     }
 
     /// Helper: parse tokens, assert failure, return error string
     fn parse_err(tokens: &[NormalizedToken<'_>]) -> String {
-        parse_tokens_to_ast(tokens).unwrap_err()
+        parse_tokens_to_ast(&unspanned(tokens)).unwrap_err()
     }
 
     // ═══════════════════════════════════════════════════════════
@@ -982,7 +1813,7 @@ mod tests {
     fn test_place_tag_backtrack_at_end() {
         // klama fa — fa with no following sumti
         let e = parse_err(&[gismu("klama"), cmavo("fa")]);
-        assert!(e.contains("unconsumed") || e.len() > 0);
+        assert!(e.contains("unconsumed") || !e.is_empty());
     }
 
     #[test]
@@ -1012,7 +1843,7 @@ mod tests {
     fn test_lo_description() {
         let r = parse_ok(&[cmavo("mi"), gismu("nelci"), cmavo("lo"), gismu("gerku")]);
         match &r.sentences[0].tail_terms[0] {
-            Sumti::Description { gadri, inner } => {
+            Sumti::Description { gadri, inner, .. } => {
                 assert_eq!(*gadri, Gadri::Lo);
                 assert_eq!(**inner, Selbri::Root("gerku".into()));
             }
@@ -1169,6 +2000,33 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_keha_with_subscript() {
+        let r = parse_ok(&[
+            cmavo("lo"),
+            gismu("gerku"),
+            cmavo("poi"),
+            cmavo("mi"),
+            gismu("nelci"),
+            cmavo("ke'a"),
+            cmavo("xi"),
+            cmavo("re"),
+            cmavo("ku'o"),
+            gismu("barda"),
+        ]);
+        match &r.sentences[0].head_terms[0] {
+            Sumti::Restricted {
+                clause:
+                    RelClause {
+                        body: RelClauseBody::Sentence(body),
+                        ..
+                    },
+                ..
+            } => assert_eq!(body.tail_terms, vec![Sumti::Relativized(2)]),
+            other => panic!("expected Restricted, got {:?}", other),
+        }
+    }
+
     #[test]
     fn test_pro_sumti_ma_question() {
         let r = parse_ok(&[cmavo("ma"), gismu("klama")]);
@@ -1346,7 +2204,7 @@ mod tests {
             tokens.push(cmavo("ke'e"));
         }
         // Should not panic/stack overflow — either parses partially or errors
-        let _ = parse_tokens_to_ast(&tokens);
+        let _ = parse_tokens_to_ast(&unspanned(&tokens));
     }
 
     // ═══════════════════════════════════════════════════════════
@@ -1552,9 +2410,16 @@ mod tests {
             gismu("barda"),
         ]);
         match &r.sentences[0].head_terms[0] {
-            Sumti::Restricted { clause, .. } => {
-                assert_eq!(clause.body.head_terms.len(), 1);
-                assert_eq!(clause.body.selbri, Selbri::Root("nelci".into()));
+            Sumti::Restricted {
+                clause:
+                    RelClause {
+                        body: RelClauseBody::Sentence(body),
+                        ..
+                    },
+                ..
+            } => {
+                assert_eq!(body.head_terms.len(), 1);
+                assert_eq!(body.selbri, Selbri::Root("nelci".into()));
             }
             other => panic!("expected Restricted with complex body, got {:?}", other),
         }
@@ -1576,7 +2441,7 @@ mod tests {
     }
 
     // ═══════════════════════════════════════════════════════════
-    // 16. UNIVERSAL QUANTIFIER (ro lo / ro le) — Phase 6b
+    // 16. UNIVERSAL QUANTIFIER (ro lo / ro le)
     // ═══════════════════════════════════════════════════════════
 
     #[test]
@@ -1590,11 +2455,15 @@ mod tests {
         ]);
         let s = &r.sentences[0];
         match &s.head_terms[0] {
-            Sumti::Description { gadri, inner } => {
-                assert_eq!(*gadri, Gadri::RoLo);
-                assert_eq!(**inner, Selbri::Root("gerku".into()));
-            }
-            other => panic!("expected RoLo, got {:?}", other),
+            Sumti::Quantified(Quantifier::Ro, desc) => match desc.as_ref() {
+                Sumti::Description {
+                    gadri: Gadri::Lo,
+                    count: None,
+                    inner,
+                } => assert_eq!(**inner, Selbri::Root("gerku".into())),
+                other => panic!("expected lo description, got {:?}", other),
+            },
+            other => panic!("expected ro lo, got {:?}", other),
         }
     }
 
@@ -1608,8 +2477,14 @@ mod tests {
             gismu("cmalu"),
         ]);
         match &r.sentences[0].head_terms[0] {
-            Sumti::Description { gadri, .. } => assert_eq!(*gadri, Gadri::RoLe),
-            other => panic!("expected RoLe, got {:?}", other),
+            Sumti::Quantified(Quantifier::Ro, desc) => assert!(matches!(
+                desc.as_ref(),
+                Sumti::Description {
+                    gadri: Gadri::Le,
+                    ..
+                }
+            )),
+            other => panic!("expected ro le, got {:?}", other),
         }
     }
 
@@ -1625,10 +2500,7 @@ mod tests {
         let s = &r.sentences[0];
         assert!(matches!(
             &s.head_terms[0],
-            Sumti::Description {
-                gadri: Gadri::RoLo,
-                ..
-            }
+            Sumti::Quantified(Quantifier::Ro, _)
         ));
         assert_eq!(s.selbri, Selbri::Root("barda".into()));
     }
@@ -1645,13 +2517,13 @@ mod tests {
             gismu("barda"),
         ]);
         match &r.sentences[0].head_terms[0] {
-            Sumti::Description {
-                gadri: Gadri::RoLo,
-                inner,
-            } => {
-                assert!(matches!(inner.as_ref(), Selbri::Tanru(_, _)));
-            }
-            other => panic!("expected RoLo tanru, got {:?}", other),
+            Sumti::Quantified(Quantifier::Ro, desc) => match desc.as_ref() {
+                Sumti::Description { inner, .. } => {
+                    assert!(matches!(inner.as_ref(), Selbri::Tanru(_, _)));
+                }
+                other => panic!("expected description, got {:?}", other),
+            },
+            other => panic!("expected ro lo tanru, got {:?}", other),
         }
     }
 
@@ -1666,16 +2538,14 @@ mod tests {
             gismu("gerku"),
         ]);
         match &r.sentences[0].tail_terms[0] {
-            Sumti::Description {
-                gadri: Gadri::RoLo, ..
-            } => {}
-            other => panic!("expected RoLo in tail, got {:?}", other),
+            Sumti::Quantified(Quantifier::Ro, _) => {}
+            other => panic!("expected ro lo in tail, got {:?}", other),
         }
     }
 
     #[test]
     fn test_ro_backtrack_without_gadri() {
-        // ro alone (not followed by lo/le) — should error
+        // ro alone (not followed by lo/le/da) — should error
         let e = parse_err(&[cmavo("ro"), gismu("klama")]);
         assert!(!e.is_empty());
     }
@@ -1697,13 +2567,11 @@ mod tests {
             Sumti::Restricted { inner, clause } => {
                 assert_eq!(clause.kind, RelClauseKind::Poi);
                 match inner.as_ref() {
-                    Sumti::Description {
-                        gadri: Gadri::RoLo, ..
-                    } => {}
-                    other => panic!("expected RoLo inside Restricted, got {:?}", other),
+                    Sumti::Quantified(Quantifier::Ro, _) => {}
+                    other => panic!("expected ro lo inside Restricted, got {:?}", other),
                 }
             }
-            other => panic!("expected Restricted(RoLo), got {:?}", other),
+            other => panic!("expected Restricted(ro lo), got {:?}", other),
         }
     }
 
@@ -1807,10 +2675,8 @@ mod tests {
             other => panic!("expected Lo head, got {:?}", other),
         }
         match &s.tail_terms[0] {
-            Sumti::Description {
-                gadri: Gadri::RoLo, ..
-            } => {}
-            other => panic!("expected RoLo tail, got {:?}", other),
+            Sumti::Quantified(Quantifier::Ro, _) => {}
+            other => panic!("expected ro lo tail, got {:?}", other),
         }
     }
 
@@ -1867,4 +2733,1046 @@ mod tests {
         let e = parse_err(&[gismu("barda"), cmavo("je")]);
         assert!(e.contains("expected") || e.contains("connective"));
     }
+
+    // ═══════════════════════════════════════════════════════════
+    // 19. ABSTRACTIONS (nu/du'u/ka/ni/si'o ... kei)
+    // ═══════════════════════════════════════════════════════════
+
+    #[test]
+    fn test_lo_nu_abstraction() {
+        // lo nu mi klama kei cu xamgu
+        let r = parse_ok(&[
+            cmavo("lo"),
+            cmavo("nu"),
+            cmavo("mi"),
+            gismu("klama"),
+            cmavo("kei"),
+            cmavo("cu"),
+            gismu("xamgu"),
+        ]);
+        let s = &r.sentences[0];
+        assert_eq!(s.selbri, Selbri::Root("xamgu".into()));
+        match &s.head_terms[0] {
+            Sumti::Description {
+                gadri: Gadri::Lo,
+                inner,
+                ..
+            } => match inner.as_ref() {
+                Selbri::Abstraction(AbstractionKind::Nu, body) => {
+                    assert_eq!(body.selbri, Selbri::Root("klama".into()));
+                    assert_eq!(body.head_terms, vec![Sumti::ProSumti("mi".into())]);
+                }
+                other => panic!("expected Abstraction(nu), got {:?}", other),
+            },
+            other => panic!("expected lo Description, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_du_u_without_kei() {
+        // mi djuno lo du'u do klama
+        let r = parse_ok(&[
+            cmavo("mi"),
+            gismu("djuno"),
+            cmavo("lo"),
+            cmavo("du'u"),
+            cmavo("do"),
+            gismu("klama"),
+        ]);
+        let s = &r.sentences[0];
+        assert_eq!(s.tail_terms.len(), 1);
+        match &s.tail_terms[0] {
+            Sumti::Description { inner, .. } => {
+                assert!(matches!(
+                    inner.as_ref(),
+                    Selbri::Abstraction(AbstractionKind::Duhu, _)
+                ));
+            }
+            other => panic!("expected Description, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_abstraction_kinds() {
+        for (word, kind) in [
+            ("nu", AbstractionKind::Nu),
+            ("du'u", AbstractionKind::Duhu),
+            ("ka", AbstractionKind::Ka),
+            ("ni", AbstractionKind::Ni),
+            ("si'o", AbstractionKind::Siho),
+        ] {
+            let r = parse_ok(&[cmavo("ti"), cmavo(word), gismu("barda")]);
+            match &r.sentences[0].selbri {
+                Selbri::Abstraction(k, _) => assert_eq!(*k, kind),
+                other => panic!("expected Abstraction for {}, got {:?}", word, other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_nested_abstraction() {
+        // lo nu lo nu mi klama kei cu rinka kei cu xamgu
+        let r = parse_ok(&[
+            cmavo("lo"),
+            cmavo("nu"),
+            cmavo("lo"),
+            cmavo("nu"),
+            cmavo("mi"),
+            gismu("klama"),
+            cmavo("kei"),
+            cmavo("cu"),
+            gismu("rinka"),
+            cmavo("kei"),
+            cmavo("cu"),
+            gismu("xamgu"),
+        ]);
+        let s = &r.sentences[0];
+        assert_eq!(s.selbri, Selbri::Root("xamgu".into()));
+        match &s.head_terms[0] {
+            Sumti::Description { inner, .. } => match inner.as_ref() {
+                Selbri::Abstraction(_, body) => {
+                    assert_eq!(body.selbri, Selbri::Root("rinka".into()));
+                    assert!(matches!(
+                        &body.head_terms[0],
+                        Sumti::Description { inner, .. }
+                            if matches!(inner.as_ref(), Selbri::Abstraction(..))
+                    ));
+                }
+                other => panic!("expected Abstraction, got {:?}", other),
+            },
+            other => panic!("expected Description, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_nu_backtrack_without_sentence() {
+        // lo nu — nothing to abstract; description must not parse
+        let e = parse_err(&[cmavo("mi"), gismu("djuno"), cmavo("lo"), cmavo("nu")]);
+        assert!(e.contains("unconsumed"));
+    }
+
+    // ═══════════════════════════════════════════════════════════
+    // 20. TENSE (PU / ZI / ZAhO)
+    // ═══════════════════════════════════════════════════════════
+
+    #[test]
+    fn test_selbri_tense_pu() {
+        // mi pu klama
+        let r = parse_ok(&[cmavo("mi"), cmavo("pu"), gismu("klama")]);
+        let s = &r.sentences[0];
+        assert_eq!(s.selbri, Selbri::Root("klama".into()));
+        assert_eq!(s.head_terms, vec![Sumti::ProSumti("mi".into())]);
+        assert_eq!(
+            s.tense,
+            Some(Tense {
+                direction: Some(TimeDirection::Pu),
+                distance: None,
+                aspect: None,
+            })
+        );
+    }
+
+    #[test]
+    fn test_no_tense_by_default() {
+        let r = parse_ok(&[cmavo("mi"), gismu("klama")]);
+        assert_eq!(r.sentences[0].tense, None);
+    }
+
+    #[test]
+    fn test_compound_tense_after_cu() {
+        // mi cu ba zu co'a klama
+        let r = parse_ok(&[
+            cmavo("mi"),
+            cmavo("cu"),
+            cmavo("ba"),
+            cmavo("zu"),
+            cmavo("co'a"),
+            gismu("klama"),
+        ]);
+        assert_eq!(
+            r.sentences[0].tense,
+            Some(Tense {
+                direction: Some(TimeDirection::Ba),
+                distance: Some(TimeDistance::Zu),
+                aspect: Some(Aspect::Coha),
+            })
+        );
+    }
+
+    #[test]
+    fn test_aspect_only() {
+        // mi ca'o klama
+        let r = parse_ok(&[cmavo("mi"), cmavo("ca'o"), gismu("klama")]);
+        let tense = r.sentences[0].tense.unwrap();
+        assert_eq!(tense.direction, None);
+        assert_eq!(tense.aspect, Some(Aspect::Caho));
+    }
+
+    #[test]
+    fn test_tense_ku_sentence_tag() {
+        // pu ku mi klama
+        let r = parse_ok(&[cmavo("pu"), cmavo("ku"), cmavo("mi"), gismu("klama")]);
+        let s = &r.sentences[0];
+        assert_eq!(s.head_terms, vec![Sumti::ProSumti("mi".into())]);
+        assert_eq!(s.tense.unwrap().direction, Some(TimeDirection::Pu));
+    }
+
+    #[test]
+    fn test_trailing_bare_tense() {
+        // mi klama ca
+        let r = parse_ok(&[cmavo("mi"), gismu("klama"), cmavo("ca")]);
+        let s = &r.sentences[0];
+        assert!(s.tail_terms.is_empty());
+        assert_eq!(s.tense.unwrap().direction, Some(TimeDirection::Ca));
+    }
+
+    #[test]
+    fn test_tense_sumti_tcita() {
+        // mi klama pu lo nu do klama
+        let r = parse_ok(&[
+            cmavo("mi"),
+            gismu("klama"),
+            cmavo("pu"),
+            cmavo("lo"),
+            cmavo("nu"),
+            cmavo("do"),
+            gismu("klama"),
+        ]);
+        let s = &r.sentences[0];
+        assert_eq!(s.tense, None);
+        match &s.tail_terms[0] {
+            Sumti::TenseTagged(tense, inner) => {
+                assert_eq!(tense.direction, Some(TimeDirection::Pu));
+                assert!(matches!(inner.as_ref(), Sumti::Description { .. }));
+            }
+            other => panic!("expected TenseTagged, got {:?}", other),
+        }
+    }
+
+    // ═══════════════════════════════════════════════════════════
+    // 21. MODAL TAGS (BAI / fi'o ... fe'u)
+    // ═══════════════════════════════════════════════════════════
+
+    #[test]
+    fn test_bai_tagged_sumti() {
+        // mi klama ri'a lo nu carvi
+        let r = parse_ok(&[
+            cmavo("mi"),
+            gismu("klama"),
+            cmavo("ri'a"),
+            cmavo("lo"),
+            cmavo("nu"),
+            gismu("carvi"),
+        ]);
+        let s = &r.sentences[0];
+        assert_eq!(s.tail_terms.len(), 1);
+        match &s.tail_terms[0] {
+            Sumti::ModalTagged(ModalTag::Bai(tag), inner) => {
+                assert_eq!(tag, "ri'a");
+                assert!(matches!(inner.as_ref(), Sumti::Description { .. }));
+            }
+            other => panic!("expected ModalTagged(ri'a), got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_bai_in_head_position() {
+        // pi'o lo tanxe mi bevri
+        let r = parse_ok(&[
+            cmavo("pi'o"),
+            cmavo("lo"),
+            gismu("tanxe"),
+            cmavo("mi"),
+            gismu("bevri"),
+        ]);
+        let s = &r.sentences[0];
+        assert_eq!(s.head_terms.len(), 2);
+        assert!(matches!(
+            &s.head_terms[0],
+            Sumti::ModalTagged(ModalTag::Bai(t), _) if t == "pi'o"
+        ));
+    }
+
+    #[test]
+    fn test_fiho_tagged_sumti() {
+        // mi tavla fi'o bangu fe'u la .lojban.
+        let r = parse_ok(&[
+            cmavo("mi"),
+            gismu("tavla"),
+            cmavo("fi'o"),
+            gismu("bangu"),
+            cmavo("fe'u"),
+            cmavo("la"),
+            pause(),
+            cmevla("lojban"),
+            pause(),
+        ]);
+        match &r.sentences[0].tail_terms[0] {
+            Sumti::ModalTagged(ModalTag::Fiho(selbri), inner) => {
+                assert_eq!(selbri.as_ref(), &Selbri::Root("bangu".into()));
+                assert_eq!(inner.as_ref(), &Sumti::Name("lojban".into()));
+            }
+            other => panic!("expected ModalTagged(fi'o), got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_bai_without_sumti_backtracks() {
+        // mi klama mu'i — dangling tag is not consumed
+        let e = parse_err(&[cmavo("mi"), gismu("klama"), cmavo("mu'i")]);
+        assert!(e.contains("unconsumed"));
+    }
+
+    // ═══════════════════════════════════════════════════════════
+    // 22. FORETHOUGHT CONNECTIVES (ge/ga/go/gu ... gi)
+    // ═══════════════════════════════════════════════════════════
+
+    #[test]
+    fn test_ganai_sentence() {
+        // ga nai carvi gi lo loldi cu cilmo
+        let r = parse_ok(&[
+            cmavo("ga"),
+            cmavo("nai"),
+            gismu("carvi"),
+            cmavo("gi"),
+            cmavo("lo"),
+            gismu("loldi"),
+            cmavo("cu"),
+            gismu("cilmo"),
+        ]);
+        assert_eq!(r.sentences.len(), 2);
+        assert_eq!(r.statements.len(), 1);
+        match &r.statements[0] {
+            Statement::Connected {
+                left,
+                connective,
+                right,
+            } => {
+                assert_eq!(connective.connective, Connective::Ja);
+                assert!(connective.negate_left);
+                assert!(!connective.negate_right);
+                assert!(!connective.converted);
+                assert_eq!(left.as_ref(), &Statement::Simple(0));
+                assert_eq!(right.as_ref(), &Statement::Simple(1));
+            }
+            other => panic!("expected Connected statement, got {:?}", other),
+        }
+        assert_eq!(r.sentences[0].selbri, Selbri::Root("carvi".into()));
+        assert_eq!(r.sentences[1].selbri, Selbri::Root("cilmo".into()));
+    }
+
+    #[test]
+    fn test_se_gek_with_ginai() {
+        // se go mi klama gi nai do klama
+        let r = parse_ok(&[
+            cmavo("se"),
+            cmavo("go"),
+            cmavo("mi"),
+            gismu("klama"),
+            cmavo("gi"),
+            cmavo("nai"),
+            cmavo("do"),
+            gismu("klama"),
+        ]);
+        match &r.statements[0] {
+            Statement::Connected { connective, .. } => {
+                assert_eq!(connective.connective, Connective::Jo);
+                assert!(connective.converted);
+                assert!(!connective.negate_left);
+                assert!(connective.negate_right);
+            }
+            other => panic!("expected Connected statement, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_nested_forethought_sentences() {
+        // ge ga mi klama gi do klama gi mi citka
+        let r = parse_ok(&[
+            cmavo("ge"),
+            cmavo("ga"),
+            cmavo("mi"),
+            gismu("klama"),
+            cmavo("gi"),
+            cmavo("do"),
+            gismu("klama"),
+            cmavo("gi"),
+            cmavo("mi"),
+            gismu("citka"),
+        ]);
+        assert_eq!(r.sentences.len(), 3);
+        match &r.statements[0] {
+            Statement::Connected { left, right, .. } => {
+                assert!(matches!(left.as_ref(), Statement::Connected { .. }));
+                assert_eq!(right.as_ref(), &Statement::Simple(2));
+            }
+            other => panic!("expected Connected statement, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_forethought_sumti_in_head() {
+        // ge mi gi do klama — falls back to a single sentence
+        let r = parse_ok(&[
+            cmavo("ge"),
+            cmavo("mi"),
+            cmavo("gi"),
+            cmavo("do"),
+            gismu("klama"),
+        ]);
+        assert_eq!(r.sentences.len(), 1);
+        assert_eq!(r.statements, vec![Statement::Simple(0)]);
+        match &r.sentences[0].head_terms[0] {
+            Sumti::Connected {
+                left,
+                connective,
+                right,
+            } => {
+                assert_eq!(connective.connective, Connective::Je);
+                assert_eq!(left.as_ref(), &Sumti::ProSumti("mi".into()));
+                assert_eq!(right.as_ref(), &Sumti::ProSumti("do".into()));
+            }
+            other => panic!("expected Connected sumti, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_forethought_sumti_in_tail() {
+        // mi klama gu lo zarci gi nai lo zdani
+        let r = parse_ok(&[
+            cmavo("mi"),
+            gismu("klama"),
+            cmavo("gu"),
+            cmavo("lo"),
+            gismu("zarci"),
+            cmavo("gi"),
+            cmavo("nai"),
+            cmavo("lo"),
+            gismu("zdani"),
+        ]);
+        let s = &r.sentences[0];
+        assert_eq!(s.tail_terms.len(), 1);
+        match &s.tail_terms[0] {
+            Sumti::Connected { connective, .. } => {
+                assert_eq!(connective.connective, Connective::Ju);
+                assert!(connective.negate_right);
+            }
+            other => panic!("expected Connected sumti, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_forethought_missing_gi() {
+        // ge mi klama — no gi
+        let e = parse_err(&[cmavo("ge"), cmavo("mi"), gismu("klama")]);
+        assert!(!e.is_empty());
+    }
+
+    // ═══════════════════════════════════════════════════════════
+    // 23. AFTERTHOUGHT SUMTI CONNECTIVES (.e/.a/.o/.u)
+    // ═══════════════════════════════════════════════════════════
+
+    #[test]
+    fn test_sumti_e_in_head() {
+        // mi .e do klama
+        let r = parse_ok(&[
+            cmavo("mi"),
+            pause(),
+            cmavo("e"),
+            cmavo("do"),
+            gismu("klama"),
+        ]);
+        let s = &r.sentences[0];
+        assert_eq!(s.head_terms.len(), 1);
+        match &s.head_terms[0] {
+            Sumti::Connected {
+                left,
+                connective,
+                right,
+            } => {
+                assert_eq!(connective.connective, Connective::Je);
+                assert!(!connective.negate_left && !connective.negate_right);
+                assert_eq!(left.as_ref(), &Sumti::ProSumti("mi".into()));
+                assert_eq!(right.as_ref(), &Sumti::ProSumti("do".into()));
+            }
+            other => panic!("expected Connected sumti, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_sumti_na_se_nai_forms() {
+        // mi klama do na.a ti se.onai ta
+        let r = parse_ok(&[
+            cmavo("mi"),
+            gismu("klama"),
+            cmavo("do"),
+            cmavo("na"),
+            pause(),
+            cmavo("a"),
+            cmavo("ti"),
+            cmavo("se"),
+            pause(),
+            cmavo("o"),
+            cmavo("nai"),
+            cmavo("ta"),
+        ]);
+        match &r.sentences[0].tail_terms[0] {
+            Sumti::Connected {
+                left, connective, ..
+            } => {
+                // Left-grouping: (do na.a ti) se.onai ta
+                assert_eq!(connective.connective, Connective::Jo);
+                assert!(connective.converted);
+                assert!(connective.negate_right);
+                assert!(!connective.negate_left);
+                match left.as_ref() {
+                    Sumti::Connected { connective, .. } => {
+                        assert_eq!(connective.connective, Connective::Ja);
+                        assert!(connective.negate_left);
+                        assert!(!connective.converted);
+                    }
+                    other => panic!("expected inner Connected sumti, got {:?}", other),
+                }
+            }
+            other => panic!("expected Connected sumti, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_sumti_connective_after_rel_clause() {
+        // lo gerku poi blanu ku'o .u lo mlatu cu klama
+        let r = parse_ok(&[
+            cmavo("lo"),
+            gismu("gerku"),
+            cmavo("poi"),
+            gismu("blanu"),
+            cmavo("ku'o"),
+            pause(),
+            cmavo("u"),
+            cmavo("lo"),
+            gismu("mlatu"),
+            cmavo("cu"),
+            gismu("klama"),
+        ]);
+        match &r.sentences[0].head_terms[0] {
+            Sumti::Connected {
+                left, connective, ..
+            } => {
+                assert_eq!(connective.connective, Connective::Ju);
+                assert!(matches!(left.as_ref(), Sumti::Restricted { .. }));
+            }
+            other => panic!("expected Connected sumti, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_dangling_sumti_connective_not_consumed() {
+        // mi klama do .e — nothing after .e
+        let e = parse_err(&[
+            cmavo("mi"),
+            gismu("klama"),
+            cmavo("do"),
+            pause(),
+            cmavo("e"),
+        ]);
+        assert!(e.contains("unconsumed"));
+    }
+
+    // ═══════════════════════════════════════════════════════════
+    // 24. SENTENCE CONNECTIVES (.i je/ja/jo/ju, bo, ke)
+    // ═══════════════════════════════════════════════════════════
+
+    /// Tokens for ".i" + the given cmavo.
+    fn dot_i_with(words: &[&'static str]) -> Vec<NormalizedToken<'static>> {
+        let mut tokens = vec![pause(), cmavo("i")];
+        tokens.extend(words.iter().map(|w| cmavo(w)));
+        tokens
+    }
+
+    #[test]
+    fn test_ije_joins_into_one_statement() {
+        // mi klama .i je do citka
+        let mut tokens = vec![cmavo("mi"), gismu("klama")];
+        tokens.extend(dot_i_with(&["je"]));
+        tokens.extend([cmavo("do"), gismu("citka")]);
+        let r = parse_ok(&tokens);
+        assert_eq!(r.sentences.len(), 2);
+        assert_eq!(r.statements.len(), 1);
+        match &r.statements[0] {
+            Statement::Connected {
+                left,
+                connective,
+                right,
+            } => {
+                assert_eq!(connective.connective, Connective::Je);
+                assert_eq!(left.as_ref(), &Statement::Simple(0));
+                assert_eq!(right.as_ref(), &Statement::Simple(1));
+            }
+            other => panic!("expected Connected statement, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_ijek_modifiers() {
+        // mi klama .i na se ja nai do citka
+        let mut tokens = vec![cmavo("mi"), gismu("klama")];
+        tokens.extend(dot_i_with(&["na", "se", "ja", "nai"]));
+        tokens.extend([cmavo("do"), gismu("citka")]);
+        let r = parse_ok(&tokens);
+        match &r.statements[0] {
+            Statement::Connected { connective, .. } => {
+                assert_eq!(connective.connective, Connective::Ja);
+                assert!(connective.negate_left);
+                assert!(connective.converted);
+                assert!(connective.negate_right);
+            }
+            other => panic!("expected Connected statement, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_ijek_left_grouping_and_plain_i() {
+        // mi klama .i ja do klama .i ju mi citka .i do citka
+        let mut tokens = vec![cmavo("mi"), gismu("klama")];
+        tokens.extend(dot_i_with(&["ja"]));
+        tokens.extend([cmavo("do"), gismu("klama")]);
+        tokens.extend(dot_i_with(&["ju"]));
+        tokens.extend([cmavo("mi"), gismu("citka")]);
+        tokens.extend(dot_i_with(&[]));
+        tokens.extend([cmavo("do"), gismu("citka")]);
+        let r = parse_ok(&tokens);
+        assert_eq!(r.sentences.len(), 4);
+        assert_eq!(r.statements.len(), 2);
+        match &r.statements[0] {
+            Statement::Connected {
+                left, connective, ..
+            } => {
+                assert_eq!(connective.connective, Connective::Ju);
+                assert!(matches!(
+                    left.as_ref(),
+                    Statement::Connected { connective, .. } if connective.connective == Connective::Ja
+                ));
+            }
+            other => panic!("expected Connected statement, got {:?}", other),
+        }
+        assert_eq!(r.statements[1], Statement::Simple(3));
+    }
+
+    #[test]
+    fn test_ijek_bo_binds_tighter() {
+        // mi klama .i je do klama .i ja bo mi citka
+        let mut tokens = vec![cmavo("mi"), gismu("klama")];
+        tokens.extend(dot_i_with(&["je"]));
+        tokens.extend([cmavo("do"), gismu("klama")]);
+        tokens.extend(dot_i_with(&["ja", "bo"]));
+        tokens.extend([cmavo("mi"), gismu("citka")]);
+        let r = parse_ok(&tokens);
+        match &r.statements[0] {
+            Statement::Connected {
+                left,
+                connective,
+                right,
+            } => {
+                assert_eq!(connective.connective, Connective::Je);
+                assert_eq!(left.as_ref(), &Statement::Simple(0));
+                assert!(matches!(
+                    right.as_ref(),
+                    Statement::Connected { connective, .. } if connective.connective == Connective::Ja
+                ));
+            }
+            other => panic!("expected Connected statement, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_sentence_ke_grouping() {
+        // mi klama .i je ke do klama .i ja mi citka ke'e
+        let mut tokens = vec![cmavo("mi"), gismu("klama")];
+        tokens.extend(dot_i_with(&["je", "ke"]));
+        tokens.extend([cmavo("do"), gismu("klama")]);
+        tokens.extend(dot_i_with(&["ja"]));
+        tokens.extend([cmavo("mi"), gismu("citka"), cmavo("ke'e")]);
+        let r = parse_ok(&tokens);
+        assert_eq!(r.statements.len(), 1);
+        match &r.statements[0] {
+            Statement::Connected { left, right, .. } => {
+                assert_eq!(left.as_ref(), &Statement::Simple(0));
+                assert!(matches!(right.as_ref(), Statement::Connected { .. }));
+            }
+            other => panic!("expected Connected statement, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_tanru_bo_grouping() {
+        // mi sutra bo gerku zdani → (sutra gerku) zdani
+        let r = parse_ok(&[
+            cmavo("mi"),
+            gismu("sutra"),
+            cmavo("bo"),
+            gismu("gerku"),
+            gismu("zdani"),
+        ]);
+        assert_eq!(
+            r.sentences[0].selbri,
+            Selbri::Tanru(
+                Box::new(Selbri::Tanru(
+                    Box::new(Selbri::Root("sutra".into())),
+                    Box::new(Selbri::Root("gerku".into())),
+                )),
+                Box::new(Selbri::Root("zdani".into())),
+            )
+        );
+    }
+
+    // ═══════════════════════════════════════════════════════════
+    // 25. PRENEX (... zo'u)
+    // ═══════════════════════════════════════════════════════════
+
+    #[test]
+    fn test_prenex_quantified_variables() {
+        // ro da su'o de zo'u da prami de
+        let r = parse_ok(&[
+            cmavo("ro"),
+            cmavo("da"),
+            cmavo("su'o"),
+            cmavo("de"),
+            cmavo("zo'u"),
+            cmavo("da"),
+            gismu("prami"),
+            cmavo("de"),
+        ]);
+        assert_eq!(r.sentences.len(), 1);
+        match &r.statements[0] {
+            Statement::Prenex { terms, body } => {
+                assert_eq!(
+                    terms,
+                    &vec![
+                        PrenexTerm::Variable(Some(Quantifier::Ro), Sumti::ProSumti("da".into())),
+                        PrenexTerm::Variable(
+                            Some(Quantifier::AtLeast(1)),
+                            Sumti::ProSumti("de".into())
+                        ),
+                    ]
+                );
+                assert_eq!(body.as_ref(), &Statement::Simple(0));
+            }
+            other => panic!("expected Prenex, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_prenex_naku_and_bare_variable() {
+        // na ku da zo'u da klama
+        let r = parse_ok(&[
+            cmavo("na"),
+            cmavo("ku"),
+            cmavo("da"),
+            cmavo("zo'u"),
+            cmavo("da"),
+            gismu("klama"),
+        ]);
+        match &r.statements[0] {
+            Statement::Prenex { terms, .. } => {
+                assert_eq!(terms[0], PrenexTerm::Naku);
+                assert_eq!(
+                    terms[1],
+                    PrenexTerm::Variable(None, Sumti::ProSumti("da".into()))
+                );
+            }
+            other => panic!("expected Prenex, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_prenex_restricted_variable() {
+        // ro da poi gerku zo'u da blanu
+        let r = parse_ok(&[
+            cmavo("ro"),
+            cmavo("da"),
+            cmavo("poi"),
+            gismu("gerku"),
+            cmavo("zo'u"),
+            cmavo("da"),
+            gismu("blanu"),
+        ]);
+        // The rel clause body is not a top-level sentence
+        assert_eq!(r.sentences.len(), 1);
+        match &r.statements[0] {
+            Statement::Prenex { terms, .. } => match &terms[0] {
+                PrenexTerm::Variable(Some(Quantifier::Ro), Sumti::Restricted { inner, clause }) => {
+                    assert_eq!(inner.as_ref(), &Sumti::ProSumti("da".into()));
+                    assert_eq!(clause.kind, RelClauseKind::Poi);
+                }
+                other => panic!("expected restricted ro da, got {:?}", other),
+            },
+            other => panic!("expected Prenex, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_prenex_scopes_over_ijek() {
+        // ro da zo'u da klama .i je da citka
+        let mut tokens = vec![
+            cmavo("ro"),
+            cmavo("da"),
+            cmavo("zo'u"),
+            cmavo("da"),
+            gismu("klama"),
+        ];
+        tokens.extend(dot_i_with(&["je"]));
+        tokens.extend([cmavo("da"), gismu("citka")]);
+        let r = parse_ok(&tokens);
+        assert_eq!(r.statements.len(), 1);
+        match &r.statements[0] {
+            Statement::Prenex { body, .. } => {
+                assert!(matches!(body.as_ref(), Statement::Connected { .. }));
+            }
+            other => panic!("expected Prenex, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_da_without_zohu_is_plain_sumti() {
+        // da klama — no zo'u, so an ordinary sentence
+        let r = parse_ok(&[cmavo("da"), gismu("klama")]);
+        assert_eq!(r.statements, vec![Statement::Simple(0)]);
+    }
+
+    // ═══════════════════════════════════════════════════════════
+    // 26. QUANTIFIED SUMTI (re lo gerku, lo ci gerku, no da)
+    // ═══════════════════════════════════════════════════════════
+
+    #[test]
+    fn test_numeric_outer_quantifier() {
+        // re lo gerku cu blabi
+        let r = parse_ok(&[
+            cmavo("re"),
+            cmavo("lo"),
+            gismu("gerku"),
+            cmavo("cu"),
+            gismu("blabi"),
+        ]);
+        match &r.sentences[0].head_terms[0] {
+            Sumti::Quantified(Quantifier::Exactly(2), desc) => assert!(matches!(
+                desc.as_ref(),
+                Sumti::Description {
+                    gadri: Gadri::Lo,
+                    count: None,
+                    ..
+                }
+            )),
+            other => panic!("expected re lo, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_multi_digit_and_suho_quantifiers() {
+        // mi nelci su'o pa no lo mlatu
+        let r = parse_ok(&[
+            cmavo("mi"),
+            gismu("nelci"),
+            cmavo("su'o"),
+            cmavo("pa"),
+            cmavo("no"),
+            cmavo("lo"),
+            gismu("mlatu"),
+        ]);
+        assert!(matches!(
+            &r.sentences[0].tail_terms[0],
+            Sumti::Quantified(Quantifier::AtLeast(10), _)
+        ));
+    }
+
+    #[test]
+    fn test_inner_quantifier() {
+        // lo ci gerku cu blabi
+        let r = parse_ok(&[
+            cmavo("lo"),
+            cmavo("ci"),
+            gismu("gerku"),
+            cmavo("cu"),
+            gismu("blabi"),
+        ]);
+        match &r.sentences[0].head_terms[0] {
+            Sumti::Description {
+                gadri: Gadri::Lo,
+                count: Some(Quantifier::Exactly(3)),
+                inner,
+            } => assert_eq!(**inner, Selbri::Root("gerku".into())),
+            other => panic!("expected lo ci gerku, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_outer_and_inner_quantifiers() {
+        // pa le re mlatu cu cmalu
+        let r = parse_ok(&[
+            cmavo("pa"),
+            cmavo("le"),
+            cmavo("re"),
+            gismu("mlatu"),
+            cmavo("cu"),
+            gismu("cmalu"),
+        ]);
+        match &r.sentences[0].head_terms[0] {
+            Sumti::Quantified(Quantifier::Exactly(1), desc) => assert!(matches!(
+                desc.as_ref(),
+                Sumti::Description {
+                    gadri: Gadri::Le,
+                    count: Some(Quantifier::Exactly(2)),
+                    ..
+                }
+            )),
+            other => panic!("expected pa le re, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_quantified_mass_descriptor() {
+        // ro loi gerku cu bajra
+        let r = parse_ok(&[
+            cmavo("ro"),
+            cmavo("loi"),
+            gismu("gerku"),
+            cmavo("cu"),
+            gismu("bajra"),
+        ]);
+        match &r.sentences[0].head_terms[0] {
+            Sumti::Quantified(Quantifier::Ro, desc) => assert!(matches!(
+                desc.as_ref(),
+                Sumti::Description {
+                    gadri: Gadri::Loi,
+                    ..
+                }
+            )),
+            other => panic!("expected ro loi, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_quantified_set_descriptor() {
+        // pa lo'i mlatu cu cmalu
+        let r = parse_ok(&[
+            cmavo("pa"),
+            cmavo("lo'i"),
+            gismu("mlatu"),
+            cmavo("cu"),
+            gismu("cmalu"),
+        ]);
+        match &r.sentences[0].head_terms[0] {
+            Sumti::Quantified(Quantifier::Exactly(1), desc) => assert!(matches!(
+                desc.as_ref(),
+                Sumti::Description {
+                    gadri: Gadri::Lohi,
+                    ..
+                }
+            )),
+            other => panic!("expected pa lo'i, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_quantified_da() {
+        // no da klama
+        let r = parse_ok(&[cmavo("no"), cmavo("da"), gismu("klama")]);
+        assert_eq!(
+            r.sentences[0].head_terms[0],
+            Sumti::Quantified(
+                Quantifier::Exactly(0),
+                Box::new(Sumti::ProSumti("da".into()))
+            )
+        );
+    }
+
+    #[test]
+    fn test_numeric_prenex_quantifier() {
+        // re da zo'u da klama
+        let r = parse_ok(&[
+            cmavo("re"),
+            cmavo("da"),
+            cmavo("zo'u"),
+            cmavo("da"),
+            gismu("klama"),
+        ]);
+        match &r.statements[0] {
+            Statement::Prenex { terms, .. } => assert_eq!(
+                terms[0],
+                PrenexTerm::Variable(Some(Quantifier::Exactly(2)), Sumti::ProSumti("da".into()))
+            ),
+            other => panic!("expected Prenex, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_quantifier_without_sumti_errors() {
+        // re klama — a number alone is not a sumti
+        let e = parse_err(&[cmavo("re"), gismu("klama")]);
+        assert!(!e.is_empty());
+    }
+
+    // ═══════════════════════════════════════════════════════════
+    // 27. OBSERVATIVES (selbri-less and x1-less sentences)
+    // ═══════════════════════════════════════════════════════════
+
+    #[test]
+    fn test_terms_only_sentence_is_coe() {
+        // lo gerku — there's a dog!
+        let r = parse_ok(&[cmavo("lo"), gismu("gerku")]);
+        let s = &r.sentences[0];
+        assert_eq!(s.selbri, Selbri::Root("co'e".into()));
+        assert_eq!(s.head_terms.len(), 1);
+        assert!(s.tail_terms.is_empty());
+    }
+
+    #[test]
+    fn test_terms_only_sentence_before_dot_i() {
+        // la .alis. .i mi klama
+        let r = parse_ok(&[
+            cmavo("la"),
+            pause(),
+            cmevla("alis"),
+            pause(),
+            pause(),
+            cmavo("i"),
+            cmavo("mi"),
+            gismu("klama"),
+        ]);
+        assert_eq!(r.sentences.len(), 2);
+        assert_eq!(r.sentences[0].selbri, Selbri::Root("co'e".into()));
+        assert_eq!(r.sentences[1].selbri, Selbri::Root("klama".into()));
+    }
+
+    #[test]
+    fn test_explicit_coe_selbri() {
+        // lo gerku cu co'e
+        let r = parse_ok(&[cmavo("lo"), gismu("gerku"), cmavo("cu"), cmavo("co'e")]);
+        assert_eq!(r.sentences[0].selbri, Selbri::Root("co'e".into()));
+    }
+
+    #[test]
+    fn test_selbri_only_observative() {
+        // carvi — it's raining
+        let r = parse_ok(&[gismu("carvi")]);
+        let s = &r.sentences[0];
+        assert_eq!(s.selbri, Selbri::Root("carvi".into()));
+        assert!(s.head_terms.is_empty() && s.tail_terms.is_empty());
+    }
+
+    #[test]
+    fn test_terms_only_needs_sentence_boundary() {
+        // ge mi gi do klama — "mi" is not a fragment before "gi"
+        let r = parse_ok(&[
+            cmavo("ge"),
+            cmavo("mi"),
+            cmavo("gi"),
+            cmavo("do"),
+            gismu("klama"),
+        ]);
+        assert_eq!(r.statements, vec![Statement::Simple(0)]);
+        assert!(matches!(
+            &r.sentences[0].head_terms[0],
+            Sumti::Connected { .. }
+        ));
+    }
 }
@@ -1,62 +1,255 @@
-use logos::Logos;
+use logos::{Logos, SpannedIter};
+use std::iter::Peekable;
 
+use crate::ast::Span;
+use crate::morphology::{self, WordClass};
+
+/// First pass: cut the canonical text into pause-free words. Morphology
+/// decides what each word is and where run-together words split.
 #[derive(Logos, Debug, PartialEq, Clone)]
-#[logos(skip r"[ \t\n\f]+")] // Whitespace is ignored, but explicit pauses (.) are not
-pub enum LojbanToken {
-    // --------------------------------------------------
-    // Metalinguistic Operators (Must be intercepted)
-    // --------------------------------------------------
-    #[token("si")]
-    EraseWord,
+#[logos(skip r"[ \t\r\n\f]+")] // Whitespace is ignored, but explicit pauses (.) are not
+enum RawToken {
+    #[regex(r"[a-z']+")]
+    Word,
+
+    #[token(".")]
+    Pause,
+}
+
+/// How `tokenize` treats input that is not Lojban.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LexMode {
+    /// Stop at the first invalid segment.
+    Strict,
+    /// Report each invalid segment, skip it and keep lexing.
+    Lenient,
+}
+
+/// A segment of the input that is not valid Lojban.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LexError {
+    pub message: String,
+    pub span: Span,
+}
+
+impl LexError {
+    /// Render against the source text with line, column and a caret
+    /// snippet, like `ParseError::render`.
+    pub fn render(&self, source: &str) -> String {
+        format!("lex error at {}", self.span.render(source, &self.message))
+    }
+}
 
-    #[token("sa")]
-    EraseClass,
+/// Input rewritten to canonical orthography (CLL 3.3, 4.9): capitals
+/// (marking irregular stress) are lowercased, `h` becomes `'`, and the
+/// `,` syllable separator is dropped. Keeps a byte map back to the
+/// original text so token spans point at what the user wrote.
+pub struct Source {
+    text: String,
+    /// Original byte offset of each byte of `text`, plus one entry for
+    /// the end of input.
+    offsets: Vec<usize>,
+}
 
-    #[token("su")]
-    EraseStream,
+impl Source {
+    pub fn new(input: &str) -> Self {
+        let mut text = String::with_capacity(input.len());
+        let mut offsets = Vec::with_capacity(input.len() + 1);
+        for (i, c) in input.char_indices() {
+            let c = match c {
+                ',' => continue,
+                'h' | 'H' => '\'',
+                c => c.to_ascii_lowercase(),
+            };
+            text.push(c);
+            offsets.extend(i..i + c.len_utf8());
+        }
+        offsets.push(input.len());
+        Self { text, offsets }
+    }
 
-    #[token("zo")]
-    QuoteNext,
+    /// The canonical text.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
 
-    #[token("zoi")]
-    QuoteDelimited,
+    /// Map a byte range of the canonical text back to the original input.
+    fn span(&self, start: usize, end: usize) -> Span {
+        if end > start {
+            Span::new(self.offsets[start], self.offsets[end - 1] + 1)
+        } else {
+            Span::new(self.offsets[start], self.offsets[start])
+        }
+    }
+}
 
-    #[token("zei")]
-    GlueWords,
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum LojbanToken {
+    // --------------------------------------------------
+    // Metalinguistic Operators (Must be intercepted)
+    // --------------------------------------------------
+    EraseWord,      // si
+    EraseClass,     // sa
+    EraseStream,    // su
+    QuoteNext,      // zo
+    QuoteDelimited, // zoi
+    GlueWords,      // zei
 
     // --------------------------------------------------
-    // Morphological Classes
+    // Morphological Classes (CLL chapter 4, see morphology.rs)
     // --------------------------------------------------
 
-    // Gismu: CVCCV or CCVCV structure (simplified for demonstration,
-    // real phonotactics check for specific valid consonant clusters)
-    #[regex(r"([bcdfghjklmnprstvxz][aeiou][bcdfghjklmnprstvxz][bcdfghjklmnprstvxz][aeiou])|([bcdfghjklmnprstvxz][bcdfghjklmnprstvxz][aeiou][bcdfghjklmnprstvxz][aeiou])")]
+    // Gismu: CVCCV with a permissible medial pair, or CCVCV with a
+    // permissible initial pair.
     Gismu,
 
+    // Lujvo: a valid rafsi decomposition, e.g. brivla = bri + vla.
+    Lujvo,
+
+    // Fu'ivla: any other valid brivla (e.g. spageti).
+    Fuhivla,
+
     // Cmevla (Names): Must end in a consonant.
-    // No dots in body — dots are pause tokens and must not be consumed as part of a word.
-    // Final character is explicitly a Lojban consonant, not a negated vowel class.
-    #[regex(r"[a-zA-Z']+[bcdfghjklmnprstvxzBCDFGHJKLMNPRSTVXZ]")]
     Cmevla,
 
-    // Cmavo (Structure words): 1-to-many vowels, optionally preceded by one consonant
-    // This acts as a fallback for structural words not explicitly tokenized above.
-    #[regex(r"[bcdfghjklmnprstvxz]?[aeiouy']+")]
+    // Cmavo (Structure words): run-together compounds are already split.
     Cmavo,
 
     // Explicit Pauses
-    #[token(".")]
     Pause,
+
+    // A zoi delimiter or the text it quotes: never validated as Lojban.
+    Foreign,
+}
+
+impl LojbanToken {
+    fn from_word(class: WordClass, text: &str) -> Self {
+        match class {
+            WordClass::Gismu => LojbanToken::Gismu,
+            WordClass::Lujvo => LojbanToken::Lujvo,
+            WordClass::Fuhivla => LojbanToken::Fuhivla,
+            WordClass::Cmevla => LojbanToken::Cmevla,
+            WordClass::Cmavo => match text {
+                "si" => LojbanToken::EraseWord,
+                "sa" => LojbanToken::EraseClass,
+                "su" => LojbanToken::EraseStream,
+                "zo" => LojbanToken::QuoteNext,
+                "zoi" => LojbanToken::QuoteDelimited,
+                "zei" => LojbanToken::GlueWords,
+                _ => LojbanToken::Cmavo,
+            },
+        }
+    }
 }
 
-/// Tokenizer wrapper that yields zero-copy string slices paired with their classification.
-pub fn tokenize(input: &str) -> Vec<(LojbanToken, &str)> {
-    let mut lex = LojbanToken::lexer(input);
+/// Tokenizer wrapper that yields zero-copy slices of the canonical text
+/// paired with their classification and the byte span in the original
+/// input. Characters outside the Lojban alphabet and words that are not
+/// valid Lojban morphology are reported as `LexError`s; `mode` decides
+/// whether lexing stops there. The text a `zoi` quotes is not Lojban, so
+/// it is passed through as `Foreign` tokens without validation.
+pub fn tokenize(source: &Source, mode: LexMode) -> (Vec<(LojbanToken, &str, Span)>, Vec<LexError>) {
+    let mut lex = RawToken::lexer(&source.text).spanned().peekable();
     let mut tokens = Vec::new();
+    let mut errors = Vec::new();
+
+    while let Some((raw, range)) = lex.next() {
+        let slice = &source.text[range.clone()];
+        let error = match raw {
+            Ok(RawToken::Pause) => {
+                tokens.push((
+                    LojbanToken::Pause,
+                    slice,
+                    source.span(range.start, range.end),
+                ));
+                continue;
+            }
+            Ok(RawToken::Word) => match morphology::split_word(slice) {
+                Some(words) => {
+                    for (class, text) in words {
+                        // Split words are subslices of `slice`
+                        let start =
+                            range.start + (text.as_ptr() as usize - slice.as_ptr() as usize);
+                        let span = source.span(start, start + text.len());
+                        tokens.push((LojbanToken::from_word(class, text), text, span));
+                    }
+                    if tokens.last().map(|(t, _, _)| *t) == Some(LojbanToken::QuoteDelimited) {
+                        quote_foreign(source, &mut lex, &mut tokens);
+                    }
+                    continue;
+                }
+                None => LexError {
+                    message: format!("\"{}\" is not a valid Lojban word", slice),
+                    span: source.span(range.start, range.end),
+                },
+            },
+            Err(()) => {
+                // One error per run of unrecognised characters
+                let mut end = range.end;
+                while let Some((Err(()), next)) = lex.peek() {
+                    if next.start != end {
+                        break;
+                    }
+                    end = next.end;
+                    lex.next();
+                }
+                LexError {
+                    message: format!(
+                        "unrecognised characters \"{}\"",
+                        &source.text[range.start..end]
+                    ),
+                    span: source.span(range.start, end),
+                }
+            }
+        };
+
+        errors.push(error);
+        if mode == LexMode::Strict {
+            break;
+        }
+    }
 
-    while let Some(Ok(token)) = lex.next() {
-        tokens.push((token, lex.slice()));
+    (tokens, errors)
+}
+
+/// After `zoi`: the delimiter word, the text up to the delimiter's next
+/// occurrence (without the pauses around it) and the closing delimiter,
+/// as `Foreign` tokens. Unterminated, the text runs to the end of input.
+fn quote_foreign<'s>(
+    source: &'s Source,
+    lex: &mut Peekable<SpannedIter<'s, RawToken>>,
+    tokens: &mut Vec<(LojbanToken, &'s str, Span)>,
+) {
+    let Some((Ok(RawToken::Word), open)) = lex.next_if(|(t, _)| *t == Ok(RawToken::Word)) else {
+        return;
+    };
+    let delimiter = &source.text[open.clone()];
+    tokens.push((
+        LojbanToken::Foreign,
+        delimiter,
+        source.span(open.start, open.end),
+    ));
+
+    let mut close = None;
+    for (raw, range) in lex.by_ref() {
+        if raw == Ok(RawToken::Word) && &source.text[range.clone()] == delimiter {
+            close = Some(range);
+            break;
+        }
     }
 
-    tokens
+    let end = close.as_ref().map_or(source.text.len(), |c| c.start);
+    let text = source.text[open.end..end].trim_matches(|c: char| c == '.' || c.is_whitespace());
+    if !text.is_empty() {
+        let start = text.as_ptr() as usize - source.text.as_ptr() as usize;
+        let span = source.span(start, start + text.len());
+        tokens.push((LojbanToken::Foreign, text, span));
+    }
+    if let Some(close) = close {
+        tokens.push((
+            LojbanToken::Foreign,
+            delimiter,
+            source.span(close.start, close.end),
+        ));
+    }
 }
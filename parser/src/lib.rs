@@ -1,7 +1,7 @@
 // parser/src/lib.rs
 //
 // WASM component entry point. Pipeline:
-//   1. Lex (Logos DFA)
+//   1. Lex (Logos DFA + CLL morphology)
 //   2. Preprocess (metalinguistic resolution)
 //   3. Parse (recursive descent)
 //   4. Flatten (tree AST → index-based WIT buffer)
@@ -11,26 +11,58 @@ pub mod ast;
 mod bindings;
 pub mod grammar;
 pub mod lexer;
+pub mod morphology;
 pub mod preprocessor;
+pub mod selmaho;
 
-use bindings::exports::lojban::nesy::parser::Guest;
+use bindings::exports::lojban::nesy::parser::{Diagnostic, Guest, LexMode, ParseResult};
 use bindings::lojban::nesy::ast_types as wit;
 
 struct ParserComponent;
 
 impl Guest for ParserComponent {
-    fn parse_text(input: String) -> Result<wit::AstBuffer, String> {
+    fn parse_text(input: String, mode: LexMode) -> ParseResult {
         // 1. Lex into morphological classification stream
-        let raw_tokens = crate::lexer::tokenize(&input);
+        let source = crate::lexer::Source::new(&input);
+        let mode = match mode {
+            LexMode::Strict => crate::lexer::LexMode::Strict,
+            LexMode::Lenient => crate::lexer::LexMode::Lenient,
+        };
+        let (raw_tokens, lex_errors) = crate::lexer::tokenize(&source, mode);
+
+        let mut diagnostics: Vec<(ast::Span, String)> = lex_errors
+            .iter()
+            .map(|e| (e.span, e.render(&input)))
+            .collect();
+        if mode == crate::lexer::LexMode::Strict && !diagnostics.is_empty() {
+            return Self::result(Flattener::new().buffer, diagnostics);
+        }
 
         // 2. Resolve metalinguistic operators (si/sa/su/zo/zoi/zei)
         let normalized = crate::preprocessor::preprocess(raw_tokens.into_iter(), &input);
 
-        // 3. Recursive descent parse
-        let parsed = crate::grammar::parse_tokens_to_ast(&normalized)?;
+        // 3. Recursive descent parse, skipping statements that fail
+        let (parsed, parse_errors) = crate::grammar::parse_tokens_recovering(&normalized);
+        diagnostics.extend(parse_errors.iter().map(|e| (e.span, e.render(&input))));
 
         // 4. Flatten tree AST → index-based WIT buffer
-        Ok(Flattener::flatten(parsed))
+        Self::result(Flattener::flatten(parsed), diagnostics)
+    }
+}
+
+impl ParserComponent {
+    fn result(ast: wit::AstBuffer, mut diagnostics: Vec<(ast::Span, String)>) -> ParseResult {
+        diagnostics.sort_by_key(|(span, _)| span.start);
+        ParseResult {
+            ast,
+            diagnostics: diagnostics
+                .into_iter()
+                .map(|(span, message)| Diagnostic {
+                    message,
+                    span: Flattener::convert_span(span),
+                })
+                .collect(),
+        }
     }
 }
 
@@ -40,8 +72,13 @@ impl Guest for ParserComponent {
 /// Each recursive AST node is pushed into the appropriate array and
 /// referenced by index from its parent.
 ///
-/// Top-level sentences are recorded in `buffer.roots`.
-/// Rel clause bodies are stored in `buffer.sentences` but are NOT roots.
+/// Every bridi lives in `buffer.sentences`. Top-level bridi are reached
+/// through `buffer.statements`, whose top-level entries are recorded in
+/// `buffer.roots`. Rel clause and abstraction bodies are stored in
+/// `buffer.sentences` but are never referenced by a statement.
+///
+/// `selbri_spans` and `sumti_spans` grow in lockstep with `selbris` and
+/// `sumtis`; nested nodes inherit the span of the node that contains them.
 struct Flattener {
     buffer: wit::AstBuffer,
 }
@@ -52,7 +89,10 @@ impl Flattener {
             buffer: wit::AstBuffer {
                 selbris: Vec::new(),
                 sumtis: Vec::new(),
+                selbri_spans: Vec::new(),
+                sumti_spans: Vec::new(),
                 sentences: Vec::new(),
+                statements: Vec::new(),
                 roots: Vec::new(),
             },
         }
@@ -60,58 +100,165 @@ impl Flattener {
 
     fn flatten(parsed: ast::ParsedText) -> wit::AstBuffer {
         let mut f = Self::new();
-        for bridi in parsed.sentences {
-            f.push_bridi(bridi);
-            // The top-level sentence is always the LAST entry added by
-            // push_bridi.  Rel clause bodies are pushed earlier (during
-            // push_sumti for Restricted variants), so they have lower indices.
-            let root_idx = (f.buffer.sentences.len() - 1) as u32;
+        let bridi_ids: Vec<u32> = parsed
+            .sentences
+            .into_iter()
+            .map(|bridi| f.push_bridi(bridi))
+            .collect();
+        for statement in parsed.statements {
+            let root_idx = f.push_statement(statement, &bridi_ids);
             f.buffer.roots.push(root_idx);
         }
         f.buffer
     }
 
+    // ─── Statement ───────────────────────────────────────────
+
+    fn push_statement(&mut self, statement: ast::Statement, bridi_ids: &[u32]) -> u32 {
+        let wit_statement = match statement {
+            ast::Statement::Simple(idx) => wit::Statement::Simple(bridi_ids[idx]),
+
+            ast::Statement::Connected {
+                left,
+                connective,
+                right,
+            } => {
+                let l_id = self.push_statement(*left, bridi_ids);
+                let r_id = self.push_statement(*right, bridi_ids);
+                wit::Statement::Connected((
+                    Self::convert_logical_connective(connective),
+                    l_id,
+                    r_id,
+                ))
+            }
+
+            ast::Statement::Prenex { terms, body } => {
+                let wit_terms = terms
+                    .into_iter()
+                    .map(|term| match term {
+                        ast::PrenexTerm::Variable(quantifier, variable) => {
+                            let wit_quantifier = quantifier.map(Self::convert_quantifier);
+                            // Prenex variables carry no source span
+                            let variable_id = self.push_sumti(variable, ast::Span::default());
+                            wit::PrenexTerm::Variable((wit_quantifier, variable_id))
+                        }
+                        ast::PrenexTerm::Naku => wit::PrenexTerm::Naku,
+                    })
+                    .collect();
+                let body_id = self.push_statement(*body, bridi_ids);
+                wit::Statement::Prenex((wit_terms, body_id))
+            }
+        };
+
+        let id = self.buffer.statements.len() as u32;
+        self.buffer.statements.push(wit_statement);
+        id
+    }
+
+    fn convert_quantifier(quantifier: ast::Quantifier) -> wit::Quantifier {
+        let (kind, count) = match quantifier {
+            ast::Quantifier::Ro => (wit::QuantifierKind::All, 0),
+            ast::Quantifier::AtLeast(n) => (wit::QuantifierKind::AtLeast, n),
+            ast::Quantifier::Exactly(n) => (wit::QuantifierKind::Exactly, n),
+        };
+        wit::Quantifier { kind, count }
+    }
+
+    fn convert_connective(connective: ast::Connective) -> wit::Connective {
+        match connective {
+            ast::Connective::Je => wit::Connective::Je,
+            ast::Connective::Ja => wit::Connective::Ja,
+            ast::Connective::Jo => wit::Connective::Jo,
+            ast::Connective::Ju => wit::Connective::Ju,
+        }
+    }
+
+    fn convert_logical_connective(connective: ast::LogicalConnective) -> wit::LogicalConnective {
+        wit::LogicalConnective {
+            connective: Self::convert_connective(connective.connective),
+            converted: connective.converted,
+            negate_left: connective.negate_left,
+            negate_right: connective.negate_right,
+        }
+    }
+
     // ─── Bridi ───────────────────────────────────────────────
 
-    fn push_bridi(&mut self, bridi: ast::Bridi) {
-        let relation = self.push_selbri(bridi.selbri);
+    /// Push a bridi (after its nested rel clause / abstraction bodies)
+    /// and return its index in `sentences`.
+    fn push_bridi(&mut self, bridi: ast::Bridi) -> u32 {
+        let relation = self.push_selbri(bridi.selbri, bridi.selbri_span);
 
         let head_terms: Vec<u32> = bridi
             .head_terms
             .into_iter()
-            .map(|s| self.push_sumti(s))
+            .zip(bridi.head_spans)
+            .map(|(s, span)| self.push_sumti(s, span))
             .collect();
 
         let tail_terms: Vec<u32> = bridi
             .tail_terms
             .into_iter()
-            .map(|s| self.push_sumti(s))
+            .zip(bridi.tail_spans)
+            .map(|(s, span)| self.push_sumti(s, span))
             .collect();
 
+        let id = self.buffer.sentences.len() as u32;
         self.buffer.sentences.push(wit::Bridi {
             relation,
             head_terms,
             tail_terms,
             negated: bridi.negated,
+            tense: bridi.tense.map(Self::convert_tense),
+            question: bridi.question,
+            span: Self::convert_span(bridi.span),
         });
+        id
+    }
+
+    fn convert_span(span: ast::Span) -> wit::Span {
+        wit::Span {
+            start: span.start as u32,
+            end: span.end as u32,
+        }
+    }
+
+    fn convert_tense(tense: ast::Tense) -> wit::Tense {
+        wit::Tense {
+            direction: tense.direction.map(|d| match d {
+                ast::TimeDirection::Pu => wit::TimeDirection::Pu,
+                ast::TimeDirection::Ca => wit::TimeDirection::Ca,
+                ast::TimeDirection::Ba => wit::TimeDirection::Ba,
+            }),
+            distance: tense.distance.map(|d| match d {
+                ast::TimeDistance::Zi => wit::TimeDistance::Zi,
+                ast::TimeDistance::Za => wit::TimeDistance::Za,
+                ast::TimeDistance::Zu => wit::TimeDistance::Zu,
+            }),
+            aspect: tense.aspect.map(|a| match a {
+                ast::Aspect::Coha => wit::Aspect::Coha,
+                ast::Aspect::Cohu => wit::Aspect::Cohu,
+                ast::Aspect::Caho => wit::Aspect::Caho,
+            }),
+        }
     }
 
     // ─── Selbri ──────────────────────────────────────────────
 
-    fn push_selbri(&mut self, selbri: ast::Selbri) -> u32 {
+    fn push_selbri(&mut self, selbri: ast::Selbri, span: ast::Span) -> u32 {
         let wit_selbri = match selbri {
             ast::Selbri::Root(s) => wit::Selbri::Root(s),
 
             ast::Selbri::Compound(parts) => wit::Selbri::Compound(parts),
 
             ast::Selbri::Tanru(modifier, head) => {
-                let m_id = self.push_selbri(*modifier);
-                let h_id = self.push_selbri(*head);
+                let m_id = self.push_selbri(*modifier, span);
+                let h_id = self.push_selbri(*head, span);
                 wit::Selbri::Tanru((m_id, h_id))
             }
 
             ast::Selbri::Converted(conv, inner) => {
-                let inner_id = self.push_selbri(*inner);
+                let inner_id = self.push_selbri(*inner, span);
                 let wit_conv = match conv {
                     ast::Conversion::Se => wit::Conversion::Se,
                     ast::Conversion::Te => wit::Conversion::Te,
@@ -122,18 +269,19 @@ impl Flattener {
             }
 
             ast::Selbri::Negated(inner) => {
-                let inner_id = self.push_selbri(*inner);
+                let inner_id = self.push_selbri(*inner, span);
                 wit::Selbri::Negated(inner_id)
             }
 
             ast::Selbri::Grouped(inner) => {
-                let inner_id = self.push_selbri(*inner);
+                let inner_id = self.push_selbri(*inner, span);
                 wit::Selbri::Grouped(inner_id)
             }
 
             ast::Selbri::WithArgs { core, args } => {
-                let core_id = self.push_selbri(*core);
-                let arg_ids: Vec<u32> = args.into_iter().map(|s| self.push_sumti(s)).collect();
+                let core_id = self.push_selbri(*core, span);
+                let arg_ids: Vec<u32> =
+                    args.into_iter().map(|s| self.push_sumti(s, span)).collect();
                 wit::Selbri::WithArgs((core_id, arg_ids))
             }
 
@@ -142,39 +290,65 @@ impl Flattener {
                 connective,
                 right,
             } => {
-                let l_id = self.push_selbri(*left);
-                let r_id = self.push_selbri(*right);
-                let wit_conn = match connective {
-                    ast::Connective::Je => wit::Connective::Je,
-                    ast::Connective::Ja => wit::Connective::Ja,
-                    ast::Connective::Jo => wit::Connective::Jo,
-                    ast::Connective::Ju => wit::Connective::Ju,
+                let l_id = self.push_selbri(*left, span);
+                let r_id = self.push_selbri(*right, span);
+                wit::Selbri::Connected((l_id, Self::convert_connective(connective), r_id))
+            }
+
+            ast::Selbri::Abstraction(kind, body) => {
+                // Like rel clause bodies, the abstracted sentence is pushed
+                // into `sentences` but is NOT a root.
+                let body_idx = self.push_bridi(*body);
+                let wit_kind = match kind {
+                    ast::AbstractionKind::Nu => wit::AbstractionKind::Nu,
+                    ast::AbstractionKind::Duhu => wit::AbstractionKind::Duhu,
+                    ast::AbstractionKind::Ka => wit::AbstractionKind::Ka,
+                    ast::AbstractionKind::Ni => wit::AbstractionKind::Ni,
+                    ast::AbstractionKind::Siho => wit::AbstractionKind::Siho,
                 };
-                wit::Selbri::Connected((l_id, wit_conn, r_id))
+                wit::Selbri::Abstraction((wit_kind, body_idx))
             }
         };
 
         let id = self.buffer.selbris.len() as u32;
         self.buffer.selbris.push(wit_selbri);
+        self.buffer.selbri_spans.push(Self::convert_span(span));
         id
     }
 
     // ─── Sumti ───────────────────────────────────────────────
 
-    fn push_sumti(&mut self, sumti: ast::Sumti) -> u32 {
+    fn push_sumti(&mut self, sumti: ast::Sumti, span: ast::Span) -> u32 {
         let wit_sumti = match sumti {
             ast::Sumti::ProSumti(s) => wit::Sumti::ProSumti(s),
 
-            ast::Sumti::Description { gadri, inner } => {
-                let inner_id = self.push_selbri(*inner);
+            ast::Sumti::Relativized(depth) => wit::Sumti::Relativized(depth),
+
+            ast::Sumti::Description {
+                gadri,
+                count,
+                inner,
+            } => {
+                let inner_id = self.push_selbri(*inner, span);
                 let wit_gadri = match gadri {
                     ast::Gadri::Lo => wit::Gadri::Lo,
                     ast::Gadri::Le => wit::Gadri::Le,
                     ast::Gadri::La => wit::Gadri::La,
-                    ast::Gadri::RoLo => wit::Gadri::RoLo,
-                    ast::Gadri::RoLe => wit::Gadri::RoLe,
+                    ast::Gadri::Loi => wit::Gadri::Loi,
+                    ast::Gadri::Lei => wit::Gadri::Lei,
+                    ast::Gadri::Lai => wit::Gadri::Lai,
+                    ast::Gadri::Lohi => wit::Gadri::Lohi,
+                    ast::Gadri::Lehi => wit::Gadri::Lehi,
+                    ast::Gadri::Lahi => wit::Gadri::Lahi,
+                    ast::Gadri::Lohe => wit::Gadri::Lohe,
+                    ast::Gadri::Lehe => wit::Gadri::Lehe,
                 };
-                wit::Sumti::Description((wit_gadri, inner_id))
+                wit::Sumti::Description((wit_gadri, count.map(Self::convert_quantifier), inner_id))
+            }
+
+            ast::Sumti::Quantified(quantifier, inner) => {
+                let inner_id = self.push_sumti(*inner, span);
+                wit::Sumti::Quantified((Self::convert_quantifier(quantifier), inner_id))
             }
 
             ast::Sumti::Name(n) => wit::Sumti::Name(n),
@@ -184,7 +358,7 @@ impl Flattener {
             ast::Sumti::Unspecified => wit::Sumti::Unspecified,
 
             ast::Sumti::Tagged(tag, inner) => {
-                let inner_id = self.push_sumti(*inner);
+                let inner_id = self.push_sumti(*inner, span);
                 let wit_tag = match tag {
                     ast::PlaceTag::Fa => wit::PlaceTag::Fa,
                     ast::PlaceTag::Fe => wit::PlaceTag::Fe,
@@ -195,34 +369,76 @@ impl Flattener {
                 wit::Sumti::Tagged((wit_tag, inner_id))
             }
 
-            ast::Sumti::Restricted { inner, clause } => {
-                let inner_id = self.push_sumti(*inner);
+            ast::Sumti::TenseTagged(tense, inner) => {
+                let inner_id = self.push_sumti(*inner, span);
+                wit::Sumti::TenseTagged((Self::convert_tense(tense), inner_id))
+            }
 
-                // The rel clause body is a sentence — push it as a bridi.
-                // It is NOT a root; it lives in `sentences` only for
-                // cross-referencing by index from the Restricted variant.
-                let body_idx = self.buffer.sentences.len() as u32;
-                self.push_bridi(*clause.body);
+            ast::Sumti::ModalTagged(tag, inner) => {
+                let wit_tag = match tag {
+                    ast::ModalTag::Bai(cmavo) => wit::ModalTag::Bai(cmavo),
+                    ast::ModalTag::Fiho(selbri) => {
+                        wit::ModalTag::Fiho(self.push_selbri(*selbri, span))
+                    }
+                };
+                let inner_id = self.push_sumti(*inner, span);
+                wit::Sumti::ModalTagged((wit_tag, inner_id))
+            }
+
+            ast::Sumti::Restricted { inner, clause } => {
+                let inner_id = self.push_sumti(*inner, span);
+
+                // A sentence body is pushed as a bridi. It is NOT a root;
+                // it lives in `sentences` only for cross-referencing by
+                // index from the Restricted variant.
+                let body = match clause.body {
+                    ast::RelClauseBody::Sentence(bridi) => {
+                        wit::RelClauseBody::Sentence(self.push_bridi(*bridi))
+                    }
+                    ast::RelClauseBody::Sumti(sumti) => {
+                        wit::RelClauseBody::Sumti(self.push_sumti(*sumti, span))
+                    }
+                };
 
                 let wit_kind = match clause.kind {
                     ast::RelClauseKind::Poi => wit::RelClauseKind::Poi,
                     ast::RelClauseKind::Noi => wit::RelClauseKind::Noi,
+                    ast::RelClauseKind::Voi => wit::RelClauseKind::Voi,
+                    ast::RelClauseKind::Goi => wit::RelClauseKind::Goi,
+                    ast::RelClauseKind::Pe => wit::RelClauseKind::Pe,
+                    ast::RelClauseKind::Ne => wit::RelClauseKind::Ne,
+                    ast::RelClauseKind::Po => wit::RelClauseKind::Po,
+                    ast::RelClauseKind::Pohe => wit::RelClauseKind::Pohe,
                 };
 
                 wit::Sumti::Restricted((
                     inner_id,
                     wit::RelClause {
                         kind: wit_kind,
-                        body_sentence: body_idx,
+                        body,
                     },
                 ))
             }
+
+            ast::Sumti::Connected {
+                left,
+                connective,
+                right,
+            } => {
+                let l_id = self.push_sumti(*left, span);
+                let r_id = self.push_sumti(*right, span);
+                wit::Sumti::Connected((l_id, Self::convert_logical_connective(connective), r_id))
+            }
         };
 
         let id = self.buffer.sumtis.len() as u32;
         self.buffer.sumtis.push(wit_sumti);
+        self.buffer.sumti_spans.push(Self::convert_span(span));
         id
     }
 }
 
+// The component exports only link on wasm; a native build keeps the rlib
+// for the integration tests.
+#[cfg(target_arch = "wasm32")]
 bindings::export!(ParserComponent with_types_in bindings);
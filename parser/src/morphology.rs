@@ -0,0 +1,376 @@
+// parser/src/morphology.rs
+//
+// Word-level morphology (CLL chapter 4). The lexer hands over each
+// pause-free word; this module classifies it as cmevla, brivla (gismu,
+// lujvo, fu'ivla) or cmavo and splits off run-together cmavo:
+//
+//   lonu       → lo + nu
+//   ienai      → ie + nai
+//   lobrivla   → lo + brivla          (lob-rivla falls apart)
+//   tosmabru   → to + smabru          (the tosmabru failure)
+//   tosymabru  → tosymabru            (the y hyphen holds it together)
+//
+// Classification works on the lowercased letters; returned slices point
+// into the original word so callers keep zero-copy, input-relative text.
+
+/// Morphological class of a single Lojban word.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WordClass {
+    Cmavo,
+    Gismu,
+    Lujvo,
+    Fuhivla,
+    Cmevla,
+}
+
+/// The 48 permissible initial consonant pairs (CLL 3.6).
+const INITIAL_PAIRS: [&str; 48] = [
+    "bl", "br", "cf", "ck", "cl", "cm", "cn", "cp", "cr", "ct", "dj", "dr", "dz", "fl", "fr", "gl",
+    "gr", "jb", "jd", "jg", "jm", "jv", "kl", "kr", "ml", "mr", "pl", "pr", "sf", "sk", "sl", "sm",
+    "sn", "sp", "sr", "st", "tc", "tr", "ts", "vl", "vr", "xl", "xr", "zb", "zd", "zg", "zm", "zv",
+];
+
+/// Vowel pairs usable in a CVV rafsi without an apostrophe.
+const RAFSI_DIPHTHONGS: [&str; 4] = ["ai", "ei", "oi", "au"];
+
+// ─── Letters and clusters ─────────────────────────────────────────
+
+fn is_vowel(c: u8) -> bool {
+    matches!(c, b'a' | b'e' | b'i' | b'o' | b'u')
+}
+
+fn is_consonant(c: u8) -> bool {
+    matches!(
+        c,
+        b'b' | b'c'
+            | b'd'
+            | b'f'
+            | b'g'
+            | b'j'
+            | b'k'
+            | b'l'
+            | b'm'
+            | b'n'
+            | b'p'
+            | b'r'
+            | b's'
+            | b't'
+            | b'v'
+            | b'x'
+            | b'z'
+    )
+}
+
+fn is_initial_pair(a: u8, b: u8) -> bool {
+    INITIAL_PAIRS.iter().any(|p| p.as_bytes() == [a, b])
+}
+
+/// CLL 3.6: any two consonants except doubled letters, voiced/unvoiced
+/// mixes (l m n r are neither), two of c j s z, and cx kx xc xk mz.
+fn is_medial_pair(a: u8, b: u8) -> bool {
+    const VOICED: &[u8] = b"bdgjvz";
+    const UNVOICED: &[u8] = b"cfkpstx";
+    const SIBILANTS: &[u8] = b"cjsz";
+
+    if a == b {
+        return false;
+    }
+    if (VOICED.contains(&a) && UNVOICED.contains(&b))
+        || (UNVOICED.contains(&a) && VOICED.contains(&b))
+    {
+        return false;
+    }
+    if SIBILANTS.contains(&a) && SIBILANTS.contains(&b) {
+        return false;
+    }
+    !matches!(
+        (a, b),
+        (b'c', b'x') | (b'k', b'x') | (b'x', b'c') | (b'x', b'k') | (b'm', b'z')
+    )
+}
+
+/// A medial triple is a medial pair followed by an initial pair, except
+/// ndj, ndz, ntc and nts.
+fn is_medial_triple(a: u8, b: u8, c: u8) -> bool {
+    is_medial_pair(a, b)
+        && is_initial_pair(b, c)
+        && !matches!(&[a, b, c], b"ndj" | b"ndz" | b"ntc" | b"nts")
+}
+
+/// Every consonant run is permissible: word-initial runs are chains of
+/// initial pairs, medial runs are medial pairs or triples.
+fn clusters_ok(w: &[u8]) -> bool {
+    let mut i = 0;
+    while i < w.len() {
+        if !is_consonant(w[i]) {
+            i += 1;
+            continue;
+        }
+        let start = i;
+        while i < w.len() && is_consonant(w[i]) {
+            i += 1;
+        }
+        let run = &w[start..i];
+        let ok = match run.len() {
+            1 => true,
+            _ if start == 0 => run.windows(2).all(|p| is_initial_pair(p[0], p[1])),
+            2 => is_medial_pair(run[0], run[1]),
+            3 => is_medial_triple(run[0], run[1], run[2]),
+            _ => false,
+        };
+        if !ok {
+            return false;
+        }
+    }
+    true
+}
+
+/// Only Lojban letters, and every apostrophe sits between two vowels
+/// (y included).
+fn letters_ok(w: &[u8]) -> bool {
+    let voiced = |c: u8| is_vowel(c) || c == b'y';
+    w.iter().enumerate().all(|(i, &c)| match c {
+        b'\'' => i > 0 && i + 1 < w.len() && voiced(w[i - 1]) && voiced(w[i + 1]),
+        _ => voiced(c) || is_consonant(c),
+    })
+}
+
+/// Index of the first consonant of the first consonant cluster, looking
+/// through `y` and `'` (CLL 4.4), together with its position among the
+/// remaining letters.
+fn first_cluster(w: &[u8]) -> Option<(usize, usize)> {
+    let letters: Vec<(usize, u8)> = w
+        .iter()
+        .copied()
+        .enumerate()
+        .filter(|&(_, c)| c != b'y' && c != b'\'')
+        .collect();
+    letters
+        .windows(2)
+        .position(|p| is_consonant(p[0].1) && is_consonant(p[1].1))
+        .map(|k| (letters[k].0, k))
+}
+
+/// Number of syllables: maximal vowel runs not broken by consonants.
+fn syllables(w: &[u8]) -> usize {
+    w.split(|&c| is_consonant(c) || c == b'\'')
+        .filter(|s| !s.is_empty())
+        .count()
+}
+
+// ─── cmavo ────────────────────────────────────────────────────────
+
+/// Split a cluster-free word into cmavo: a new cmavo starts at every
+/// consonant (CLL 4.2), so "lonu" is lo + nu and "ienai" is ie + nai.
+fn split_cmavo(w: &[u8]) -> Option<Vec<(usize, usize)>> {
+    if w.is_empty() {
+        return None;
+    }
+    let mut bounds: Vec<usize> = (1..w.len()).filter(|&i| is_consonant(w[i])).collect();
+    bounds.insert(0, 0);
+    bounds.push(w.len());
+
+    let pieces: Vec<(usize, usize)> = bounds.windows(2).map(|b| (b[0], b[1])).collect();
+    let valid = pieces.iter().all(|&(start, end)| {
+        let body = if is_consonant(w[start]) {
+            &w[start + 1..end]
+        } else {
+            &w[start..end]
+        };
+        !body.is_empty()
+    });
+    valid.then_some(pieces)
+}
+
+// ─── brivla ───────────────────────────────────────────────────────
+
+fn is_gismu(w: &[u8]) -> bool {
+    if w.len() != 5 || !w.iter().all(|&c| is_vowel(c) || is_consonant(c)) {
+        return false;
+    }
+    let shape: Vec<bool> = w.iter().map(|&c| is_consonant(c)).collect();
+    match shape.as_slice() {
+        [true, false, true, true, false] => is_medial_pair(w[2], w[3]),
+        [true, true, false, true, false] => is_initial_pair(w[0], w[1]),
+        _ => false,
+    }
+}
+
+/// A rafsi that may end a lujvo: CCV, CVV, CV'V or a full gismu.
+fn is_final_rafsi(r: &[u8]) -> bool {
+    match r {
+        [a, b, c] if is_consonant(*a) && is_consonant(*b) && is_vowel(*c) => {
+            is_initial_pair(*a, *b)
+        }
+        [a, b, c] if is_consonant(*a) => RAFSI_DIPHTHONGS.iter().any(|d| d.as_bytes() == [*b, *c]),
+        [a, b, b'\'', c] => is_consonant(*a) && is_vowel(*b) && is_vowel(*c),
+        _ => is_gismu(r),
+    }
+}
+
+/// Non-final rafsi shapes at the start of `r`: (length, needs a y hyphen).
+/// CVC, CCV, CVV, CV'V, and the 4-letter CVCC/CCVC forms that must be
+/// followed by y.
+fn non_final_rafsi(r: &[u8]) -> Vec<(usize, bool)> {
+    let c = |i: usize| r.get(i).is_some_and(|&x| is_consonant(x));
+    let v = |i: usize| r.get(i).is_some_and(|&x| is_vowel(x));
+    let mut shapes = Vec::new();
+    if !c(0) {
+        return shapes;
+    }
+    if v(1) && c(2) {
+        shapes.push((3, false));
+        if c(3) && is_medial_pair(r[2], r[3]) {
+            shapes.push((4, true));
+        }
+    }
+    if c(1) && v(2) && is_initial_pair(r[0], r[1]) {
+        shapes.push((3, false));
+        if c(3) {
+            shapes.push((4, true));
+        }
+    }
+    if v(1) && v(2) && RAFSI_DIPHTHONGS.iter().any(|d| d.as_bytes() == &r[1..3]) {
+        shapes.push((3, false));
+    }
+    if v(1) && r.get(2) == Some(&b'\'') && v(3) {
+        shapes.push((4, false));
+    }
+    shapes
+}
+
+/// Decompose `w[pos..]` into rafsi, pushing their ranges onto `out`.
+/// Accepts y hyphens between consonants and an r/n hyphen after an
+/// initial CVV rafsi.
+fn rafsi_from(w: &[u8], pos: usize, out: &mut Vec<(usize, usize)>) -> bool {
+    if !out.is_empty() && is_final_rafsi(&w[pos..]) {
+        out.push((pos, w.len()));
+        return true;
+    }
+
+    for (len, needs_y) in non_final_rafsi(&w[pos..]) {
+        let end = pos + len;
+        if end >= w.len() {
+            continue;
+        }
+        let mut nexts = Vec::new();
+        let ends_in_consonant = is_consonant(w[end - 1]);
+        if w[end] == b'y' {
+            if ends_in_consonant {
+                nexts.push(end + 1);
+            }
+        } else if !needs_y {
+            nexts.push(end);
+            let hyphen = w.get(end + 1).copied().unwrap_or(0);
+            let r_hyphen = w[end] == b'r' && is_consonant(hyphen) && hyphen != b'r';
+            let n_hyphen = w[end] == b'n' && hyphen == b'r';
+            let cvv = is_vowel(w[pos + 1]) && !ends_in_consonant;
+            if out.is_empty() && cvv && (r_hyphen || n_hyphen) {
+                nexts.push(end + 1);
+            }
+        }
+
+        for next in nexts {
+            out.push((pos, end));
+            if next < w.len() && rafsi_from(w, next, out) {
+                return true;
+            }
+            out.pop();
+        }
+    }
+    false
+}
+
+/// Rafsi decomposition of a lujvo, if `w` is one.
+fn lujvo_rafsi(w: &[u8]) -> Option<Vec<(usize, usize)>> {
+    let mut rafsi = Vec::new();
+    let y_ok = w
+        .iter()
+        .enumerate()
+        .filter(|&(_, &c)| c == b'y')
+        .all(|(i, _)| {
+            i > 0 && is_consonant(w[i - 1]) && w.get(i + 1).is_some_and(|&c| is_consonant(c))
+        });
+    (y_ok && rafsi_from(w, 0, &mut rafsi) && rafsi.len() >= 2 && clusters_ok(w)).then_some(rafsi)
+}
+
+/// A brivla candidate "falls apart" if some cmavo prefix, ending no later
+/// than the first cluster, leaves a valid brivla behind (CLL 4.11).
+fn falls_apart(w: &[u8], cluster: usize) -> bool {
+    (1..=cluster).any(|k| {
+        is_consonant(w[k]) && split_cmavo(&w[..k]).is_some() && classify_brivla(&w[k..]).is_some()
+    })
+}
+
+/// Classify a whole word as a brivla: it starts with a consonant, ends
+/// in a vowel, has a consonant cluster within its first five letters and
+/// at least two syllables.
+fn classify_brivla(w: &[u8]) -> Option<WordClass> {
+    let last = *w.last()?;
+    if !is_consonant(w[0]) || !is_vowel(last) || syllables(w) < 2 {
+        return None;
+    }
+    let (cluster, letter) = first_cluster(w)?;
+    if letter > 3 {
+        return None;
+    }
+    if is_gismu(w) {
+        return Some(WordClass::Gismu);
+    }
+    if falls_apart(w, cluster) {
+        return None;
+    }
+    if lujvo_rafsi(w).is_some() {
+        return Some(WordClass::Lujvo);
+    }
+
+    // fu'ivla: no hyphens, valid clusters, and not a lujvo once a CV
+    // cmavo is prefixed (the slinku'i test).
+    let mut slinkuhi = b"pa".to_vec();
+    slinkuhi.extend_from_slice(w);
+    let fuhivla = !w.contains(&b'y') && clusters_ok(w) && lujvo_rafsi(&slinkuhi).is_none();
+    fuhivla.then_some(WordClass::Fuhivla)
+}
+
+// ─── Public entry point ───────────────────────────────────────────
+
+/// Classify a pause-free word and split it into its component words.
+/// Returns `None` if the word is not morphologically valid Lojban.
+pub fn split_word(word: &str) -> Option<Vec<(WordClass, &str)>> {
+    let w: Vec<u8> = word.bytes().map(|b| b.to_ascii_lowercase()).collect();
+    if w.is_empty() || !letters_ok(&w) {
+        return None;
+    }
+
+    if is_consonant(w[w.len() - 1]) {
+        return Some(vec![(WordClass::Cmevla, word)]);
+    }
+
+    let cmavo = |pieces: Vec<(usize, usize)>| {
+        pieces
+            .into_iter()
+            .map(|(start, end)| (WordClass::Cmavo, &word[start..end]))
+            .collect::<Vec<_>>()
+    };
+
+    // A brivla runs to the end of the word and starts no later than the
+    // first cluster; anything before it is cmavo. The earliest valid
+    // start wins.
+    if let Some((cluster, _)) = first_cluster(&w) {
+        for start in (0..=cluster).filter(|&i| is_consonant(w[i])) {
+            let prefix = if start == 0 {
+                Some(Vec::new())
+            } else {
+                split_cmavo(&w[..start])
+            };
+            if let (Some(prefix), Some(class)) = (prefix, classify_brivla(&w[start..])) {
+                let mut words = cmavo(prefix);
+                words.push((class, &word[start..]));
+                return Some(words);
+            }
+        }
+    }
+
+    // No brivla: letteral strings like "bycy" still split as cmavo.
+    split_cmavo(&w).map(cmavo)
+}
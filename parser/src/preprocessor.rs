@@ -2,15 +2,17 @@
 //
 // Consumes the raw lexical stream and resolves metalinguistic operations:
 //   si  — erase preceding word
-//   sa  — erase backward to matching class (degraded: no-op with warning)
+//   sa  — erase backward to the last word of the next word's selma'o
 //   su  — erase entire discourse
 //   zo  — quote next word
 //   zoi — quote delimited text
 //   zei — glue adjacent words into compound
 
+use crate::ast::Span;
 use crate::lexer::LojbanToken;
+use crate::selmaho;
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum NormalizedToken<'a> {
     /// A standard Lojban word (Gismu, Cmavo, Cmevla, etc.)
     Standard(LojbanToken, &'a str),
@@ -21,14 +23,16 @@ pub enum NormalizedToken<'a> {
 }
 
 /// Consumes the raw lexical stream and resolves metalinguistic operations in O(n) time.
+/// Each output token keeps the byte span of the source text it came from; a
+/// quote or zei compound spans its whole construct.
 pub fn preprocess<'a>(
-    raw_tokens: impl Iterator<Item = (LojbanToken, &'a str)>,
+    raw_tokens: impl Iterator<Item = (LojbanToken, &'a str, Span)>,
     original_input: &'a str,
-) -> Vec<NormalizedToken<'a>> {
-    let mut output: Vec<NormalizedToken<'a>> = Vec::with_capacity(128);
+) -> Vec<(NormalizedToken<'a>, Span)> {
+    let mut output: Vec<(NormalizedToken<'a>, Span)> = Vec::with_capacity(128);
     let mut iter = raw_tokens.peekable();
 
-    while let Some((token, text)) = iter.next() {
+    while let Some((token, text, span)) = iter.next() {
         match token {
             // ── Erasure Operations ────────────────────────────────
             LojbanToken::EraseWord => {
@@ -42,46 +46,55 @@ pub fn preprocess<'a>(
             }
 
             LojbanToken::EraseClass => {
-                // `sa` erases backward until a token of the same grammatical class
-                // is found. Full implementation requires selma'o classification.
-                //
-                // B4 FIX: was `unimplemented!()` which caused WASM trap.
-                // Degraded behavior: erase preceding word (same as si).
-                // This is safe and handles the most common use case.
-                eprintln!(
-                    "[parser] warning: 'sa' erasure degraded to single-word erase (si behavior)"
-                );
-                output.pop();
+                // `sa` erases back to the last word of the same selma'o as the
+                // word that follows it; that word then takes its place:
+                //   "mi klama lo zarci sa lo zdani" → "mi klama lo zdani"
+                // Without a match (or an unclassified next word) it erases
+                // only the preceding word, like `si`.
+                let target = iter.peek().and_then(|&(t, s, _)| selmaho::of_token(t, s));
+                let start = target.and_then(|class| {
+                    output
+                        .iter()
+                        .rposition(|(t, _)| selmaho::of_normalized(t) == Some(class))
+                });
+                match start {
+                    Some(start) => output.truncate(start),
+                    None => {
+                        output.pop();
+                    }
+                }
             }
 
             // ── Quotation Operations ─────────────────────────────
             LojbanToken::QuoteNext => {
                 // `zo` treats the immediately following token as a literal string.
-                if let Some((_, quoted_text)) = iter.next() {
-                    output.push(NormalizedToken::Quoted(quoted_text));
+                if let Some((_, quoted_text, quoted_span)) = iter.next() {
+                    output.push((NormalizedToken::Quoted(quoted_text), span.to(quoted_span)));
                 }
             }
 
             LojbanToken::QuoteDelimited => {
                 // `zoi` requires a delimiter, arbitrary text, and the same delimiter.
-                if let Some((_, delimiter)) = iter.next() {
-                    let start_ptr = delimiter.as_ptr() as usize - original_input.as_ptr() as usize
-                        + delimiter.len();
-                    let mut end_ptr = start_ptr;
+                if let Some((_, delimiter, delimiter_span)) = iter.next() {
+                    let mut start = None;
+                    let mut end = delimiter_span.end;
+                    let mut construct = span.to(delimiter_span);
 
                     // Consume until we hit the exact same delimiter token
-                    while let Some((_, next_text)) = iter.next() {
+                    for (_, next_text, next_span) in iter.by_ref() {
+                        construct = construct.to(next_span);
                         if next_text == delimiter {
                             break;
                         }
-                        end_ptr = next_text.as_ptr() as usize - original_input.as_ptr() as usize
-                            + next_text.len();
+                        start.get_or_insert(next_span.start);
+                        end = next_span.end;
                     }
+                    let start = start.unwrap_or(end);
 
                     // Extract the zero-copy payload slice from the original input
-                    if end_ptr > start_ptr && end_ptr <= original_input.len() {
-                        let payload = &original_input[start_ptr..end_ptr].trim();
-                        output.push(NormalizedToken::Quoted(payload));
+                    if end > start && end <= original_input.len() {
+                        let payload = &original_input[start..end].trim();
+                        output.push((NormalizedToken::Quoted(payload), construct));
                     }
                 }
             }
@@ -90,21 +103,21 @@ pub fn preprocess<'a>(
             LojbanToken::GlueWords => {
                 // `zei` joins the previous token and the next token into a single
                 // lujvo-like unit.
-                if let Some(prev) = output.pop() {
-                    if let Some((_, next_text)) = iter.next() {
-                        let mut parts = match prev {
-                            NormalizedToken::Glued(existing) => existing,
-                            NormalizedToken::Standard(_, t) | NormalizedToken::Quoted(t) => vec![t],
-                        };
-                        parts.push(next_text);
-                        output.push(NormalizedToken::Glued(parts));
-                    }
+                if let Some((prev, prev_span)) = output.pop()
+                    && let Some((_, next_text, next_span)) = iter.next()
+                {
+                    let mut parts = match prev {
+                        NormalizedToken::Glued(existing) => existing,
+                        NormalizedToken::Standard(_, t) | NormalizedToken::Quoted(t) => vec![t],
+                    };
+                    parts.push(next_text);
+                    output.push((NormalizedToken::Glued(parts), prev_span.to(next_span)));
                 }
             }
 
             // ── Standard Tokens ──────────────────────────────────
             _ => {
-                output.push(NormalizedToken::Standard(token, text));
+                output.push((NormalizedToken::Standard(token, text), span));
             }
         }
     }
@@ -0,0 +1,47 @@
+// parser/src/selmaho.rs
+//
+// Grammatical classes of words, driven by the cmavo → selma'o table
+// generated from jbovlaste at build time. Used by `sa` erasure.
+
+use crate::lexer::LojbanToken;
+use crate::preprocessor::NormalizedToken;
+
+// Include the perfect hash map generated at compile time
+include!(concat!(env!("OUT_DIR"), "/generated_selmaho.rs"));
+
+/// Pseudo-selma'o for words outside the cmavo table.
+pub const BRIVLA: &str = "BRIVLA";
+pub const CMEVLA: &str = "CMEVLA";
+/// zo and zoi quotes are indistinguishable once resolved.
+pub const QUOTE: &str = "ZO";
+
+/// Selma'o of a cmavo (e.g. "lo" → "LE"). Returns None for cmavo
+/// jbovlaste does not list.
+pub fn of_cmavo(cmavo: &str) -> Option<&'static str> {
+    CMAVO_SELMAHO.get(cmavo).copied()
+}
+
+/// Class of a raw lexer token: its selma'o for cmavo, a pseudo-selma'o
+/// for brivla, cmevla and quote operators, None for pauses.
+pub fn of_token(token: LojbanToken, text: &str) -> Option<&'static str> {
+    match token {
+        LojbanToken::Gismu | LojbanToken::Lujvo | LojbanToken::Fuhivla => Some(BRIVLA),
+        LojbanToken::Cmevla => Some(CMEVLA),
+        LojbanToken::QuoteNext | LojbanToken::QuoteDelimited => Some(QUOTE),
+        LojbanToken::Pause | LojbanToken::Foreign => None,
+        LojbanToken::Cmavo
+        | LojbanToken::EraseWord
+        | LojbanToken::EraseClass
+        | LojbanToken::EraseStream
+        | LojbanToken::GlueWords => of_cmavo(text),
+    }
+}
+
+/// Class of an already-resolved token; zei compounds are brivla.
+pub fn of_normalized(token: &NormalizedToken<'_>) -> Option<&'static str> {
+    match token {
+        NormalizedToken::Standard(token, text) => of_token(*token, text),
+        NormalizedToken::Quoted(_) => Some(QUOTE),
+        NormalizedToken::Glued(_) => Some(BRIVLA),
+    }
+}
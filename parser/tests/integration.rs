@@ -1,17 +1,19 @@
-use parser_test::ast::*;
-use parser_test::grammar::parse_tokens_to_ast;
-use parser_test::lexer::tokenize;
-use parser_test::preprocessor::preprocess;
+use parser::ast::*;
+use parser::grammar::{parse_tokens, parse_tokens_recovering, parse_tokens_to_ast};
+use parser::lexer::{LexMode, LojbanToken, Source, tokenize};
+use parser::preprocessor::preprocess;
 
 /// Parse a raw Lojban string through the full pipeline.
 fn parse(input: &str) -> ParsedText {
-    let raw = tokenize(input);
+    let source = Source::new(input);
+    let (raw, _) = tokenize(&source, LexMode::Strict);
     let normalized = preprocess(raw.into_iter(), input);
-    parse_tokens_to_ast(&normalized).expect(&format!("failed to parse: {:?}", input))
+    parse_tokens_to_ast(&normalized).unwrap_or_else(|_| panic!("failed to parse: {:?}", input))
 }
 
 fn parse_err(input: &str) -> String {
-    let raw = tokenize(input);
+    let source = Source::new(input);
+    let (raw, _) = tokenize(&source, LexMode::Strict);
     let normalized = preprocess(raw.into_iter(), input);
     parse_tokens_to_ast(&normalized).unwrap_err()
 }
@@ -77,7 +79,7 @@ fn lo_description() {
     let s = &p.sentences[0];
     assert_eq!(s.tail_terms.len(), 1);
     match &s.tail_terms[0] {
-        Sumti::Description { gadri, inner } => {
+        Sumti::Description { gadri, inner, .. } => {
             assert_eq!(*gadri, Gadri::Lo);
             assert_eq!(**inner, Selbri::Root("gerku".into()));
         }
@@ -100,7 +102,7 @@ fn lo_with_ku() {
     let s = &p.sentences[0];
     assert_eq!(s.head_terms.len(), 1);
     match &s.head_terms[0] {
-        Sumti::Description { gadri, inner } => {
+        Sumti::Description { gadri, inner, .. } => {
             assert_eq!(*gadri, Gadri::Lo);
             assert_eq!(**inner, Selbri::Root("gerku".into()));
         }
@@ -109,6 +111,37 @@ fn lo_with_ku() {
     assert_eq!(s.selbri, Selbri::Root("klama".into()));
 }
 
+#[test]
+fn mass_set_and_typical_gadri() {
+    let cases = [
+        ("loi", Gadri::Loi),
+        ("lei", Gadri::Lei),
+        ("lo'i", Gadri::Lohi),
+        ("le'i", Gadri::Lehi),
+        ("lo'e", Gadri::Lohe),
+        ("le'e", Gadri::Lehe),
+    ];
+    for (cmavo, expected) in cases {
+        let p = parse(&format!("{} gerku cu barda", cmavo));
+        match &p.sentences[0].head_terms[0] {
+            Sumti::Description { gadri, .. } => assert_eq!(*gadri, expected, "{}", cmavo),
+            other => panic!("expected Description for {}, got {:?}", cmavo, other),
+        }
+    }
+}
+
+#[test]
+fn lai_name_is_mass_description() {
+    let p = parse("lai .djan. cu klama");
+    match &p.sentences[0].head_terms[0] {
+        Sumti::Description { gadri, inner, .. } => {
+            assert_eq!(*gadri, Gadri::Lai);
+            assert_eq!(**inner, Selbri::Root("djan".into()));
+        }
+        other => panic!("expected Description, got {:?}", other),
+    }
+}
+
 // ─── Names (la + cmevla) ────────────────────────────────────────
 
 #[test]
@@ -295,6 +328,66 @@ fn poi_relative_clause() {
     }
 }
 
+#[test]
+fn goi_assignment_and_goi_selbri() {
+    let p = parse("la .alis. goi ko'a cu klama .i do go'i");
+    match &p.sentences[0].head_terms[0] {
+        Sumti::Restricted { inner, clause } => {
+            assert_eq!(clause.kind, RelClauseKind::Goi);
+            assert_eq!(**inner, Sumti::Name("alis".into()));
+            assert_eq!(
+                clause.body,
+                RelClauseBody::Sumti(Box::new(Sumti::ProSumti("ko'a".into())))
+            );
+        }
+        other => panic!("expected Restricted, got {:?}", other),
+    }
+    assert_eq!(p.sentences[1].selbri, Selbri::Root("go'i".into()));
+}
+
+#[test]
+fn relative_phrases_and_voi() {
+    let kind_of = |sumti: &Sumti| match sumti {
+        Sumti::Restricted { clause, .. } => clause.kind,
+        other => panic!("expected Restricted, got {:?}", other),
+    };
+    let p = parse("le gerku pe mi .i lo cukta po do .i lo birka po'e mi ge'u ne do");
+    assert_eq!(kind_of(&p.sentences[0].head_terms[0]), RelClauseKind::Pe);
+    assert_eq!(kind_of(&p.sentences[1].head_terms[0]), RelClauseKind::Po);
+    match &p.sentences[2].head_terms[0] {
+        Sumti::Restricted { inner, clause } => {
+            assert_eq!(clause.kind, RelClauseKind::Ne);
+            assert_eq!(kind_of(inner), RelClauseKind::Pohe);
+        }
+        other => panic!("expected Restricted, got {:?}", other),
+    }
+
+    let p = parse("lo gerku voi blanu cu sutra");
+    assert_eq!(kind_of(&p.sentences[0].head_terms[0]), RelClauseKind::Voi);
+}
+
+#[test]
+fn possessor_inside_description_is_pe() {
+    let p = parse("le mi gerku cu sutra");
+    match &p.sentences[0].head_terms[0] {
+        Sumti::Restricted { inner, clause } => {
+            assert_eq!(clause.kind, RelClauseKind::Pe);
+            assert_eq!(
+                clause.body,
+                RelClauseBody::Sumti(Box::new(Sumti::ProSumti("mi".into())))
+            );
+            assert!(matches!(
+                inner.as_ref(),
+                Sumti::Description {
+                    gadri: Gadri::Le,
+                    ..
+                }
+            ));
+        }
+        other => panic!("expected Restricted, got {:?}", other),
+    }
+}
+
 // ─── zo'e (explicit unspecified) ─────────────────────────────────
 
 #[test]
@@ -323,6 +416,465 @@ fn su_erasure() {
     );
 }
 
+#[test]
+fn sa_erasure() {
+    // "mi klama lo zarci sa lo zdani" → mi klama lo zdani (back to the last LE)
+    let p = parse("mi klama lo zarci sa lo zdani");
+    assert_eq!(p.sentences[0].selbri, Selbri::Root("klama".into()));
+    assert!(matches!(
+        &p.sentences[0].tail_terms[..],
+        [Sumti::Description { inner, .. }] if **inner == Selbri::Root("zdani".into())
+    ));
+
+    // "mi klama do sa prami do" → mi prami do (back to the last brivla)
+    let p = parse("mi klama do sa prami do");
+    assert_eq!(p.sentences[0].selbri, Selbri::Root("prami".into()));
+    assert_eq!(p.sentences[0].tail_terms.len(), 1);
+}
+
+// ─── Abstractions (nu/du'u ... kei) ──────────────────────────────
+
+#[test]
+fn nu_abstraction_as_argument() {
+    let p = parse("lo nu mi klama kei cu rinka lo nu do gleki");
+    let s = &p.sentences[0];
+    assert_eq!(s.selbri, Selbri::Root("rinka".into()));
+    for term in s.head_terms.iter().chain(s.tail_terms.iter()) {
+        match term {
+            Sumti::Description {
+                gadri: Gadri::Lo,
+                inner,
+                ..
+            } => assert!(matches!(
+                inner.as_ref(),
+                Selbri::Abstraction(AbstractionKind::Nu, _)
+            )),
+            other => panic!("expected lo nu description, got {:?}", other),
+        }
+    }
+}
+
+#[test]
+fn du_u_abstraction() {
+    let p = parse("mi djuno lo du'u la .bob. cu klama");
+    match &p.sentences[0].tail_terms[0] {
+        Sumti::Description { inner, .. } => match inner.as_ref() {
+            Selbri::Abstraction(AbstractionKind::Duhu, body) => {
+                assert_eq!(body.head_terms, vec![Sumti::Name("bob".into())]);
+            }
+            other => panic!("expected du'u abstraction, got {:?}", other),
+        },
+        other => panic!("expected Description, got {:?}", other),
+    }
+}
+
+// ─── Tense (pu/ca/ba, zi/za/zu, co'a/co'u/ca'o) ──────────────────
+
+#[test]
+fn selbri_tense() {
+    let p = parse("la .alis. pu zi klama");
+    let tense = p.sentences[0].tense.expect("tense");
+    assert_eq!(tense.direction, Some(TimeDirection::Pu));
+    assert_eq!(tense.distance, Some(TimeDistance::Zi));
+    assert_eq!(tense.aspect, None);
+}
+
+#[test]
+fn sumti_tcita_and_sentence_tense() {
+    let p = parse("mi ba klama ca lo nu do sipna");
+    let s = &p.sentences[0];
+    assert_eq!(s.tense.unwrap().direction, Some(TimeDirection::Ba));
+    assert!(matches!(
+        &s.tail_terms[0],
+        Sumti::TenseTagged(
+            Tense {
+                direction: Some(TimeDirection::Ca),
+                ..
+            },
+            _
+        )
+    ));
+}
+
+// ─── Modal tags (BAI, fi'o) ──────────────────────────────────────
+
+#[test]
+fn bai_and_fiho_tags() {
+    let p = parse("mi klama mu'i lo nu mi djica kei fi'o sidju fe'u do");
+    let s = &p.sentences[0];
+    assert_eq!(s.tail_terms.len(), 2);
+    assert!(matches!(
+        &s.tail_terms[0],
+        Sumti::ModalTagged(ModalTag::Bai(t), _) if t == "mu'i"
+    ));
+    assert!(matches!(
+        &s.tail_terms[1],
+        Sumti::ModalTagged(ModalTag::Fiho(_), inner) if **inner == Sumti::ProSumti("do".into())
+    ));
+}
+
+// ─── Forethought connectives (ge/ga/go/gu ... gi) ────────────────
+
+#[test]
+fn ganai_gi_conditional() {
+    let p = parse("ga nai lo tsani cu carvi gi lo loldi cu cilmo");
+    assert_eq!(p.sentences.len(), 2);
+    match &p.statements[0] {
+        Statement::Connected { connective, .. } => {
+            assert_eq!(connective.connective, Connective::Ja);
+            assert!(connective.negate_left);
+        }
+        other => panic!("expected Connected statement, got {:?}", other),
+    }
+}
+
+#[test]
+fn forethought_sumti_and_sentences_mix() {
+    let p = parse("mi klama .i ge la .alis. gi la .bob. cu citka");
+    assert_eq!(p.statements.len(), 2);
+    assert_eq!(p.statements[1], Statement::Simple(1));
+    assert!(matches!(
+        &p.sentences[1].head_terms[0],
+        Sumti::Connected { left, .. } if **left == Sumti::Name("alis".into())
+    ));
+}
+
+// ─── Sumti connectives (.e/.a/.o/.u) ─────────────────────────────
+
+#[test]
+fn sumti_afterthought_connectives() {
+    let p = parse("la .alis. .e la .bob. cu klama lo zarci .a nai lo zdani");
+    let s = &p.sentences[0];
+    match &s.head_terms[0] {
+        Sumti::Connected {
+            left,
+            connective,
+            right,
+        } => {
+            assert_eq!(connective.connective, Connective::Je);
+            assert_eq!(**left, Sumti::Name("alis".into()));
+            assert_eq!(**right, Sumti::Name("bob".into()));
+        }
+        other => panic!("expected Connected, got {:?}", other),
+    }
+    assert!(matches!(
+        &s.tail_terms[0],
+        Sumti::Connected { connective, .. }
+            if connective.connective == Connective::Ja && connective.negate_right
+    ));
+}
+
+#[test]
+fn sumti_connective_does_not_swallow_dot_i() {
+    let p = parse("mi .e do klama .i mi citka");
+    assert_eq!(p.sentences.len(), 2);
+    assert_eq!(p.statements.len(), 2);
+}
+
+// ─── Sentence connectives (.i je/ja/jo/ju, bo, ke) ───────────────
+
+#[test]
+fn ija_is_one_root() {
+    let p = parse("lo tsani cu carvi .i ja lo loldi cu cilmo .i mi klama");
+    assert_eq!(p.sentences.len(), 3);
+    assert_eq!(p.statements.len(), 2);
+    assert!(matches!(
+        &p.statements[0],
+        Statement::Connected { connective, .. } if connective.connective == Connective::Ja
+    ));
+}
+
+#[test]
+fn ijek_with_bo_and_tanru_bo() {
+    let p = parse("mi klama .i je do citka .i jo bo mi sutra bo gerku zdani");
+    match &p.statements[0] {
+        Statement::Connected { right, .. } => {
+            assert!(matches!(right.as_ref(), Statement::Connected { .. }))
+        }
+        other => panic!("expected Connected, got {:?}", other),
+    }
+    assert!(matches!(
+        &p.sentences[2].selbri,
+        Selbri::Tanru(modifier, _) if matches!(modifier.as_ref(), Selbri::Tanru(..))
+    ));
+}
+
+// ─── Prenex (zo'u) ───────────────────────────────────────────────
+
+#[test]
+fn prenex_with_naku_and_restriction() {
+    let p = parse("na ku ro da poi prenu ku'o su'o de zo'u da prami de");
+    match &p.statements[0] {
+        Statement::Prenex { terms, body } => {
+            assert_eq!(terms.len(), 3);
+            assert_eq!(terms[0], PrenexTerm::Naku);
+            assert!(matches!(
+                &terms[1],
+                PrenexTerm::Variable(Some(Quantifier::Ro), Sumti::Restricted { .. })
+            ));
+            assert_eq!(
+                terms[2],
+                PrenexTerm::Variable(Some(Quantifier::AtLeast(1)), Sumti::ProSumti("de".into()))
+            );
+            assert_eq!(**body, Statement::Simple(0));
+        }
+        other => panic!("expected Prenex, got {:?}", other),
+    }
+    assert_eq!(p.sentences[0].selbri, Selbri::Root("prami".into()));
+}
+
+// ─── Quantified sumti (PA, su'o, ro) ─────────────────────────────
+
+#[test]
+fn numeric_quantifiers_outer_and_inner() {
+    let p = parse("re lo ci gerku cu nelci no lo mlatu");
+    let s = &p.sentences[0];
+    match &s.head_terms[0] {
+        Sumti::Quantified(Quantifier::Exactly(2), desc) => assert!(matches!(
+            desc.as_ref(),
+            Sumti::Description {
+                count: Some(Quantifier::Exactly(3)),
+                ..
+            }
+        )),
+        other => panic!("expected re lo ci, got {:?}", other),
+    }
+    assert!(matches!(
+        &s.tail_terms[0],
+        Sumti::Quantified(Quantifier::Exactly(0), _)
+    ));
+}
+
+#[test]
+fn suho_da_with_relative_clause() {
+    let p = parse("su'o da poi gerku cu blabi");
+    match &p.sentences[0].head_terms[0] {
+        Sumti::Restricted { inner, .. } => assert_eq!(
+            **inner,
+            Sumti::Quantified(
+                Quantifier::AtLeast(1),
+                Box::new(Sumti::ProSumti("da".into()))
+            )
+        ),
+        other => panic!("expected Restricted(su'o da), got {:?}", other),
+    }
+}
+
+// ─── Observatives ────────────────────────────────────────────────
+
+#[test]
+fn observative_fragments() {
+    let p = parse("lo gerku .i carvi .i klama lo zarci");
+    assert_eq!(p.statements.len(), 3);
+    assert_eq!(p.sentences[0].selbri, Selbri::Root("co'e".into()));
+    assert_eq!(p.sentences[1].selbri, Selbri::Root("carvi".into()));
+    assert!(p.sentences[2].head_terms.is_empty());
+    assert_eq!(p.sentences[2].tail_terms.len(), 1);
+}
+
+// ─── Questions (xu, ma, mo) ──────────────────────────────────────
+
+#[test]
+fn xu_marks_a_question() {
+    let p = parse("xu do klama .i do klama xu .i do klama");
+    assert!(p.sentences[0].question);
+    assert!(p.sentences[1].question);
+    assert!(!p.sentences[2].question);
+    assert_eq!(
+        p.sentences[0].head_terms,
+        vec![Sumti::ProSumti("do".into())]
+    );
+}
+
+#[test]
+fn mo_is_a_selbri() {
+    let p = parse("do mo");
+    let s = &p.sentences[0];
+    assert_eq!(s.selbri, Selbri::Root("mo".into()));
+    assert_eq!(s.head_terms, vec![Sumti::ProSumti("do".into())]);
+}
+
+// ─── Morphology (lujvo, fu'ivla, cmavo compounds) ────────────────
+
+#[test]
+fn run_together_cmavo_are_split() {
+    let source = Source::new(".ienai lonu");
+    let (tokens, _) = tokenize(&source, LexMode::Strict);
+    let words: Vec<&str> = tokens.iter().map(|t| t.1).collect();
+    assert_eq!(words, vec![".", "ie", "nai", "lo", "nu"]);
+
+    let p = parse("ganai lo tsani cu carvi gi lo loldi cu cilmo .ije mi klama");
+    assert_eq!(p.sentences.len(), 3);
+    match &p.statements[0] {
+        Statement::Connected { left, .. } => match left.as_ref() {
+            Statement::Connected { connective, .. } => assert!(connective.negate_left),
+            other => panic!("expected ganai … gi, got {:?}", other),
+        },
+        other => panic!("expected Connected statement, got {:?}", other),
+    }
+}
+
+#[test]
+fn lujvo_and_fuhivla_classification() {
+    let classes = |input| {
+        let source = Source::new(input);
+        let (tokens, _) = tokenize(&source, LexMode::Strict);
+        tokens.into_iter().map(|t| t.0).collect::<Vec<_>>()
+    };
+    assert_eq!(classes("jbobau"), vec![LojbanToken::Lujvo]);
+    assert_eq!(classes("spageti"), vec![LojbanToken::Fuhivla]);
+    assert_eq!(classes("tosymabru"), vec![LojbanToken::Lujvo]);
+    assert_eq!(
+        classes("tosmabru"),
+        vec![LojbanToken::Cmavo, LojbanToken::Lujvo]
+    );
+    assert_eq!(
+        classes("lobrivla"),
+        vec![LojbanToken::Cmavo, LojbanToken::Lujvo]
+    );
+
+    let p = parse("mi citka lo spageti .i do gerzda");
+    assert!(matches!(
+        &p.sentences[0].tail_terms[0],
+        Sumti::Description { inner, .. } if **inner == Selbri::Root("spageti".into())
+    ));
+    assert_eq!(p.sentences[1].selbri, Selbri::Root("gerzda".into()));
+}
+
+// ─── Source spans ────────────────────────────────────────────────
+
+#[test]
+fn spans_cover_sentences_terms_and_selbri() {
+    let input = "mi klama lo zarci .i do citka";
+    let p = parse(input);
+    let s = &p.sentences[0];
+    let text = |span: Span| &input[span.start..span.end];
+    assert_eq!(text(s.span), "mi klama lo zarci");
+    assert_eq!(text(s.selbri_span), "klama");
+    assert_eq!(
+        s.head_spans.iter().map(|&sp| text(sp)).collect::<Vec<_>>(),
+        vec!["mi"]
+    );
+    assert_eq!(text(s.tail_spans[0]), "lo zarci");
+
+    // zo quotes span the quote word and the quoted word
+    let source = Source::new("zo mi");
+    let (raw, _) = tokenize(&source, LexMode::Strict);
+    let normalized = preprocess(raw.into_iter(), "zo mi");
+    assert_eq!(normalized[0].1, Span::new(0, 5));
+}
+
+#[test]
+fn parse_error_renders_line_and_caret() {
+    let input = "mi klama\n.i lo ku";
+    let source = Source::new(input);
+    let (raw, _) = tokenize(&source, LexMode::Strict);
+    let normalized = preprocess(raw.into_iter(), input);
+    let err = parse_tokens(&normalized).unwrap_err();
+    let rendered = err.render(input);
+    assert!(
+        rendered.starts_with("parse error at line 2, column 4:"),
+        "{}",
+        rendered
+    );
+    assert!(rendered.ends_with("\n  .i lo ku\n     ^^"), "{}", rendered);
+}
+
+// ─── Lexer diagnostics and orthography ───────────────────────────
+
+#[test]
+fn orthographic_variants_are_canonicalised() {
+    // h for ', capitals for stress, comma as syllable separator
+    let input = "la .mar,ias. cu DUNda zohe lo cukta";
+    let source = Source::new(input);
+    let (tokens, errors) = tokenize(&source, LexMode::Strict);
+    assert!(errors.is_empty(), "{:?}", errors);
+    let words: Vec<&str> = tokens.iter().map(|t| t.1).collect();
+    assert!(words.contains(&"marias"));
+    assert!(words.contains(&"dunda"));
+    assert!(words.contains(&"zo'e"));
+
+    // Spans point at the original text
+    let name = tokens.iter().find(|t| t.1 == "marias").unwrap().2;
+    assert_eq!(&input[name.start..name.end], "mar,ias");
+    assert_eq!(
+        parse(input).sentences[0].selbri,
+        Selbri::Root("dunda".into())
+    );
+}
+
+#[test]
+fn invalid_segments_are_reported_with_spans() {
+    let input = "mi klama 42 lo zarci .i do qwerty citka";
+    let source = Source::new(input);
+
+    let (tokens, errors) = tokenize(&source, LexMode::Strict);
+    assert_eq!(tokens.len(), 2);
+    assert_eq!(errors.len(), 1);
+    assert_eq!(&input[errors[0].span.start..errors[0].span.end], "42");
+
+    let (tokens, errors) = tokenize(&source, LexMode::Lenient);
+    let spans: Vec<&str> = errors
+        .iter()
+        .map(|e| &input[e.span.start..e.span.end])
+        .collect();
+    assert_eq!(spans, vec!["42", "qwerty"]);
+    assert!(tokens.iter().any(|t| t.1 == "citka"));
+    assert!(
+        errors[1]
+            .render(input)
+            .starts_with("lex error at line 1, column 28:")
+    );
+}
+
+#[test]
+fn zoi_payload_is_not_validated() {
+    let input = "mi cusku zoi gy. hello world, 42! gy. .i do citka";
+    let source = Source::new(input);
+    let (_, errors) = tokenize(&source, LexMode::Strict);
+    assert!(errors.is_empty(), "{:?}", errors);
+
+    let p = parse(input);
+    assert_eq!(p.sentences.len(), 2);
+    assert_eq!(
+        p.sentences[0].tail_terms,
+        vec![Sumti::QuotedLiteral("hello world, 42!".into())]
+    );
+    assert_eq!(p.sentences[1].selbri, Selbri::Root("citka".into()));
+
+    // Outside the quote, strict lexing still rejects non-Lojban
+    let source = Source::new("zoi gy. hello gy. 42");
+    let (_, errors) = tokenize(&source, LexMode::Strict);
+    assert_eq!(errors.len(), 1);
+}
+
+// ─── Error recovery ──────────────────────────────────────────────
+
+#[test]
+fn recovery_skips_to_next_separator() {
+    let input = "mi klama .i lo ku ku .i do citka ni'o mi mi ku ni'o do prami mi";
+    let source = Source::new(input);
+    let (raw, _) = tokenize(&source, LexMode::Strict);
+    let normalized = preprocess(raw.into_iter(), input);
+    let (p, errors) = parse_tokens_recovering(&normalized);
+
+    let selbri: Vec<_> = p.sentences.iter().map(|s| s.selbri.clone()).collect();
+    assert_eq!(
+        selbri,
+        vec![
+            Selbri::Root("klama".into()),
+            Selbri::Root("citka".into()),
+            Selbri::Root("prami".into()),
+        ]
+    );
+    assert_eq!(p.statements.len(), 3);
+    assert_eq!(errors.len(), 2);
+    assert_eq!(&input[errors[0].span.start..errors[0].span.end], "lo");
+
+    // Without recovery the first error aborts the parse
+    assert!(parse_err(input).contains("expected selbri"));
+}
+
 // ─── Complex combinations ────────────────────────────────────────
 
 #[test]
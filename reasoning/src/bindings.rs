@@ -130,8 +130,14 @@ pub mod lojban {
                 Lo,
                 Le,
                 La,
-                RoLo,
-                RoLe,
+                Loi,
+                Lei,
+                Lai,
+                Lohi,
+                Lehi,
+                Lahi,
+                Lohe,
+                Lehe,
             }
             impl ::core::fmt::Debug for Gadri {
                 fn fmt(
@@ -142,8 +148,14 @@ pub mod lojban {
                         Gadri::Lo => f.debug_tuple("Gadri::Lo").finish(),
                         Gadri::Le => f.debug_tuple("Gadri::Le").finish(),
                         Gadri::La => f.debug_tuple("Gadri::La").finish(),
-                        Gadri::RoLo => f.debug_tuple("Gadri::RoLo").finish(),
-                        Gadri::RoLe => f.debug_tuple("Gadri::RoLe").finish(),
+                        Gadri::Loi => f.debug_tuple("Gadri::Loi").finish(),
+                        Gadri::Lei => f.debug_tuple("Gadri::Lei").finish(),
+                        Gadri::Lai => f.debug_tuple("Gadri::Lai").finish(),
+                        Gadri::Lohi => f.debug_tuple("Gadri::Lohi").finish(),
+                        Gadri::Lehi => f.debug_tuple("Gadri::Lehi").finish(),
+                        Gadri::Lahi => f.debug_tuple("Gadri::Lahi").finish(),
+                        Gadri::Lohe => f.debug_tuple("Gadri::Lohe").finish(),
+                        Gadri::Lehe => f.debug_tuple("Gadri::Lehe").finish(),
                     }
                 }
             }
@@ -157,8 +169,14 @@ pub mod lojban {
                         0 => Gadri::Lo,
                         1 => Gadri::Le,
                         2 => Gadri::La,
-                        3 => Gadri::RoLo,
-                        4 => Gadri::RoLe,
+                        3 => Gadri::Loi,
+                        4 => Gadri::Lei,
+                        5 => Gadri::Lai,
+                        6 => Gadri::Lohi,
+                        7 => Gadri::Lehi,
+                        8 => Gadri::Lahi,
+                        9 => Gadri::Lohe,
+                        10 => Gadri::Lehe,
                         _ => panic!("invalid enum discriminant"),
                     }
                 }
@@ -168,6 +186,12 @@ pub mod lojban {
             pub enum RelClauseKind {
                 Poi,
                 Noi,
+                Voi,
+                Goi,
+                Pe,
+                Ne,
+                Po,
+                Pohe,
             }
             impl ::core::fmt::Debug for RelClauseKind {
                 fn fmt(
@@ -181,6 +205,18 @@ pub mod lojban {
                         RelClauseKind::Noi => {
                             f.debug_tuple("RelClauseKind::Noi").finish()
                         }
+                        RelClauseKind::Voi => {
+                            f.debug_tuple("RelClauseKind::Voi").finish()
+                        }
+                        RelClauseKind::Goi => {
+                            f.debug_tuple("RelClauseKind::Goi").finish()
+                        }
+                        RelClauseKind::Pe => f.debug_tuple("RelClauseKind::Pe").finish(),
+                        RelClauseKind::Ne => f.debug_tuple("RelClauseKind::Ne").finish(),
+                        RelClauseKind::Po => f.debug_tuple("RelClauseKind::Po").finish(),
+                        RelClauseKind::Pohe => {
+                            f.debug_tuple("RelClauseKind::Pohe").finish()
+                        }
                     }
                 }
             }
@@ -193,15 +229,330 @@ pub mod lojban {
                     match val {
                         0 => RelClauseKind::Poi,
                         1 => RelClauseKind::Noi,
+                        2 => RelClauseKind::Voi,
+                        3 => RelClauseKind::Goi,
+                        4 => RelClauseKind::Pe,
+                        5 => RelClauseKind::Ne,
+                        6 => RelClauseKind::Po,
+                        7 => RelClauseKind::Pohe,
+                        _ => panic!("invalid enum discriminant"),
+                    }
+                }
+            }
+            #[repr(u8)]
+            #[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+            pub enum AbstractionKind {
+                Nu,
+                Duhu,
+                Ka,
+                Ni,
+                Siho,
+            }
+            impl ::core::fmt::Debug for AbstractionKind {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    match self {
+                        AbstractionKind::Nu => {
+                            f.debug_tuple("AbstractionKind::Nu").finish()
+                        }
+                        AbstractionKind::Duhu => {
+                            f.debug_tuple("AbstractionKind::Duhu").finish()
+                        }
+                        AbstractionKind::Ka => {
+                            f.debug_tuple("AbstractionKind::Ka").finish()
+                        }
+                        AbstractionKind::Ni => {
+                            f.debug_tuple("AbstractionKind::Ni").finish()
+                        }
+                        AbstractionKind::Siho => {
+                            f.debug_tuple("AbstractionKind::Siho").finish()
+                        }
+                    }
+                }
+            }
+            impl AbstractionKind {
+                #[doc(hidden)]
+                pub unsafe fn _lift(val: u8) -> AbstractionKind {
+                    if !cfg!(debug_assertions) {
+                        return ::core::mem::transmute(val);
+                    }
+                    match val {
+                        0 => AbstractionKind::Nu,
+                        1 => AbstractionKind::Duhu,
+                        2 => AbstractionKind::Ka,
+                        3 => AbstractionKind::Ni,
+                        4 => AbstractionKind::Siho,
+                        _ => panic!("invalid enum discriminant"),
+                    }
+                }
+            }
+            #[repr(u8)]
+            #[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+            pub enum TimeDirection {
+                Pu,
+                Ca,
+                Ba,
+            }
+            impl ::core::fmt::Debug for TimeDirection {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    match self {
+                        TimeDirection::Pu => f.debug_tuple("TimeDirection::Pu").finish(),
+                        TimeDirection::Ca => f.debug_tuple("TimeDirection::Ca").finish(),
+                        TimeDirection::Ba => f.debug_tuple("TimeDirection::Ba").finish(),
+                    }
+                }
+            }
+            impl TimeDirection {
+                #[doc(hidden)]
+                pub unsafe fn _lift(val: u8) -> TimeDirection {
+                    if !cfg!(debug_assertions) {
+                        return ::core::mem::transmute(val);
+                    }
+                    match val {
+                        0 => TimeDirection::Pu,
+                        1 => TimeDirection::Ca,
+                        2 => TimeDirection::Ba,
+                        _ => panic!("invalid enum discriminant"),
+                    }
+                }
+            }
+            #[repr(u8)]
+            #[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+            pub enum TimeDistance {
+                Zi,
+                Za,
+                Zu,
+            }
+            impl ::core::fmt::Debug for TimeDistance {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    match self {
+                        TimeDistance::Zi => f.debug_tuple("TimeDistance::Zi").finish(),
+                        TimeDistance::Za => f.debug_tuple("TimeDistance::Za").finish(),
+                        TimeDistance::Zu => f.debug_tuple("TimeDistance::Zu").finish(),
+                    }
+                }
+            }
+            impl TimeDistance {
+                #[doc(hidden)]
+                pub unsafe fn _lift(val: u8) -> TimeDistance {
+                    if !cfg!(debug_assertions) {
+                        return ::core::mem::transmute(val);
+                    }
+                    match val {
+                        0 => TimeDistance::Zi,
+                        1 => TimeDistance::Za,
+                        2 => TimeDistance::Zu,
+                        _ => panic!("invalid enum discriminant"),
+                    }
+                }
+            }
+            #[repr(u8)]
+            #[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+            pub enum Aspect {
+                Coha,
+                Cohu,
+                Caho,
+            }
+            impl ::core::fmt::Debug for Aspect {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    match self {
+                        Aspect::Coha => f.debug_tuple("Aspect::Coha").finish(),
+                        Aspect::Cohu => f.debug_tuple("Aspect::Cohu").finish(),
+                        Aspect::Caho => f.debug_tuple("Aspect::Caho").finish(),
+                    }
+                }
+            }
+            impl Aspect {
+                #[doc(hidden)]
+                pub unsafe fn _lift(val: u8) -> Aspect {
+                    if !cfg!(debug_assertions) {
+                        return ::core::mem::transmute(val);
+                    }
+                    match val {
+                        0 => Aspect::Coha,
+                        1 => Aspect::Cohu,
+                        2 => Aspect::Caho,
+                        _ => panic!("invalid enum discriminant"),
+                    }
+                }
+            }
+            #[repr(u8)]
+            #[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+            pub enum QuantifierKind {
+                All,
+                AtLeast,
+                Exactly,
+            }
+            impl ::core::fmt::Debug for QuantifierKind {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    match self {
+                        QuantifierKind::All => {
+                            f.debug_tuple("QuantifierKind::All").finish()
+                        }
+                        QuantifierKind::AtLeast => {
+                            f.debug_tuple("QuantifierKind::AtLeast").finish()
+                        }
+                        QuantifierKind::Exactly => {
+                            f.debug_tuple("QuantifierKind::Exactly").finish()
+                        }
+                    }
+                }
+            }
+            impl QuantifierKind {
+                #[doc(hidden)]
+                pub unsafe fn _lift(val: u8) -> QuantifierKind {
+                    if !cfg!(debug_assertions) {
+                        return ::core::mem::transmute(val);
+                    }
+                    match val {
+                        0 => QuantifierKind::All,
+                        1 => QuantifierKind::AtLeast,
+                        2 => QuantifierKind::Exactly,
                         _ => panic!("invalid enum discriminant"),
                     }
                 }
             }
+            /// Byte range in the source text, end-exclusive.
+            #[repr(C)]
+            #[derive(Clone, Copy)]
+            pub struct Span {
+                pub start: u32,
+                pub end: u32,
+            }
+            impl ::core::fmt::Debug for Span {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    f.debug_struct("Span")
+                        .field("start", &self.start)
+                        .field("end", &self.end)
+                        .finish()
+                }
+            }
+            /// PU? ZI? ZAhO? — at least one part is present.
+            #[repr(C)]
+            #[derive(Clone, Copy)]
+            pub struct Tense {
+                pub direction: Option<TimeDirection>,
+                pub distance: Option<TimeDistance>,
+                pub aspect: Option<Aspect>,
+            }
+            impl ::core::fmt::Debug for Tense {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    f.debug_struct("Tense")
+                        .field("direction", &self.direction)
+                        .field("distance", &self.distance)
+                        .field("aspect", &self.aspect)
+                        .finish()
+                }
+            }
+            /// ro → all; su'o N → at-least N (bare su'o: 1); PA → exactly N
+            /// (no: exactly 0). `count` is unused for `all`.
+            #[repr(C)]
+            #[derive(Clone, Copy)]
+            pub struct Quantifier {
+                pub kind: QuantifierKind,
+                pub count: u32,
+            }
+            impl ::core::fmt::Debug for Quantifier {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    f.debug_struct("Quantifier")
+                        .field("kind", &self.kind)
+                        .field("count", &self.count)
+                        .finish()
+                }
+            }
+            /// BAI cmavo (e.g. "ri'a") or ad-hoc fi'o + selbri.
+            #[derive(Clone)]
+            pub enum ModalTag {
+                Bai(_rt::String),
+                Fiho(SelbriId),
+            }
+            impl ::core::fmt::Debug for ModalTag {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    match self {
+                        ModalTag::Bai(e) => {
+                            f.debug_tuple("ModalTag::Bai").field(e).finish()
+                        }
+                        ModalTag::Fiho(e) => {
+                            f.debug_tuple("ModalTag::Fiho").field(e).finish()
+                        }
+                    }
+                }
+            }
+            /// se? GA nai? ... gi nai? ... — a connective with conversion and negation.
+            #[repr(C)]
+            #[derive(Clone, Copy)]
+            pub struct LogicalConnective {
+                pub connective: Connective,
+                pub converted: bool,
+                pub negate_left: bool,
+                pub negate_right: bool,
+            }
+            impl ::core::fmt::Debug for LogicalConnective {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    f.debug_struct("LogicalConnective")
+                        .field("connective", &self.connective)
+                        .field("converted", &self.converted)
+                        .field("negate-left", &self.negate_left)
+                        .field("negate-right", &self.negate_right)
+                        .finish()
+                }
+            }
+            /// poi/noi/voi attach a sentence (an index into `sentences`); goi
+            /// and the relative phrases pe/ne/po/po'e attach a sumti.
+            #[derive(Clone, Copy)]
+            pub enum RelClauseBody {
+                Sentence(u32),
+                Sumti(SumtiId),
+            }
+            impl ::core::fmt::Debug for RelClauseBody {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    match self {
+                        RelClauseBody::Sentence(e) => {
+                            f.debug_tuple("RelClauseBody::Sentence").field(e).finish()
+                        }
+                        RelClauseBody::Sumti(e) => {
+                            f.debug_tuple("RelClauseBody::Sumti").field(e).finish()
+                        }
+                    }
+                }
+            }
             #[repr(C)]
             #[derive(Clone, Copy)]
             pub struct RelClause {
                 pub kind: RelClauseKind,
-                pub body_sentence: u32,
+                pub body: RelClauseBody,
             }
             impl ::core::fmt::Debug for RelClause {
                 fn fmt(
@@ -210,19 +561,27 @@ pub mod lojban {
                 ) -> ::core::fmt::Result {
                     f.debug_struct("RelClause")
                         .field("kind", &self.kind)
-                        .field("body-sentence", &self.body_sentence)
+                        .field("body", &self.body)
                         .finish()
                 }
             }
             #[derive(Clone)]
             pub enum Sumti {
                 ProSumti(_rt::String),
-                Description((Gadri, SelbriId)),
+                /// ke'a xi N; 1 is the innermost relative clause
+                Relativized(u32),
+                /// gadri, inner quantifier ("lo ci gerku"), selbri
+                Description((Gadri, Option<Quantifier>, SelbriId)),
+                /// Outer quantifier on a description or da/de/di ("re lo gerku")
+                Quantified((Quantifier, SumtiId)),
                 Name(_rt::String),
                 QuotedLiteral(_rt::String),
                 Unspecified,
                 Tagged((PlaceTag, SumtiId)),
+                TenseTagged((Tense, SumtiId)),
+                ModalTagged((ModalTag, SumtiId)),
                 Restricted((SumtiId, RelClause)),
+                Connected((SumtiId, LogicalConnective, SumtiId)),
             }
             impl ::core::fmt::Debug for Sumti {
                 fn fmt(
@@ -233,9 +592,15 @@ pub mod lojban {
                         Sumti::ProSumti(e) => {
                             f.debug_tuple("Sumti::ProSumti").field(e).finish()
                         }
+                        Sumti::Relativized(e) => {
+                            f.debug_tuple("Sumti::Relativized").field(e).finish()
+                        }
                         Sumti::Description(e) => {
                             f.debug_tuple("Sumti::Description").field(e).finish()
                         }
+                        Sumti::Quantified(e) => {
+                            f.debug_tuple("Sumti::Quantified").field(e).finish()
+                        }
                         Sumti::Name(e) => f.debug_tuple("Sumti::Name").field(e).finish(),
                         Sumti::QuotedLiteral(e) => {
                             f.debug_tuple("Sumti::QuotedLiteral").field(e).finish()
@@ -246,9 +611,18 @@ pub mod lojban {
                         Sumti::Tagged(e) => {
                             f.debug_tuple("Sumti::Tagged").field(e).finish()
                         }
+                        Sumti::TenseTagged(e) => {
+                            f.debug_tuple("Sumti::TenseTagged").field(e).finish()
+                        }
+                        Sumti::ModalTagged(e) => {
+                            f.debug_tuple("Sumti::ModalTagged").field(e).finish()
+                        }
                         Sumti::Restricted(e) => {
                             f.debug_tuple("Sumti::Restricted").field(e).finish()
                         }
+                        Sumti::Connected(e) => {
+                            f.debug_tuple("Sumti::Connected").field(e).finish()
+                        }
                     }
                 }
             }
@@ -262,6 +636,8 @@ pub mod lojban {
                 Grouped(SelbriId),
                 WithArgs((SelbriId, _rt::Vec<SumtiId>)),
                 Connected((SelbriId, Connective, SelbriId)),
+                /// NU-class abstraction; the u32 indexes the body in `sentences`.
+                Abstraction((AbstractionKind, u32)),
             }
             impl ::core::fmt::Debug for Selbri {
                 fn fmt(
@@ -293,6 +669,9 @@ pub mod lojban {
                         Selbri::Connected(e) => {
                             f.debug_tuple("Selbri::Connected").field(e).finish()
                         }
+                        Selbri::Abstraction(e) => {
+                            f.debug_tuple("Selbri::Abstraction").field(e).finish()
+                        }
                     }
                 }
             }
@@ -302,6 +681,10 @@ pub mod lojban {
                 pub head_terms: _rt::Vec<SumtiId>,
                 pub tail_terms: _rt::Vec<SumtiId>,
                 pub negated: bool,
+                pub tense: Option<Tense>,
+                /// Marked with xu: a yes/no question rather than a claim.
+                pub question: bool,
+                pub span: Span,
             }
             impl ::core::fmt::Debug for Bridi {
                 fn fmt(
@@ -313,16 +696,71 @@ pub mod lojban {
                         .field("head-terms", &self.head_terms)
                         .field("tail-terms", &self.tail_terms)
                         .field("negated", &self.negated)
+                        .field("tense", &self.tense)
+                        .field("question", &self.question)
+                        .field("span", &self.span)
                         .finish()
                 }
             }
+            #[derive(Clone, Copy)]
+            pub enum PrenexTerm {
+                /// quantifier? + da/de/di; a bare variable is existential.
+                Variable((Option<Quantifier>, SumtiId)),
+                Naku,
+            }
+            impl ::core::fmt::Debug for PrenexTerm {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    match self {
+                        PrenexTerm::Variable(e) => {
+                            f.debug_tuple("PrenexTerm::Variable").field(e).finish()
+                        }
+                        PrenexTerm::Naku => f.debug_tuple("PrenexTerm::Naku").finish(),
+                    }
+                }
+            }
+            #[derive(Clone)]
+            pub enum Statement {
+                /// A single top-level bridi; the u32 indexes it in `sentences`.
+                Simple(u32),
+                /// Connected statements (GA ... gi, .i JA); the u32s index `statements`.
+                Connected((LogicalConnective, u32, u32)),
+                /// terms zo'u statement; the u32 indexes the body in `statements`.
+                Prenex((_rt::Vec<PrenexTerm>, u32)),
+            }
+            impl ::core::fmt::Debug for Statement {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    match self {
+                        Statement::Simple(e) => {
+                            f.debug_tuple("Statement::Simple").field(e).finish()
+                        }
+                        Statement::Connected(e) => {
+                            f.debug_tuple("Statement::Connected").field(e).finish()
+                        }
+                        Statement::Prenex(e) => {
+                            f.debug_tuple("Statement::Prenex").field(e).finish()
+                        }
+                    }
+                }
+            }
             #[derive(Clone)]
             pub struct AstBuffer {
                 pub selbris: _rt::Vec<Selbri>,
                 pub sumtis: _rt::Vec<Sumti>,
+                /// Source spans parallel to `selbris` and `sumtis`. Nested nodes
+                /// (description selbri, be/rel clause arguments, ...) share the span
+                /// of the term or selbri they belong to.
+                pub selbri_spans: _rt::Vec<Span>,
+                pub sumti_spans: _rt::Vec<Span>,
                 pub sentences: _rt::Vec<Bridi>,
-                /// Indices into `sentences` for top-level sentences only.
-                /// Rel clause bodies live in `sentences` but are NOT roots.
+                pub statements: _rt::Vec<Statement>,
+                /// Indices into `statements` for top-level statements only.
+                /// Rel clause and abstraction bodies live in `sentences` but are NOT roots.
                 pub roots: _rt::Vec<u32>,
             }
             impl ::core::fmt::Debug for AstBuffer {
@@ -333,7 +771,10 @@ pub mod lojban {
                     f.debug_struct("AstBuffer")
                         .field("selbris", &self.selbris)
                         .field("sumtis", &self.sumtis)
+                        .field("selbri-spans", &self.selbri_spans)
+                        .field("sumti-spans", &self.sumti_spans)
                         .field("sentences", &self.sentences)
+                        .field("statements", &self.statements)
                         .field("roots", &self.roots)
                         .finish()
                 }
@@ -345,6 +786,8 @@ pub mod lojban {
                 Constant(_rt::String),
                 Description(_rt::String),
                 Unspecified,
+                /// Reified proposition; the u32 indexes its formula in `nodes`.
+                Proposition(u32),
             }
             impl ::core::fmt::Debug for LogicalTerm {
                 fn fmt(
@@ -364,6 +807,9 @@ pub mod lojban {
                         LogicalTerm::Unspecified => {
                             f.debug_tuple("LogicalTerm::Unspecified").finish()
                         }
+                        LogicalTerm::Proposition(e) => {
+                            f.debug_tuple("LogicalTerm::Proposition").field(e).finish()
+                        }
                     }
                 }
             }
@@ -372,9 +818,15 @@ pub mod lojban {
                 Predicate((_rt::String, _rt::Vec<LogicalTerm>)),
                 AndNode((u32, u32)),
                 OrNode((u32, u32)),
+                ImpliesNode((u32, u32)),
                 NotNode(u32),
                 ExistsNode((_rt::String, u32)),
                 ForAllNode((_rt::String, u32)),
+                /// Time-indexed formula: the body holds at the given interval.
+                AtNode((LogicalTerm, u32)),
+                /// Defeasible generic (lo'e): variable, restrictor, body. Each x
+                /// meeting the restrictor meets the body unless that is contradicted.
+                GenericNode((_rt::String, u32, u32)),
             }
             impl ::core::fmt::Debug for LogicNode {
                 fn fmt(
@@ -388,73 +840,1287 @@ pub mod lojban {
                         LogicNode::AndNode(e) => {
                             f.debug_tuple("LogicNode::AndNode").field(e).finish()
                         }
-                        LogicNode::OrNode(e) => {
-                            f.debug_tuple("LogicNode::OrNode").field(e).finish()
+                        LogicNode::OrNode(e) => {
+                            f.debug_tuple("LogicNode::OrNode").field(e).finish()
+                        }
+                        LogicNode::ImpliesNode(e) => {
+                            f.debug_tuple("LogicNode::ImpliesNode").field(e).finish()
+                        }
+                        LogicNode::NotNode(e) => {
+                            f.debug_tuple("LogicNode::NotNode").field(e).finish()
+                        }
+                        LogicNode::ExistsNode(e) => {
+                            f.debug_tuple("LogicNode::ExistsNode").field(e).finish()
+                        }
+                        LogicNode::ForAllNode(e) => {
+                            f.debug_tuple("LogicNode::ForAllNode").field(e).finish()
+                        }
+                        LogicNode::AtNode(e) => {
+                            f.debug_tuple("LogicNode::AtNode").field(e).finish()
+                        }
+                        LogicNode::GenericNode(e) => {
+                            f.debug_tuple("LogicNode::GenericNode").field(e).finish()
+                        }
+                    }
+                }
+            }
+            #[derive(Clone)]
+            pub struct LogicBuffer {
+                pub nodes: _rt::Vec<LogicNode>,
+                pub roots: _rt::Vec<u32>,
+            }
+            impl ::core::fmt::Debug for LogicBuffer {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    f.debug_struct("LogicBuffer")
+                        .field("nodes", &self.nodes)
+                        .field("roots", &self.roots)
+                        .finish()
+                }
+            }
+        }
+    }
+}
+#[rustfmt::skip]
+#[allow(dead_code, clippy::all)]
+pub mod exports {
+    pub mod lojban {
+        pub mod nesy {
+            #[allow(dead_code, async_fn_in_trait, unused_imports, clippy::all)]
+            pub mod reasoning {
+                #[used]
+                #[doc(hidden)]
+                static __FORCE_SECTION_REF: fn() = super::super::super::super::__link_custom_section_describing_imports;
+                use super::super::super::super::_rt;
+                pub type LogicBuffer = super::super::super::super::lojban::nesy::ast_types::LogicBuffer;
+                /// What a question variable was bound to.
+                #[derive(Clone)]
+                pub enum AnswerTerm {
+                    /// An entity, for ma: a name, pro-sumti or Skolem constant.
+                    Entity(_rt::String),
+                    /// A relation, for mo.
+                    Relation(_rt::String),
+                }
+                impl ::core::fmt::Debug for AnswerTerm {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        match self {
+                            AnswerTerm::Entity(e) => {
+                                f.debug_tuple("AnswerTerm::Entity").field(e).finish()
+                            }
+                            AnswerTerm::Relation(e) => {
+                                f.debug_tuple("AnswerTerm::Relation").field(e).finish()
+                            }
+                        }
+                    }
+                }
+                #[derive(Clone)]
+                pub struct Binding {
+                    pub variable: _rt::String,
+                    pub value: AnswerTerm,
+                }
+                impl ::core::fmt::Debug for Binding {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        f.debug_struct("Binding")
+                            .field("variable", &self.variable)
+                            .field("value", &self.value)
+                            .finish()
+                    }
+                }
+                /// One way of filling every question variable.
+                pub type Substitution = _rt::Vec<Binding>;
+                /// The outcome of a query.
+                #[repr(u8)]
+                #[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+                pub enum TruthValue {
+                    /// The knowledge base entails the formula.
+                    Entailed,
+                    /// The knowledge base entails its negation.
+                    Refuted,
+                    /// Neither (open world only).
+                    Unknown,
+                }
+                impl ::core::fmt::Debug for TruthValue {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        match self {
+                            TruthValue::Entailed => {
+                                f.debug_tuple("TruthValue::Entailed").finish()
+                            }
+                            TruthValue::Refuted => {
+                                f.debug_tuple("TruthValue::Refuted").finish()
+                            }
+                            TruthValue::Unknown => {
+                                f.debug_tuple("TruthValue::Unknown").finish()
+                            }
+                        }
+                    }
+                }
+                impl TruthValue {
+                    #[doc(hidden)]
+                    pub unsafe fn _lift(val: u8) -> TruthValue {
+                        if !cfg!(debug_assertions) {
+                            return ::core::mem::transmute(val);
+                        }
+                        match val {
+                            0 => TruthValue::Entailed,
+                            1 => TruthValue::Refuted,
+                            2 => TruthValue::Unknown,
+                            _ => panic!("invalid enum discriminant"),
+                        }
+                    }
+                }
+                /// How a query treats what the knowledge base does not say.
+                #[repr(u8)]
+                #[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+                pub enum WorldAssumption {
+                    /// Whatever cannot be proven is false: na is negation as failure
+                    /// and ro ranges over the known entities.
+                    Closed,
+                    /// Only proofs count: a formula is refuted when its negation is
+                    /// entailed, and unknown when neither is.
+                    Open,
+                }
+                impl ::core::fmt::Debug for WorldAssumption {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        match self {
+                            WorldAssumption::Closed => {
+                                f.debug_tuple("WorldAssumption::Closed").finish()
+                            }
+                            WorldAssumption::Open => {
+                                f.debug_tuple("WorldAssumption::Open").finish()
+                            }
+                        }
+                    }
+                }
+                impl WorldAssumption {
+                    #[doc(hidden)]
+                    pub unsafe fn _lift(val: u8) -> WorldAssumption {
+                        if !cfg!(debug_assertions) {
+                            return ::core::mem::transmute(val);
+                        }
+                        match val {
+                            0 => WorldAssumption::Closed,
+                            1 => WorldAssumption::Open,
+                            _ => panic!("invalid enum discriminant"),
+                        }
+                    }
+                }
+                /// A formula that holds together with its negation.
+                #[derive(Clone)]
+                pub struct Conflict {
+                    /// The formula, as an s-expression.
+                    pub formula: _rt::String,
+                    /// The source sentences it was asserted or derived from.
+                    pub sources: _rt::Vec<_rt::String>,
+                }
+                impl ::core::fmt::Debug for Conflict {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        f.debug_struct("Conflict")
+                            .field("formula", &self.formula)
+                            .field("sources", &self.sources)
+                            .finish()
+                    }
+                }
+                /// What assert-fact does with an assertion that would make the
+                /// knowledge base inconsistent. Either way the knowledge base is left
+                /// as it was.
+                #[repr(u8)]
+                #[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+                pub enum ConflictPolicy {
+                    /// Drop the assertion.
+                    Reject,
+                    /// Keep the assertion aside, with its conflicts (see `quarantined`).
+                    Quarantine,
+                }
+                impl ::core::fmt::Debug for ConflictPolicy {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        match self {
+                            ConflictPolicy::Reject => {
+                                f.debug_tuple("ConflictPolicy::Reject").finish()
+                            }
+                            ConflictPolicy::Quarantine => {
+                                f.debug_tuple("ConflictPolicy::Quarantine").finish()
+                            }
+                        }
+                    }
+                }
+                impl ConflictPolicy {
+                    #[doc(hidden)]
+                    pub unsafe fn _lift(val: u8) -> ConflictPolicy {
+                        if !cfg!(debug_assertions) {
+                            return ::core::mem::transmute(val);
+                        }
+                        match val {
+                            0 => ConflictPolicy::Reject,
+                            1 => ConflictPolicy::Quarantine,
+                            _ => panic!("invalid enum discriminant"),
+                        }
+                    }
+                }
+                #[derive(Clone)]
+                pub enum AssertError {
+                    /// The assertion contradicts the knowledge base and was not added.
+                    Conflict(_rt::Vec<Conflict>),
+                    /// The assertion could not be processed.
+                    Failed(_rt::String),
+                }
+                impl ::core::fmt::Debug for AssertError {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        match self {
+                            AssertError::Conflict(e) => {
+                                f.debug_tuple("AssertError::Conflict").field(e).finish()
+                            }
+                            AssertError::Failed(e) => {
+                                f.debug_tuple("AssertError::Failed").field(e).finish()
+                            }
+                        }
+                    }
+                }
+                impl ::core::fmt::Display for AssertError {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        write!(f, "{:?}", self)
+                    }
+                }
+                impl std::error::Error for AssertError {}
+                /// Names one assert-fact call, for retracting it later.
+                pub type FactHandle = u64;
+                /// An assertion set aside by the quarantine policy.
+                #[derive(Clone)]
+                pub struct QuarantinedFact {
+                    pub sources: _rt::Vec<_rt::String>,
+                    pub conflicts: _rt::Vec<Conflict>,
+                }
+                impl ::core::fmt::Debug for QuarantinedFact {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        f.debug_struct("QuarantinedFact")
+                            .field("sources", &self.sources)
+                            .field("conflicts", &self.conflicts)
+                            .finish()
+                    }
+                }
+                /// An isolated knowledge base: its own e-graph, entities, universals,
+                /// defaults, facts and quarantine. A host can hold any number of them
+                /// (say one per session or test case); dropping one frees its state.
+                #[derive(Debug)]
+                #[repr(transparent)]
+                pub struct KnowledgeBase {
+                    handle: _rt::Resource<KnowledgeBase>,
+                }
+                type _KnowledgeBaseRep<T> = Option<T>;
+                impl KnowledgeBase {
+                    /// Creates a new resource from the specified representation.
+                    ///
+                    /// This function will create a new resource handle by moving `val` onto
+                    /// the heap and then passing that heap pointer to the component model to
+                    /// create a handle. The owned handle is then returned as `KnowledgeBase`.
+                    pub fn new<T: GuestKnowledgeBase>(val: T) -> Self {
+                        Self::type_guard::<T>();
+                        let val: _KnowledgeBaseRep<T> = Some(val);
+                        let ptr: *mut _KnowledgeBaseRep<T> = _rt::Box::into_raw(
+                            _rt::Box::new(val),
+                        );
+                        unsafe { Self::from_handle(T::_resource_new(ptr.cast())) }
+                    }
+                    /// Gets access to the underlying `T` which represents this resource.
+                    pub fn get<T: GuestKnowledgeBase>(&self) -> &T {
+                        let ptr = unsafe { &*self.as_ptr::<T>() };
+                        ptr.as_ref().unwrap()
+                    }
+                    /// Gets mutable access to the underlying `T` which represents this
+                    /// resource.
+                    pub fn get_mut<T: GuestKnowledgeBase>(&mut self) -> &mut T {
+                        let ptr = unsafe { &mut *self.as_ptr::<T>() };
+                        ptr.as_mut().unwrap()
+                    }
+                    /// Consumes this resource and returns the underlying `T`.
+                    pub fn into_inner<T: GuestKnowledgeBase>(self) -> T {
+                        let ptr = unsafe { &mut *self.as_ptr::<T>() };
+                        ptr.take().unwrap()
+                    }
+                    #[doc(hidden)]
+                    pub unsafe fn from_handle(handle: u32) -> Self {
+                        Self {
+                            handle: unsafe { _rt::Resource::from_handle(handle) },
+                        }
+                    }
+                    #[doc(hidden)]
+                    pub fn take_handle(&self) -> u32 {
+                        _rt::Resource::take_handle(&self.handle)
+                    }
+                    #[doc(hidden)]
+                    pub fn handle(&self) -> u32 {
+                        _rt::Resource::handle(&self.handle)
+                    }
+                    #[doc(hidden)]
+                    fn type_guard<T: 'static>() {
+                        use core::any::TypeId;
+                        static mut LAST_TYPE: Option<TypeId> = None;
+                        unsafe {
+                            assert!(! cfg!(target_feature = "atomics"));
+                            let id = TypeId::of::<T>();
+                            match LAST_TYPE {
+                                Some(ty) => {
+                                    assert!(
+                                        ty == id, "cannot use two types with this resource type"
+                                    )
+                                }
+                                None => LAST_TYPE = Some(id),
+                            }
+                        }
+                    }
+                    #[doc(hidden)]
+                    pub unsafe fn dtor<T: 'static>(handle: *mut u8) {
+                        Self::type_guard::<T>();
+                        let _ = unsafe {
+                            _rt::Box::from_raw(handle as *mut _KnowledgeBaseRep<T>)
+                        };
+                    }
+                    fn as_ptr<T: GuestKnowledgeBase>(
+                        &self,
+                    ) -> *mut _KnowledgeBaseRep<T> {
+                        KnowledgeBase::type_guard::<T>();
+                        T::_resource_rep(self.handle()).cast()
+                    }
+                }
+                /// A borrowed version of [`KnowledgeBase`] which represents a borrowed value
+                /// with the lifetime `'a`.
+                #[derive(Debug)]
+                #[repr(transparent)]
+                pub struct KnowledgeBaseBorrow<'a> {
+                    rep: *mut u8,
+                    _marker: core::marker::PhantomData<&'a KnowledgeBase>,
+                }
+                impl<'a> KnowledgeBaseBorrow<'a> {
+                    #[doc(hidden)]
+                    pub unsafe fn lift(rep: usize) -> Self {
+                        Self {
+                            rep: rep as *mut u8,
+                            _marker: core::marker::PhantomData,
+                        }
+                    }
+                    /// Gets access to the underlying `T` in this resource.
+                    pub fn get<T: GuestKnowledgeBase>(&self) -> &T {
+                        let ptr = unsafe { &mut *self.as_ptr::<T>() };
+                        ptr.as_ref().unwrap()
+                    }
+                    fn as_ptr<T: 'static>(&self) -> *mut _KnowledgeBaseRep<T> {
+                        KnowledgeBase::type_guard::<T>();
+                        self.rep.cast()
+                    }
+                }
+                unsafe impl _rt::WasmResource for KnowledgeBase {
+                    #[inline]
+                    unsafe fn drop(_handle: u32) {
+                        #[cfg(not(target_arch = "wasm32"))]
+                        unreachable!();
+                        #[cfg(target_arch = "wasm32")]
+                        {
+                            #[link(
+                                wasm_import_module = "[export]lojban:nesy/reasoning@0.1.0"
+                            )]
+                            unsafe extern "C" {
+                                #[link_name = "[resource-drop]knowledge-base"]
+                                fn drop(_: u32);
+                            }
+                            unsafe { drop(_handle) };
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_constructor_knowledge_base_cabi<
+                    T: GuestKnowledgeBase,
+                >() -> i32 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let result0 = KnowledgeBase::new(T::new());
+                    (result0).take_handle() as i32
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_knowledge_base_assert_fact_cabi<
+                    T: GuestKnowledgeBase,
+                >(
+                    arg0: *mut u8,
+                    arg1: *mut u8,
+                    arg2: usize,
+                    arg3: *mut u8,
+                    arg4: usize,
+                    arg5: *mut u8,
+                    arg6: usize,
+                    arg7: i32,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let base53 = arg1;
+                    let len53 = arg2;
+                    let mut result53 = _rt::Vec::with_capacity(len53);
+                    for i in 0..len53 {
+                        let base = base53
+                            .add(i * (5 * ::core::mem::size_of::<*const u8>()));
+                        let e53 = {
+                            let l0 = i32::from(*base.add(0).cast::<u8>());
+                            use super::super::super::super::lojban::nesy::ast_types::LogicNode as V52;
+                            let v52 = match l0 {
+                                0 => {
+                                    let e52 = {
+                                        let l1 = *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<*mut u8>();
+                                        let l2 = *base
+                                            .add(2 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<usize>();
+                                        let len3 = l2;
+                                        let bytes3 = _rt::Vec::from_raw_parts(
+                                            l1.cast(),
+                                            len3,
+                                            len3,
+                                        );
+                                        let l4 = *base
+                                            .add(3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<*mut u8>();
+                                        let l5 = *base
+                                            .add(4 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<usize>();
+                                        let base18 = l4;
+                                        let len18 = l5;
+                                        let mut result18 = _rt::Vec::with_capacity(len18);
+                                        for i in 0..len18 {
+                                            let base = base18
+                                                .add(i * (3 * ::core::mem::size_of::<*const u8>()));
+                                            let e18 = {
+                                                let l6 = i32::from(*base.add(0).cast::<u8>());
+                                                use super::super::super::super::lojban::nesy::ast_types::LogicalTerm as V17;
+                                                let v17 = match l6 {
+                                                    0 => {
+                                                        let e17 = {
+                                                            let l7 = *base
+                                                                .add(::core::mem::size_of::<*const u8>())
+                                                                .cast::<*mut u8>();
+                                                            let l8 = *base
+                                                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                                                .cast::<usize>();
+                                                            let len9 = l8;
+                                                            let bytes9 = _rt::Vec::from_raw_parts(
+                                                                l7.cast(),
+                                                                len9,
+                                                                len9,
+                                                            );
+                                                            _rt::string_lift(bytes9)
+                                                        };
+                                                        V17::Variable(e17)
+                                                    }
+                                                    1 => {
+                                                        let e17 = {
+                                                            let l10 = *base
+                                                                .add(::core::mem::size_of::<*const u8>())
+                                                                .cast::<*mut u8>();
+                                                            let l11 = *base
+                                                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                                                .cast::<usize>();
+                                                            let len12 = l11;
+                                                            let bytes12 = _rt::Vec::from_raw_parts(
+                                                                l10.cast(),
+                                                                len12,
+                                                                len12,
+                                                            );
+                                                            _rt::string_lift(bytes12)
+                                                        };
+                                                        V17::Constant(e17)
+                                                    }
+                                                    2 => {
+                                                        let e17 = {
+                                                            let l13 = *base
+                                                                .add(::core::mem::size_of::<*const u8>())
+                                                                .cast::<*mut u8>();
+                                                            let l14 = *base
+                                                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                                                .cast::<usize>();
+                                                            let len15 = l14;
+                                                            let bytes15 = _rt::Vec::from_raw_parts(
+                                                                l13.cast(),
+                                                                len15,
+                                                                len15,
+                                                            );
+                                                            _rt::string_lift(bytes15)
+                                                        };
+                                                        V17::Description(e17)
+                                                    }
+                                                    3 => V17::Unspecified,
+                                                    n => {
+                                                        debug_assert_eq!(n, 4, "invalid enum discriminant");
+                                                        let e17 = {
+                                                            let l16 = *base
+                                                                .add(::core::mem::size_of::<*const u8>())
+                                                                .cast::<i32>();
+                                                            l16 as u32
+                                                        };
+                                                        V17::Proposition(e17)
+                                                    }
+                                                };
+                                                v17
+                                            };
+                                            result18.push(e18);
+                                        }
+                                        _rt::cabi_dealloc(
+                                            base18,
+                                            len18 * (3 * ::core::mem::size_of::<*const u8>()),
+                                            ::core::mem::size_of::<*const u8>(),
+                                        );
+                                        (_rt::string_lift(bytes3), result18)
+                                    };
+                                    V52::Predicate(e52)
+                                }
+                                1 => {
+                                    let e52 = {
+                                        let l19 = *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        let l20 = *base
+                                            .add(4 + 1 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        (l19 as u32, l20 as u32)
+                                    };
+                                    V52::AndNode(e52)
+                                }
+                                2 => {
+                                    let e52 = {
+                                        let l21 = *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        let l22 = *base
+                                            .add(4 + 1 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        (l21 as u32, l22 as u32)
+                                    };
+                                    V52::OrNode(e52)
+                                }
+                                3 => {
+                                    let e52 = {
+                                        let l23 = *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        let l24 = *base
+                                            .add(4 + 1 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        (l23 as u32, l24 as u32)
+                                    };
+                                    V52::ImpliesNode(e52)
+                                }
+                                4 => {
+                                    let e52 = {
+                                        let l25 = *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        l25 as u32
+                                    };
+                                    V52::NotNode(e52)
+                                }
+                                5 => {
+                                    let e52 = {
+                                        let l26 = *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<*mut u8>();
+                                        let l27 = *base
+                                            .add(2 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<usize>();
+                                        let len28 = l27;
+                                        let bytes28 = _rt::Vec::from_raw_parts(
+                                            l26.cast(),
+                                            len28,
+                                            len28,
+                                        );
+                                        let l29 = *base
+                                            .add(3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        (_rt::string_lift(bytes28), l29 as u32)
+                                    };
+                                    V52::ExistsNode(e52)
+                                }
+                                6 => {
+                                    let e52 = {
+                                        let l30 = *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<*mut u8>();
+                                        let l31 = *base
+                                            .add(2 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<usize>();
+                                        let len32 = l31;
+                                        let bytes32 = _rt::Vec::from_raw_parts(
+                                            l30.cast(),
+                                            len32,
+                                            len32,
+                                        );
+                                        let l33 = *base
+                                            .add(3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        (_rt::string_lift(bytes32), l33 as u32)
+                                    };
+                                    V52::ForAllNode(e52)
+                                }
+                                7 => {
+                                    let e52 = {
+                                        let l34 = i32::from(
+                                            *base.add(::core::mem::size_of::<*const u8>()).cast::<u8>(),
+                                        );
+                                        use super::super::super::super::lojban::nesy::ast_types::LogicalTerm as V45;
+                                        let v45 = match l34 {
+                                            0 => {
+                                                let e45 = {
+                                                    let l35 = *base
+                                                        .add(2 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<*mut u8>();
+                                                    let l36 = *base
+                                                        .add(3 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<usize>();
+                                                    let len37 = l36;
+                                                    let bytes37 = _rt::Vec::from_raw_parts(
+                                                        l35.cast(),
+                                                        len37,
+                                                        len37,
+                                                    );
+                                                    _rt::string_lift(bytes37)
+                                                };
+                                                V45::Variable(e45)
+                                            }
+                                            1 => {
+                                                let e45 = {
+                                                    let l38 = *base
+                                                        .add(2 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<*mut u8>();
+                                                    let l39 = *base
+                                                        .add(3 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<usize>();
+                                                    let len40 = l39;
+                                                    let bytes40 = _rt::Vec::from_raw_parts(
+                                                        l38.cast(),
+                                                        len40,
+                                                        len40,
+                                                    );
+                                                    _rt::string_lift(bytes40)
+                                                };
+                                                V45::Constant(e45)
+                                            }
+                                            2 => {
+                                                let e45 = {
+                                                    let l41 = *base
+                                                        .add(2 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<*mut u8>();
+                                                    let l42 = *base
+                                                        .add(3 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<usize>();
+                                                    let len43 = l42;
+                                                    let bytes43 = _rt::Vec::from_raw_parts(
+                                                        l41.cast(),
+                                                        len43,
+                                                        len43,
+                                                    );
+                                                    _rt::string_lift(bytes43)
+                                                };
+                                                V45::Description(e45)
+                                            }
+                                            3 => V45::Unspecified,
+                                            n => {
+                                                debug_assert_eq!(n, 4, "invalid enum discriminant");
+                                                let e45 = {
+                                                    let l44 = *base
+                                                        .add(2 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<i32>();
+                                                    l44 as u32
+                                                };
+                                                V45::Proposition(e45)
+                                            }
+                                        };
+                                        let l46 = *base
+                                            .add(4 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        (v45, l46 as u32)
+                                    };
+                                    V52::AtNode(e52)
+                                }
+                                n => {
+                                    debug_assert_eq!(n, 8, "invalid enum discriminant");
+                                    let e52 = {
+                                        let l47 = *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<*mut u8>();
+                                        let l48 = *base
+                                            .add(2 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<usize>();
+                                        let len49 = l48;
+                                        let bytes49 = _rt::Vec::from_raw_parts(
+                                            l47.cast(),
+                                            len49,
+                                            len49,
+                                        );
+                                        let l50 = *base
+                                            .add(3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        let l51 = *base
+                                            .add(4 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        (_rt::string_lift(bytes49), l50 as u32, l51 as u32)
+                                    };
+                                    V52::GenericNode(e52)
+                                }
+                            };
+                            v52
+                        };
+                        result53.push(e53);
+                    }
+                    _rt::cabi_dealloc(
+                        base53,
+                        len53 * (5 * ::core::mem::size_of::<*const u8>()),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let len54 = arg4;
+                    let base58 = arg5;
+                    let len58 = arg6;
+                    let mut result58 = _rt::Vec::with_capacity(len58);
+                    for i in 0..len58 {
+                        let base = base58
+                            .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                        let e58 = {
+                            let l55 = *base.add(0).cast::<*mut u8>();
+                            let l56 = *base
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            let len57 = l56;
+                            let bytes57 = _rt::Vec::from_raw_parts(
+                                l55.cast(),
+                                len57,
+                                len57,
+                            );
+                            _rt::string_lift(bytes57)
+                        };
+                        result58.push(e58);
+                    }
+                    _rt::cabi_dealloc(
+                        base58,
+                        len58 * (2 * ::core::mem::size_of::<*const u8>()),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let result59 = T::assert_fact(
+                        unsafe { KnowledgeBaseBorrow::lift(arg0 as u32 as usize) }.get(),
+                        super::super::super::super::lojban::nesy::ast_types::LogicBuffer {
+                            nodes: result53,
+                            roots: _rt::Vec::from_raw_parts(arg3.cast(), len54, len54),
+                        },
+                        result58,
+                        ConflictPolicy::_lift(arg7 as u8),
+                    );
+                    let ptr60 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    match result59 {
+                        Ok(e) => {
+                            *ptr60.add(0).cast::<u8>() = (0i32) as u8;
+                            *ptr60.add(8).cast::<i64>() = _rt::as_i64(e);
+                        }
+                        Err(e) => {
+                            *ptr60.add(0).cast::<u8>() = (1i32) as u8;
+                            match e {
+                                AssertError::Conflict(e) => {
+                                    *ptr60.add(8).cast::<u8>() = (0i32) as u8;
+                                    let vec65 = e;
+                                    let len65 = vec65.len();
+                                    let layout65 = _rt::alloc::Layout::from_size_align_unchecked(
+                                        vec65.len() * (4 * ::core::mem::size_of::<*const u8>()),
+                                        ::core::mem::size_of::<*const u8>(),
+                                    );
+                                    let result65 = if layout65.size() != 0 {
+                                        let ptr = _rt::alloc::alloc(layout65).cast::<u8>();
+                                        if ptr.is_null() {
+                                            _rt::alloc::handle_alloc_error(layout65);
+                                        }
+                                        ptr
+                                    } else {
+                                        ::core::ptr::null_mut()
+                                    };
+                                    for (i, e) in vec65.into_iter().enumerate() {
+                                        let base = result65
+                                            .add(i * (4 * ::core::mem::size_of::<*const u8>()));
+                                        {
+                                            let Conflict { formula: formula61, sources: sources61 } = e;
+                                            let vec62 = (formula61.into_bytes()).into_boxed_slice();
+                                            let ptr62 = vec62.as_ptr().cast::<u8>();
+                                            let len62 = vec62.len();
+                                            ::core::mem::forget(vec62);
+                                            *base
+                                                .add(::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>() = len62;
+                                            *base.add(0).cast::<*mut u8>() = ptr62.cast_mut();
+                                            let vec64 = sources61;
+                                            let len64 = vec64.len();
+                                            let layout64 = _rt::alloc::Layout::from_size_align_unchecked(
+                                                vec64.len() * (2 * ::core::mem::size_of::<*const u8>()),
+                                                ::core::mem::size_of::<*const u8>(),
+                                            );
+                                            let result64 = if layout64.size() != 0 {
+                                                let ptr = _rt::alloc::alloc(layout64).cast::<u8>();
+                                                if ptr.is_null() {
+                                                    _rt::alloc::handle_alloc_error(layout64);
+                                                }
+                                                ptr
+                                            } else {
+                                                ::core::ptr::null_mut()
+                                            };
+                                            for (i, e) in vec64.into_iter().enumerate() {
+                                                let base = result64
+                                                    .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                                                {
+                                                    let vec63 = (e.into_bytes()).into_boxed_slice();
+                                                    let ptr63 = vec63.as_ptr().cast::<u8>();
+                                                    let len63 = vec63.len();
+                                                    ::core::mem::forget(vec63);
+                                                    *base
+                                                        .add(::core::mem::size_of::<*const u8>())
+                                                        .cast::<usize>() = len63;
+                                                    *base.add(0).cast::<*mut u8>() = ptr63.cast_mut();
+                                                }
+                                            }
+                                            *base
+                                                .add(3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>() = len64;
+                                            *base
+                                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>() = result64;
+                                        }
+                                    }
+                                    *ptr60
+                                        .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len65;
+                                    *ptr60
+                                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>() = result65;
+                                }
+                                AssertError::Failed(e) => {
+                                    *ptr60.add(8).cast::<u8>() = (1i32) as u8;
+                                    let vec66 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr66 = vec66.as_ptr().cast::<u8>();
+                                    let len66 = vec66.len();
+                                    ::core::mem::forget(vec66);
+                                    *ptr60
+                                        .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len66;
+                                    *ptr60
+                                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>() = ptr66.cast_mut();
+                                }
+                            }
+                        }
+                    };
+                    ptr60
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_knowledge_base_assert_fact<
+                    T: GuestKnowledgeBase,
+                >(arg0: *mut u8) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {}
+                        _ => {
+                            let l1 = i32::from(*arg0.add(8).cast::<u8>());
+                            match l1 {
+                                0 => {
+                                    let l2 = *arg0
+                                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>();
+                                    let l3 = *arg0
+                                        .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    let base11 = l2;
+                                    let len11 = l3;
+                                    for i in 0..len11 {
+                                        let base = base11
+                                            .add(i * (4 * ::core::mem::size_of::<*const u8>()));
+                                        {
+                                            let l4 = *base.add(0).cast::<*mut u8>();
+                                            let l5 = *base
+                                                .add(::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>();
+                                            _rt::cabi_dealloc(l4, l5, 1);
+                                            let l6 = *base
+                                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>();
+                                            let l7 = *base
+                                                .add(3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>();
+                                            let base10 = l6;
+                                            let len10 = l7;
+                                            for i in 0..len10 {
+                                                let base = base10
+                                                    .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                                                {
+                                                    let l8 = *base.add(0).cast::<*mut u8>();
+                                                    let l9 = *base
+                                                        .add(::core::mem::size_of::<*const u8>())
+                                                        .cast::<usize>();
+                                                    _rt::cabi_dealloc(l8, l9, 1);
+                                                }
+                                            }
+                                            _rt::cabi_dealloc(
+                                                base10,
+                                                len10 * (2 * ::core::mem::size_of::<*const u8>()),
+                                                ::core::mem::size_of::<*const u8>(),
+                                            );
+                                        }
+                                    }
+                                    _rt::cabi_dealloc(
+                                        base11,
+                                        len11 * (4 * ::core::mem::size_of::<*const u8>()),
+                                        ::core::mem::size_of::<*const u8>(),
+                                    );
+                                }
+                                _ => {
+                                    let l12 = *arg0
+                                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>();
+                                    let l13 = *arg0
+                                        .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l12, l13, 1);
+                                }
+                            }
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_knowledge_base_retract_fact_cabi<
+                    T: GuestKnowledgeBase,
+                >(arg0: *mut u8, arg1: i64) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let result0 = T::retract_fact(
+                        unsafe { KnowledgeBaseBorrow::lift(arg0 as u32 as usize) }.get(),
+                        arg1 as u64,
+                    );
+                    let ptr1 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    match result0 {
+                        Ok(_) => {
+                            *ptr1.add(0).cast::<u8>() = (0i32) as u8;
+                        }
+                        Err(e) => {
+                            *ptr1.add(0).cast::<u8>() = (1i32) as u8;
+                            let vec2 = (e.into_bytes()).into_boxed_slice();
+                            let ptr2 = vec2.as_ptr().cast::<u8>();
+                            let len2 = vec2.len();
+                            ::core::mem::forget(vec2);
+                            *ptr1
+                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len2;
+                            *ptr1
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>() = ptr2.cast_mut();
                         }
-                        LogicNode::NotNode(e) => {
-                            f.debug_tuple("LogicNode::NotNode").field(e).finish()
+                    };
+                    ptr1
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_knowledge_base_retract_fact<
+                    T: GuestKnowledgeBase,
+                >(arg0: *mut u8) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {}
+                        _ => {
+                            let l1 = *arg0
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l2 = *arg0
+                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            _rt::cabi_dealloc(l1, l2, 1);
                         }
-                        LogicNode::ExistsNode(e) => {
-                            f.debug_tuple("LogicNode::ExistsNode").field(e).finish()
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_knowledge_base_quarantined_cabi<
+                    T: GuestKnowledgeBase,
+                >(arg0: *mut u8) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let result0 = T::quarantined(
+                        unsafe { KnowledgeBaseBorrow::lift(arg0 as u32 as usize) }.get(),
+                    );
+                    let ptr1 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    let vec10 = result0;
+                    let len10 = vec10.len();
+                    let layout10 = _rt::alloc::Layout::from_size_align_unchecked(
+                        vec10.len() * (4 * ::core::mem::size_of::<*const u8>()),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let result10 = if layout10.size() != 0 {
+                        let ptr = _rt::alloc::alloc(layout10).cast::<u8>();
+                        if ptr.is_null() {
+                            _rt::alloc::handle_alloc_error(layout10);
                         }
-                        LogicNode::ForAllNode(e) => {
-                            f.debug_tuple("LogicNode::ForAllNode").field(e).finish()
+                        ptr
+                    } else {
+                        ::core::ptr::null_mut()
+                    };
+                    for (i, e) in vec10.into_iter().enumerate() {
+                        let base = result10
+                            .add(i * (4 * ::core::mem::size_of::<*const u8>()));
+                        {
+                            let QuarantinedFact {
+                                sources: sources2,
+                                conflicts: conflicts2,
+                            } = e;
+                            let vec4 = sources2;
+                            let len4 = vec4.len();
+                            let layout4 = _rt::alloc::Layout::from_size_align_unchecked(
+                                vec4.len() * (2 * ::core::mem::size_of::<*const u8>()),
+                                ::core::mem::size_of::<*const u8>(),
+                            );
+                            let result4 = if layout4.size() != 0 {
+                                let ptr = _rt::alloc::alloc(layout4).cast::<u8>();
+                                if ptr.is_null() {
+                                    _rt::alloc::handle_alloc_error(layout4);
+                                }
+                                ptr
+                            } else {
+                                ::core::ptr::null_mut()
+                            };
+                            for (i, e) in vec4.into_iter().enumerate() {
+                                let base = result4
+                                    .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                                {
+                                    let vec3 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr3 = vec3.as_ptr().cast::<u8>();
+                                    let len3 = vec3.len();
+                                    ::core::mem::forget(vec3);
+                                    *base
+                                        .add(::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len3;
+                                    *base.add(0).cast::<*mut u8>() = ptr3.cast_mut();
+                                }
+                            }
+                            *base
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len4;
+                            *base.add(0).cast::<*mut u8>() = result4;
+                            let vec9 = conflicts2;
+                            let len9 = vec9.len();
+                            let layout9 = _rt::alloc::Layout::from_size_align_unchecked(
+                                vec9.len() * (4 * ::core::mem::size_of::<*const u8>()),
+                                ::core::mem::size_of::<*const u8>(),
+                            );
+                            let result9 = if layout9.size() != 0 {
+                                let ptr = _rt::alloc::alloc(layout9).cast::<u8>();
+                                if ptr.is_null() {
+                                    _rt::alloc::handle_alloc_error(layout9);
+                                }
+                                ptr
+                            } else {
+                                ::core::ptr::null_mut()
+                            };
+                            for (i, e) in vec9.into_iter().enumerate() {
+                                let base = result9
+                                    .add(i * (4 * ::core::mem::size_of::<*const u8>()));
+                                {
+                                    let Conflict { formula: formula5, sources: sources5 } = e;
+                                    let vec6 = (formula5.into_bytes()).into_boxed_slice();
+                                    let ptr6 = vec6.as_ptr().cast::<u8>();
+                                    let len6 = vec6.len();
+                                    ::core::mem::forget(vec6);
+                                    *base
+                                        .add(::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len6;
+                                    *base.add(0).cast::<*mut u8>() = ptr6.cast_mut();
+                                    let vec8 = sources5;
+                                    let len8 = vec8.len();
+                                    let layout8 = _rt::alloc::Layout::from_size_align_unchecked(
+                                        vec8.len() * (2 * ::core::mem::size_of::<*const u8>()),
+                                        ::core::mem::size_of::<*const u8>(),
+                                    );
+                                    let result8 = if layout8.size() != 0 {
+                                        let ptr = _rt::alloc::alloc(layout8).cast::<u8>();
+                                        if ptr.is_null() {
+                                            _rt::alloc::handle_alloc_error(layout8);
+                                        }
+                                        ptr
+                                    } else {
+                                        ::core::ptr::null_mut()
+                                    };
+                                    for (i, e) in vec8.into_iter().enumerate() {
+                                        let base = result8
+                                            .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                                        {
+                                            let vec7 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr7 = vec7.as_ptr().cast::<u8>();
+                                            let len7 = vec7.len();
+                                            ::core::mem::forget(vec7);
+                                            *base
+                                                .add(::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>() = len7;
+                                            *base.add(0).cast::<*mut u8>() = ptr7.cast_mut();
+                                        }
+                                    }
+                                    *base
+                                        .add(3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len8;
+                                    *base
+                                        .add(2 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>() = result8;
+                                }
+                            }
+                            *base
+                                .add(3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len9;
+                            *base
+                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>() = result9;
                         }
                     }
+                    *ptr1.add(::core::mem::size_of::<*const u8>()).cast::<usize>() = len10;
+                    *ptr1.add(0).cast::<*mut u8>() = result10;
+                    ptr1
                 }
-            }
-            #[derive(Clone)]
-            pub struct LogicBuffer {
-                pub nodes: _rt::Vec<LogicNode>,
-                pub roots: _rt::Vec<u32>,
-            }
-            impl ::core::fmt::Debug for LogicBuffer {
-                fn fmt(
-                    &self,
-                    f: &mut ::core::fmt::Formatter<'_>,
-                ) -> ::core::fmt::Result {
-                    f.debug_struct("LogicBuffer")
-                        .field("nodes", &self.nodes)
-                        .field("roots", &self.roots)
-                        .finish()
-                }
-            }
-        }
-    }
-}
-#[rustfmt::skip]
-#[allow(dead_code, clippy::all)]
-pub mod exports {
-    pub mod lojban {
-        pub mod nesy {
-            #[allow(dead_code, async_fn_in_trait, unused_imports, clippy::all)]
-            pub mod reasoning {
-                #[used]
                 #[doc(hidden)]
-                static __FORCE_SECTION_REF: fn() = super::super::super::super::__link_custom_section_describing_imports;
-                use super::super::super::super::_rt;
-                pub type LogicBuffer = super::super::super::super::lojban::nesy::ast_types::LogicBuffer;
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_knowledge_base_quarantined<
+                    T: GuestKnowledgeBase,
+                >(arg0: *mut u8) {
+                    let l0 = *arg0.add(0).cast::<*mut u8>();
+                    let l1 = *arg0
+                        .add(::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base17 = l0;
+                    let len17 = l1;
+                    for i in 0..len17 {
+                        let base = base17
+                            .add(i * (4 * ::core::mem::size_of::<*const u8>()));
+                        {
+                            let l2 = *base.add(0).cast::<*mut u8>();
+                            let l3 = *base
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            let base6 = l2;
+                            let len6 = l3;
+                            for i in 0..len6 {
+                                let base = base6
+                                    .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                                {
+                                    let l4 = *base.add(0).cast::<*mut u8>();
+                                    let l5 = *base
+                                        .add(::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l4, l5, 1);
+                                }
+                            }
+                            _rt::cabi_dealloc(
+                                base6,
+                                len6 * (2 * ::core::mem::size_of::<*const u8>()),
+                                ::core::mem::size_of::<*const u8>(),
+                            );
+                            let l7 = *base
+                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l8 = *base
+                                .add(3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            let base16 = l7;
+                            let len16 = l8;
+                            for i in 0..len16 {
+                                let base = base16
+                                    .add(i * (4 * ::core::mem::size_of::<*const u8>()));
+                                {
+                                    let l9 = *base.add(0).cast::<*mut u8>();
+                                    let l10 = *base
+                                        .add(::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l9, l10, 1);
+                                    let l11 = *base
+                                        .add(2 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>();
+                                    let l12 = *base
+                                        .add(3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    let base15 = l11;
+                                    let len15 = l12;
+                                    for i in 0..len15 {
+                                        let base = base15
+                                            .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                                        {
+                                            let l13 = *base.add(0).cast::<*mut u8>();
+                                            let l14 = *base
+                                                .add(::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>();
+                                            _rt::cabi_dealloc(l13, l14, 1);
+                                        }
+                                    }
+                                    _rt::cabi_dealloc(
+                                        base15,
+                                        len15 * (2 * ::core::mem::size_of::<*const u8>()),
+                                        ::core::mem::size_of::<*const u8>(),
+                                    );
+                                }
+                            }
+                            _rt::cabi_dealloc(
+                                base16,
+                                len16 * (4 * ::core::mem::size_of::<*const u8>()),
+                                ::core::mem::size_of::<*const u8>(),
+                            );
+                        }
+                    }
+                    _rt::cabi_dealloc(
+                        base17,
+                        len17 * (4 * ::core::mem::size_of::<*const u8>()),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                }
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
-                pub unsafe fn _export_assert_fact_cabi<T: Guest>(
+                pub unsafe fn _export_method_knowledge_base_query_entailment_cabi<
+                    T: GuestKnowledgeBase,
+                >(
                     arg0: *mut u8,
-                    arg1: usize,
-                    arg2: *mut u8,
-                    arg3: usize,
+                    arg1: *mut u8,
+                    arg2: usize,
+                    arg3: *mut u8,
+                    arg4: usize,
+                    arg5: i32,
                 ) -> *mut u8 {
                     #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
-                    let base32 = arg0;
-                    let len32 = arg1;
-                    let mut result32 = _rt::Vec::with_capacity(len32);
-                    for i in 0..len32 {
-                        let base = base32
+                    let base53 = arg1;
+                    let len53 = arg2;
+                    let mut result53 = _rt::Vec::with_capacity(len53);
+                    for i in 0..len53 {
+                        let base = base53
                             .add(i * (5 * ::core::mem::size_of::<*const u8>()));
-                        let e32 = {
+                        let e53 = {
                             let l0 = i32::from(*base.add(0).cast::<u8>());
-                            use super::super::super::super::lojban::nesy::ast_types::LogicNode as V31;
-                            let v31 = match l0 {
+                            use super::super::super::super::lojban::nesy::ast_types::LogicNode as V52;
+                            let v52 = match l0 {
                                 0 => {
-                                    let e31 = {
+                                    let e52 = {
                                         let l1 = *base
                                             .add(::core::mem::size_of::<*const u8>())
                                             .cast::<*mut u8>();
@@ -473,18 +2139,18 @@ pub mod exports {
                                         let l5 = *base
                                             .add(4 * ::core::mem::size_of::<*const u8>())
                                             .cast::<usize>();
-                                        let base17 = l4;
-                                        let len17 = l5;
-                                        let mut result17 = _rt::Vec::with_capacity(len17);
-                                        for i in 0..len17 {
-                                            let base = base17
+                                        let base18 = l4;
+                                        let len18 = l5;
+                                        let mut result18 = _rt::Vec::with_capacity(len18);
+                                        for i in 0..len18 {
+                                            let base = base18
                                                 .add(i * (3 * ::core::mem::size_of::<*const u8>()));
-                                            let e17 = {
+                                            let e18 = {
                                                 let l6 = i32::from(*base.add(0).cast::<u8>());
-                                                use super::super::super::super::lojban::nesy::ast_types::LogicalTerm as V16;
-                                                let v16 = match l6 {
+                                                use super::super::super::super::lojban::nesy::ast_types::LogicalTerm as V17;
+                                                let v17 = match l6 {
                                                     0 => {
-                                                        let e16 = {
+                                                        let e17 = {
                                                             let l7 = *base
                                                                 .add(::core::mem::size_of::<*const u8>())
                                                                 .cast::<*mut u8>();
@@ -499,10 +2165,10 @@ pub mod exports {
                                                             );
                                                             _rt::string_lift(bytes9)
                                                         };
-                                                        V16::Variable(e16)
+                                                        V17::Variable(e17)
                                                     }
                                                     1 => {
-                                                        let e16 = {
+                                                        let e17 = {
                                                             let l10 = *base
                                                                 .add(::core::mem::size_of::<*const u8>())
                                                                 .cast::<*mut u8>();
@@ -517,10 +2183,10 @@ pub mod exports {
                                                             );
                                                             _rt::string_lift(bytes12)
                                                         };
-                                                        V16::Constant(e16)
+                                                        V17::Constant(e17)
                                                     }
                                                     2 => {
-                                                        let e16 = {
+                                                        let e17 = {
                                                             let l13 = *base
                                                                 .add(::core::mem::size_of::<*const u8>())
                                                                 .cast::<*mut u8>();
@@ -535,141 +2201,273 @@ pub mod exports {
                                                             );
                                                             _rt::string_lift(bytes15)
                                                         };
-                                                        V16::Description(e16)
+                                                        V17::Description(e17)
                                                     }
+                                                    3 => V17::Unspecified,
                                                     n => {
-                                                        debug_assert_eq!(n, 3, "invalid enum discriminant");
-                                                        V16::Unspecified
+                                                        debug_assert_eq!(n, 4, "invalid enum discriminant");
+                                                        let e17 = {
+                                                            let l16 = *base
+                                                                .add(::core::mem::size_of::<*const u8>())
+                                                                .cast::<i32>();
+                                                            l16 as u32
+                                                        };
+                                                        V17::Proposition(e17)
                                                     }
                                                 };
-                                                v16
+                                                v17
                                             };
-                                            result17.push(e17);
+                                            result18.push(e18);
                                         }
                                         _rt::cabi_dealloc(
-                                            base17,
-                                            len17 * (3 * ::core::mem::size_of::<*const u8>()),
+                                            base18,
+                                            len18 * (3 * ::core::mem::size_of::<*const u8>()),
                                             ::core::mem::size_of::<*const u8>(),
                                         );
-                                        (_rt::string_lift(bytes3), result17)
+                                        (_rt::string_lift(bytes3), result18)
                                     };
-                                    V31::Predicate(e31)
+                                    V52::Predicate(e52)
                                 }
                                 1 => {
-                                    let e31 = {
-                                        let l18 = *base
+                                    let e52 = {
+                                        let l19 = *base
                                             .add(::core::mem::size_of::<*const u8>())
                                             .cast::<i32>();
-                                        let l19 = *base
+                                        let l20 = *base
                                             .add(4 + 1 * ::core::mem::size_of::<*const u8>())
                                             .cast::<i32>();
-                                        (l18 as u32, l19 as u32)
+                                        (l19 as u32, l20 as u32)
                                     };
-                                    V31::AndNode(e31)
+                                    V52::AndNode(e52)
                                 }
                                 2 => {
-                                    let e31 = {
-                                        let l20 = *base
+                                    let e52 = {
+                                        let l21 = *base
                                             .add(::core::mem::size_of::<*const u8>())
                                             .cast::<i32>();
-                                        let l21 = *base
+                                        let l22 = *base
                                             .add(4 + 1 * ::core::mem::size_of::<*const u8>())
                                             .cast::<i32>();
-                                        (l20 as u32, l21 as u32)
+                                        (l21 as u32, l22 as u32)
                                     };
-                                    V31::OrNode(e31)
+                                    V52::OrNode(e52)
                                 }
                                 3 => {
-                                    let e31 = {
-                                        let l22 = *base
+                                    let e52 = {
+                                        let l23 = *base
                                             .add(::core::mem::size_of::<*const u8>())
                                             .cast::<i32>();
-                                        l22 as u32
+                                        let l24 = *base
+                                            .add(4 + 1 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        (l23 as u32, l24 as u32)
                                     };
-                                    V31::NotNode(e31)
+                                    V52::ImpliesNode(e52)
                                 }
                                 4 => {
-                                    let e31 = {
-                                        let l23 = *base
+                                    let e52 = {
+                                        let l25 = *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        l25 as u32
+                                    };
+                                    V52::NotNode(e52)
+                                }
+                                5 => {
+                                    let e52 = {
+                                        let l26 = *base
                                             .add(::core::mem::size_of::<*const u8>())
                                             .cast::<*mut u8>();
-                                        let l24 = *base
+                                        let l27 = *base
                                             .add(2 * ::core::mem::size_of::<*const u8>())
                                             .cast::<usize>();
-                                        let len25 = l24;
-                                        let bytes25 = _rt::Vec::from_raw_parts(
-                                            l23.cast(),
-                                            len25,
-                                            len25,
+                                        let len28 = l27;
+                                        let bytes28 = _rt::Vec::from_raw_parts(
+                                            l26.cast(),
+                                            len28,
+                                            len28,
                                         );
-                                        let l26 = *base
+                                        let l29 = *base
+                                            .add(3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        (_rt::string_lift(bytes28), l29 as u32)
+                                    };
+                                    V52::ExistsNode(e52)
+                                }
+                                6 => {
+                                    let e52 = {
+                                        let l30 = *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<*mut u8>();
+                                        let l31 = *base
+                                            .add(2 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<usize>();
+                                        let len32 = l31;
+                                        let bytes32 = _rt::Vec::from_raw_parts(
+                                            l30.cast(),
+                                            len32,
+                                            len32,
+                                        );
+                                        let l33 = *base
                                             .add(3 * ::core::mem::size_of::<*const u8>())
                                             .cast::<i32>();
-                                        (_rt::string_lift(bytes25), l26 as u32)
+                                        (_rt::string_lift(bytes32), l33 as u32)
+                                    };
+                                    V52::ForAllNode(e52)
+                                }
+                                7 => {
+                                    let e52 = {
+                                        let l34 = i32::from(
+                                            *base.add(::core::mem::size_of::<*const u8>()).cast::<u8>(),
+                                        );
+                                        use super::super::super::super::lojban::nesy::ast_types::LogicalTerm as V45;
+                                        let v45 = match l34 {
+                                            0 => {
+                                                let e45 = {
+                                                    let l35 = *base
+                                                        .add(2 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<*mut u8>();
+                                                    let l36 = *base
+                                                        .add(3 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<usize>();
+                                                    let len37 = l36;
+                                                    let bytes37 = _rt::Vec::from_raw_parts(
+                                                        l35.cast(),
+                                                        len37,
+                                                        len37,
+                                                    );
+                                                    _rt::string_lift(bytes37)
+                                                };
+                                                V45::Variable(e45)
+                                            }
+                                            1 => {
+                                                let e45 = {
+                                                    let l38 = *base
+                                                        .add(2 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<*mut u8>();
+                                                    let l39 = *base
+                                                        .add(3 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<usize>();
+                                                    let len40 = l39;
+                                                    let bytes40 = _rt::Vec::from_raw_parts(
+                                                        l38.cast(),
+                                                        len40,
+                                                        len40,
+                                                    );
+                                                    _rt::string_lift(bytes40)
+                                                };
+                                                V45::Constant(e45)
+                                            }
+                                            2 => {
+                                                let e45 = {
+                                                    let l41 = *base
+                                                        .add(2 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<*mut u8>();
+                                                    let l42 = *base
+                                                        .add(3 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<usize>();
+                                                    let len43 = l42;
+                                                    let bytes43 = _rt::Vec::from_raw_parts(
+                                                        l41.cast(),
+                                                        len43,
+                                                        len43,
+                                                    );
+                                                    _rt::string_lift(bytes43)
+                                                };
+                                                V45::Description(e45)
+                                            }
+                                            3 => V45::Unspecified,
+                                            n => {
+                                                debug_assert_eq!(n, 4, "invalid enum discriminant");
+                                                let e45 = {
+                                                    let l44 = *base
+                                                        .add(2 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<i32>();
+                                                    l44 as u32
+                                                };
+                                                V45::Proposition(e45)
+                                            }
+                                        };
+                                        let l46 = *base
+                                            .add(4 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        (v45, l46 as u32)
                                     };
-                                    V31::ExistsNode(e31)
+                                    V52::AtNode(e52)
                                 }
                                 n => {
-                                    debug_assert_eq!(n, 5, "invalid enum discriminant");
-                                    let e31 = {
-                                        let l27 = *base
+                                    debug_assert_eq!(n, 8, "invalid enum discriminant");
+                                    let e52 = {
+                                        let l47 = *base
                                             .add(::core::mem::size_of::<*const u8>())
                                             .cast::<*mut u8>();
-                                        let l28 = *base
+                                        let l48 = *base
                                             .add(2 * ::core::mem::size_of::<*const u8>())
                                             .cast::<usize>();
-                                        let len29 = l28;
-                                        let bytes29 = _rt::Vec::from_raw_parts(
-                                            l27.cast(),
-                                            len29,
-                                            len29,
+                                        let len49 = l48;
+                                        let bytes49 = _rt::Vec::from_raw_parts(
+                                            l47.cast(),
+                                            len49,
+                                            len49,
                                         );
-                                        let l30 = *base
+                                        let l50 = *base
                                             .add(3 * ::core::mem::size_of::<*const u8>())
                                             .cast::<i32>();
-                                        (_rt::string_lift(bytes29), l30 as u32)
+                                        let l51 = *base
+                                            .add(4 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        (_rt::string_lift(bytes49), l50 as u32, l51 as u32)
                                     };
-                                    V31::ForAllNode(e31)
+                                    V52::GenericNode(e52)
                                 }
                             };
-                            v31
+                            v52
                         };
-                        result32.push(e32);
+                        result53.push(e53);
                     }
                     _rt::cabi_dealloc(
-                        base32,
-                        len32 * (5 * ::core::mem::size_of::<*const u8>()),
+                        base53,
+                        len53 * (5 * ::core::mem::size_of::<*const u8>()),
                         ::core::mem::size_of::<*const u8>(),
                     );
-                    let len33 = arg3;
-                    let result34 = T::assert_fact(super::super::super::super::lojban::nesy::ast_types::LogicBuffer {
-                        nodes: result32,
-                        roots: _rt::Vec::from_raw_parts(arg2.cast(), len33, len33),
-                    });
-                    let ptr35 = (&raw mut _RET_AREA.0).cast::<u8>();
-                    match result34 {
-                        Ok(_) => {
-                            *ptr35.add(0).cast::<u8>() = (0i32) as u8;
+                    let len54 = arg4;
+                    let result55 = T::query_entailment(
+                        unsafe { KnowledgeBaseBorrow::lift(arg0 as u32 as usize) }.get(),
+                        super::super::super::super::lojban::nesy::ast_types::LogicBuffer {
+                            nodes: result53,
+                            roots: _rt::Vec::from_raw_parts(arg3.cast(), len54, len54),
+                        },
+                        WorldAssumption::_lift(arg5 as u8),
+                    );
+                    let ptr56 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    match result55 {
+                        Ok(e) => {
+                            *ptr56.add(0).cast::<u8>() = (0i32) as u8;
+                            *ptr56
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (e.clone() as i32) as u8;
                         }
                         Err(e) => {
-                            *ptr35.add(0).cast::<u8>() = (1i32) as u8;
-                            let vec36 = (e.into_bytes()).into_boxed_slice();
-                            let ptr36 = vec36.as_ptr().cast::<u8>();
-                            let len36 = vec36.len();
-                            ::core::mem::forget(vec36);
-                            *ptr35
+                            *ptr56.add(0).cast::<u8>() = (1i32) as u8;
+                            let vec57 = (e.into_bytes()).into_boxed_slice();
+                            let ptr57 = vec57.as_ptr().cast::<u8>();
+                            let len57 = vec57.len();
+                            ::core::mem::forget(vec57);
+                            *ptr56
                                 .add(2 * ::core::mem::size_of::<*const u8>())
-                                .cast::<usize>() = len36;
-                            *ptr35
+                                .cast::<usize>() = len57;
+                            *ptr56
                                 .add(::core::mem::size_of::<*const u8>())
-                                .cast::<*mut u8>() = ptr36.cast_mut();
+                                .cast::<*mut u8>() = ptr57.cast_mut();
                         }
                     };
-                    ptr35
+                    ptr56
                 }
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
-                pub unsafe fn __post_return_assert_fact<T: Guest>(arg0: *mut u8) {
+                pub unsafe fn __post_return_method_knowledge_base_query_entailment<
+                    T: GuestKnowledgeBase,
+                >(arg0: *mut u8) {
                     let l0 = i32::from(*arg0.add(0).cast::<u8>());
                     match l0 {
                         0 => {}
@@ -686,25 +2484,31 @@ pub mod exports {
                 }
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
-                pub unsafe fn _export_query_entailment_cabi<T: Guest>(
+                pub unsafe fn _export_method_knowledge_base_query_bindings_cabi<
+                    T: GuestKnowledgeBase,
+                >(
                     arg0: *mut u8,
-                    arg1: usize,
-                    arg2: *mut u8,
-                    arg3: usize,
+                    arg1: *mut u8,
+                    arg2: usize,
+                    arg3: *mut u8,
+                    arg4: usize,
+                    arg5: *mut u8,
+                    arg6: usize,
+                    arg7: i32,
                 ) -> *mut u8 {
                     #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
-                    let base32 = arg0;
-                    let len32 = arg1;
-                    let mut result32 = _rt::Vec::with_capacity(len32);
-                    for i in 0..len32 {
-                        let base = base32
+                    let base53 = arg1;
+                    let len53 = arg2;
+                    let mut result53 = _rt::Vec::with_capacity(len53);
+                    for i in 0..len53 {
+                        let base = base53
                             .add(i * (5 * ::core::mem::size_of::<*const u8>()));
-                        let e32 = {
+                        let e53 = {
                             let l0 = i32::from(*base.add(0).cast::<u8>());
-                            use super::super::super::super::lojban::nesy::ast_types::LogicNode as V31;
-                            let v31 = match l0 {
+                            use super::super::super::super::lojban::nesy::ast_types::LogicNode as V52;
+                            let v52 = match l0 {
                                 0 => {
-                                    let e31 = {
+                                    let e52 = {
                                         let l1 = *base
                                             .add(::core::mem::size_of::<*const u8>())
                                             .cast::<*mut u8>();
@@ -723,18 +2527,18 @@ pub mod exports {
                                         let l5 = *base
                                             .add(4 * ::core::mem::size_of::<*const u8>())
                                             .cast::<usize>();
-                                        let base17 = l4;
-                                        let len17 = l5;
-                                        let mut result17 = _rt::Vec::with_capacity(len17);
-                                        for i in 0..len17 {
-                                            let base = base17
+                                        let base18 = l4;
+                                        let len18 = l5;
+                                        let mut result18 = _rt::Vec::with_capacity(len18);
+                                        for i in 0..len18 {
+                                            let base = base18
                                                 .add(i * (3 * ::core::mem::size_of::<*const u8>()));
-                                            let e17 = {
+                                            let e18 = {
                                                 let l6 = i32::from(*base.add(0).cast::<u8>());
-                                                use super::super::super::super::lojban::nesy::ast_types::LogicalTerm as V16;
-                                                let v16 = match l6 {
+                                                use super::super::super::super::lojban::nesy::ast_types::LogicalTerm as V17;
+                                                let v17 = match l6 {
                                                     0 => {
-                                                        let e16 = {
+                                                        let e17 = {
                                                             let l7 = *base
                                                                 .add(::core::mem::size_of::<*const u8>())
                                                                 .cast::<*mut u8>();
@@ -749,10 +2553,10 @@ pub mod exports {
                                                             );
                                                             _rt::string_lift(bytes9)
                                                         };
-                                                        V16::Variable(e16)
+                                                        V17::Variable(e17)
                                                     }
                                                     1 => {
-                                                        let e16 = {
+                                                        let e17 = {
                                                             let l10 = *base
                                                                 .add(::core::mem::size_of::<*const u8>())
                                                                 .cast::<*mut u8>();
@@ -767,10 +2571,10 @@ pub mod exports {
                                                             );
                                                             _rt::string_lift(bytes12)
                                                         };
-                                                        V16::Constant(e16)
+                                                        V17::Constant(e17)
                                                     }
                                                     2 => {
-                                                        let e16 = {
+                                                        let e17 = {
                                                             let l13 = *base
                                                                 .add(::core::mem::size_of::<*const u8>())
                                                                 .cast::<*mut u8>();
@@ -785,201 +2589,690 @@ pub mod exports {
                                                             );
                                                             _rt::string_lift(bytes15)
                                                         };
-                                                        V16::Description(e16)
+                                                        V17::Description(e17)
                                                     }
+                                                    3 => V17::Unspecified,
                                                     n => {
-                                                        debug_assert_eq!(n, 3, "invalid enum discriminant");
-                                                        V16::Unspecified
+                                                        debug_assert_eq!(n, 4, "invalid enum discriminant");
+                                                        let e17 = {
+                                                            let l16 = *base
+                                                                .add(::core::mem::size_of::<*const u8>())
+                                                                .cast::<i32>();
+                                                            l16 as u32
+                                                        };
+                                                        V17::Proposition(e17)
                                                     }
                                                 };
-                                                v16
+                                                v17
                                             };
-                                            result17.push(e17);
+                                            result18.push(e18);
                                         }
                                         _rt::cabi_dealloc(
-                                            base17,
-                                            len17 * (3 * ::core::mem::size_of::<*const u8>()),
+                                            base18,
+                                            len18 * (3 * ::core::mem::size_of::<*const u8>()),
                                             ::core::mem::size_of::<*const u8>(),
                                         );
-                                        (_rt::string_lift(bytes3), result17)
+                                        (_rt::string_lift(bytes3), result18)
                                     };
-                                    V31::Predicate(e31)
+                                    V52::Predicate(e52)
                                 }
                                 1 => {
-                                    let e31 = {
-                                        let l18 = *base
+                                    let e52 = {
+                                        let l19 = *base
                                             .add(::core::mem::size_of::<*const u8>())
                                             .cast::<i32>();
-                                        let l19 = *base
+                                        let l20 = *base
                                             .add(4 + 1 * ::core::mem::size_of::<*const u8>())
                                             .cast::<i32>();
-                                        (l18 as u32, l19 as u32)
+                                        (l19 as u32, l20 as u32)
                                     };
-                                    V31::AndNode(e31)
+                                    V52::AndNode(e52)
                                 }
                                 2 => {
-                                    let e31 = {
-                                        let l20 = *base
+                                    let e52 = {
+                                        let l21 = *base
                                             .add(::core::mem::size_of::<*const u8>())
                                             .cast::<i32>();
-                                        let l21 = *base
+                                        let l22 = *base
                                             .add(4 + 1 * ::core::mem::size_of::<*const u8>())
                                             .cast::<i32>();
-                                        (l20 as u32, l21 as u32)
+                                        (l21 as u32, l22 as u32)
                                     };
-                                    V31::OrNode(e31)
+                                    V52::OrNode(e52)
                                 }
                                 3 => {
-                                    let e31 = {
-                                        let l22 = *base
+                                    let e52 = {
+                                        let l23 = *base
                                             .add(::core::mem::size_of::<*const u8>())
                                             .cast::<i32>();
-                                        l22 as u32
+                                        let l24 = *base
+                                            .add(4 + 1 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        (l23 as u32, l24 as u32)
                                     };
-                                    V31::NotNode(e31)
+                                    V52::ImpliesNode(e52)
                                 }
                                 4 => {
-                                    let e31 = {
-                                        let l23 = *base
+                                    let e52 = {
+                                        let l25 = *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        l25 as u32
+                                    };
+                                    V52::NotNode(e52)
+                                }
+                                5 => {
+                                    let e52 = {
+                                        let l26 = *base
                                             .add(::core::mem::size_of::<*const u8>())
                                             .cast::<*mut u8>();
-                                        let l24 = *base
+                                        let l27 = *base
                                             .add(2 * ::core::mem::size_of::<*const u8>())
                                             .cast::<usize>();
-                                        let len25 = l24;
-                                        let bytes25 = _rt::Vec::from_raw_parts(
-                                            l23.cast(),
-                                            len25,
-                                            len25,
+                                        let len28 = l27;
+                                        let bytes28 = _rt::Vec::from_raw_parts(
+                                            l26.cast(),
+                                            len28,
+                                            len28,
                                         );
-                                        let l26 = *base
+                                        let l29 = *base
+                                            .add(3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        (_rt::string_lift(bytes28), l29 as u32)
+                                    };
+                                    V52::ExistsNode(e52)
+                                }
+                                6 => {
+                                    let e52 = {
+                                        let l30 = *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<*mut u8>();
+                                        let l31 = *base
+                                            .add(2 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<usize>();
+                                        let len32 = l31;
+                                        let bytes32 = _rt::Vec::from_raw_parts(
+                                            l30.cast(),
+                                            len32,
+                                            len32,
+                                        );
+                                        let l33 = *base
                                             .add(3 * ::core::mem::size_of::<*const u8>())
                                             .cast::<i32>();
-                                        (_rt::string_lift(bytes25), l26 as u32)
+                                        (_rt::string_lift(bytes32), l33 as u32)
                                     };
-                                    V31::ExistsNode(e31)
+                                    V52::ForAllNode(e52)
+                                }
+                                7 => {
+                                    let e52 = {
+                                        let l34 = i32::from(
+                                            *base.add(::core::mem::size_of::<*const u8>()).cast::<u8>(),
+                                        );
+                                        use super::super::super::super::lojban::nesy::ast_types::LogicalTerm as V45;
+                                        let v45 = match l34 {
+                                            0 => {
+                                                let e45 = {
+                                                    let l35 = *base
+                                                        .add(2 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<*mut u8>();
+                                                    let l36 = *base
+                                                        .add(3 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<usize>();
+                                                    let len37 = l36;
+                                                    let bytes37 = _rt::Vec::from_raw_parts(
+                                                        l35.cast(),
+                                                        len37,
+                                                        len37,
+                                                    );
+                                                    _rt::string_lift(bytes37)
+                                                };
+                                                V45::Variable(e45)
+                                            }
+                                            1 => {
+                                                let e45 = {
+                                                    let l38 = *base
+                                                        .add(2 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<*mut u8>();
+                                                    let l39 = *base
+                                                        .add(3 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<usize>();
+                                                    let len40 = l39;
+                                                    let bytes40 = _rt::Vec::from_raw_parts(
+                                                        l38.cast(),
+                                                        len40,
+                                                        len40,
+                                                    );
+                                                    _rt::string_lift(bytes40)
+                                                };
+                                                V45::Constant(e45)
+                                            }
+                                            2 => {
+                                                let e45 = {
+                                                    let l41 = *base
+                                                        .add(2 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<*mut u8>();
+                                                    let l42 = *base
+                                                        .add(3 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<usize>();
+                                                    let len43 = l42;
+                                                    let bytes43 = _rt::Vec::from_raw_parts(
+                                                        l41.cast(),
+                                                        len43,
+                                                        len43,
+                                                    );
+                                                    _rt::string_lift(bytes43)
+                                                };
+                                                V45::Description(e45)
+                                            }
+                                            3 => V45::Unspecified,
+                                            n => {
+                                                debug_assert_eq!(n, 4, "invalid enum discriminant");
+                                                let e45 = {
+                                                    let l44 = *base
+                                                        .add(2 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<i32>();
+                                                    l44 as u32
+                                                };
+                                                V45::Proposition(e45)
+                                            }
+                                        };
+                                        let l46 = *base
+                                            .add(4 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        (v45, l46 as u32)
+                                    };
+                                    V52::AtNode(e52)
                                 }
                                 n => {
-                                    debug_assert_eq!(n, 5, "invalid enum discriminant");
-                                    let e31 = {
-                                        let l27 = *base
+                                    debug_assert_eq!(n, 8, "invalid enum discriminant");
+                                    let e52 = {
+                                        let l47 = *base
                                             .add(::core::mem::size_of::<*const u8>())
                                             .cast::<*mut u8>();
-                                        let l28 = *base
+                                        let l48 = *base
                                             .add(2 * ::core::mem::size_of::<*const u8>())
                                             .cast::<usize>();
-                                        let len29 = l28;
-                                        let bytes29 = _rt::Vec::from_raw_parts(
-                                            l27.cast(),
-                                            len29,
-                                            len29,
+                                        let len49 = l48;
+                                        let bytes49 = _rt::Vec::from_raw_parts(
+                                            l47.cast(),
+                                            len49,
+                                            len49,
                                         );
-                                        let l30 = *base
+                                        let l50 = *base
                                             .add(3 * ::core::mem::size_of::<*const u8>())
                                             .cast::<i32>();
-                                        (_rt::string_lift(bytes29), l30 as u32)
+                                        let l51 = *base
+                                            .add(4 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        (_rt::string_lift(bytes49), l50 as u32, l51 as u32)
                                     };
-                                    V31::ForAllNode(e31)
+                                    V52::GenericNode(e52)
                                 }
                             };
-                            v31
+                            v52
+                        };
+                        result53.push(e53);
+                    }
+                    _rt::cabi_dealloc(
+                        base53,
+                        len53 * (5 * ::core::mem::size_of::<*const u8>()),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let len54 = arg4;
+                    let base58 = arg5;
+                    let len58 = arg6;
+                    let mut result58 = _rt::Vec::with_capacity(len58);
+                    for i in 0..len58 {
+                        let base = base58
+                            .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                        let e58 = {
+                            let l55 = *base.add(0).cast::<*mut u8>();
+                            let l56 = *base
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            let len57 = l56;
+                            let bytes57 = _rt::Vec::from_raw_parts(
+                                l55.cast(),
+                                len57,
+                                len57,
+                            );
+                            _rt::string_lift(bytes57)
                         };
-                        result32.push(e32);
+                        result58.push(e58);
                     }
                     _rt::cabi_dealloc(
-                        base32,
-                        len32 * (5 * ::core::mem::size_of::<*const u8>()),
+                        base58,
+                        len58 * (2 * ::core::mem::size_of::<*const u8>()),
                         ::core::mem::size_of::<*const u8>(),
                     );
-                    let len33 = arg3;
-                    let result34 = T::query_entailment(super::super::super::super::lojban::nesy::ast_types::LogicBuffer {
-                        nodes: result32,
-                        roots: _rt::Vec::from_raw_parts(arg2.cast(), len33, len33),
-                    });
-                    let ptr35 = (&raw mut _RET_AREA.0).cast::<u8>();
-                    match result34 {
+                    let result59 = T::query_bindings(
+                        unsafe { KnowledgeBaseBorrow::lift(arg0 as u32 as usize) }.get(),
+                        super::super::super::super::lojban::nesy::ast_types::LogicBuffer {
+                            nodes: result53,
+                            roots: _rt::Vec::from_raw_parts(arg3.cast(), len54, len54),
+                        },
+                        result58,
+                        WorldAssumption::_lift(arg7 as u8),
+                    );
+                    let ptr60 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    match result59 {
                         Ok(e) => {
-                            *ptr35.add(0).cast::<u8>() = (0i32) as u8;
-                            *ptr35
+                            *ptr60.add(0).cast::<u8>() = (0i32) as u8;
+                            let vec66 = e;
+                            let len66 = vec66.len();
+                            let layout66 = _rt::alloc::Layout::from_size_align_unchecked(
+                                vec66.len() * (2 * ::core::mem::size_of::<*const u8>()),
+                                ::core::mem::size_of::<*const u8>(),
+                            );
+                            let result66 = if layout66.size() != 0 {
+                                let ptr = _rt::alloc::alloc(layout66).cast::<u8>();
+                                if ptr.is_null() {
+                                    _rt::alloc::handle_alloc_error(layout66);
+                                }
+                                ptr
+                            } else {
+                                ::core::ptr::null_mut()
+                            };
+                            for (i, e) in vec66.into_iter().enumerate() {
+                                let base = result66
+                                    .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                                {
+                                    let vec65 = e;
+                                    let len65 = vec65.len();
+                                    let layout65 = _rt::alloc::Layout::from_size_align_unchecked(
+                                        vec65.len() * (5 * ::core::mem::size_of::<*const u8>()),
+                                        ::core::mem::size_of::<*const u8>(),
+                                    );
+                                    let result65 = if layout65.size() != 0 {
+                                        let ptr = _rt::alloc::alloc(layout65).cast::<u8>();
+                                        if ptr.is_null() {
+                                            _rt::alloc::handle_alloc_error(layout65);
+                                        }
+                                        ptr
+                                    } else {
+                                        ::core::ptr::null_mut()
+                                    };
+                                    for (i, e) in vec65.into_iter().enumerate() {
+                                        let base = result65
+                                            .add(i * (5 * ::core::mem::size_of::<*const u8>()));
+                                        {
+                                            let Binding { variable: variable61, value: value61 } = e;
+                                            let vec62 = (variable61.into_bytes()).into_boxed_slice();
+                                            let ptr62 = vec62.as_ptr().cast::<u8>();
+                                            let len62 = vec62.len();
+                                            ::core::mem::forget(vec62);
+                                            *base
+                                                .add(::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>() = len62;
+                                            *base.add(0).cast::<*mut u8>() = ptr62.cast_mut();
+                                            match value61 {
+                                                AnswerTerm::Entity(e) => {
+                                                    *base
+                                                        .add(2 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<u8>() = (0i32) as u8;
+                                                    let vec63 = (e.into_bytes()).into_boxed_slice();
+                                                    let ptr63 = vec63.as_ptr().cast::<u8>();
+                                                    let len63 = vec63.len();
+                                                    ::core::mem::forget(vec63);
+                                                    *base
+                                                        .add(4 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<usize>() = len63;
+                                                    *base
+                                                        .add(3 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<*mut u8>() = ptr63.cast_mut();
+                                                }
+                                                AnswerTerm::Relation(e) => {
+                                                    *base
+                                                        .add(2 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<u8>() = (1i32) as u8;
+                                                    let vec64 = (e.into_bytes()).into_boxed_slice();
+                                                    let ptr64 = vec64.as_ptr().cast::<u8>();
+                                                    let len64 = vec64.len();
+                                                    ::core::mem::forget(vec64);
+                                                    *base
+                                                        .add(4 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<usize>() = len64;
+                                                    *base
+                                                        .add(3 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<*mut u8>() = ptr64.cast_mut();
+                                                }
+                                            }
+                                        }
+                                    }
+                                    *base
+                                        .add(::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len65;
+                                    *base.add(0).cast::<*mut u8>() = result65;
+                                }
+                            }
+                            *ptr60
+                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len66;
+                            *ptr60
                                 .add(::core::mem::size_of::<*const u8>())
-                                .cast::<u8>() = (match e {
-                                true => 1,
-                                false => 0,
-                            }) as u8;
+                                .cast::<*mut u8>() = result66;
                         }
                         Err(e) => {
-                            *ptr35.add(0).cast::<u8>() = (1i32) as u8;
-                            let vec36 = (e.into_bytes()).into_boxed_slice();
-                            let ptr36 = vec36.as_ptr().cast::<u8>();
-                            let len36 = vec36.len();
-                            ::core::mem::forget(vec36);
-                            *ptr35
+                            *ptr60.add(0).cast::<u8>() = (1i32) as u8;
+                            let vec67 = (e.into_bytes()).into_boxed_slice();
+                            let ptr67 = vec67.as_ptr().cast::<u8>();
+                            let len67 = vec67.len();
+                            ::core::mem::forget(vec67);
+                            *ptr60
                                 .add(2 * ::core::mem::size_of::<*const u8>())
-                                .cast::<usize>() = len36;
-                            *ptr35
+                                .cast::<usize>() = len67;
+                            *ptr60
                                 .add(::core::mem::size_of::<*const u8>())
-                                .cast::<*mut u8>() = ptr36.cast_mut();
+                                .cast::<*mut u8>() = ptr67.cast_mut();
                         }
                     };
-                    ptr35
+                    ptr60
                 }
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
-                pub unsafe fn __post_return_query_entailment<T: Guest>(arg0: *mut u8) {
+                pub unsafe fn __post_return_method_knowledge_base_query_bindings<
+                    T: GuestKnowledgeBase,
+                >(arg0: *mut u8) {
                     let l0 = i32::from(*arg0.add(0).cast::<u8>());
                     match l0 {
-                        0 => {}
-                        _ => {
+                        0 => {
                             let l1 = *arg0
                                 .add(::core::mem::size_of::<*const u8>())
                                 .cast::<*mut u8>();
                             let l2 = *arg0
                                 .add(2 * ::core::mem::size_of::<*const u8>())
                                 .cast::<usize>();
-                            _rt::cabi_dealloc(l1, l2, 1);
+                            let base13 = l1;
+                            let len13 = l2;
+                            for i in 0..len13 {
+                                let base = base13
+                                    .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                                {
+                                    let l3 = *base.add(0).cast::<*mut u8>();
+                                    let l4 = *base
+                                        .add(::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    let base12 = l3;
+                                    let len12 = l4;
+                                    for i in 0..len12 {
+                                        let base = base12
+                                            .add(i * (5 * ::core::mem::size_of::<*const u8>()));
+                                        {
+                                            let l5 = *base.add(0).cast::<*mut u8>();
+                                            let l6 = *base
+                                                .add(::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>();
+                                            _rt::cabi_dealloc(l5, l6, 1);
+                                            let l7 = i32::from(
+                                                *base
+                                                    .add(2 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<u8>(),
+                                            );
+                                            match l7 {
+                                                0 => {
+                                                    let l8 = *base
+                                                        .add(3 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<*mut u8>();
+                                                    let l9 = *base
+                                                        .add(4 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<usize>();
+                                                    _rt::cabi_dealloc(l8, l9, 1);
+                                                }
+                                                _ => {
+                                                    let l10 = *base
+                                                        .add(3 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<*mut u8>();
+                                                    let l11 = *base
+                                                        .add(4 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<usize>();
+                                                    _rt::cabi_dealloc(l10, l11, 1);
+                                                }
+                                            }
+                                        }
+                                    }
+                                    _rt::cabi_dealloc(
+                                        base12,
+                                        len12 * (5 * ::core::mem::size_of::<*const u8>()),
+                                        ::core::mem::size_of::<*const u8>(),
+                                    );
+                                }
+                            }
+                            _rt::cabi_dealloc(
+                                base13,
+                                len13 * (2 * ::core::mem::size_of::<*const u8>()),
+                                ::core::mem::size_of::<*const u8>(),
+                            );
+                        }
+                        _ => {
+                            let l14 = *arg0
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l15 = *arg0
+                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            _rt::cabi_dealloc(l14, l15, 1);
                         }
                     }
                 }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_knowledge_base_reset_cabi<
+                    T: GuestKnowledgeBase,
+                >(arg0: *mut u8) {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    T::reset(
+                        unsafe { KnowledgeBaseBorrow::lift(arg0 as u32 as usize) }.get(),
+                    );
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_knowledge_base_fork_cabi<
+                    T: GuestKnowledgeBase,
+                >(arg0: *mut u8) -> i32 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let result0 = T::fork(
+                        unsafe { KnowledgeBaseBorrow::lift(arg0 as u32 as usize) }.get(),
+                    );
+                    (result0).take_handle() as i32
+                }
                 pub trait Guest {
-                    fn assert_fact(logic: LogicBuffer) -> Result<(), _rt::String>;
-                    fn query_entailment(logic: LogicBuffer) -> Result<bool, _rt::String>;
+                    type KnowledgeBase: GuestKnowledgeBase;
+                }
+                pub trait GuestKnowledgeBase: 'static {
+                    #[doc(hidden)]
+                    unsafe fn _resource_new(val: *mut u8) -> u32
+                    where
+                        Self: Sized,
+                    {
+                        #[cfg(not(target_arch = "wasm32"))]
+                        {
+                            let _ = val;
+                            unreachable!();
+                        }
+                        #[cfg(target_arch = "wasm32")]
+                        {
+                            #[link(
+                                wasm_import_module = "[export]lojban:nesy/reasoning@0.1.0"
+                            )]
+                            unsafe extern "C" {
+                                #[link_name = "[resource-new]knowledge-base"]
+                                fn new(_: *mut u8) -> u32;
+                            }
+                            unsafe { new(val) }
+                        }
+                    }
+                    #[doc(hidden)]
+                    fn _resource_rep(handle: u32) -> *mut u8
+                    where
+                        Self: Sized,
+                    {
+                        #[cfg(not(target_arch = "wasm32"))]
+                        {
+                            let _ = handle;
+                            unreachable!();
+                        }
+                        #[cfg(target_arch = "wasm32")]
+                        {
+                            #[link(
+                                wasm_import_module = "[export]lojban:nesy/reasoning@0.1.0"
+                            )]
+                            unsafe extern "C" {
+                                #[link_name = "[resource-rep]knowledge-base"]
+                                fn rep(_: u32) -> *mut u8;
+                            }
+                            unsafe { rep(handle) }
+                        }
+                    }
+                    /// An empty knowledge base.
+                    fn new() -> Self;
+                    /// Assert each root of `logic`; `sources` holds the source sentence
+                    /// of each root. All roots are added, or none.
+                    fn assert_fact(
+                        &self,
+                        logic: LogicBuffer,
+                        sources: _rt::Vec<_rt::String>,
+                        policy: ConflictPolicy,
+                    ) -> Result<FactHandle, AssertError>;
+                    /// Withdraw an assertion, together with everything derived from it:
+                    /// its universals' instances, its Skolem constants and any conclusion
+                    /// that no remaining fact supports.
+                    fn retract_fact(
+                        &self,
+                        handle: FactHandle,
+                    ) -> Result<(), _rt::String>;
+                    fn quarantined(&self) -> _rt::Vec<QuarantinedFact>;
+                    fn query_entailment(
+                        &self,
+                        logic: LogicBuffer,
+                        assumption: WorldAssumption,
+                    ) -> Result<TruthValue, _rt::String>;
+                    /// The substitutions for `variables` under which the formula is
+                    /// entailed. A variable used as a relation name is a predicate variable.
+                    fn query_bindings(
+                        &self,
+                        logic: LogicBuffer,
+                        variables: _rt::Vec<_rt::String>,
+                        assumption: WorldAssumption,
+                    ) -> Result<_rt::Vec<Substitution>, _rt::String>;
+                    /// Forget everything asserted and quarantined.
+                    fn reset(&self) -> ();
+                    /// A copy of the knowledge base that evolves independently of it.
+                    fn fork(&self) -> KnowledgeBase;
                 }
                 #[doc(hidden)]
                 macro_rules! __export_lojban_nesy_reasoning_0_1_0_cabi {
                     ($ty:ident with_types_in $($path_to_types:tt)*) => {
                         const _ : () = { #[unsafe (export_name =
-                        "lojban:nesy/reasoning@0.1.0#assert-fact")] unsafe extern "C" fn
-                        export_assert_fact(arg0 : * mut u8, arg1 : usize, arg2 : * mut
-                        u8, arg3 : usize,) -> * mut u8 { unsafe { $($path_to_types)*::
-                        _export_assert_fact_cabi::<$ty > (arg0, arg1, arg2, arg3) } }
-                        #[unsafe (export_name =
-                        "cabi_post_lojban:nesy/reasoning@0.1.0#assert-fact")] unsafe
-                        extern "C" fn _post_return_assert_fact(arg0 : * mut u8,) { unsafe
-                        { $($path_to_types)*:: __post_return_assert_fact::<$ty > (arg0) }
-                        } #[unsafe (export_name =
-                        "lojban:nesy/reasoning@0.1.0#query-entailment")] unsafe extern
-                        "C" fn export_query_entailment(arg0 : * mut u8, arg1 : usize,
-                        arg2 : * mut u8, arg3 : usize,) -> * mut u8 { unsafe {
-                        $($path_to_types)*:: _export_query_entailment_cabi::<$ty > (arg0,
-                        arg1, arg2, arg3) } } #[unsafe (export_name =
-                        "cabi_post_lojban:nesy/reasoning@0.1.0#query-entailment")] unsafe
-                        extern "C" fn _post_return_query_entailment(arg0 : * mut u8,) {
+                        "lojban:nesy/reasoning@0.1.0#[constructor]knowledge-base")]
+                        unsafe extern "C" fn export_constructor_knowledge_base() -> i32 {
                         unsafe { $($path_to_types)*::
-                        __post_return_query_entailment::<$ty > (arg0) } } };
+                        _export_constructor_knowledge_base_cabi::<<$ty as
+                        $($path_to_types)*:: Guest >::KnowledgeBase > () } } #[unsafe
+                        (export_name =
+                        "lojban:nesy/reasoning@0.1.0#[method]knowledge-base.assert-fact")]
+                        unsafe extern "C" fn
+                        export_method_knowledge_base_assert_fact(arg0 : * mut u8, arg1 :
+                        * mut u8, arg2 : usize, arg3 : * mut u8, arg4 : usize, arg5 : *
+                        mut u8, arg6 : usize, arg7 : i32,) -> * mut u8 { unsafe {
+                        $($path_to_types)*::
+                        _export_method_knowledge_base_assert_fact_cabi::<<$ty as
+                        $($path_to_types)*:: Guest >::KnowledgeBase > (arg0, arg1, arg2,
+                        arg3, arg4, arg5, arg6, arg7) } } #[unsafe (export_name =
+                        "cabi_post_lojban:nesy/reasoning@0.1.0#[method]knowledge-base.assert-fact")]
+                        unsafe extern "C" fn
+                        _post_return_method_knowledge_base_assert_fact(arg0 : * mut u8,)
+                        { unsafe { $($path_to_types)*::
+                        __post_return_method_knowledge_base_assert_fact::<<$ty as
+                        $($path_to_types)*:: Guest >::KnowledgeBase > (arg0) } } #[unsafe
+                        (export_name =
+                        "lojban:nesy/reasoning@0.1.0#[method]knowledge-base.retract-fact")]
+                        unsafe extern "C" fn
+                        export_method_knowledge_base_retract_fact(arg0 : * mut u8, arg1 :
+                        i64,) -> * mut u8 { unsafe { $($path_to_types)*::
+                        _export_method_knowledge_base_retract_fact_cabi::<<$ty as
+                        $($path_to_types)*:: Guest >::KnowledgeBase > (arg0, arg1) } }
+                        #[unsafe (export_name =
+                        "cabi_post_lojban:nesy/reasoning@0.1.0#[method]knowledge-base.retract-fact")]
+                        unsafe extern "C" fn
+                        _post_return_method_knowledge_base_retract_fact(arg0 : * mut u8,)
+                        { unsafe { $($path_to_types)*::
+                        __post_return_method_knowledge_base_retract_fact::<<$ty as
+                        $($path_to_types)*:: Guest >::KnowledgeBase > (arg0) } } #[unsafe
+                        (export_name =
+                        "lojban:nesy/reasoning@0.1.0#[method]knowledge-base.quarantined")]
+                        unsafe extern "C" fn
+                        export_method_knowledge_base_quarantined(arg0 : * mut u8,) -> *
+                        mut u8 { unsafe { $($path_to_types)*::
+                        _export_method_knowledge_base_quarantined_cabi::<<$ty as
+                        $($path_to_types)*:: Guest >::KnowledgeBase > (arg0) } } #[unsafe
+                        (export_name =
+                        "cabi_post_lojban:nesy/reasoning@0.1.0#[method]knowledge-base.quarantined")]
+                        unsafe extern "C" fn
+                        _post_return_method_knowledge_base_quarantined(arg0 : * mut u8,)
+                        { unsafe { $($path_to_types)*::
+                        __post_return_method_knowledge_base_quarantined::<<$ty as
+                        $($path_to_types)*:: Guest >::KnowledgeBase > (arg0) } } #[unsafe
+                        (export_name =
+                        "lojban:nesy/reasoning@0.1.0#[method]knowledge-base.query-entailment")]
+                        unsafe extern "C" fn
+                        export_method_knowledge_base_query_entailment(arg0 : * mut u8,
+                        arg1 : * mut u8, arg2 : usize, arg3 : * mut u8, arg4 : usize,
+                        arg5 : i32,) -> * mut u8 { unsafe { $($path_to_types)*::
+                        _export_method_knowledge_base_query_entailment_cabi::<<$ty as
+                        $($path_to_types)*:: Guest >::KnowledgeBase > (arg0, arg1, arg2,
+                        arg3, arg4, arg5) } } #[unsafe (export_name =
+                        "cabi_post_lojban:nesy/reasoning@0.1.0#[method]knowledge-base.query-entailment")]
+                        unsafe extern "C" fn
+                        _post_return_method_knowledge_base_query_entailment(arg0 : * mut
+                        u8,) { unsafe { $($path_to_types)*::
+                        __post_return_method_knowledge_base_query_entailment::<<$ty as
+                        $($path_to_types)*:: Guest >::KnowledgeBase > (arg0) } } #[unsafe
+                        (export_name =
+                        "lojban:nesy/reasoning@0.1.0#[method]knowledge-base.query-bindings")]
+                        unsafe extern "C" fn
+                        export_method_knowledge_base_query_bindings(arg0 : * mut u8, arg1
+                        : * mut u8, arg2 : usize, arg3 : * mut u8, arg4 : usize, arg5 : *
+                        mut u8, arg6 : usize, arg7 : i32,) -> * mut u8 { unsafe {
+                        $($path_to_types)*::
+                        _export_method_knowledge_base_query_bindings_cabi::<<$ty as
+                        $($path_to_types)*:: Guest >::KnowledgeBase > (arg0, arg1, arg2,
+                        arg3, arg4, arg5, arg6, arg7) } } #[unsafe (export_name =
+                        "cabi_post_lojban:nesy/reasoning@0.1.0#[method]knowledge-base.query-bindings")]
+                        unsafe extern "C" fn
+                        _post_return_method_knowledge_base_query_bindings(arg0 : * mut
+                        u8,) { unsafe { $($path_to_types)*::
+                        __post_return_method_knowledge_base_query_bindings::<<$ty as
+                        $($path_to_types)*:: Guest >::KnowledgeBase > (arg0) } } #[unsafe
+                        (export_name =
+                        "lojban:nesy/reasoning@0.1.0#[method]knowledge-base.reset")]
+                        unsafe extern "C" fn export_method_knowledge_base_reset(arg0 : *
+                        mut u8,) { unsafe { $($path_to_types)*::
+                        _export_method_knowledge_base_reset_cabi::<<$ty as
+                        $($path_to_types)*:: Guest >::KnowledgeBase > (arg0) } } #[unsafe
+                        (export_name =
+                        "lojban:nesy/reasoning@0.1.0#[method]knowledge-base.fork")]
+                        unsafe extern "C" fn export_method_knowledge_base_fork(arg0 : *
+                        mut u8,) -> i32 { unsafe { $($path_to_types)*::
+                        _export_method_knowledge_base_fork_cabi::<<$ty as
+                        $($path_to_types)*:: Guest >::KnowledgeBase > (arg0) } } const _
+                        : () = { #[doc(hidden)] #[unsafe (export_name =
+                        "lojban:nesy/reasoning@0.1.0#[dtor]knowledge-base")]
+                        #[allow(non_snake_case)] unsafe extern "C" fn dtor(rep : * mut
+                        u8) { unsafe { $($path_to_types)*:: KnowledgeBase::dtor::< <$ty
+                        as $($path_to_types)*:: Guest >::KnowledgeBase > (rep) } } }; };
                     };
                 }
                 #[doc(hidden)]
                 pub(crate) use __export_lojban_nesy_reasoning_0_1_0_cabi;
-                #[cfg_attr(target_pointer_width = "64", repr(align(8)))]
-                #[cfg_attr(target_pointer_width = "32", repr(align(4)))]
+                #[repr(align(8))]
                 struct _RetArea(
                     [::core::mem::MaybeUninit<
                         u8,
-                    >; 3 * ::core::mem::size_of::<*const u8>()],
+                    >; 16 + 2 * ::core::mem::size_of::<*const u8>()],
                 );
                 static mut _RET_AREA: _RetArea = _RetArea(
-                    [::core::mem::MaybeUninit::uninit(); 3
-                        * ::core::mem::size_of::<*const u8>()],
+                    [::core::mem::MaybeUninit::uninit(); 16
+                        + 2 * ::core::mem::size_of::<*const u8>()],
                 );
             }
         }
@@ -990,6 +3283,81 @@ mod _rt {
     #![allow(dead_code, clippy::all)]
     pub use alloc_crate::string::String;
     pub use alloc_crate::vec::Vec;
+    use core::fmt;
+    use core::marker;
+    use core::sync::atomic::{AtomicU32, Ordering::Relaxed};
+    /// A type which represents a component model resource, either imported or
+    /// exported into this component.
+    ///
+    /// This is a low-level wrapper which handles the lifetime of the resource
+    /// (namely this has a destructor). The `T` provided defines the component model
+    /// intrinsics that this wrapper uses.
+    ///
+    /// One of the chief purposes of this type is to provide `Deref` implementations
+    /// to access the underlying data when it is owned.
+    ///
+    /// This type is primarily used in generated code for exported and imported
+    /// resources.
+    #[repr(transparent)]
+    pub struct Resource<T: WasmResource> {
+        handle: AtomicU32,
+        _marker: marker::PhantomData<T>,
+    }
+    /// A trait which all wasm resources implement, namely providing the ability to
+    /// drop a resource.
+    ///
+    /// This generally is implemented by generated code, not user-facing code.
+    #[allow(clippy::missing_safety_doc)]
+    pub unsafe trait WasmResource {
+        /// Invokes the `[resource-drop]...` intrinsic.
+        unsafe fn drop(handle: u32);
+    }
+    impl<T: WasmResource> Resource<T> {
+        #[doc(hidden)]
+        pub unsafe fn from_handle(handle: u32) -> Self {
+            debug_assert!(handle != u32::MAX);
+            Self {
+                handle: AtomicU32::new(handle),
+                _marker: marker::PhantomData,
+            }
+        }
+        /// Takes ownership of the handle owned by `resource`.
+        ///
+        /// Note that this ideally would be `into_handle` taking `Resource<T>` by
+        /// ownership. The code generator does not enable that in all situations,
+        /// unfortunately, so this is provided instead.
+        ///
+        /// Also note that `take_handle` is in theory only ever called on values
+        /// owned by a generated function. For example a generated function might
+        /// take `Resource<T>` as an argument but then call `take_handle` on a
+        /// reference to that argument. In that sense the dynamic nature of
+        /// `take_handle` should only be exposed internally to generated code, not
+        /// to user code.
+        #[doc(hidden)]
+        pub fn take_handle(resource: &Resource<T>) -> u32 {
+            resource.handle.swap(u32::MAX, Relaxed)
+        }
+        #[doc(hidden)]
+        pub fn handle(resource: &Resource<T>) -> u32 {
+            resource.handle.load(Relaxed)
+        }
+    }
+    impl<T: WasmResource> fmt::Debug for Resource<T> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_struct("Resource").field("handle", &self.handle).finish()
+        }
+    }
+    impl<T: WasmResource> Drop for Resource<T> {
+        fn drop(&mut self) {
+            unsafe {
+                match self.handle.load(Relaxed) {
+                    u32::MAX => {}
+                    other => T::drop(other),
+                }
+            }
+        }
+    }
+    pub use alloc_crate::boxed::Box;
     #[cfg(target_arch = "wasm32")]
     pub fn run_ctors_once() {
         wit_bindgen_rt::run_ctors_once();
@@ -1008,8 +3376,31 @@ mod _rt {
         let layout = alloc::Layout::from_size_align_unchecked(size, align);
         alloc::dealloc(ptr, layout);
     }
-    extern crate alloc as alloc_crate;
+    pub fn as_i64<T: AsI64>(t: T) -> i64 {
+        t.as_i64()
+    }
+    pub trait AsI64 {
+        fn as_i64(self) -> i64;
+    }
+    impl<'a, T: Copy + AsI64> AsI64 for &'a T {
+        fn as_i64(self) -> i64 {
+            (*self).as_i64()
+        }
+    }
+    impl AsI64 for i64 {
+        #[inline]
+        fn as_i64(self) -> i64 {
+            self as i64
+        }
+    }
+    impl AsI64 for u64 {
+        #[inline]
+        fn as_i64(self) -> i64 {
+            self as i64
+        }
+    }
     pub use alloc_crate::alloc;
+    extern crate alloc as alloc_crate;
 }
 /// Generates `#[unsafe(no_mangle)]` functions to export the specified type as
 /// the root implementation of all generated traits.
@@ -1047,35 +3438,71 @@ pub(crate) use __export_reasoning_component_impl as export;
 )]
 #[doc(hidden)]
 #[allow(clippy::octal_escapes)]
-pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 1207] = *b"\
-\0asm\x0d\0\x01\0\0\x19\x16wit-component-encoding\x04\0\x07\xad\x08\x01A\x02\x01\
-A\x05\x01B0\x01y\x04\0\x09selbri-id\x03\0\0\x01y\x04\0\x08sumti-id\x03\0\x02\x01\
+pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 2899] = *b"\
+\0asm\x0d\0\x01\0\0\x19\x16wit-component-encoding\x04\0\x07\xc9\x15\x01A\x02\x01\
+A\x05\x01B\\\x01y\x04\0\x09selbri-id\x03\0\0\x01y\x04\0\x08sumti-id\x03\0\x02\x01\
 m\x05\x02fa\x02fe\x02fi\x02fo\x02fu\x04\0\x09place-tag\x03\0\x04\x01m\x04\x02se\x02\
 te\x02ve\x02xe\x04\0\x0aconversion\x03\0\x06\x01m\x04\x02je\x02ja\x02jo\x02ju\x04\
-\0\x0aconnective\x03\0\x08\x01m\x05\x02lo\x02le\x02la\x05ro-lo\x05ro-le\x04\0\x05\
-gadri\x03\0\x0a\x01m\x02\x03poi\x03noi\x04\0\x0frel-clause-kind\x03\0\x0c\x01r\x02\
-\x04kind\x0d\x0dbody-sentencey\x04\0\x0arel-clause\x03\0\x0e\x01o\x02\x0b\x01\x01\
-o\x02\x05\x03\x01o\x02\x03\x0f\x01q\x07\x09pro-sumti\x01s\0\x0bdescription\x01\x10\
-\0\x04name\x01s\0\x0equoted-literal\x01s\0\x0bunspecified\0\0\x06tagged\x01\x11\0\
-\x0arestricted\x01\x12\0\x04\0\x05sumti\x03\0\x13\x01ps\x01o\x02\x01\x01\x01o\x02\
-\x07\x01\x01p\x03\x01o\x02\x01\x18\x01o\x03\x01\x09\x01\x01q\x08\x04root\x01s\0\x08\
-compound\x01\x15\0\x05tanru\x01\x16\0\x09converted\x01\x17\0\x07negated\x01\x01\0\
-\x07grouped\x01\x01\0\x09with-args\x01\x19\0\x09connected\x01\x1a\0\x04\0\x06sel\
-bri\x03\0\x1b\x01r\x04\x08relation\x01\x0ahead-terms\x18\x0atail-terms\x18\x07ne\
-gated\x7f\x04\0\x05bridi\x03\0\x1d\x01p\x1c\x01p\x14\x01p\x1e\x01py\x01r\x04\x07\
-selbris\x1f\x06sumtis\x20\x09sentences!\x05roots\"\x04\0\x0aast-buffer\x03\0#\x01\
-q\x04\x08variable\x01s\0\x08constant\x01s\0\x0bdescription\x01s\0\x0bunspecified\
-\0\0\x04\0\x0clogical-term\x03\0%\x01p&\x01o\x02s'\x01o\x02yy\x01o\x02sy\x01q\x06\
-\x09predicate\x01(\0\x08and-node\x01)\0\x07or-node\x01)\0\x08not-node\x01y\0\x0b\
-exists-node\x01*\0\x0cfor-all-node\x01*\0\x04\0\x0alogic-node\x03\0+\x01p,\x01r\x02\
-\x05nodes-\x05roots\"\x04\0\x0clogic-buffer\x03\0.\x03\0\x1blojban:nesy/ast-type\
-s@0.1.0\x05\0\x02\x03\0\0\x0clogic-buffer\x01B\x08\x02\x03\x02\x01\x01\x04\0\x0c\
-logic-buffer\x03\0\0\x01j\0\x01s\x01@\x01\x05logic\x01\0\x02\x04\0\x0bassert-fac\
-t\x01\x03\x01j\x01\x7f\x01s\x01@\x01\x05logic\x01\0\x04\x04\0\x10query-entailmen\
-t\x01\x05\x04\0\x1blojban:nesy/reasoning@0.1.0\x05\x02\x04\0%lojban:nesy/reasoni\
-ng-component@0.1.0\x04\0\x0b\x19\x01\0\x13reasoning-component\x03\0\0\0G\x09prod\
-ucers\x01\x0cprocessed-by\x02\x0dwit-component\x070.227.1\x10wit-bindgen-rust\x06\
-0.41.0";
+\0\x0aconnective\x03\0\x08\x01m\x0b\x02lo\x02le\x02la\x03loi\x03lei\x03lai\x04lo\
+hi\x04lehi\x04lahi\x04lohe\x04lehe\x04\0\x05gadri\x03\0\x0a\x01m\x08\x03poi\x03n\
+oi\x03voi\x03goi\x02pe\x02ne\x02po\x04pohe\x04\0\x0frel-clause-kind\x03\0\x0c\x01\
+m\x05\x02nu\x04duhu\x02ka\x02ni\x04siho\x04\0\x10abstraction-kind\x03\0\x0e\x01m\
+\x03\x02pu\x02ca\x02ba\x04\0\x0etime-direction\x03\0\x10\x01m\x03\x02zi\x02za\x02\
+zu\x04\0\x0dtime-distance\x03\0\x12\x01m\x03\x04coha\x04cohu\x04caho\x04\0\x06as\
+pect\x03\0\x14\x01m\x03\x03all\x08at-least\x07exactly\x04\0\x0fquantifier-kind\x03\
+\0\x16\x01r\x02\x05starty\x03endy\x04\0\x04span\x03\0\x18\x01k\x11\x01k\x13\x01k\
+\x15\x01r\x03\x09direction\x1a\x08distance\x1b\x06aspect\x1c\x04\0\x05tense\x03\0\
+\x1d\x01r\x02\x04kind\x17\x05county\x04\0\x0aquantifier\x03\0\x1f\x01q\x02\x03ba\
+i\x01s\0\x04fiho\x01\x01\0\x04\0\x09modal-tag\x03\0!\x01r\x04\x0aconnective\x09\x09\
+converted\x7f\x0bnegate-left\x7f\x0cnegate-right\x7f\x04\0\x12logical-connective\
+\x03\0#\x01q\x02\x08sentence\x01y\0\x05sumti\x01\x03\0\x04\0\x0frel-clause-body\x03\
+\0%\x01r\x02\x04kind\x0d\x04body&\x04\0\x0arel-clause\x03\0'\x01k\x20\x01o\x03\x0b\
+)\x01\x01o\x02\x20\x03\x01o\x02\x05\x03\x01o\x02\x1e\x03\x01o\x02\"\x03\x01o\x02\
+\x03(\x01o\x03\x03$\x03\x01q\x0c\x09pro-sumti\x01s\0\x0brelativized\x01y\0\x0bde\
+scription\x01*\0\x0aquantified\x01+\0\x04name\x01s\0\x0equoted-literal\x01s\0\x0b\
+unspecified\0\0\x06tagged\x01,\0\x0ctense-tagged\x01-\0\x0cmodal-tagged\x01.\0\x0a\
+restricted\x01/\0\x09connected\x010\0\x04\0\x05sumti\x03\01\x01ps\x01o\x02\x01\x01\
+\x01o\x02\x07\x01\x01p\x03\x01o\x02\x016\x01o\x03\x01\x09\x01\x01o\x02\x0fy\x01q\
+\x09\x04root\x01s\0\x08compound\x013\0\x05tanru\x014\0\x09converted\x015\0\x07ne\
+gated\x01\x01\0\x07grouped\x01\x01\0\x09with-args\x017\0\x09connected\x018\0\x0b\
+abstraction\x019\0\x04\0\x06selbri\x03\0:\x01k\x1e\x01r\x07\x08relation\x01\x0ah\
+ead-terms6\x0atail-terms6\x07negated\x7f\x05tense<\x08question\x7f\x04span\x19\x04\
+\0\x05bridi\x03\0=\x01o\x02)\x03\x01q\x02\x08variable\x01?\0\x04naku\0\0\x04\0\x0b\
+prenex-term\x03\0@\x01o\x03$yy\x01p\xc1\0\x01o\x02\xc3\0y\x01q\x03\x06simple\x01\
+y\0\x09connected\x01\xc2\0\0\x06prenex\x01\xc4\0\0\x04\0\x09statement\x03\0E\x01\
+p;\x01p2\x01p\x19\x01p>\x01p\xc6\0\x01py\x01r\x07\x07selbris\xc7\0\x06sumtis\xc8\
+\0\x0cselbri-spans\xc9\0\x0bsumti-spans\xc9\0\x09sentences\xca\0\x0astatements\xcb\
+\0\x05roots\xcc\0\x04\0\x0aast-buffer\x03\0M\x01q\x05\x08variable\x01s\0\x08cons\
+tant\x01s\0\x0bdescription\x01s\0\x0bunspecified\0\0\x0bproposition\x01y\0\x04\0\
+\x0clogical-term\x03\0O\x01p\xd0\0\x01o\x02s\xd1\0\x01o\x02yy\x01o\x02sy\x01o\x02\
+\xd0\0y\x01o\x03syy\x01q\x09\x09predicate\x01\xd2\0\0\x08and-node\x01\xd3\0\0\x07\
+or-node\x01\xd3\0\0\x0cimplies-node\x01\xd3\0\0\x08not-node\x01y\0\x0bexists-nod\
+e\x01\xd4\0\0\x0cfor-all-node\x01\xd4\0\0\x07at-node\x01\xd5\0\0\x0cgeneric-node\
+\x01\xd6\0\0\x04\0\x0alogic-node\x03\0W\x01p\xd8\0\x01r\x02\x05nodes\xd9\0\x05ro\
+ots\xcc\0\x04\0\x0clogic-buffer\x03\0Z\x03\0\x1blojban:nesy/ast-types@0.1.0\x05\0\
+\x02\x03\0\0\x0clogic-buffer\x01B1\x02\x03\x02\x01\x01\x04\0\x0clogic-buffer\x03\
+\0\0\x01q\x02\x06entity\x01s\0\x08relation\x01s\0\x04\0\x0banswer-term\x03\0\x02\
+\x01r\x02\x08variables\x05value\x03\x04\0\x07binding\x03\0\x04\x01p\x05\x04\0\x0c\
+substitution\x03\0\x06\x01m\x03\x08entailed\x07refuted\x07unknown\x04\0\x0btruth\
+-value\x03\0\x08\x01m\x02\x06closed\x04open\x04\0\x10world-assumption\x03\0\x0a\x01\
+ps\x01r\x02\x07formulas\x07sources\x0c\x04\0\x08conflict\x03\0\x0d\x01m\x02\x06r\
+eject\x0aquarantine\x04\0\x0fconflict-policy\x03\0\x0f\x01p\x0e\x01q\x02\x08conf\
+lict\x01\x11\0\x06failed\x01s\0\x04\0\x0cassert-error\x03\0\x12\x01w\x04\0\x0bfa\
+ct-handle\x03\0\x14\x01r\x02\x07sources\x0c\x09conflicts\x11\x04\0\x10quarantine\
+d-fact\x03\0\x16\x04\0\x0eknowledge-base\x03\x01\x01i\x18\x01@\0\0\x19\x04\0\x1b\
+[constructor]knowledge-base\x01\x1a\x01h\x18\x01j\x01\x15\x01\x13\x01@\x04\x04se\
+lf\x1b\x05logic\x01\x07sources\x0c\x06policy\x10\0\x1c\x04\0\"[method]knowledge-\
+base.assert-fact\x01\x1d\x01j\0\x01s\x01@\x02\x04self\x1b\x06handle\x15\0\x1e\x04\
+\0#[method]knowledge-base.retract-fact\x01\x1f\x01p\x17\x01@\x01\x04self\x1b\0\x20\
+\x04\0\"[method]knowledge-base.quarantined\x01!\x01j\x01\x09\x01s\x01@\x03\x04se\
+lf\x1b\x05logic\x01\x0aassumption\x0b\0\"\x04\0'[method]knowledge-base.query-ent\
+ailment\x01#\x01p\x07\x01j\x01$\x01s\x01@\x04\x04self\x1b\x05logic\x01\x09variab\
+les\x0c\x0aassumption\x0b\0%\x04\0%[method]knowledge-base.query-bindings\x01&\x01\
+@\x01\x04self\x1b\x01\0\x04\0\x1c[method]knowledge-base.reset\x01'\x01@\x01\x04s\
+elf\x1b\0\x19\x04\0\x1b[method]knowledge-base.fork\x01(\x04\0\x1blojban:nesy/rea\
+soning@0.1.0\x05\x02\x04\0%lojban:nesy/reasoning-component@0.1.0\x04\0\x0b\x19\x01\
+\0\x13reasoning-component\x03\0\0\0G\x09producers\x01\x0cprocessed-by\x02\x0dwit\
+-component\x070.227.1\x10wit-bindgen-rust\x060.41.0";
 #[inline(never)]
 #[doc(hidden)]
 pub fn __link_custom_section_describing_imports() {
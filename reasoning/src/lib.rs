@@ -1,105 +1,211 @@
 #[allow(warnings)]
 mod bindings;
 
-use crate::bindings::exports::lojban::nesy::reasoning::Guest;
+use crate::bindings::exports::lojban::nesy::reasoning::{
+    self, AnswerTerm, AssertError, Binding, Conflict, ConflictPolicy, FactHandle, Guest,
+    GuestKnowledgeBase, QuarantinedFact, Substitution, TruthValue, WorldAssumption,
+};
 use crate::bindings::lojban::nesy::ast_types::{LogicBuffer, LogicNode, LogicalTerm};
 use egglog::EGraph;
+use std::cell::RefCell;
 use std::collections::{HashMap, HashSet};
-use std::sync::{Mutex, OnceLock};
+use std::rc::Rc;
 
-// ─── Global Persistent State ──────────────────────────────────
+// ─── Knowledge Base State ─────────────────────────────────────
 
-static EGRAPH: OnceLock<Mutex<EGraph>> = OnceLock::new();
-static SKOLEM_COUNTER: OnceLock<Mutex<usize>> = OnceLock::new();
-static KNOWN_ENTITIES: OnceLock<Mutex<HashSet<String>>> = OnceLock::new();
-static UNIVERSAL_TEMPLATES: OnceLock<Mutex<Vec<UniversalTemplate>>> = OnceLock::new();
+/// Everything one knowledge base knows. Each `knowledge-base` resource
+/// owns one, so knowledge bases never see each other's facts; forking
+/// one is a clone.
+#[derive(Clone)]
+struct KnowledgeBase {
+    egraph: EGraph,
+    skolem_counter: usize,
+    entities: HashSet<String>,
+    /// Time intervals (`now`, event and reference intervals): a sort of
+    /// their own, so universals, witnesses and ma never range over them.
+    intervals: HashSet<String>,
+    /// Every relation asserted so far, with its arity: the candidates for mo.
+    relations: HashMap<String, usize>,
+    templates: Vec<UniversalTemplate>,
+    defaults: Vec<DefaultRule>,
+    facts: Vec<Fact>,
+    fact_counter: FactHandle,
+    quarantine: Vec<QuarantinedFact>,
+}
 
 /// A stored universal formula for Herbrand instantiation.
 /// When new entities appear, we instantiate the template for each.
+#[derive(Clone)]
 struct UniversalTemplate {
     var_name: String,
     /// The body s-expression with (Var "var_name") as placeholder
     body_sexp: String,
+    /// The body's atoms, with the same placeholder
+    atoms: Vec<String>,
+    /// The sentence the universal was asserted in
+    source: String,
+}
+
+/// An asserted root: its source sentence and the ground atoms it
+/// mentions, for tracing a conflict back to what was said, and enough
+/// to assert it again when the knowledge base is rebuilt.
+#[derive(Clone)]
+struct Fact {
+    /// The assert-fact call the root came in with
+    handle: FactHandle,
+    logic: Rc<LogicBuffer>,
+    root_id: u32,
+    /// The Skolem constants chosen for its existentials
+    skolems: HashMap<String, String>,
+    source: String,
+    atoms: Vec<String>,
 }
 
-fn fresh_skolem() -> String {
-    let counter = SKOLEM_COUNTER.get_or_init(|| Mutex::new(0));
-    let mut c = counter.lock().unwrap();
-    let sk = format!("sk_{}", *c);
-    *c += 1;
-    sk
-}
-
-/// Register an entity and instantiate all stored universals for it.
-/// Returns true if the entity was NEW (not previously known).
-fn register_entity(name: &str, egraph: &mut EGraph) -> bool {
-    let entities = KNOWN_ENTITIES.get_or_init(|| Mutex::new(HashSet::new()));
-    let is_new = entities.lock().unwrap().insert(name.to_string());
-
-    if is_new {
-        // Instantiate all stored universals for the new entity
-        let templates = UNIVERSAL_TEMPLATES.get_or_init(|| Mutex::new(Vec::new()));
-        let templates_guard = templates.lock().unwrap();
-        for tmpl in templates_guard.iter() {
-            let instantiated = tmpl.body_sexp.replace(
-                &format!("(Var \"{}\")", tmpl.var_name),
-                &format!("(Const \"{}\")", name),
-            );
-            let command = format!("(IsTrue {})", instantiated);
-            if let Err(e) = egraph.parse_and_run_program(None, &command) {
-                eprintln!(
-                    "[reasoning] Failed to instantiate universal for {}: {}",
-                    name, e
+/// A stored generic (lo'e): members of the restrictor typically satisfy
+/// the conclusion. Unlike a universal it is never asserted per entity;
+/// queries fall back on it when an atom is not provable and its negation
+/// is not known.
+#[derive(Clone)]
+struct DefaultRule {
+    var_name: String,
+    /// Restrictor conjuncts with (Var "var_name") as placeholder
+    restrictor: Vec<String>,
+    /// Body conjuncts with (Var "var_name") as placeholder
+    conclusion: Vec<String>,
+    /// Restrictor and body with the variable renamed, for matching a
+    /// queried generic against the stored ones
+    canonical: String,
+}
+
+impl KnowledgeBase {
+    fn new() -> Self {
+        Self {
+            egraph: new_egraph(),
+            skolem_counter: 0,
+            entities: HashSet::new(),
+            intervals: HashSet::new(),
+            relations: HashMap::new(),
+            templates: Vec::new(),
+            defaults: Vec::new(),
+            facts: Vec::new(),
+            fact_counter: 0,
+            quarantine: Vec::new(),
+        }
+    }
+
+    fn fresh_skolem(&mut self) -> String {
+        let sk = format!("sk_{}", self.skolem_counter);
+        self.skolem_counter += 1;
+        sk
+    }
+
+    fn fresh_handle(&mut self) -> FactHandle {
+        let handle = self.fact_counter;
+        self.fact_counter += 1;
+        handle
+    }
+
+    /// Register an entity and instantiate all stored universals for it.
+    /// Returns true if the entity was NEW (not previously known).
+    fn register_entity(&mut self, name: &str) -> bool {
+        let is_new = self.entities.insert(name.to_string());
+
+        if is_new {
+            // Instantiate all stored universals for the new entity
+            for tmpl in &self.templates {
+                let instantiated = tmpl.body_sexp.replace(
+                    &format!("(Var \"{}\")", tmpl.var_name),
+                    &format!("(Const \"{}\")", name),
                 );
+                let command = format!("(IsTrue {})", instantiated);
+                if let Err(e) = self.egraph.parse_and_run_program(None, &command) {
+                    eprintln!(
+                        "[reasoning] Failed to instantiate universal for {}: {}",
+                        name, e
+                    );
+                }
             }
         }
+
+        is_new
     }
 
-    is_new
-}
+    fn known_entities(&self) -> Vec<String> {
+        self.entities.iter().cloned().collect()
+    }
 
-fn get_known_entities() -> Vec<String> {
-    let entities = KNOWN_ENTITIES.get_or_init(|| Mutex::new(HashSet::new()));
-    entities.lock().unwrap().iter().cloned().collect()
+    fn known_intervals(&self) -> Vec<String> {
+        self.intervals.iter().cloned().collect()
+    }
+
+    /// What a variable of `buffer` ranges over: the intervals if it is a
+    /// time variable, the entities otherwise.
+    fn domain(&self, buffer: &LogicBuffer, var: &str) -> Vec<String> {
+        if interval_variables(buffer).contains(var) {
+            self.known_intervals()
+        } else {
+            self.known_entities()
+        }
+    }
+
+    fn known_relations(&self) -> Vec<(String, usize)> {
+        let mut relations: Vec<_> = self
+            .relations
+            .iter()
+            .map(|(r, &arity)| (r.clone(), arity))
+            .collect();
+        relations.sort();
+        relations
+    }
 }
 
-fn get_egraph() -> &'static Mutex<EGraph> {
-    EGRAPH.get_or_init(|| {
-        let mut egraph = EGraph::default();
+/// An empty e-graph with the schema and rules loaded.
+fn new_egraph() -> EGraph {
+    let mut egraph = EGraph::default();
 
-        let schema_str = r#"
+    let schema_str = r#"
             ;; ═══════════════════════════════════════════════
             ;; Lojban NeSy Engine — FOL Schema & Rules
             ;; Phase 6b: Skolemization + Herbrand instantiation
             ;; ═══════════════════════════════════════════════
 
-            ;; Atomic Terms
-            (datatype Term
-                (Var String)
-                (Const String)
-                (Desc String)
-                (Zoe)
-            )
+            ;; Terms, argument lists and formulas are mutually recursive:
+            ;; a reified proposition (Prop) is a Term that wraps a Formula,
+            ;; so predicates like djuno/rinka can take propositions as arguments.
+            (datatype*
+                ;; Atomic Terms
+                (Term
+                    (Var String)
+                    (Const String)
+                    (Desc String)
+                    (Zoe)
+                    (Prop Formula)
+                )
 
-            ;; Variadic Argument List (Linked List)
-            (datatype TermList
-                (Nil)
-                (Cons Term TermList)
-            )
+                ;; Variadic Argument List (Linked List)
+                (TermList
+                    (Nil)
+                    (Cons Term TermList)
+                )
 
-            ;; Well-Formed Formulas
-            (datatype Formula
-                (Pred String TermList)
-                (And Formula Formula)
-                (Or Formula Formula)
-                (Not Formula)
-                (Implies Formula Formula)
-                (Exists String Formula)
-                (ForAll String Formula)
+                ;; Well-Formed Formulas
+                (Formula
+                    (Pred String TermList)
+                    (And Formula Formula)
+                    (Or Formula Formula)
+                    (Not Formula)
+                    (Implies Formula Formula)
+                    (Exists String Formula)
+                    (ForAll String Formula)
+                    ;; Time-indexed: formula holds at interval Term
+                    (At Term Formula)
+                    (Generic String Formula Formula)
+                )
             )
 
             ;; The Knowledge Base
             (relation IsTrue (Formula))
+            (relation Conflict (Formula))
 
             ;; ───────────────────────────────────────────────
             ;; STRUCTURAL REWRITES
@@ -150,6 +256,55 @@ fn get_egraph() -> &'static Mutex<EGraph> {
             (rule ((IsTrue (Implies A B)) (IsTrue (Not B)))
                   ((IsTrue (Not A))))
 
+            ;; A formula that holds together with its negation
+            (rule ((IsTrue A) (IsTrue (Not A)))
+                  ((Conflict A)))
+
+            ;; Temporal detachment: what held at some interval holds
+            ;; tenselessly (an untensed bridi leaves time unspecified)
+            (rule ((IsTrue (At t A)))
+                  ((IsTrue A)))
+
+            ;; ───────────────────────────────────────────────
+            ;; INTERVAL ORDERING (Allen-style)
+            ;;   before(a, b): a ends before b starts
+            ;;   meets(a, b):  a ends exactly where b starts
+            ;;   during(a, b): a lies within b
+            ;; ───────────────────────────────────────────────
+
+            ;; before ∘ before = before
+            (rule ((IsTrue (Pred "before" (Cons a (Cons b (Nil)))))
+                   (IsTrue (Pred "before" (Cons b (Cons c (Nil))))))
+                  ((IsTrue (Pred "before" (Cons a (Cons c (Nil)))))))
+
+            ;; meets ∘ before = before, before ∘ meets = before
+            (rule ((IsTrue (Pred "meets" (Cons a (Cons b (Nil)))))
+                   (IsTrue (Pred "before" (Cons b (Cons c (Nil))))))
+                  ((IsTrue (Pred "before" (Cons a (Cons c (Nil)))))))
+            (rule ((IsTrue (Pred "before" (Cons a (Cons b (Nil)))))
+                   (IsTrue (Pred "meets" (Cons b (Cons c (Nil))))))
+                  ((IsTrue (Pred "before" (Cons a (Cons c (Nil)))))))
+
+            ;; meets ∘ meets = before
+            (rule ((IsTrue (Pred "meets" (Cons a (Cons b (Nil)))))
+                   (IsTrue (Pred "meets" (Cons b (Cons c (Nil))))))
+                  ((IsTrue (Pred "before" (Cons a (Cons c (Nil)))))))
+
+            ;; during ∘ during = during
+            (rule ((IsTrue (Pred "during" (Cons a (Cons b (Nil)))))
+                   (IsTrue (Pred "during" (Cons b (Cons c (Nil))))))
+                  ((IsTrue (Pred "during" (Cons a (Cons c (Nil)))))))
+
+            ;; during(a, b) ∧ before(b, c) ⊢ before(a, c)
+            (rule ((IsTrue (Pred "during" (Cons a (Cons b (Nil)))))
+                   (IsTrue (Pred "before" (Cons b (Cons c (Nil))))))
+                  ((IsTrue (Pred "before" (Cons a (Cons c (Nil)))))))
+
+            ;; before(a, b) ∧ during(c, b) ⊢ before(a, c)
+            (rule ((IsTrue (Pred "before" (Cons a (Cons b (Nil)))))
+                   (IsTrue (Pred "during" (Cons c (Cons b (Nil))))))
+                  ((IsTrue (Pred "before" (Cons a (Cons c (Nil)))))))
+
             ;; ───────────────────────────────────────────────
             ;; QUANTIFIER RULES (residual)
             ;; ───────────────────────────────────────────────
@@ -163,12 +318,11 @@ fn get_egraph() -> &'static Mutex<EGraph> {
                   ((IsTrue (And (ForAll v A) (ForAll v B)))))
         "#;
 
-        egraph
-            .parse_and_run_program(None, schema_str)
-            .expect("Failed to load FOL schema and rules");
+    egraph
+        .parse_and_run_program(None, schema_str)
+        .expect("Failed to load FOL schema and rules");
 
-        Mutex::new(egraph)
-    })
+    egraph
 }
 
 // ─── WIT Export Implementation ────────────────────────────────
@@ -176,284 +330,1029 @@ fn get_egraph() -> &'static Mutex<EGraph> {
 struct ReasoningComponent;
 
 impl Guest for ReasoningComponent {
-    /// Assert facts with Skolemization (∃) and Herbrand instantiation (∀).
-    fn assert_fact(logic: LogicBuffer) -> Result<(), String> {
-        let egraph_mutex = get_egraph();
-        let mut egraph = egraph_mutex.lock().unwrap();
+    type KnowledgeBase = KnowledgeBaseResource;
+}
 
-        for &root_id in &logic.roots {
-            // Phase 1: Collect existential variables for Skolemization
-            let mut skolem_subs = HashMap::new();
-            collect_exists_for_skolem(&logic, root_id, &mut skolem_subs);
+/// The `knowledge-base` resource. Its methods take `&self`, so the state
+/// sits in a RefCell; dropping the resource drops the state.
+struct KnowledgeBaseResource(RefCell<KnowledgeBase>);
+
+impl GuestKnowledgeBase for KnowledgeBaseResource {
+    fn new() -> Self {
+        Self(RefCell::new(KnowledgeBase::new()))
+    }
+
+    fn assert_fact(
+        &self,
+        logic: LogicBuffer,
+        sources: Vec<String>,
+        policy: ConflictPolicy,
+    ) -> Result<FactHandle, AssertError> {
+        self.0.borrow_mut().assert_fact(logic, sources, policy)
+    }
+
+    fn retract_fact(&self, handle: FactHandle) -> Result<(), String> {
+        self.0.borrow_mut().retract_fact(handle)
+    }
+
+    fn quarantined(&self) -> Vec<QuarantinedFact> {
+        self.0.borrow().quarantine.clone()
+    }
 
-            // Register Skolem constants as entities
-            for sk in skolem_subs.values() {
-                register_entity(sk, &mut egraph);
+    fn query_entailment(
+        &self,
+        logic: LogicBuffer,
+        assumption: WorldAssumption,
+    ) -> Result<TruthValue, String> {
+        self.0.borrow_mut().query_entailment(logic, assumption)
+    }
+
+    fn query_bindings(
+        &self,
+        logic: LogicBuffer,
+        variables: Vec<String>,
+        assumption: WorldAssumption,
+    ) -> Result<Vec<Substitution>, String> {
+        self.0
+            .borrow_mut()
+            .query_bindings(logic, variables, assumption)
+    }
+
+    fn reset(&self) {
+        self.0.borrow_mut().reset();
+    }
+
+    fn fork(&self) -> reasoning::KnowledgeBase {
+        reasoning::KnowledgeBase::new(Self(RefCell::new(self.0.borrow().clone())))
+    }
+}
+
+impl KnowledgeBase {
+    /// Assert facts in negation normal form with Skolemization (∃) and
+    /// Herbrand instantiation (∀), then saturate. If that derives a conflict the knowledge base is
+    /// rebuilt from the facts it had before and the conflicts are reported
+    /// (and, under the quarantine policy, the assertion is kept aside).
+    /// Nothing is copied up front: consistent assertions are the common
+    /// case, and only a rejected one pays for the rollback.
+    fn assert_fact(
+        &mut self,
+        logic: LogicBuffer,
+        sources: Vec<String>,
+        policy: ConflictPolicy,
+    ) -> Result<FactHandle, AssertError> {
+        let logic = negation_normal_form(&logic);
+        let kept = self.facts.len();
+        let handle = self.fresh_handle();
+        let shared = Rc::new(logic.clone());
+
+        let result = logic
+            .roots
+            .iter()
+            .enumerate()
+            .try_for_each(|(i, &root_id)| {
+                let mut skolems = HashMap::new();
+                collect_exists_for_skolem(&logic, root_id, &mut skolems, self);
+                let fact = Fact {
+                    handle,
+                    logic: shared.clone(),
+                    root_id,
+                    skolems,
+                    source: sources.get(i).cloned().unwrap_or_default(),
+                    atoms: Vec::new(),
+                };
+                assert_root(fact, self)
+            })
+            .and_then(|()| saturate(&mut self.egraph))
+            .and_then(|()| find_conflicts(&logic, &sources, self));
+        if let Ok(conflicts) = &result
+            && conflicts.is_empty()
+        {
+            return Ok(handle);
+        }
+
+        // The handle goes unused, so the next assertion takes it
+        self.fact_counter = handle;
+        let facts = self.facts[..kept].to_vec();
+        *self = rebuild(facts, self).map_err(AssertError::Failed)?;
+        let conflicts = result.map_err(AssertError::Failed)?;
+        if policy == ConflictPolicy::Quarantine {
+            self.quarantine.push(QuarantinedFact {
+                sources,
+                conflicts: conflicts.clone(),
+            });
+        }
+        Err(AssertError::Conflict(conflicts))
+    }
+
+    /// Forget everything asserted and quarantined. Handles keep counting,
+    /// so one from before the reset cannot retract a fact asserted after it.
+    fn reset(&mut self) {
+        *self = KnowledgeBase {
+            fact_counter: self.fact_counter,
+            ..KnowledgeBase::new()
+        };
+    }
+
+    /// Withdraw the roots asserted under `handle` and rebuild from the rest.
+    fn retract_fact(&mut self, handle: FactHandle) -> Result<(), String> {
+        let mut remaining = self.facts.clone();
+        let before = remaining.len();
+        remaining.retain(|fact| fact.handle != handle);
+        if remaining.len() == before {
+            return Err(format!("No fact with handle {}", handle));
+        }
+
+        *self = rebuild(remaining, self)?;
+        Ok(())
+    }
+
+    /// Query entailment via recursive Rust-side formula decomposition.
+    ///
+    /// All connective logic (And/Or/Not) and quantifier resolution
+    /// (Exists/ForAll) is handled in Rust. Only atomic predicates
+    /// are delegated to egglog via `(check (IsTrue ...))`, together
+    /// with their negation.
+    fn query_entailment(
+        &mut self,
+        logic: LogicBuffer,
+        assumption: WorldAssumption,
+    ) -> Result<TruthValue, String> {
+        saturate(&mut self.egraph)?;
+
+        let mut value = TruthValue::Entailed;
+        for &root_id in &logic.roots {
+            let root = evaluate_formula(&logic, root_id, &HashMap::new(), assumption, self)?;
+            value = conjoin(value, root);
+            if value == TruthValue::Refuted {
+                break;
             }
+        }
 
-            // Register named constants as entities
-            collect_and_register_constants(&logic, root_id, &mut egraph);
+        Ok(value)
+    }
 
-            // Phase 2: Check for ForAll nodes and handle Herbrand instantiation
-            let forall_entries = collect_forall_nodes(&logic, root_id, &skolem_subs);
+    /// Answer a ma/mo question by enumeration: term variables range over
+    /// the known entities, predicate variables over the known relations
+    /// (with their places padded with zo'e or cut to fit), and every
+    /// assignment under which all roots hold is an answer.
+    fn query_bindings(
+        &mut self,
+        logic: LogicBuffer,
+        variables: Vec<String>,
+        assumption: WorldAssumption,
+    ) -> Result<Vec<Substitution>, String> {
+        saturate(&mut self.egraph)?;
 
-            if !forall_entries.is_empty() {
-                for (var_name, body_sexp) in &forall_entries {
-                    println!(
-                        "[Universal] ∀{} registered for Herbrand instantiation",
-                        var_name
-                    );
+        let mut entities = self.known_entities();
+        entities.sort();
+        let relations = self.known_relations();
+        let candidates: Vec<Vec<AnswerTerm>> = variables
+            .iter()
+            .map(|v| {
+                if is_relation(&logic, v) {
+                    relations
+                        .iter()
+                        .map(|(r, _)| AnswerTerm::Relation(r.clone()))
+                        .collect()
+                } else {
+                    entities.iter().cloned().map(AnswerTerm::Entity).collect()
+                }
+            })
+            .collect();
 
-                    // Store template for future entity registration
-                    let templates = UNIVERSAL_TEMPLATES.get_or_init(|| Mutex::new(Vec::new()));
-                    templates.lock().unwrap().push(UniversalTemplate {
-                        var_name: var_name.clone(),
-                        body_sexp: body_sexp.clone(),
-                    });
-
-                    // Instantiate for all currently known entities
-                    let entities = get_known_entities();
-                    for entity in &entities {
-                        let instantiated = body_sexp.replace(
-                            &format!("(Var \"{}\")", var_name),
-                            &format!("(Const \"{}\")", entity),
-                        );
-                        let command = format!("(IsTrue {})", instantiated);
-                        if let Err(e) = egraph.parse_and_run_program(None, &command) {
-                            return Err(format!(
-                                "Failed to instantiate universal for {}: {}",
-                                entity, e
-                            ));
-                        }
+        let mut answers = Vec::new();
+        for assignment in assignments(&candidates) {
+            let mut subs = HashMap::new();
+            let mut buffer = logic.clone();
+            for (var, value) in variables.iter().zip(&assignment) {
+                match value {
+                    AnswerTerm::Entity(e) => {
+                        subs.insert(var.clone(), e.clone());
                     }
-                    if !entities.is_empty() {
-                        println!(
-                            "[Universal] Instantiated for {} known entities",
-                            entities.len()
-                        );
+                    AnswerTerm::Relation(r) => {
+                        let arity = relations
+                            .iter()
+                            .find(|(known, _)| known == r)
+                            .map_or(0, |(_, arity)| *arity);
+                        buffer = with_relation(buffer, var, r, arity);
                     }
                 }
             }
 
-            // Phase 3: Assert the (possibly Skolemized) formula itself
-            if !skolem_subs.is_empty() {
-                println!(
-                    "[Skolem] {} variable(s) → {}",
-                    skolem_subs.len(),
-                    skolem_subs
+            let mut holds = true;
+            for &root_id in &buffer.roots {
+                let value = evaluate_formula(&buffer, root_id, &subs, assumption, self)?;
+                if value != TruthValue::Entailed {
+                    holds = false;
+                    break;
+                }
+            }
+            if holds {
+                answers.push(
+                    variables
                         .iter()
-                        .map(|(v, sk)| format!("{} ↦ {}", v, sk))
-                        .collect::<Vec<_>>()
-                        .join(", ")
+                        .cloned()
+                        .zip(assignment)
+                        .map(|(variable, value)| Binding { variable, value })
+                        .collect(),
                 );
             }
+        }
+
+        Ok(answers)
+    }
+}
+
+// ─── Assertion ────────────────────────────────────────────────
+
+/// Assert one root: Skolemize its existentials with the constants in
+/// `fact.skolems`, instantiate its universals for every known entity,
+/// store its generics, then assert the formula. The fact is logged in
+/// the knowledge base with the ground atoms it mentions.
+fn assert_root(mut fact: Fact, kb: &mut KnowledgeBase) -> Result<(), String> {
+    let logic = Rc::clone(&fact.logic);
+    let logic = logic.as_ref();
+    let root_id = fact.root_id;
+    let skolem_subs = fact.skolems.clone();
+    let source = fact.source.clone();
+
+    // Phase 1: Register Skolem constants as entities, or as intervals
+    // when they stand for a time variable
+    let times = interval_variables(logic);
+    for (var, sk) in &skolem_subs {
+        if times.contains(var) {
+            kb.intervals.insert(sk.clone());
+        } else {
+            kb.register_entity(sk);
+        }
+    }
+
+    // Register named constants as entities (or intervals)
+    collect_and_register_constants(logic, root_id, kb);
+    register_relations(logic, root_id, kb);
 
-            let sexp = reconstruct_sexp_with_subs(&logic, root_id, &skolem_subs);
-            let command = format!("(IsTrue {})", sexp);
-            if let Err(e) = egraph.parse_and_run_program(None, &command) {
-                return Err(format!("Failed to assert fact: {}", e));
+    // Log the atoms the fact mentions (the ground ones: the rest belong
+    // to its universals)
+    collect_atoms(logic, root_id, &skolem_subs, &mut fact.atoms);
+    fact.atoms.retain(|atom| !atom.contains("(Var "));
+    kb.facts.push(fact);
+
+    // Phase 2: Check for ForAll nodes and handle Herbrand instantiation
+    let forall_entries = collect_forall_nodes(logic, root_id);
+
+    if !forall_entries.is_empty() {
+        for (var_name, body) in &forall_entries {
+            let body_sexp = reconstruct_sexp_with_subs(logic, *body, &skolem_subs);
+            let mut atoms = Vec::new();
+            collect_atoms(logic, *body, &skolem_subs, &mut atoms);
+
+            // Store template for future entity registration
+            kb.templates.push(UniversalTemplate {
+                var_name: var_name.clone(),
+                body_sexp: body_sexp.clone(),
+                atoms,
+                source: source.clone(),
+            });
+
+            // Instantiate for all currently known entities
+            let entities = kb.known_entities();
+            for entity in &entities {
+                let instantiated = body_sexp.replace(
+                    &format!("(Var \"{}\")", var_name),
+                    &format!("(Const \"{}\")", entity),
+                );
+                let command = format!("(IsTrue {})", instantiated);
+                if let Err(e) = kb.egraph.parse_and_run_program(None, &command) {
+                    return Err(format!(
+                        "Failed to instantiate universal for {}: {}",
+                        entity, e
+                    ));
+                }
             }
         }
-        Ok(())
     }
 
-    /// Query entailment via recursive Rust-side formula decomposition.
-    ///
-    /// All connective logic (And/Or/Not) and quantifier resolution
-    /// (Exists/ForAll) is handled in Rust. Only atomic predicates
-    /// are delegated to egglog via `(check (IsTrue ...))`.
-    fn query_entailment(logic: LogicBuffer) -> Result<bool, String> {
-        let egraph_mutex = get_egraph();
-        let mut egraph = egraph_mutex.lock().unwrap();
+    // Phase 2b: Store generics as defaults
+    let mut defaults = Vec::new();
+    collect_generic_nodes(logic, root_id, &skolem_subs, &mut defaults);
+    if !defaults.is_empty() {
+        kb.defaults.extend(defaults);
+    }
+
+    // Phase 3: Assert the (possibly Skolemized) formula itself
+    let sexp = reconstruct_sexp_with_subs(logic, root_id, &skolem_subs);
+    let command = format!("(IsTrue {})", sexp);
+    if let Err(e) = kb.egraph.parse_and_run_program(None, &command) {
+        return Err(format!("Failed to assert fact: {}", e));
+    }
+    Ok(())
+}
+
+// ─── Truth maintenance ────────────────────────────────────────
+
+/// A knowledge base built from `facts` alone, keeping the counters and
+/// quarantine of `base`, which is left as it was. egglog cannot forget a
+/// derivation, so rather than undo one the e-graph is started afresh and
+/// each remaining root is asserted again, in order, with the Skolem
+/// constants it had. Universal instances, constants and conclusions that
+/// only a withdrawn fact supported are then never derived.
+fn rebuild(facts: Vec<Fact>, base: &KnowledgeBase) -> Result<KnowledgeBase, String> {
+    // Counters and quarantine carry over: handles and Skolem constants
+    // stay unique, and a quarantined fact is not supported by anything
+    let mut kb = KnowledgeBase {
+        skolem_counter: base.skolem_counter,
+        fact_counter: base.fact_counter,
+        quarantine: base.quarantine.clone(),
+        ..KnowledgeBase::new()
+    };
+
+    for fact in facts {
+        assert_root(
+            Fact {
+                atoms: Vec::new(),
+                ..fact
+            },
+            &mut kb,
+        )?;
+    }
+    saturate(&mut kb.egraph)?;
+    Ok(kb)
+}
 
-        // Saturate
-        if let Err(e) = egraph.parse_and_run_program(None, "(run-schedule (saturate (run)))") {
-            eprintln!(
-                "[reasoning] saturate failed, falling back to bounded run: {}",
-                e
-            );
-            if let Err(e2) = egraph.parse_and_run_program(None, "(run 100)") {
-                return Err(format!("Saturation error: {}", e2));
+// ─── Consistency ──────────────────────────────────────────────
+
+/// After saturation: the atoms that hold together with their negation,
+/// each with the sentences that mention it, directly or through a
+/// universal. A clash egglog finds only between compound formulas is
+/// blamed on the roots just asserted.
+fn find_conflicts(
+    logic: &LogicBuffer,
+    sources: &[String],
+    kb: &mut KnowledgeBase,
+) -> Result<Vec<Conflict>, String> {
+    if !check_fact("(Conflict f)", &mut kb.egraph)? {
+        return Ok(Vec::new());
+    }
+
+    let mut candidates: Vec<(String, String)> = Vec::new();
+    for fact in &kb.facts {
+        for atom in &fact.atoms {
+            candidates.push((atom.clone(), fact.source.clone()));
+        }
+    }
+    let entities = kb.known_entities();
+    for template in &kb.templates {
+        let placeholder = format!("(Var \"{}\")", template.var_name);
+        for atom in &template.atoms {
+            for entity in &entities {
+                let constant = format!("(Const \"{}\")", entity);
+                candidates.push((
+                    atom.replace(&placeholder, &constant),
+                    template.source.clone(),
+                ));
             }
         }
+    }
 
-        for &root_id in &logic.roots {
-            if !check_formula_holds(&logic, root_id, &HashMap::new(), &mut egraph)? {
-                return Ok(false);
+    let mut consistent = HashSet::new();
+    let mut conflicts: Vec<Conflict> = Vec::new();
+    for (atom, source) in candidates {
+        if consistent.contains(&atom) {
+            continue;
+        }
+        if let Some(conflict) = conflicts.iter_mut().find(|c| c.formula == atom) {
+            if !conflict.sources.contains(&source) {
+                conflict.sources.push(source);
             }
+        } else if check_fact(&format!("(Conflict {})", atom), &mut kb.egraph)? {
+            conflicts.push(Conflict {
+                formula: atom,
+                sources: vec![source],
+            });
+        } else {
+            consistent.insert(atom);
+        }
+    }
+
+    if conflicts.is_empty() {
+        conflicts = logic
+            .roots
+            .iter()
+            .enumerate()
+            .map(|(i, &root_id)| Conflict {
+                formula: reconstruct_sexp(logic, root_id),
+                sources: sources.get(i).into_iter().cloned().collect(),
+            })
+            .collect();
+    }
+    Ok(conflicts)
+}
+
+/// The predicate s-expressions in a formula, wherever they occur.
+fn collect_atoms(
+    buffer: &LogicBuffer,
+    node_id: u32,
+    subs: &HashMap<String, String>,
+    atoms: &mut Vec<String>,
+) {
+    match &buffer.nodes[node_id as usize] {
+        LogicNode::Predicate(_) => atoms.push(reconstruct_sexp_with_subs(buffer, node_id, subs)),
+        LogicNode::AndNode((l, r)) | LogicNode::OrNode((l, r)) | LogicNode::ImpliesNode((l, r)) => {
+            collect_atoms(buffer, *l, subs, atoms);
+            collect_atoms(buffer, *r, subs, atoms);
+        }
+        LogicNode::NotNode(inner)
+        | LogicNode::ExistsNode((_, inner))
+        | LogicNode::ForAllNode((_, inner))
+        | LogicNode::AtNode((_, inner)) => collect_atoms(buffer, *inner, subs, atoms),
+        // Defaults are never asserted, so never in conflict
+        LogicNode::GenericNode(_) => {}
+    }
+}
+
+fn saturate(egraph: &mut EGraph) -> Result<(), String> {
+    if let Err(e) = egraph.parse_and_run_program(None, "(run-schedule (saturate (run)))") {
+        eprintln!(
+            "[reasoning] saturate failed, falling back to bounded run: {}",
+            e
+        );
+        if let Err(e2) = egraph.parse_and_run_program(None, "(run 100)") {
+            return Err(format!("Saturation error: {}", e2));
         }
+    }
+    Ok(())
+}
+
+// ─── Question Helpers ─────────────────────────────────────────
+
+/// Every way of picking one candidate per variable.
+fn assignments(candidates: &[Vec<AnswerTerm>]) -> Vec<Vec<AnswerTerm>> {
+    candidates
+        .iter()
+        .fold(vec![Vec::new()], |partial, options| {
+            partial
+                .iter()
+                .flat_map(|prefix| {
+                    options.iter().map(move |option| {
+                        let mut next = prefix.clone();
+                        next.push(option.clone());
+                        next
+                    })
+                })
+                .collect()
+        })
+}
 
-        Ok(true)
+/// Whether `var` is used as a relation name, i.e. is a predicate variable.
+fn is_relation(buffer: &LogicBuffer, var: &str) -> bool {
+    buffer
+        .nodes
+        .iter()
+        .any(|node| matches!(node, LogicNode::Predicate((rel, _)) if rel == var))
+}
+
+/// The buffer with predicate variable `var` replaced by `relation`, its
+/// places fitted to `arity`.
+fn with_relation(mut buffer: LogicBuffer, var: &str, relation: &str, arity: usize) -> LogicBuffer {
+    let predicates = buffer.nodes.iter_mut().filter_map(|node| match node {
+        LogicNode::Predicate((rel, args)) if rel == var => Some((rel, args)),
+        _ => None,
+    });
+    for (rel, args) in predicates {
+        *rel = relation.to_string();
+        args.resize(arity, LogicalTerm::Unspecified);
     }
+    buffer
 }
 
-// ─── Recursive Formula Checking (Rust-side decomposition) ─────
+// ─── Recursive Formula Evaluation (Rust-side decomposition) ───
 
-/// Recursively check whether a formula holds, decomposing connectives
-/// in Rust rather than relying on egglog conjunction introduction.
+/// Recursively evaluate a formula to entailed, refuted or unknown,
+/// decomposing connectives in Rust (Kleene's strong three-valued logic)
+/// rather than relying on egglog conjunction introduction.
+///
+/// - Pred/At: entailed if `(IsTrue φ)`, refuted if `(IsTrue (Not φ))`;
+///   otherwise a default may entail a predicate
+/// - And/Or/Implies/Not: Kleene connectives
+/// - Exists: a known entity is a witness; refuted if every known entity
+///   refutes the body (closed) or the negation was asserted (open)
+/// - ForAll: a known entity is a counterexample; entailed if every known
+///   entity satisfies the body (closed) or the universal was asserted (open)
+/// - Generic: a default with the same restrictor and body was asserted
 ///
-/// - Pred: check directly via egglog `(check (IsTrue ...))`
-/// - And(A,B): both must hold
-/// - Or(A,B): at least one must hold
-/// - Not(A): A must NOT hold
-/// - Exists: enumerate entities (delegated to caller)
-/// - ForAll: strip wrapper (variable already substituted by caller)
-fn check_formula_holds(
+/// Under the closed-world assumption nothing is unknown: whatever is not
+/// entailed is refuted.
+fn evaluate_formula(
     buffer: &LogicBuffer,
     node_id: u32,
     subs: &HashMap<String, String>,
-    egraph: &mut EGraph,
-) -> Result<bool, String> {
-    match &buffer.nodes[node_id as usize] {
+    world: WorldAssumption,
+    kb: &mut KnowledgeBase,
+) -> Result<TruthValue, String> {
+    let value = match &buffer.nodes[node_id as usize] {
         LogicNode::AndNode((l, r)) => {
-            // Both conjuncts must hold
-            Ok(check_formula_holds(buffer, *l, subs, egraph)?
-                && check_formula_holds(buffer, *r, subs, egraph)?)
+            let left = evaluate_formula(buffer, *l, subs, world, kb)?;
+            if left == TruthValue::Refuted {
+                return Ok(left);
+            }
+            conjoin(left, evaluate_formula(buffer, *r, subs, world, kb)?)
         }
         LogicNode::OrNode((l, r)) => {
-            // At least one disjunct must hold
-            Ok(check_formula_holds(buffer, *l, subs, egraph)?
-                || check_formula_holds(buffer, *r, subs, egraph)?)
+            let left = evaluate_formula(buffer, *l, subs, world, kb)?;
+            if left == TruthValue::Entailed {
+                return Ok(left);
+            }
+            disjoin(left, evaluate_formula(buffer, *r, subs, world, kb)?)
         }
-        LogicNode::NotNode(inner) => {
-            // Inner must NOT hold
-            Ok(!check_formula_holds(buffer, *inner, subs, egraph)?)
+        LogicNode::ImpliesNode((l, r)) => {
+            let antecedent = negate(evaluate_formula(buffer, *l, subs, world, kb)?);
+            if antecedent == TruthValue::Entailed {
+                return Ok(antecedent);
+            }
+            disjoin(antecedent, evaluate_formula(buffer, *r, subs, world, kb)?)
         }
+        LogicNode::NotNode(inner) => negate(evaluate_formula(buffer, *inner, subs, world, kb)?),
         LogicNode::ExistsNode((v, body)) => {
             // If variable is already substituted, just check body
             if subs.contains_key(v.as_str()) {
-                return check_formula_holds(buffer, *body, subs, egraph);
+                return evaluate_formula(buffer, *body, subs, world, kb);
             }
-            // Otherwise enumerate entities
-            let entities = get_known_entities();
-            for entity in &entities {
+            // Otherwise enumerate entities (or intervals) for a witness
+            let mut value = TruthValue::Refuted;
+            for entity in &kb.domain(buffer, v) {
                 let mut new_subs = subs.clone();
                 new_subs.insert(v.clone(), entity.clone());
-                if check_formula_holds(buffer, *body, &new_subs, egraph)? {
-                    return Ok(true);
+                value = disjoin(
+                    value,
+                    evaluate_formula(buffer, *body, &new_subs, world, kb)?,
+                );
+                if value == TruthValue::Entailed {
+                    return Ok(value);
+                }
+            }
+            // An entity nobody has mentioned yet might be the witness
+            match world {
+                WorldAssumption::Closed => value,
+                WorldAssumption::Open => {
+                    let sexp = reconstruct_sexp_with_subs(buffer, node_id, subs);
+                    if check_atom(&format!("(Not {})", sexp), &mut kb.egraph)?
+                        || universal_denies(buffer, v, *body, subs, kb)
+                    {
+                        TruthValue::Refuted
+                    } else {
+                        TruthValue::Unknown
+                    }
                 }
             }
-            Ok(false)
         }
         LogicNode::ForAllNode((v, body)) => {
             // If variable is already substituted, just check body
             if subs.contains_key(v.as_str()) {
-                return check_formula_holds(buffer, *body, subs, egraph);
-            }
-            // Otherwise all entities must satisfy
-            let entities = get_known_entities();
-            if entities.is_empty() {
-                return Ok(true); // Vacuously true
+                return evaluate_formula(buffer, *body, subs, world, kb);
             }
-            for entity in &entities {
+            // Otherwise look for a counterexample among the entities
+            // (vacuously entailed when there are none)
+            let mut value = TruthValue::Entailed;
+            for entity in &kb.domain(buffer, v) {
                 let mut new_subs = subs.clone();
                 new_subs.insert(v.clone(), entity.clone());
-                if !check_formula_holds(buffer, *body, &new_subs, egraph)? {
-                    return Ok(false);
+                value = conjoin(
+                    value,
+                    evaluate_formula(buffer, *body, &new_subs, world, kb)?,
+                );
+                if value == TruthValue::Refuted {
+                    return Ok(value);
+                }
+            }
+            // Only an asserted universal covers the entities not yet seen
+            match world {
+                WorldAssumption::Closed => value,
+                WorldAssumption::Open if universal_asserted(buffer, v, *body, subs, kb) => {
+                    TruthValue::Entailed
                 }
+                WorldAssumption::Open => TruthValue::Unknown,
+            }
+        }
+        LogicNode::GenericNode((v, restrictor, body)) => {
+            let canonical = canonical_generic(buffer, v, *restrictor, *body, subs);
+            if kb.defaults.iter().any(|r| r.canonical == canonical) {
+                TruthValue::Entailed
+            } else {
+                TruthValue::Unknown
             }
-            Ok(true)
         }
         LogicNode::Predicate(_) => {
+            let sexp = reconstruct_sexp_with_subs(buffer, node_id, subs);
+            match check_literal(&sexp, &mut kb.egraph)? {
+                TruthValue::Unknown if check_defaults(&sexp, kb)? => TruthValue::Entailed,
+                value => value,
+            }
+        }
+        LogicNode::AtNode(_) => {
             // Atomic: delegate to egglog
             let sexp = reconstruct_sexp_with_subs(buffer, node_id, subs);
-            let command = format!("(check (IsTrue {}))", sexp);
-            match egraph.parse_and_run_program(None, &command) {
-                Ok(_) => Ok(true),
-                Err(e) => {
-                    let msg = e.to_string();
-                    if msg.contains("Check failed") {
-                        Ok(false)
-                    } else {
-                        Err(format!("Reasoning error: {}", msg))
-                    }
+            check_literal(&sexp, &mut kb.egraph)?
+        }
+    };
+
+    Ok(match (world, value) {
+        (WorldAssumption::Closed, TruthValue::Unknown) => TruthValue::Refuted,
+        (_, value) => value,
+    })
+}
+
+fn negate(value: TruthValue) -> TruthValue {
+    match value {
+        TruthValue::Entailed => TruthValue::Refuted,
+        TruthValue::Refuted => TruthValue::Entailed,
+        TruthValue::Unknown => TruthValue::Unknown,
+    }
+}
+
+fn conjoin(a: TruthValue, b: TruthValue) -> TruthValue {
+    match (a, b) {
+        (TruthValue::Refuted, _) | (_, TruthValue::Refuted) => TruthValue::Refuted,
+        (TruthValue::Entailed, TruthValue::Entailed) => TruthValue::Entailed,
+        _ => TruthValue::Unknown,
+    }
+}
+
+fn disjoin(a: TruthValue, b: TruthValue) -> TruthValue {
+    negate(conjoin(negate(a), negate(b)))
+}
+
+/// Whether a ground formula or its negation is in the e-graph.
+fn check_literal(sexp: &str, egraph: &mut EGraph) -> Result<TruthValue, String> {
+    if check_atom(sexp, egraph)? {
+        Ok(TruthValue::Entailed)
+    } else if check_atom(&format!("(Not {})", sexp), egraph)? {
+        Ok(TruthValue::Refuted)
+    } else {
+        Ok(TruthValue::Unknown)
+    }
+}
+
+/// Whether ∀var.body was asserted, up to the name of its variable.
+fn universal_asserted(
+    buffer: &LogicBuffer,
+    var: &str,
+    body: u32,
+    subs: &HashMap<String, String>,
+    kb: &KnowledgeBase,
+) -> bool {
+    let canonical =
+        |var: &str, body: &str| body.replace(&format!("(Var \"{}\")", var), "(Var \"_\")");
+    let query = canonical(var, &reconstruct_sexp_with_subs(buffer, body, subs));
+    kb.templates
+        .iter()
+        .any(|t| canonical(&t.var_name, &t.body_sexp) == query)
+}
+
+/// Whether ∀var.¬body was asserted: the universal a negated existential
+/// ("no lo gerku cu sutra") is stored as.
+fn universal_denies(
+    buffer: &LogicBuffer,
+    var: &str,
+    body: u32,
+    subs: &HashMap<String, String>,
+    kb: &KnowledgeBase,
+) -> bool {
+    let mut negated = buffer.clone();
+    let body = push_nnf(buffer, body, true, &mut negated.nodes);
+    universal_asserted(&negated, var, body, subs, kb)
+}
+
+/// Check a ground formula directly via egglog `(check (IsTrue ...))`.
+fn check_atom(sexp: &str, egraph: &mut EGraph) -> Result<bool, String> {
+    check_fact(&format!("(IsTrue {})", sexp), egraph)
+}
+
+/// Run an egglog `(check ...)` on a fact, which may contain variables.
+fn check_fact(fact: &str, egraph: &mut EGraph) -> Result<bool, String> {
+    let command = format!("(check {})", fact);
+    match egraph.parse_and_run_program(None, &command) {
+        Ok(_) => Ok(true),
+        Err(e) => {
+            let msg = e.to_string();
+            if msg.contains("Check failed") {
+                Ok(false)
+            } else {
+                Err(format!("Reasoning error: {}", msg))
+            }
+        }
+    }
+}
+
+/// Default reasoning over the stored generics: an unprovable atom holds
+/// when some entity instantiates a default's conclusion to it, satisfies
+/// the default's restrictor, and the atom's negation is not known.
+fn check_defaults(sexp: &str, kb: &mut KnowledgeBase) -> Result<bool, String> {
+    if kb.defaults.is_empty() {
+        return Ok(false);
+    }
+    let entities = kb.known_entities();
+    for rule in &kb.defaults {
+        let placeholder = format!("(Var \"{}\")", rule.var_name);
+        for entity in &entities {
+            let constant = format!("(Const \"{}\")", entity);
+            if !rule
+                .conclusion
+                .iter()
+                .any(|c| c.replace(&placeholder, &constant) == sexp)
+            {
+                continue;
+            }
+            let mut applies = true;
+            for r in &rule.restrictor {
+                if !check_atom(&r.replace(&placeholder, &constant), &mut kb.egraph)? {
+                    applies = false;
+                    break;
                 }
             }
+            if applies && !check_atom(&format!("(Not {})", sexp), &mut kb.egraph)? {
+                return Ok(true);
+            }
         }
     }
+    Ok(false)
+}
+
+// ─── Time Helpers ─────────────────────────────────────────────
+
+/// The interval relations tense lowers to; their places hold intervals.
+const TEMPORAL_RELATIONS: &[&str] = &["before", "meets", "during", "zi", "za", "zu"];
+
+/// The variables of a buffer that stand for intervals: those indexing
+/// an At node or filling a place of an interval relation.
+fn interval_variables(buffer: &LogicBuffer) -> HashSet<String> {
+    let mut times = HashSet::new();
+    for node in &buffer.nodes {
+        let terms: &[LogicalTerm] = match node {
+            LogicNode::AtNode((time, _)) => std::slice::from_ref(time),
+            LogicNode::Predicate((rel, args)) if TEMPORAL_RELATIONS.contains(&rel.as_str()) => args,
+            _ => continue,
+        };
+        for term in terms {
+            if let LogicalTerm::Variable(v) = term {
+                times.insert(v.clone());
+            }
+        }
+    }
+    times
 }
 
 // ─── Skolemization Helpers ────────────────────────────────────
 
+/// `buffer` with each root in negation normal form: negations pushed
+/// down to the atoms, implications unfolded, and a negated quantifier
+/// turned into its dual, so "no lo gerku cu sutra", ¬∃x. (gerku(x) ∧
+/// sutra(x)), is the universal ∀x. (¬gerku(x) ∨ ¬sutra(x)). New nodes
+/// are appended, so the ids of subformulas left as they were (inside
+/// propositions, generics and tensed formulas) stay valid.
+fn negation_normal_form(buffer: &LogicBuffer) -> LogicBuffer {
+    let mut nnf = buffer.clone();
+    nnf.roots = buffer
+        .roots
+        .iter()
+        .map(|&root_id| push_nnf(buffer, root_id, false, &mut nnf.nodes))
+        .collect();
+    nnf
+}
+
+/// Append the negation normal form of `node_id`, negated if `negated`,
+/// and return its id.
+fn push_nnf(buffer: &LogicBuffer, node_id: u32, negated: bool, nodes: &mut Vec<LogicNode>) -> u32 {
+    let mut push = |id: u32, negated: bool| push_nnf(buffer, id, negated, nodes);
+    let node = match (&buffer.nodes[node_id as usize], negated) {
+        (LogicNode::NotNode(inner), _) => return push(*inner, !negated),
+        (LogicNode::AndNode((l, r)), false) => {
+            LogicNode::AndNode((push(*l, false), push(*r, false)))
+        }
+        (LogicNode::AndNode((l, r)), true) => LogicNode::OrNode((push(*l, true), push(*r, true))),
+        (LogicNode::OrNode((l, r)), false) => LogicNode::OrNode((push(*l, false), push(*r, false))),
+        (LogicNode::OrNode((l, r)), true) => LogicNode::AndNode((push(*l, true), push(*r, true))),
+        (LogicNode::ImpliesNode((l, r)), false) => {
+            LogicNode::OrNode((push(*l, true), push(*r, false)))
+        }
+        (LogicNode::ImpliesNode((l, r)), true) => {
+            LogicNode::AndNode((push(*l, false), push(*r, true)))
+        }
+        (LogicNode::ExistsNode((v, body)), false) => {
+            LogicNode::ExistsNode((v.clone(), push(*body, false)))
+        }
+        (LogicNode::ExistsNode((v, body)), true) => {
+            LogicNode::ForAllNode((v.clone(), push(*body, true)))
+        }
+        (LogicNode::ForAllNode((v, body)), false) => {
+            LogicNode::ForAllNode((v.clone(), push(*body, false)))
+        }
+        (LogicNode::ForAllNode((v, body)), true) => {
+            LogicNode::ExistsNode((v.clone(), push(*body, true)))
+        }
+        (LogicNode::AtNode((time, body)), false) => {
+            LogicNode::AtNode((time.clone(), push(*body, false)))
+        }
+        // Not holding at a time is not holding something else then
+        (LogicNode::AtNode(_), true) => LogicNode::NotNode(push(node_id, false)),
+        // Defaults are negated whole, like atoms
+        (LogicNode::Predicate(_) | LogicNode::GenericNode(_), false) => return node_id,
+        (LogicNode::Predicate(_) | LogicNode::GenericNode(_), true) => LogicNode::NotNode(node_id),
+    };
+    nodes.push(node);
+    nodes.len() as u32 - 1
+}
+
+/// Skolemize the existentials of a root in negation normal form that are
+/// outside every universal. One under a universal depends on its
+/// variable, so a single constant would be the wrong witness: it is left
+/// for the universal's instances.
 fn collect_exists_for_skolem(
     buffer: &LogicBuffer,
     node_id: u32,
     subs: &mut HashMap<String, String>,
+    kb: &mut KnowledgeBase,
 ) {
     match &buffer.nodes[node_id as usize] {
         LogicNode::ExistsNode((v, body)) => {
             if !subs.contains_key(v.as_str()) {
-                subs.insert(v.clone(), fresh_skolem());
+                subs.insert(v.clone(), kb.fresh_skolem());
             }
-            collect_exists_for_skolem(buffer, *body, subs);
-        }
-        LogicNode::ForAllNode((_, body)) => {
-            collect_exists_for_skolem(buffer, *body, subs);
+            collect_exists_for_skolem(buffer, *body, subs, kb);
         }
         LogicNode::AndNode((l, r)) | LogicNode::OrNode((l, r)) => {
-            collect_exists_for_skolem(buffer, *l, subs);
-            collect_exists_for_skolem(buffer, *r, subs);
-        }
-        LogicNode::NotNode(inner) => {
-            collect_exists_for_skolem(buffer, *inner, subs);
+            collect_exists_for_skolem(buffer, *l, subs, kb);
+            collect_exists_for_skolem(buffer, *r, subs, kb);
         }
-        LogicNode::Predicate(_) => {}
+        LogicNode::AtNode((_, inner)) => collect_exists_for_skolem(buffer, *inner, subs, kb),
+        // Generics stay unskolemized: they are defaults, not facts
+        LogicNode::ForAllNode(_)
+        | LogicNode::ImpliesNode(_)
+        | LogicNode::NotNode(_)
+        | LogicNode::Predicate(_)
+        | LogicNode::GenericNode(_) => {}
     }
 }
 
-/// Extract ForAll nodes: returns (var_name, body_sexp) pairs.
-/// The body_sexp has the ForAll wrapper stripped and existentials Skolemized.
-fn collect_forall_nodes(
-    buffer: &LogicBuffer,
-    node_id: u32,
-    skolem_subs: &HashMap<String, String>,
-) -> Vec<(String, String)> {
+/// Extract the universals a root in negation normal form asserts
+/// outright, through conjunctions and Skolemized existentials: returns
+/// (var_name, body) pairs, the body being the node under the ForAll
+/// wrapper.
+fn collect_forall_nodes(buffer: &LogicBuffer, node_id: u32) -> Vec<(String, u32)> {
     let mut entries = Vec::new();
-    collect_forall_nodes_rec(buffer, node_id, skolem_subs, &mut entries);
+    collect_forall_nodes_rec(buffer, node_id, &mut entries);
     entries
 }
 
-fn collect_forall_nodes_rec(
+fn collect_forall_nodes_rec(buffer: &LogicBuffer, node_id: u32, entries: &mut Vec<(String, u32)>) {
+    match &buffer.nodes[node_id as usize] {
+        LogicNode::ForAllNode((v, body)) => {
+            entries.push((v.clone(), *body));
+            // Don't recurse into body — the template is the complete body
+        }
+        LogicNode::AndNode((l, r)) => {
+            collect_forall_nodes_rec(buffer, *l, entries);
+            collect_forall_nodes_rec(buffer, *r, entries);
+        }
+        LogicNode::ExistsNode((_, inner)) | LogicNode::AtNode((_, inner)) => {
+            collect_forall_nodes_rec(buffer, *inner, entries);
+        }
+        // A universal in a disjunction holds only if the other side does
+        // not: it stays in the asserted formula, not a template
+        _ => {}
+    }
+}
+
+/// Extract Generic nodes as default rules, with their restrictor and body
+/// split into conjuncts.
+fn collect_generic_nodes(
     buffer: &LogicBuffer,
     node_id: u32,
     skolem_subs: &HashMap<String, String>,
-    entries: &mut Vec<(String, String)>,
+    defaults: &mut Vec<DefaultRule>,
 ) {
     match &buffer.nodes[node_id as usize] {
-        LogicNode::ForAllNode((v, body)) => {
-            let body_sexp = reconstruct_sexp_with_subs(buffer, *body, skolem_subs);
-            entries.push((v.clone(), body_sexp));
-            // Don't recurse into body — the template is the complete body
+        LogicNode::GenericNode((v, restrictor, body)) => {
+            let mut restrictors = Vec::new();
+            collect_conjuncts(buffer, *restrictor, skolem_subs, &mut restrictors);
+            let mut conclusion = Vec::new();
+            collect_conjuncts(buffer, *body, skolem_subs, &mut conclusion);
+            defaults.push(DefaultRule {
+                var_name: v.clone(),
+                restrictor: restrictors,
+                conclusion,
+                canonical: canonical_generic(buffer, v, *restrictor, *body, skolem_subs),
+            });
         }
-        LogicNode::AndNode((l, r)) | LogicNode::OrNode((l, r)) => {
-            collect_forall_nodes_rec(buffer, *l, skolem_subs, entries);
-            collect_forall_nodes_rec(buffer, *r, skolem_subs, entries);
+        LogicNode::AndNode((l, r)) => {
+            collect_generic_nodes(buffer, *l, skolem_subs, defaults);
+            collect_generic_nodes(buffer, *r, skolem_subs, defaults);
         }
-        LogicNode::NotNode(inner) | LogicNode::ExistsNode((_, inner)) => {
-            collect_forall_nodes_rec(buffer, *inner, skolem_subs, entries);
+        LogicNode::ExistsNode((_, inner)) | LogicNode::AtNode((_, inner)) => {
+            collect_generic_nodes(buffer, *inner, skolem_subs, defaults);
         }
         _ => {}
     }
 }
 
-/// Register all Const terms found in the formula as known entities.
-fn collect_and_register_constants(buffer: &LogicBuffer, node_id: u32, egraph: &mut EGraph) {
+fn collect_conjuncts(
+    buffer: &LogicBuffer,
+    node_id: u32,
+    subs: &HashMap<String, String>,
+    conjuncts: &mut Vec<String>,
+) {
+    match &buffer.nodes[node_id as usize] {
+        LogicNode::AndNode((l, r)) => {
+            collect_conjuncts(buffer, *l, subs, conjuncts);
+            collect_conjuncts(buffer, *r, subs, conjuncts);
+        }
+        _ => conjuncts.push(reconstruct_sexp_with_subs(buffer, node_id, subs)),
+    }
+}
+
+/// A generic's restrictor and body with its variable given a fixed name,
+/// so that "lo'e gerku cu citka" compiles to the same key every time.
+fn canonical_generic(
+    buffer: &LogicBuffer,
+    var: &str,
+    restrictor: u32,
+    body: u32,
+    subs: &HashMap<String, String>,
+) -> String {
+    let mut subs = subs.clone();
+    subs.insert(var.to_string(), "_generic".to_string());
+    format!(
+        "{} {}",
+        reconstruct_sexp_with_subs(buffer, restrictor, &subs),
+        reconstruct_sexp_with_subs(buffer, body, &subs)
+    )
+}
+
+/// Record the relations a formula uses, with their arity.
+fn register_relations(buffer: &LogicBuffer, node_id: u32, kb: &mut KnowledgeBase) {
+    let mut pending = vec![node_id];
+    while let Some(id) = pending.pop() {
+        match &buffer.nodes[id as usize] {
+            LogicNode::Predicate((rel, args)) => {
+                if !TEMPORAL_RELATIONS.contains(&rel.as_str()) {
+                    kb.relations.insert(rel.clone(), args.len());
+                }
+                for arg in args {
+                    if let LogicalTerm::Proposition(inner) = arg {
+                        pending.push(*inner);
+                    }
+                }
+            }
+            LogicNode::AndNode((l, r))
+            | LogicNode::OrNode((l, r))
+            | LogicNode::ImpliesNode((l, r))
+            | LogicNode::GenericNode((_, l, r)) => pending.extend([*l, *r]),
+            LogicNode::NotNode(inner)
+            | LogicNode::ExistsNode((_, inner))
+            | LogicNode::ForAllNode((_, inner))
+            | LogicNode::AtNode((_, inner)) => pending.push(*inner),
+        }
+    }
+}
+
+/// Register all Const terms found in the formula as known entities, or
+/// as intervals when they stand in a time position.
+fn collect_and_register_constants(buffer: &LogicBuffer, node_id: u32, kb: &mut KnowledgeBase) {
     match &buffer.nodes[node_id as usize] {
-        LogicNode::Predicate((_, args)) => {
+        LogicNode::Predicate((rel, args)) => {
+            let temporal = TEMPORAL_RELATIONS.contains(&rel.as_str());
             for arg in args {
-                if let LogicalTerm::Constant(c) = arg {
-                    register_entity(c, egraph);
+                match arg {
+                    LogicalTerm::Constant(c) if temporal => {
+                        kb.intervals.insert(c.clone());
+                    }
+                    LogicalTerm::Constant(c) => {
+                        kb.register_entity(c);
+                    }
+                    LogicalTerm::Proposition(inner) => {
+                        collect_and_register_constants(buffer, *inner, kb);
+                    }
+                    _ => {}
                 }
             }
         }
-        LogicNode::AndNode((l, r)) | LogicNode::OrNode((l, r)) => {
-            collect_and_register_constants(buffer, *l, egraph);
-            collect_and_register_constants(buffer, *r, egraph);
+        LogicNode::AndNode((l, r)) | LogicNode::OrNode((l, r)) | LogicNode::ImpliesNode((l, r)) => {
+            collect_and_register_constants(buffer, *l, kb);
+            collect_and_register_constants(buffer, *r, kb);
         }
         LogicNode::NotNode(inner)
         | LogicNode::ExistsNode((_, inner))
         | LogicNode::ForAllNode((_, inner)) => {
-            collect_and_register_constants(buffer, *inner, egraph);
+            collect_and_register_constants(buffer, *inner, kb);
+        }
+        LogicNode::AtNode((time, inner)) => {
+            if let LogicalTerm::Constant(c) = time {
+                kb.intervals.insert(c.clone());
+            }
+            collect_and_register_constants(buffer, *inner, kb);
+        }
+        LogicNode::GenericNode((_, restrictor, body)) => {
+            collect_and_register_constants(buffer, *restrictor, kb);
+            collect_and_register_constants(buffer, *body, kb);
         }
     }
 }
@@ -469,22 +1368,18 @@ fn reconstruct_sexp_with_subs(
         LogicNode::Predicate((rel, args)) => {
             let mut args_str = String::from("(Nil)");
             for arg in args.iter().rev() {
-                let term_str = match arg {
-                    LogicalTerm::Variable(v) => {
-                        if let Some(replacement) = subs.get(v.as_str()) {
-                            format!("(Const \"{}\")", replacement)
-                        } else {
-                            format!("(Var \"{}\")", v)
-                        }
-                    }
-                    LogicalTerm::Constant(c) => format!("(Const \"{}\")", c),
-                    LogicalTerm::Description(d) => format!("(Desc \"{}\")", d),
-                    LogicalTerm::Unspecified => "(Zoe)".to_string(),
-                };
+                let term_str = reconstruct_term_with_subs(buffer, arg, subs);
                 args_str = format!("(Cons {} {})", term_str, args_str);
             }
             format!("(Pred \"{}\" {})", rel, args_str)
         }
+        LogicNode::AtNode((time, body)) => {
+            format!(
+                "(At {} {})",
+                reconstruct_term_with_subs(buffer, time, subs),
+                reconstruct_sexp_with_subs(buffer, *body, subs)
+            )
+        }
         LogicNode::ExistsNode((v, body)) => {
             if subs.contains_key(v.as_str()) {
                 reconstruct_sexp_with_subs(buffer, *body, subs)
@@ -522,9 +1417,49 @@ fn reconstruct_sexp_with_subs(
                 reconstruct_sexp_with_subs(buffer, *r, subs)
             )
         }
+        LogicNode::ImpliesNode((l, r)) => {
+            format!(
+                "(Implies {} {})",
+                reconstruct_sexp_with_subs(buffer, *l, subs),
+                reconstruct_sexp_with_subs(buffer, *r, subs)
+            )
+        }
         LogicNode::NotNode(inner) => {
             format!("(Not {})", reconstruct_sexp_with_subs(buffer, *inner, subs))
         }
+        LogicNode::GenericNode((v, restrictor, body)) => {
+            format!(
+                "(Generic \"{}\" {} {})",
+                v,
+                reconstruct_sexp_with_subs(buffer, *restrictor, subs),
+                reconstruct_sexp_with_subs(buffer, *body, subs)
+            )
+        }
+    }
+}
+
+fn reconstruct_term_with_subs(
+    buffer: &LogicBuffer,
+    term: &LogicalTerm,
+    subs: &HashMap<String, String>,
+) -> String {
+    match term {
+        LogicalTerm::Variable(v) => {
+            if let Some(replacement) = subs.get(v.as_str()) {
+                format!("(Const \"{}\")", replacement)
+            } else {
+                format!("(Var \"{}\")", v)
+            }
+        }
+        LogicalTerm::Constant(c) => format!("(Const \"{}\")", c),
+        LogicalTerm::Description(d) => format!("(Desc \"{}\")", d),
+        LogicalTerm::Unspecified => "(Zoe)".to_string(),
+        LogicalTerm::Proposition(inner) => {
+            format!(
+                "(Prop {})",
+                reconstruct_sexp_with_subs(buffer, *inner, subs)
+            )
+        }
     }
 }
 
@@ -532,4 +1467,496 @@ fn reconstruct_sexp(buffer: &LogicBuffer, node_id: u32) -> String {
     reconstruct_sexp_with_subs(buffer, node_id, &HashMap::new())
 }
 
+#[cfg(target_arch = "wasm32")]
 bindings::export!(ReasoningComponent with_types_in bindings);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ─── Buffer construction ──────────────────────────────────
+
+    fn c(name: &str) -> LogicalTerm {
+        LogicalTerm::Constant(name.to_string())
+    }
+
+    fn v(name: &str) -> LogicalTerm {
+        LogicalTerm::Variable(name.to_string())
+    }
+
+    /// Builds a logic buffer bottom-up, the way semantics flattens one.
+    #[derive(Default)]
+    struct Logic {
+        nodes: Vec<LogicNode>,
+    }
+
+    impl Logic {
+        fn push(&mut self, node: LogicNode) -> u32 {
+            self.nodes.push(node);
+            self.nodes.len() as u32 - 1
+        }
+
+        fn pred(&mut self, relation: &str, args: Vec<LogicalTerm>) -> u32 {
+            self.push(LogicNode::Predicate((relation.to_string(), args)))
+        }
+
+        fn and(&mut self, l: u32, r: u32) -> u32 {
+            self.push(LogicNode::AndNode((l, r)))
+        }
+
+        fn or(&mut self, l: u32, r: u32) -> u32 {
+            self.push(LogicNode::OrNode((l, r)))
+        }
+
+        fn not(&mut self, inner: u32) -> u32 {
+            self.push(LogicNode::NotNode(inner))
+        }
+
+        fn exists(&mut self, var: &str, body: u32) -> u32 {
+            self.push(LogicNode::ExistsNode((var.to_string(), body)))
+        }
+
+        fn for_all(&mut self, var: &str, body: u32) -> u32 {
+            self.push(LogicNode::ForAllNode((var.to_string(), body)))
+        }
+
+        fn at(&mut self, time: LogicalTerm, body: u32) -> u32 {
+            self.push(LogicNode::AtNode((time, body)))
+        }
+
+        fn finish(self, roots: Vec<u32>) -> LogicBuffer {
+            LogicBuffer {
+                nodes: self.nodes,
+                roots,
+            }
+        }
+    }
+
+    /// A single predication as a one-root buffer.
+    fn atom(relation: &str, args: Vec<LogicalTerm>) -> LogicBuffer {
+        let mut logic = Logic::default();
+        let root = logic.pred(relation, args);
+        logic.finish(vec![root])
+    }
+
+    /// ¬relation(args)
+    fn negated(relation: &str, args: Vec<LogicalTerm>) -> LogicBuffer {
+        let mut logic = Logic::default();
+        let inner = logic.pred(relation, args);
+        let root = logic.not(inner);
+        logic.finish(vec![root])
+    }
+
+    /// ∀x. (¬restrictor(x) ∨ body(x))
+    fn every(restrictor: &str, body: &str) -> LogicBuffer {
+        let mut logic = Logic::default();
+        let r = logic.pred(restrictor, vec![v("x")]);
+        let not_r = logic.not(r);
+        let b = logic.pred(body, vec![v("x")]);
+        let or = logic.or(not_r, b);
+        let root = logic.for_all("x", or);
+        logic.finish(vec![root])
+    }
+
+    /// ∃x. (restrictor(x) ∧ body(x)), or its negation when `none`
+    fn some(restrictor: &str, body: &str, none: bool) -> LogicBuffer {
+        let mut logic = Logic::default();
+        let r = logic.pred(restrictor, vec![v("x")]);
+        let b = logic.pred(body, vec![v("x")]);
+        let and = logic.and(r, b);
+        let mut root = logic.exists("x", and);
+        if none {
+            root = logic.not(root);
+        }
+        logic.finish(vec![root])
+    }
+
+    /// "la .alis. pu klama": ∃e. At(e, klama(alis)) ∧ before(e, now)
+    fn went(who: &str) -> LogicBuffer {
+        let mut logic = Logic::default();
+        let klama = logic.pred("klama", vec![c(who), LogicalTerm::Unspecified]);
+        let at = logic.at(v("_v0"), klama);
+        let before = logic.pred("before", vec![v("_v0"), c("now")]);
+        let body = logic.and(at, before);
+        let root = logic.exists("_v0", body);
+        logic.finish(vec![root])
+    }
+
+    fn assert_ok(kb: &mut KnowledgeBase, logic: LogicBuffer) -> FactHandle {
+        let sources = vec![String::new(); logic.roots.len()];
+        match kb.assert_fact(logic, sources, ConflictPolicy::Reject) {
+            Ok(handle) => handle,
+            Err(AssertError::Conflict(c)) => panic!("unexpected conflict: {:?}", c),
+            Err(AssertError::Failed(e)) => panic!("assertion failed: {}", e),
+        }
+    }
+
+    /// Assert a one-root buffer said as `source`.
+    fn assert_said(
+        kb: &mut KnowledgeBase,
+        logic: LogicBuffer,
+        source: &str,
+        policy: ConflictPolicy,
+    ) -> Result<FactHandle, AssertError> {
+        kb.assert_fact(logic, vec![source.to_string()], policy)
+    }
+
+    fn conflicts(result: Result<FactHandle, AssertError>) -> Vec<Conflict> {
+        match result {
+            Err(AssertError::Conflict(conflicts)) => conflicts,
+            other => panic!("expected a conflict, got {:?}", other.map_err(|_| ())),
+        }
+    }
+
+    fn query(kb: &mut KnowledgeBase, logic: LogicBuffer, world: WorldAssumption) -> TruthValue {
+        kb.query_entailment(logic, world).expect("query failed")
+    }
+
+    fn sorted_entities(kb: &KnowledgeBase) -> Vec<String> {
+        let mut entities = kb.known_entities();
+        entities.sort();
+        entities
+    }
+
+    // ─── Three-valued queries ─────────────────────────────────
+
+    #[test]
+    fn open_world_distinguishes_refuted_from_unknown() {
+        let mut kb = KnowledgeBase::new();
+        assert_ok(&mut kb, atom("gerku", vec![c("rex")]));
+        assert_ok(&mut kb, negated("mlatu", vec![c("rex")]));
+
+        let open = WorldAssumption::Open;
+        let cases = [
+            (atom("gerku", vec![c("rex")]), TruthValue::Entailed),
+            (negated("gerku", vec![c("rex")]), TruthValue::Refuted),
+            (atom("mlatu", vec![c("rex")]), TruthValue::Refuted),
+            (negated("mlatu", vec![c("rex")]), TruthValue::Entailed),
+            (atom("blanu", vec![c("rex")]), TruthValue::Unknown),
+            (negated("blanu", vec![c("rex")]), TruthValue::Unknown),
+        ];
+        for (logic, expected) in cases {
+            assert_eq!(query(&mut kb, logic, open), expected);
+        }
+    }
+
+    #[test]
+    fn closed_world_refutes_what_it_cannot_prove() {
+        let mut kb = KnowledgeBase::new();
+        assert_ok(&mut kb, atom("gerku", vec![c("rex")]));
+
+        let closed = WorldAssumption::Closed;
+        let cases = [
+            (atom("gerku", vec![c("rex")]), TruthValue::Entailed),
+            (atom("blanu", vec![c("rex")]), TruthValue::Refuted),
+            (negated("blanu", vec![c("rex")]), TruthValue::Entailed),
+        ];
+        for (logic, expected) in cases {
+            assert_eq!(query(&mut kb, logic, closed), expected);
+        }
+    }
+
+    #[test]
+    fn universals_range_over_known_entities_only_when_closed() {
+        let mut kb = KnowledgeBase::new();
+        assert_ok(&mut kb, atom("gerku", vec![c("rex")]));
+
+        let mut logic = Logic::default();
+        let gerku = logic.pred("gerku", vec![v("da")]);
+        let root = logic.for_all("da", gerku);
+        let all_dogs = logic.finish(vec![root]);
+        assert_eq!(
+            query(&mut kb, all_dogs.clone(), WorldAssumption::Closed),
+            TruthValue::Entailed
+        );
+        assert_eq!(
+            query(&mut kb, all_dogs, WorldAssumption::Open),
+            TruthValue::Unknown
+        );
+    }
+
+    #[test]
+    fn no_refutes_the_existential() {
+        // no lo gerku cu sutra ⊢ ¬(lo gerku cu sutra)
+        let mut kb = KnowledgeBase::new();
+        assert_ok(&mut kb, some("gerku", "sutra", true));
+        assert!(sorted_entities(&kb).is_empty());
+        assert_eq!(
+            query(
+                &mut kb,
+                some("gerku", "sutra", false),
+                WorldAssumption::Open
+            ),
+            TruthValue::Refuted
+        );
+
+        // ... and of each dog met later
+        assert_ok(&mut kb, atom("gerku", vec![c("rex")]));
+        assert_eq!(
+            query(
+                &mut kb,
+                atom("sutra", vec![c("rex")]),
+                WorldAssumption::Open
+            ),
+            TruthValue::Refuted
+        );
+    }
+
+    // ─── Conflicts ────────────────────────────────────────────
+
+    #[test]
+    fn rejected_assertion_leaves_the_knowledge_base_as_it_was() {
+        let mut kb = KnowledgeBase::new();
+        let first = assert_ok(&mut kb, atom("gerku", vec![c("rex")]));
+
+        let result = assert_said(
+            &mut kb,
+            negated("gerku", vec![c("rex")]),
+            "rex na gerku",
+            ConflictPolicy::Reject,
+        );
+        let conflicts = conflicts(result);
+        assert_eq!(conflicts.len(), 1);
+        assert!(conflicts[0].sources.contains(&"rex na gerku".to_string()));
+
+        assert!(kb.quarantine.is_empty());
+        assert_eq!(kb.facts.len(), 1);
+        assert_eq!(
+            query(
+                &mut kb,
+                atom("gerku", vec![c("rex")]),
+                WorldAssumption::Open
+            ),
+            TruthValue::Entailed
+        );
+        // The rejected assertion's handle is not used up
+        assert_eq!(assert_ok(&mut kb, atom("mlatu", vec![c("tom")])), first + 1);
+    }
+
+    #[test]
+    fn quarantined_assertion_is_kept_aside_with_its_conflicts() {
+        let mut kb = KnowledgeBase::new();
+        assert_said(
+            &mut kb,
+            every("gerku", "danlu"),
+            "ro lo gerku cu danlu",
+            ConflictPolicy::Quarantine,
+        )
+        .unwrap();
+        assert_said(
+            &mut kb,
+            negated("danlu", vec![c("rex")]),
+            "rex na danlu",
+            ConflictPolicy::Quarantine,
+        )
+        .unwrap();
+
+        let result = assert_said(
+            &mut kb,
+            atom("gerku", vec![c("rex")]),
+            "rex gerku",
+            ConflictPolicy::Quarantine,
+        );
+        let conflicts = conflicts(result);
+
+        assert_eq!(kb.quarantine.len(), 1);
+        assert_eq!(kb.quarantine[0].sources, vec!["rex gerku"]);
+        assert_eq!(kb.quarantine[0].conflicts.len(), conflicts.len());
+        // danlu(rex) is traced to the universal that derived it
+        assert_eq!(
+            conflicts[0].sources,
+            vec!["rex na danlu", "ro lo gerku cu danlu"]
+        );
+
+        // What the knowledge base kept still says rex is no dog
+        assert_eq!(
+            query(
+                &mut kb,
+                atom("gerku", vec![c("rex")]),
+                WorldAssumption::Open
+            ),
+            TruthValue::Refuted
+        );
+    }
+
+    // ─── Retraction ───────────────────────────────────────────
+
+    #[test]
+    fn retraction_withdraws_what_only_the_fact_supported() {
+        let mut kb = KnowledgeBase::new();
+        let rule = assert_ok(&mut kb, every("gerku", "danlu"));
+        assert_ok(&mut kb, atom("gerku", vec![c("rex")]));
+        let open = WorldAssumption::Open;
+        assert_eq!(
+            query(&mut kb, atom("danlu", vec![c("rex")]), open),
+            TruthValue::Entailed
+        );
+
+        kb.retract_fact(rule).unwrap();
+        assert!(kb.templates.is_empty());
+        assert_eq!(
+            query(&mut kb, atom("danlu", vec![c("rex")]), open),
+            TruthValue::Unknown
+        );
+        assert_eq!(
+            query(&mut kb, atom("gerku", vec![c("rex")]), open),
+            TruthValue::Entailed
+        );
+    }
+
+    #[test]
+    fn retraction_drops_the_facts_skolem_constants() {
+        let mut kb = KnowledgeBase::new();
+        assert_ok(&mut kb, atom("gerku", vec![c("rex")]));
+        let mut logic = Logic::default();
+        let mlatu = logic.pred("mlatu", vec![v("da")]);
+        let root = logic.exists("da", mlatu);
+        let handle = assert_ok(&mut kb, logic.finish(vec![root]));
+        assert_eq!(kb.known_entities().len(), 2);
+
+        kb.retract_fact(handle).unwrap();
+        assert_eq!(sorted_entities(&kb), vec!["rex"]);
+    }
+
+    #[test]
+    fn retraction_makes_room_for_a_rejected_assertion() {
+        let mut kb = KnowledgeBase::new();
+        let handle = assert_ok(&mut kb, atom("gerku", vec![c("rex")]));
+        let denial = || negated("gerku", vec![c("rex")]);
+        assert!(
+            kb.assert_fact(denial(), vec![String::new()], ConflictPolicy::Reject)
+                .is_err()
+        );
+
+        kb.retract_fact(handle).unwrap();
+        let next = assert_ok(&mut kb, denial());
+        assert!(next > handle);
+        assert_eq!(
+            query(
+                &mut kb,
+                atom("gerku", vec![c("rex")]),
+                WorldAssumption::Open
+            ),
+            TruthValue::Refuted
+        );
+        assert!(kb.retract_fact(handle).is_err());
+    }
+
+    // ─── Reset and fork ───────────────────────────────────────
+
+    #[test]
+    fn fork_evolves_independently() {
+        let mut kb = KnowledgeBase::new();
+        assert_ok(&mut kb, atom("gerku", vec![c("rex")]));
+        let mut fork = kb.clone();
+        assert_ok(&mut fork, atom("mlatu", vec![c("tom")]));
+        assert_ok(&mut kb, negated("mlatu", vec![c("tom")]));
+
+        let open = WorldAssumption::Open;
+        assert_eq!(
+            query(&mut kb, atom("mlatu", vec![c("tom")]), open),
+            TruthValue::Refuted
+        );
+        assert_eq!(
+            query(&mut fork, atom("mlatu", vec![c("tom")]), open),
+            TruthValue::Entailed
+        );
+        assert_eq!(
+            query(&mut fork, atom("gerku", vec![c("rex")]), open),
+            TruthValue::Entailed
+        );
+    }
+
+    #[test]
+    fn reset_forgets_everything_but_the_handle_count() {
+        let mut kb = KnowledgeBase::new();
+        assert_ok(&mut kb, every("gerku", "danlu"));
+        let handle = assert_ok(&mut kb, atom("gerku", vec![c("rex")]));
+        let _ = kb.assert_fact(
+            negated("gerku", vec![c("rex")]),
+            vec![String::new()],
+            ConflictPolicy::Quarantine,
+        );
+        let fork = kb.clone();
+
+        kb.reset();
+        assert!(kb.known_entities().is_empty());
+        assert!(kb.templates.is_empty() && kb.quarantine.is_empty());
+        assert_eq!(
+            query(
+                &mut kb,
+                atom("gerku", vec![c("rex")]),
+                WorldAssumption::Open
+            ),
+            TruthValue::Unknown
+        );
+        assert!(kb.retract_fact(handle).is_err());
+        assert!(assert_ok(&mut kb, atom("gerku", vec![c("rex")])) > handle);
+
+        // A fork taken before the reset keeps what it knew
+        assert_eq!(fork.facts.len(), 2);
+        assert_eq!(fork.quarantine.len(), 1);
+    }
+
+    // ─── Time ─────────────────────────────────────────────────
+
+    #[test]
+    fn intervals_are_not_entities() {
+        let mut kb = KnowledgeBase::new();
+        assert_ok(&mut kb, atom("prenu", vec![c("alis")]));
+        assert_ok(&mut kb, went("alis"));
+
+        assert_eq!(sorted_entities(&kb), vec!["alis"]);
+        assert_eq!(kb.known_intervals().len(), 2);
+
+        let mut logic = Logic::default();
+        let prenu = logic.pred("prenu", vec![v("da")]);
+        let root = logic.for_all("da", prenu);
+        let everyone = logic.finish(vec![root]);
+        assert_eq!(
+            query(&mut kb, everyone, WorldAssumption::Closed),
+            TruthValue::Entailed
+        );
+    }
+
+    #[test]
+    fn tensed_query_ranges_over_intervals() {
+        let mut kb = KnowledgeBase::new();
+        assert_ok(&mut kb, went("alis"));
+        assert_eq!(
+            query(&mut kb, went("alis"), WorldAssumption::Open),
+            TruthValue::Entailed
+        );
+        assert_eq!(
+            query(&mut kb, went("bob"), WorldAssumption::Closed),
+            TruthValue::Refuted
+        );
+    }
+
+    #[test]
+    fn universals_skip_intervals() {
+        let mut kb = KnowledgeBase::new();
+        assert_ok(&mut kb, every("klama", "prenu"));
+        assert_ok(&mut kb, went("alis"));
+        assert_eq!(kb.templates.len(), 1);
+        assert!(kb.known_intervals().iter().all(|t| {
+            let atom = format!("(Pred \"prenu\" (Cons (Const \"{}\") (Nil)))", t);
+            !check_atom(&atom, &mut kb.egraph).unwrap()
+        }));
+    }
+
+    #[test]
+    fn ma_is_never_bound_to_a_time() {
+        let mut kb = KnowledgeBase::new();
+        assert_ok(&mut kb, went("alis"));
+        let mut logic = Logic::default();
+        let klama = logic.pred("klama", vec![v("ma"), LogicalTerm::Unspecified]);
+        let question = logic.finish(vec![klama]);
+        let answers = kb
+            .query_bindings(question, vec!["ma".to_string()], WorldAssumption::Open)
+            .unwrap();
+        let values: Vec<_> = answers.iter().map(|s| s[0].value.clone()).collect();
+        assert!(matches!(values.as_slice(), [AnswerTerm::Entity(e)] if e == "alis"));
+        assert!(kb.known_relations().iter().all(|(r, _)| r != "before"));
+    }
+}
@@ -22,6 +22,22 @@ mod pipeline_bind {
     wasmtime::component::bindgen!({ path: "../wit/world.wit", world: "engine-pipeline" });
 }
 
+use pipeline_bind::exports::lojban::nesy::engine::Answer;
+use pipeline_bind::lojban::nesy::reasoning::{
+    AnswerTerm, AssertError, Binding, ConflictPolicy, TruthValue, WorldAssumption,
+};
+
+/// "ma = djan, mo = prami"
+fn format_substitution(substitution: &[Binding]) -> String {
+    substitution
+        .iter()
+        .map(|b| match &b.value {
+            AnswerTerm::Entity(e) | AnswerTerm::Relation(e) => format!("{} = {}", b.variable, e),
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
 fn main() -> Result<()> {
     println!("==================================================");
     println!(" Lojban Neuro-Symbolic Engine - V4 Typed Pipeline  ");
@@ -44,13 +60,22 @@ fn main() -> Result<()> {
     let pipeline_comp =
         Component::from_file(&engine, "target/wasm32-wasip1/release/engine-pipeline.wasm")?;
     let pipeline = pipeline_bind::EnginePipeline::instantiate(&mut store, &pipeline_comp, &linker)?;
+    let kb_api = pipeline.lojban_nesy_engine().knowledge_base();
+
+    // Independent knowledge bases; text goes to the current one
+    let mut kbs = vec![kb_api.call_constructor(&mut store)?];
+    let mut current = 0;
 
     let mut line_editor = Reedline::create();
     let prompt = DefaultPrompt::default();
 
-    println!("Ready. Commands: :quit :debug <text> :help");
+    println!("Ready. Commands: :quit :debug <text> :retract <n> :new :fork :use <n> :help");
     println!("Prefix '?' for queries, plain text for assertions.\n");
 
+    // Open world by default: what the KB does not say is unknown
+    let mut assumption = WorldAssumption::Open;
+    let mut policy = ConflictPolicy::Reject;
+
     loop {
         let sig = line_editor.read_line(&prompt);
         match sig {
@@ -64,16 +89,96 @@ fn main() -> Result<()> {
                     ":quit" | ":q" => break,
                     ":help" | ":h" => {
                         println!("  <text>           Assert Lojban as fact");
-                        println!("  ? <text>         Query entailment");
+                        println!("  ? <text>         Query entailment, or ask a xu/ma/mo question");
                         println!("  :debug <text>    Show compiled logic tree");
+                        println!("  :retract <n>     Withdraw fact #n and what follows from it");
+                        println!("  :new             Switch to a new, empty knowledge base");
+                        println!("  :fork            Switch to a copy of this knowledge base");
+                        println!("  :use <n>         Switch to knowledge base #n");
+                        println!("  :reset           Empty the current knowledge base");
+                        println!("  :open            Unprovable queries are UNKNOWN (default)");
+                        println!("  :closed          Unprovable queries are FALSE");
+                        println!("  :reject          Drop contradictory assertions (default)");
+                        println!("  :quarantine      Set contradictory assertions aside");
                         println!("  :quit            Exit");
                         continue;
                     }
+                    ":open" => {
+                        assumption = WorldAssumption::Open;
+                        println!("[Host] Open-world queries");
+                        continue;
+                    }
+                    ":closed" => {
+                        assumption = WorldAssumption::Closed;
+                        println!("[Host] Closed-world queries");
+                        continue;
+                    }
+                    ":reject" => {
+                        policy = ConflictPolicy::Reject;
+                        println!("[Host] Contradictory assertions are dropped");
+                        continue;
+                    }
+                    ":quarantine" => {
+                        policy = ConflictPolicy::Quarantine;
+                        println!("[Host] Contradictory assertions are quarantined");
+                        continue;
+                    }
+                    ":new" => {
+                        match kb_api.call_constructor(&mut store) {
+                            Ok(kb) => {
+                                kbs.push(kb);
+                                current = kbs.len() - 1;
+                                println!("[Host] Knowledge base #{} (empty)", current);
+                            }
+                            Err(e) => println!("[Host Error] {:?}", e),
+                        }
+                        continue;
+                    }
+                    ":fork" => {
+                        match kb_api.call_fork(&mut store, kbs[current]) {
+                            Ok(kb) => {
+                                kbs.push(kb);
+                                println!(
+                                    "[Host] Knowledge base #{} (fork of #{})",
+                                    kbs.len() - 1,
+                                    current
+                                );
+                                current = kbs.len() - 1;
+                            }
+                            Err(e) => println!("[Host Error] {:?}", e),
+                        }
+                        continue;
+                    }
+                    ":reset" => {
+                        match kb_api.call_reset(&mut store, kbs[current]) {
+                            Ok(()) => println!("[Host] Knowledge base #{} emptied", current),
+                            Err(e) => println!("[Host Error] {:?}", e),
+                        }
+                        continue;
+                    }
                     _ => {}
                 }
 
                 // ── Route by prefix ──
-                if let Some(debug_text) = input.strip_prefix(":debug ") {
+                if let Some(n) = input.strip_prefix(":use") {
+                    match n.trim().trim_start_matches('#').parse::<usize>() {
+                        Ok(n) if n < kbs.len() => {
+                            current = n;
+                            println!("[Host] Knowledge base #{}", current);
+                        }
+                        _ => println!("[Host] Usage: :use <0..{}>", kbs.len() - 1),
+                    }
+                } else if let Some(handle) = input.strip_prefix(":retract") {
+                    let Ok(handle) = handle.trim().trim_start_matches('#').parse() else {
+                        println!("[Host] Usage: :retract <fact number>");
+                        continue;
+                    };
+                    match kb_api.call_retract(&mut store, kbs[current], handle) {
+                        Ok(Ok(())) => println!("[Retract] Fact #{} withdrawn.", handle),
+                        Ok(Err(e)) => println!("[Error] {}", e),
+                        Err(e) => println!("[Host Error] {:?}", e),
+                    }
+                } else if let Some(debug_text) = input.strip_prefix(":debug ") {
                     let text = debug_text.trim();
                     if text.is_empty() {
                         println!("[Host] Usage: :debug <lojban text>");
@@ -90,16 +195,43 @@ fn main() -> Result<()> {
                         println!("[Host] Usage: ? <lojban query>");
                         continue;
                     }
-                    match pipeline.call_query_text(&mut store, text) {
-                        Ok(Ok(true)) => println!("[Query] TRUE"),
-                        Ok(Ok(false)) => println!("[Query] FALSE"),
+                    match kb_api.call_ask_text(&mut store, kbs[current], text, assumption) {
+                        Ok(Ok(Answer::Truth(TruthValue::Entailed))) => println!("[Query] TRUE"),
+                        Ok(Ok(Answer::Truth(TruthValue::Refuted))) => println!("[Query] FALSE"),
+                        Ok(Ok(Answer::Truth(TruthValue::Unknown))) => println!("[Query] UNKNOWN"),
+                        Ok(Ok(Answer::Bindings(answers))) if answers.is_empty() => {
+                            println!("[Query] No answers")
+                        }
+                        Ok(Ok(Answer::Bindings(answers))) => {
+                            for substitution in &answers {
+                                println!("[Answer] {}", format_substitution(substitution));
+                            }
+                        }
                         Ok(Err(e)) => println!("[Error] {}", e),
                         Err(e) => println!("[Host Error] {:?}", e),
                     }
                 } else {
-                    match pipeline.call_assert_text(&mut store, input) {
-                        Ok(Ok(n)) => println!("[Assert] {} fact(s) inserted.", n),
-                        Ok(Err(e)) => println!("[Error] {}", e),
+                    match kb_api.call_assert_text(&mut store, kbs[current], input, policy) {
+                        Ok(Ok(assertion)) => {
+                            for diagnostic in &assertion.diagnostics {
+                                println!("[Skipped] {}", diagnostic);
+                            }
+                            println!(
+                                "[Assert] {} fact(s) inserted as #{}.",
+                                assertion.roots, assertion.handle
+                            );
+                        }
+                        Ok(Err(AssertError::Conflict(conflicts))) => {
+                            println!("[Assert] Contradiction, nothing inserted:");
+                            for conflict in &conflicts {
+                                println!(
+                                    "  {} (from {})",
+                                    conflict.formula,
+                                    conflict.sources.join(" / ")
+                                );
+                            }
+                        }
+                        Ok(Err(AssertError::Failed(e))) => println!("[Error] {}", e),
                         Err(e) => println!("[Host Error] {:?}", e),
                     }
                 }
@@ -111,5 +243,9 @@ fn main() -> Result<()> {
             }
         }
     }
+
+    for kb in kbs {
+        kb.resource_drop(&mut store)?;
+    }
     Ok(())
 }
@@ -15,12 +15,34 @@ fn main() {
     let mut lujvo_count: usize = 0;
     let mut override_count: usize = 0;
 
+    // BAI cmavo → gismu whose x1 the tagged sumti fills
+    let mut bai_entries: Vec<(String, String)> = Vec::new();
+
+    // rafsi → source gismu, and lujvo → place mappings onto their components
+    let mut rafsi_map = phf_codegen::Map::new();
+    let mut rafsi_count: usize = 0;
+    let mut place_map = phf_codegen::Map::new();
+    let mut mapped_lujvo_count: usize = 0;
+
     // Iterate through every <valsi> block, bypassing XML strictness
     // (jbovlaste XML is riddled with formatting errors that crash strict parsers)
     for block in content.split("<valsi ") {
         let word = extract_attribute(block, "word=\"");
         let typ = extract_attribute(block, "type=\"");
 
+        if typ == "cmavo" && extract_selmaho(block).is_some_and(|s| s.starts_with("BAI")) {
+            let gismu = if let Some(&(_, g)) = CORE_BAI_GISMU.iter().find(|(c, _)| *c == word) {
+                Some(g)
+            } else {
+                extract_definition(block).and_then(extract_bai_gismu)
+            };
+            let seen = bai_entries.iter().any(|(c, _)| c == word);
+            if let (Some(gismu), false) = (gismu, seen) {
+                bai_entries.push((word.to_string(), format!("{:?}", gismu)));
+            }
+            continue;
+        }
+
         if word.is_empty() || (typ != "gismu" && typ != "lujvo") {
             continue;
         }
@@ -46,6 +68,28 @@ fn main() {
 
         map.entry(word.to_string(), arity_str);
 
+        if typ == "gismu" {
+            for rafsi in extract_rafsi(block) {
+                rafsi_map.entry(rafsi.to_string(), format!("{:?}", word));
+                rafsi_count += 1;
+            }
+        }
+
+        let places = match (typ, extract_definition(block)) {
+            ("lujvo", Some(definition)) => extract_place_mapping(definition),
+            _ => Vec::new(),
+        };
+        if !places.is_empty() {
+            let entries: Vec<String> = places
+                .iter()
+                .map(|(lujvo_place, letter, place)| {
+                    format!("({}, {:?}, {})", lujvo_place, letter, place)
+                })
+                .collect();
+            place_map.entry(word.to_string(), format!("&[{}]", entries.join(", ")));
+            mapped_lujvo_count += 1;
+        }
+
         match typ {
             "gismu" => gismu_count += 1,
             "lujvo" => lujvo_count += 1,
@@ -53,11 +97,29 @@ fn main() {
         }
     }
 
+    // Core BAI missing from the dictionary dump still get an entry
+    for &(cmavo, gismu) in CORE_BAI_GISMU {
+        if !bai_entries.iter().any(|(c, _)| c == cmavo) {
+            bai_entries.push((cmavo.to_string(), format!("{:?}", gismu)));
+        }
+    }
+
+    let mut bai_map = phf_codegen::Map::new();
+    for (cmavo, gismu) in &bai_entries {
+        bai_map.entry(cmavo.as_str(), gismu);
+    }
+
     // Build-time diagnostics
     let total = gismu_count + lujvo_count;
     println!(
-        "cargo:warning=jbovlaste: {} entries ({} gismu, {} lujvo, {} from hardcoded overrides)",
-        total, gismu_count, lujvo_count, override_count
+        "cargo:warning=jbovlaste: {} entries ({} gismu, {} lujvo, {} from hardcoded overrides), {} BAI, {} rafsi, {} lujvo place mappings",
+        total,
+        gismu_count,
+        lujvo_count,
+        override_count,
+        bai_entries.len(),
+        rafsi_count,
+        mapped_lujvo_count
     );
 
     let out_dir = env::var("OUT_DIR").unwrap();
@@ -70,6 +132,27 @@ fn main() {
         map.build()
     )
     .unwrap();
+
+    writeln!(
+        &mut file,
+        "pub static BAI_GISMU: phf::Map<&'static str, &'static str> = \n{};",
+        bai_map.build()
+    )
+    .unwrap();
+
+    writeln!(
+        &mut file,
+        "pub static RAFSI_GISMU: phf::Map<&'static str, &'static str> = \n{};",
+        rafsi_map.build()
+    )
+    .unwrap();
+
+    writeln!(
+        &mut file,
+        "pub static LUJVO_PLACES: phf::Map<&'static str, &'static [(usize, char, usize)]> = \n{};",
+        place_map.build()
+    )
+    .unwrap();
 }
 
 /// Extracts an XML attribute value from a block using string scanning.
@@ -91,6 +174,80 @@ fn extract_definition(block: &str) -> Option<&str> {
     Some(&rest[..end])
 }
 
+/// Extracts the <selmaho> text from a cmavo block.
+fn extract_selmaho(block: &str) -> Option<&str> {
+    let start = block.find("<selmaho>")?;
+    let rest = &block[start + 9..]; // 9 = "<selmaho>".len()
+    let end = rest.find("</selmaho>")?;
+    Some(rest[..end].trim())
+}
+
+/// Extracts every <rafsi> of a gismu block.
+fn extract_rafsi(block: &str) -> Vec<&str> {
+    block
+        .split("<rafsi>")
+        .skip(1)
+        .filter_map(|rest| rest.split_once("</rafsi>"))
+        .map(|(rafsi, _)| rafsi.trim())
+        .filter(|rafsi| !rafsi.is_empty())
+        .collect()
+}
+
+/// Extracts lujvo place mappings from equations in a definition, e.g.
+/// "$x_{1}=z_{1}$ is a kennel for dog $x_{2}=z_{2}=g_{1}$".
+///
+/// Each mapping is (lujvo place, component letter, component place); the
+/// letter is the initial of a component gismu. Definitions written purely
+/// in component letters ("$z_1$ ... $z_2=g_1$") use the first letter as
+/// the lujvo's own places.
+fn extract_place_mapping(definition: &str) -> Vec<(usize, char, usize)> {
+    let chains: Vec<Vec<(char, usize)>> = definition
+        .split('$')
+        .skip(1)
+        .step_by(2)
+        .filter_map(|math| math.split('=').map(parse_place_ref).collect())
+        .collect();
+
+    let lujvo_letter = if chains.iter().flatten().any(|(letter, _)| *letter == 'x') {
+        'x'
+    } else if let Some(&(letter, _)) = chains.first().and_then(|c| c.first()) {
+        letter
+    } else {
+        return Vec::new();
+    };
+
+    let mut mappings = Vec::new();
+    for chain in &chains {
+        let Some(&(_, lujvo_place)) = chain.iter().find(|(l, _)| *l == lujvo_letter) else {
+            continue;
+        };
+        for &(letter, place) in chain {
+            let mapping = (lujvo_place, letter, place);
+            if letter != 'x' && !mappings.contains(&mapping) {
+                mappings.push(mapping);
+            }
+        }
+    }
+    mappings
+}
+
+/// Parses a place reference such as `x_{1}`, `g_1` or `z2`.
+fn parse_place_ref(text: &str) -> Option<(char, usize)> {
+    let mut chars = text.trim().chars();
+    let letter = chars.next().filter(|c| c.is_ascii_lowercase())?;
+    let digits: String = chars.filter(|c| !matches!(c, '_' | '{' | '}')).collect();
+    let place: usize = digits.parse().ok()?;
+    (1..=5).contains(&place).then_some((letter, place))
+}
+
+/// Extracts the source gismu from a BAI definition such as
+/// "rinka modal, 1st place (physically caused by) ...".
+fn extract_bai_gismu(definition: &str) -> Option<&str> {
+    let (head, _) = definition.trim().split_once(" modal")?;
+    let is_gismu = head.len() == 5 && head.bytes().all(|b| b.is_ascii_lowercase());
+    is_gismu.then_some(head)
+}
+
 /// Extracts the highest place variable (x1..x5) from a jbovlaste definition.
 ///
 /// Handles all known formats in jbovlaste:
@@ -131,7 +288,7 @@ fn extract_arity(definition: &str) -> usize {
 
                 if j < len && bytes[j].is_ascii_digit() {
                     let digit = (bytes[j] - b'0') as usize;
-                    if digit >= 1 && digit <= 5 {
+                    if (1..=5).contains(&digit) {
                         max_place = max_place.max(digit);
                     }
                 }
@@ -195,3 +352,73 @@ const CORE_GISMU_ARITIES: &[(&str, usize)] = &[
     ("pelxu", 1), // x1 is yellow
     ("crino", 1), // x1 is green
 ];
+
+/// BAI cmavo and the gismu whose x1 they tag.
+///
+/// Source: CLL Chapter 9.16 (BAI table). Authoritative over definition parsing.
+const CORE_BAI_GISMU: &[(&str, &str)] = &[
+    ("ba'i", "basti"), // replaced by
+    ("bai", "bapli"),  // compelled by
+    ("bau", "bangu"),  // in language
+    ("be'i", "benji"), // sent by
+    ("ca'i", "catni"), // by authority of
+    ("cau", "claxu"),  // lacked by
+    ("ci'e", "ciste"), // in system
+    ("ci'o", "cinmo"), // felt by
+    ("ci'u", "ckilu"), // on scale
+    ("cu'u", "cusku"), // as said by
+    ("de'i", "detri"), // dated
+    ("di'o", "diklo"), // at the locus of
+    ("du'i", "dunli"), // as much as
+    ("du'o", "djuno"), // according to
+    ("fa'e", "fatne"), // reverse of
+    ("fau", "fasnu"),  // in the event of
+    ("fi'e", "finti"), // created by
+    ("ga'a", "zgana"), // to observer
+    ("gau", "gasnu"),  // with agent
+    ("ja'e", "jalge"), // resulting in
+    ("ja'i", "javni"), // by rule
+    ("ji'e", "jimte"), // up to limit
+    ("ji'o", "jitro"), // under direction of
+    ("ji'u", "jicmu"), // based on
+    ("ka'a", "klama"), // gone to by
+    ("ka'i", "krati"), // represented by
+    ("kai", "ckaji"),  // characterizing
+    ("ki'i", "ckini"), // as a relation of
+    ("ki'u", "krinu"), // justified by
+    ("koi", "korbi"),  // bordered by
+    ("ku'u", "kulnu"), // in culture
+    ("la'u", "klani"), // quantifying
+    ("le'a", "klesi"), // in category
+    ("li'e", "lidne"), // preceded by
+    ("ma'e", "marji"), // in material
+    ("ma'i", "manri"), // in reference frame
+    ("mau", "zmadu"),  // exceeded by
+    ("me'a", "mleca"), // undercut by
+    ("me'e", "cmene"), // with name
+    ("mu'i", "mukti"), // motivated by
+    ("mu'u", "mupli"), // exemplified by
+    ("ni'i", "nibli"), // entailed by
+    ("pa'a", "panra"), // in addition to
+    ("pa'u", "pagbu"), // with component
+    ("pi'o", "pilno"), // used by
+    ("po'i", "porsi"), // in the sequence
+    ("pu'a", "pluka"), // pleased by
+    ("pu'e", "pruce"), // by process
+    ("ra'a", "srana"), // pertained to by
+    ("ra'i", "krasi"), // from source
+    ("rai", "traji"),  // with superlative
+    ("ri'a", "rinka"), // caused by
+    ("ri'i", "lifri"), // experienced by
+    ("sau", "sarcu"),  // requiring
+    ("si'u", "sidju"), // aided by
+    ("ta'i", "tadji"), // by method
+    ("tai", "tamsmi"), // resembling
+    ("ti'i", "stidi"), // suggested by
+    ("ti'u", "tcika"), // with time
+    ("tu'i", "stuzi"), // with site
+    ("va'o", "vanbi"), // under conditions
+    ("va'u", "xamgu"), // benefiting from
+    ("zau", "zanru"),  // approved by
+    ("zu'e", "zukte"), // with motive
+];
@@ -130,8 +130,14 @@ pub mod lojban {
                 Lo,
                 Le,
                 La,
-                RoLo,
-                RoLe,
+                Loi,
+                Lei,
+                Lai,
+                Lohi,
+                Lehi,
+                Lahi,
+                Lohe,
+                Lehe,
             }
             impl ::core::fmt::Debug for Gadri {
                 fn fmt(
@@ -142,8 +148,14 @@ pub mod lojban {
                         Gadri::Lo => f.debug_tuple("Gadri::Lo").finish(),
                         Gadri::Le => f.debug_tuple("Gadri::Le").finish(),
                         Gadri::La => f.debug_tuple("Gadri::La").finish(),
-                        Gadri::RoLo => f.debug_tuple("Gadri::RoLo").finish(),
-                        Gadri::RoLe => f.debug_tuple("Gadri::RoLe").finish(),
+                        Gadri::Loi => f.debug_tuple("Gadri::Loi").finish(),
+                        Gadri::Lei => f.debug_tuple("Gadri::Lei").finish(),
+                        Gadri::Lai => f.debug_tuple("Gadri::Lai").finish(),
+                        Gadri::Lohi => f.debug_tuple("Gadri::Lohi").finish(),
+                        Gadri::Lehi => f.debug_tuple("Gadri::Lehi").finish(),
+                        Gadri::Lahi => f.debug_tuple("Gadri::Lahi").finish(),
+                        Gadri::Lohe => f.debug_tuple("Gadri::Lohe").finish(),
+                        Gadri::Lehe => f.debug_tuple("Gadri::Lehe").finish(),
                     }
                 }
             }
@@ -157,8 +169,14 @@ pub mod lojban {
                         0 => Gadri::Lo,
                         1 => Gadri::Le,
                         2 => Gadri::La,
-                        3 => Gadri::RoLo,
-                        4 => Gadri::RoLe,
+                        3 => Gadri::Loi,
+                        4 => Gadri::Lei,
+                        5 => Gadri::Lai,
+                        6 => Gadri::Lohi,
+                        7 => Gadri::Lehi,
+                        8 => Gadri::Lahi,
+                        9 => Gadri::Lohe,
+                        10 => Gadri::Lehe,
                         _ => panic!("invalid enum discriminant"),
                     }
                 }
@@ -168,6 +186,12 @@ pub mod lojban {
             pub enum RelClauseKind {
                 Poi,
                 Noi,
+                Voi,
+                Goi,
+                Pe,
+                Ne,
+                Po,
+                Pohe,
             }
             impl ::core::fmt::Debug for RelClauseKind {
                 fn fmt(
@@ -181,6 +205,18 @@ pub mod lojban {
                         RelClauseKind::Noi => {
                             f.debug_tuple("RelClauseKind::Noi").finish()
                         }
+                        RelClauseKind::Voi => {
+                            f.debug_tuple("RelClauseKind::Voi").finish()
+                        }
+                        RelClauseKind::Goi => {
+                            f.debug_tuple("RelClauseKind::Goi").finish()
+                        }
+                        RelClauseKind::Pe => f.debug_tuple("RelClauseKind::Pe").finish(),
+                        RelClauseKind::Ne => f.debug_tuple("RelClauseKind::Ne").finish(),
+                        RelClauseKind::Po => f.debug_tuple("RelClauseKind::Po").finish(),
+                        RelClauseKind::Pohe => {
+                            f.debug_tuple("RelClauseKind::Pohe").finish()
+                        }
                     }
                 }
             }
@@ -193,15 +229,330 @@ pub mod lojban {
                     match val {
                         0 => RelClauseKind::Poi,
                         1 => RelClauseKind::Noi,
+                        2 => RelClauseKind::Voi,
+                        3 => RelClauseKind::Goi,
+                        4 => RelClauseKind::Pe,
+                        5 => RelClauseKind::Ne,
+                        6 => RelClauseKind::Po,
+                        7 => RelClauseKind::Pohe,
+                        _ => panic!("invalid enum discriminant"),
+                    }
+                }
+            }
+            #[repr(u8)]
+            #[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+            pub enum AbstractionKind {
+                Nu,
+                Duhu,
+                Ka,
+                Ni,
+                Siho,
+            }
+            impl ::core::fmt::Debug for AbstractionKind {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    match self {
+                        AbstractionKind::Nu => {
+                            f.debug_tuple("AbstractionKind::Nu").finish()
+                        }
+                        AbstractionKind::Duhu => {
+                            f.debug_tuple("AbstractionKind::Duhu").finish()
+                        }
+                        AbstractionKind::Ka => {
+                            f.debug_tuple("AbstractionKind::Ka").finish()
+                        }
+                        AbstractionKind::Ni => {
+                            f.debug_tuple("AbstractionKind::Ni").finish()
+                        }
+                        AbstractionKind::Siho => {
+                            f.debug_tuple("AbstractionKind::Siho").finish()
+                        }
+                    }
+                }
+            }
+            impl AbstractionKind {
+                #[doc(hidden)]
+                pub unsafe fn _lift(val: u8) -> AbstractionKind {
+                    if !cfg!(debug_assertions) {
+                        return ::core::mem::transmute(val);
+                    }
+                    match val {
+                        0 => AbstractionKind::Nu,
+                        1 => AbstractionKind::Duhu,
+                        2 => AbstractionKind::Ka,
+                        3 => AbstractionKind::Ni,
+                        4 => AbstractionKind::Siho,
+                        _ => panic!("invalid enum discriminant"),
+                    }
+                }
+            }
+            #[repr(u8)]
+            #[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+            pub enum TimeDirection {
+                Pu,
+                Ca,
+                Ba,
+            }
+            impl ::core::fmt::Debug for TimeDirection {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    match self {
+                        TimeDirection::Pu => f.debug_tuple("TimeDirection::Pu").finish(),
+                        TimeDirection::Ca => f.debug_tuple("TimeDirection::Ca").finish(),
+                        TimeDirection::Ba => f.debug_tuple("TimeDirection::Ba").finish(),
+                    }
+                }
+            }
+            impl TimeDirection {
+                #[doc(hidden)]
+                pub unsafe fn _lift(val: u8) -> TimeDirection {
+                    if !cfg!(debug_assertions) {
+                        return ::core::mem::transmute(val);
+                    }
+                    match val {
+                        0 => TimeDirection::Pu,
+                        1 => TimeDirection::Ca,
+                        2 => TimeDirection::Ba,
+                        _ => panic!("invalid enum discriminant"),
+                    }
+                }
+            }
+            #[repr(u8)]
+            #[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+            pub enum TimeDistance {
+                Zi,
+                Za,
+                Zu,
+            }
+            impl ::core::fmt::Debug for TimeDistance {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    match self {
+                        TimeDistance::Zi => f.debug_tuple("TimeDistance::Zi").finish(),
+                        TimeDistance::Za => f.debug_tuple("TimeDistance::Za").finish(),
+                        TimeDistance::Zu => f.debug_tuple("TimeDistance::Zu").finish(),
+                    }
+                }
+            }
+            impl TimeDistance {
+                #[doc(hidden)]
+                pub unsafe fn _lift(val: u8) -> TimeDistance {
+                    if !cfg!(debug_assertions) {
+                        return ::core::mem::transmute(val);
+                    }
+                    match val {
+                        0 => TimeDistance::Zi,
+                        1 => TimeDistance::Za,
+                        2 => TimeDistance::Zu,
+                        _ => panic!("invalid enum discriminant"),
+                    }
+                }
+            }
+            #[repr(u8)]
+            #[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+            pub enum Aspect {
+                Coha,
+                Cohu,
+                Caho,
+            }
+            impl ::core::fmt::Debug for Aspect {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    match self {
+                        Aspect::Coha => f.debug_tuple("Aspect::Coha").finish(),
+                        Aspect::Cohu => f.debug_tuple("Aspect::Cohu").finish(),
+                        Aspect::Caho => f.debug_tuple("Aspect::Caho").finish(),
+                    }
+                }
+            }
+            impl Aspect {
+                #[doc(hidden)]
+                pub unsafe fn _lift(val: u8) -> Aspect {
+                    if !cfg!(debug_assertions) {
+                        return ::core::mem::transmute(val);
+                    }
+                    match val {
+                        0 => Aspect::Coha,
+                        1 => Aspect::Cohu,
+                        2 => Aspect::Caho,
+                        _ => panic!("invalid enum discriminant"),
+                    }
+                }
+            }
+            #[repr(u8)]
+            #[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+            pub enum QuantifierKind {
+                All,
+                AtLeast,
+                Exactly,
+            }
+            impl ::core::fmt::Debug for QuantifierKind {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    match self {
+                        QuantifierKind::All => {
+                            f.debug_tuple("QuantifierKind::All").finish()
+                        }
+                        QuantifierKind::AtLeast => {
+                            f.debug_tuple("QuantifierKind::AtLeast").finish()
+                        }
+                        QuantifierKind::Exactly => {
+                            f.debug_tuple("QuantifierKind::Exactly").finish()
+                        }
+                    }
+                }
+            }
+            impl QuantifierKind {
+                #[doc(hidden)]
+                pub unsafe fn _lift(val: u8) -> QuantifierKind {
+                    if !cfg!(debug_assertions) {
+                        return ::core::mem::transmute(val);
+                    }
+                    match val {
+                        0 => QuantifierKind::All,
+                        1 => QuantifierKind::AtLeast,
+                        2 => QuantifierKind::Exactly,
                         _ => panic!("invalid enum discriminant"),
                     }
                 }
             }
+            /// Byte range in the source text, end-exclusive.
+            #[repr(C)]
+            #[derive(Clone, Copy)]
+            pub struct Span {
+                pub start: u32,
+                pub end: u32,
+            }
+            impl ::core::fmt::Debug for Span {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    f.debug_struct("Span")
+                        .field("start", &self.start)
+                        .field("end", &self.end)
+                        .finish()
+                }
+            }
+            /// PU? ZI? ZAhO? — at least one part is present.
+            #[repr(C)]
+            #[derive(Clone, Copy)]
+            pub struct Tense {
+                pub direction: Option<TimeDirection>,
+                pub distance: Option<TimeDistance>,
+                pub aspect: Option<Aspect>,
+            }
+            impl ::core::fmt::Debug for Tense {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    f.debug_struct("Tense")
+                        .field("direction", &self.direction)
+                        .field("distance", &self.distance)
+                        .field("aspect", &self.aspect)
+                        .finish()
+                }
+            }
+            /// ro → all; su'o N → at-least N (bare su'o: 1); PA → exactly N
+            /// (no: exactly 0). `count` is unused for `all`.
+            #[repr(C)]
+            #[derive(Clone, Copy)]
+            pub struct Quantifier {
+                pub kind: QuantifierKind,
+                pub count: u32,
+            }
+            impl ::core::fmt::Debug for Quantifier {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    f.debug_struct("Quantifier")
+                        .field("kind", &self.kind)
+                        .field("count", &self.count)
+                        .finish()
+                }
+            }
+            /// BAI cmavo (e.g. "ri'a") or ad-hoc fi'o + selbri.
+            #[derive(Clone)]
+            pub enum ModalTag {
+                Bai(_rt::String),
+                Fiho(SelbriId),
+            }
+            impl ::core::fmt::Debug for ModalTag {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    match self {
+                        ModalTag::Bai(e) => {
+                            f.debug_tuple("ModalTag::Bai").field(e).finish()
+                        }
+                        ModalTag::Fiho(e) => {
+                            f.debug_tuple("ModalTag::Fiho").field(e).finish()
+                        }
+                    }
+                }
+            }
+            /// se? GA nai? ... gi nai? ... — a connective with conversion and negation.
+            #[repr(C)]
+            #[derive(Clone, Copy)]
+            pub struct LogicalConnective {
+                pub connective: Connective,
+                pub converted: bool,
+                pub negate_left: bool,
+                pub negate_right: bool,
+            }
+            impl ::core::fmt::Debug for LogicalConnective {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    f.debug_struct("LogicalConnective")
+                        .field("connective", &self.connective)
+                        .field("converted", &self.converted)
+                        .field("negate-left", &self.negate_left)
+                        .field("negate-right", &self.negate_right)
+                        .finish()
+                }
+            }
+            /// poi/noi/voi attach a sentence (an index into `sentences`); goi
+            /// and the relative phrases pe/ne/po/po'e attach a sumti.
+            #[derive(Clone, Copy)]
+            pub enum RelClauseBody {
+                Sentence(u32),
+                Sumti(SumtiId),
+            }
+            impl ::core::fmt::Debug for RelClauseBody {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    match self {
+                        RelClauseBody::Sentence(e) => {
+                            f.debug_tuple("RelClauseBody::Sentence").field(e).finish()
+                        }
+                        RelClauseBody::Sumti(e) => {
+                            f.debug_tuple("RelClauseBody::Sumti").field(e).finish()
+                        }
+                    }
+                }
+            }
             #[repr(C)]
             #[derive(Clone, Copy)]
             pub struct RelClause {
                 pub kind: RelClauseKind,
-                pub body_sentence: u32,
+                pub body: RelClauseBody,
             }
             impl ::core::fmt::Debug for RelClause {
                 fn fmt(
@@ -210,19 +561,27 @@ pub mod lojban {
                 ) -> ::core::fmt::Result {
                     f.debug_struct("RelClause")
                         .field("kind", &self.kind)
-                        .field("body-sentence", &self.body_sentence)
+                        .field("body", &self.body)
                         .finish()
                 }
             }
             #[derive(Clone)]
             pub enum Sumti {
                 ProSumti(_rt::String),
-                Description((Gadri, SelbriId)),
+                /// ke'a xi N; 1 is the innermost relative clause
+                Relativized(u32),
+                /// gadri, inner quantifier ("lo ci gerku"), selbri
+                Description((Gadri, Option<Quantifier>, SelbriId)),
+                /// Outer quantifier on a description or da/de/di ("re lo gerku")
+                Quantified((Quantifier, SumtiId)),
                 Name(_rt::String),
                 QuotedLiteral(_rt::String),
                 Unspecified,
                 Tagged((PlaceTag, SumtiId)),
+                TenseTagged((Tense, SumtiId)),
+                ModalTagged((ModalTag, SumtiId)),
                 Restricted((SumtiId, RelClause)),
+                Connected((SumtiId, LogicalConnective, SumtiId)),
             }
             impl ::core::fmt::Debug for Sumti {
                 fn fmt(
@@ -233,9 +592,15 @@ pub mod lojban {
                         Sumti::ProSumti(e) => {
                             f.debug_tuple("Sumti::ProSumti").field(e).finish()
                         }
+                        Sumti::Relativized(e) => {
+                            f.debug_tuple("Sumti::Relativized").field(e).finish()
+                        }
                         Sumti::Description(e) => {
                             f.debug_tuple("Sumti::Description").field(e).finish()
                         }
+                        Sumti::Quantified(e) => {
+                            f.debug_tuple("Sumti::Quantified").field(e).finish()
+                        }
                         Sumti::Name(e) => f.debug_tuple("Sumti::Name").field(e).finish(),
                         Sumti::QuotedLiteral(e) => {
                             f.debug_tuple("Sumti::QuotedLiteral").field(e).finish()
@@ -246,9 +611,18 @@ pub mod lojban {
                         Sumti::Tagged(e) => {
                             f.debug_tuple("Sumti::Tagged").field(e).finish()
                         }
+                        Sumti::TenseTagged(e) => {
+                            f.debug_tuple("Sumti::TenseTagged").field(e).finish()
+                        }
+                        Sumti::ModalTagged(e) => {
+                            f.debug_tuple("Sumti::ModalTagged").field(e).finish()
+                        }
                         Sumti::Restricted(e) => {
                             f.debug_tuple("Sumti::Restricted").field(e).finish()
                         }
+                        Sumti::Connected(e) => {
+                            f.debug_tuple("Sumti::Connected").field(e).finish()
+                        }
                     }
                 }
             }
@@ -262,6 +636,8 @@ pub mod lojban {
                 Grouped(SelbriId),
                 WithArgs((SelbriId, _rt::Vec<SumtiId>)),
                 Connected((SelbriId, Connective, SelbriId)),
+                /// NU-class abstraction; the u32 indexes the body in `sentences`.
+                Abstraction((AbstractionKind, u32)),
             }
             impl ::core::fmt::Debug for Selbri {
                 fn fmt(
@@ -293,6 +669,9 @@ pub mod lojban {
                         Selbri::Connected(e) => {
                             f.debug_tuple("Selbri::Connected").field(e).finish()
                         }
+                        Selbri::Abstraction(e) => {
+                            f.debug_tuple("Selbri::Abstraction").field(e).finish()
+                        }
                     }
                 }
             }
@@ -302,6 +681,10 @@ pub mod lojban {
                 pub head_terms: _rt::Vec<SumtiId>,
                 pub tail_terms: _rt::Vec<SumtiId>,
                 pub negated: bool,
+                pub tense: Option<Tense>,
+                /// Marked with xu: a yes/no question rather than a claim.
+                pub question: bool,
+                pub span: Span,
             }
             impl ::core::fmt::Debug for Bridi {
                 fn fmt(
@@ -313,16 +696,71 @@ pub mod lojban {
                         .field("head-terms", &self.head_terms)
                         .field("tail-terms", &self.tail_terms)
                         .field("negated", &self.negated)
+                        .field("tense", &self.tense)
+                        .field("question", &self.question)
+                        .field("span", &self.span)
                         .finish()
                 }
             }
+            #[derive(Clone, Copy)]
+            pub enum PrenexTerm {
+                /// quantifier? + da/de/di; a bare variable is existential.
+                Variable((Option<Quantifier>, SumtiId)),
+                Naku,
+            }
+            impl ::core::fmt::Debug for PrenexTerm {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    match self {
+                        PrenexTerm::Variable(e) => {
+                            f.debug_tuple("PrenexTerm::Variable").field(e).finish()
+                        }
+                        PrenexTerm::Naku => f.debug_tuple("PrenexTerm::Naku").finish(),
+                    }
+                }
+            }
+            #[derive(Clone)]
+            pub enum Statement {
+                /// A single top-level bridi; the u32 indexes it in `sentences`.
+                Simple(u32),
+                /// Connected statements (GA ... gi, .i JA); the u32s index `statements`.
+                Connected((LogicalConnective, u32, u32)),
+                /// terms zo'u statement; the u32 indexes the body in `statements`.
+                Prenex((_rt::Vec<PrenexTerm>, u32)),
+            }
+            impl ::core::fmt::Debug for Statement {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    match self {
+                        Statement::Simple(e) => {
+                            f.debug_tuple("Statement::Simple").field(e).finish()
+                        }
+                        Statement::Connected(e) => {
+                            f.debug_tuple("Statement::Connected").field(e).finish()
+                        }
+                        Statement::Prenex(e) => {
+                            f.debug_tuple("Statement::Prenex").field(e).finish()
+                        }
+                    }
+                }
+            }
             #[derive(Clone)]
             pub struct AstBuffer {
                 pub selbris: _rt::Vec<Selbri>,
                 pub sumtis: _rt::Vec<Sumti>,
+                /// Source spans parallel to `selbris` and `sumtis`. Nested nodes
+                /// (description selbri, be/rel clause arguments, ...) share the span
+                /// of the term or selbri they belong to.
+                pub selbri_spans: _rt::Vec<Span>,
+                pub sumti_spans: _rt::Vec<Span>,
                 pub sentences: _rt::Vec<Bridi>,
-                /// Indices into `sentences` for top-level sentences only.
-                /// Rel clause bodies live in `sentences` but are NOT roots.
+                pub statements: _rt::Vec<Statement>,
+                /// Indices into `statements` for top-level statements only.
+                /// Rel clause and abstraction bodies live in `sentences` but are NOT roots.
                 pub roots: _rt::Vec<u32>,
             }
             impl ::core::fmt::Debug for AstBuffer {
@@ -333,7 +771,10 @@ pub mod lojban {
                     f.debug_struct("AstBuffer")
                         .field("selbris", &self.selbris)
                         .field("sumtis", &self.sumtis)
+                        .field("selbri-spans", &self.selbri_spans)
+                        .field("sumti-spans", &self.sumti_spans)
                         .field("sentences", &self.sentences)
+                        .field("statements", &self.statements)
                         .field("roots", &self.roots)
                         .finish()
                 }
@@ -345,6 +786,8 @@ pub mod lojban {
                 Constant(_rt::String),
                 Description(_rt::String),
                 Unspecified,
+                /// Reified proposition; the u32 indexes its formula in `nodes`.
+                Proposition(u32),
             }
             impl ::core::fmt::Debug for LogicalTerm {
                 fn fmt(
@@ -364,6 +807,9 @@ pub mod lojban {
                         LogicalTerm::Unspecified => {
                             f.debug_tuple("LogicalTerm::Unspecified").finish()
                         }
+                        LogicalTerm::Proposition(e) => {
+                            f.debug_tuple("LogicalTerm::Proposition").field(e).finish()
+                        }
                     }
                 }
             }
@@ -372,9 +818,15 @@ pub mod lojban {
                 Predicate((_rt::String, _rt::Vec<LogicalTerm>)),
                 AndNode((u32, u32)),
                 OrNode((u32, u32)),
+                ImpliesNode((u32, u32)),
                 NotNode(u32),
                 ExistsNode((_rt::String, u32)),
                 ForAllNode((_rt::String, u32)),
+                /// Time-indexed formula: the body holds at the given interval.
+                AtNode((LogicalTerm, u32)),
+                /// Defeasible generic (lo'e): variable, restrictor, body. Each x
+                /// meeting the restrictor meets the body unless that is contradicted.
+                GenericNode((_rt::String, u32, u32)),
             }
             impl ::core::fmt::Debug for LogicNode {
                 fn fmt(
@@ -391,6 +843,9 @@ pub mod lojban {
                         LogicNode::OrNode(e) => {
                             f.debug_tuple("LogicNode::OrNode").field(e).finish()
                         }
+                        LogicNode::ImpliesNode(e) => {
+                            f.debug_tuple("LogicNode::ImpliesNode").field(e).finish()
+                        }
                         LogicNode::NotNode(e) => {
                             f.debug_tuple("LogicNode::NotNode").field(e).finish()
                         }
@@ -400,6 +855,12 @@ pub mod lojban {
                         LogicNode::ForAllNode(e) => {
                             f.debug_tuple("LogicNode::ForAllNode").field(e).finish()
                         }
+                        LogicNode::AtNode(e) => {
+                            f.debug_tuple("LogicNode::AtNode").field(e).finish()
+                        }
+                        LogicNode::GenericNode(e) => {
+                            f.debug_tuple("LogicNode::GenericNode").field(e).finish()
+                        }
                     }
                 }
             }
@@ -435,31 +896,173 @@ pub mod exports {
                 use super::super::super::super::_rt;
                 pub type AstBuffer = super::super::super::super::lojban::nesy::ast_types::AstBuffer;
                 pub type LogicBuffer = super::super::super::super::lojban::nesy::ast_types::LogicBuffer;
+                /// A compiler with a discourse of its own: what ri/ra/ru, go'i and
+                /// the ko'a series refer to carries over from one compile-buffer call
+                /// to the next, and to no other compiler.
+                #[derive(Debug)]
+                #[repr(transparent)]
+                pub struct Compiler {
+                    handle: _rt::Resource<Compiler>,
+                }
+                type _CompilerRep<T> = Option<T>;
+                impl Compiler {
+                    /// Creates a new resource from the specified representation.
+                    ///
+                    /// This function will create a new resource handle by moving `val` onto
+                    /// the heap and then passing that heap pointer to the component model to
+                    /// create a handle. The owned handle is then returned as `Compiler`.
+                    pub fn new<T: GuestCompiler>(val: T) -> Self {
+                        Self::type_guard::<T>();
+                        let val: _CompilerRep<T> = Some(val);
+                        let ptr: *mut _CompilerRep<T> = _rt::Box::into_raw(
+                            _rt::Box::new(val),
+                        );
+                        unsafe { Self::from_handle(T::_resource_new(ptr.cast())) }
+                    }
+                    /// Gets access to the underlying `T` which represents this resource.
+                    pub fn get<T: GuestCompiler>(&self) -> &T {
+                        let ptr = unsafe { &*self.as_ptr::<T>() };
+                        ptr.as_ref().unwrap()
+                    }
+                    /// Gets mutable access to the underlying `T` which represents this
+                    /// resource.
+                    pub fn get_mut<T: GuestCompiler>(&mut self) -> &mut T {
+                        let ptr = unsafe { &mut *self.as_ptr::<T>() };
+                        ptr.as_mut().unwrap()
+                    }
+                    /// Consumes this resource and returns the underlying `T`.
+                    pub fn into_inner<T: GuestCompiler>(self) -> T {
+                        let ptr = unsafe { &mut *self.as_ptr::<T>() };
+                        ptr.take().unwrap()
+                    }
+                    #[doc(hidden)]
+                    pub unsafe fn from_handle(handle: u32) -> Self {
+                        Self {
+                            handle: unsafe { _rt::Resource::from_handle(handle) },
+                        }
+                    }
+                    #[doc(hidden)]
+                    pub fn take_handle(&self) -> u32 {
+                        _rt::Resource::take_handle(&self.handle)
+                    }
+                    #[doc(hidden)]
+                    pub fn handle(&self) -> u32 {
+                        _rt::Resource::handle(&self.handle)
+                    }
+                    #[doc(hidden)]
+                    fn type_guard<T: 'static>() {
+                        use core::any::TypeId;
+                        static mut LAST_TYPE: Option<TypeId> = None;
+                        unsafe {
+                            assert!(! cfg!(target_feature = "atomics"));
+                            let id = TypeId::of::<T>();
+                            match LAST_TYPE {
+                                Some(ty) => {
+                                    assert!(
+                                        ty == id, "cannot use two types with this resource type"
+                                    )
+                                }
+                                None => LAST_TYPE = Some(id),
+                            }
+                        }
+                    }
+                    #[doc(hidden)]
+                    pub unsafe fn dtor<T: 'static>(handle: *mut u8) {
+                        Self::type_guard::<T>();
+                        let _ = unsafe {
+                            _rt::Box::from_raw(handle as *mut _CompilerRep<T>)
+                        };
+                    }
+                    fn as_ptr<T: GuestCompiler>(&self) -> *mut _CompilerRep<T> {
+                        Compiler::type_guard::<T>();
+                        T::_resource_rep(self.handle()).cast()
+                    }
+                }
+                /// A borrowed version of [`Compiler`] which represents a borrowed value
+                /// with the lifetime `'a`.
+                #[derive(Debug)]
+                #[repr(transparent)]
+                pub struct CompilerBorrow<'a> {
+                    rep: *mut u8,
+                    _marker: core::marker::PhantomData<&'a Compiler>,
+                }
+                impl<'a> CompilerBorrow<'a> {
+                    #[doc(hidden)]
+                    pub unsafe fn lift(rep: usize) -> Self {
+                        Self {
+                            rep: rep as *mut u8,
+                            _marker: core::marker::PhantomData,
+                        }
+                    }
+                    /// Gets access to the underlying `T` in this resource.
+                    pub fn get<T: GuestCompiler>(&self) -> &T {
+                        let ptr = unsafe { &mut *self.as_ptr::<T>() };
+                        ptr.as_ref().unwrap()
+                    }
+                    fn as_ptr<T: 'static>(&self) -> *mut _CompilerRep<T> {
+                        Compiler::type_guard::<T>();
+                        self.rep.cast()
+                    }
+                }
+                unsafe impl _rt::WasmResource for Compiler {
+                    #[inline]
+                    unsafe fn drop(_handle: u32) {
+                        #[cfg(not(target_arch = "wasm32"))]
+                        unreachable!();
+                        #[cfg(target_arch = "wasm32")]
+                        {
+                            #[link(
+                                wasm_import_module = "[export]lojban:nesy/semantics@0.1.0"
+                            )]
+                            unsafe extern "C" {
+                                #[link_name = "[resource-drop]compiler"]
+                                fn drop(_: u32);
+                            }
+                            unsafe { drop(_handle) };
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_constructor_compiler_cabi<T: GuestCompiler>() -> i32 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let result0 = Compiler::new(T::new());
+                    (result0).take_handle() as i32
+                }
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
-                pub unsafe fn _export_compile_buffer_cabi<T: Guest>(
+                pub unsafe fn _export_method_compiler_compile_buffer_cabi<
+                    T: GuestCompiler,
+                >(
                     arg0: *mut u8,
-                    arg1: usize,
-                    arg2: *mut u8,
-                    arg3: usize,
-                    arg4: *mut u8,
-                    arg5: usize,
-                    arg6: *mut u8,
-                    arg7: usize,
+                    arg1: *mut u8,
+                    arg2: usize,
+                    arg3: *mut u8,
+                    arg4: usize,
+                    arg5: *mut u8,
+                    arg6: usize,
+                    arg7: *mut u8,
+                    arg8: usize,
+                    arg9: *mut u8,
+                    arg10: usize,
+                    arg11: *mut u8,
+                    arg12: usize,
+                    arg13: *mut u8,
+                    arg14: usize,
                 ) -> *mut u8 {
                     #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
-                    let base24 = arg0;
-                    let len24 = arg1;
-                    let mut result24 = _rt::Vec::with_capacity(len24);
-                    for i in 0..len24 {
-                        let base = base24
+                    let base26 = arg1;
+                    let len26 = arg2;
+                    let mut result26 = _rt::Vec::with_capacity(len26);
+                    for i in 0..len26 {
+                        let base = base26
                             .add(i * (4 * ::core::mem::size_of::<*const u8>()));
-                        let e24 = {
+                        let e26 = {
                             let l0 = i32::from(*base.add(0).cast::<u8>());
-                            use super::super::super::super::lojban::nesy::ast_types::Selbri as V23;
-                            let v23 = match l0 {
+                            use super::super::super::super::lojban::nesy::ast_types::Selbri as V25;
+                            let v25 = match l0 {
                                 0 => {
-                                    let e23 = {
+                                    let e25 = {
                                         let l1 = *base
                                             .add(::core::mem::size_of::<*const u8>())
                                             .cast::<*mut u8>();
@@ -474,10 +1077,10 @@ pub mod exports {
                                         );
                                         _rt::string_lift(bytes3)
                                     };
-                                    V23::Root(e23)
+                                    V25::Root(e25)
                                 }
                                 1 => {
-                                    let e23 = {
+                                    let e25 = {
                                         let l4 = *base
                                             .add(::core::mem::size_of::<*const u8>())
                                             .cast::<*mut u8>();
@@ -512,10 +1115,10 @@ pub mod exports {
                                         );
                                         result9
                                     };
-                                    V23::Compound(e23)
+                                    V25::Compound(e25)
                                 }
                                 2 => {
-                                    let e23 = {
+                                    let e25 = {
                                         let l10 = *base
                                             .add(::core::mem::size_of::<*const u8>())
                                             .cast::<i32>();
@@ -524,10 +1127,10 @@ pub mod exports {
                                             .cast::<i32>();
                                         (l10 as u32, l11 as u32)
                                     };
-                                    V23::Tanru(e23)
+                                    V25::Tanru(e25)
                                 }
                                 3 => {
-                                    let e23 = {
+                                    let e25 = {
                                         let l12 = i32::from(
                                             *base.add(::core::mem::size_of::<*const u8>()).cast::<u8>(),
                                         );
@@ -541,28 +1144,28 @@ pub mod exports {
                                             l13 as u32,
                                         )
                                     };
-                                    V23::Converted(e23)
+                                    V25::Converted(e25)
                                 }
                                 4 => {
-                                    let e23 = {
+                                    let e25 = {
                                         let l14 = *base
                                             .add(::core::mem::size_of::<*const u8>())
                                             .cast::<i32>();
                                         l14 as u32
                                     };
-                                    V23::Negated(e23)
+                                    V25::Negated(e25)
                                 }
                                 5 => {
-                                    let e23 = {
+                                    let e25 = {
                                         let l15 = *base
                                             .add(::core::mem::size_of::<*const u8>())
                                             .cast::<i32>();
                                         l15 as u32
                                     };
-                                    V23::Grouped(e23)
+                                    V25::Grouped(e25)
                                 }
                                 6 => {
-                                    let e23 = {
+                                    let e25 = {
                                         let l16 = *base
                                             .add(::core::mem::size_of::<*const u8>())
                                             .cast::<i32>();
@@ -578,11 +1181,10 @@ pub mod exports {
                                             _rt::Vec::from_raw_parts(l17.cast(), len19, len19),
                                         )
                                     };
-                                    V23::WithArgs(e23)
+                                    V25::WithArgs(e25)
                                 }
-                                n => {
-                                    debug_assert_eq!(n, 7, "invalid enum discriminant");
-                                    let e23 = {
+                                7 => {
+                                    let e25 = {
                                         let l20 = *base
                                             .add(::core::mem::size_of::<*const u8>())
                                             .cast::<i32>();
@@ -602,423 +1204,1040 @@ pub mod exports {
                                             l22 as u32,
                                         )
                                     };
-                                    V23::Connected(e23)
+                                    V25::Connected(e25)
+                                }
+                                n => {
+                                    debug_assert_eq!(n, 8, "invalid enum discriminant");
+                                    let e25 = {
+                                        let l23 = i32::from(
+                                            *base.add(::core::mem::size_of::<*const u8>()).cast::<u8>(),
+                                        );
+                                        let l24 = *base
+                                            .add(4 + 1 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        (
+                                            super::super::super::super::lojban::nesy::ast_types::AbstractionKind::_lift(
+                                                l23 as u8,
+                                            ),
+                                            l24 as u32,
+                                        )
+                                    };
+                                    V25::Abstraction(e25)
                                 }
                             };
-                            v23
+                            v25
                         };
-                        result24.push(e24);
+                        result26.push(e26);
                     }
                     _rt::cabi_dealloc(
-                        base24,
-                        len24 * (4 * ::core::mem::size_of::<*const u8>()),
+                        base26,
+                        len26 * (4 * ::core::mem::size_of::<*const u8>()),
                         ::core::mem::size_of::<*const u8>(),
                     );
-                    let base43 = arg2;
-                    let len43 = arg3;
-                    let mut result43 = _rt::Vec::with_capacity(len43);
-                    for i in 0..len43 {
-                        let base = base43
-                            .add(i * (8 + 2 * ::core::mem::size_of::<*const u8>()));
-                        let e43 = {
-                            let l25 = i32::from(*base.add(0).cast::<u8>());
-                            use super::super::super::super::lojban::nesy::ast_types::Sumti as V42;
-                            let v42 = match l25 {
+                    let base75 = arg3;
+                    let len75 = arg4;
+                    let mut result75 = _rt::Vec::with_capacity(len75);
+                    for i in 0..len75 {
+                        let base = base75
+                            .add(i * (8 + 4 * ::core::mem::size_of::<*const u8>()));
+                        let e75 = {
+                            let l27 = i32::from(*base.add(0).cast::<u8>());
+                            use super::super::super::super::lojban::nesy::ast_types::Sumti as V74;
+                            let v74 = match l27 {
                                 0 => {
-                                    let e42 = {
-                                        let l26 = *base
+                                    let e74 = {
+                                        let l28 = *base
                                             .add(::core::mem::size_of::<*const u8>())
                                             .cast::<*mut u8>();
-                                        let l27 = *base
+                                        let l29 = *base
                                             .add(2 * ::core::mem::size_of::<*const u8>())
                                             .cast::<usize>();
-                                        let len28 = l27;
-                                        let bytes28 = _rt::Vec::from_raw_parts(
-                                            l26.cast(),
-                                            len28,
-                                            len28,
+                                        let len30 = l29;
+                                        let bytes30 = _rt::Vec::from_raw_parts(
+                                            l28.cast(),
+                                            len30,
+                                            len30,
                                         );
-                                        _rt::string_lift(bytes28)
+                                        _rt::string_lift(bytes30)
                                     };
-                                    V42::ProSumti(e42)
+                                    V74::ProSumti(e74)
                                 }
                                 1 => {
-                                    let e42 = {
-                                        let l29 = i32::from(
+                                    let e74 = {
+                                        let l31 = *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        l31 as u32
+                                    };
+                                    V74::Relativized(e74)
+                                }
+                                2 => {
+                                    let e74 = {
+                                        let l32 = i32::from(
                                             *base.add(::core::mem::size_of::<*const u8>()).cast::<u8>(),
                                         );
-                                        let l30 = *base
-                                            .add(4 + 1 * ::core::mem::size_of::<*const u8>())
+                                        let l33 = i32::from(
+                                            *base
+                                                .add(4 + 1 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>(),
+                                        );
+                                        let l36 = *base
+                                            .add(16 + 1 * ::core::mem::size_of::<*const u8>())
                                             .cast::<i32>();
                                         (
                                             super::super::super::super::lojban::nesy::ast_types::Gadri::_lift(
-                                                l29 as u8,
+                                                l32 as u8,
                                             ),
-                                            l30 as u32,
+                                            match l33 {
+                                                0 => None,
+                                                1 => {
+                                                    let e = {
+                                                        let l34 = i32::from(
+                                                            *base
+                                                                .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                                                .cast::<u8>(),
+                                                        );
+                                                        let l35 = *base
+                                                            .add(12 + 1 * ::core::mem::size_of::<*const u8>())
+                                                            .cast::<i32>();
+                                                        super::super::super::super::lojban::nesy::ast_types::Quantifier {
+                                                            kind: super::super::super::super::lojban::nesy::ast_types::QuantifierKind::_lift(
+                                                                l34 as u8,
+                                                            ),
+                                                            count: l35 as u32,
+                                                        }
+                                                    };
+                                                    Some(e)
+                                                }
+                                                _ => _rt::invalid_enum_discriminant(),
+                                            },
+                                            l36 as u32,
                                         )
                                     };
-                                    V42::Description(e42)
+                                    V74::Description(e74)
                                 }
-                                2 => {
-                                    let e42 = {
-                                        let l31 = *base
+                                3 => {
+                                    let e74 = {
+                                        let l37 = i32::from(
+                                            *base.add(::core::mem::size_of::<*const u8>()).cast::<u8>(),
+                                        );
+                                        let l38 = *base
+                                            .add(4 + 1 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        let l39 = *base
+                                            .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        (
+                                            super::super::super::super::lojban::nesy::ast_types::Quantifier {
+                                                kind: super::super::super::super::lojban::nesy::ast_types::QuantifierKind::_lift(
+                                                    l37 as u8,
+                                                ),
+                                                count: l38 as u32,
+                                            },
+                                            l39 as u32,
+                                        )
+                                    };
+                                    V74::Quantified(e74)
+                                }
+                                4 => {
+                                    let e74 = {
+                                        let l40 = *base
                                             .add(::core::mem::size_of::<*const u8>())
                                             .cast::<*mut u8>();
-                                        let l32 = *base
+                                        let l41 = *base
                                             .add(2 * ::core::mem::size_of::<*const u8>())
                                             .cast::<usize>();
-                                        let len33 = l32;
-                                        let bytes33 = _rt::Vec::from_raw_parts(
-                                            l31.cast(),
-                                            len33,
-                                            len33,
+                                        let len42 = l41;
+                                        let bytes42 = _rt::Vec::from_raw_parts(
+                                            l40.cast(),
+                                            len42,
+                                            len42,
                                         );
-                                        _rt::string_lift(bytes33)
+                                        _rt::string_lift(bytes42)
                                     };
-                                    V42::Name(e42)
+                                    V74::Name(e74)
                                 }
-                                3 => {
-                                    let e42 = {
-                                        let l34 = *base
+                                5 => {
+                                    let e74 = {
+                                        let l43 = *base
                                             .add(::core::mem::size_of::<*const u8>())
                                             .cast::<*mut u8>();
-                                        let l35 = *base
+                                        let l44 = *base
                                             .add(2 * ::core::mem::size_of::<*const u8>())
                                             .cast::<usize>();
-                                        let len36 = l35;
-                                        let bytes36 = _rt::Vec::from_raw_parts(
-                                            l34.cast(),
-                                            len36,
-                                            len36,
+                                        let len45 = l44;
+                                        let bytes45 = _rt::Vec::from_raw_parts(
+                                            l43.cast(),
+                                            len45,
+                                            len45,
                                         );
-                                        _rt::string_lift(bytes36)
+                                        _rt::string_lift(bytes45)
                                     };
-                                    V42::QuotedLiteral(e42)
+                                    V74::QuotedLiteral(e74)
                                 }
-                                4 => V42::Unspecified,
-                                5 => {
-                                    let e42 = {
-                                        let l37 = i32::from(
+                                6 => V74::Unspecified,
+                                7 => {
+                                    let e74 = {
+                                        let l46 = i32::from(
                                             *base.add(::core::mem::size_of::<*const u8>()).cast::<u8>(),
                                         );
-                                        let l38 = *base
+                                        let l47 = *base
                                             .add(4 + 1 * ::core::mem::size_of::<*const u8>())
                                             .cast::<i32>();
                                         (
                                             super::super::super::super::lojban::nesy::ast_types::PlaceTag::_lift(
-                                                l37 as u8,
+                                                l46 as u8,
                                             ),
-                                            l38 as u32,
+                                            l47 as u32,
+                                        )
+                                    };
+                                    V74::Tagged(e74)
+                                }
+                                8 => {
+                                    let e74 = {
+                                        let l48 = i32::from(
+                                            *base.add(::core::mem::size_of::<*const u8>()).cast::<u8>(),
+                                        );
+                                        let l50 = i32::from(
+                                            *base
+                                                .add(2 + 1 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>(),
+                                        );
+                                        let l52 = i32::from(
+                                            *base
+                                                .add(4 + 1 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>(),
+                                        );
+                                        let l54 = *base
+                                            .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        (
+                                            super::super::super::super::lojban::nesy::ast_types::Tense {
+                                                direction: match l48 {
+                                                    0 => None,
+                                                    1 => {
+                                                        let e = {
+                                                            let l49 = i32::from(
+                                                                *base
+                                                                    .add(1 + 1 * ::core::mem::size_of::<*const u8>())
+                                                                    .cast::<u8>(),
+                                                            );
+                                                            super::super::super::super::lojban::nesy::ast_types::TimeDirection::_lift(
+                                                                l49 as u8,
+                                                            )
+                                                        };
+                                                        Some(e)
+                                                    }
+                                                    _ => _rt::invalid_enum_discriminant(),
+                                                },
+                                                distance: match l50 {
+                                                    0 => None,
+                                                    1 => {
+                                                        let e = {
+                                                            let l51 = i32::from(
+                                                                *base
+                                                                    .add(3 + 1 * ::core::mem::size_of::<*const u8>())
+                                                                    .cast::<u8>(),
+                                                            );
+                                                            super::super::super::super::lojban::nesy::ast_types::TimeDistance::_lift(
+                                                                l51 as u8,
+                                                            )
+                                                        };
+                                                        Some(e)
+                                                    }
+                                                    _ => _rt::invalid_enum_discriminant(),
+                                                },
+                                                aspect: match l52 {
+                                                    0 => None,
+                                                    1 => {
+                                                        let e = {
+                                                            let l53 = i32::from(
+                                                                *base
+                                                                    .add(5 + 1 * ::core::mem::size_of::<*const u8>())
+                                                                    .cast::<u8>(),
+                                                            );
+                                                            super::super::super::super::lojban::nesy::ast_types::Aspect::_lift(
+                                                                l53 as u8,
+                                                            )
+                                                        };
+                                                        Some(e)
+                                                    }
+                                                    _ => _rt::invalid_enum_discriminant(),
+                                                },
+                                            },
+                                            l54 as u32,
                                         )
                                     };
-                                    V42::Tagged(e42)
+                                    V74::TenseTagged(e74)
+                                }
+                                9 => {
+                                    let e74 = {
+                                        let l55 = i32::from(
+                                            *base.add(::core::mem::size_of::<*const u8>()).cast::<u8>(),
+                                        );
+                                        use super::super::super::super::lojban::nesy::ast_types::ModalTag as V60;
+                                        let v60 = match l55 {
+                                            0 => {
+                                                let e60 = {
+                                                    let l56 = *base
+                                                        .add(2 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<*mut u8>();
+                                                    let l57 = *base
+                                                        .add(3 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<usize>();
+                                                    let len58 = l57;
+                                                    let bytes58 = _rt::Vec::from_raw_parts(
+                                                        l56.cast(),
+                                                        len58,
+                                                        len58,
+                                                    );
+                                                    _rt::string_lift(bytes58)
+                                                };
+                                                V60::Bai(e60)
+                                            }
+                                            n => {
+                                                debug_assert_eq!(n, 1, "invalid enum discriminant");
+                                                let e60 = {
+                                                    let l59 = *base
+                                                        .add(2 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<i32>();
+                                                    l59 as u32
+                                                };
+                                                V60::Fiho(e60)
+                                            }
+                                        };
+                                        let l61 = *base
+                                            .add(4 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        (v60, l61 as u32)
+                                    };
+                                    V74::ModalTagged(e74)
+                                }
+                                10 => {
+                                    let e74 = {
+                                        let l62 = *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        let l63 = i32::from(
+                                            *base
+                                                .add(4 + 1 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>(),
+                                        );
+                                        let l64 = i32::from(
+                                            *base
+                                                .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>(),
+                                        );
+                                        use super::super::super::super::lojban::nesy::ast_types::RelClauseBody as V67;
+                                        let v67 = match l64 {
+                                            0 => {
+                                                let e67 = {
+                                                    let l65 = *base
+                                                        .add(12 + 1 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<i32>();
+                                                    l65 as u32
+                                                };
+                                                V67::Sentence(e67)
+                                            }
+                                            n => {
+                                                debug_assert_eq!(n, 1, "invalid enum discriminant");
+                                                let e67 = {
+                                                    let l66 = *base
+                                                        .add(12 + 1 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<i32>();
+                                                    l66 as u32
+                                                };
+                                                V67::Sumti(e67)
+                                            }
+                                        };
+                                        (
+                                            l62 as u32,
+                                            super::super::super::super::lojban::nesy::ast_types::RelClause {
+                                                kind: super::super::super::super::lojban::nesy::ast_types::RelClauseKind::_lift(
+                                                    l63 as u8,
+                                                ),
+                                                body: v67,
+                                            },
+                                        )
+                                    };
+                                    V74::Restricted(e74)
                                 }
                                 n => {
-                                    debug_assert_eq!(n, 6, "invalid enum discriminant");
-                                    let e42 = {
-                                        let l39 = *base
+                                    debug_assert_eq!(n, 11, "invalid enum discriminant");
+                                    let e74 = {
+                                        let l68 = *base
                                             .add(::core::mem::size_of::<*const u8>())
                                             .cast::<i32>();
-                                        let l40 = i32::from(
+                                        let l69 = i32::from(
                                             *base
                                                 .add(4 + 1 * ::core::mem::size_of::<*const u8>())
                                                 .cast::<u8>(),
                                         );
-                                        let l41 = *base
+                                        let l70 = i32::from(
+                                            *base
+                                                .add(5 + 1 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>(),
+                                        );
+                                        let l71 = i32::from(
+                                            *base
+                                                .add(6 + 1 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>(),
+                                        );
+                                        let l72 = i32::from(
+                                            *base
+                                                .add(7 + 1 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>(),
+                                        );
+                                        let l73 = *base
                                             .add(8 + 1 * ::core::mem::size_of::<*const u8>())
                                             .cast::<i32>();
                                         (
-                                            l39 as u32,
-                                            super::super::super::super::lojban::nesy::ast_types::RelClause {
-                                                kind: super::super::super::super::lojban::nesy::ast_types::RelClauseKind::_lift(
-                                                    l40 as u8,
+                                            l68 as u32,
+                                            super::super::super::super::lojban::nesy::ast_types::LogicalConnective {
+                                                connective: super::super::super::super::lojban::nesy::ast_types::Connective::_lift(
+                                                    l69 as u8,
                                                 ),
-                                                body_sentence: l41 as u32,
+                                                converted: _rt::bool_lift(l70 as u8),
+                                                negate_left: _rt::bool_lift(l71 as u8),
+                                                negate_right: _rt::bool_lift(l72 as u8),
                                             },
+                                            l73 as u32,
                                         )
                                     };
-                                    V42::Restricted(e42)
+                                    V74::Connected(e74)
                                 }
                             };
-                            v42
+                            v74
                         };
-                        result43.push(e43);
+                        result75.push(e75);
                     }
                     _rt::cabi_dealloc(
-                        base43,
-                        len43 * (8 + 2 * ::core::mem::size_of::<*const u8>()),
+                        base75,
+                        len75 * (8 + 4 * ::core::mem::size_of::<*const u8>()),
                         ::core::mem::size_of::<*const u8>(),
                     );
-                    let base52 = arg4;
-                    let len52 = arg5;
-                    let mut result52 = _rt::Vec::with_capacity(len52);
-                    for i in 0..len52 {
-                        let base = base52
-                            .add(i * (6 * ::core::mem::size_of::<*const u8>()));
-                        let e52 = {
-                            let l44 = *base.add(0).cast::<i32>();
-                            let l45 = *base
+                    let len76 = arg6;
+                    let len77 = arg8;
+                    let base96 = arg9;
+                    let len96 = arg10;
+                    let mut result96 = _rt::Vec::with_capacity(len96);
+                    for i in 0..len96 {
+                        let base = base96
+                            .add(i * (16 + 6 * ::core::mem::size_of::<*const u8>()));
+                        let e96 = {
+                            let l78 = *base.add(0).cast::<i32>();
+                            let l79 = *base
                                 .add(::core::mem::size_of::<*const u8>())
                                 .cast::<*mut u8>();
-                            let l46 = *base
+                            let l80 = *base
                                 .add(2 * ::core::mem::size_of::<*const u8>())
                                 .cast::<usize>();
-                            let len47 = l46;
-                            let l48 = *base
+                            let len81 = l80;
+                            let l82 = *base
                                 .add(3 * ::core::mem::size_of::<*const u8>())
                                 .cast::<*mut u8>();
-                            let l49 = *base
+                            let l83 = *base
                                 .add(4 * ::core::mem::size_of::<*const u8>())
                                 .cast::<usize>();
-                            let len50 = l49;
-                            let l51 = i32::from(
+                            let len84 = l83;
+                            let l85 = i32::from(
                                 *base
                                     .add(5 * ::core::mem::size_of::<*const u8>())
                                     .cast::<u8>(),
                             );
+                            let l86 = i32::from(
+                                *base
+                                    .add(1 + 5 * ::core::mem::size_of::<*const u8>())
+                                    .cast::<u8>(),
+                            );
+                            let l93 = i32::from(
+                                *base
+                                    .add(8 + 5 * ::core::mem::size_of::<*const u8>())
+                                    .cast::<u8>(),
+                            );
+                            let l94 = *base
+                                .add(12 + 5 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>();
+                            let l95 = *base
+                                .add(16 + 5 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>();
                             super::super::super::super::lojban::nesy::ast_types::Bridi {
-                                relation: l44 as u32,
+                                relation: l78 as u32,
                                 head_terms: _rt::Vec::from_raw_parts(
-                                    l45.cast(),
-                                    len47,
-                                    len47,
+                                    l79.cast(),
+                                    len81,
+                                    len81,
                                 ),
                                 tail_terms: _rt::Vec::from_raw_parts(
-                                    l48.cast(),
-                                    len50,
-                                    len50,
+                                    l82.cast(),
+                                    len84,
+                                    len84,
                                 ),
-                                negated: _rt::bool_lift(l51 as u8),
+                                negated: _rt::bool_lift(l85 as u8),
+                                tense: match l86 {
+                                    0 => None,
+                                    1 => {
+                                        let e = {
+                                            let l87 = i32::from(
+                                                *base
+                                                    .add(2 + 5 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<u8>(),
+                                            );
+                                            let l89 = i32::from(
+                                                *base
+                                                    .add(4 + 5 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<u8>(),
+                                            );
+                                            let l91 = i32::from(
+                                                *base
+                                                    .add(6 + 5 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<u8>(),
+                                            );
+                                            super::super::super::super::lojban::nesy::ast_types::Tense {
+                                                direction: match l87 {
+                                                    0 => None,
+                                                    1 => {
+                                                        let e = {
+                                                            let l88 = i32::from(
+                                                                *base
+                                                                    .add(3 + 5 * ::core::mem::size_of::<*const u8>())
+                                                                    .cast::<u8>(),
+                                                            );
+                                                            super::super::super::super::lojban::nesy::ast_types::TimeDirection::_lift(
+                                                                l88 as u8,
+                                                            )
+                                                        };
+                                                        Some(e)
+                                                    }
+                                                    _ => _rt::invalid_enum_discriminant(),
+                                                },
+                                                distance: match l89 {
+                                                    0 => None,
+                                                    1 => {
+                                                        let e = {
+                                                            let l90 = i32::from(
+                                                                *base
+                                                                    .add(5 + 5 * ::core::mem::size_of::<*const u8>())
+                                                                    .cast::<u8>(),
+                                                            );
+                                                            super::super::super::super::lojban::nesy::ast_types::TimeDistance::_lift(
+                                                                l90 as u8,
+                                                            )
+                                                        };
+                                                        Some(e)
+                                                    }
+                                                    _ => _rt::invalid_enum_discriminant(),
+                                                },
+                                                aspect: match l91 {
+                                                    0 => None,
+                                                    1 => {
+                                                        let e = {
+                                                            let l92 = i32::from(
+                                                                *base
+                                                                    .add(7 + 5 * ::core::mem::size_of::<*const u8>())
+                                                                    .cast::<u8>(),
+                                                            );
+                                                            super::super::super::super::lojban::nesy::ast_types::Aspect::_lift(
+                                                                l92 as u8,
+                                                            )
+                                                        };
+                                                        Some(e)
+                                                    }
+                                                    _ => _rt::invalid_enum_discriminant(),
+                                                },
+                                            }
+                                        };
+                                        Some(e)
+                                    }
+                                    _ => _rt::invalid_enum_discriminant(),
+                                },
+                                question: _rt::bool_lift(l93 as u8),
+                                span: super::super::super::super::lojban::nesy::ast_types::Span {
+                                    start: l94 as u32,
+                                    end: l95 as u32,
+                                },
                             }
                         };
-                        result52.push(e52);
+                        result96.push(e96);
+                    }
+                    _rt::cabi_dealloc(
+                        base96,
+                        len96 * (16 + 6 * ::core::mem::size_of::<*const u8>()),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let base116 = arg11;
+                    let len116 = arg12;
+                    let mut result116 = _rt::Vec::with_capacity(len116);
+                    for i in 0..len116 {
+                        let base = base116
+                            .add(i * (4 * ::core::mem::size_of::<*const u8>()));
+                        let e116 = {
+                            let l97 = i32::from(*base.add(0).cast::<u8>());
+                            use super::super::super::super::lojban::nesy::ast_types::Statement as V115;
+                            let v115 = match l97 {
+                                0 => {
+                                    let e115 = {
+                                        let l98 = *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        l98 as u32
+                                    };
+                                    V115::Simple(e115)
+                                }
+                                1 => {
+                                    let e115 = {
+                                        let l99 = i32::from(
+                                            *base.add(::core::mem::size_of::<*const u8>()).cast::<u8>(),
+                                        );
+                                        let l100 = i32::from(
+                                            *base
+                                                .add(1 + 1 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>(),
+                                        );
+                                        let l101 = i32::from(
+                                            *base
+                                                .add(2 + 1 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>(),
+                                        );
+                                        let l102 = i32::from(
+                                            *base
+                                                .add(3 + 1 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>(),
+                                        );
+                                        let l103 = *base
+                                            .add(4 + 1 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        let l104 = *base
+                                            .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        (
+                                            super::super::super::super::lojban::nesy::ast_types::LogicalConnective {
+                                                connective: super::super::super::super::lojban::nesy::ast_types::Connective::_lift(
+                                                    l99 as u8,
+                                                ),
+                                                converted: _rt::bool_lift(l100 as u8),
+                                                negate_left: _rt::bool_lift(l101 as u8),
+                                                negate_right: _rt::bool_lift(l102 as u8),
+                                            },
+                                            l103 as u32,
+                                            l104 as u32,
+                                        )
+                                    };
+                                    V115::Connected(e115)
+                                }
+                                n => {
+                                    debug_assert_eq!(n, 2, "invalid enum discriminant");
+                                    let e115 = {
+                                        let l105 = *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<*mut u8>();
+                                        let l106 = *base
+                                            .add(2 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<usize>();
+                                        let base113 = l105;
+                                        let len113 = l106;
+                                        let mut result113 = _rt::Vec::with_capacity(len113);
+                                        for i in 0..len113 {
+                                            let base = base113.add(i * 20);
+                                            let e113 = {
+                                                let l107 = i32::from(*base.add(0).cast::<u8>());
+                                                use super::super::super::super::lojban::nesy::ast_types::PrenexTerm as V112;
+                                                let v112 = match l107 {
+                                                    0 => {
+                                                        let e112 = {
+                                                            let l108 = i32::from(*base.add(4).cast::<u8>());
+                                                            let l111 = *base.add(16).cast::<i32>();
+                                                            (
+                                                                match l108 {
+                                                                    0 => None,
+                                                                    1 => {
+                                                                        let e = {
+                                                                            let l109 = i32::from(*base.add(8).cast::<u8>());
+                                                                            let l110 = *base.add(12).cast::<i32>();
+                                                                            super::super::super::super::lojban::nesy::ast_types::Quantifier {
+                                                                                kind: super::super::super::super::lojban::nesy::ast_types::QuantifierKind::_lift(
+                                                                                    l109 as u8,
+                                                                                ),
+                                                                                count: l110 as u32,
+                                                                            }
+                                                                        };
+                                                                        Some(e)
+                                                                    }
+                                                                    _ => _rt::invalid_enum_discriminant(),
+                                                                },
+                                                                l111 as u32,
+                                                            )
+                                                        };
+                                                        V112::Variable(e112)
+                                                    }
+                                                    n => {
+                                                        debug_assert_eq!(n, 1, "invalid enum discriminant");
+                                                        V112::Naku
+                                                    }
+                                                };
+                                                v112
+                                            };
+                                            result113.push(e113);
+                                        }
+                                        _rt::cabi_dealloc(base113, len113 * 20, 4);
+                                        let l114 = *base
+                                            .add(3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        (result113, l114 as u32)
+                                    };
+                                    V115::Prenex(e115)
+                                }
+                            };
+                            v115
+                        };
+                        result116.push(e116);
                     }
                     _rt::cabi_dealloc(
-                        base52,
-                        len52 * (6 * ::core::mem::size_of::<*const u8>()),
+                        base116,
+                        len116 * (4 * ::core::mem::size_of::<*const u8>()),
                         ::core::mem::size_of::<*const u8>(),
                     );
-                    let len53 = arg7;
-                    let result54 = T::compile_buffer(super::super::super::super::lojban::nesy::ast_types::AstBuffer {
-                        selbris: result24,
-                        sumtis: result43,
-                        sentences: result52,
-                        roots: _rt::Vec::from_raw_parts(arg6.cast(), len53, len53),
-                    });
-                    let ptr55 = (&raw mut _RET_AREA.0).cast::<u8>();
-                    match result54 {
+                    let len117 = arg14;
+                    let result118 = T::compile_buffer(
+                        unsafe { CompilerBorrow::lift(arg0 as u32 as usize) }.get(),
+                        super::super::super::super::lojban::nesy::ast_types::AstBuffer {
+                            selbris: result26,
+                            sumtis: result75,
+                            selbri_spans: _rt::Vec::from_raw_parts(
+                                arg5.cast(),
+                                len76,
+                                len76,
+                            ),
+                            sumti_spans: _rt::Vec::from_raw_parts(
+                                arg7.cast(),
+                                len77,
+                                len77,
+                            ),
+                            sentences: result96,
+                            statements: result116,
+                            roots: _rt::Vec::from_raw_parts(arg13.cast(), len117, len117),
+                        },
+                    );
+                    let ptr119 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    match result118 {
                         Ok(e) => {
-                            *ptr55.add(0).cast::<u8>() = (0i32) as u8;
+                            *ptr119.add(0).cast::<u8>() = (0i32) as u8;
                             let super::super::super::super::lojban::nesy::ast_types::LogicBuffer {
-                                nodes: nodes56,
-                                roots: roots56,
+                                nodes: nodes120,
+                                roots: roots120,
                             } = e;
-                            let vec71 = nodes56;
-                            let len71 = vec71.len();
-                            let layout71 = _rt::alloc::Layout::from_size_align_unchecked(
-                                vec71.len() * (5 * ::core::mem::size_of::<*const u8>()),
+                            let vec143 = nodes120;
+                            let len143 = vec143.len();
+                            let layout143 = _rt::alloc::Layout::from_size_align_unchecked(
+                                vec143.len() * (5 * ::core::mem::size_of::<*const u8>()),
                                 ::core::mem::size_of::<*const u8>(),
                             );
-                            let result71 = if layout71.size() != 0 {
-                                let ptr = _rt::alloc::alloc(layout71).cast::<u8>();
+                            let result143 = if layout143.size() != 0 {
+                                let ptr = _rt::alloc::alloc(layout143).cast::<u8>();
                                 if ptr.is_null() {
-                                    _rt::alloc::handle_alloc_error(layout71);
+                                    _rt::alloc::handle_alloc_error(layout143);
                                 }
                                 ptr
                             } else {
                                 ::core::ptr::null_mut()
                             };
-                            for (i, e) in vec71.into_iter().enumerate() {
-                                let base = result71
+                            for (i, e) in vec143.into_iter().enumerate() {
+                                let base = result143
                                     .add(i * (5 * ::core::mem::size_of::<*const u8>()));
                                 {
-                                    use super::super::super::super::lojban::nesy::ast_types::LogicNode as V70;
+                                    use super::super::super::super::lojban::nesy::ast_types::LogicNode as V142;
                                     match e {
-                                        V70::Predicate(e) => {
+                                        V142::Predicate(e) => {
                                             *base.add(0).cast::<u8>() = (0i32) as u8;
-                                            let (t57_0, t57_1) = e;
-                                            let vec58 = (t57_0.into_bytes()).into_boxed_slice();
-                                            let ptr58 = vec58.as_ptr().cast::<u8>();
-                                            let len58 = vec58.len();
-                                            ::core::mem::forget(vec58);
+                                            let (t121_0, t121_1) = e;
+                                            let vec122 = (t121_0.into_bytes()).into_boxed_slice();
+                                            let ptr122 = vec122.as_ptr().cast::<u8>();
+                                            let len122 = vec122.len();
+                                            ::core::mem::forget(vec122);
                                             *base
                                                 .add(2 * ::core::mem::size_of::<*const u8>())
-                                                .cast::<usize>() = len58;
+                                                .cast::<usize>() = len122;
                                             *base
                                                 .add(::core::mem::size_of::<*const u8>())
-                                                .cast::<*mut u8>() = ptr58.cast_mut();
-                                            let vec63 = t57_1;
-                                            let len63 = vec63.len();
-                                            let layout63 = _rt::alloc::Layout::from_size_align_unchecked(
-                                                vec63.len() * (3 * ::core::mem::size_of::<*const u8>()),
+                                                .cast::<*mut u8>() = ptr122.cast_mut();
+                                            let vec127 = t121_1;
+                                            let len127 = vec127.len();
+                                            let layout127 = _rt::alloc::Layout::from_size_align_unchecked(
+                                                vec127.len() * (3 * ::core::mem::size_of::<*const u8>()),
                                                 ::core::mem::size_of::<*const u8>(),
                                             );
-                                            let result63 = if layout63.size() != 0 {
-                                                let ptr = _rt::alloc::alloc(layout63).cast::<u8>();
+                                            let result127 = if layout127.size() != 0 {
+                                                let ptr = _rt::alloc::alloc(layout127).cast::<u8>();
                                                 if ptr.is_null() {
-                                                    _rt::alloc::handle_alloc_error(layout63);
+                                                    _rt::alloc::handle_alloc_error(layout127);
                                                 }
                                                 ptr
                                             } else {
                                                 ::core::ptr::null_mut()
                                             };
-                                            for (i, e) in vec63.into_iter().enumerate() {
-                                                let base = result63
+                                            for (i, e) in vec127.into_iter().enumerate() {
+                                                let base = result127
                                                     .add(i * (3 * ::core::mem::size_of::<*const u8>()));
                                                 {
-                                                    use super::super::super::super::lojban::nesy::ast_types::LogicalTerm as V62;
+                                                    use super::super::super::super::lojban::nesy::ast_types::LogicalTerm as V126;
                                                     match e {
-                                                        V62::Variable(e) => {
+                                                        V126::Variable(e) => {
                                                             *base.add(0).cast::<u8>() = (0i32) as u8;
-                                                            let vec59 = (e.into_bytes()).into_boxed_slice();
-                                                            let ptr59 = vec59.as_ptr().cast::<u8>();
-                                                            let len59 = vec59.len();
-                                                            ::core::mem::forget(vec59);
+                                                            let vec123 = (e.into_bytes()).into_boxed_slice();
+                                                            let ptr123 = vec123.as_ptr().cast::<u8>();
+                                                            let len123 = vec123.len();
+                                                            ::core::mem::forget(vec123);
                                                             *base
                                                                 .add(2 * ::core::mem::size_of::<*const u8>())
-                                                                .cast::<usize>() = len59;
+                                                                .cast::<usize>() = len123;
                                                             *base
                                                                 .add(::core::mem::size_of::<*const u8>())
-                                                                .cast::<*mut u8>() = ptr59.cast_mut();
+                                                                .cast::<*mut u8>() = ptr123.cast_mut();
                                                         }
-                                                        V62::Constant(e) => {
+                                                        V126::Constant(e) => {
                                                             *base.add(0).cast::<u8>() = (1i32) as u8;
-                                                            let vec60 = (e.into_bytes()).into_boxed_slice();
-                                                            let ptr60 = vec60.as_ptr().cast::<u8>();
-                                                            let len60 = vec60.len();
-                                                            ::core::mem::forget(vec60);
+                                                            let vec124 = (e.into_bytes()).into_boxed_slice();
+                                                            let ptr124 = vec124.as_ptr().cast::<u8>();
+                                                            let len124 = vec124.len();
+                                                            ::core::mem::forget(vec124);
                                                             *base
                                                                 .add(2 * ::core::mem::size_of::<*const u8>())
-                                                                .cast::<usize>() = len60;
+                                                                .cast::<usize>() = len124;
                                                             *base
                                                                 .add(::core::mem::size_of::<*const u8>())
-                                                                .cast::<*mut u8>() = ptr60.cast_mut();
+                                                                .cast::<*mut u8>() = ptr124.cast_mut();
                                                         }
-                                                        V62::Description(e) => {
+                                                        V126::Description(e) => {
                                                             *base.add(0).cast::<u8>() = (2i32) as u8;
-                                                            let vec61 = (e.into_bytes()).into_boxed_slice();
-                                                            let ptr61 = vec61.as_ptr().cast::<u8>();
-                                                            let len61 = vec61.len();
-                                                            ::core::mem::forget(vec61);
+                                                            let vec125 = (e.into_bytes()).into_boxed_slice();
+                                                            let ptr125 = vec125.as_ptr().cast::<u8>();
+                                                            let len125 = vec125.len();
+                                                            ::core::mem::forget(vec125);
                                                             *base
                                                                 .add(2 * ::core::mem::size_of::<*const u8>())
-                                                                .cast::<usize>() = len61;
+                                                                .cast::<usize>() = len125;
                                                             *base
                                                                 .add(::core::mem::size_of::<*const u8>())
-                                                                .cast::<*mut u8>() = ptr61.cast_mut();
+                                                                .cast::<*mut u8>() = ptr125.cast_mut();
                                                         }
-                                                        V62::Unspecified => {
+                                                        V126::Unspecified => {
                                                             *base.add(0).cast::<u8>() = (3i32) as u8;
                                                         }
+                                                        V126::Proposition(e) => {
+                                                            *base.add(0).cast::<u8>() = (4i32) as u8;
+                                                            *base
+                                                                .add(::core::mem::size_of::<*const u8>())
+                                                                .cast::<i32>() = _rt::as_i32(e);
+                                                        }
                                                     }
                                                 }
                                             }
                                             *base
                                                 .add(4 * ::core::mem::size_of::<*const u8>())
-                                                .cast::<usize>() = len63;
+                                                .cast::<usize>() = len127;
                                             *base
                                                 .add(3 * ::core::mem::size_of::<*const u8>())
-                                                .cast::<*mut u8>() = result63;
+                                                .cast::<*mut u8>() = result127;
                                         }
-                                        V70::AndNode(e) => {
+                                        V142::AndNode(e) => {
                                             *base.add(0).cast::<u8>() = (1i32) as u8;
-                                            let (t64_0, t64_1) = e;
+                                            let (t128_0, t128_1) = e;
                                             *base
                                                 .add(::core::mem::size_of::<*const u8>())
-                                                .cast::<i32>() = _rt::as_i32(t64_0);
+                                                .cast::<i32>() = _rt::as_i32(t128_0);
                                             *base
                                                 .add(4 + 1 * ::core::mem::size_of::<*const u8>())
-                                                .cast::<i32>() = _rt::as_i32(t64_1);
+                                                .cast::<i32>() = _rt::as_i32(t128_1);
                                         }
-                                        V70::OrNode(e) => {
+                                        V142::OrNode(e) => {
                                             *base.add(0).cast::<u8>() = (2i32) as u8;
-                                            let (t65_0, t65_1) = e;
+                                            let (t129_0, t129_1) = e;
                                             *base
                                                 .add(::core::mem::size_of::<*const u8>())
-                                                .cast::<i32>() = _rt::as_i32(t65_0);
+                                                .cast::<i32>() = _rt::as_i32(t129_0);
                                             *base
                                                 .add(4 + 1 * ::core::mem::size_of::<*const u8>())
-                                                .cast::<i32>() = _rt::as_i32(t65_1);
+                                                .cast::<i32>() = _rt::as_i32(t129_1);
                                         }
-                                        V70::NotNode(e) => {
+                                        V142::ImpliesNode(e) => {
                                             *base.add(0).cast::<u8>() = (3i32) as u8;
+                                            let (t130_0, t130_1) = e;
                                             *base
                                                 .add(::core::mem::size_of::<*const u8>())
-                                                .cast::<i32>() = _rt::as_i32(e);
+                                                .cast::<i32>() = _rt::as_i32(t130_0);
+                                            *base
+                                                .add(4 + 1 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>() = _rt::as_i32(t130_1);
                                         }
-                                        V70::ExistsNode(e) => {
+                                        V142::NotNode(e) => {
                                             *base.add(0).cast::<u8>() = (4i32) as u8;
-                                            let (t66_0, t66_1) = e;
-                                            let vec67 = (t66_0.into_bytes()).into_boxed_slice();
-                                            let ptr67 = vec67.as_ptr().cast::<u8>();
-                                            let len67 = vec67.len();
-                                            ::core::mem::forget(vec67);
+                                            *base
+                                                .add(::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        V142::ExistsNode(e) => {
+                                            *base.add(0).cast::<u8>() = (5i32) as u8;
+                                            let (t131_0, t131_1) = e;
+                                            let vec132 = (t131_0.into_bytes()).into_boxed_slice();
+                                            let ptr132 = vec132.as_ptr().cast::<u8>();
+                                            let len132 = vec132.len();
+                                            ::core::mem::forget(vec132);
                                             *base
                                                 .add(2 * ::core::mem::size_of::<*const u8>())
-                                                .cast::<usize>() = len67;
+                                                .cast::<usize>() = len132;
                                             *base
                                                 .add(::core::mem::size_of::<*const u8>())
-                                                .cast::<*mut u8>() = ptr67.cast_mut();
+                                                .cast::<*mut u8>() = ptr132.cast_mut();
                                             *base
                                                 .add(3 * ::core::mem::size_of::<*const u8>())
-                                                .cast::<i32>() = _rt::as_i32(t66_1);
+                                                .cast::<i32>() = _rt::as_i32(t131_1);
                                         }
-                                        V70::ForAllNode(e) => {
-                                            *base.add(0).cast::<u8>() = (5i32) as u8;
-                                            let (t68_0, t68_1) = e;
-                                            let vec69 = (t68_0.into_bytes()).into_boxed_slice();
-                                            let ptr69 = vec69.as_ptr().cast::<u8>();
-                                            let len69 = vec69.len();
-                                            ::core::mem::forget(vec69);
+                                        V142::ForAllNode(e) => {
+                                            *base.add(0).cast::<u8>() = (6i32) as u8;
+                                            let (t133_0, t133_1) = e;
+                                            let vec134 = (t133_0.into_bytes()).into_boxed_slice();
+                                            let ptr134 = vec134.as_ptr().cast::<u8>();
+                                            let len134 = vec134.len();
+                                            ::core::mem::forget(vec134);
+                                            *base
+                                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>() = len134;
+                                            *base
+                                                .add(::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>() = ptr134.cast_mut();
+                                            *base
+                                                .add(3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>() = _rt::as_i32(t133_1);
+                                        }
+                                        V142::AtNode(e) => {
+                                            *base.add(0).cast::<u8>() = (7i32) as u8;
+                                            let (t135_0, t135_1) = e;
+                                            use super::super::super::super::lojban::nesy::ast_types::LogicalTerm as V139;
+                                            match t135_0 {
+                                                V139::Variable(e) => {
+                                                    *base
+                                                        .add(::core::mem::size_of::<*const u8>())
+                                                        .cast::<u8>() = (0i32) as u8;
+                                                    let vec136 = (e.into_bytes()).into_boxed_slice();
+                                                    let ptr136 = vec136.as_ptr().cast::<u8>();
+                                                    let len136 = vec136.len();
+                                                    ::core::mem::forget(vec136);
+                                                    *base
+                                                        .add(3 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<usize>() = len136;
+                                                    *base
+                                                        .add(2 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<*mut u8>() = ptr136.cast_mut();
+                                                }
+                                                V139::Constant(e) => {
+                                                    *base
+                                                        .add(::core::mem::size_of::<*const u8>())
+                                                        .cast::<u8>() = (1i32) as u8;
+                                                    let vec137 = (e.into_bytes()).into_boxed_slice();
+                                                    let ptr137 = vec137.as_ptr().cast::<u8>();
+                                                    let len137 = vec137.len();
+                                                    ::core::mem::forget(vec137);
+                                                    *base
+                                                        .add(3 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<usize>() = len137;
+                                                    *base
+                                                        .add(2 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<*mut u8>() = ptr137.cast_mut();
+                                                }
+                                                V139::Description(e) => {
+                                                    *base
+                                                        .add(::core::mem::size_of::<*const u8>())
+                                                        .cast::<u8>() = (2i32) as u8;
+                                                    let vec138 = (e.into_bytes()).into_boxed_slice();
+                                                    let ptr138 = vec138.as_ptr().cast::<u8>();
+                                                    let len138 = vec138.len();
+                                                    ::core::mem::forget(vec138);
+                                                    *base
+                                                        .add(3 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<usize>() = len138;
+                                                    *base
+                                                        .add(2 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<*mut u8>() = ptr138.cast_mut();
+                                                }
+                                                V139::Unspecified => {
+                                                    *base
+                                                        .add(::core::mem::size_of::<*const u8>())
+                                                        .cast::<u8>() = (3i32) as u8;
+                                                }
+                                                V139::Proposition(e) => {
+                                                    *base
+                                                        .add(::core::mem::size_of::<*const u8>())
+                                                        .cast::<u8>() = (4i32) as u8;
+                                                    *base
+                                                        .add(2 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<i32>() = _rt::as_i32(e);
+                                                }
+                                            }
+                                            *base
+                                                .add(4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>() = _rt::as_i32(t135_1);
+                                        }
+                                        V142::GenericNode(e) => {
+                                            *base.add(0).cast::<u8>() = (8i32) as u8;
+                                            let (t140_0, t140_1, t140_2) = e;
+                                            let vec141 = (t140_0.into_bytes()).into_boxed_slice();
+                                            let ptr141 = vec141.as_ptr().cast::<u8>();
+                                            let len141 = vec141.len();
+                                            ::core::mem::forget(vec141);
                                             *base
                                                 .add(2 * ::core::mem::size_of::<*const u8>())
-                                                .cast::<usize>() = len69;
+                                                .cast::<usize>() = len141;
                                             *base
                                                 .add(::core::mem::size_of::<*const u8>())
-                                                .cast::<*mut u8>() = ptr69.cast_mut();
+                                                .cast::<*mut u8>() = ptr141.cast_mut();
                                             *base
                                                 .add(3 * ::core::mem::size_of::<*const u8>())
-                                                .cast::<i32>() = _rt::as_i32(t68_1);
+                                                .cast::<i32>() = _rt::as_i32(t140_1);
+                                            *base
+                                                .add(4 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>() = _rt::as_i32(t140_2);
                                         }
                                     }
                                 }
                             }
-                            *ptr55
+                            *ptr119
                                 .add(2 * ::core::mem::size_of::<*const u8>())
-                                .cast::<usize>() = len71;
-                            *ptr55
+                                .cast::<usize>() = len143;
+                            *ptr119
                                 .add(::core::mem::size_of::<*const u8>())
-                                .cast::<*mut u8>() = result71;
-                            let vec72 = (roots56).into_boxed_slice();
-                            let ptr72 = vec72.as_ptr().cast::<u8>();
-                            let len72 = vec72.len();
-                            ::core::mem::forget(vec72);
-                            *ptr55
+                                .cast::<*mut u8>() = result143;
+                            let vec144 = (roots120).into_boxed_slice();
+                            let ptr144 = vec144.as_ptr().cast::<u8>();
+                            let len144 = vec144.len();
+                            ::core::mem::forget(vec144);
+                            *ptr119
                                 .add(4 * ::core::mem::size_of::<*const u8>())
-                                .cast::<usize>() = len72;
-                            *ptr55
+                                .cast::<usize>() = len144;
+                            *ptr119
                                 .add(3 * ::core::mem::size_of::<*const u8>())
-                                .cast::<*mut u8>() = ptr72.cast_mut();
+                                .cast::<*mut u8>() = ptr144.cast_mut();
                         }
                         Err(e) => {
-                            *ptr55.add(0).cast::<u8>() = (1i32) as u8;
-                            let vec73 = (e.into_bytes()).into_boxed_slice();
-                            let ptr73 = vec73.as_ptr().cast::<u8>();
-                            let len73 = vec73.len();
-                            ::core::mem::forget(vec73);
-                            *ptr55
+                            *ptr119.add(0).cast::<u8>() = (1i32) as u8;
+                            let vec145 = (e.into_bytes()).into_boxed_slice();
+                            let ptr145 = vec145.as_ptr().cast::<u8>();
+                            let len145 = vec145.len();
+                            ::core::mem::forget(vec145);
+                            *ptr119
                                 .add(2 * ::core::mem::size_of::<*const u8>())
-                                .cast::<usize>() = len73;
-                            *ptr55
+                                .cast::<usize>() = len145;
+                            *ptr119
                                 .add(::core::mem::size_of::<*const u8>())
-                                .cast::<*mut u8>() = ptr73.cast_mut();
+                                .cast::<*mut u8>() = ptr145.cast_mut();
                         }
                     };
-                    ptr55
+                    ptr119
                 }
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
-                pub unsafe fn __post_return_compile_buffer<T: Guest>(arg0: *mut u8) {
+                pub unsafe fn __post_return_method_compiler_compile_buffer<
+                    T: GuestCompiler,
+                >(arg0: *mut u8) {
                     let l0 = i32::from(*arg0.add(0).cast::<u8>());
                     match l0 {
                         0 => {
@@ -1028,10 +2247,10 @@ pub mod exports {
                             let l2 = *arg0
                                 .add(2 * ::core::mem::size_of::<*const u8>())
                                 .cast::<usize>();
-                            let base20 = l1;
-                            let len20 = l2;
-                            for i in 0..len20 {
-                                let base = base20
+                            let base29 = l1;
+                            let len29 = l2;
+                            for i in 0..len29 {
+                                let base = base29
                                     .add(i * (5 * ::core::mem::size_of::<*const u8>()));
                                 {
                                     let l3 = i32::from(*base.add(0).cast::<u8>());
@@ -1085,6 +2304,7 @@ pub mod exports {
                                                                 .cast::<usize>();
                                                             _rt::cabi_dealloc(l13, l14, 1);
                                                         }
+                                                        3 => {}
                                                         _ => {}
                                                     }
                                                 }
@@ -1098,7 +2318,8 @@ pub mod exports {
                                         1 => {}
                                         2 => {}
                                         3 => {}
-                                        4 => {
+                                        4 => {}
+                                        5 => {
                                             let l16 = *base
                                                 .add(::core::mem::size_of::<*const u8>())
                                                 .cast::<*mut u8>();
@@ -1107,7 +2328,7 @@ pub mod exports {
                                                 .cast::<usize>();
                                             _rt::cabi_dealloc(l16, l17, 1);
                                         }
-                                        _ => {
+                                        6 => {
                                             let l18 = *base
                                                 .add(::core::mem::size_of::<*const u8>())
                                                 .cast::<*mut u8>();
@@ -1116,54 +2337,202 @@ pub mod exports {
                                                 .cast::<usize>();
                                             _rt::cabi_dealloc(l18, l19, 1);
                                         }
+                                        7 => {
+                                            let l20 = i32::from(
+                                                *base.add(::core::mem::size_of::<*const u8>()).cast::<u8>(),
+                                            );
+                                            match l20 {
+                                                0 => {
+                                                    let l21 = *base
+                                                        .add(2 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<*mut u8>();
+                                                    let l22 = *base
+                                                        .add(3 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<usize>();
+                                                    _rt::cabi_dealloc(l21, l22, 1);
+                                                }
+                                                1 => {
+                                                    let l23 = *base
+                                                        .add(2 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<*mut u8>();
+                                                    let l24 = *base
+                                                        .add(3 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<usize>();
+                                                    _rt::cabi_dealloc(l23, l24, 1);
+                                                }
+                                                2 => {
+                                                    let l25 = *base
+                                                        .add(2 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<*mut u8>();
+                                                    let l26 = *base
+                                                        .add(3 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<usize>();
+                                                    _rt::cabi_dealloc(l25, l26, 1);
+                                                }
+                                                3 => {}
+                                                _ => {}
+                                            }
+                                        }
+                                        _ => {
+                                            let l27 = *base
+                                                .add(::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>();
+                                            let l28 = *base
+                                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>();
+                                            _rt::cabi_dealloc(l27, l28, 1);
+                                        }
                                     }
                                 }
                             }
                             _rt::cabi_dealloc(
-                                base20,
-                                len20 * (5 * ::core::mem::size_of::<*const u8>()),
+                                base29,
+                                len29 * (5 * ::core::mem::size_of::<*const u8>()),
                                 ::core::mem::size_of::<*const u8>(),
                             );
-                            let l21 = *arg0
+                            let l30 = *arg0
                                 .add(3 * ::core::mem::size_of::<*const u8>())
                                 .cast::<*mut u8>();
-                            let l22 = *arg0
+                            let l31 = *arg0
                                 .add(4 * ::core::mem::size_of::<*const u8>())
                                 .cast::<usize>();
-                            let base23 = l21;
-                            let len23 = l22;
-                            _rt::cabi_dealloc(base23, len23 * 4, 4);
+                            let base32 = l30;
+                            let len32 = l31;
+                            _rt::cabi_dealloc(base32, len32 * 4, 4);
                         }
                         _ => {
-                            let l24 = *arg0
+                            let l33 = *arg0
                                 .add(::core::mem::size_of::<*const u8>())
                                 .cast::<*mut u8>();
-                            let l25 = *arg0
+                            let l34 = *arg0
                                 .add(2 * ::core::mem::size_of::<*const u8>())
                                 .cast::<usize>();
-                            _rt::cabi_dealloc(l24, l25, 1);
+                            _rt::cabi_dealloc(l33, l34, 1);
                         }
                     }
                 }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_compiler_reset_cabi<T: GuestCompiler>(
+                    arg0: *mut u8,
+                ) {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    T::reset(
+                        unsafe { CompilerBorrow::lift(arg0 as u32 as usize) }.get(),
+                    );
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_compiler_fork_cabi<T: GuestCompiler>(
+                    arg0: *mut u8,
+                ) -> i32 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let result0 = T::fork(
+                        unsafe { CompilerBorrow::lift(arg0 as u32 as usize) }.get(),
+                    );
+                    (result0).take_handle() as i32
+                }
                 pub trait Guest {
+                    type Compiler: GuestCompiler;
+                }
+                pub trait GuestCompiler: 'static {
+                    #[doc(hidden)]
+                    unsafe fn _resource_new(val: *mut u8) -> u32
+                    where
+                        Self: Sized,
+                    {
+                        #[cfg(not(target_arch = "wasm32"))]
+                        {
+                            let _ = val;
+                            unreachable!();
+                        }
+                        #[cfg(target_arch = "wasm32")]
+                        {
+                            #[link(
+                                wasm_import_module = "[export]lojban:nesy/semantics@0.1.0"
+                            )]
+                            unsafe extern "C" {
+                                #[link_name = "[resource-new]compiler"]
+                                fn new(_: *mut u8) -> u32;
+                            }
+                            unsafe { new(val) }
+                        }
+                    }
+                    #[doc(hidden)]
+                    fn _resource_rep(handle: u32) -> *mut u8
+                    where
+                        Self: Sized,
+                    {
+                        #[cfg(not(target_arch = "wasm32"))]
+                        {
+                            let _ = handle;
+                            unreachable!();
+                        }
+                        #[cfg(target_arch = "wasm32")]
+                        {
+                            #[link(
+                                wasm_import_module = "[export]lojban:nesy/semantics@0.1.0"
+                            )]
+                            unsafe extern "C" {
+                                #[link_name = "[resource-rep]compiler"]
+                                fn rep(_: u32) -> *mut u8;
+                            }
+                            unsafe { rep(handle) }
+                        }
+                    }
+                    /// A compiler with nothing said yet.
+                    fn new() -> Self;
                     fn compile_buffer(
+                        &self,
                         ast: AstBuffer,
                     ) -> Result<LogicBuffer, _rt::String>;
+                    /// Forget the discourse.
+                    fn reset(&self) -> ();
+                    /// A copy of the compiler whose discourse goes on independently.
+                    fn fork(&self) -> Compiler;
                 }
                 #[doc(hidden)]
                 macro_rules! __export_lojban_nesy_semantics_0_1_0_cabi {
                     ($ty:ident with_types_in $($path_to_types:tt)*) => {
                         const _ : () = { #[unsafe (export_name =
-                        "lojban:nesy/semantics@0.1.0#compile-buffer")] unsafe extern "C"
-                        fn export_compile_buffer(arg0 : * mut u8, arg1 : usize, arg2 : *
-                        mut u8, arg3 : usize, arg4 : * mut u8, arg5 : usize, arg6 : * mut
-                        u8, arg7 : usize,) -> * mut u8 { unsafe { $($path_to_types)*::
-                        _export_compile_buffer_cabi::<$ty > (arg0, arg1, arg2, arg3,
-                        arg4, arg5, arg6, arg7) } } #[unsafe (export_name =
-                        "cabi_post_lojban:nesy/semantics@0.1.0#compile-buffer")] unsafe
-                        extern "C" fn _post_return_compile_buffer(arg0 : * mut u8,) {
-                        unsafe { $($path_to_types)*:: __post_return_compile_buffer::<$ty
-                        > (arg0) } } };
+                        "lojban:nesy/semantics@0.1.0#[constructor]compiler")] unsafe
+                        extern "C" fn export_constructor_compiler() -> i32 { unsafe {
+                        $($path_to_types)*:: _export_constructor_compiler_cabi::<<$ty as
+                        $($path_to_types)*:: Guest >::Compiler > () } } #[unsafe
+                        (export_name =
+                        "lojban:nesy/semantics@0.1.0#[method]compiler.compile-buffer")]
+                        unsafe extern "C" fn export_method_compiler_compile_buffer(arg0 :
+                        * mut u8, arg1 : * mut u8, arg2 : usize, arg3 : * mut u8, arg4 :
+                        usize, arg5 : * mut u8, arg6 : usize, arg7 : * mut u8, arg8 :
+                        usize, arg9 : * mut u8, arg10 : usize, arg11 : * mut u8, arg12 :
+                        usize, arg13 : * mut u8, arg14 : usize,) -> * mut u8 { unsafe {
+                        $($path_to_types)*::
+                        _export_method_compiler_compile_buffer_cabi::<<$ty as
+                        $($path_to_types)*:: Guest >::Compiler > (arg0, arg1, arg2, arg3,
+                        arg4, arg5, arg6, arg7, arg8, arg9, arg10, arg11, arg12, arg13,
+                        arg14) } } #[unsafe (export_name =
+                        "cabi_post_lojban:nesy/semantics@0.1.0#[method]compiler.compile-buffer")]
+                        unsafe extern "C" fn
+                        _post_return_method_compiler_compile_buffer(arg0 : * mut u8,) {
+                        unsafe { $($path_to_types)*::
+                        __post_return_method_compiler_compile_buffer::<<$ty as
+                        $($path_to_types)*:: Guest >::Compiler > (arg0) } } #[unsafe
+                        (export_name =
+                        "lojban:nesy/semantics@0.1.0#[method]compiler.reset")] unsafe
+                        extern "C" fn export_method_compiler_reset(arg0 : * mut u8,) {
+                        unsafe { $($path_to_types)*::
+                        _export_method_compiler_reset_cabi::<<$ty as $($path_to_types)*::
+                        Guest >::Compiler > (arg0) } } #[unsafe (export_name =
+                        "lojban:nesy/semantics@0.1.0#[method]compiler.fork")] unsafe
+                        extern "C" fn export_method_compiler_fork(arg0 : * mut u8,) ->
+                        i32 { unsafe { $($path_to_types)*::
+                        _export_method_compiler_fork_cabi::<<$ty as $($path_to_types)*::
+                        Guest >::Compiler > (arg0) } } const _ : () = { #[doc(hidden)]
+                        #[unsafe (export_name =
+                        "lojban:nesy/semantics@0.1.0#[dtor]compiler")]
+                        #[allow(non_snake_case)] unsafe extern "C" fn dtor(rep : * mut
+                        u8) { unsafe { $($path_to_types)*:: Compiler::dtor::< <$ty as
+                        $($path_to_types)*:: Guest >::Compiler > (rep) } } }; };
                     };
                 }
                 #[doc(hidden)]
@@ -1188,6 +2557,81 @@ mod _rt {
     #![allow(dead_code, clippy::all)]
     pub use alloc_crate::string::String;
     pub use alloc_crate::vec::Vec;
+    use core::fmt;
+    use core::marker;
+    use core::sync::atomic::{AtomicU32, Ordering::Relaxed};
+    /// A type which represents a component model resource, either imported or
+    /// exported into this component.
+    ///
+    /// This is a low-level wrapper which handles the lifetime of the resource
+    /// (namely this has a destructor). The `T` provided defines the component model
+    /// intrinsics that this wrapper uses.
+    ///
+    /// One of the chief purposes of this type is to provide `Deref` implementations
+    /// to access the underlying data when it is owned.
+    ///
+    /// This type is primarily used in generated code for exported and imported
+    /// resources.
+    #[repr(transparent)]
+    pub struct Resource<T: WasmResource> {
+        handle: AtomicU32,
+        _marker: marker::PhantomData<T>,
+    }
+    /// A trait which all wasm resources implement, namely providing the ability to
+    /// drop a resource.
+    ///
+    /// This generally is implemented by generated code, not user-facing code.
+    #[allow(clippy::missing_safety_doc)]
+    pub unsafe trait WasmResource {
+        /// Invokes the `[resource-drop]...` intrinsic.
+        unsafe fn drop(handle: u32);
+    }
+    impl<T: WasmResource> Resource<T> {
+        #[doc(hidden)]
+        pub unsafe fn from_handle(handle: u32) -> Self {
+            debug_assert!(handle != u32::MAX);
+            Self {
+                handle: AtomicU32::new(handle),
+                _marker: marker::PhantomData,
+            }
+        }
+        /// Takes ownership of the handle owned by `resource`.
+        ///
+        /// Note that this ideally would be `into_handle` taking `Resource<T>` by
+        /// ownership. The code generator does not enable that in all situations,
+        /// unfortunately, so this is provided instead.
+        ///
+        /// Also note that `take_handle` is in theory only ever called on values
+        /// owned by a generated function. For example a generated function might
+        /// take `Resource<T>` as an argument but then call `take_handle` on a
+        /// reference to that argument. In that sense the dynamic nature of
+        /// `take_handle` should only be exposed internally to generated code, not
+        /// to user code.
+        #[doc(hidden)]
+        pub fn take_handle(resource: &Resource<T>) -> u32 {
+            resource.handle.swap(u32::MAX, Relaxed)
+        }
+        #[doc(hidden)]
+        pub fn handle(resource: &Resource<T>) -> u32 {
+            resource.handle.load(Relaxed)
+        }
+    }
+    impl<T: WasmResource> fmt::Debug for Resource<T> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_struct("Resource").field("handle", &self.handle).finish()
+        }
+    }
+    impl<T: WasmResource> Drop for Resource<T> {
+        fn drop(&mut self) {
+            unsafe {
+                match self.handle.load(Relaxed) {
+                    u32::MAX => {}
+                    other => T::drop(other),
+                }
+            }
+        }
+    }
+    pub use alloc_crate::boxed::Box;
     #[cfg(target_arch = "wasm32")]
     pub fn run_ctors_once() {
         wit_bindgen_rt::run_ctors_once();
@@ -1206,6 +2650,13 @@ mod _rt {
         let layout = alloc::Layout::from_size_align_unchecked(size, align);
         alloc::dealloc(ptr, layout);
     }
+    pub unsafe fn invalid_enum_discriminant<T>() -> T {
+        if cfg!(debug_assertions) {
+            panic!("invalid enum discriminant")
+        } else {
+            unsafe { core::hint::unreachable_unchecked() }
+        }
+    }
     pub unsafe fn bool_lift(val: u8) -> bool {
         if cfg!(debug_assertions) {
             match val {
@@ -1217,7 +2668,6 @@ mod _rt {
             val != 0
         }
     }
-    pub use alloc_crate::alloc;
     pub fn as_i32<T: AsI32>(t: T) -> i32 {
         t.as_i32()
     }
@@ -1277,6 +2727,7 @@ mod _rt {
             self as i32
         }
     }
+    pub use alloc_crate::alloc;
     extern crate alloc as alloc_crate;
 }
 /// Generates `#[unsafe(no_mangle)]` functions to export the specified type as
@@ -1315,35 +2766,58 @@ pub(crate) use __export_semantics_component_impl as export;
 )]
 #[doc(hidden)]
 #[allow(clippy::octal_escapes)]
-pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 1206] = *b"\
-\0asm\x0d\0\x01\0\0\x19\x16wit-component-encoding\x04\0\x07\xac\x08\x01A\x02\x01\
-A\x06\x01B0\x01y\x04\0\x09selbri-id\x03\0\0\x01y\x04\0\x08sumti-id\x03\0\x02\x01\
+pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 2232] = *b"\
+\0asm\x0d\0\x01\0\0\x19\x16wit-component-encoding\x04\0\x07\xae\x10\x01A\x02\x01\
+A\x06\x01B\\\x01y\x04\0\x09selbri-id\x03\0\0\x01y\x04\0\x08sumti-id\x03\0\x02\x01\
 m\x05\x02fa\x02fe\x02fi\x02fo\x02fu\x04\0\x09place-tag\x03\0\x04\x01m\x04\x02se\x02\
 te\x02ve\x02xe\x04\0\x0aconversion\x03\0\x06\x01m\x04\x02je\x02ja\x02jo\x02ju\x04\
-\0\x0aconnective\x03\0\x08\x01m\x05\x02lo\x02le\x02la\x05ro-lo\x05ro-le\x04\0\x05\
-gadri\x03\0\x0a\x01m\x02\x03poi\x03noi\x04\0\x0frel-clause-kind\x03\0\x0c\x01r\x02\
-\x04kind\x0d\x0dbody-sentencey\x04\0\x0arel-clause\x03\0\x0e\x01o\x02\x0b\x01\x01\
-o\x02\x05\x03\x01o\x02\x03\x0f\x01q\x07\x09pro-sumti\x01s\0\x0bdescription\x01\x10\
-\0\x04name\x01s\0\x0equoted-literal\x01s\0\x0bunspecified\0\0\x06tagged\x01\x11\0\
-\x0arestricted\x01\x12\0\x04\0\x05sumti\x03\0\x13\x01ps\x01o\x02\x01\x01\x01o\x02\
-\x07\x01\x01p\x03\x01o\x02\x01\x18\x01o\x03\x01\x09\x01\x01q\x08\x04root\x01s\0\x08\
-compound\x01\x15\0\x05tanru\x01\x16\0\x09converted\x01\x17\0\x07negated\x01\x01\0\
-\x07grouped\x01\x01\0\x09with-args\x01\x19\0\x09connected\x01\x1a\0\x04\0\x06sel\
-bri\x03\0\x1b\x01r\x04\x08relation\x01\x0ahead-terms\x18\x0atail-terms\x18\x07ne\
-gated\x7f\x04\0\x05bridi\x03\0\x1d\x01p\x1c\x01p\x14\x01p\x1e\x01py\x01r\x04\x07\
-selbris\x1f\x06sumtis\x20\x09sentences!\x05roots\"\x04\0\x0aast-buffer\x03\0#\x01\
-q\x04\x08variable\x01s\0\x08constant\x01s\0\x0bdescription\x01s\0\x0bunspecified\
-\0\0\x04\0\x0clogical-term\x03\0%\x01p&\x01o\x02s'\x01o\x02yy\x01o\x02sy\x01q\x06\
-\x09predicate\x01(\0\x08and-node\x01)\0\x07or-node\x01)\0\x08not-node\x01y\0\x0b\
-exists-node\x01*\0\x0cfor-all-node\x01*\0\x04\0\x0alogic-node\x03\0+\x01p,\x01r\x02\
-\x05nodes-\x05roots\"\x04\0\x0clogic-buffer\x03\0.\x03\0\x1blojban:nesy/ast-type\
-s@0.1.0\x05\0\x02\x03\0\0\x0aast-buffer\x02\x03\0\0\x0clogic-buffer\x01B\x07\x02\
-\x03\x02\x01\x01\x04\0\x0aast-buffer\x03\0\0\x02\x03\x02\x01\x02\x04\0\x0clogic-\
-buffer\x03\0\x02\x01j\x01\x03\x01s\x01@\x01\x03ast\x01\0\x04\x04\0\x0ecompile-bu\
-ffer\x01\x05\x04\0\x1blojban:nesy/semantics@0.1.0\x05\x03\x04\0%lojban:nesy/sema\
-ntics-component@0.1.0\x04\0\x0b\x19\x01\0\x13semantics-component\x03\0\0\0G\x09p\
-roducers\x01\x0cprocessed-by\x02\x0dwit-component\x070.227.1\x10wit-bindgen-rust\
-\x060.41.0";
+\0\x0aconnective\x03\0\x08\x01m\x0b\x02lo\x02le\x02la\x03loi\x03lei\x03lai\x04lo\
+hi\x04lehi\x04lahi\x04lohe\x04lehe\x04\0\x05gadri\x03\0\x0a\x01m\x08\x03poi\x03n\
+oi\x03voi\x03goi\x02pe\x02ne\x02po\x04pohe\x04\0\x0frel-clause-kind\x03\0\x0c\x01\
+m\x05\x02nu\x04duhu\x02ka\x02ni\x04siho\x04\0\x10abstraction-kind\x03\0\x0e\x01m\
+\x03\x02pu\x02ca\x02ba\x04\0\x0etime-direction\x03\0\x10\x01m\x03\x02zi\x02za\x02\
+zu\x04\0\x0dtime-distance\x03\0\x12\x01m\x03\x04coha\x04cohu\x04caho\x04\0\x06as\
+pect\x03\0\x14\x01m\x03\x03all\x08at-least\x07exactly\x04\0\x0fquantifier-kind\x03\
+\0\x16\x01r\x02\x05starty\x03endy\x04\0\x04span\x03\0\x18\x01k\x11\x01k\x13\x01k\
+\x15\x01r\x03\x09direction\x1a\x08distance\x1b\x06aspect\x1c\x04\0\x05tense\x03\0\
+\x1d\x01r\x02\x04kind\x17\x05county\x04\0\x0aquantifier\x03\0\x1f\x01q\x02\x03ba\
+i\x01s\0\x04fiho\x01\x01\0\x04\0\x09modal-tag\x03\0!\x01r\x04\x0aconnective\x09\x09\
+converted\x7f\x0bnegate-left\x7f\x0cnegate-right\x7f\x04\0\x12logical-connective\
+\x03\0#\x01q\x02\x08sentence\x01y\0\x05sumti\x01\x03\0\x04\0\x0frel-clause-body\x03\
+\0%\x01r\x02\x04kind\x0d\x04body&\x04\0\x0arel-clause\x03\0'\x01k\x20\x01o\x03\x0b\
+)\x01\x01o\x02\x20\x03\x01o\x02\x05\x03\x01o\x02\x1e\x03\x01o\x02\"\x03\x01o\x02\
+\x03(\x01o\x03\x03$\x03\x01q\x0c\x09pro-sumti\x01s\0\x0brelativized\x01y\0\x0bde\
+scription\x01*\0\x0aquantified\x01+\0\x04name\x01s\0\x0equoted-literal\x01s\0\x0b\
+unspecified\0\0\x06tagged\x01,\0\x0ctense-tagged\x01-\0\x0cmodal-tagged\x01.\0\x0a\
+restricted\x01/\0\x09connected\x010\0\x04\0\x05sumti\x03\01\x01ps\x01o\x02\x01\x01\
+\x01o\x02\x07\x01\x01p\x03\x01o\x02\x016\x01o\x03\x01\x09\x01\x01o\x02\x0fy\x01q\
+\x09\x04root\x01s\0\x08compound\x013\0\x05tanru\x014\0\x09converted\x015\0\x07ne\
+gated\x01\x01\0\x07grouped\x01\x01\0\x09with-args\x017\0\x09connected\x018\0\x0b\
+abstraction\x019\0\x04\0\x06selbri\x03\0:\x01k\x1e\x01r\x07\x08relation\x01\x0ah\
+ead-terms6\x0atail-terms6\x07negated\x7f\x05tense<\x08question\x7f\x04span\x19\x04\
+\0\x05bridi\x03\0=\x01o\x02)\x03\x01q\x02\x08variable\x01?\0\x04naku\0\0\x04\0\x0b\
+prenex-term\x03\0@\x01o\x03$yy\x01p\xc1\0\x01o\x02\xc3\0y\x01q\x03\x06simple\x01\
+y\0\x09connected\x01\xc2\0\0\x06prenex\x01\xc4\0\0\x04\0\x09statement\x03\0E\x01\
+p;\x01p2\x01p\x19\x01p>\x01p\xc6\0\x01py\x01r\x07\x07selbris\xc7\0\x06sumtis\xc8\
+\0\x0cselbri-spans\xc9\0\x0bsumti-spans\xc9\0\x09sentences\xca\0\x0astatements\xcb\
+\0\x05roots\xcc\0\x04\0\x0aast-buffer\x03\0M\x01q\x05\x08variable\x01s\0\x08cons\
+tant\x01s\0\x0bdescription\x01s\0\x0bunspecified\0\0\x0bproposition\x01y\0\x04\0\
+\x0clogical-term\x03\0O\x01p\xd0\0\x01o\x02s\xd1\0\x01o\x02yy\x01o\x02sy\x01o\x02\
+\xd0\0y\x01o\x03syy\x01q\x09\x09predicate\x01\xd2\0\0\x08and-node\x01\xd3\0\0\x07\
+or-node\x01\xd3\0\0\x0cimplies-node\x01\xd3\0\0\x08not-node\x01y\0\x0bexists-nod\
+e\x01\xd4\0\0\x0cfor-all-node\x01\xd4\0\0\x07at-node\x01\xd5\0\0\x0cgeneric-node\
+\x01\xd6\0\0\x04\0\x0alogic-node\x03\0W\x01p\xd8\0\x01r\x02\x05nodes\xd9\0\x05ro\
+ots\xcc\0\x04\0\x0clogic-buffer\x03\0Z\x03\0\x1blojban:nesy/ast-types@0.1.0\x05\0\
+\x02\x03\0\0\x0aast-buffer\x02\x03\0\0\x0clogic-buffer\x01B\x10\x02\x03\x02\x01\x01\
+\x04\0\x0aast-buffer\x03\0\0\x02\x03\x02\x01\x02\x04\0\x0clogic-buffer\x03\0\x02\
+\x04\0\x08compiler\x03\x01\x01i\x04\x01@\0\0\x05\x04\0\x15[constructor]compiler\x01\
+\x06\x01h\x04\x01j\x01\x03\x01s\x01@\x02\x04self\x07\x03ast\x01\0\x08\x04\0\x1f[\
+method]compiler.compile-buffer\x01\x09\x01@\x01\x04self\x07\x01\0\x04\0\x16[meth\
+od]compiler.reset\x01\x0a\x01@\x01\x04self\x07\0\x05\x04\0\x15[method]compiler.f\
+ork\x01\x0b\x04\0\x1blojban:nesy/semantics@0.1.0\x05\x03\x04\0%lojban:nesy/seman\
+tics-component@0.1.0\x04\0\x0b\x19\x01\0\x13semantics-component\x03\0\0\0G\x09pr\
+oducers\x01\x0cprocessed-by\x02\x0dwit-component\x070.227.1\x10wit-bindgen-rust\x06\
+0.41.0";
 #[inline(never)]
 #[doc(hidden)]
 pub fn __link_custom_section_describing_imports() {
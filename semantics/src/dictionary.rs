@@ -11,8 +11,146 @@ impl JbovlasteSchema {
     }
 
     /// Retrieves the arity, defaulting to 2 for unknown words.
-    /// Use this only when a fallback is acceptable (e.g., lujvo not in dictionary).
+    /// A lujvo missing from jbovlaste inherits the arity of its head gismu.
     pub fn get_arity_or_default(word: &str) -> usize {
-        JBOVLASTE_ARITIES.get(word).copied().unwrap_or(2)
+        Self::get_arity(word)
+            .or_else(|| {
+                let components = Self::decompose_lujvo(word)?;
+                Self::get_arity(components.last()?)
+            })
+            .unwrap_or(2)
+    }
+
+    /// Retrieves the gismu underlying a BAI modal tag (e.g. ri'a → rinka).
+    pub fn get_bai_gismu(cmavo: &str) -> Option<&'static str> {
+        BAI_GISMU.get(cmavo).copied()
+    }
+
+    /// Place mappings of a lujvo onto its components, as listed in
+    /// jbovlaste: (lujvo place, component initial, component place).
+    pub fn get_lujvo_places(lujvo: &str) -> Option<&'static [(usize, char, usize)]> {
+        LUJVO_PLACES.get(lujvo).copied()
+    }
+
+    /// Splits a lujvo into the gismu its rafsi stand for, skipping y and
+    /// r/n hyphens (e.g. gerzda → [gerku, zdani]). Returns None unless the
+    /// word decomposes into at least two known rafsi.
+    pub fn decompose_lujvo(word: &str) -> Option<Vec<&'static str>> {
+        let mut components = Vec::new();
+        let ends_in_vowel = word.ends_with(['a', 'e', 'i', 'o', 'u']);
+        let found =
+            word.is_ascii() && ends_in_vowel && Self::split_rafsi(word, true, &mut components);
+        (found && components.len() >= 2).then_some(components)
+    }
+
+    fn split_rafsi(rest: &str, first: bool, out: &mut Vec<&'static str>) -> bool {
+        if rest.is_empty() {
+            return true;
+        }
+
+        // Final 5-letter rafsi: the gismu itself
+        if let Some(gismu) = Self::gismu_key(rest) {
+            out.push(gismu);
+            return true;
+        }
+
+        let mut candidates: Vec<(&'static str, usize)> = Vec::new();
+        // 4-letter rafsi: a gismu minus its final vowel, always followed by y
+        if rest.len() > 5 && rest.as_bytes()[4] == b'y' {
+            for vowel in ["a", "e", "i", "o", "u"] {
+                if let Some(gismu) = Self::gismu_key(&format!("{}{}", &rest[..4], vowel)) {
+                    candidates.push((gismu, 5));
+                }
+            }
+        }
+        for len in [3, 4] {
+            if let Some(gismu) = rest.get(..len).and_then(|r| RAFSI_GISMU.get(r)) {
+                candidates.push((gismu, len));
+            }
+        }
+
+        for (gismu, len) in candidates {
+            let after = &rest[len..];
+            let ends_in_vowel =
+                matches!(rest.as_bytes()[len - 1], b'a' | b'e' | b'i' | b'o' | b'u');
+            let mut nexts = vec![after];
+            if let Some(unhyphenated) = after.strip_prefix('y') {
+                nexts.push(unhyphenated);
+            }
+            if first && ends_in_vowel && (after.starts_with('r') || after.starts_with("nr")) {
+                nexts.push(&after[1..]);
+            }
+            // A hyphen never ends the word
+            nexts.retain(|next| !next.is_empty() || next.len() == after.len());
+            for next in nexts {
+                out.push(gismu);
+                if Self::split_rafsi(next, false, out) {
+                    return true;
+                }
+                out.pop();
+            }
+        }
+        false
+    }
+
+    fn gismu_key(word: &str) -> Option<&'static str> {
+        let (key, _) = JBOVLASTE_ARITIES.get_entry(word)?;
+        (key.len() == 5).then_some(*key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decomposes_cvc_and_ccv_rafsi() {
+        assert_eq!(
+            JbovlasteSchema::decompose_lujvo("gerzda"),
+            Some(vec!["gerku", "zdani"])
+        );
+        assert_eq!(
+            JbovlasteSchema::decompose_lujvo("jbobau"),
+            Some(vec!["lojbo", "bangu"])
+        );
+    }
+
+    #[test]
+    fn decomposes_long_rafsi_and_final_gismu() {
+        assert_eq!(
+            JbovlasteSchema::decompose_lujvo("gerkyzda"),
+            Some(vec!["gerku", "zdani"])
+        );
+        assert_eq!(
+            JbovlasteSchema::decompose_lujvo("gerzdani"),
+            Some(vec!["gerku", "zdani"])
+        );
+    }
+
+    #[test]
+    fn skips_hyphens() {
+        // y between rafsi, r after an initial CVV rafsi
+        assert_eq!(
+            JbovlasteSchema::decompose_lujvo("nelyzda"),
+            Some(vec!["nelci", "zdani"])
+        );
+        assert_eq!(
+            JbovlasteSchema::decompose_lujvo("baurkla"),
+            Some(vec!["bangu", "klama"])
+        );
+    }
+
+    #[test]
+    fn rejects_non_lujvo() {
+        assert_eq!(JbovlasteSchema::decompose_lujvo("gerku"), None);
+        assert_eq!(JbovlasteSchema::decompose_lujvo("gerzd"), None);
+        assert_eq!(JbovlasteSchema::decompose_lujvo("zzzzzz"), None);
+    }
+
+    #[test]
+    fn unknown_lujvo_takes_its_head_arity() {
+        assert_eq!(JbovlasteSchema::get_arity("nelcti"), None);
+        assert_eq!(JbovlasteSchema::get_arity_or_default("nelcti"), 2);
+        assert_eq!(JbovlasteSchema::get_arity_or_default("nelkla"), 5);
     }
 }
@@ -0,0 +1,229 @@
+// semantics/src/discourse.rs
+//
+// Discourse state that outlives a single sentence (CLL 7.5–7.6):
+//   ri/ra/ru    — back-counting over the sumti mentioned so far
+//   ko'a..ko'u  — assigned with goi, valid until reassigned
+//   go'i        — repeats the previous main bridi
+//
+// Terms are kept as compiled. A variable can only be referred to while
+// its quantifier is in scope; once the scope closes every reference to
+// it is replaced by its fallback — the described thing for a lo
+// description (read like le), zo'e otherwise.
+
+use crate::ir::{LogicalForm, LogicalTerm};
+use lasso::Spur;
+
+/// A sumti as later anaphora see it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Referent {
+    pub term: LogicalTerm,
+    /// What `term` becomes when it is a variable whose scope has closed.
+    pub fallback: LogicalTerm,
+}
+
+/// The previous main bridi: its predication over the placeholder
+/// variables x1..xn, and the terms that filled them.
+#[derive(Debug, Clone)]
+pub struct PreviousBridi {
+    template: LogicalForm,
+    placeholders: Vec<Spur>,
+    args: Vec<LogicalTerm>,
+}
+
+impl PreviousBridi {
+    pub fn new(template: LogicalForm, placeholders: Vec<Spur>, args: Vec<LogicalTerm>) -> Self {
+        Self {
+            template,
+            placeholders,
+            args,
+        }
+    }
+
+    pub fn arity(&self) -> usize {
+        self.placeholders.len()
+    }
+
+    /// The predication over `args`; a place left zo'e keeps the previous
+    /// bridi's filler ("mi klama lo zarci .i do go'i" → do klama lo zarci).
+    pub fn repeat(&self, args: &[LogicalTerm]) -> (LogicalForm, Vec<LogicalTerm>) {
+        let args: Vec<LogicalTerm> = self
+            .args
+            .iter()
+            .enumerate()
+            .map(|(i, old)| match args.get(i) {
+                Some(LogicalTerm::Unspecified) | None => old.clone(),
+                Some(new) => new.clone(),
+            })
+            .collect();
+        (self.instantiate(&args), args)
+    }
+
+    /// The same predication, now filled by `args`.
+    pub fn with_args(self, args: Vec<LogicalTerm>) -> Self {
+        Self { args, ..self }
+    }
+
+    /// Fill the placeholders of the template with `args`.
+    pub fn instantiate(&self, args: &[LogicalTerm]) -> LogicalForm {
+        self.placeholders
+            .iter()
+            .zip(args)
+            .fold(self.template.clone(), |form, (&var, term)| {
+                substitute(&form, var, term)
+            })
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct Discourse {
+    /// Every counted sumti, in order of completion.
+    mentions: Vec<Referent>,
+    /// ko'a-series assignments made with goi.
+    assignments: Vec<(Spur, Referent)>,
+    previous: Option<PreviousBridi>,
+}
+
+impl Discourse {
+    /// Record a completed sumti for ri/ra/ru.
+    pub fn mention(&mut self, referent: Referent) {
+        self.mentions.push(referent);
+    }
+
+    /// ri: the last sumti; ra: the most recent one other than ri's
+    /// referent; ru: the earliest one other than ri's and ra's. Each
+    /// falls back to the nearer one when the discourse is too short.
+    pub fn back_reference(&self, cmavo: &str) -> Option<Referent> {
+        let ri = self.mentions.last()?;
+        let ra = self
+            .mentions
+            .iter()
+            .rev()
+            .find(|r| r.term != ri.term)
+            .unwrap_or(ri);
+        let referent = match cmavo {
+            "ri" => ri,
+            "ra" => ra,
+            "ru" => self
+                .mentions
+                .iter()
+                .find(|r| r.term != ri.term && r.term != ra.term)
+                .unwrap_or(ra),
+            _ => return None,
+        };
+        Some(referent.clone())
+    }
+
+    /// Bind a ko'a-series cmavo, replacing any earlier assignment.
+    pub fn assign(&mut self, koha: Spur, referent: Referent) {
+        self.assignments.retain(|(k, _)| *k != koha);
+        self.assignments.push((koha, referent));
+    }
+
+    pub fn assigned(&self, koha: Spur) -> Option<Referent> {
+        self.assignments
+            .iter()
+            .find(|(k, _)| *k == koha)
+            .map(|(_, referent)| referent.clone())
+    }
+
+    /// The referent already recorded for `term`, if any.
+    pub fn referent_of(&self, term: &LogicalTerm) -> Option<Referent> {
+        self.mentions
+            .iter()
+            .rev()
+            .chain(self.assignments.iter().map(|(_, referent)| referent))
+            .find(|r| r.term == *term)
+            .cloned()
+    }
+
+    pub fn previous(&self) -> Option<&PreviousBridi> {
+        self.previous.as_ref()
+    }
+
+    pub fn set_previous(&mut self, previous: PreviousBridi) {
+        self.previous = Some(previous);
+    }
+
+    /// `var`'s quantifier has been wrapped: replace every reference to it
+    /// by its fallback.
+    pub fn close_scope(&mut self, var: Spur) {
+        let bound = LogicalTerm::Variable(var);
+        let fallback = self
+            .referent_of(&bound)
+            .map_or(LogicalTerm::Unspecified, |r| r.fallback);
+
+        let referents = self
+            .mentions
+            .iter_mut()
+            .chain(self.assignments.iter_mut().map(|(_, referent)| referent));
+        for referent in referents {
+            if referent.term == bound {
+                referent.term = referent.fallback.clone();
+            }
+        }
+        if let Some(previous) = &mut self.previous {
+            for arg in &mut previous.args {
+                if *arg == bound {
+                    *arg = fallback.clone();
+                }
+            }
+        }
+    }
+
+    /// End of a root statement: no variable is in scope any more.
+    pub fn close_statement(&mut self) {
+        let vars: Vec<Spur> = self
+            .mentions
+            .iter()
+            .chain(self.assignments.iter().map(|(_, referent)| referent))
+            .map(|r| &r.term)
+            .chain(self.previous.iter().flat_map(|p| p.args.iter()))
+            .filter_map(|term| match term {
+                LogicalTerm::Variable(v) => Some(*v),
+                _ => None,
+            })
+            .collect();
+        for var in vars {
+            self.close_scope(var);
+        }
+    }
+}
+
+/// Replace the free occurrences of variable `var` in `form` with `term`.
+pub fn substitute(form: &LogicalForm, var: Spur, term: &LogicalTerm) -> LogicalForm {
+    let sub = |f: &LogicalForm| Box::new(substitute(f, var, term));
+    match form {
+        LogicalForm::Predicate { relation, args } => LogicalForm::Predicate {
+            relation: *relation,
+            args: args
+                .iter()
+                .map(|arg| substitute_term(arg, var, term))
+                .collect(),
+        },
+        LogicalForm::ForAll(v, _) | LogicalForm::Exists(v, _) | LogicalForm::Generic(v, _, _)
+            if *v == var =>
+        {
+            form.clone()
+        }
+        LogicalForm::ForAll(v, body) => LogicalForm::ForAll(*v, sub(body)),
+        LogicalForm::Exists(v, body) => LogicalForm::Exists(*v, sub(body)),
+        LogicalForm::And(l, r) => LogicalForm::And(sub(l), sub(r)),
+        LogicalForm::Or(l, r) => LogicalForm::Or(sub(l), sub(r)),
+        LogicalForm::Implies(l, r) => LogicalForm::Implies(sub(l), sub(r)),
+        LogicalForm::Not(inner) => LogicalForm::Not(sub(inner)),
+        LogicalForm::At(time, body) => LogicalForm::At(substitute_term(time, var, term), sub(body)),
+        LogicalForm::Generic(v, restrictor, body) => {
+            LogicalForm::Generic(*v, sub(restrictor), sub(body))
+        }
+    }
+}
+
+fn substitute_term(arg: &LogicalTerm, var: Spur, term: &LogicalTerm) -> LogicalTerm {
+    match arg {
+        LogicalTerm::Variable(v) if *v == var => term.clone(),
+        LogicalTerm::Proposition(inner) => {
+            LogicalTerm::Proposition(Box::new(substitute(inner, var, term)))
+        }
+        other => other.clone(),
+    }
+}
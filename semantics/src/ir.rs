@@ -11,6 +11,8 @@ pub enum LogicalTerm {
     Description(Spur),
     /// An explicitly unspecified argument (zo'e)
     Unspecified,
+    /// A reified proposition (e.g., 'lo nu mi klama' -> the event of my going)
+    Proposition(Box<LogicalForm>),
 }
 
 /// The Well-Formed Formulas (WFFs) of our First-Order Logic engine.
@@ -29,6 +31,13 @@ pub enum LogicalForm {
     And(Box<LogicalForm>, Box<LogicalForm>),
     /// Logical Disjunction: A ∨ B
     Or(Box<LogicalForm>, Box<LogicalForm>),
+    /// Material Implication: A → B
+    Implies(Box<LogicalForm>, Box<LogicalForm>),
     /// Logical Negation: ¬A
     Not(Box<LogicalForm>),
+    /// Time-indexed formula: A holds at interval t
+    At(LogicalTerm, Box<LogicalForm>),
+    /// Defeasible generic (lo'e): an x satisfying the restrictor
+    /// typically satisfies the body, unless known otherwise
+    Generic(Spur, Box<LogicalForm>, Box<LogicalForm>),
 }
@@ -1,61 +1,88 @@
 #[allow(warnings)]
 pub mod bindings;
 pub mod dictionary;
+pub mod discourse;
 pub mod ir;
 pub mod semantic;
 
-use bindings::exports::lojban::nesy::semantics::Guest;
+use bindings::exports::lojban::nesy::semantics::{self, Guest, GuestCompiler};
 use bindings::lojban::nesy::ast_types::{
     AstBuffer, LogicBuffer, LogicNode, LogicalTerm as WitTerm,
 };
 use ir::{LogicalForm, LogicalTerm};
 use semantic::SemanticCompiler;
+use std::cell::RefCell;
 
 struct SemanticsComponent;
 
 impl Guest for SemanticsComponent {
-    fn compile_buffer(ast: AstBuffer) -> Result<LogicBuffer, String> {
-        let mut compiler = SemanticCompiler::new();
-        let mut logic_forms = Vec::with_capacity(ast.roots.len());
-
-        // Only compile top-level (root) sentences.
-        // Rel clause bodies live in ast.sentences but are referenced
-        // by index from Sumti::Restricted — they are NOT roots.
-        for &root_idx in ast.roots.iter() {
-            let sentence = &ast.sentences[root_idx as usize];
-            logic_forms.push(compiler.compile_bridi(
-                sentence,
-                &ast.selbris,
-                &ast.sumtis,
-                &ast.sentences,
-            ));
-        }
+    type Compiler = CompilerResource;
+}
 
-        let mut nodes = Vec::new();
-        let mut roots = Vec::with_capacity(logic_forms.len());
+/// The `compiler` resource: one SemanticCompiler, whose interner and
+/// discourse state (ri, ko'a, go'i) carry over from one compile-buffer
+/// call to the next. Its methods take `&self`, so it sits in a RefCell.
+struct CompilerResource(RefCell<SemanticCompiler>);
 
-        for form in logic_forms {
-            let root_id = flatten_form(&form, &mut nodes, &compiler.interner);
-            roots.push(root_id);
-        }
+impl GuestCompiler for CompilerResource {
+    fn new() -> Self {
+        Self(RefCell::new(SemanticCompiler::new()))
+    }
+
+    fn compile_buffer(&self, ast: AstBuffer) -> Result<LogicBuffer, String> {
+        compile(&mut self.0.borrow_mut(), &ast)
+    }
+
+    fn reset(&self) {
+        *self.0.borrow_mut() = SemanticCompiler::new();
+    }
 
-        Ok(LogicBuffer { nodes, roots })
+    fn fork(&self) -> semantics::Compiler {
+        semantics::Compiler::new(Self(RefCell::new(self.0.borrow().clone())))
     }
 }
 
+/// Compile the root statements of `ast`, in order, as one logic buffer.
+/// Fails if any of them holds something the compiler cannot lower.
+fn compile(compiler: &mut SemanticCompiler, ast: &AstBuffer) -> Result<LogicBuffer, String> {
+    let mut logic_forms = Vec::with_capacity(ast.roots.len());
+
+    // Only compile top-level (root) statements.
+    // Rel clause and abstraction bodies live in ast.sentences but are
+    // referenced by index from Sumti::Restricted / Selbri::Abstraction
+    // — they are NOT roots.
+    for &root_idx in ast.roots.iter() {
+        logic_forms.push(compiler.compile_statement(
+            root_idx,
+            &ast.statements,
+            &ast.selbris,
+            &ast.sumtis,
+            &ast.sentences,
+        ));
+    }
+
+    let errors = compiler.take_errors();
+    if !errors.is_empty() {
+        return Err(errors.join("; "));
+    }
+
+    let mut nodes = Vec::new();
+    let mut roots = Vec::with_capacity(logic_forms.len());
+
+    for form in logic_forms {
+        let root_id = flatten_form(&form, &mut nodes, &compiler.interner);
+        roots.push(root_id);
+    }
+
+    Ok(LogicBuffer { nodes, roots })
+}
+
 fn flatten_form(form: &LogicalForm, nodes: &mut Vec<LogicNode>, interner: &lasso::Rodeo) -> u32 {
     match form {
         LogicalForm::Predicate { relation, args } => {
             let wit_args = args
                 .iter()
-                .map(|a| match a {
-                    LogicalTerm::Variable(v) => WitTerm::Variable(interner.resolve(v).to_string()),
-                    LogicalTerm::Constant(c) => WitTerm::Constant(interner.resolve(c).to_string()),
-                    LogicalTerm::Description(d) => {
-                        WitTerm::Description(interner.resolve(d).to_string())
-                    }
-                    LogicalTerm::Unspecified => WitTerm::Unspecified,
-                })
+                .map(|a| flatten_term(a, nodes, interner))
                 .collect();
 
             let id = nodes.len() as u32;
@@ -79,6 +106,13 @@ fn flatten_form(form: &LogicalForm, nodes: &mut Vec<LogicNode>, interner: &lasso
             nodes.push(LogicNode::OrNode((l_id, r_id)));
             id
         }
+        LogicalForm::Implies(left, right) => {
+            let l_id = flatten_form(left, nodes, interner);
+            let r_id = flatten_form(right, nodes, interner);
+            let id = nodes.len() as u32;
+            nodes.push(LogicNode::ImpliesNode((l_id, r_id)));
+            id
+        }
         LogicalForm::Not(inner) => {
             let inner_id = flatten_form(inner, nodes, interner);
             let id = nodes.len() as u32;
@@ -94,6 +128,24 @@ fn flatten_form(form: &LogicalForm, nodes: &mut Vec<LogicNode>, interner: &lasso
             )));
             id
         }
+        LogicalForm::At(time, body) => {
+            let b_id = flatten_form(body, nodes, interner);
+            let wit_time = flatten_term(time, nodes, interner);
+            let id = nodes.len() as u32;
+            nodes.push(LogicNode::AtNode((wit_time, b_id)));
+            id
+        }
+        LogicalForm::Generic(v, restrictor, body) => {
+            let r_id = flatten_form(restrictor, nodes, interner);
+            let b_id = flatten_form(body, nodes, interner);
+            let id = nodes.len() as u32;
+            nodes.push(LogicNode::GenericNode((
+                interner.resolve(v).to_string(),
+                r_id,
+                b_id,
+            )));
+            id
+        }
         LogicalForm::ForAll(v, body) => {
             let b_id = flatten_form(body, nodes, interner);
             let id = nodes.len() as u32;
@@ -106,4 +158,21 @@ fn flatten_form(form: &LogicalForm, nodes: &mut Vec<LogicNode>, interner: &lasso
     }
 }
 
+fn flatten_term(
+    term: &LogicalTerm,
+    nodes: &mut Vec<LogicNode>,
+    interner: &lasso::Rodeo,
+) -> WitTerm {
+    match term {
+        LogicalTerm::Variable(v) => WitTerm::Variable(interner.resolve(v).to_string()),
+        LogicalTerm::Constant(c) => WitTerm::Constant(interner.resolve(c).to_string()),
+        LogicalTerm::Description(d) => WitTerm::Description(interner.resolve(d).to_string()),
+        LogicalTerm::Unspecified => WitTerm::Unspecified,
+        // The nested formula is flattened first, so it always has a lower
+        // index than the predicate that references it.
+        LogicalTerm::Proposition(form) => WitTerm::Proposition(flatten_form(form, nodes, interner)),
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
 bindings::export!(SemanticsComponent with_types_in bindings);
@@ -1,23 +1,72 @@
 use crate::bindings::lojban::nesy::ast_types::{
-    Bridi, Connective, Conversion, Gadri, PlaceTag, Selbri, Sumti,
+    AbstractionKind, Aspect, Bridi, Connective, Conversion, Gadri, LogicalConnective, ModalTag,
+    PlaceTag, PrenexTerm, Quantifier, QuantifierKind, RelClause, RelClauseBody, RelClauseKind,
+    Selbri, Statement, Sumti, Tense, TimeDirection, TimeDistance,
 };
 use crate::dictionary::JbovlasteSchema;
+use crate::discourse::{Discourse, PreviousBridi, Referent};
 use crate::ir::{LogicalForm, LogicalTerm};
 use lasso::Rodeo;
 
-/// Tracks a quantifier introduced by a description (lo/le/ro lo/ro le),
-/// with an optional relative clause restrictor.
+/// su'o: the quantifier of a bare lo description or prenex variable.
+const SUHO: Quantifier = Quantifier {
+    kind: QuantifierKind::AtLeast,
+    count: 1,
+};
+
+/// ro: the outer quantifier of a lo description with an inner count.
+const RO: Quantifier = Quantifier {
+    kind: QuantifierKind::All,
+    count: 0,
+};
+
+/// Personal pro-sumti: not counted by ri/ra/ru (CLL 7.6).
+const PERSONAL: &[&str] = &["mi", "do", "mi'o", "mi'a", "ma'a", "do'o", "ko"];
+
+/// Tracks a quantifier introduced by a quantified sumti (lo, ro lo,
+/// re le, su'o da, ...), with an optional relative clause restrictor.
 struct QuantifierEntry {
     var: lasso::Spur,
-    desc_id: u32,
+    /// Description selbri restricting `var`; None for da/de/di
+    desc_id: Option<u32>,
     restrictor: Option<LogicalForm>,
-    /// If true, wraps as ∀x.(restrictor → body) instead of ∃x.(restrictor ∧ body)
-    universal: bool,
+    /// noi/ne clauses: claimed of the whole domain, never narrowing it
+    appositive: Option<LogicalForm>,
+    /// lo'e/le'e: a defeasible generic rather than a quantifier
+    typical: bool,
+    quantifier: Quantifier,
+    /// Inner quantifier ("lo ci gerku"): how many things fit the description
+    count: Option<Quantifier>,
 }
 
+#[derive(Clone)]
 pub struct SemanticCompiler {
     pub interner: Rodeo,
     pub var_counter: usize,
+    /// Variables bound by the enclosing prenexes while compiling their body.
+    prenex_scope: Vec<lasso::Spur>,
+    /// Anaphora state carried from one statement to the next.
+    pub discourse: Discourse,
+    /// Relative clauses on sumti with no quantifier to restrict
+    /// ("la .alis. poi sutra"): conjoined to the enclosing bridi.
+    claims: Vec<LogicalForm>,
+    /// What ke'a stands for in each enclosing relative clause, innermost
+    /// last, and whether the clause has used it.
+    relativized: Vec<(LogicalTerm, bool)>,
+    /// The ma of the current statement, by sumti id: variables left free
+    /// for a question to bind.
+    questions: Vec<(u32, lasso::Spur)>,
+    /// Connected sumti being expanded: (slot, operand) while the slot holds
+    /// a copy of the operand, so a ma copied there keeps its own variable.
+    operands: Vec<(u32, u32)>,
+    /// What could not be lowered since the last `take_errors`.
+    errors: Vec<String>,
+}
+
+impl Default for SemanticCompiler {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl SemanticCompiler {
@@ -25,6 +74,13 @@ impl SemanticCompiler {
         Self {
             interner: Rodeo::new(),
             var_counter: 0,
+            prenex_scope: Vec::new(),
+            discourse: Discourse::default(),
+            claims: Vec::new(),
+            relativized: Vec::new(),
+            questions: Vec::new(),
+            operands: Vec::new(),
+            errors: Vec::new(),
         }
     }
 
@@ -36,8 +92,28 @@ impl SemanticCompiler {
 
     // ─── Selbri Introspection ────────────────────────────────────
 
+    fn abstraction_name(kind: AbstractionKind) -> &'static str {
+        match kind {
+            AbstractionKind::Nu => "nu",
+            AbstractionKind::Duhu => "du'u",
+            AbstractionKind::Ka => "ka",
+            AbstractionKind::Ni => "ni",
+            AbstractionKind::Siho => "si'o",
+        }
+    }
+
+    /// Place count of the abstraction selbri, excluding the reified body.
+    fn abstraction_arity(kind: AbstractionKind) -> usize {
+        match kind {
+            AbstractionKind::Nu | AbstractionKind::Ka => 1,
+            AbstractionKind::Duhu | AbstractionKind::Ni | AbstractionKind::Siho => 2,
+        }
+    }
+
     fn get_selbri_arity(&self, selbri_id: u32, selbris: &[Selbri]) -> usize {
         match &selbris[selbri_id as usize] {
+            // mo may stand for any brivla: leave room for all five places
+            Selbri::Root(g) if g == "mo" => 5,
             Selbri::Root(g) => JbovlasteSchema::get_arity_or_default(g.as_str()),
             Selbri::Tanru((_, head_id)) => self.get_selbri_arity(*head_id, selbris),
             Selbri::Converted((_, inner_id)) => self.get_selbri_arity(*inner_id, selbris),
@@ -49,6 +125,7 @@ impl SemanticCompiler {
                 .last()
                 .map(|s| JbovlasteSchema::get_arity_or_default(s.as_str()))
                 .unwrap_or(2),
+            Selbri::Abstraction((kind, _)) => Self::abstraction_arity(*kind),
         }
     }
 
@@ -62,92 +139,200 @@ impl SemanticCompiler {
             Selbri::WithArgs((core_id, _)) => self.get_selbri_head_name(*core_id, selbris),
             Selbri::Connected((left_id, _, _)) => self.get_selbri_head_name(*left_id, selbris),
             Selbri::Compound(parts) => parts.last().map(|s| s.as_str()).unwrap_or("entity"),
+            Selbri::Abstraction((kind, _)) => Self::abstraction_name(*kind),
         }
     }
 
     // ─── Sumti Resolution ────────────────────────────────────────
 
+    /// Resolve a sumti and record it for ri/ra/ru once it is complete.
+    /// Tags and connectives are not counted themselves; the sumti inside
+    /// them are.
     fn resolve_sumti(
         &mut self,
-        sumti: &Sumti,
+        sumti_id: u32,
         sumtis: &[Sumti],
         selbris: &[Selbri],
         sentences: &[Bridi],
     ) -> (LogicalTerm, Vec<QuantifierEntry>) {
-        match sumti {
+        let sumti = &sumtis[sumti_id as usize];
+        let (term, quants) = self.lower_sumti(sumti_id, sumtis, selbris, sentences);
+        let counted = match sumti {
+            Sumti::ProSumti(p) => !PERSONAL.contains(&p.as_str()) && p != "ma",
+            Sumti::Name(_)
+            | Sumti::Description(_)
+            | Sumti::Quantified(_)
+            | Sumti::QuotedLiteral(_)
+            | Sumti::Restricted(_) => true,
+            Sumti::Unspecified
+            | Sumti::Relativized(_)
+            | Sumti::Tagged(_)
+            | Sumti::TenseTagged(_)
+            | Sumti::ModalTagged(_)
+            | Sumti::Connected(_) => false,
+        };
+        if counted {
+            let referent = self.referent(term.clone(), &quants, selbris);
+            self.discourse.mention(referent);
+        }
+        (term, quants)
+    }
+
+    /// A term with the fallback it takes once out of scope: a variable
+    /// bound by a lo description becomes the described thing.
+    fn referent(
+        &mut self,
+        term: LogicalTerm,
+        quants: &[QuantifierEntry],
+        selbris: &[Selbri],
+    ) -> Referent {
+        let LogicalTerm::Variable(var) = term else {
+            return Referent {
+                fallback: term.clone(),
+                term,
+            };
+        };
+        let fallback = match quants.iter().find(|q| q.var == var) {
+            Some(QuantifierEntry {
+                desc_id: Some(desc_id),
+                ..
+            }) => {
+                let desc = self.get_selbri_head_name(*desc_id, selbris);
+                LogicalTerm::Description(self.interner.get_or_intern(desc))
+            }
+            Some(_) => LogicalTerm::Unspecified,
+            None => self
+                .discourse
+                .referent_of(&term)
+                .map_or(LogicalTerm::Unspecified, |r| r.fallback),
+        };
+        Referent { term, fallback }
+    }
+
+    fn lower_sumti(
+        &mut self,
+        sumti_id: u32,
+        sumtis: &[Sumti],
+        selbris: &[Selbri],
+        sentences: &[Bridi],
+    ) -> (LogicalTerm, Vec<QuantifierEntry>) {
+        match &sumtis[sumti_id as usize] {
             Sumti::ProSumti(p) => {
-                let term = if matches!(p.as_str(), "da" | "de" | "di") {
-                    LogicalTerm::Variable(self.interner.get_or_intern(p.as_str()))
-                } else {
-                    LogicalTerm::Constant(self.interner.get_or_intern(p.as_str()))
+                let name = self.interner.get_or_intern(p.as_str());
+                let term = match p.as_str() {
+                    "da" | "de" | "di" => LogicalTerm::Variable(name),
+                    // Each ma is its own blank: ma, ma2, ma3, ... A bridi
+                    // lowered once per operand of a connected sumti asks
+                    // the same question each time.
+                    "ma" => {
+                        let node = self
+                            .operands
+                            .iter()
+                            .rev()
+                            .find(|(slot, _)| *slot == sumti_id)
+                            .map_or(sumti_id, |&(_, operand)| operand);
+                        let asked = self.questions.iter().find(|(id, _)| *id == node);
+                        let var = match (asked, self.questions.len()) {
+                            (Some(&(_, var)), _) => var,
+                            (None, 0) => name,
+                            (None, n) => self.interner.get_or_intern(format!("ma{}", n + 1)),
+                        };
+                        if asked.is_none() {
+                            self.questions.push((node, var));
+                        }
+                        LogicalTerm::Variable(var)
+                    }
+                    // With nothing to refer back to, ri is just zo'e
+                    "ri" | "ra" | "ru" => self
+                        .discourse
+                        .back_reference(p)
+                        .map_or(LogicalTerm::Unspecified, |r| r.term),
+                    // An unassigned ko'a names itself
+                    _ => self
+                        .discourse
+                        .assigned(name)
+                        .map_or(LogicalTerm::Constant(name), |r| r.term),
                 };
                 (term, vec![])
             }
 
+            Sumti::Relativized(depth) => {
+                let clauses = self.relativized.len();
+                match clauses.checked_sub(*depth as usize).filter(|_| *depth > 0) {
+                    Some(idx) => {
+                        self.relativized[idx].1 = true;
+                        (self.relativized[idx].0.clone(), vec![])
+                    }
+                    None => {
+                        eprintln!(
+                            "[semantics] ke'a xi {} outside {} enclosing relative clause(s); treated as zo'e",
+                            depth, clauses
+                        );
+                        (LogicalTerm::Unspecified, vec![])
+                    }
+                }
+            }
+
             Sumti::Name(n) => (
                 LogicalTerm::Constant(self.interner.get_or_intern(n.as_str())),
                 vec![],
             ),
 
-            Sumti::Description((gadri, desc_id)) => {
-                match gadri {
-                    // Existential: lo → ∃x
-                    Gadri::Lo => {
-                        let var = self.fresh_var();
-                        (
-                            LogicalTerm::Variable(var),
-                            vec![QuantifierEntry {
-                                var,
-                                desc_id: *desc_id,
-                                restrictor: None,
-                                universal: false,
-                            }],
-                        )
-                    }
-
-                    // Universal: ro lo → ∀x, ro le → ∀x
-                    Gadri::RoLo | Gadri::RoLe => {
-                        let var = self.fresh_var();
-                        (
-                            LogicalTerm::Variable(var),
-                            vec![QuantifierEntry {
-                                var,
-                                desc_id: *desc_id,
-                                restrictor: None,
-                                universal: true,
-                            }],
-                        )
-                    }
+            Sumti::Description(description) => {
+                self.resolve_description(description, None, sumtis, selbris, sentences)
+            }
 
-                    // le/la: non-quantified specific referent
-                    _ => {
-                        let desc_str = self.get_selbri_head_name(*desc_id, selbris);
-                        (
-                            LogicalTerm::Description(self.interner.get_or_intern(desc_str)),
-                            vec![],
-                        )
-                    }
+            Sumti::Quantified((quantifier, inner_id)) => match &sumtis[*inner_id as usize] {
+                Sumti::Description(description) => self.resolve_description(
+                    description,
+                    Some(*quantifier),
+                    sumtis,
+                    selbris,
+                    sentences,
+                ),
+                Sumti::ProSumti(v) if matches!(v.as_str(), "da" | "de" | "di") => {
+                    let var = self.interner.get_or_intern(v.as_str());
+                    (
+                        LogicalTerm::Variable(var),
+                        vec![QuantifierEntry {
+                            var,
+                            desc_id: None,
+                            restrictor: None,
+                            appositive: None,
+                            typical: false,
+                            quantifier: *quantifier,
+                            count: None,
+                        }],
+                    )
                 }
-            }
+                _ => self.lower_sumti(*inner_id, sumtis, selbris, sentences),
+            },
 
-            Sumti::Tagged((_tag, inner_id)) => {
-                let inner = &sumtis[*inner_id as usize];
-                self.resolve_sumti(inner, sumtis, selbris, sentences)
+            Sumti::Tagged((_, inner_id))
+            | Sumti::TenseTagged((_, inner_id))
+            | Sumti::ModalTagged((_, inner_id)) => {
+                self.resolve_sumti(*inner_id, sumtis, selbris, sentences)
             }
 
             Sumti::Restricted((inner_id, rel_clause)) => {
-                let inner = &sumtis[*inner_id as usize];
-                let (term, mut quants) = self.resolve_sumti(inner, sumtis, selbris, sentences);
+                if let (RelClauseKind::Goi, RelClauseBody::Sumti(target_id)) =
+                    (rel_clause.kind, rel_clause.body)
+                    && let Some(assigned) =
+                        self.assign(*inner_id, target_id, sumtis, selbris, sentences)
+                {
+                    return assigned;
+                }
+                let (mut term, mut quants) =
+                    self.lower_sumti(*inner_id, sumtis, selbris, sentences);
 
-                let rel_body = self.compile_bridi(
-                    &sentences[rel_clause.body_sentence as usize],
-                    selbris,
-                    sumtis,
-                    sentences,
-                );
+                let restriction =
+                    self.restriction(rel_clause, &mut term, sumtis, selbris, sentences);
 
-                if let Some(last) = quants.last_mut() {
-                    last.restrictor = Some(Self::inject_variable(rel_body, last.var));
+                match quants.last_mut() {
+                    Some(last) if term == LogicalTerm::Variable(last.var) => {
+                        Self::attach(last, rel_clause.kind, restriction);
+                    }
+                    _ => self.claims.push(restriction),
                 }
 
                 (term, quants)
@@ -159,10 +344,325 @@ impl SemanticCompiler {
             ),
 
             Sumti::Unspecified => (LogicalTerm::Unspecified, vec![]),
+
+            // The enclosing bridi expands every connected sumti it holds;
+            // one outside any bridi (a prenex restriction) has no bridi to
+            // expand into.
+            Sumti::Connected(_) => {
+                self.errors
+                    .push("a connected sumti outside a bridi cannot be lowered".to_string());
+                (LogicalTerm::Unspecified, vec![])
+            }
+        }
+    }
+
+    /// Resolve a description under an optional outer quantifier.
+    /// A lo description with only an inner quantifier is implicitly ro
+    /// over the counted set ("lo ci gerku" = "ro lo ci gerku");
+    /// unquantified le/la denote a specific referent.
+    /// Masses and sets are single terms; lo'e/le'e bind a generic.
+    fn resolve_description(
+        &mut self,
+        &(gadri, count, desc_id): &(Gadri, Option<Quantifier>, u32),
+        outer: Option<Quantifier>,
+        sumtis: &[Sumti],
+        selbris: &[Selbri],
+        sentences: &[Bridi],
+    ) -> (LogicalTerm, Vec<QuantifierEntry>) {
+        // Abstraction descriptions denote the reified proposition
+        // itself: "lo nu mi klama" → ⟨klama(mi, …)⟩ rather than ∃x. nu(x).
+        if let Selbri::Abstraction((_, body_idx)) = &selbris[desc_id as usize] {
+            let body =
+                self.compile_bridi(&sentences[*body_idx as usize], selbris, sumtis, sentences);
+            let body = self.close_free_variables(body);
+            return (LogicalTerm::Proposition(Box::new(body)), vec![]);
+        }
+
+        let (quantifier, count) = match (outer, gadri, count) {
+            (
+                _,
+                Gadri::Loi | Gadri::Lei | Gadri::Lai | Gadri::Lohi | Gadri::Lehi | Gadri::Lahi,
+                _,
+            ) => {
+                return (
+                    self.collective(gadri, desc_id, sumtis, selbris, sentences),
+                    vec![],
+                );
+            }
+            (_, Gadri::Lohe | Gadri::Lehe, _) => (SUHO, None),
+            (Some(quantifier), _, count) => (quantifier, count),
+            (None, Gadri::Lo, Some(count)) => (RO, Some(count)),
+            (None, Gadri::Lo, None) => (SUHO, None),
+            // le/la: non-quantified specific referent
+            (None, _, _) => {
+                let desc_str = self.get_selbri_head_name(desc_id, selbris);
+                return (
+                    LogicalTerm::Description(self.interner.get_or_intern(desc_str)),
+                    vec![],
+                );
+            }
+        };
+
+        let var = self.fresh_var();
+        (
+            LogicalTerm::Variable(var),
+            vec![QuantifierEntry {
+                var,
+                desc_id: Some(desc_id),
+                restrictor: None,
+                appositive: None,
+                typical: matches!(gadri, Gadri::Lohe | Gadri::Lehe),
+                quantifier,
+                count,
+            }],
+        )
+    }
+
+    /// A mass (loi/lei/lai) or set (lo'i/le'i/la'i) as one term named by
+    /// its gadri and description, e.g. Desc("lo'i gerku"). The veridical
+    /// ones also say what they are made of:
+    ///   lo'i → ∀x. (¬gerku(x) ∨ cmima(x, S)) ∧ ∀x. (¬cmima(x, S) ∨ gerku(x))
+    ///   loi  → ∃x. (gerku(x) ∧ gunma(M, x))
+    /// The others, like le and la, claim nothing about their members.
+    fn collective(
+        &mut self,
+        gadri: Gadri,
+        desc_id: u32,
+        sumtis: &[Sumti],
+        selbris: &[Selbri],
+        sentences: &[Bridi],
+    ) -> LogicalTerm {
+        let cmavo = match gadri {
+            Gadri::Loi => "loi",
+            Gadri::Lei => "lei",
+            Gadri::Lai => "lai",
+            Gadri::Lohi => "lo'i",
+            Gadri::Lehi => "le'i",
+            _ => "la'i",
+        };
+        let name = format!("{} {}", cmavo, self.get_selbri_head_name(desc_id, selbris));
+        let term = LogicalTerm::Description(self.interner.get_or_intern(name));
+
+        let var = self.fresh_var();
+        let member = LogicalTerm::Variable(var);
+        let claim = match gadri {
+            Gadri::Lohi => {
+                let described = self.describe(desc_id, var, selbris, sumtis, sentences);
+                let cmima = self.predicate("cmima", vec![member, term.clone()]);
+                let inclusion = |a: &LogicalForm, b: &LogicalForm| {
+                    LogicalForm::ForAll(
+                        var,
+                        Box::new(LogicalForm::Or(
+                            Box::new(LogicalForm::Not(Box::new(a.clone()))),
+                            Box::new(b.clone()),
+                        )),
+                    )
+                };
+                LogicalForm::And(
+                    Box::new(inclusion(&described, &cmima)),
+                    Box::new(inclusion(&cmima, &described)),
+                )
+            }
+            Gadri::Loi => {
+                let described = self.describe(desc_id, var, selbris, sumtis, sentences);
+                let gunma = self.predicate("gunma", vec![term.clone(), member]);
+                LogicalForm::Exists(
+                    var,
+                    Box::new(LogicalForm::And(Box::new(described), Box::new(gunma))),
+                )
+            }
+            _ => return term,
+        };
+        self.claims.push(claim);
+        term
+    }
+
+    /// The description selbri applied to `var`: gerku(x, zo'e).
+    fn describe(
+        &mut self,
+        desc_id: u32,
+        var: lasso::Spur,
+        selbris: &[Selbri],
+        sumtis: &[Sumti],
+        sentences: &[Bridi],
+    ) -> LogicalForm {
+        let desc_arity = self.get_selbri_arity(desc_id, selbris);
+        let mut args = Vec::with_capacity(desc_arity);
+        args.push(LogicalTerm::Variable(var));
+        while args.len() < desc_arity {
+            args.push(LogicalTerm::Unspecified);
+        }
+        self.apply_selbri(desc_id, &args, selbris, sumtis, sentences)
+    }
+
+    /// The ko'a-series cmavo a sumti is, if any.
+    fn koha(sumti: &Sumti) -> Option<&str> {
+        match sumti {
+            Sumti::ProSumti(p) if p.starts_with("ko'") && p.len() == 4 => Some(p.as_str()),
+            _ => None,
+        }
+    }
+
+    /// "la .alis. goi ko'a" / "ko'a goi la .alis.": bind the ko'a-series
+    /// side to the other sumti's referent. The bound cmavo keeps that
+    /// referent until reassigned, across statements. None if neither side
+    /// is a ko'a-series cmavo.
+    fn assign(
+        &mut self,
+        inner_id: u32,
+        target_id: u32,
+        sumtis: &[Sumti],
+        selbris: &[Selbri],
+        sentences: &[Bridi],
+    ) -> Option<(LogicalTerm, Vec<QuantifierEntry>)> {
+        let inner = &sumtis[inner_id as usize];
+        let target = &sumtis[target_id as usize];
+        let (koha, source) = match (Self::koha(inner), Self::koha(target)) {
+            (_, Some(koha)) => (koha, inner_id),
+            (Some(koha), None) => (koha, target_id),
+            (None, None) => return None,
+        };
+        let (term, quants) = self.lower_sumti(source, sumtis, selbris, sentences);
+        let referent = self.referent(term.clone(), &quants, selbris);
+        let koha = self.interner.get_or_intern(koha);
+        self.discourse.assign(koha, referent);
+        Some((term, quants))
+    }
+
+    /// What a relative clause says about the restricted `term`:
+    ///   poi/noi  → the clause body with `term` in its open place
+    ///   voi      → skicu(zo'e, x, zo'e, ⟨body⟩): x is described as body,
+    ///              which is not itself claimed
+    ///   pe/ne    → srana(x, y): generic association
+    ///   po       → ponse(y, x): y owns x
+    ///   po'e     → jinzi(x, y): x is intrinsic to y
+    ///   goi      → du(x, y), when there is no ko'a-series cmavo to bind
+    /// A specific description restricted by pe, po or po'e is named after
+    /// its possessor as well, so "le mi gerku" and "le do gerku" are two
+    /// referents: Desc("gerku pe mi") and Desc("gerku pe do").
+    fn restriction(
+        &mut self,
+        clause: &RelClause,
+        term: &mut LogicalTerm,
+        sumtis: &[Sumti],
+        selbris: &[Selbri],
+        sentences: &[Bridi],
+    ) -> LogicalForm {
+        let target_id = match clause.body {
+            RelClauseBody::Sentence(body_idx) => {
+                self.relativized.push((term.clone(), false));
+                let body =
+                    self.compile_bridi(&sentences[body_idx as usize], selbris, sumtis, sentences);
+                let body = match self.relativized.pop() {
+                    Some((_, true)) => body,
+                    _ => {
+                        if Self::binding_is_ambiguous(&body) {
+                            eprintln!(
+                                "[semantics] relative clause without ke'a has several open places; binding the first"
+                            );
+                        }
+                        Self::inject_term(body, term)
+                    }
+                };
+                if clause.kind != RelClauseKind::Voi {
+                    return body;
+                }
+                let args = vec![
+                    LogicalTerm::Unspecified,
+                    term.clone(),
+                    LogicalTerm::Unspecified,
+                    LogicalTerm::Proposition(Box::new(body)),
+                ];
+                return self.predicate("skicu", args);
+            }
+            RelClauseBody::Sumti(target_id) => target_id,
+        };
+
+        let (other, quants) = self.resolve_sumti(target_id, sumtis, selbris, sentences);
+        if let (
+            LogicalTerm::Description(desc),
+            LogicalTerm::Constant(owner) | LogicalTerm::Description(owner),
+        ) = (&*term, &other)
+        {
+            let phrase = match clause.kind {
+                RelClauseKind::Pe => Some("pe"),
+                RelClauseKind::Po => Some("po"),
+                RelClauseKind::Pohe => Some("po'e"),
+                _ => None,
+            };
+            if let Some(phrase) = phrase {
+                let name = format!(
+                    "{} {} {}",
+                    self.interner.resolve(desc),
+                    phrase,
+                    self.interner.resolve(owner)
+                );
+                *term = LogicalTerm::Description(self.interner.get_or_intern(name));
+            }
+        }
+        let mut form = match clause.kind {
+            RelClauseKind::Po => self.predicate("ponse", vec![other, term.clone()]),
+            RelClauseKind::Pohe => self.predicate("jinzi", vec![term.clone(), other]),
+            RelClauseKind::Goi => LogicalForm::Predicate {
+                relation: self.interner.get_or_intern("du"),
+                args: vec![term.clone(), other],
+            },
+            _ => self.predicate("srana", vec![term.clone(), other]),
+        };
+        for entry in quants.into_iter().rev() {
+            form = self.wrap_quantifier(entry, form, selbris, sumtis, sentences);
+        }
+        form
+    }
+
+    /// Add a relative clause to the quantifier of the term it is on.
+    /// Restrictive clauses narrow the domain; noi and ne are appositive
+    /// and hold of every member of it instead (see `wrap_quantifier`).
+    fn attach(entry: &mut QuantifierEntry, kind: RelClauseKind, form: LogicalForm) {
+        let slot = match kind {
+            RelClauseKind::Noi | RelClauseKind::Ne => &mut entry.appositive,
+            _ => &mut entry.restrictor,
+        };
+        *slot = Some(match slot.take() {
+            Some(existing) => LogicalForm::And(Box::new(existing), Box::new(form)),
+            None => form,
+        });
+    }
+
+    /// `relation` over `args`, padded with zo'e to its dictionary arity.
+    fn predicate(&mut self, relation: &str, args: Vec<LogicalTerm>) -> LogicalForm {
+        let arity = JbovlasteSchema::get_arity_or_default(relation);
+        LogicalForm::Predicate {
+            relation: self.interner.get_or_intern(relation),
+            args: Self::fit_args(&args, arity.max(args.len())),
         }
     }
 
-    fn inject_variable(form: LogicalForm, var: lasso::Spur) -> LogicalForm {
+    /// Without ke'a, the relativized place is the first zo'e. That guess
+    /// is ambiguous once x1 is filled and several places are left open:
+    /// "lo zarci poi mi klama" could be the destination or the origin.
+    fn binding_is_ambiguous(form: &LogicalForm) -> bool {
+        match form {
+            LogicalForm::Predicate { args, .. } => {
+                let open: Vec<usize> = args
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, a)| matches!(a, LogicalTerm::Unspecified))
+                    .map(|(i, _)| i)
+                    .collect();
+                open.len() > 1 && open[0] != 0
+            }
+            LogicalForm::And(l, r) => {
+                Self::binding_is_ambiguous(l) || Self::binding_is_ambiguous(r)
+            }
+            LogicalForm::Not(inner) => Self::binding_is_ambiguous(inner),
+            _ => false,
+        }
+    }
+
+    /// Fallback when the clause has no ke'a: bind `term` in the first
+    /// zo'e place of each predication.
+    fn inject_term(form: LogicalForm, term: &LogicalTerm) -> LogicalForm {
         match form {
             LogicalForm::Predicate { relation, mut args } => {
                 // Find the FIRST Unspecified slot in any position and bind the
@@ -173,19 +673,17 @@ impl SemanticCompiler {
                     .iter()
                     .position(|a| matches!(a, LogicalTerm::Unspecified));
                 if let Some(idx) = first_unspec {
-                    args[idx] = LogicalTerm::Variable(var);
+                    args[idx] = term.clone();
                 } else if args.is_empty() {
-                    args.push(LogicalTerm::Variable(var));
+                    args.push(term.clone());
                 }
                 LogicalForm::Predicate { relation, args }
             }
             LogicalForm::And(l, r) => LogicalForm::And(
-                Box::new(Self::inject_variable(*l, var)),
-                Box::new(Self::inject_variable(*r, var)),
+                Box::new(Self::inject_term(*l, term)),
+                Box::new(Self::inject_term(*r, term)),
             ),
-            LogicalForm::Not(inner) => {
-                LogicalForm::Not(Box::new(Self::inject_variable(*inner, var)))
-            }
+            LogicalForm::Not(inner) => LogicalForm::Not(Box::new(Self::inject_term(*inner, term))),
             other => other,
         }
     }
@@ -216,10 +714,18 @@ impl SemanticCompiler {
     ) -> LogicalForm {
         match &selbris[selbri_id as usize] {
             Selbri::Root(g) => {
-                let arity = JbovlasteSchema::get_arity_or_default(g.as_str());
-                LogicalForm::Predicate {
+                // mo is a predicate variable: it keeps the places it was given
+                let arity = match g.as_str() {
+                    "mo" => args.len(),
+                    g => JbovlasteSchema::get_arity_or_default(g),
+                };
+                let predicate = LogicalForm::Predicate {
                     relation: self.interner.get_or_intern(g.as_str()),
                     args: Self::fit_args(args, arity),
+                };
+                match JbovlasteSchema::decompose_lujvo(g.as_str()) {
+                    Some(components) => self.lujvo_entailments(g, &components, args, predicate),
+                    None => predicate,
                 }
             }
 
@@ -279,9 +785,7 @@ impl SemanticCompiler {
                 });
 
                 for bound_id in bound_ids.iter() {
-                    let bound_sumti = &sumtis[*bound_id as usize];
-                    let (term, quants) =
-                        self.resolve_sumti(bound_sumti, sumtis, selbris, sentences);
+                    let (term, quants) = self.resolve_sumti(*bound_id, sumtis, selbris, sentences);
                     inner_quantifiers.extend(quants);
                     merged.push(term);
                 }
@@ -298,40 +802,7 @@ impl SemanticCompiler {
                 let mut form = self.apply_selbri(*core_id, &merged, selbris, sumtis, sentences);
 
                 for entry in inner_quantifiers.into_iter().rev() {
-                    let desc_arity = self.get_selbri_arity(entry.desc_id, selbris);
-                    let mut restrictor_args = vec![LogicalTerm::Variable(entry.var)];
-                    while restrictor_args.len() < desc_arity {
-                        restrictor_args.push(LogicalTerm::Unspecified);
-                    }
-                    let restrictor = self.apply_selbri(
-                        entry.desc_id,
-                        &restrictor_args,
-                        selbris,
-                        sumtis,
-                        sentences,
-                    );
-
-                    if entry.universal {
-                        // ∀x. (restrictor → body) = ∀x. (¬restrictor ∨ body)
-                        let mut body = LogicalForm::Or(
-                            Box::new(LogicalForm::Not(Box::new(restrictor))),
-                            Box::new(form),
-                        );
-                        if let Some(rel_restrictor) = entry.restrictor {
-                            body = LogicalForm::Or(
-                                Box::new(LogicalForm::Not(Box::new(rel_restrictor))),
-                                Box::new(body),
-                            );
-                        }
-                        form = LogicalForm::ForAll(entry.var, Box::new(body));
-                    } else {
-                        // ∃x. (restrictor ∧ body)
-                        let mut body = LogicalForm::And(Box::new(restrictor), Box::new(form));
-                        if let Some(rel_restrictor) = entry.restrictor {
-                            body = LogicalForm::And(Box::new(rel_restrictor), Box::new(body));
-                        }
-                        form = LogicalForm::Exists(entry.var, Box::new(body));
-                    }
+                    form = self.wrap_quantifier(entry, form, selbris, sumtis, sentences);
                 }
 
                 form
@@ -345,27 +816,20 @@ impl SemanticCompiler {
                 let left = self.apply_selbri(*left_id, &left_args, selbris, sumtis, sentences);
                 let right = self.apply_selbri(*right_id, &right_args, selbris, sumtis, sentences);
 
-                match conn {
-                    Connective::Je => LogicalForm::And(Box::new(left), Box::new(right)),
-                    Connective::Ja => LogicalForm::Or(Box::new(left), Box::new(right)),
-                    Connective::Jo => {
-                        let not_l = LogicalForm::Not(Box::new(left.clone()));
-                        let not_r = LogicalForm::Not(Box::new(right.clone()));
-                        LogicalForm::And(
-                            Box::new(LogicalForm::Or(Box::new(not_l), Box::new(right))),
-                            Box::new(LogicalForm::Or(Box::new(not_r), Box::new(left))),
-                        )
-                    }
-                    Connective::Ju => LogicalForm::And(
-                        Box::new(LogicalForm::Or(
-                            Box::new(left.clone()),
-                            Box::new(right.clone()),
-                        )),
-                        Box::new(LogicalForm::Not(Box::new(LogicalForm::And(
-                            Box::new(left),
-                            Box::new(right),
-                        )))),
-                    ),
+                Self::connect(*conn, left, right)
+            }
+
+            Selbri::Abstraction((kind, body_idx)) => {
+                // x1 is the abstraction itself; the reified body follows it:
+                // "ti nu mi klama" → nu(ti, ⟨klama(mi, …)⟩)
+                let body =
+                    self.compile_bridi(&sentences[*body_idx as usize], selbris, sumtis, sentences);
+                let body = self.close_free_variables(body);
+                let mut fitted = Self::fit_args(args, Self::abstraction_arity(*kind));
+                fitted.insert(1, LogicalTerm::Proposition(Box::new(body)));
+                LogicalForm::Predicate {
+                    relation: self.interner.get_or_intern(Self::abstraction_name(*kind)),
+                    args: fitted,
                 }
             }
 
@@ -380,8 +844,725 @@ impl SemanticCompiler {
         }
     }
 
+    /// Conjoins a lujvo predicate with the component gismu it is built
+    /// from: "gerzda" → gerzda(x1, x2) ∧ gerku(x2, zo'e) ∧ zdani(x1, x2).
+    ///
+    /// Places follow the jbovlaste mapping when the lujvo has one; a
+    /// component it never mentions stays fully unspecified. Without a
+    /// mapping the lujvo reads like its tanru: the head (last) component
+    /// takes every place and the modifiers share only x1.
+    fn lujvo_entailments(
+        &mut self,
+        lujvo: &str,
+        components: &[&str],
+        args: &[LogicalTerm],
+        predicate: LogicalForm,
+    ) -> LogicalForm {
+        let mappings = JbovlasteSchema::get_lujvo_places(lujvo);
+        let head = components.len() - 1;
+        let mut form = predicate;
+
+        for (i, gismu) in components.iter().enumerate() {
+            let arity = JbovlasteSchema::get_arity_or_default(gismu);
+            let component_args = match mappings {
+                Some(mappings) => {
+                    let mut fitted = vec![LogicalTerm::Unspecified; arity];
+                    for &(lujvo_place, letter, place) in mappings {
+                        // A letter names the first component with that initial
+                        let owner = components.iter().position(|c| c.starts_with(letter));
+                        if owner == Some(i) && place <= arity && lujvo_place <= args.len() {
+                            fitted[place - 1] = args[lujvo_place - 1].clone();
+                        }
+                    }
+                    fitted
+                }
+                None if i == head => Self::fit_args(args, arity),
+                None => Self::fit_args(&args[..args.len().min(1)], arity),
+            };
+
+            let component = LogicalForm::Predicate {
+                relation: self.interner.get_or_intern(gismu),
+                args: component_args,
+            };
+            form = LogicalForm::And(Box::new(form), Box::new(component));
+        }
+        form
+    }
+
+    // ─── Logical Connectives ─────────────────────────────────────
+
+    fn connect(conn: Connective, left: LogicalForm, right: LogicalForm) -> LogicalForm {
+        match conn {
+            Connective::Je => LogicalForm::And(Box::new(left), Box::new(right)),
+            Connective::Ja => LogicalForm::Or(Box::new(left), Box::new(right)),
+            Connective::Jo => {
+                let not_l = LogicalForm::Not(Box::new(left.clone()));
+                let not_r = LogicalForm::Not(Box::new(right.clone()));
+                LogicalForm::And(
+                    Box::new(LogicalForm::Or(Box::new(not_l), Box::new(right))),
+                    Box::new(LogicalForm::Or(Box::new(not_r), Box::new(left))),
+                )
+            }
+            // A whether or not B: B claims nothing
+            Connective::Ju => left,
+        }
+    }
+
+    /// se? GA nai? A gi nai? B — `se` swaps the operands before the `nai`
+    /// negations apply, so "se ganai A gi B" is B → A.
+    fn connect_logical(
+        conn: &LogicalConnective,
+        left: LogicalForm,
+        right: LogicalForm,
+    ) -> LogicalForm {
+        let (left, right) = if conn.converted {
+            (right, left)
+        } else {
+            (left, right)
+        };
+
+        // ganai A gi B → A → B
+        if conn.connective == Connective::Ja && conn.negate_left && !conn.negate_right {
+            return LogicalForm::Implies(Box::new(left), Box::new(right));
+        }
+
+        let left = if conn.negate_left {
+            LogicalForm::Not(Box::new(left))
+        } else {
+            left
+        };
+        let right = if conn.negate_right {
+            LogicalForm::Not(Box::new(right))
+        } else {
+            right
+        };
+        Self::connect(conn.connective, left, right)
+    }
+
+    /// The first connected sumti a bridi expands, as (slot id,
+    /// connective, left, right): one among its terms or in a be place of
+    /// its selbri, looking through tags, quantifiers, descriptions and
+    /// pe/ne/po/goi clauses. Relative clause and abstraction bodies are
+    /// bridi of their own and expand their connected sumti themselves.
+    fn find_connected_sumti(
+        bridi: &Bridi,
+        selbris: &[Selbri],
+        sumtis: &[Sumti],
+    ) -> Option<(u32, LogicalConnective, u32, u32)> {
+        bridi
+            .head_terms
+            .iter()
+            .chain(bridi.tail_terms.iter())
+            .find_map(|&term_id| Self::connected_in_sumti(term_id, selbris, sumtis))
+            .or_else(|| Self::connected_in_selbri(bridi.relation, selbris, sumtis))
+    }
+
+    fn connected_in_sumti(
+        sumti_id: u32,
+        selbris: &[Selbri],
+        sumtis: &[Sumti],
+    ) -> Option<(u32, LogicalConnective, u32, u32)> {
+        match &sumtis[sumti_id as usize] {
+            Sumti::Connected((left_id, conn, right_id)) => {
+                Some((sumti_id, *conn, *left_id, *right_id))
+            }
+            Sumti::ModalTagged((ModalTag::Fiho(selbri_id), inner_id)) => {
+                Self::connected_in_selbri(*selbri_id, selbris, sumtis)
+                    .or_else(|| Self::connected_in_sumti(*inner_id, selbris, sumtis))
+            }
+            Sumti::Tagged((_, inner_id))
+            | Sumti::TenseTagged((_, inner_id))
+            | Sumti::ModalTagged((_, inner_id))
+            | Sumti::Quantified((_, inner_id)) => {
+                Self::connected_in_sumti(*inner_id, selbris, sumtis)
+            }
+            Sumti::Description((_, _, desc_id)) => {
+                Self::connected_in_selbri(*desc_id, selbris, sumtis)
+            }
+            Sumti::Restricted((inner_id, rel_clause)) => {
+                Self::connected_in_sumti(*inner_id, selbris, sumtis).or_else(|| {
+                    match rel_clause.body {
+                        RelClauseBody::Sumti(target_id) => {
+                            Self::connected_in_sumti(target_id, selbris, sumtis)
+                        }
+                        RelClauseBody::Sentence(_) => None,
+                    }
+                })
+            }
+            _ => None,
+        }
+    }
+
+    fn connected_in_selbri(
+        selbri_id: u32,
+        selbris: &[Selbri],
+        sumtis: &[Sumti],
+    ) -> Option<(u32, LogicalConnective, u32, u32)> {
+        match &selbris[selbri_id as usize] {
+            Selbri::WithArgs((core_id, args)) => {
+                Self::connected_in_selbri(*core_id, selbris, sumtis).or_else(|| {
+                    args.iter()
+                        .find_map(|&arg_id| Self::connected_in_sumti(arg_id, selbris, sumtis))
+                })
+            }
+            Selbri::Tanru((left_id, right_id)) | Selbri::Connected((left_id, _, right_id)) => {
+                Self::connected_in_selbri(*left_id, selbris, sumtis)
+                    .or_else(|| Self::connected_in_selbri(*right_id, selbris, sumtis))
+            }
+            Selbri::Converted((_, inner_id))
+            | Selbri::Negated(inner_id)
+            | Selbri::Grouped(inner_id) => Self::connected_in_selbri(*inner_id, selbris, sumtis),
+            _ => None,
+        }
+    }
+
+    // ─── Quantifiers ─────────────────────────────────────────────
+
+    /// Wrap `body` in the quantifier of `entry`, restricted by its
+    /// description and relative clause. An inner quantifier adds a
+    /// separate count of the description: "re lo ci gerku cu blabi" →
+    /// (exactly 3 gerku) ∧ (exactly 2 gerku are blabi).
+    /// An appositive is claimed with the body: "su'o lo gerku noi blanu
+    /// cu sutra" → ∃x. (gerku(x) ∧ sutra(x) ∧ blanu(x)), and under ro
+    /// ∀x. (¬gerku(x) ∨ (sutra(x) ∧ blanu(x))).
+    fn wrap_quantifier(
+        &mut self,
+        entry: QuantifierEntry,
+        body: LogicalForm,
+        selbris: &[Selbri],
+        sumtis: &[Sumti],
+        sentences: &[Bridi],
+    ) -> LogicalForm {
+        let mut restrictors = Vec::new();
+        if let Some(desc_id) = entry.desc_id {
+            restrictors.push(self.describe(desc_id, entry.var, selbris, sumtis, sentences));
+        }
+        restrictors.extend(entry.restrictor);
+
+        // The appositive is claimed of each thing the quantifier picks
+        // out, with the body. Nothing is picked out under no, so there it
+        // claims nothing.
+        let body = match entry.appositive {
+            Some(_)
+                if entry.quantifier.kind == QuantifierKind::Exactly
+                    && entry.quantifier.count == 0 =>
+            {
+                eprintln!("[semantics] noi/ne clause under no claims nothing; dropped");
+                body
+            }
+            Some(appositive) => LogicalForm::And(Box::new(body), Box::new(appositive)),
+            None => body,
+        };
+
+        // lo'e gerku cu citka → typically, gerku(x) ⇒ citka(x)
+        if entry.typical {
+            let restrictor = restrictors
+                .into_iter()
+                .reduce(|a, b| LogicalForm::And(Box::new(a), Box::new(b)));
+            if let Some(restrictor) = restrictor {
+                return LogicalForm::Generic(entry.var, Box::new(restrictor), Box::new(body));
+            }
+            return body;
+        }
+
+        let count = match entry.count {
+            Some(count) if count.kind != QuantifierKind::All => {
+                let y = self.fresh_var();
+                let described = restrictors
+                    .iter()
+                    .map(|r| Self::rename_variable(r, entry.var, y))
+                    .reduce(|a, b| LogicalForm::And(Box::new(a), Box::new(b)));
+                described.map(|d| self.quantify(count, y, Vec::new(), d))
+            }
+            _ => None,
+        };
+
+        let form = self.quantify(entry.quantifier, entry.var, restrictors, body);
+        match count {
+            Some(count) => LogicalForm::And(Box::new(count), Box::new(form)),
+            None => form,
+        }
+    }
+
+    /// Quantify `var` over `body` under `restrictors` (R):
+    ///   ro       → ∀x. (¬R ∨ body)
+    ///   su'o     → ∃x. (R ∧ body)
+    ///   no       → ¬∃x. (R ∧ body)
+    ///   su'o N   → ∃x1…xN. S(x1) ∧ … ∧ S(xN) ∧ ⋀ ¬du(xi, xj), S = R ∧ body
+    ///   N        → the same ∧ ∀y. (¬S(y) ∨ du(y, x1) ∨ … ∨ du(y, xN))
+    fn quantify(
+        &mut self,
+        quantifier: Quantifier,
+        var: lasso::Spur,
+        restrictors: Vec<LogicalForm>,
+        body: LogicalForm,
+    ) -> LogicalForm {
+        if quantifier.kind == QuantifierKind::All {
+            let body = restrictors.into_iter().fold(body, |body, r| {
+                LogicalForm::Or(Box::new(LogicalForm::Not(Box::new(r))), Box::new(body))
+            });
+            return LogicalForm::ForAll(var, Box::new(body));
+        }
+
+        let scope = restrictors.into_iter().fold(body, |body, r| {
+            LogicalForm::And(Box::new(r), Box::new(body))
+        });
+        let exactly = quantifier.kind == QuantifierKind::Exactly;
+        match quantifier.count {
+            0 if exactly => LogicalForm::Not(Box::new(LogicalForm::Exists(var, Box::new(scope)))),
+            0 | 1 if !exactly => LogicalForm::Exists(var, Box::new(scope)),
+            n => {
+                let mut vars = vec![var];
+                for _ in 1..n {
+                    vars.push(self.fresh_var());
+                }
+
+                let mut form = vars
+                    .iter()
+                    .map(|v| Self::rename_variable(&scope, var, *v))
+                    .reduce(|a, b| LogicalForm::And(Box::new(a), Box::new(b)))
+                    .expect("at least one variable");
+                for (i, a) in vars.iter().enumerate() {
+                    for b in &vars[i + 1..] {
+                        let distinct = LogicalForm::Not(Box::new(self.identity(*a, *b)));
+                        form = LogicalForm::And(Box::new(form), Box::new(distinct));
+                    }
+                }
+
+                if exactly {
+                    let y = self.fresh_var();
+                    let others = vars
+                        .iter()
+                        .map(|v| self.identity(y, *v))
+                        .reduce(|a, b| LogicalForm::Or(Box::new(a), Box::new(b)))
+                        .expect("at least one variable");
+                    let outside = LogicalForm::Not(Box::new(Self::rename_variable(&scope, var, y)));
+                    let unique = LogicalForm::ForAll(
+                        y,
+                        Box::new(LogicalForm::Or(Box::new(outside), Box::new(others))),
+                    );
+                    form = LogicalForm::And(Box::new(form), Box::new(unique));
+                }
+
+                vars.into_iter()
+                    .rev()
+                    .fold(form, |body, v| LogicalForm::Exists(v, Box::new(body)))
+            }
+        }
+    }
+
+    fn identity(&mut self, a: lasso::Spur, b: lasso::Spur) -> LogicalForm {
+        LogicalForm::Predicate {
+            relation: self.interner.get_or_intern("du"),
+            args: vec![LogicalTerm::Variable(a), LogicalTerm::Variable(b)],
+        }
+    }
+
+    /// Replace the free occurrences of `from` in `form` with `to`.
+    fn rename_variable(form: &LogicalForm, from: lasso::Spur, to: lasso::Spur) -> LogicalForm {
+        let rename = |f: &LogicalForm| Box::new(Self::rename_variable(f, from, to));
+        match form {
+            LogicalForm::Predicate { relation, args } => LogicalForm::Predicate {
+                relation: *relation,
+                args: args
+                    .iter()
+                    .map(|arg| Self::rename_term(arg, from, to))
+                    .collect(),
+            },
+            LogicalForm::ForAll(v, _)
+            | LogicalForm::Exists(v, _)
+            | LogicalForm::Generic(v, _, _)
+                if *v == from =>
+            {
+                form.clone()
+            }
+            LogicalForm::ForAll(v, body) => LogicalForm::ForAll(*v, rename(body)),
+            LogicalForm::Exists(v, body) => LogicalForm::Exists(*v, rename(body)),
+            LogicalForm::And(l, r) => LogicalForm::And(rename(l), rename(r)),
+            LogicalForm::Or(l, r) => LogicalForm::Or(rename(l), rename(r)),
+            LogicalForm::Implies(l, r) => LogicalForm::Implies(rename(l), rename(r)),
+            LogicalForm::Not(inner) => LogicalForm::Not(rename(inner)),
+            LogicalForm::At(time, body) => {
+                LogicalForm::At(Self::rename_term(time, from, to), rename(body))
+            }
+            LogicalForm::Generic(v, restrictor, body) => {
+                LogicalForm::Generic(*v, rename(restrictor), rename(body))
+            }
+        }
+    }
+
+    fn rename_term(term: &LogicalTerm, from: lasso::Spur, to: lasso::Spur) -> LogicalTerm {
+        match term {
+            LogicalTerm::Variable(v) if *v == from => LogicalTerm::Variable(to),
+            LogicalTerm::Proposition(inner) => {
+                LogicalTerm::Proposition(Box::new(Self::rename_variable(inner, from, to)))
+            }
+            other => other.clone(),
+        }
+    }
+
+    // ─── Statements ──────────────────────────────────────────────
+
+    /// Compile a root statement. Any da/de/di left unbound by a prenex
+    /// is existentially closed over the whole statement (CLL 16.2); ma
+    /// stays free for the question to bind.
+    pub fn compile_statement(
+        &mut self,
+        statement_id: u32,
+        statements: &[Statement],
+        selbris: &[Selbri],
+        sumtis: &[Sumti],
+        sentences: &[Bridi],
+    ) -> LogicalForm {
+        let form = self.lower_statement(statement_id, statements, selbris, sumtis, sentences);
+        self.discourse.close_statement();
+        let form = self.close_free_variables(form);
+        self.questions.clear();
+        form
+    }
+
+    /// What the statements compiled since the last call could not lower.
+    pub fn take_errors(&mut self) -> Vec<String> {
+        std::mem::take(&mut self.errors)
+    }
+
+    fn lower_statement(
+        &mut self,
+        statement_id: u32,
+        statements: &[Statement],
+        selbris: &[Selbri],
+        sumtis: &[Sumti],
+        sentences: &[Bridi],
+    ) -> LogicalForm {
+        match &statements[statement_id as usize] {
+            Statement::Simple(bridi_idx) => self.lower_bridi(
+                &sentences[*bridi_idx as usize],
+                true,
+                selbris,
+                sumtis,
+                sentences,
+            ),
+            Statement::Connected((conn, left_id, right_id)) => {
+                let left = self.lower_statement(*left_id, statements, selbris, sumtis, sentences);
+                let right = self.lower_statement(*right_id, statements, selbris, sumtis, sentences);
+                Self::connect_logical(conn, left, right)
+            }
+            Statement::Prenex((terms, body_id)) => {
+                let scope_len = self.prenex_scope.len();
+
+                // Bind each variable (and compile its restrictor) left to
+                // right, so later terms and the body see earlier variables.
+                let mut bound = Vec::with_capacity(terms.len());
+                for term in terms {
+                    match term {
+                        PrenexTerm::Variable((quantifier, sumti_id)) => {
+                            let mut entry =
+                                self.prenex_variable(*sumti_id, sumtis, selbris, sentences);
+                            entry.quantifier = quantifier.unwrap_or(SUHO);
+                            self.prenex_scope.push(entry.var);
+                            bound.push(Some(entry));
+                        }
+                        PrenexTerm::Naku => bound.push(None),
+                    }
+                }
+
+                let mut form =
+                    self.lower_statement(*body_id, statements, selbris, sumtis, sentences);
+                self.prenex_scope.truncate(scope_len);
+
+                // Wrap inner-to-outer: the leftmost term has widest scope.
+                for entry in bound.into_iter().rev() {
+                    form = match entry {
+                        None => LogicalForm::Not(Box::new(form)),
+                        Some(entry) => {
+                            let var = entry.var;
+                            let form =
+                                self.wrap_quantifier(entry, form, selbris, sumtis, sentences);
+                            self.discourse.close_scope(var);
+                            form
+                        }
+                    };
+                }
+
+                form
+            }
+        }
+    }
+
+    /// The quantifier entry for a prenex term, with its relative clauses
+    /// ("da poi gerku") attached. Its quantifier is su'o until the caller
+    /// sets the one written in the prenex.
+    fn prenex_variable(
+        &mut self,
+        sumti_id: u32,
+        sumtis: &[Sumti],
+        selbris: &[Selbri],
+        sentences: &[Bridi],
+    ) -> QuantifierEntry {
+        let var = match &sumtis[sumti_id as usize] {
+            Sumti::ProSumti(v) => self.interner.get_or_intern(v.as_str()),
+            Sumti::Restricted((inner_id, rel_clause)) => {
+                let mut entry = self.prenex_variable(*inner_id, sumtis, selbris, sentences);
+                let mut term = LogicalTerm::Variable(entry.var);
+                // "da goi ko'a zo'u": ko'a is da within the statement
+                let koha = match (rel_clause.kind, rel_clause.body) {
+                    (RelClauseKind::Goi, RelClauseBody::Sumti(target_id)) => {
+                        Self::koha(&sumtis[target_id as usize])
+                    }
+                    _ => None,
+                };
+                if let Some(koha) = koha {
+                    let koha = self.interner.get_or_intern(koha);
+                    let referent = Referent {
+                        term,
+                        fallback: LogicalTerm::Unspecified,
+                    };
+                    self.discourse.assign(koha, referent);
+                    return entry;
+                }
+                let rel_body = self.restriction(rel_clause, &mut term, sumtis, selbris, sentences);
+                Self::attach(&mut entry, rel_clause.kind, rel_body);
+                return entry;
+            }
+            // The parser only puts da/de/di (+ rel clauses) in a prenex.
+            _ => self.fresh_var(),
+        };
+        QuantifierEntry {
+            var,
+            desc_id: None,
+            restrictor: None,
+            appositive: None,
+            typical: false,
+            quantifier: SUHO,
+            count: None,
+        }
+    }
+
+    /// Existentially close the da-series variables of `form` that are not
+    /// bound by a quantifier inside it or by an enclosing prenex. The
+    /// first-mentioned variable gets the widest scope.
+    fn close_free_variables(&self, form: LogicalForm) -> LogicalForm {
+        let mut bound = self.prenex_scope.clone();
+        bound.extend(self.questions.iter().map(|&(_, var)| var));
+        let mut free = Vec::new();
+        Self::collect_free_variables(&form, &mut bound, &mut free);
+        free.into_iter()
+            .rev()
+            .fold(form, |body, var| LogicalForm::Exists(var, Box::new(body)))
+    }
+
+    fn collect_free_variables(
+        form: &LogicalForm,
+        bound: &mut Vec<lasso::Spur>,
+        free: &mut Vec<lasso::Spur>,
+    ) {
+        match form {
+            LogicalForm::Predicate { args, .. } => {
+                for arg in args {
+                    Self::collect_free_term(arg, bound, free);
+                }
+            }
+            LogicalForm::ForAll(v, body) | LogicalForm::Exists(v, body) => {
+                bound.push(*v);
+                Self::collect_free_variables(body, bound, free);
+                bound.pop();
+            }
+            LogicalForm::And(l, r) | LogicalForm::Or(l, r) | LogicalForm::Implies(l, r) => {
+                Self::collect_free_variables(l, bound, free);
+                Self::collect_free_variables(r, bound, free);
+            }
+            LogicalForm::Not(inner) => Self::collect_free_variables(inner, bound, free),
+            LogicalForm::At(time, body) => {
+                Self::collect_free_term(time, bound, free);
+                Self::collect_free_variables(body, bound, free);
+            }
+            LogicalForm::Generic(v, restrictor, body) => {
+                bound.push(*v);
+                Self::collect_free_variables(restrictor, bound, free);
+                Self::collect_free_variables(body, bound, free);
+                bound.pop();
+            }
+        }
+    }
+
+    fn collect_free_term(
+        term: &LogicalTerm,
+        bound: &mut Vec<lasso::Spur>,
+        free: &mut Vec<lasso::Spur>,
+    ) {
+        match term {
+            LogicalTerm::Variable(v) if !bound.contains(v) && !free.contains(v) => free.push(*v),
+            LogicalTerm::Proposition(inner) => Self::collect_free_variables(inner, bound, free),
+            _ => {}
+        }
+    }
+
+    // ─── Tense Lowering ──────────────────────────────────────────
+
+    fn temporal(&mut self, relation: &str, a: LogicalTerm, b: LogicalTerm) -> LogicalForm {
+        LogicalForm::Predicate {
+            relation: self.interner.get_or_intern(relation),
+            args: vec![a, b],
+        }
+    }
+
+    /// Wrap a bridi body as a time-indexed formula over a fresh event
+    /// interval e:  ∃e. At(e, body) ∧ constraints
+    ///
+    /// The sentence tense is measured from the `now` constant; each sumti
+    /// tcita is measured from its own reference. An event reference
+    /// ("pu lo nu do klama") is anchored to an interval of its own.
+    fn apply_tenses(
+        &mut self,
+        body: LogicalForm,
+        sentence_tense: Option<Tense>,
+        tagged: Vec<(Tense, LogicalTerm)>,
+    ) -> LogicalForm {
+        let event = self.fresh_var();
+        let mut bound = vec![event];
+        let mut form = LogicalForm::At(LogicalTerm::Variable(event), Box::new(body));
+
+        let now = LogicalTerm::Constant(self.interner.get_or_intern("now"));
+        let refs: Vec<(Tense, LogicalTerm)> = sentence_tense
+            .map(|t| (t, now))
+            .into_iter()
+            .chain(tagged)
+            .collect();
+
+        for (tense, reference) in refs {
+            let reference = match reference {
+                LogicalTerm::Proposition(prop) => {
+                    let r = self.fresh_var();
+                    bound.push(r);
+                    form = LogicalForm::And(
+                        Box::new(form),
+                        Box::new(LogicalForm::At(LogicalTerm::Variable(r), prop)),
+                    );
+                    LogicalTerm::Variable(r)
+                }
+                other => other,
+            };
+            for constraint in self.tense_constraints(tense, event, reference, &mut bound) {
+                form = LogicalForm::And(Box::new(form), Box::new(constraint));
+            }
+        }
+
+        for var in bound.into_iter().rev() {
+            form = LogicalForm::Exists(var, Box::new(form));
+        }
+        form
+    }
+
+    /// Interval constraints for one tense, in Allen-style relations:
+    ///   PU:   pu → before(p, ref), ba → before(ref, p), ca → during(p, ref)
+    ///   ZI:   zi/za/zu(p, ref)
+    ///   ZAhO: ca'o → during(p, e), co'a → meets(p, e), co'u → meets(e, p)
+    ///
+    /// Without an aspect, the tensed point p is the event interval e itself.
+    /// With a bare aspect ("mi ca'o klama"), p is the reference point.
+    fn tense_constraints(
+        &mut self,
+        tense: Tense,
+        event: lasso::Spur,
+        reference: LogicalTerm,
+        bound: &mut Vec<lasso::Spur>,
+    ) -> Vec<LogicalForm> {
+        let e = LogicalTerm::Variable(event);
+        let point = if tense.aspect.is_none() {
+            e.clone()
+        } else if tense.direction.is_none() && tense.distance.is_none() {
+            reference.clone()
+        } else {
+            let p = self.fresh_var();
+            bound.push(p);
+            LogicalTerm::Variable(p)
+        };
+
+        let mut constraints = Vec::new();
+
+        if let Some(aspect) = tense.aspect {
+            constraints.push(match aspect {
+                Aspect::Caho => self.temporal("during", point.clone(), e.clone()),
+                Aspect::Coha => self.temporal("meets", point.clone(), e.clone()),
+                Aspect::Cohu => self.temporal("meets", e.clone(), point.clone()),
+            });
+        }
+
+        if let Some(direction) = tense.direction {
+            constraints.push(match direction {
+                TimeDirection::Pu => self.temporal("before", point.clone(), reference.clone()),
+                TimeDirection::Ba => self.temporal("before", reference.clone(), point.clone()),
+                TimeDirection::Ca => self.temporal("during", point.clone(), reference.clone()),
+            });
+        }
+
+        if let Some(distance) = tense.distance {
+            let name = match distance {
+                TimeDistance::Zi => "zi",
+                TimeDistance::Za => "za",
+                TimeDistance::Zu => "zu",
+            };
+            constraints.push(self.temporal(name, point, reference));
+        }
+
+        constraints
+    }
+
+    // ─── Observatives ────────────────────────────────────────────
+
+    /// The term for an observative's elided x1: a fresh variable (recorded
+    /// in `bound` for ∃-closure) at the top level, zo'e otherwise.
+    fn elided_x1(&mut self, bind: bool, bound: &mut Option<lasso::Spur>) -> LogicalTerm {
+        if !bind {
+            return LogicalTerm::Unspecified;
+        }
+        let var = self.fresh_var();
+        *bound = Some(var);
+        LogicalTerm::Variable(var)
+    }
+
+    /// Treat co'e predications as true where they are conjuncts:
+    /// ∃x. (gerku(x) ∧ co'e(x)) → ∃x. gerku(x).
+    fn drop_coe(&mut self, form: LogicalForm) -> LogicalForm {
+        let coe = self.interner.get_or_intern("co'e");
+        Self::drop_conjunct(form, coe)
+    }
+
+    fn drop_conjunct(form: LogicalForm, relation: lasso::Spur) -> LogicalForm {
+        let is_dropped = |f: &LogicalForm| matches!(f, LogicalForm::Predicate { relation: r, .. } if *r == relation);
+        match form {
+            LogicalForm::And(l, r) if is_dropped(&r) => Self::drop_conjunct(*l, relation),
+            LogicalForm::And(l, r) if is_dropped(&l) => Self::drop_conjunct(*r, relation),
+            LogicalForm::And(l, r) => LogicalForm::And(
+                Box::new(Self::drop_conjunct(*l, relation)),
+                Box::new(Self::drop_conjunct(*r, relation)),
+            ),
+            LogicalForm::Or(l, r) => LogicalForm::Or(
+                Box::new(Self::drop_conjunct(*l, relation)),
+                Box::new(Self::drop_conjunct(*r, relation)),
+            ),
+            LogicalForm::Not(inner) => {
+                LogicalForm::Not(Box::new(Self::drop_conjunct(*inner, relation)))
+            }
+            LogicalForm::Exists(v, body) => {
+                LogicalForm::Exists(v, Box::new(Self::drop_conjunct(*body, relation)))
+            }
+            LogicalForm::ForAll(v, body) => {
+                LogicalForm::ForAll(v, Box::new(Self::drop_conjunct(*body, relation)))
+            }
+            LogicalForm::Generic(v, restrictor, body) => LogicalForm::Generic(
+                v,
+                restrictor,
+                Box::new(Self::drop_conjunct(*body, relation)),
+            ),
+            other => other,
+        }
+    }
+
     // ─── Top-Level Bridi Compilation ─────────────────────────────
 
+    /// Compile an embedded bridi (rel clause or abstraction body). An
+    /// elided x1 stays zo'e: it is the place ke'a fills.
     pub fn compile_bridi(
         &mut self,
         bridi: &Bridi,
@@ -389,19 +1570,112 @@ impl SemanticCompiler {
         sumtis: &[Sumti],
         sentences: &[Bridi],
     ) -> LogicalForm {
-        let target_arity = self.get_selbri_arity(bridi.relation, selbris);
+        self.lower_bridi(bridi, false, selbris, sumtis, sentences)
+    }
+
+    /// zo'e policy: unfilled places lower to `Unspecified` (zo'e), except
+    /// the elided x1 of a top-level observative ("carvi", "klama lo
+    /// zarci"), which is "something": ∃x. carvi(x, zo'e). The elliptical
+    /// selbri co'e claims nothing beyond its arguments, so "lo gerku"
+    /// (= "lo gerku cu co'e") is just ∃x. gerku(x).
+    fn lower_bridi(
+        &mut self,
+        bridi: &Bridi,
+        bind_elided_x1: bool,
+        selbris: &[Selbri],
+        sumtis: &[Sumti],
+        sentences: &[Bridi],
+    ) -> LogicalForm {
+        // Connected sumti expand to connected bridi, wherever in the bridi
+        // they sit: "ge mi gi do klama" → klama(mi, …) ∧ klama(do, …).
+        // Each operand replaces the connected slot in a copy of the arena;
+        // sentence negation scopes over the whole expansion.
+        if let Some((slot, conn, left_id, right_id)) =
+            Self::find_connected_sumti(bridi, selbris, sumtis)
+        {
+            let mut core = bridi.clone();
+            core.negated = false;
+
+            let mut left_sumtis = sumtis.to_vec();
+            left_sumtis[slot as usize] = sumtis[left_id as usize].clone();
+            self.operands.push((slot, left_id));
+            let left = self.lower_bridi(&core, bind_elided_x1, selbris, &left_sumtis, sentences);
+            self.operands.pop();
+
+            let mut right_sumtis = sumtis.to_vec();
+            right_sumtis[slot as usize] = sumtis[right_id as usize].clone();
+            self.operands.push((slot, right_id));
+            let right = self.lower_bridi(&core, bind_elided_x1, selbris, &right_sumtis, sentences);
+            self.operands.pop();
+
+            let form = Self::connect_logical(&conn, left, right);
+            return if bridi.negated {
+                LogicalForm::Not(Box::new(form))
+            } else {
+                form
+            };
+        }
+
+        let claims_len = self.claims.len();
+
+        // go'i repeats the previous main bridi with the places it fills
+        // replaced; with nothing to repeat it is co'e.
+        let goi = matches!(&selbris[bridi.relation as usize], Selbri::Root(r) if r == "go'i");
+        let previous = self.discourse.previous().filter(|_| goi).cloned();
+        let coe = goi && previous.is_none()
+            || matches!(&selbris[bridi.relation as usize], Selbri::Root(r) if r == "co'e");
+        let target_arity = match &previous {
+            Some(previous) => previous.arity(),
+            None => self.get_selbri_arity(bridi.relation, selbris),
+        };
 
         let mut positioned: Vec<Option<LogicalTerm>> = vec![None; target_arity];
         let mut untagged: Vec<LogicalTerm> = Vec::new();
         let mut quantifiers: Vec<QuantifierEntry> = Vec::new();
+        let mut tense_refs: Vec<(Tense, LogicalTerm)> = Vec::new();
+        let mut modal_refs: Vec<(String, LogicalTerm)> = Vec::new();
+        let mut elided_x1 = None;
+
+        for (i, &term_id) in bridi
+            .head_terms
+            .iter()
+            .chain(bridi.tail_terms.iter())
+            .enumerate()
+        {
+            // Observative: nothing before the selbri filled x1, so the
+            // tail starts at x2 (CLL 2.9).
+            if i == bridi.head_terms.len()
+                && untagged.is_empty()
+                && positioned.first() == Some(&None)
+            {
+                positioned[0] = Some(self.elided_x1(bind_elided_x1, &mut elided_x1));
+            }
 
-        for &term_id in bridi.head_terms.iter().chain(bridi.tail_terms.iter()) {
             let sumti = &sumtis[term_id as usize];
 
             match sumti {
+                // Sumti tcita: a time reference, not an argument place
+                Sumti::TenseTagged((tense, inner_id)) => {
+                    let (term, quants) = self.resolve_sumti(*inner_id, sumtis, selbris, sentences);
+                    quantifiers.extend(quants);
+                    tense_refs.push((*tense, term));
+                }
+                // BAI / fi'o: an extra place outside the selbri's own structure
+                Sumti::ModalTagged((tag, inner_id)) => {
+                    let gismu = match tag {
+                        ModalTag::Bai(cmavo) => JbovlasteSchema::get_bai_gismu(cmavo)
+                            .unwrap_or(cmavo.as_str())
+                            .to_string(),
+                        ModalTag::Fiho(selbri_id) => {
+                            self.get_selbri_head_name(*selbri_id, selbris).to_string()
+                        }
+                    };
+                    let (term, quants) = self.resolve_sumti(*inner_id, sumtis, selbris, sentences);
+                    quantifiers.extend(quants);
+                    modal_refs.push((gismu, term));
+                }
                 Sumti::Tagged((tag, inner_id)) => {
-                    let inner = &sumtis[*inner_id as usize];
-                    let (term, quants) = self.resolve_sumti(inner, sumtis, selbris, sentences);
+                    let (term, quants) = self.resolve_sumti(*inner_id, sumtis, selbris, sentences);
                     quantifiers.extend(quants);
                     let idx = match tag {
                         PlaceTag::Fa => 0,
@@ -414,14 +1688,18 @@ impl SemanticCompiler {
                         positioned[idx] = Some(term);
                     }
                 }
-                other => {
-                    let (term, quants) = self.resolve_sumti(other, sumtis, selbris, sentences);
+                _ => {
+                    let (term, quants) = self.resolve_sumti(term_id, sumtis, selbris, sentences);
                     quantifiers.extend(quants);
                     untagged.push(term);
                 }
             }
         }
 
+        if bridi.tail_terms.is_empty() && untagged.is_empty() && positioned.first() == Some(&None) {
+            positioned[0] = Some(self.elided_x1(bind_elided_x1, &mut elided_x1));
+        }
+
         let mut untagged_iter = untagged.into_iter();
         let args: Vec<LogicalTerm> = positioned
             .into_iter()
@@ -431,57 +1709,506 @@ impl SemanticCompiler {
             })
             .collect();
 
-        let mut final_form = self.apply_selbri(bridi.relation, &args, selbris, sumtis, sentences);
-
-        // Wrap with quantifiers (inner-to-outer)
-        for entry in quantifiers.into_iter().rev() {
-            let desc_arity = self.get_selbri_arity(entry.desc_id, selbris);
+        // A tail "fa" may still fill the elided x1: "carvi fa mi"
+        let elided_x1 = elided_x1.filter(|var| args.first() == Some(&LogicalTerm::Variable(*var)));
 
-            let mut restrictor_args = Vec::with_capacity(desc_arity);
-            restrictor_args.push(LogicalTerm::Variable(entry.var));
-            while restrictor_args.len() < desc_arity {
-                restrictor_args.push(LogicalTerm::Unspecified);
+        let mut final_form = match previous {
+            Some(previous) => {
+                let (form, args) = previous.repeat(&args);
+                if bind_elided_x1 {
+                    self.discourse.set_previous(previous.with_args(args));
+                }
+                form
             }
+            None if goi => LogicalForm::Predicate {
+                relation: self.interner.get_or_intern("co'e"),
+                args: args.clone(),
+            },
+            // A main bridi is kept over placeholders for a later go'i
+            None if bind_elided_x1 => {
+                let placeholders: Vec<lasso::Spur> = (1..=args.len())
+                    .map(|i| self.interner.get_or_intern(format!("_x{}", i)))
+                    .collect();
+                let slots: Vec<LogicalTerm> = placeholders
+                    .iter()
+                    .map(|&v| LogicalTerm::Variable(v))
+                    .collect();
+                let template =
+                    self.apply_selbri(bridi.relation, &slots, selbris, sumtis, sentences);
+                let previous = PreviousBridi::new(template, placeholders, args.clone());
+                let form = previous.instantiate(&args);
+                self.discourse.set_previous(previous);
+                form
+            }
+            None => self.apply_selbri(bridi.relation, &args, selbris, sumtis, sentences),
+        };
 
-            let desc_restrictor =
-                self.apply_selbri(entry.desc_id, &restrictor_args, selbris, sumtis, sentences);
-
-            if entry.universal {
-                // ∀x. (restrictor → body) = ∀x. (¬restrictor ∨ body)
-                let mut body = LogicalForm::Or(
-                    Box::new(LogicalForm::Not(Box::new(desc_restrictor))),
-                    Box::new(final_form),
-                );
+        // Modal places relate the core predication to the tagged sumti:
+        // "mi klama ri'a lo nu carvi"
+        //   → klama(mi, …) ∧ modal(rinka, ⟨klama(mi, …)⟩, ⟨carvi(…)⟩)
+        if !modal_refs.is_empty() {
+            let event = LogicalTerm::Proposition(Box::new(final_form.clone()));
+            let modal = self.interner.get_or_intern("modal");
+            for (gismu, term) in modal_refs {
+                let relation = LogicalForm::Predicate {
+                    relation: modal,
+                    args: vec![
+                        LogicalTerm::Constant(self.interner.get_or_intern(&gismu)),
+                        event.clone(),
+                        term,
+                    ],
+                };
+                final_form = LogicalForm::And(Box::new(final_form), Box::new(relation));
+            }
+        }
 
-                // Conjoin relative clause restrictor into antecedent if present
-                if let Some(rel_restrictor) = entry.restrictor {
-                    // ∀x. ((restrictor ∧ rel_clause) → body)
-                    // = ∀x. (¬(restrictor ∧ rel_clause) ∨ body)
-                    // = ∀x. (¬restrictor ∨ ¬rel_clause ∨ body)
-                    // But cleaner: just wrap the rel_clause into the antecedent
-                    body = LogicalForm::Or(
-                        Box::new(LogicalForm::Not(Box::new(rel_restrictor))),
-                        Box::new(body),
-                    );
-                }
+        // Tense binds the predication itself; description quantifiers
+        // wrap outside so restrictors stay timeless.
+        if bridi.tense.is_some() || !tense_refs.is_empty() {
+            final_form = self.apply_tenses(final_form, bridi.tense, tense_refs);
+        }
 
-                final_form = LogicalForm::ForAll(entry.var, Box::new(body));
-            } else {
-                // ∃x. (restrictor ∧ body)
-                let mut body = LogicalForm::And(Box::new(desc_restrictor), Box::new(final_form));
+        // Wrap with quantifiers (inner-to-outer); anaphora to their
+        // variables now refer to the described things instead.
+        let scoped: Vec<lasso::Spur> = quantifiers
+            .iter()
+            .map(|entry| entry.var)
+            .chain(elided_x1)
+            .collect();
+        for entry in quantifiers.into_iter().rev() {
+            final_form = self.wrap_quantifier(entry, final_form, selbris, sumtis, sentences);
+        }
 
-                if let Some(rel_restrictor) = entry.restrictor {
-                    body = LogicalForm::And(Box::new(rel_restrictor), Box::new(body));
-                }
+        if let Some(var) = elided_x1 {
+            final_form = LogicalForm::Exists(var, Box::new(final_form));
+        }
+        for var in scoped {
+            self.discourse.close_scope(var);
+        }
 
-                final_form = LogicalForm::Exists(entry.var, Box::new(body));
-            }
+        if coe {
+            final_form = self.drop_coe(final_form);
         }
 
         if bridi.negated {
             final_form = LogicalForm::Not(Box::new(final_form));
         }
 
+        // Clauses on specific referents hold whatever the bridi claims:
+        // "la .alis. poi sutra cu na klama" → ¬klama(alis, …) ∧ sutra(alis)
+        let claims: Vec<LogicalForm> = self.claims.drain(claims_len..).collect();
+        for claim in claims {
+            final_form = LogicalForm::And(Box::new(final_form), Box::new(claim));
+        }
+
         final_form
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bindings::lojban::nesy::ast_types::Span;
+
+    // ─── AST construction ─────────────────────────────────────
+
+    /// Builds an AST buffer by hand, children before parents, the way
+    /// the parser flattens one.
+    #[derive(Default)]
+    struct Ast {
+        selbris: Vec<Selbri>,
+        sumtis: Vec<Sumti>,
+        sentences: Vec<Bridi>,
+        statements: Vec<Statement>,
+    }
+
+    impl Ast {
+        fn selbri(&mut self, selbri: Selbri) -> u32 {
+            self.selbris.push(selbri);
+            self.selbris.len() as u32 - 1
+        }
+
+        fn brivla(&mut self, word: &str) -> u32 {
+            self.selbri(Selbri::Root(word.to_string()))
+        }
+
+        fn sumti(&mut self, sumti: Sumti) -> u32 {
+            self.sumtis.push(sumti);
+            self.sumtis.len() as u32 - 1
+        }
+
+        fn pro(&mut self, cmavo: &str) -> u32 {
+            self.sumti(Sumti::ProSumti(cmavo.to_string()))
+        }
+
+        fn description(&mut self, gadri: Gadri, count: Option<Quantifier>, word: &str) -> u32 {
+            let selbri = self.brivla(word);
+            self.sumti(Sumti::Description((gadri, count, selbri)))
+        }
+
+        fn quantified(&mut self, quantifier: Quantifier, inner: u32) -> u32 {
+            self.sumti(Sumti::Quantified((quantifier, inner)))
+        }
+
+        fn name(&mut self, name: &str) -> u32 {
+            self.sumti(Sumti::Name(name.to_string()))
+        }
+
+        /// "left .A right", with .Anai when `negate_right`
+        fn connected(
+            &mut self,
+            left: u32,
+            connective: Connective,
+            negate_right: bool,
+            right: u32,
+        ) -> u32 {
+            let connective = LogicalConnective {
+                connective,
+                converted: false,
+                negate_left: false,
+                negate_right,
+            };
+            self.sumti(Sumti::Connected((left, connective, right)))
+        }
+
+        /// "left .e right"
+        fn and(&mut self, left: u32, right: u32) -> u32 {
+            self.connected(left, Connective::Je, false, right)
+        }
+
+        fn restricted(&mut self, inner: u32, kind: RelClauseKind, body: RelClauseBody) -> u32 {
+            self.sumti(Sumti::Restricted((inner, RelClause { kind, body })))
+        }
+
+        /// A bridi over an already built selbri; returns its sentence index.
+        fn bridi_with(&mut self, head: Vec<u32>, relation: u32, tail: Vec<u32>) -> u32 {
+            self.sentences.push(Bridi {
+                relation,
+                head_terms: head,
+                tail_terms: tail,
+                negated: false,
+                tense: None,
+                question: false,
+                span: Span { start: 0, end: 0 },
+            });
+            self.sentences.len() as u32 - 1
+        }
+
+        fn bridi(&mut self, head: Vec<u32>, brivla: &str, tail: Vec<u32>) -> u32 {
+            let relation = self.brivla(brivla);
+            self.bridi_with(head, relation, tail)
+        }
+
+        /// Compile `bridi` as a root statement and render the result.
+        fn compile(&mut self, compiler: &mut SemanticCompiler, bridi: u32) -> String {
+            self.statements.push(Statement::Simple(bridi));
+            let form = compiler.compile_statement(
+                self.statements.len() as u32 - 1,
+                &self.statements,
+                &self.selbris,
+                &self.sumtis,
+                &self.sentences,
+            );
+            show(&form, &compiler.interner)
+        }
+    }
+
+    fn exactly(count: u32) -> Quantifier {
+        Quantifier {
+            kind: QuantifierKind::Exactly,
+            count,
+        }
+    }
+
+    // ─── Rendering ────────────────────────────────────────────
+
+    fn show(form: &LogicalForm, interner: &Rodeo) -> String {
+        match form {
+            LogicalForm::Predicate { relation, args } => {
+                let args: Vec<String> = args.iter().map(|a| show_term(a, interner)).collect();
+                format!("{}({})", interner.resolve(relation), args.join(", "))
+            }
+            LogicalForm::And(l, r) => format!("({} ∧ {})", show(l, interner), show(r, interner)),
+            LogicalForm::Or(l, r) => format!("({} ∨ {})", show(l, interner), show(r, interner)),
+            LogicalForm::Implies(l, r) => {
+                format!("({} → {})", show(l, interner), show(r, interner))
+            }
+            LogicalForm::Not(inner) => format!("¬{}", show(inner, interner)),
+            LogicalForm::Exists(v, body) => {
+                format!("∃{}. {}", interner.resolve(v), show(body, interner))
+            }
+            LogicalForm::ForAll(v, body) => {
+                format!("∀{}. {}", interner.resolve(v), show(body, interner))
+            }
+            LogicalForm::At(time, body) => {
+                format!(
+                    "At({}, {})",
+                    show_term(time, interner),
+                    show(body, interner)
+                )
+            }
+            LogicalForm::Generic(v, restrictor, body) => format!(
+                "Gen {}. ({} ⇒ {})",
+                interner.resolve(v),
+                show(restrictor, interner),
+                show(body, interner)
+            ),
+        }
+    }
+
+    fn show_term(term: &LogicalTerm, interner: &Rodeo) -> String {
+        match term {
+            LogicalTerm::Variable(v) | LogicalTerm::Constant(v) => interner.resolve(v).to_string(),
+            LogicalTerm::Description(d) => format!("[{}]", interner.resolve(d)),
+            LogicalTerm::Unspecified => "zo'e".to_string(),
+            LogicalTerm::Proposition(inner) => format!("⟨{}⟩", show(inner, interner)),
+        }
+    }
+
+    // ─── Quantified descriptions ──────────────────────────────
+
+    /// "Q lo ci gerku cu sutra", with Q omitted when `outer` is None.
+    fn three_dogs_are_fast(outer: Option<Quantifier>) -> String {
+        let mut ast = Ast::default();
+        let dogs = ast.description(Gadri::Lo, Some(exactly(3)), "gerku");
+        let dogs = match outer {
+            Some(q) => ast.quantified(q, dogs),
+            None => dogs,
+        };
+        let bridi = ast.bridi(vec![dogs], "sutra", vec![]);
+        ast.compile(&mut SemanticCompiler::new(), bridi)
+    }
+
+    #[test]
+    fn inner_count_alone_is_ro_over_the_set() {
+        let bare = three_dogs_are_fast(None);
+        assert_eq!(bare, three_dogs_are_fast(Some(RO)));
+        assert!(
+            bare.ends_with(" ∧ ∀_v0. (¬gerku(_v0, zo'e) ∨ sutra(_v0, zo'e)))"),
+            "{}",
+            bare
+        );
+    }
+
+    #[test]
+    fn outer_quantifier_counts_within_the_set() {
+        let two = three_dogs_are_fast(Some(exactly(2)));
+        // The set has three members and exactly two of them are fast
+        let (count, claim) = two.split_once(" ∧ ∃_v0. ∃").expect(&two);
+        assert!(count.contains("∀_v4. (¬gerku(_v4, zo'e) ∨ "), "{}", two);
+        assert!(claim.contains("sutra(_v0, zo'e)"), "{}", two);
+        assert!(!count.contains("sutra"), "{}", two);
+    }
+
+    // ─── Lujvo ────────────────────────────────────────────────
+
+    fn mi_brivla_do(brivla: &str) -> String {
+        let mut ast = Ast::default();
+        let mi = ast.pro("mi");
+        let do_ = ast.pro("do");
+        let bridi = ast.bridi(vec![mi], brivla, vec![do_]);
+        ast.compile(&mut SemanticCompiler::new(), bridi)
+    }
+
+    #[test]
+    fn mapped_lujvo_entails_its_components() {
+        // gerzda: x1=z1 is a kennel for dog x2=z2=g1
+        assert_eq!(
+            mi_brivla_do("gerzda"),
+            "((gerzda(mi, do) ∧ gerku(do, zo'e)) ∧ zdani(mi, do))"
+        );
+    }
+
+    #[test]
+    fn unmapped_lujvo_reads_like_its_tanru() {
+        // The head takes every place, the modifier only x1
+        assert_eq!(
+            mi_brivla_do("nelcti"),
+            "((nelcti(mi, do) ∧ nelci(mi, zo'e)) ∧ citka(mi, do))"
+        );
+    }
+
+    #[test]
+    fn gismu_has_no_components() {
+        assert_eq!(mi_brivla_do("prami"), "prami(mi, do)");
+    }
+
+    // ─── Relative clauses ─────────────────────────────────────
+
+    /// "le <owner> gerku cu blanu", the possessor being a pe phrase.
+    fn owners_dog_is_blue(compiler: &mut SemanticCompiler, owner: &str) -> String {
+        let mut ast = Ast::default();
+        let dog = ast.description(Gadri::Le, None, "gerku");
+        let owner = ast.pro(owner);
+        let dog = ast.restricted(dog, RelClauseKind::Pe, RelClauseBody::Sumti(owner));
+        let bridi = ast.bridi(vec![dog], "blanu", vec![]);
+        ast.compile(compiler, bridi)
+    }
+
+    #[test]
+    fn possessor_is_part_of_a_specific_description() {
+        let mut compiler = SemanticCompiler::new();
+        assert_eq!(
+            owners_dog_is_blue(&mut compiler, "mi"),
+            "(blanu([gerku pe mi]) ∧ srana([gerku pe mi], mi))"
+        );
+        assert_eq!(
+            owners_dog_is_blue(&mut compiler, "do"),
+            "(blanu([gerku pe do]) ∧ srana([gerku pe do], do))"
+        );
+    }
+
+    /// "Q lo gerku noi blanu cu sutra"
+    fn blue_dogs_are_fast(outer: Quantifier) -> String {
+        let mut ast = Ast::default();
+        let dogs = ast.description(Gadri::Lo, None, "gerku");
+        let blue = ast.bridi(vec![], "blanu", vec![]);
+        let dogs = ast.quantified(outer, dogs);
+        let dogs = ast.restricted(dogs, RelClauseKind::Noi, RelClauseBody::Sentence(blue));
+        let bridi = ast.bridi(vec![dogs], "sutra", vec![]);
+        ast.compile(&mut SemanticCompiler::new(), bridi)
+    }
+
+    #[test]
+    fn appositive_is_claimed_of_each_under_ro() {
+        assert_eq!(
+            blue_dogs_are_fast(RO),
+            "∀_v0. (¬gerku(_v0, zo'e) ∨ (sutra(_v0, zo'e) ∧ blanu(_v0)))"
+        );
+    }
+
+    #[test]
+    fn appositive_is_claimed_of_what_is_counted() {
+        assert_eq!(
+            blue_dogs_are_fast(SUHO),
+            "∃_v0. (gerku(_v0, zo'e) ∧ (sutra(_v0, zo'e) ∧ blanu(_v0)))"
+        );
+        let two = blue_dogs_are_fast(exactly(2));
+        assert!(two.starts_with("∃_v0. ∃_v1."), "{}", two);
+        assert!(two.contains("(sutra(_v0, zo'e) ∧ blanu(_v0))"), "{}", two);
+    }
+
+    #[test]
+    fn appositive_is_dropped_under_no() {
+        assert_eq!(
+            blue_dogs_are_fast(exactly(0)),
+            "¬∃_v0. (gerku(_v0, zo'e) ∧ sutra(_v0, zo'e))"
+        );
+    }
+
+    // ─── Connectives ──────────────────────────────────────────
+
+    /// "la .alis. .A la .bob. cu klama"
+    fn alice_or_bob_goes(connective: Connective, negate_right: bool) -> String {
+        let mut ast = Ast::default();
+        let alice = ast.name("alis");
+        let bob = ast.name("bob");
+        let who = ast.connected(alice, connective, negate_right, bob);
+        let bridi = ast.bridi(vec![who], "klama", vec![]);
+        ast.compile(&mut SemanticCompiler::new(), bridi)
+    }
+
+    #[test]
+    fn onai_is_exclusive_or() {
+        assert_eq!(
+            alice_or_bob_goes(Connective::Jo, true),
+            "((¬klama(alis, zo'e, zo'e, zo'e, zo'e) ∨ ¬klama(bob, zo'e, zo'e, zo'e, zo'e)) \
+             ∧ (¬¬klama(bob, zo'e, zo'e, zo'e, zo'e) ∨ klama(alis, zo'e, zo'e, zo'e, zo'e)))"
+        );
+    }
+
+    #[test]
+    fn u_claims_its_left_operand_whether_or_not_the_right() {
+        assert_eq!(
+            alice_or_bob_goes(Connective::Ju, false),
+            "klama(alis, zo'e, zo'e, zo'e, zo'e)"
+        );
+    }
+
+    #[test]
+    fn connected_be_place_expands_at_the_bridi() {
+        // mi pendo be la .alis. .e la .bob.
+        let mut ast = Ast::default();
+        let mi = ast.pro("mi");
+        let alice = ast.name("alis");
+        let bob = ast.name("bob");
+        let both = ast.and(alice, bob);
+        let pendo = ast.brivla("pendo");
+        let relation = ast.selbri(Selbri::WithArgs((pendo, vec![both])));
+        let bridi = ast.bridi_with(vec![mi], relation, vec![]);
+        assert_eq!(
+            ast.compile(&mut SemanticCompiler::new(), bridi),
+            "(pendo(mi, alis) ∧ pendo(mi, bob))"
+        );
+    }
+
+    // ─── Questions ────────────────────────────────────────────
+
+    #[test]
+    fn ma_is_asked_once_across_connected_sumti() {
+        // ma klama la .bob. .e la .karl.
+        let mut ast = Ast::default();
+        let ma = ast.pro("ma");
+        let bob = ast.name("bob");
+        let karl = ast.name("karl");
+        let both = ast.and(bob, karl);
+        let bridi = ast.bridi(vec![ma], "klama", vec![both]);
+        assert_eq!(
+            ast.compile(&mut SemanticCompiler::new(), bridi),
+            "(klama(ma, bob, zo'e, zo'e, zo'e) ∧ klama(ma, karl, zo'e, zo'e, zo'e))"
+        );
+    }
+
+    #[test]
+    fn each_ma_keeps_its_variable_across_connected_sumti() {
+        // ma .e la .bob. cu klama ma
+        let mut ast = Ast::default();
+        let ma = ast.pro("ma");
+        let bob = ast.name("bob");
+        let who = ast.and(ma, bob);
+        let to = ast.pro("ma");
+        let bridi = ast.bridi(vec![who], "klama", vec![to]);
+        assert_eq!(
+            ast.compile(&mut SemanticCompiler::new(), bridi),
+            "(klama(ma, ma2, zo'e, zo'e, zo'e) ∧ klama(bob, ma2, zo'e, zo'e, zo'e))"
+        );
+    }
+
+    // ─── Discourse ────────────────────────────────────────────
+
+    /// "ko'a <brivla>", after "ko'a goi la .<name>. cu klama" when `name`
+    /// is given.
+    fn koha(compiler: &mut SemanticCompiler, name: Option<&str>, brivla: &str) -> String {
+        let mut ast = Ast::default();
+        if let Some(name) = name {
+            let koha = ast.pro("ko'a");
+            let name = ast.name(name);
+            let assigned = ast.restricted(koha, RelClauseKind::Goi, RelClauseBody::Sumti(name));
+            let bridi = ast.bridi(vec![assigned], "klama", vec![]);
+            ast.compile(compiler, bridi);
+        }
+        let koha = ast.pro("ko'a");
+        let bridi = ast.bridi(vec![koha], brivla, vec![]);
+        ast.compile(compiler, bridi)
+    }
+
+    #[test]
+    fn forked_discourse_goes_on_independently() {
+        let mut compiler = SemanticCompiler::new();
+        assert_eq!(
+            koha(&mut compiler, Some("alis"), "sutra"),
+            "sutra(alis, zo'e)"
+        );
+
+        let mut fork = compiler.clone();
+        assert_eq!(koha(&mut fork, Some("bob"), "sutra"), "sutra(bob, zo'e)");
+        assert_eq!(koha(&mut compiler, None, "sutra"), "sutra(alis, zo'e)");
+
+        // A new compiler, as after a reset, has no ko'a assigned
+        assert_eq!(
+            koha(&mut SemanticCompiler::new(), None, "sutra"),
+            "sutra(ko'a, zo'e)"
+        );
+        assert_eq!(koha(&mut fork, None, "sutra"), "sutra(bob, zo'e)");
+    }
+}